@@ -90,7 +90,7 @@ fn aleo_encryption_decrypt(c: &mut Criterion) {
     let msg = b"aleo_encryption_encrypt_encrypt_encrypt_encrypt_encrypt_encrypt";
     let encoded_message = EncryptionScheme::encode_message(msg).unwrap();
     let ct = parameters.encrypt(&sym_key, &encoded_message);
-    c.bench_function("Aleo Encryption Decrypt", move |b| b.iter(|| parameters.decrypt(&sym_key, &ct)));
+    c.bench_function("Aleo Encryption Decrypt", move |b| b.iter(|| parameters.decrypt_unauthenticated(&sym_key, &ct)));
 }
 
 criterion_group! {
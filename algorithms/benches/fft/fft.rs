@@ -16,7 +16,7 @@
 
 extern crate criterion;
 
-use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain};
+use snarkvm_algorithms::fft::{polynomial::PolyMultiplier, DensePolynomial, EvaluationDomain};
 use snarkvm_curves::bls12_377::Fr as Bls12_377_Fr;
 use snarkvm_fields::PrimeField;
 
@@ -116,5 +116,53 @@ fn bench_bls12_377(c: &mut Criterion) {
     fft_benches::<Bls12_377_Fr>(c, "BLS12-377 - radix-2");
 }
 
-criterion_group!(benches, bench_bls12_377);
+/// Benchmarks the in-place FFT and IFFT at 2^12, 2^18, and 2^22, with 1, 4, and 16 threads.
+fn bench_bls12_377_threads(c: &mut Criterion) {
+    for (name, ifft) in [("fft_in_place", false), ("ifft_in_place", true)] {
+        let mut group = c.benchmark_group(format!("BLS12-377 - radix-2 - {name} - threads"));
+        group.sample_size(10);
+        for log_degree in [12, 18, 22] {
+            let (domain, mut a) = create_evaluation_domain::<Bls12_377_Fr>(1 << log_degree);
+            for num_threads in [1, 4, 16] {
+                let pool = rayon::ThreadPoolBuilder::new().num_threads(num_threads).build().unwrap();
+                let id = BenchmarkId::new(format!("2^{log_degree}"), format!("{num_threads} threads"));
+                group.bench_function(id, |b| {
+                    pool.install(|| match ifft {
+                        true => b.iter(|| domain.ifft_in_place(&mut a)),
+                        false => b.iter(|| domain.fft_in_place(&mut a)),
+                    })
+                });
+            }
+        }
+        group.finish();
+    }
+}
+
+/// Benchmarks schoolbook, Karatsuba, and FFT multiplication of two polynomials of equal degree, to locate the crossovers.
+fn bench_bls12_377_poly_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("BLS12-377 - poly_mul");
+    for log_degree in 2..=8 {
+        let rng = &mut thread_rng();
+        let a = DensePolynomial::<Bls12_377_Fr>::rand((1 << log_degree) - 1, rng);
+        let b = DensePolynomial::<Bls12_377_Fr>::rand((1 << log_degree) - 1, rng);
+
+        group.bench_with_input(BenchmarkId::new("naive", 1 << log_degree), &(&a, &b), |bencher, (a, b)| {
+            bencher.iter(|| a.naive_mul(b))
+        });
+        group.bench_with_input(BenchmarkId::new("karatsuba", 1 << log_degree), &(&a, &b), |bencher, (a, b)| {
+            bencher.iter(|| a.mul_karatsuba(b))
+        });
+        group.bench_with_input(BenchmarkId::new("fft", 1 << log_degree), &(&a, &b), |bencher, (a, b)| {
+            bencher.iter(|| {
+                let mut m = PolyMultiplier::new();
+                m.add_polynomial_ref(a, "a");
+                m.add_polynomial_ref(b, "b");
+                m.multiply().unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_bls12_377, bench_bls12_377_threads, bench_bls12_377_poly_mul);
 criterion_main!(benches);
@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_algorithms::{
+    crh::{PoseidonCRH, BHPCRH},
+    merkle_tree::{MerkleTree, MerkleTreeParameters, MERKLE_TREE_PARALLEL_THRESHOLD},
+    traits::MerkleParameters,
+};
+use snarkvm_curves::{bls12_377::Fr, edwards_bls12::EdwardsProjective};
+
+use rand::{thread_rng, Rng};
+use std::sync::Arc;
+
+use criterion::Criterion;
+
+const SETUP_MESSAGE: &str = "merkle_tree_parallel_benchmark";
+
+const NUM_WINDOWS: usize = 3;
+const LEAF_WINDOW_SIZE: usize = 29;
+const TWO_TO_ONE_WINDOW_SIZE: usize = 57;
+const TREE_DEPTH: usize = 32;
+
+type BHPParameters = MerkleTreeParameters<
+    BHPCRH<EdwardsProjective, NUM_WINDOWS, LEAF_WINDOW_SIZE>,
+    BHPCRH<EdwardsProjective, NUM_WINDOWS, TWO_TO_ONE_WINDOW_SIZE>,
+    TREE_DEPTH,
+>;
+type PoseidonParameters = MerkleTreeParameters<PoseidonCRH<Fr, 2>, PoseidonCRH<Fr, 3>, TREE_DEPTH>;
+
+const NUM_ENTRIES: usize = 1 << 20;
+const LEAF_SIZE: usize = 32;
+
+/// Generates the specified number of random Merkle tree leaves.
+fn generate_random_leaves(num_leaves: usize) -> Vec<[u8; LEAF_SIZE]> {
+    let mut rng = thread_rng();
+    (0..num_leaves).map(|_| rng.gen()).collect()
+}
+
+/// Benchmarks the construction of a Merkle tree, hashing serially and in parallel.
+fn new<P: MerkleParameters>(c: &mut Criterion, name: &str) {
+    let parameters = Arc::new(P::setup(SETUP_MESSAGE));
+    let leaves = generate_random_leaves(NUM_ENTRIES);
+
+    for (mode, threshold) in [("serial", usize::MAX), ("parallel", MERKLE_TREE_PARALLEL_THRESHOLD)] {
+        c.bench_function(&format!("New {} Merkle Tree ({} entries, {})", name, NUM_ENTRIES, mode), |b| {
+            b.iter(|| {
+                MerkleTree::<P>::new_with_parallel_threshold(parameters.clone(), &leaves, threshold).unwrap();
+            })
+        });
+    }
+}
+
+fn new_bhp(c: &mut Criterion) {
+    new::<BHPParameters>(c, "BHP");
+}
+
+fn new_poseidon(c: &mut Criterion) {
+    new::<PoseidonParameters>(c, "Poseidon");
+}
+
+criterion_group! {
+    name = merkle_tree_parallel;
+    config = Criterion::default().sample_size(10);
+    targets = new_bhp, new_poseidon
+}
+
+criterion_main!(merkle_tree_parallel);
@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_algorithms::msm::*;
+use snarkvm_curves::{
+    bls12_377::{Fr, G1Affine},
+    AffineCurve,
+};
+use snarkvm_fields::PrimeField;
+
+use criterion::Criterion;
+use rand::thread_rng;
+use rayon::prelude::*;
+
+#[macro_use]
+extern crate criterion;
+
+fn create_scalar_bases<G: AffineCurve<ScalarField = F>, F: PrimeField>(size: usize) -> (Vec<G>, Vec<F>) {
+    let bases = (0..size).into_par_iter().map(|_| G::rand(&mut thread_rng())).collect::<Vec<_>>();
+    let scalars = (0..size).into_par_iter().map(|_| F::rand(&mut thread_rng())).collect::<Vec<_>>();
+    (bases, scalars)
+}
+
+fn precomputed_base_bls12_377(c: &mut Criterion) {
+    let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(1 << 16);
+    let scalar_reprs = scalars.iter().map(|scalar| scalar.to_repr()).collect::<Vec<_>>();
+
+    for log_size in [12, 14, 16] {
+        let size = 1 << log_size;
+        c.bench_function(&format!("VariableBase MSM on BLS12-377 (2^{})", log_size), |b| {
+            b.iter(|| VariableBase::msm(&bases[..size], &scalar_reprs[..size]))
+        });
+
+        for window_bits in [8, 12, 16] {
+            c.bench_function(&format!("Precompute bases on BLS12-377 (2^{}, {} bits)", log_size, window_bits), |b| {
+                b.iter(|| FixedBaseMSM::precompute(&bases[..size], window_bits))
+            });
+
+            // The per-call cost, once the precomputation is amortized.
+            let precomputed = FixedBaseMSM::precompute(&bases[..size], window_bits);
+            c.bench_function(&format!("Precomputed MSM on BLS12-377 (2^{}, {} bits)", log_size, window_bits), |b| {
+                b.iter(|| precomputed.msm(&scalars[..size]))
+            });
+        }
+    }
+}
+
+criterion_group! {
+    name = precomputed_base_group;
+    config = Criterion::default().sample_size(10);
+    targets = precomputed_base_bls12_377
+}
+
+criterion_main!(precomputed_base_group);
@@ -53,10 +53,91 @@ fn variable_base_edwards_bls12(c: &mut Criterion) {
     }
 }
 
+fn variable_base_window_size(c: &mut Criterion) {
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(1 << 14);
+
+    for log_size in 10..=14 {
+        let size = 1 << log_size;
+        // The window size of the formula used prior to the tuning table.
+        let legacy = MSMConfig::new(Some(log_size * 69 / 100 + 2), None, None).unwrap();
+
+        for (name, config) in [("tuned", MSMConfig::default()), ("legacy", legacy)] {
+            c.bench_function(&format!("VariableBase MSM on BLS12-377 (2^{}, {} window size)", log_size, name), |b| {
+                b.iter(|| VariableBase::msm_with_config(&bases[..size], &scalars[..size], &config))
+            });
+        }
+    }
+}
+
+fn variable_base_signed_digits(c: &mut Criterion) {
+    fn bench<G: AffineCurve>(c: &mut Criterion, name: &str) {
+        let (bases, scalars) = create_scalar_bases::<G, G::ScalarField>(1 << 20);
+
+        for log_size in [16, 20] {
+            let size = 1 << log_size;
+            for signed_digits in [false, true] {
+                let config = MSMConfig::new(None, Some(signed_digits), None).unwrap();
+                let digits = if signed_digits { "signed" } else { "unsigned" };
+                c.bench_function(&format!("VariableBase MSM on {} (2^{}, {} digits)", name, log_size, digits), |b| {
+                    b.iter(|| VariableBase::msm_with_config(&bases[..size], &scalars[..size], &config))
+                });
+            }
+        }
+    }
+
+    bench::<snarkvm_curves::bls12_377::G1Affine>(c, "BLS12-377 G1");
+    bench::<snarkvm_curves::bls12_377::G2Affine>(c, "BLS12-377 G2");
+    bench::<snarkvm_curves::edwards_bls12::EdwardsAffine>(c, "Edwards-BLS12");
+}
+
+fn variable_base_multi_scalar_mul_batched(c: &mut Criterion) {
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(1 << 18);
+    let scalar_vecs =
+        (0..12).map(|i| scalars[i..].iter().chain(&scalars[..i]).copied().collect::<Vec<_>>()).collect::<Vec<_>>();
+    let scalar_slices = scalar_vecs.iter().map(|scalars| scalars.as_slice()).collect::<Vec<_>>();
+
+    for num_vectors in [1, 4, 12] {
+        c.bench_function(&format!("VariableBase MSM on BLS12-377 (2^18, {} separate vectors)", num_vectors), |b| {
+            b.iter(|| {
+                scalar_slices[..num_vectors]
+                    .iter()
+                    .map(|scalars| VariableBase::msm(&bases, scalars))
+                    .collect::<Vec<_>>()
+            })
+        });
+        c.bench_function(&format!("VariableBase MSM on BLS12-377 (2^18, {} batched vectors)", num_vectors), |b| {
+            b.iter(|| VariableBase::multi_scalar_mul_batched(&bases, &scalar_slices[..num_vectors]).unwrap())
+        });
+    }
+}
+
+fn variable_base_small_scalars(c: &mut Criterion) {
+    fn bench<G: AffineCurve<ScalarField = F>, F: PrimeField>(c: &mut Criterion, name: &str) {
+        let (bases, full) = create_scalar_bases::<G, F>(1 << 16);
+        let scalars = (0..1 << 16).map(|_| rand::random::<u64>()).collect::<Vec<_>>();
+        let lifted = scalars.iter().map(|scalar| F::from(*scalar as u128).to_repr()).collect::<Vec<_>>();
+
+        c.bench_function(&format!("VariableBase MSM on {} (2^16, full scalars)", name), |b| {
+            b.iter(|| VariableBase::msm(&bases, &full))
+        });
+        c.bench_function(&format!("VariableBase MSM on {} (2^16, lifted u64 scalars)", name), |b| {
+            b.iter(|| VariableBase::msm(&bases, &lifted))
+        });
+        c.bench_function(&format!("VariableBase MSM on {} (2^16, u64 scalars)", name), |b| {
+            b.iter(|| VariableBase::msm_u64(&bases, &scalars))
+        });
+    }
+
+    bench::<snarkvm_curves::bls12_377::G1Affine, _>(c, "BLS12-377 G1");
+    bench::<snarkvm_curves::edwards_bls12::EdwardsAffine, _>(c, "Edwards-BLS12");
+}
+
 criterion_group! {
     name = variable_base_group;
     config = Criterion::default().sample_size(10);
-    targets = variable_base_bls12_377, variable_base_edwards_bls12
+    targets = variable_base_bls12_377, variable_base_edwards_bls12, variable_base_window_size, variable_base_signed_digits, variable_base_multi_scalar_mul_batched, variable_base_small_scalars
 }
 
 criterion_main!(variable_base_group);
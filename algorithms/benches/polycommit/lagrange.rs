@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonSponge,
+    fft::{DensePolynomial, EvaluationDomain},
+    polycommit::{kzg10::KZG10, sonic_pc::SonicKZG10},
+    snark::marlin::FiatShamirAlgebraicSpongeRng,
+};
+use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+use snarkvm_utilities::UniformRand;
+
+use criterion::Criterion;
+use rand::thread_rng;
+use std::sync::atomic::AtomicBool;
+
+type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>;
+type PC = SonicKZG10<Bls12_377, FS>;
+
+fn commit_evaluations(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+    let max_degree = 1 << 14;
+    let pp = PC::setup(max_degree, rng).unwrap();
+    let (ck, _) = PC::trim(&pp, max_degree, None, 0, None).unwrap();
+
+    for log_size in [10, 12, 14] {
+        let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+        let lagrange_ck = ck.to_lagrange_basis(domain).unwrap();
+        let evaluations = (0..domain.size()).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        c.bench_function(&format!("Commit to evaluations via iFFT (2^{})", log_size), |b| {
+            b.iter(|| {
+                let polynomial = DensePolynomial::from_coefficients_vec(domain.ifft(&evaluations));
+                KZG10::commit(&ck.powers(), &polynomial.into(), None, &AtomicBool::new(false), None).unwrap()
+            })
+        });
+        c.bench_function(&format!("Commit to evaluations in the Lagrange basis (2^{})", log_size), |b| {
+            b.iter(|| lagrange_ck.commit_evaluations(&evaluations).unwrap())
+        });
+    }
+}
+
+criterion_group! {
+    name = lagrange;
+    config = Criterion::default().sample_size(10);
+    targets = commit_evaluations
+}
+
+criterion_main!(lagrange);
@@ -20,6 +20,7 @@ extern crate criterion;
 use snarkvm_algorithms::{
     crypto_hash::PoseidonSponge,
     snark::marlin::{ahp::AHPForR1CS, FiatShamirAlgebraicSpongeRng, MarlinHidingMode, MarlinSNARK},
+    Prepare,
     SNARK,
 };
 use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
@@ -144,10 +145,115 @@ fn snark_verify(c: &mut Criterion) {
     });
 }
 
+fn snark_verify_with_prepared_inputs(c: &mut Criterion) {
+    let num_constraints = 1000;
+    let num_variables = 25;
+    let rng = &mut thread_rng();
+
+    let x = Fr::rand(rng);
+    let y = Fr::rand(rng);
+    let mut z = x;
+    z.mul_assign(&y);
+
+    let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(1000000, 100000, 1000000).unwrap();
+    let universal_srs = MarlinInst::universal_setup(&max_degree, rng).unwrap();
+
+    let circuit = Benchmark::<Fr> { a: Some(x), b: Some(y), num_constraints, num_variables };
+
+    let params = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+    let prepared_vk = params.1.prepare();
+    let prepared_inputs = MarlinInst::prepare_inputs(&params.1, [z]).unwrap();
+
+    let proof = MarlinInst::prove(&params.0, &circuit, rng).unwrap();
+
+    c.bench_function("snark_verify_with_prepared_inputs", move |b| {
+        b.iter(|| {
+            let verification = MarlinInst::verify_with_prepared_inputs(&prepared_vk, &prepared_inputs, &proof).unwrap();
+            assert!(verification);
+        })
+    });
+}
+
+fn snark_verify_proofs(c: &mut Criterion) {
+    let num_constraints = 1000;
+    let num_variables = 25;
+    let rng = &mut thread_rng();
+
+    let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(1000000, 100000, 1000000).unwrap();
+    let universal_srs = MarlinInst::universal_setup(&max_degree, rng).unwrap();
+
+    let circuit = Benchmark::<Fr> { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+    let params = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+    let mut instances = Vec::new();
+    for _ in 0..16 {
+        let x = Fr::rand(rng);
+        let y = Fr::rand(rng);
+        let circuit = Benchmark::<Fr> { a: Some(x), b: Some(y), num_constraints, num_variables };
+        instances.push(([x * y], MarlinInst::prove(&params.0, &circuit, rng).unwrap()));
+    }
+
+    for num_proofs in [1, 4, 16] {
+        let instances = instances[..num_proofs].iter().map(|(input, proof)| (*input, proof)).collect::<Vec<_>>();
+
+        c.bench_function(&format!("snark_verify_sequential ({} proofs)", num_proofs), |b| {
+            b.iter(|| {
+                for (input, proof) in &instances {
+                    assert!(MarlinInst::verify(&params.1, *input, proof).unwrap());
+                }
+            })
+        });
+        c.bench_function(&format!("snark_verify_proofs ({} proofs)", num_proofs), |b| {
+            b.iter(|| assert!(MarlinInst::verify_proofs(&params.1, &instances).unwrap()))
+        });
+    }
+}
+
+fn snark_verify_proofs_with_keys(c: &mut Criterion) {
+    let num_variables = 25;
+    let rng = &mut thread_rng();
+
+    let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(1000000, 100000, 1000000).unwrap();
+    let universal_srs = MarlinInst::universal_setup(&max_degree, rng).unwrap();
+
+    // Two circuits of different sizes, whose proofs alternate in the batch.
+    let keys = [1000, 2000].map(|num_constraints| {
+        let circuit =
+            Benchmark::<Fr> { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+        (MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap(), num_constraints)
+    });
+
+    let mut instances = Vec::new();
+    for i in 0..64 {
+        let ((index_pk, index_vk), num_constraints) = &keys[i % keys.len()];
+        let x = Fr::rand(rng);
+        let y = Fr::rand(rng);
+        let circuit = Benchmark::<Fr> { a: Some(x), b: Some(y), num_constraints: *num_constraints, num_variables };
+        instances.push((index_vk, [x * y], MarlinInst::prove(index_pk, &circuit, rng).unwrap()));
+    }
+
+    for num_proofs in [8, 64] {
+        let instances =
+            instances[..num_proofs].iter().map(|(vk, input, proof)| (*vk, &input[..], proof)).collect::<Vec<_>>();
+
+        c.bench_function(&format!("snark_verify_sequential_with_keys ({} proofs)", num_proofs), |b| {
+            b.iter(|| {
+                for (vk, input, proof) in &instances {
+                    assert!(MarlinInst::verify(vk, *input, proof).unwrap());
+                }
+            })
+        });
+        c.bench_function(&format!("snark_verify_proofs_with_keys ({} proofs)", num_proofs), |b| {
+            b.iter(|| assert!(MarlinInst::verify_proofs_with_keys(&instances).unwrap()))
+        });
+    }
+}
+
 criterion_group! {
     name = marlin_snark;
     config = Criterion::default().sample_size(10);
-    targets = snark_universal_setup, snark_circuit_setup, snark_prove, snark_verify,
+    targets = snark_universal_setup, snark_circuit_setup, snark_prove, snark_verify, snark_verify_with_prepared_inputs,
+              snark_verify_proofs, snark_verify_proofs_with_keys,
 }
 
 criterion_main!(marlin_snark);
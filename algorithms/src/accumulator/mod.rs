@@ -0,0 +1,226 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A bilinear accumulator over the scalar field of a pairing engine, with membership and
+//! non-membership witnesses ([Nguyen05](https://eprint.iacr.org/2005/123), [DT08](https://eprint.iacr.org/2008/538)).
+//!
+//! The accumulator of a set `S` is `A := G^f(s)` for `f(X) := prod_{x in S} (X + x)` and a trapdoor `s`.
+//! Dividing `f(X)` by `(X + y)` leaves a quotient `q(X)` and a remainder `r = f(-y)`, which is zero
+//! if and only if `y` is in `S`. The witness for `y` is `W := G^q(s)` and `r`, and is checked as
+//! `e(W, H^s * H^y) * e(G^r, H) == e(A, H)`.
+//!
+//! The trapdoor is sampled and discarded in `setup`, so the parameters must come from a trusted setup.
+
+use crate::msm::{FixedBase, VariableBase};
+use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_utilities::rand::UniformRand;
+
+use anyhow::{ensure, Result};
+use rand::{CryptoRng, Rng};
+
+#[cfg(test)]
+mod tests;
+
+/// The public parameters of the accumulator, for sets of up to `capacity` elements.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccumulatorParameters<E: PairingEngine> {
+    /// The powers `G^(s^i)` for `i` in `0..=capacity`.
+    powers_of_s_g: Vec<E::G1Affine>,
+    /// The generator `H` of G2.
+    h: E::G2Affine,
+    /// The element `H^s` of G2.
+    s_h: E::G2Affine,
+}
+
+impl<E: PairingEngine> AccumulatorParameters<E> {
+    ///
+    /// Returns the parameters for sets of up to `capacity` elements, from a fresh trapdoor.
+    ///
+    pub fn setup<R: Rng + CryptoRng>(capacity: usize, rng: &mut R) -> Self {
+        let s = E::Fr::rand(rng);
+        let g = E::G1Affine::prime_subgroup_generator().to_projective();
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        // Compute `G^(s^i)` for `i` in `0..=capacity`.
+        let mut powers_of_s = vec![E::Fr::one()];
+        for i in 0..capacity {
+            powers_of_s.push(powers_of_s[i] * s);
+        }
+        let scalar_bits = E::Fr::size_in_bits();
+        let window_size = FixedBase::get_mul_window_size(capacity + 1);
+        let g_table = FixedBase::get_window_table(scalar_bits, window_size, g);
+        let powers_of_s_g = FixedBase::msm::<E::G1Projective>(scalar_bits, window_size, &g_table, &powers_of_s);
+
+        Self {
+            powers_of_s_g: E::G1Projective::batch_normalization_into_affine(powers_of_s_g),
+            h,
+            s_h: (h * s).to_affine(),
+        }
+    }
+
+    /// Returns the maximum number of elements in an accumulated set.
+    pub fn capacity(&self) -> usize {
+        self.powers_of_s_g.len() - 1
+    }
+
+    /// Returns `G^p(s)` for the polynomial `p` with the given coefficients, in increasing degree.
+    fn commit(&self, coefficients: &[E::Fr]) -> E::G1Affine {
+        let coefficients = coefficients.iter().map(|coefficient| coefficient.to_repr()).collect::<Vec<_>>();
+        VariableBase::msm(&self.powers_of_s_g[..coefficients.len()], &coefficients).to_affine()
+    }
+}
+
+/// A witness for an element of the scalar field, with respect to an accumulator.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Witness<E: PairingEngine> {
+    /// A witness `G^q(s)` that the element `y` is in the set, where `f(X) = q(X) * (X + y)`.
+    Membership(E::G1Affine),
+    /// A witness `(G^q(s), r)` that the element `y` is not in the set, where `f(X) = q(X) * (X + y) + r`
+    /// for a non-zero `r`.
+    NonMembership(E::G1Affine, E::Fr),
+}
+
+/// An accumulator of a set of elements of the scalar field.
+#[derive(Clone, Debug)]
+pub struct Accumulator<'a, E: PairingEngine> {
+    /// The public parameters.
+    parameters: &'a AccumulatorParameters<E>,
+    /// The coefficients of `f(X) = prod_{x in S} (X + x)`, in increasing degree.
+    coefficients: Vec<E::Fr>,
+    /// The accumulator value `G^f(s)`.
+    value: E::G1Affine,
+}
+
+impl<'a, E: PairingEngine> Accumulator<'a, E> {
+    /// Returns an accumulator of the empty set.
+    pub fn new(parameters: &'a AccumulatorParameters<E>) -> Self {
+        Self { parameters, coefficients: vec![E::Fr::one()], value: parameters.powers_of_s_g[0] }
+    }
+
+    /// Returns the accumulator value `G^f(s)`.
+    pub fn value(&self) -> E::G1Affine {
+        self.value
+    }
+
+    /// Returns the number of accumulated elements.
+    pub fn len(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// Returns `true` if no element is accumulated.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the given element is accumulated.
+    pub fn contains(&self, element: &E::Fr) -> bool {
+        self.divide(element).1.is_zero()
+    }
+
+    ///
+    /// Adds the given element to the set, and updates the accumulator value.
+    ///
+    pub fn add(&mut self, element: E::Fr) -> Result<()> {
+        ensure!(self.len() < self.parameters.capacity(), "The accumulator is at its capacity");
+        ensure!(!self.contains(&element), "The element is already accumulated");
+
+        // Multiply `f(X)` by `(X + y)`.
+        self.coefficients.insert(0, E::Fr::zero());
+        for i in 0..self.coefficients.len() - 1 {
+            let product = self.coefficients[i + 1] * element;
+            self.coefficients[i] += product;
+        }
+        self.value = self.parameters.commit(&self.coefficients);
+        Ok(())
+    }
+
+    ///
+    /// Returns the membership witness of the given element if it is accumulated,
+    /// and its non-membership witness otherwise.
+    ///
+    pub fn witness(&self, element: &E::Fr) -> Witness<E> {
+        let (quotient, remainder) = self.divide(element);
+        let witness = self.parameters.commit(&quotient);
+        match remainder.is_zero() {
+            true => Witness::Membership(witness),
+            false => Witness::NonMembership(witness, remainder),
+        }
+    }
+
+    ///
+    /// Returns `true` if the witness proves the element is in the set of the accumulator value.
+    ///
+    pub fn verify_membership(
+        parameters: &AccumulatorParameters<E>,
+        value: &E::G1Affine,
+        element: &E::Fr,
+        witness: &Witness<E>,
+    ) -> bool {
+        match witness {
+            Witness::Membership(witness) => Self::check(parameters, value, element, witness, &E::Fr::zero()),
+            Witness::NonMembership(..) => false,
+        }
+    }
+
+    ///
+    /// Returns `true` if the witness proves the element is not in the set of the accumulator value.
+    ///
+    pub fn verify_non_membership(
+        parameters: &AccumulatorParameters<E>,
+        value: &E::G1Affine,
+        element: &E::Fr,
+        witness: &Witness<E>,
+    ) -> bool {
+        match witness {
+            Witness::NonMembership(witness, remainder) if !remainder.is_zero() => {
+                Self::check(parameters, value, element, witness, remainder)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the quotient and remainder of the division of `f(X)` by `(X + y)`.
+    fn divide(&self, element: &E::Fr) -> (Vec<E::Fr>, E::Fr) {
+        let mut quotient = vec![E::Fr::zero(); self.len()];
+        let mut remainder = self.coefficients[self.len()];
+        for i in (0..self.len()).rev() {
+            quotient[i] = remainder;
+            remainder = self.coefficients[i] - remainder * element;
+        }
+        (quotient, remainder)
+    }
+
+    /// Returns `true` if `e(W, H^s * H^y) * e(G^r, H) == e(A, H)`.
+    fn check(
+        parameters: &AccumulatorParameters<E>,
+        value: &E::G1Affine,
+        element: &E::Fr,
+        witness: &E::G1Affine,
+        remainder: &E::Fr,
+    ) -> bool {
+        // Ensure the accumulator value and the witness are in the prime-order subgroup.
+        if !value.is_in_correct_subgroup_assuming_on_curve() || !witness.is_in_correct_subgroup_assuming_on_curve() {
+            return false;
+        }
+
+        // Compute e(W, H^s * H^y) * e(G^r - A, H).
+        let s_plus_y_h = (parameters.s_h.to_projective() + parameters.h * *element).to_affine();
+        let r_g_minus_a = (parameters.powers_of_s_g[0] * *remainder - value.to_projective()).to_affine();
+        let pairs = [(witness.prepare(), s_plus_y_h.prepare()), (r_g_minus_a.prepare(), parameters.h.prepare())];
+        E::product_of_pairings(pairs.iter().map(|(a, b)| (a, b))).is_one()
+    }
+}
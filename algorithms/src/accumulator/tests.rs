@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_curves::bls12_377::{Bls12_377, Fr, G1Projective};
+use snarkvm_utilities::test_crypto_rng;
+
+type TestAccumulator<'a> = Accumulator<'a, Bls12_377>;
+
+#[test]
+fn test_accumulator_membership() {
+    let rng = &mut test_crypto_rng();
+    let parameters = AccumulatorParameters::setup(16, rng);
+
+    let members = (0..10).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+    let mut accumulator = TestAccumulator::new(&parameters);
+    for member in &members {
+        accumulator.add(*member).unwrap();
+    }
+    assert_eq!(members.len(), accumulator.len());
+    let value = accumulator.value();
+
+    // Ensure every member has a valid membership witness, and no valid non-membership witness.
+    for member in &members {
+        let witness = accumulator.witness(member);
+        assert!(matches!(witness, Witness::Membership(_)));
+        assert!(TestAccumulator::verify_membership(&parameters, &value, member, &witness));
+        assert!(!TestAccumulator::verify_non_membership(&parameters, &value, member, &witness));
+
+        let w = match witness {
+            Witness::Membership(w) => w,
+            _ => unreachable!(),
+        };
+        for remainder in [Fr::zero(), Fr::one(), Fr::rand(rng)] {
+            let forged = Witness::NonMembership(w, remainder);
+            assert!(!TestAccumulator::verify_non_membership(&parameters, &value, member, &forged));
+        }
+    }
+
+    // Ensure a witness does not verify for another member.
+    let witness = accumulator.witness(&members[0]);
+    assert!(!TestAccumulator::verify_membership(&parameters, &value, &members[1], &witness));
+}
+
+#[test]
+fn test_accumulator_non_membership() {
+    let rng = &mut test_crypto_rng();
+    let parameters = AccumulatorParameters::setup(16, rng);
+
+    let mut accumulator = TestAccumulator::new(&parameters);
+    for _ in 0..10 {
+        accumulator.add(Fr::rand(rng)).unwrap();
+    }
+    let value = accumulator.value();
+
+    // Ensure every non-member has a valid non-membership witness, and no valid membership witness.
+    for _ in 0..10 {
+        let non_member = Fr::rand(rng);
+        let witness = accumulator.witness(&non_member);
+        assert!(matches!(witness, Witness::NonMembership(..)));
+        assert!(TestAccumulator::verify_non_membership(&parameters, &value, &non_member, &witness));
+        assert!(!TestAccumulator::verify_membership(&parameters, &value, &non_member, &witness));
+
+        let (w, remainder) = match witness {
+            Witness::NonMembership(w, remainder) => (w, remainder),
+            _ => unreachable!(),
+        };
+        assert!(!TestAccumulator::verify_membership(&parameters, &value, &non_member, &Witness::Membership(w)));
+        let tampered = Witness::NonMembership(w, remainder + Fr::one());
+        assert!(!TestAccumulator::verify_non_membership(&parameters, &value, &non_member, &tampered));
+    }
+
+    // Ensure every element is a non-member of the empty set.
+    let empty = TestAccumulator::new(&parameters);
+    let element = Fr::rand(rng);
+    let witness = empty.witness(&element);
+    assert!(TestAccumulator::verify_non_membership(&parameters, &empty.value(), &element, &witness));
+}
+
+#[test]
+fn test_accumulator_updates() {
+    let rng = &mut test_crypto_rng();
+    let parameters = AccumulatorParameters::setup(4, rng);
+    let mut accumulator = TestAccumulator::new(&parameters);
+
+    let element = Fr::rand(rng);
+    let witness = accumulator.witness(&element);
+    let value = accumulator.value();
+    accumulator.add(element).unwrap();
+
+    // Ensure the non-membership witness is invalid for the updated accumulator value.
+    assert!(TestAccumulator::verify_non_membership(&parameters, &value, &element, &witness));
+    assert!(!TestAccumulator::verify_non_membership(&parameters, &accumulator.value(), &element, &witness));
+
+    // Ensure duplicate elements, and elements beyond the capacity, fail.
+    assert!(accumulator.add(element).is_err());
+    for _ in 0..3 {
+        accumulator.add(Fr::rand(rng)).unwrap();
+    }
+    assert!(accumulator.add(Fr::rand(rng)).is_err());
+
+    // Ensure an accumulator value outside the prime-order subgroup fails.
+    let witness = accumulator.witness(&element);
+    let value = (accumulator.value().to_projective() + G1Projective::rand(rng)).to_affine();
+    assert!(!TestAccumulator::verify_membership(&parameters, &value, &element, &witness));
+}
@@ -22,6 +22,8 @@ use snarkvm_utilities::BitIteratorLE;
 use itertools::Itertools;
 use std::fmt::Debug;
 
+/// A BHP commitment, whose output is the x-coordinate of the resulting point.
+/// As the output is compressed, BHP commitments are not homomorphic.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BHPCommitment<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> {
     bhp_crh: BHPCRH<G, NUM_WINDOWS, WINDOW_SIZE>,
@@ -19,3 +19,6 @@ pub use bhp::*;
 
 pub mod pedersen;
 pub use pedersen::*;
+
+pub mod pedersen_equality;
+pub use pedersen_equality::*;
@@ -14,13 +14,25 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{crh::PedersenCRH, crypto_hash::hash_to_curve, CommitmentError, CommitmentScheme, CRH};
+use crate::{
+    crh::PedersenCRH,
+    crypto_hash::hash_to_curve,
+    msm::VariableBase,
+    CRHError,
+    CommitmentError,
+    CommitmentScheme,
+    HomomorphicCommitment,
+    CRH,
+};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::BitIteratorLE;
+use snarkvm_utilities::{BitIteratorLE, FromBits};
 
 use itertools::Itertools;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PedersenCommitment<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> {
     pub crh: PedersenCRH<G, NUM_WINDOWS, WINDOW_SIZE>,
@@ -71,3 +83,193 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> Com
         (self.crh.bases.clone(), self.random_base.clone())
     }
 }
+
+impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> HomomorphicCommitment
+    for PedersenCommitment<G, NUM_WINDOWS, WINDOW_SIZE>
+{
+    fn add(first: &Self::Output, second: &Self::Output) -> Self::Output {
+        (first.to_projective() + second.to_projective()).to_affine()
+    }
+
+    fn scalar_mul(commitment: &Self::Output, scalar: &Self::Randomness) -> Self::Output {
+        (*commitment * *scalar).to_affine()
+    }
+
+    fn add_randomness(first: &Self::Randomness, second: &Self::Randomness) -> Self::Randomness {
+        *first + second
+    }
+
+    fn scalar_mul_randomness(randomness: &Self::Randomness, scalar: &Self::Randomness) -> Self::Randomness {
+        *randomness * scalar
+    }
+
+    ///
+    /// Returns the commitments to each of the given inputs, under the corresponding randomness.
+    ///
+    /// Each window of an input is read as a little-endian integer, so that every commitment is
+    /// a single multi-scalar multiplication over the first base of each window and the random base.
+    ///
+    fn commit_many(
+        &self,
+        inputs: &[Vec<bool>],
+        randomness: &[Self::Randomness],
+    ) -> Result<Vec<Self::Output>, CommitmentError> {
+        if inputs.len() != randomness.len() {
+            return Err(CommitmentError::Message(format!(
+                "Expected randomness for {} inputs, found {}",
+                inputs.len(),
+                randomness.len()
+            )));
+        }
+
+        // If a window does not fit into a scalar, commit to each input separately.
+        if WINDOW_SIZE >= G::ScalarField::size_in_bits() {
+            return inputs.iter().zip_eq(randomness).map(|(input, randomness)| self.commit(input, randomness)).collect();
+        }
+
+        // Ensure each input size is within the parameter size.
+        if let Some(input) = inputs.iter().find(|input| input.len() > WINDOW_SIZE * NUM_WINDOWS) {
+            return Err(CRHError::IncorrectInputLength(input.len(), WINDOW_SIZE, NUM_WINDOWS).into());
+        }
+
+        // Prepare the bases, which are shared by every commitment.
+        let bases = G::batch_normalization_into_affine(
+            self.crh.bases.iter().map(|powers| powers[0]).chain([self.random_base[0]]).collect(),
+        );
+
+        let commitments = crate::cfg_iter!(inputs)
+            .zip_eq(randomness)
+            .map(|(input, randomness)| {
+                let scalars = input
+                    .chunks(WINDOW_SIZE)
+                    .map(<G::ScalarField as PrimeField>::BigInteger::from_bits_le)
+                    .chain(core::iter::repeat(Default::default()))
+                    .take(NUM_WINDOWS)
+                    .chain([randomness.to_repr()])
+                    .collect::<Vec<_>>();
+                VariableBase::msm(&bases, &scalars)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(G::batch_normalization_into_affine(commitments))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::{EdwardsProjective, Fr};
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use rand::Rng;
+
+    const NUM_WINDOWS: usize = 8;
+    const WINDOW_SIZE: usize = 32;
+    const ITERATIONS: usize = 10;
+
+    type TestCommitment = PedersenCommitment<EdwardsProjective, NUM_WINDOWS, WINDOW_SIZE>;
+
+    /// Returns a random input, with the top bit of each window unset, so that the window-wise sum does not overflow.
+    fn random_input<R: Rng>(rng: &mut R) -> Vec<bool> {
+        (0..NUM_WINDOWS * WINDOW_SIZE).map(|i| (i + 1) % WINDOW_SIZE != 0 && rng.gen()).collect()
+    }
+
+    /// Returns the window-wise sum of the given inputs.
+    fn add_inputs(first: &[bool], second: &[bool]) -> Vec<bool> {
+        let to_window = |bits: &[bool]| bits.iter().rev().fold(0u64, |acc, bit| (acc << 1) | *bit as u64);
+        first
+            .chunks(WINDOW_SIZE)
+            .map(to_window)
+            .zip_eq(second.chunks(WINDOW_SIZE).map(to_window))
+            .flat_map(|(a, b)| (0..WINDOW_SIZE).map(move |i| ((a + b) >> i) & 1 == 1))
+            .collect()
+    }
+
+    #[test]
+    fn test_homomorphic_add() {
+        let rng = &mut test_rng();
+        let pedersen = TestCommitment::setup("test_homomorphic_commitment");
+
+        for _ in 0..ITERATIONS {
+            let (first, second) = (random_input(rng), random_input(rng));
+            let (r_first, r_second) = (Fr::rand(rng), Fr::rand(rng));
+
+            let c_first = pedersen.commit(&first, &r_first).unwrap();
+            let c_second = pedersen.commit(&second, &r_second).unwrap();
+
+            // Verify the combined opening.
+            let randomness = TestCommitment::add_randomness(&r_first, &r_second);
+            let expected = pedersen.commit(&add_inputs(&first, &second), &randomness).unwrap();
+            assert_eq!(expected, TestCommitment::add(&c_first, &c_second));
+        }
+    }
+
+    #[test]
+    fn test_homomorphic_scalar_mul() {
+        let rng = &mut test_rng();
+        let pedersen = TestCommitment::setup("test_homomorphic_commitment");
+
+        for _ in 0..ITERATIONS {
+            let input = random_input(rng);
+            let randomness = Fr::rand(rng);
+            let commitment = pedersen.commit(&input, &randomness).unwrap();
+
+            // Multiplying by a small scalar is equivalent to repeated addition.
+            let three = Fr::from(3u64);
+            let expected = TestCommitment::add(&TestCommitment::add(&commitment, &commitment), &commitment);
+            assert_eq!(expected, TestCommitment::scalar_mul(&commitment, &three));
+
+            // Verify the opening of the scaled commitment, which doubles the input and the randomness.
+            let doubled = add_inputs(&input, &input);
+            let doubled_randomness = TestCommitment::scalar_mul_randomness(&randomness, &Fr::from(2u64));
+            let expected = pedersen.commit(&doubled, &doubled_randomness).unwrap();
+            assert_eq!(expected, TestCommitment::scalar_mul(&commitment, &Fr::from(2u64)));
+
+            // Multiplying by one and zero.
+            assert_eq!(commitment, TestCommitment::scalar_mul(&commitment, &Fr::one()));
+            assert!(TestCommitment::scalar_mul(&commitment, &Fr::zero()).is_zero());
+        }
+    }
+
+    #[test]
+    fn test_identity_commitment() {
+        let rng = &mut test_rng();
+        let pedersen = TestCommitment::setup("test_homomorphic_commitment");
+
+        // The commitment to the empty input with zero randomness is the identity.
+        let identity = pedersen.commit(&[], &Fr::zero()).unwrap();
+        assert!(identity.is_zero());
+
+        let input = random_input(rng);
+        let randomness = Fr::rand(rng);
+        let commitment = pedersen.commit(&input, &randomness).unwrap();
+        assert_eq!(commitment, TestCommitment::add(&commitment, &identity));
+        assert_eq!(randomness, TestCommitment::add_randomness(&randomness, &Fr::zero()));
+    }
+
+    #[test]
+    fn test_commit_many() {
+        let rng = &mut test_rng();
+        let pedersen = TestCommitment::setup("test_homomorphic_commitment");
+
+        // Include inputs that are shorter than the capacity.
+        let inputs = (0..ITERATIONS)
+            .map(|i| {
+                let mut input = random_input(rng);
+                input.truncate(i * WINDOW_SIZE / 2);
+                input
+            })
+            .collect::<Vec<_>>();
+        let randomness = (0..ITERATIONS).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        let expected = inputs.iter().zip_eq(&randomness).map(|(input, r)| pedersen.commit(input, r).unwrap());
+        assert_eq!(expected.collect::<Vec<_>>(), pedersen.commit_many(&inputs, &randomness).unwrap());
+
+        // Ensure the randomness matches the inputs.
+        assert!(pedersen.commit_many(&inputs, &randomness[1..]).is_err());
+        // Ensure the input size is within the parameter size.
+        let inputs = vec![vec![true; NUM_WINDOWS * WINDOW_SIZE + 1]];
+        assert!(pedersen.commit_many(&inputs, &randomness[..1]).is_err());
+    }
+}
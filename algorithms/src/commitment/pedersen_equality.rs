@@ -0,0 +1,199 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commitment::PedersenCommitment, crypto_hash::Transcript, CommitmentError, CommitmentScheme};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+
+/// The transcript label, which separates equality proofs from other uses of the transcript.
+const EQUALITY_LABEL: u128 = 3;
+
+///
+/// A proof that two Pedersen commitments open to the same input, which is a Schnorr proof of
+/// knowledge of `r1 - r2`, such that `c1 - c2 == H^(r1 - r2)` for the random base `H`.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PedersenEqualityProof<G: AffineCurve> {
+    /// The nonce commitment, `H^k`.
+    pub nonce_commitment: G,
+    pub response: G::ScalarField,
+}
+
+impl<G: AffineCurve> FromBytes for PedersenEqualityProof<G> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let nonce_commitment = G::read_le(&mut reader)?;
+        let response = G::ScalarField::read_le(&mut reader)?;
+
+        Ok(Self { nonce_commitment, response })
+    }
+}
+
+impl<G: AffineCurve> ToBytes for PedersenEqualityProof<G> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.nonce_commitment.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>
+    PedersenCommitment<G, NUM_WINDOWS, WINDOW_SIZE>
+{
+    ///
+    /// Returns a proof that the commitments `first` and `second` open to the same input, given their randomness.
+    ///
+    /// Returns an error if either commitment does not open to the given input under its randomness.
+    ///
+    pub fn prove_equality<R: Rng + CryptoRng>(
+        &self,
+        first: &G::Affine,
+        first_randomness: &G::ScalarField,
+        second: &G::Affine,
+        second_randomness: &G::ScalarField,
+        input: &[bool],
+        rng: &mut R,
+    ) -> Result<PedersenEqualityProof<G::Affine>, CommitmentError> {
+        // Ensure the commitments open to the input.
+        if self.commit(input, first_randomness)? != *first || self.commit(input, second_randomness)? != *second {
+            return Err(CommitmentError::Message("The commitments do not open to the given input".to_string()));
+        }
+
+        // Compute the nonce commitment H^k.
+        let nonce = G::ScalarField::rand(rng);
+        let nonce_commitment = self.random_base[0].mul_bits_constant_time(nonce.to_bits_be().into_iter()).to_affine();
+
+        // Compute the challenge and the response s := k + e * (r1 - r2).
+        let challenge = self.equality_challenge(first, second, &nonce_commitment)?;
+        let response = nonce + challenge * (*first_randomness - second_randomness);
+
+        Ok(PedersenEqualityProof { nonce_commitment, response })
+    }
+
+    ///
+    /// Returns `true` if the proof shows that the commitments `first` and `second` open to the same input,
+    /// by checking `H^s == nonce_commitment * (first - second)^e`.
+    ///
+    pub fn verify_equality(
+        &self,
+        first: &G::Affine,
+        second: &G::Affine,
+        proof: &PedersenEqualityProof<G::Affine>,
+    ) -> bool {
+        let challenge = match self.equality_challenge(first, second, &proof.nonce_commitment) {
+            Ok(challenge) => challenge,
+            Err(_) => return false,
+        };
+
+        let difference = first.to_projective() - second.to_projective();
+        self.random_base[0] * proof.response == proof.nonce_commitment.to_projective() + difference * challenge
+    }
+
+    /// Returns the challenge Hash(H, first, second, nonce_commitment).
+    fn equality_challenge(
+        &self,
+        first: &G::Affine,
+        second: &G::Affine,
+        nonce_commitment: &G::Affine,
+    ) -> Result<G::ScalarField> {
+        let mut transcript = Transcript::<G::Affine>::new();
+        transcript.absorb_field(&G::ScalarField::from(EQUALITY_LABEL));
+        for point in [&self.random_base[0].to_affine(), first, second, nonce_commitment] {
+            transcript.absorb_point(point)?;
+        }
+        Ok(transcript.challenge())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::{EdwardsProjective, Fr};
+    use snarkvm_fields::One;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type TestCommitment = PedersenCommitment<EdwardsProjective, 8, 32>;
+
+    #[test]
+    fn test_pedersen_equality() {
+        let rng = &mut test_crypto_rng();
+        let pedersen = TestCommitment::setup("test_pedersen_equality");
+
+        for _ in 0..10 {
+            let input = (0..256).map(|_| rng.gen()).collect::<Vec<bool>>();
+            let (first_randomness, second_randomness) = (Fr::rand(rng), Fr::rand(rng));
+            let first = pedersen.commit(&input, &first_randomness).unwrap();
+            let second = pedersen.commit(&input, &second_randomness).unwrap();
+
+            let proof =
+                pedersen.prove_equality(&first, &first_randomness, &second, &second_randomness, &input, rng).unwrap();
+            assert!(pedersen.verify_equality(&first, &second, &proof));
+
+            // Ensure the proof round trips through its byte representation.
+            let candidate = PedersenEqualityProof::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+            assert!(pedersen.verify_equality(&first, &second, &candidate));
+
+            // Ensure the proof is bound to the order of the commitments, and to the response.
+            assert!(!pedersen.verify_equality(&second, &first, &proof));
+            let tampered = PedersenEqualityProof { response: proof.response + Fr::one(), ..proof };
+            assert!(!pedersen.verify_equality(&first, &second, &tampered));
+        }
+    }
+
+    #[test]
+    fn test_pedersen_equality_mismatch() {
+        let rng = &mut test_crypto_rng();
+        let pedersen = TestCommitment::setup("test_pedersen_equality");
+
+        let first_input = (0..256).map(|_| rng.gen()).collect::<Vec<bool>>();
+        let mut second_input = first_input.clone();
+        second_input[0] = !second_input[0];
+
+        let (first_randomness, second_randomness) = (Fr::rand(rng), Fr::rand(rng));
+        let first = pedersen.commit(&first_input, &first_randomness).unwrap();
+        let second = pedersen.commit(&second_input, &second_randomness).unwrap();
+
+        // Ensure a proof can not be produced for commitments to different inputs.
+        for input in [&first_input, &second_input] {
+            assert!(pedersen
+                .prove_equality(&first, &first_randomness, &second, &second_randomness, input, rng)
+                .is_err());
+        }
+
+        // Ensure a proof computed from the randomness, without the input check, fails.
+        let nonce = Fr::rand(rng);
+        let nonce_commitment = (pedersen.random_base[0] * nonce).to_affine();
+        let challenge = pedersen.equality_challenge(&first, &second, &nonce_commitment).unwrap();
+        let response = nonce + challenge * (first_randomness - second_randomness);
+        assert!(!pedersen.verify_equality(&first, &second, &PedersenEqualityProof { nonce_commitment, response }));
+
+        // Ensure a proof for another pair of commitments fails.
+        let third = pedersen.commit(&first_input, &second_randomness).unwrap();
+        let proof =
+            pedersen.prove_equality(&first, &first_randomness, &third, &second_randomness, &first_input, rng).unwrap();
+        assert!(pedersen.verify_equality(&first, &third, &proof));
+        assert!(!pedersen.verify_equality(&first, &second, &proof));
+    }
+}
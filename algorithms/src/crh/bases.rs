@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::crypto_hash::hash_to_curve;
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::Zero;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
+
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::Arc,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The instances returned by `setup_cached`, by their type and setup message.
+static SETUP_CACHE: Lazy<RwLock<HashMap<(TypeId, String), Arc<dyn Any + Send + Sync>>>> = Lazy::new(Default::default);
+
+///
+/// Returns the instance of `T` for the given setup message, and otherwise runs the given setup
+/// and caches its result for the rest of the process.
+///
+pub(crate) fn setup_cached<T: Any + Clone + Send + Sync>(message: &str, setup: impl FnOnce() -> T) -> T {
+    let key = (TypeId::of::<T>(), message.to_string());
+
+    if let Some(instance) = SETUP_CACHE.read().get(&key) {
+        if let Some(instance) = instance.downcast_ref::<T>() {
+            return instance.clone();
+        }
+    }
+
+    // Run the setup without holding the lock, as it may be slow.
+    let instance = setup();
+    SETUP_CACHE.write().insert(key, Arc::new(instance.clone()));
+    instance
+}
+
+///
+/// Returns `num_generators` generators for the given domain, where generator `i` is hashed to the curve
+/// from `"{domain} at {i}"`.
+///
+/// The generators only depend on public inputs, so anyone can reproduce them, and no discrete logarithm
+/// relation between them is known. Each generator is a non-zero element of the prime-order subgroup.
+///
+pub fn derive_generators<G: AffineCurve>(domain: &str, num_generators: usize) -> Vec<G> {
+    crate::cfg_into_iter!(0..num_generators)
+        .map(|index| {
+            // Construct an indexed message to attempt to sample a generator.
+            let (generator, _, _) = hash_to_curve::<G>(&format!("{domain} at {index}"));
+            generator
+        })
+        .collect()
+}
+
+///
+/// Returns `num_windows` windows of `window_size` bases, where window `i` starts from generator `i`
+/// of `derive_generators` for the message, and each base is the previous base doubled `num_doublings` times.
+///
+/// The windows are independent, so they are derived in parallel.
+///
+pub(crate) fn derive_bases<G: ProjectiveCurve>(
+    message: &str,
+    num_windows: usize,
+    window_size: usize,
+    num_doublings: usize,
+) -> Vec<Vec<G>> {
+    crate::cfg_into_iter!(derive_generators::<G::Affine>(message, num_windows))
+        .map(|generator| {
+            let mut base = generator.to_projective();
+            let mut powers = Vec::with_capacity(window_size);
+            for _ in 0..window_size {
+                powers.push(base);
+                for _ in 0..num_doublings {
+                    base.double_in_place();
+                }
+            }
+            powers
+        })
+        .collect()
+}
+
+/// Writes the bases in affine form, window by window.
+pub(crate) fn write_bases<G: ProjectiveCurve, W: Write>(bases: &[Vec<G>], mut writer: W) -> IoResult<()> {
+    for base in G::batch_normalization_into_affine(bases.iter().flatten().copied().collect()) {
+        base.write_le(&mut writer)?;
+    }
+    Ok(())
+}
+
+/// Reads `num_windows` windows of `window_size` bases, and ensures each base is a non-zero element of the prime-order subgroup.
+pub(crate) fn read_bases<G: ProjectiveCurve, R: Read>(
+    num_windows: usize,
+    window_size: usize,
+    mut reader: R,
+) -> IoResult<Vec<Vec<G>>> {
+    let mut bases = Vec::with_capacity(num_windows);
+    for _ in 0..num_windows {
+        let mut powers = Vec::with_capacity(window_size);
+        for _ in 0..window_size {
+            let base = G::Affine::read_le(&mut reader)?;
+            if base.is_zero() || !base.is_on_curve() || !base.is_in_correct_subgroup_assuming_on_curve() {
+                return Err(error("Invalid base in the CRH parameters"));
+            }
+            powers.push(base.to_projective());
+        }
+        bases.push(powers);
+    }
+    Ok(bases)
+}
@@ -14,10 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{crypto_hash::hash_to_curve, CRHError, CRH};
+use crate::{
+    crh::{derive_bases, read_bases, setup_cached, write_bases},
+    CRHError,
+    CRH,
+};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::BigInteger;
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    BigInteger,
+    FromBytes,
+    ToBytes,
+};
 
 use std::{fmt::Debug, sync::Arc};
 
@@ -40,36 +49,38 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> CRH
     type Parameters = Arc<Vec<Vec<G>>>;
 
     fn setup(message: &str) -> Self {
-        // Calculate the maximum window size.
-        let mut maximum_window_size = 0;
-        let mut range = <G::ScalarField as PrimeField>::BigInteger::from(2_u64);
-        while range < G::ScalarField::modulus_minus_one_div_two() {
-            // range < (p-1)/2
-            range.muln(4); // range * 2^4
-            maximum_window_size += 1;
-        }
-        assert!(WINDOW_SIZE <= maximum_window_size, "The maximum BHP window size is {maximum_window_size}");
-
-        // Compute the bases.
-        let bases = (0..NUM_WINDOWS)
-            .map(|index| {
-                // Construct an indexed message to attempt to sample a base.
-                let (generator, _, _) = hash_to_curve::<G::Affine>(&format!("{message} at {index}"));
-                let mut base = generator.to_projective();
-                // Compute the generators for the sampled base.
-                let mut powers = Vec::with_capacity(WINDOW_SIZE);
-                for _ in 0..WINDOW_SIZE {
-                    powers.push(base);
-                    for _ in 0..4 {
-                        base.double_in_place();
-                    }
-                }
-                powers
-            })
-            .collect::<Vec<Vec<G>>>();
-        debug_assert_eq!(bases.len(), NUM_WINDOWS, "Incorrect number of windows ({:?}) for BHP", bases.len());
-        bases.iter().for_each(|window| debug_assert_eq!(window.len(), WINDOW_SIZE));
+        setup_cached(message, || {
+            // Calculate the maximum window size.
+            let mut maximum_window_size = 0;
+            let mut range = <G::ScalarField as PrimeField>::BigInteger::from(2_u64);
+            while range < G::ScalarField::modulus_minus_one_div_two() {
+                // range < (p-1)/2
+                range.muln(4); // range * 2^4
+                maximum_window_size += 1;
+            }
+            assert!(WINDOW_SIZE <= maximum_window_size, "The maximum BHP window size is {maximum_window_size}");
 
+            // Compute the bases, where each base is the previous base doubled 4 times.
+            let bases = derive_bases::<G>(message, NUM_WINDOWS, WINDOW_SIZE, 4);
+            debug_assert_eq!(bases.len(), NUM_WINDOWS, "Incorrect number of windows ({:?}) for BHP", bases.len());
+            bases.iter().for_each(|window| debug_assert_eq!(window.len(), WINDOW_SIZE));
+
+            Self::from_bases(bases)
+        })
+    }
+
+    fn hash(&self, input: &[bool]) -> Result<Self::Output, CRHError> {
+        Ok(self.hash_bits_inner(input)?.to_affine().to_x_coordinate())
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        &self.bases
+    }
+}
+
+impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> BHPCRH<G, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Initializes a new instance of BHP from the given bases.
+    fn from_bases(bases: Vec<Vec<G>>) -> Self {
         // Compute the base lookup.
         let base_lookup = crate::cfg_iter!(bases)
             .map(|x| {
@@ -99,16 +110,6 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> CRH
         Self { bases: Arc::new(bases), base_lookup }
     }
 
-    fn hash(&self, input: &[bool]) -> Result<Self::Output, CRHError> {
-        Ok(self.hash_bits_inner(input)?.to_affine().to_x_coordinate())
-    }
-
-    fn parameters(&self) -> &Self::Parameters {
-        &self.bases
-    }
-}
-
-impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> BHPCRH<G, NUM_WINDOWS, WINDOW_SIZE> {
     pub(crate) fn hash_bits_inner(&self, input: &[bool]) -> Result<G, CRHError> {
         // Ensure the input size is within the parameter size,
         if input.len() > NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE {
@@ -140,14 +141,136 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> BHP
     }
 }
 
+impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> BHPCRH<G, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns an incremental hasher, whose output matches `hash_bytes` on the concatenation of its updates.
+    pub fn hasher(&self) -> BHPHasher<'_, G, NUM_WINDOWS, WINDOW_SIZE> {
+        BHPHasher { crh: self, sum: G::zero(), num_bits: 0, num_chunks: 0, pending: 0, num_pending: 0 }
+    }
+}
+
+///
+/// An incremental BHP hasher, which sums the base of each chunk of the input as it is fed,
+/// so that the bits of the input are never materialized at once.
+///
+/// The input is still bounded by the parameter size, of `NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE` bits.
+///
+#[derive(Clone, Debug)]
+pub struct BHPHasher<'a, G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> {
+    crh: &'a BHPCRH<G, NUM_WINDOWS, WINDOW_SIZE>,
+    /// The sum of the bases of the complete chunks so far.
+    sum: G,
+    /// The number of bits fed so far.
+    num_bits: usize,
+    /// The number of complete chunks so far.
+    num_chunks: usize,
+    /// The bits of the incomplete chunk, from the least significant bit.
+    pending: usize,
+    num_pending: usize,
+}
+
+impl<'a, G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>
+    BHPHasher<'a, G, NUM_WINDOWS, WINDOW_SIZE>
+{
+    /// Feeds the little-endian bits of each of the given bytes to the hasher.
+    pub fn update(&mut self, input: &[u8]) -> Result<(), CRHError> {
+        // Ensure the input size is within the parameter size.
+        let num_bits = self.num_bits + input.len() * 8;
+        if num_bits > NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE {
+            return Err(CRHError::IncorrectInputLength(num_bits, WINDOW_SIZE, NUM_WINDOWS * BHP_CHUNK_SIZE));
+        }
+        self.num_bits = num_bits;
+
+        for byte in input {
+            for i in 0..8 {
+                self.pending |= (((byte >> i) & 1) as usize) << self.num_pending;
+                self.num_pending += 1;
+                if self.num_pending == BHP_CHUNK_SIZE {
+                    self.add_pending_chunk();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the hash of the input fed so far.
+    pub fn finalize(mut self) -> <G::Affine as AffineCurve>::BaseField {
+        // Pad the final chunk with zeros, as in `hash`.
+        if self.num_pending > 0 {
+            self.add_pending_chunk();
+        }
+        self.sum.to_affine().to_x_coordinate()
+    }
+
+    /// Adds the base of the pending chunk to the sum, and clears the pending chunk.
+    fn add_pending_chunk(&mut self) {
+        let index = self.num_chunks;
+        self.sum += self.crh.base_lookup[index / WINDOW_SIZE][index % WINDOW_SIZE][self.pending];
+        self.num_chunks += 1;
+        self.pending = 0;
+        self.num_pending = 0;
+    }
+}
+
+impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> FromBytes
+    for BHPCRH<G, NUM_WINDOWS, WINDOW_SIZE>
+{
+    /// Reads the bases, such as a snapshot from `write_le`, and recomputes the base lookup.
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self::from_bases(read_bases(NUM_WINDOWS, WINDOW_SIZE, reader)?))
+    }
+}
+
+impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> ToBytes
+    for BHPCRH<G, NUM_WINDOWS, WINDOW_SIZE>
+{
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        write_bases(&self.bases, writer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use snarkvm_curves::edwards_bls12::EdwardsProjective;
+    use snarkvm_utilities::test_rng;
+
+    use rand::Rng;
 
     const NUM_WINDOWS: usize = 8;
     const WINDOW_SIZE: usize = 32;
 
+    #[test]
+    fn test_bhp_hasher() {
+        let crh = <BHPCRH<EdwardsProjective, NUM_WINDOWS, WINDOW_SIZE> as CRH>::setup("test_bowe_pedersen");
+        let rng = &mut test_rng();
+
+        for num_bytes in [0, 1, 2, 3, 50, 95, 96] {
+            let input = (0..num_bytes).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let expected = crh.hash_bytes(&input).unwrap();
+
+            // Ensure the chunk boundaries do not affect the output.
+            for _ in 0..10 {
+                let mut hasher = crh.hasher();
+                let mut remaining = &input[..];
+                while !remaining.is_empty() {
+                    let (chunk, rest) = remaining.split_at(rng.gen_range(0..=remaining.len()));
+                    hasher.update(chunk).unwrap();
+                    remaining = rest;
+                }
+                assert_eq!(expected, hasher.finalize());
+            }
+        }
+
+        // Ensure the input can not exceed the parameter size.
+        let mut hasher = crh.hasher();
+        hasher.update(&[0u8; 90]).unwrap();
+        assert!(matches!(hasher.update(&[0u8; 7]), Err(CRHError::IncorrectInputLength(..))));
+        hasher.update(&[0u8; 6]).unwrap();
+        assert_eq!(crh.hash_bytes(&[0u8; 96]).unwrap(), hasher.finalize());
+    }
+
     #[test]
     fn test_bhp_sanity_check() {
         let crh = <BHPCRH<EdwardsProjective, NUM_WINDOWS, WINDOW_SIZE> as CRH>::setup("test_bowe_pedersen");
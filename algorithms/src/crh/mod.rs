@@ -17,6 +17,10 @@
 pub mod bhp;
 pub use bhp::*;
 
+mod bases;
+pub use bases::derive_generators;
+pub(crate) use bases::*;
+
 pub mod pedersen;
 pub use pedersen::*;
 
@@ -25,3 +29,6 @@ pub use pedersen_compressed::*;
 
 pub mod poseidon;
 pub use poseidon::*;
+
+#[cfg(test)]
+mod tests;
@@ -14,12 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{crypto_hash::hash_to_curve, CRHError, CRH};
-use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use crate::{
+    crh::{derive_bases, read_bases, setup_cached, write_bases},
+    CRHError,
+    CRH,
+};
+use snarkvm_curves::ProjectiveCurve;
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
 
 use itertools::Itertools;
 use std::{borrow::Cow, fmt::Debug};
 
+/// The number of bits in the length block appended by `hash_with_len_padding`.
+pub const PEDERSEN_LENGTH_BITS: usize = 64;
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PedersenCRH<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> {
     pub bases: Vec<Vec<G>>,
@@ -32,21 +44,8 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> CRH
     type Parameters = Vec<Vec<G>>;
 
     fn setup(message: &str) -> Self {
-        let bases = (0..NUM_WINDOWS)
-            .map(|index| {
-                // Construct an indexed message to attempt to sample a base.
-                let (generator, _, _) = hash_to_curve::<G::Affine>(&format!("{message} at {index}"));
-                let mut base = generator.to_projective();
-                let mut powers = Vec::with_capacity(WINDOW_SIZE);
-                for _ in 0..WINDOW_SIZE {
-                    powers.push(base);
-                    base.double_in_place();
-                }
-                powers
-            })
-            .collect();
-
-        Self { bases }
+        // Compute the bases, where each base is the previous base doubled.
+        setup_cached(message, || Self { bases: derive_bases(message, NUM_WINDOWS, WINDOW_SIZE, 1) })
     }
 
     fn hash(&self, input: &[bool]) -> Result<Self::Output, CRHError> {
@@ -76,3 +75,84 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> CRH
         &self.bases
     }
 }
+
+impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> PedersenCRH<G, NUM_WINDOWS, WINDOW_SIZE> {
+    ///
+    /// Returns the Pedersen hash of the given variable-length input.
+    ///
+    /// The input is followed by its length as a 64-bit little-endian block, and zero-filled to the
+    /// window boundary. This padding is injective for inputs of up to `NUM_WINDOWS * WINDOW_SIZE - 64` bits.
+    ///
+    pub fn hash_with_len_padding(&self, input: &[bool]) -> Result<G::Affine, CRHError> {
+        // Ensure the padded input size is within the parameter size.
+        if input.len() + PEDERSEN_LENGTH_BITS > WINDOW_SIZE * NUM_WINDOWS {
+            return Err(CRHError::IncorrectInputLength(input.len() + PEDERSEN_LENGTH_BITS, WINDOW_SIZE, NUM_WINDOWS));
+        }
+
+        // Append the length of the input, and hash the padded input.
+        let length = (0..PEDERSEN_LENGTH_BITS).map(|i| (input.len() as u64 >> i) & 1 == 1);
+        self.hash(&input.iter().copied().chain(length).collect::<Vec<_>>())
+    }
+}
+
+impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> FromBytes
+    for PedersenCRH<G, NUM_WINDOWS, WINDOW_SIZE>
+{
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self { bases: read_bases(NUM_WINDOWS, WINDOW_SIZE, reader)? })
+    }
+}
+
+impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> ToBytes
+    for PedersenCRH<G, NUM_WINDOWS, WINDOW_SIZE>
+{
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        write_bases(&self.bases, writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::EdwardsProjective;
+
+    const NUM_WINDOWS: usize = 4;
+    const WINDOW_SIZE: usize = 64;
+    const CAPACITY: usize = NUM_WINDOWS * WINDOW_SIZE;
+
+    type TestCRH = PedersenCRH<EdwardsProjective, NUM_WINDOWS, WINDOW_SIZE>;
+
+    #[test]
+    fn test_hash_with_len_padding() {
+        let crh = TestCRH::setup("test_pedersen_len_padding");
+
+        for num_bits in [0, 1, CAPACITY - 65, CAPACITY - 64] {
+            let input = (0..num_bits).map(|i| i % 3 == 0).collect::<Vec<_>>();
+
+            // The padded input is the input, followed by its length in little-endian, zero-filled to the capacity.
+            let mut padded = input.clone();
+            padded.extend((0..64).map(|i| (num_bits >> i) & 1 == 1));
+            padded.resize(CAPACITY, false);
+            assert_eq!(crh.hash(&padded).unwrap(), crh.hash_with_len_padding(&input).unwrap());
+        }
+
+        // Ensure the padded input can not exceed the capacity.
+        for num_bits in [CAPACITY - 63, CAPACITY, CAPACITY + 1] {
+            let input = vec![true; num_bits];
+            assert!(matches!(crh.hash_with_len_padding(&input), Err(CRHError::IncorrectInputLength(..))));
+        }
+    }
+
+    #[test]
+    fn test_hash_with_len_padding_is_injective() {
+        let crh = TestCRH::setup("test_pedersen_len_padding");
+
+        // Inputs that only differ by trailing zeros collide without the length block.
+        let (first, second) = ([false, false], [false, false, false]);
+        assert_eq!(crh.hash(&first).unwrap(), crh.hash(&second).unwrap());
+        assert_ne!(crh.hash_with_len_padding(&first).unwrap(), crh.hash_with_len_padding(&second).unwrap());
+        assert_ne!(crh.hash_with_len_padding(&[]).unwrap(), crh.hash_with_len_padding(&first).unwrap());
+    }
+}
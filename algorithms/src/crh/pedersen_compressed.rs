@@ -16,6 +16,11 @@
 
 use crate::{crh::PedersenCRH, CRHError, CRH};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
 
 use std::fmt::Debug;
 
@@ -43,3 +48,31 @@ impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> CRH
         &self.crh
     }
 }
+
+impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>
+    PedersenCompressedCRH<G, NUM_WINDOWS, WINDOW_SIZE>
+{
+    /// Returns the affine x-coordinate of the Pedersen hash of the given variable-length input.
+    /// See `PedersenCRH::hash_with_len_padding` for the padding of the input.
+    pub fn hash_with_len_padding(&self, input: &[bool]) -> Result<<G::Affine as AffineCurve>::BaseField, CRHError> {
+        Ok(self.crh.hash_with_len_padding(input)?.to_x_coordinate())
+    }
+}
+
+impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> FromBytes
+    for PedersenCompressedCRH<G, NUM_WINDOWS, WINDOW_SIZE>
+{
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self { crh: PedersenCRH::read_le(reader)? })
+    }
+}
+
+impl<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> ToBytes
+    for PedersenCompressedCRH<G, NUM_WINDOWS, WINDOW_SIZE>
+{
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.crh.write_le(writer)
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crh::{derive_generators, PedersenCRH, PedersenCompressedCRH, PoseidonCRH, BHPCRH},
+    crypto_hash::hash_to_curve,
+    CRH,
+};
+use snarkvm_curves::{
+    edwards_bls12::{EdwardsAffine, EdwardsProjective, Fq},
+    edwards_bw6::EdwardsProjective as EdwardsBW6,
+    AffineCurve,
+    ProjectiveCurve,
+};
+use snarkvm_fields::Zero;
+use snarkvm_utilities::{FromBytes, ToBits, ToBytes};
+
+use itertools::Itertools;
+use std::sync::Arc;
+
+type TestPedersenCRH = PedersenCRH<EdwardsProjective, 8, 64>;
+type TestBHPCRH = BHPCRH<EdwardsProjective, 8, 32>;
+type TestPoseidonCRH = PoseidonCRH<Fq, 4>;
+
+/// Ensures equivalent bytes, bits, and field elements hash to the same digest.
+fn check_input_consistency<C: CRH>(crh: &C) {
+    let bytes = (0..32u8).map(|i| i.wrapping_mul(37)).collect::<Vec<_>>();
+    let bits = bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+    assert_eq!(crh.hash(&bits).unwrap(), crh.hash_bytes(&bytes).unwrap());
+
+    let elements = [Fq::from(3u128), -Fq::from(5u128)];
+    let bits = [elements[0].to_bits_le(), elements[1].to_bits_le()].concat();
+    assert_eq!(2 * 253, bits.len());
+    assert_eq!(crh.hash(&bits).unwrap(), crh.hash_field_elements(&elements).unwrap());
+}
+
+#[test]
+fn test_pedersen_crh_input_consistency() {
+    let crh = TestPedersenCRH::setup("test_crh_input_consistency");
+    check_input_consistency(&crh);
+
+    let output = crh.hash_bytes(&[1, 2, 3]).unwrap().to_x_coordinate();
+    assert_eq!("6862449634412794771924551715542176052598532148010052841846844663459464641565", output.to_string());
+    let output = crh.hash_field_elements(&[Fq::from(1u128), Fq::from(2u128)]).unwrap().to_x_coordinate();
+    assert_eq!("6569057376146520344531475223942074335735170429422669412762292271634203593772", output.to_string());
+}
+
+#[test]
+fn test_bhp_crh_input_consistency() {
+    let crh = TestBHPCRH::setup("test_crh_input_consistency");
+    check_input_consistency(&crh);
+
+    let output = crh.hash_bytes(&[1, 2, 3]).unwrap();
+    assert_eq!("3485948913651917669735090269378932198028187762815781620353104896605949141407", output.to_string());
+    let output = crh.hash_field_elements(&[Fq::from(1u128), Fq::from(2u128)]).unwrap();
+    assert_eq!("4373367861853383334880992358965622319881658242041839557795541236255626722645", output.to_string());
+}
+
+#[test]
+fn test_poseidon_crh_input_consistency() {
+    let crh = TestPoseidonCRH::setup("test_crh_input_consistency");
+    check_input_consistency(&crh);
+
+    let output = crh.hash_bytes(&[1, 2, 3]).unwrap();
+    assert_eq!("4389788748425114662254075846497345970014533558386476453808303614309699249154", output.to_string());
+    let output = crh.hash_field_elements(&[Fq::from(1u128), Fq::from(2u128)]).unwrap();
+    assert_eq!("6312488688556365735167863840966953373989116942905956506900855989479826677494", output.to_string());
+}
+
+/// Returns the bases from a serial derivation, window by window.
+fn serial_bases<G: ProjectiveCurve>(
+    message: &str,
+    num_windows: usize,
+    window_size: usize,
+    num_doublings: usize,
+) -> Vec<Vec<G>> {
+    let mut bases = Vec::with_capacity(num_windows);
+    for index in 0..num_windows {
+        let (generator, _, _) = hash_to_curve::<G::Affine>(&format!("{message} at {index}"));
+        let mut base = generator.to_projective();
+        let mut powers = Vec::with_capacity(window_size);
+        for _ in 0..window_size {
+            powers.push(base);
+            for _ in 0..num_doublings {
+                base.double_in_place();
+            }
+        }
+        bases.push(powers);
+    }
+    bases
+}
+
+/// Ensures the BHP bases match the serial derivation, are cached, and round trip through bytes.
+fn check_bhp_bases<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>(message: &str) {
+    let crh = BHPCRH::<G, NUM_WINDOWS, WINDOW_SIZE>::setup(message);
+    assert_eq!(serial_bases::<G>(message, NUM_WINDOWS, WINDOW_SIZE, 4), *crh.bases);
+
+    // Ensure a repeated setup returns the cached bases.
+    assert!(Arc::ptr_eq(&crh.bases, &BHPCRH::<G, NUM_WINDOWS, WINDOW_SIZE>::setup(message).bases));
+
+    let bytes = crh.to_bytes_le().unwrap();
+    assert_eq!(crh, BHPCRH::read_le(&bytes[..]).unwrap());
+}
+
+/// Ensures the Pedersen bases match the serial derivation, and round trip through bytes.
+fn check_pedersen_bases<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>(message: &str) {
+    let crh = PedersenCRH::<G, NUM_WINDOWS, WINDOW_SIZE>::setup(message);
+    assert_eq!(serial_bases::<G>(message, NUM_WINDOWS, WINDOW_SIZE, 1), crh.bases);
+    assert_eq!(crh, PedersenCRH::setup(message));
+
+    let bytes = crh.to_bytes_le().unwrap();
+    assert_eq!(crh, PedersenCRH::read_le(&bytes[..]).unwrap());
+
+    let compressed = PedersenCompressedCRH::<G, NUM_WINDOWS, WINDOW_SIZE>::setup(message);
+    assert_eq!(bytes, compressed.to_bytes_le().unwrap());
+    assert_eq!(compressed, PedersenCompressedCRH::read_le(&bytes[..]).unwrap());
+}
+
+#[test]
+fn test_bhp_bases() {
+    // The instantiations of BHP in the networks.
+    check_bhp_bases::<EdwardsProjective, 3, 57>("AleoBlockHashCRH0");
+    check_bhp_bases::<EdwardsProjective, 41, 63>("AleoCommitmentScheme0");
+    check_bhp_bases::<EdwardsProjective, 2, 43>("AleoLedgerRootCRH0");
+    check_bhp_bases::<EdwardsProjective, 8, 16>("AleoProgramIDCRH0");
+    check_bhp_bases::<EdwardsProjective, 8, 32>("AleoProgramIDCRH0");
+    check_bhp_bases::<EdwardsBW6, 31, 63>("AleoInputCircuitIDCRH0");
+    check_bhp_bases::<EdwardsBW6, 27, 63>("AleoOutputCircuitIDCRH0");
+}
+
+#[test]
+fn test_pedersen_bases() {
+    // The instantiations of Pedersen in the networks.
+    check_pedersen_bases::<EdwardsProjective, 8, 36>("AleoBlockHeaderRootCRH0");
+    check_pedersen_bases::<EdwardsProjective, 4, 128>("AleoBlockHeaderRootCRH0");
+    check_pedersen_bases::<EdwardsProjective, 4, 32>("AleoValueCommitment0");
+}
+
+#[test]
+fn test_read_invalid_bases() {
+    let crh = TestPedersenCRH::setup("test_read_invalid_bases");
+    let bytes = crh.to_bytes_le().unwrap();
+
+    // Ensure truncated bases fail.
+    assert!(TestPedersenCRH::read_le(&bytes[..bytes.len() - 1]).is_err());
+
+    // Ensure a zero base fails.
+    let mut bytes = bytes;
+    let zero = <EdwardsProjective as ProjectiveCurve>::Affine::zero().to_bytes_le().unwrap();
+    bytes[..zero.len()].copy_from_slice(&zero);
+    assert!(TestPedersenCRH::read_le(&bytes[..]).is_err());
+}
+
+#[test]
+fn test_derive_generators() {
+    // The x-coordinates of the first generators, which are reproducible from the domain alone.
+    let expected = [
+        "7647625547402169559993407356891080114701049716417282454813851102121185000632",
+        "3814310613904308194993409057720981632805048322757742557843565388685745321615",
+        "8243962720406153568621474756780942728907254223328670411326471349198558110519",
+        "5842659314263949444887506205488389267768572069935723896133691943891703202676",
+    ];
+
+    let generators = derive_generators::<EdwardsAffine>("AleoBlockHeaderRootCRH0", expected.len());
+    for (generator, expected) in generators.iter().zip_eq(expected) {
+        assert_eq!(expected, generator.to_x_coordinate().to_string());
+        assert!(!generator.is_zero());
+        assert!(generator.is_on_curve() && generator.is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    // Ensure the generators are a prefix of a longer derivation, and start the windows of the CRH bases.
+    assert_eq!(generators, derive_generators::<EdwardsAffine>("AleoBlockHeaderRootCRH0", 8)[..expected.len()]);
+    let crh = PedersenCRH::<EdwardsProjective, 4, 32>::setup("AleoBlockHeaderRootCRH0");
+    for (generator, powers) in generators.iter().zip_eq(&crh.bases) {
+        assert_eq!(generator.to_projective(), powers[0]);
+    }
+
+    // Ensure another domain derives other generators.
+    assert_ne!(generators, derive_generators::<EdwardsAffine>("AleoBlockHeaderRootCRH1", expected.len()));
+}
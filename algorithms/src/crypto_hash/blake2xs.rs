@@ -92,9 +92,27 @@ impl Blake2Xs {
     }
 }
 
+/// An extendable-output function from BLAKE2Xs, for outputs of arbitrary length under an 8-byte personalization.
+pub struct AleoXof;
+
+impl AleoXof {
+    /// The largest output length, as BLAKE2Xs reserves a digest length of `u16::MAX` for outputs of unknown length.
+    pub const MAX_OUTPUT_LENGTH: usize = u16::MAX as usize - 1;
+
+    /// Returns `output_len` bytes of output for the given input and personalization.
+    ///
+    /// Panics if `output_len` is zero or exceeds `MAX_OUTPUT_LENGTH`.
+    pub fn evaluate(input: &[u8], output_len: usize, personalization: &[u8; 8]) -> Vec<u8> {
+        assert!(output_len > 0, "The output length must be non-zero");
+        assert!(output_len <= Self::MAX_OUTPUT_LENGTH, "The output length may be at most {}", Self::MAX_OUTPUT_LENGTH);
+
+        Blake2Xs::evaluate(input, output_len as u16, personalization)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::crypto_hash::Blake2Xs;
+    use crate::crypto_hash::{AleoXof, Blake2Xs};
     use serde::Deserialize;
 
     #[derive(Deserialize)]
@@ -130,6 +148,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_aleo_xof() {
+        // Run test vector cases for the given output lengths.
+        let vectors: Vec<Case> = serde_json::from_str(include_str!("./resources/blake2-kat.json")).unwrap();
+        for output_len in [1, 32, 48, 96, 200] {
+            let case = vectors
+                .iter()
+                .find(|v| &v.hash == "blake2xs" && v.key.is_empty() && v.output.len() == 2 * output_len)
+                .unwrap();
+            let input = hex::decode(case.input.as_bytes()).unwrap();
+            let output = AleoXof::evaluate(&input, output_len, &[0u8; 8]);
+            assert_eq!(output_len, output.len());
+            assert_eq!(hex::encode(output), case.output);
+        }
+
+        // Ensure the personalization separates the outputs.
+        assert_ne!(AleoXof::evaluate(b"input", 64, b"AleoHtC0"), AleoXof::evaluate(b"input", 64, b"AleoHtC1"));
+    }
+
     #[test]
     fn test_blake2s() {
         // Run test vector cases for blake2s as a sanity check for the underlying impl.
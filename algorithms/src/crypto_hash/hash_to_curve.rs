@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::crypto_hash::Blake2Xs;
+use crate::crypto_hash::AleoXof;
 use snarkvm_curves::AffineCurve;
 
 /// Runs hash-to-curve and returns the generator, message, and counter on success.
@@ -41,7 +41,7 @@ pub fn try_hash_to_curve<G: AffineCurve>(input: &str) -> Option<G> {
     debug_assert!(G::SERIALIZED_SIZE > 0);
 
     // Compute the digest for sampling the generator.
-    let digest = Blake2Xs::evaluate(input.as_bytes(), G::SERIALIZED_SIZE as u16, "AleoHtC0".as_bytes());
+    let digest = AleoXof::evaluate(input.as_bytes(), G::SERIALIZED_SIZE, b"AleoHtC0");
     debug_assert!(digest.len() == G::SERIALIZED_SIZE);
 
     // Attempt to use the digest to derive a generator.
@@ -244,3 +244,58 @@ mod bls12_377 {
         );
     }
 }
+
+#[cfg(test)]
+mod edwards_bls12 {
+    use crate::crypto_hash::hash_to_curve::hash_to_curve;
+    use snarkvm_curves::edwards_bls12::EdwardsAffine;
+    use snarkvm_utilities::ToBytes;
+
+    /// The messages used to derive the generators and bases of the Testnet2 schemes.
+    const MESSAGES: [&str; 13] = [
+        "AleoAccountEncryptionAndSignatureScheme0",
+        "AleoBlockHashCRH0",
+        "AleoBlockHeaderRootCRH0",
+        "AleoCommitmentScheme0",
+        "AleoFunctionIDCRH0",
+        "AleoLedgerRootCRH0",
+        "AleoProgramIDCRH0",
+        "AleoTransactionsRootCRH0",
+        "AleoTransactionIDCRH0",
+        "AleoTransitionIDCRH0",
+        "AleoValueCommitment0",
+        "AleoInputCircuitIDCRH0",
+        "AleoOutputCircuitIDCRH0",
+    ];
+
+    #[test]
+    fn hash_edwards_bls12_regression() {
+        // Derive the generator for each message, and the bases as in the Pedersen and BHP setups.
+        let mut generators = Vec::new();
+        for message in MESSAGES {
+            generators.push(hash_to_curve::<EdwardsAffine>(message));
+            generators.push(hash_to_curve::<EdwardsAffine>(&format!("{message} for random base")));
+            for index in 0..64 {
+                generators.push(hash_to_curve::<EdwardsAffine>(&format!("{message} at {index}")));
+            }
+        }
+
+        // Pin the first generator, and a digest of every generator and its counter.
+        let (generator, _, counter) = &generators[0];
+        assert_eq!(
+            generator.x.to_string(),
+            "522678458525321116977504528531602186870683848189190546523208313015552693483"
+        );
+        assert_eq!(1, *counter);
+
+        let mut bytes = Vec::new();
+        for (generator, _, counter) in &generators {
+            generator.write_le(&mut bytes).unwrap();
+            (*counter as u64).write_le(&mut bytes).unwrap();
+        }
+        assert_eq!(
+            hex::encode(blake2s_simd::blake2s(&bytes).as_bytes()),
+            "34d87d334775da552f8d4846268430c6c1a17fcb647d4e903c959ffc6bdc3e9d"
+        );
+    }
+}
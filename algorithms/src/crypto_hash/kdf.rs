@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::AffineCurve;
+
+/// The personalization of the keyed BLAKE2s, which separates the KDF from other uses of BLAKE2s.
+const KDF_PERSONALIZATION: &[u8; 8] = b"AleoKDF0";
+
+/// The length of a BLAKE2s digest, in bytes.
+const KDF_DIGEST_LENGTH: usize = 32;
+
+/// The largest output length of the KDF, in bytes.
+pub const MAX_KDF_LENGTH: usize = 255 * KDF_DIGEST_LENGTH;
+
+///
+/// Returns a symmetric key of `length` bytes, derived from the shared group element of a Diffie-Hellman
+/// exchange, and bound to the given context `info`.
+///
+/// This is HKDF (RFC 5869) with keyed BLAKE2s in place of HMAC, where the input keying material is
+/// the serialization of the shared element:
+///
+/// ```ignore
+///     PRK := BLAKE2s_key("AleoKDF0 extract", shared)
+///     T(i) := BLAKE2s_PRK(T(i - 1) || info || i)
+///     key := the first `length` bytes of T(1) || T(2) || ...
+/// ```
+///
+/// Panics if `length` exceeds `MAX_KDF_LENGTH`.
+///
+pub fn kdf<G: AffineCurve>(shared: &G, info: &[u8], length: usize) -> Vec<u8> {
+    assert!(length <= MAX_KDF_LENGTH, "The KDF output length may be at most {MAX_KDF_LENGTH}");
+
+    // Extract a pseudorandom key from the shared element.
+    let shared = shared.to_bytes_le().expect("Failed to serialize the shared element");
+    let prk = keyed_blake2s(b"AleoKDF0 extract").hash(&shared);
+
+    // Expand the pseudorandom key into the output.
+    let mut output = Vec::with_capacity(length);
+    let mut block = Vec::new();
+    for counter in 1..=((length + KDF_DIGEST_LENGTH - 1) / KDF_DIGEST_LENGTH) as u8 {
+        let mut state = keyed_blake2s(prk.as_bytes()).to_state();
+        state.update(&block).update(info).update(&[counter]);
+        block = state.finalize().as_bytes().to_vec();
+        output.extend_from_slice(&block);
+    }
+    output.truncate(length);
+    output
+}
+
+/// Returns the parameters of BLAKE2s, keyed with the given key, under the KDF personalization.
+fn keyed_blake2s(key: &[u8]) -> blake2s_simd::Params {
+    let mut params = blake2s_simd::Params::new();
+    params.hash_length(KDF_DIGEST_LENGTH).key(key).personal(KDF_PERSONALIZATION);
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::{
+        bls12_377::{G1Affine, G1Projective},
+        ProjectiveCurve,
+    };
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    #[test]
+    fn test_kdf_determinism() {
+        let rng = &mut test_rng();
+
+        for _ in 0..10 {
+            let shared = G1Projective::rand(rng).to_affine();
+            for length in [0, 1, 31, 32, 33, 100, MAX_KDF_LENGTH] {
+                let key = kdf(&shared, b"context", length);
+                assert_eq!(length, key.len());
+                assert_eq!(key, kdf(&shared, b"context", length));
+
+                // Ensure a shorter key is a prefix of a longer key.
+                assert_eq!(&kdf(&shared, b"context", MAX_KDF_LENGTH)[..length], &key[..]);
+            }
+        }
+
+        // The expected key is computed independently from the RFC 5869 construction with keyed BLAKE2s.
+        let key = kdf(&G1Affine::prime_subgroup_generator(), b"AleoKDF0 test vector", 48);
+        assert_eq!(
+            "eb23f6f2ec4ad8fb1e9c73d86170d5138ca34323002d6a6a27de7143a8013aa30585ae448aaa6455aa0d7d9b21598462",
+            hex::encode(key)
+        );
+    }
+
+    #[test]
+    fn test_kdf_info_separation() {
+        let rng = &mut test_rng();
+        let shared = G1Projective::rand(rng).to_affine();
+        let other = G1Projective::rand(rng).to_affine();
+
+        // Ensure different contexts and different shared elements yield different keys.
+        let key = kdf(&shared, b"encryption", 32);
+        for info in [&b""[..], b"authentication", b"encryption\0", b"Encryption"] {
+            assert_ne!(key, kdf(&shared, info, 32));
+        }
+        assert_ne!(key, kdf(&other, b"encryption", 32));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kdf_max_length() {
+        kdf(&G1Affine::prime_subgroup_generator(), b"", MAX_KDF_LENGTH + 1);
+    }
+}
@@ -20,11 +20,17 @@ pub use blake2xs::*;
 pub mod hash_to_curve;
 pub use hash_to_curve::*;
 
+pub mod kdf;
+pub use kdf::*;
+
 pub mod poseidon;
 pub use poseidon::*;
 
 pub mod sha256;
 pub use sha256::*;
 
+pub mod transcript;
+pub use transcript::*;
+
 #[cfg(test)]
 pub mod tests;
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{AlgebraicSponge, DefaultCapacityAlgebraicSponge, DuplexSpongeMode};
+use snarkvm_curves::AffineCurve;
 use snarkvm_fields::{PoseidonParameters, PrimeField};
 
 use smallvec::SmallVec;
@@ -63,6 +64,9 @@ impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> IndexMut<usize> fo
     }
 }
 
+/// The maximum number of attempts to hash to a group element, matching `hash_to_curve`.
+pub const MAX_HASH_TO_GROUP_ATTEMPTS: usize = 128;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Poseidon<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool> {
     parameters: Arc<PoseidonParameters<F, RATE, 1>>,
@@ -93,6 +97,28 @@ impl<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool> Poseid
         self.evaluate(&[vec![F::from(input.len() as u128)], input.to_vec()].concat())
     }
 
+    /// Runs hash-to-group on a list of field elements as input, and returns the group element
+    /// and the attempt on success.
+    ///
+    /// The input is hashed to a digest, and for each attempt `i`, the hash of `(digest, i)` is used
+    /// as a candidate x-coordinate, with the greatest y-coordinate. The output is the first candidate
+    /// that is on the curve, and is non-zero once the cofactor is cleared.
+    pub fn hash_to_group<G: AffineCurve<BaseField = F>>(&self, input: &[F]) -> (G, usize) {
+        let digest = self.evaluate(input);
+        for attempt in 0..MAX_HASH_TO_GROUP_ATTEMPTS {
+            let x = self.evaluate(&[digest, F::from(attempt as u128)]);
+            if let Some(point) = G::from_x_coordinate(x, true) {
+                let point = point.mul_by_cofactor();
+                if !point.is_zero() {
+                    return (point, attempt);
+                }
+            }
+        }
+
+        // Panic with probability 2^-128.
+        panic!("Unable to hash to group on the given input")
+    }
+
     pub fn parameters(&self) -> &Arc<PoseidonParameters<F, RATE, 1>> {
         &self.parameters
     }
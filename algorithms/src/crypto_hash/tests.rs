@@ -14,9 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{crypto_hash::PoseidonSponge, AlgebraicSponge, DuplexSpongeMode};
-use snarkvm_curves::bls12_377::Fr;
-use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR};
+use crate::{
+    crh::PoseidonCRH,
+    crypto_hash::{Poseidon, PoseidonSponge, Transcript, MAX_HASH_TO_GROUP_ATTEMPTS},
+    AlgebraicSponge,
+    DuplexSpongeMode,
+    CRH,
+};
+use snarkvm_curves::{
+    bls12_377::{Fq, Fr, G1Affine, G1Projective},
+    edwards_bls12::EdwardsAffine,
+    AffineCurve,
+    ProjectiveCurve,
+};
+use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR, Zero};
+use snarkvm_utilities::{test_rng, UniformRand};
 
 use itertools::Itertools;
 use std::{path::PathBuf, sync::Arc};
@@ -69,6 +81,125 @@ fn test_poseidon_sponge_consistency() {
     }
 }
 
+#[test]
+fn test_poseidon_evaluate_consistency() {
+    let poseidon = Poseidon::<Fr, 2, false>::setup();
+    for num_inputs in 0..8 {
+        let input = (0..num_inputs).map(|i| Fr::from(1237812u64 + i as u64)).collect::<Vec<_>>();
+        expect_file_with_name(format!("Evaluate {}", num_inputs), poseidon.evaluate(&input));
+        expect_file_with_name(format!("Evaluate many {}", num_inputs), poseidon.evaluate_many(&input, 3));
+    }
+}
+
+#[test]
+fn test_poseidon_crh_consistency() {
+    let crh = PoseidonCRH::<Fr, 2>::setup("PoseidonCRH");
+    let input = (0..300).map(|i| i % 3 == 0).collect::<Vec<_>>();
+    expect_file_with_name("CRH", crh.hash(&input).unwrap());
+}
+
+#[test]
+fn test_poseidon_hash_to_group() {
+    let rng = &mut test_rng();
+    let poseidon = Poseidon::<Fr, 4, false>::setup();
+
+    for num_inputs in 0..5 {
+        let input = (0..num_inputs).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let (point, attempt) = poseidon.hash_to_group::<EdwardsAffine>(&input);
+        assert!(attempt < MAX_HASH_TO_GROUP_ATTEMPTS);
+        assert!(!point.is_zero());
+        assert!(point.is_on_curve());
+        assert!(point.is_in_correct_subgroup_assuming_on_curve());
+
+        // The output is deterministic.
+        assert_eq!((point, attempt), poseidon.hash_to_group::<EdwardsAffine>(&input));
+    }
+}
+
+#[test]
+fn test_poseidon_sponge_squeeze_nonnative_consistency() {
+    let sponge_param = Arc::new(Fq::default_poseidon_parameters::<6>(false).unwrap());
+    let mut sponge = PoseidonSponge::<Fq, 6, 1>::new(&sponge_param);
+    sponge.absorb(&[Fq::from(1237812u64)]);
+    expect_file_with_name("Squeeze bits", sponge.squeeze_bits(300));
+    expect_file_with_name("Squeeze nonnative", sponge.squeeze_nonnative_field_elements::<Fr>(3));
+    expect_file_with_name("Squeeze short nonnative", sponge.squeeze_short_nonnative_field_elements::<Fr>(3));
+}
+
+#[test]
+fn test_poseidon_sponge_chunked_absorb() {
+    const RATE: usize = 2;
+    let sponge_param = Arc::new(Fr::default_poseidon_parameters::<RATE>(false).unwrap());
+    let rng = &mut test_rng();
+
+    for num_inputs in 0..10 {
+        let input = (0..num_inputs).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        let mut expected = PoseidonSponge::<Fr, RATE, 1>::new(&sponge_param);
+        expected.absorb(&input);
+        let expected = expected.squeeze(5);
+
+        // Absorbing the input in chunks of any size is equivalent to absorbing it at once.
+        for chunk_size in 1..=num_inputs {
+            let mut sponge = PoseidonSponge::<Fr, RATE, 1>::new(&sponge_param);
+            for chunk in input.chunks(chunk_size) {
+                sponge.absorb(chunk);
+            }
+            assert_eq!(expected, sponge.squeeze(5), "chunk size {}", chunk_size);
+        }
+
+        // Squeezing the output in chunks is equivalent to squeezing it at once.
+        let mut sponge = PoseidonSponge::<Fr, RATE, 1>::new(&sponge_param);
+        sponge.absorb(&input);
+        let candidate = (0..5).flat_map(|_| sponge.squeeze(1)).collect::<Vec<_>>();
+        assert_eq!(expected.to_vec(), candidate);
+    }
+}
+
+#[test]
+fn test_poseidon_sponge_empty_absorb() {
+    const RATE: usize = 2;
+    let sponge_param = Arc::new(Fr::default_poseidon_parameters::<RATE>(false).unwrap());
+
+    let mut expected = PoseidonSponge::<Fr, RATE, 1>::new(&sponge_param);
+    expected.absorb(&[Fr::from(1u64)]);
+    let first = expected.squeeze(1);
+    let second = expected.squeeze(3);
+
+    // An empty absorption leaves the sponge unchanged, in both the absorbing and squeezing modes.
+    let mut sponge = PoseidonSponge::<Fr, RATE, 1>::new(&sponge_param);
+    sponge.absorb(&[]);
+    assert_eq!(sponge.mode, DuplexSpongeMode::Absorbing { next_absorb_index: 0 });
+    sponge.absorb(&[Fr::from(1u64)]);
+    sponge.absorb(&[]);
+    assert_eq!(first, sponge.squeeze(1));
+    sponge.absorb(&[]);
+    assert_eq!(sponge.mode, DuplexSpongeMode::Squeezing { next_squeeze_index: 1 });
+    assert_eq!(second, sponge.squeeze(3));
+}
+
+#[test]
+fn test_poseidon_sponge_interleaved() {
+    const RATE: usize = 2;
+    let sponge_param = Arc::new(Fr::default_poseidon_parameters::<RATE>(false).unwrap());
+
+    let mut sponge = PoseidonSponge::<Fr, RATE, 1>::new(&sponge_param);
+    for (i, (absorb, squeeze)) in [(1, 1), (3, 2), (0, 3), (2, 0), (4, 5)].into_iter().enumerate() {
+        sponge.absorb(&vec![Fr::from(1237812u64 + i as u64); absorb]);
+        let iteration_name = format!("Interleaved {} absorb {} and squeeze {}", i, absorb, squeeze);
+        expect_file_with_name(&iteration_name, sponge.squeeze(squeeze));
+    }
+
+    // Squeezing after an absorption permutes the absorbed elements, so the outputs differ.
+    let mut first = PoseidonSponge::<Fr, RATE, 1>::new(&sponge_param);
+    first.absorb(&[Fr::from(1u64)]);
+    let mut second = first.clone();
+    first.squeeze(1);
+    first.absorb(&[Fr::from(2u64)]);
+    second.absorb(&[Fr::from(2u64)]);
+    assert_ne!(first.squeeze(1), second.squeeze(1));
+}
+
 #[test]
 fn bls12_377_fr_poseidon_default_parameters_test() {
     fn single_rate_test<const RATE: usize>(optimize_for_weights: bool) {
@@ -94,3 +225,131 @@ fn bls12_377_fr_poseidon_default_parameters_test() {
     single_rate_test::<7>(true);
     single_rate_test::<8>(true);
 }
+
+#[test]
+fn test_transcript_determinism() {
+    let rng = &mut test_rng();
+
+    for _ in 0..10 {
+        let points = (0..3).map(|_| G1Projective::rand(rng).to_affine()).collect::<Vec<_>>();
+        let scalars = (0..3).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        // Absorb the same sequence into two transcripts.
+        let mut first = Transcript::<G1Affine>::new();
+        let mut second = Transcript::<G1Affine>::new();
+        for transcript in [&mut first, &mut second] {
+            for (point, scalar) in points.iter().zip_eq(&scalars) {
+                transcript.absorb_point(point).unwrap();
+                transcript.absorb_field(scalar);
+            }
+        }
+
+        // Ensure the transcripts produce the same challenges.
+        for _ in 0..3 {
+            assert_eq!(first.challenge(), second.challenge());
+        }
+    }
+}
+
+#[test]
+fn test_transcript_order_sensitivity() {
+    let rng = &mut test_rng();
+
+    for _ in 0..10 {
+        let (a, b) = (G1Projective::rand(rng).to_affine(), G1Projective::rand(rng).to_affine());
+        let scalar = Fr::rand(rng);
+
+        let challenge = |absorb: &dyn Fn(&mut Transcript<G1Affine>)| {
+            let mut transcript = Transcript::new();
+            absorb(&mut transcript);
+            transcript.challenge()
+        };
+
+        // Ensure swapping the points changes the challenge.
+        let expected = challenge(&|t| {
+            t.absorb_point(&a).unwrap();
+            t.absorb_point(&b).unwrap();
+        });
+        let candidate = challenge(&|t| {
+            t.absorb_point(&b).unwrap();
+            t.absorb_point(&a).unwrap();
+        });
+        assert_ne!(expected, candidate);
+
+        // Ensure interleaving a scalar at different positions changes the challenge.
+        let expected = challenge(&|t| {
+            t.absorb_field(&scalar);
+            t.absorb_point(&a).unwrap();
+        });
+        let candidate = challenge(&|t| {
+            t.absorb_point(&a).unwrap();
+            t.absorb_field(&scalar);
+        });
+        assert_ne!(expected, candidate);
+
+        // Ensure a challenge changes the subsequent challenges.
+        let mut first = Transcript::<G1Affine>::new();
+        let mut second = Transcript::<G1Affine>::new();
+        first.absorb_field(&scalar);
+        second.absorb_field(&scalar);
+        first.challenge();
+        assert_ne!(first.challenge(), second.challenge());
+    }
+}
+
+#[test]
+fn test_transcript_fork() {
+    let rng = &mut test_rng();
+
+    for _ in 0..10 {
+        let point = G1Projective::rand(rng).to_affine();
+        let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+
+        let mut parent = Transcript::<G1Affine>::new();
+        parent.absorb_point(&point).unwrap();
+        parent.absorb_field(&a);
+
+        // Ensure the fork starts from the same state as the parent.
+        assert_eq!(parent.fork().challenge(), parent.clone().challenge());
+
+        // Ensure absorptions into the fork do not affect the parent.
+        let expected = parent.clone().challenge();
+        let mut fork = parent.fork();
+        fork.absorb_field(&b);
+        assert_eq!(expected, parent.clone().challenge());
+
+        // Ensure merging the fork changes the parent.
+        parent.append_fork(fork);
+        assert_ne!(expected, parent.clone().challenge());
+    }
+}
+
+#[test]
+fn test_transcript_append_fork_order() {
+    let rng = &mut test_rng();
+
+    for _ in 0..10 {
+        let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+
+        let mut parent = Transcript::<G1Affine>::new();
+        parent.absorb_field(&a);
+
+        let mut first = parent.fork();
+        first.absorb_field(&a);
+        let mut second = parent.fork();
+        second.absorb_field(&b);
+
+        let merge = |forks: [&Transcript<G1Affine>; 2]| {
+            let mut transcript = parent.clone();
+            for fork in forks {
+                transcript.append_fork(fork.clone());
+            }
+            transcript.challenge()
+        };
+
+        // Ensure merging the same forks in the same order is deterministic.
+        assert_eq!(merge([&first, &second]), merge([&first, &second]));
+        // Ensure merging the forks in a different order changes the challenge.
+        assert_ne!(merge([&first, &second]), merge([&second, &first]));
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::PoseidonSponge,
+    traits::{AlgebraicSponge, DefaultCapacityAlgebraicSponge},
+};
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::{Field, One, ToConstraintField, Zero};
+use snarkvm_utilities::ToBits;
+
+use anyhow::Result;
+use core::marker::PhantomData;
+
+/// The rate of the Poseidon sponge used by the transcript.
+const TRANSCRIPT_RATE: usize = 4;
+
+///
+/// A Fiat-Shamir transcript over the Poseidon sponge, which absorbs curve points and scalars,
+/// and squeezes challenges in the scalar field of the curve.
+///
+/// Each absorbed value is preceded by a tag for its type, so that the challenge depends on
+/// both the values and their order, and a point is never absorbed as the same elements as scalars.
+///
+#[derive(Clone, Debug)]
+pub struct Transcript<G: AffineCurve> {
+    /// The Poseidon sponge.
+    sponge: PoseidonSponge<G::ScalarField, TRANSCRIPT_RATE, 1>,
+    _curve: PhantomData<G>,
+}
+
+impl<G: AffineCurve> Transcript<G> {
+    /// Initializes a new transcript.
+    pub fn new() -> Self {
+        let parameters = PoseidonSponge::<G::ScalarField, TRANSCRIPT_RATE, 1>::sample_parameters();
+        Self { sponge: PoseidonSponge::new(&parameters), _curve: PhantomData }
+    }
+
+    /// Absorbs the given curve point, as the packed bits of its serialization.
+    pub fn absorb_point(&mut self, point: &G) -> Result<()> {
+        let elements: Vec<G::ScalarField> = point.to_bytes_le()?.to_bits_le().to_field_elements()?;
+        self.sponge.absorb(&[G::ScalarField::one()]);
+        self.sponge.absorb(&elements);
+        Ok(())
+    }
+
+    /// Absorbs the given scalar field element.
+    pub fn absorb_field(&mut self, element: &G::ScalarField) {
+        self.sponge.absorb(&[G::ScalarField::zero(), *element]);
+    }
+
+    /// Returns a challenge, which is determined by the sequence of absorbed values and prior challenges.
+    pub fn challenge(&mut self) -> G::ScalarField {
+        self.sponge.squeeze(1)[0]
+    }
+
+    /// Returns a fork of the transcript, which starts from the current state,
+    /// and whose absorptions do not affect this transcript until it is merged with `append_fork`.
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    /// Merges the given fork into this transcript, by absorbing a digest squeezed from the fork.
+    ///
+    /// The result depends on the state of the fork and on the order in which forks are merged.
+    pub fn append_fork(&mut self, mut fork: Self) {
+        let digest = fork.challenge();
+        self.sponge.absorb(&[G::ScalarField::one().double(), digest]);
+    }
+}
+
+impl<G: AffineCurve> Default for Transcript<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -15,7 +15,9 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    crypto_hash::{hash_to_curve, Poseidon},
+    crypto_hash::{hash_to_curve, Poseidon, PoseidonSponge},
+    AlgebraicSponge,
+    DecryptionError,
     EncryptionError,
     EncryptionScheme,
 };
@@ -40,6 +42,7 @@ where
     poseidon: Poseidon<TE::BaseField, 4, false>,
     symmetric_key_commitment_domain: TE::BaseField,
     symmetric_encryption_domain: TE::BaseField,
+    symmetric_authentication_domain: TE::BaseField,
 }
 
 impl<TE: TwistedEdwardsParameters> EncryptionScheme for ECIESPoseidonEncryption<TE>
@@ -60,8 +63,15 @@ where
         let poseidon = Poseidon::<TE::BaseField, 4, false>::setup();
         let symmetric_key_commitment_domain = TE::BaseField::from_bytes_le_mod_order(b"AleoSymmetricKeyCommitment0");
         let symmetric_encryption_domain = TE::BaseField::from_bytes_le_mod_order(b"AleoSymmetricEncryption0");
-
-        Self { generator, poseidon, symmetric_key_commitment_domain, symmetric_encryption_domain }
+        let symmetric_authentication_domain = TE::BaseField::from_bytes_le_mod_order(b"AleoSymmetricAuthentication0");
+
+        Self {
+            generator,
+            poseidon,
+            symmetric_key_commitment_domain,
+            symmetric_encryption_domain,
+            symmetric_authentication_domain,
+        }
     }
 
     fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::PrivateKey {
@@ -117,28 +127,7 @@ where
         private_key: &<Self as EncryptionScheme>::PrivateKey,
         ciphertext_randomizer: Self::CiphertextRandomizer,
     ) -> Option<Self::SymmetricKey> {
-        // Recover the ciphertext randomizer group element.
-        let mut randomizer = None;
-
-        if let Some(element) = TEAffine::<TE>::from_x_coordinate(ciphertext_randomizer, true) {
-            if element.is_in_correct_subgroup_assuming_on_curve() {
-                randomizer = Some(element);
-            }
-        }
-        if randomizer.is_none() {
-            if let Some(element) = TEAffine::<TE>::from_x_coordinate(ciphertext_randomizer, false) {
-                if element.is_in_correct_subgroup_assuming_on_curve() {
-                    randomizer = Some(element);
-                }
-            }
-        }
-
-        randomizer.map(|randomizer| {
-            randomizer
-                .mul_bits(BitIteratorBE::new_without_leading_zeros(private_key.to_repr()))
-                .to_affine()
-                .to_x_coordinate()
-        })
+        self.try_generate_symmetric_key(private_key, ciphertext_randomizer).ok()
     }
 
     ///
@@ -240,9 +229,54 @@ where
     }
 
     ///
-    /// Decrypts the given ciphertext with the given symmetric key.
+    /// Encrypts the given message, and appends an authentication tag on the ciphertext:
+    ///
+    /// ```ignore
+    ///     ciphertext := to_bytes_le![C_1, ..., C_n, tag], where tag := H(G^ar, C_1, ..., C_n, n)
+    /// ```
+    ///
+    /// The randomizers and the tag are derived from the symmetric key under separate Poseidon domains,
+    /// and the output equals the chunks and tag of an `ECIESPoseidonEncryptor` with the same symmetric key.
+    ///
+    fn encrypt_authenticated(
+        &self,
+        symmetric_key: &Self::SymmetricKey,
+        message: &[Self::MessageType],
+    ) -> Vec<Self::MessageType> {
+        let mut ciphertext = self.encrypt(symmetric_key, message);
+        ciphertext.push(self.authenticate(symmetric_key, &ciphertext));
+        ciphertext
+    }
+
+    ///
+    /// Decrypts the given ciphertext from `encrypt_authenticated` with the given symmetric key,
+    /// and returns an error if the authentication tag does not match.
+    ///
+    fn decrypt_authenticated(
+        &self,
+        symmetric_key: &Self::SymmetricKey,
+        ciphertext: &[Self::MessageType],
+    ) -> Result<Vec<Self::MessageType>, DecryptionError> {
+        // Split the authentication tag from the ciphertext.
+        let (tag, ciphertext) = ciphertext.split_last().ok_or(DecryptionError::MissingMac)?;
+
+        // Check the authentication tag before decrypting.
+        match self.authenticate(symmetric_key, ciphertext) == *tag {
+            true => Ok(self.decrypt_unauthenticated(symmetric_key, ciphertext)),
+            false => Err(DecryptionError::MacMismatch),
+        }
+    }
+
+    ///
+    /// Decrypts the given ciphertext from `encrypt` with the given symmetric key.
     ///
-    fn decrypt(&self, symmetric_key: &Self::SymmetricKey, ciphertext: &[Self::MessageType]) -> Vec<Self::MessageType> {
+    /// This does not check the integrity of the ciphertext, and is intended for legacy ciphertexts.
+    ///
+    fn decrypt_unauthenticated(
+        &self,
+        symmetric_key: &Self::SymmetricKey,
+        ciphertext: &[Self::MessageType],
+    ) -> Vec<Self::MessageType> {
         // Obtain random field elements from Poseidon.
         let randomizers =
             self.poseidon.evaluate_many(&[self.symmetric_encryption_domain, *symmetric_key], ciphertext.len());
@@ -259,3 +293,70 @@ where
         Self::PrivateKey::size_in_bits()
     }
 }
+
+impl<TE: TwistedEdwardsParameters> ECIESPoseidonEncryption<TE>
+where
+    TE::BaseField: PrimeField,
+{
+    ///
+    /// Given the private key and ciphertext randomizer, returns the symmetric key as in `generate_symmetric_key`,
+    /// or an error if the ciphertext randomizer is not the x-coordinate of a point in the prime-order subgroup.
+    ///
+    /// Both points with the x-coordinate are checked, and the private key is used in full,
+    /// so that the running time does not depend on which point is in the subgroup, or on the private key.
+    ///
+    pub fn try_generate_symmetric_key(
+        &self,
+        private_key: &TE::ScalarField,
+        ciphertext_randomizer: TE::BaseField,
+    ) -> Result<TE::BaseField, EncryptionError> {
+        // Recover the ciphertext randomizer group element, which is one of the points with the x-coordinate.
+        let (greatest, least) = match (
+            TEAffine::<TE>::from_x_coordinate(ciphertext_randomizer, true),
+            TEAffine::<TE>::from_x_coordinate(ciphertext_randomizer, false),
+        ) {
+            (Some(greatest), Some(least)) => (greatest, least),
+            _ => return Err(EncryptionError::RandomizerNotOnCurve),
+        };
+        let randomizer = match (
+            greatest.is_in_correct_subgroup_assuming_on_curve(),
+            least.is_in_correct_subgroup_assuming_on_curve(),
+        ) {
+            (true, _) => greatest,
+            (false, true) => least,
+            (false, false) => return Err(EncryptionError::RandomizerNotInSubgroup),
+        };
+
+        // Multiply with the constant-time ladder, which does not branch on the bits of the private key.
+        let bits = BitIteratorBE::new(private_key.to_repr());
+        Ok(randomizer.to_projective().mul_bits_constant_time(bits).to_affine().to_x_coordinate())
+    }
+
+    ///
+    /// Returns the authentication tag on the given ciphertext, as computed by `ECIESPoseidonEncryptor::finalize`:
+    ///
+    /// ```ignore
+    ///     tag := H(G^ar, C_1, ..., C_n, n)
+    /// ```
+    ///
+    pub fn authenticate(&self, symmetric_key: &TE::BaseField, ciphertext: &[TE::BaseField]) -> TE::BaseField {
+        let mut authenticator = self.authenticator(symmetric_key);
+        authenticator.absorb(ciphertext);
+        authenticator.absorb(&[TE::BaseField::from(ciphertext.len() as u128)]);
+        authenticator.squeeze(1)[0]
+    }
+
+    /// Returns a sponge which squeezes the randomizers used in `encrypt` and `decrypt`.
+    pub(crate) fn keystream(&self, symmetric_key: &TE::BaseField) -> PoseidonSponge<TE::BaseField, 4, 1> {
+        let mut sponge = PoseidonSponge::new(self.poseidon.parameters());
+        sponge.absorb(&[self.symmetric_encryption_domain, *symmetric_key]);
+        sponge
+    }
+
+    /// Returns a sponge which absorbs the ciphertext to compute its authentication tag.
+    pub(crate) fn authenticator(&self, symmetric_key: &TE::BaseField) -> PoseidonSponge<TE::BaseField, 4, 1> {
+        let mut sponge = PoseidonSponge::new(self.poseidon.parameters());
+        sponge.absorb(&[self.symmetric_authentication_domain, *symmetric_key]);
+        sponge
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::PoseidonSponge,
+    encryption::ECIESPoseidonEncryption,
+    AlgebraicSponge,
+    EncryptionError,
+    EncryptionScheme,
+};
+use snarkvm_curves::{templates::twisted_edwards_extended::Affine as TEAffine, TwistedEdwardsParameters};
+use snarkvm_fields::PrimeField;
+
+use itertools::Itertools;
+use rand::{CryptoRng, Rng};
+
+///
+/// Encrypts a message in chunks, holding only the keystream and authentication state in memory.
+///
+/// The concatenated ciphertext chunks are equal to the output of `ECIESPoseidonEncryption::encrypt`
+/// on the concatenated message, and `finalize` returns the tag from `ECIESPoseidonEncryption::authenticate`.
+///
+#[derive(Clone, Debug)]
+pub struct ECIESPoseidonEncryptor<TE: TwistedEdwardsParameters>
+where
+    TE::BaseField: PrimeField,
+{
+    ciphertext_randomizer: TE::BaseField,
+    keystream: PoseidonSponge<TE::BaseField, 4, 1>,
+    authenticator: PoseidonSponge<TE::BaseField, 4, 1>,
+    num_elements: usize,
+}
+
+impl<TE: TwistedEdwardsParameters> ECIESPoseidonEncryptor<TE>
+where
+    TE::BaseField: PrimeField,
+{
+    /// Initializes a new encryptor to the given public key, with a freshly-sampled ciphertext randomizer.
+    pub fn new<R: Rng + CryptoRng>(
+        encryption: &ECIESPoseidonEncryption<TE>,
+        public_key: &TEAffine<TE>,
+        rng: &mut R,
+    ) -> Self {
+        let (_randomness, ciphertext_randomizer, symmetric_key) = encryption.generate_asymmetric_key(public_key, rng);
+
+        Self {
+            ciphertext_randomizer,
+            keystream: encryption.keystream(&symmetric_key),
+            authenticator: encryption.authenticator(&symmetric_key),
+            num_elements: 0,
+        }
+    }
+
+    /// Returns the ciphertext randomizer, which the recipient uses to derive the symmetric key.
+    pub fn ciphertext_randomizer(&self) -> TE::BaseField {
+        self.ciphertext_randomizer
+    }
+
+    /// Encrypts the next chunk of the message, and returns the corresponding chunk of the ciphertext.
+    pub fn encrypt_chunk(&mut self, plaintext: &[TE::BaseField]) -> Vec<TE::BaseField> {
+        // Obtain the next random field elements from Poseidon.
+        let randomizers = self.keystream.squeeze(plaintext.len());
+
+        // Add the random field elements to the plaintext elements.
+        let ciphertext =
+            plaintext.iter().zip_eq(randomizers).map(|(plaintext, randomizer)| *plaintext + randomizer).collect_vec();
+
+        // Absorb the ciphertext into the authentication tag.
+        self.authenticator.absorb(&ciphertext);
+        self.num_elements += ciphertext.len();
+
+        ciphertext
+    }
+
+    /// Returns the authentication tag on the ciphertext chunks.
+    pub fn finalize(mut self) -> TE::BaseField {
+        self.authenticator.absorb(&[TE::BaseField::from(self.num_elements as u128)]);
+        self.authenticator.squeeze(1)[0]
+    }
+}
+
+///
+/// Decrypts a ciphertext in chunks, mirroring `ECIESPoseidonEncryptor`.
+///
+/// Note that decrypted chunks are returned before the authentication tag is checked,
+/// so callers must not act on the plaintext until `finalize` succeeds.
+///
+#[derive(Clone, Debug)]
+pub struct ECIESPoseidonDecryptor<TE: TwistedEdwardsParameters>
+where
+    TE::BaseField: PrimeField,
+{
+    keystream: PoseidonSponge<TE::BaseField, 4, 1>,
+    authenticator: PoseidonSponge<TE::BaseField, 4, 1>,
+    num_elements: usize,
+}
+
+impl<TE: TwistedEdwardsParameters> ECIESPoseidonDecryptor<TE>
+where
+    TE::BaseField: PrimeField,
+{
+    /// Initializes a new decryptor from the private key and the ciphertext randomizer of the encryptor.
+    pub fn new(
+        encryption: &ECIESPoseidonEncryption<TE>,
+        private_key: &TE::ScalarField,
+        ciphertext_randomizer: TE::BaseField,
+    ) -> Result<Self, EncryptionError> {
+        let symmetric_key = encryption.try_generate_symmetric_key(private_key, ciphertext_randomizer)?;
+
+        Ok(Self {
+            keystream: encryption.keystream(&symmetric_key),
+            authenticator: encryption.authenticator(&symmetric_key),
+            num_elements: 0,
+        })
+    }
+
+    /// Decrypts the next chunk of the ciphertext, and returns the corresponding chunk of the message.
+    pub fn decrypt_chunk(&mut self, ciphertext: &[TE::BaseField]) -> Vec<TE::BaseField> {
+        // Absorb the ciphertext into the authentication tag.
+        self.authenticator.absorb(ciphertext);
+        self.num_elements += ciphertext.len();
+
+        // Obtain the next random field elements from Poseidon.
+        let randomizers = self.keystream.squeeze(ciphertext.len());
+
+        // Subtract the random field elements from the ciphertext elements.
+        ciphertext.iter().zip_eq(randomizers).map(|(ciphertext, randomizer)| *ciphertext - randomizer).collect()
+    }
+
+    /// Checks the given authentication tag against the ciphertext chunks.
+    pub fn finalize(mut self, tag: &TE::BaseField) -> Result<(), EncryptionError> {
+        self.authenticator.absorb(&[TE::BaseField::from(self.num_elements as u128)]);
+        match self.authenticator.squeeze(1)[0] == *tag {
+            true => Ok(()),
+            false => Err(EncryptionError::InvalidCiphertextTag),
+        }
+    }
+}
@@ -17,5 +17,8 @@
 pub mod ecies_poseidon;
 pub use ecies_poseidon::*;
 
+pub mod ecies_poseidon_stream;
+pub use ecies_poseidon_stream::*;
+
 #[cfg(test)]
 pub mod tests;
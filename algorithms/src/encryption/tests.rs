@@ -15,10 +15,18 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod ecies {
-    use crate::{encryption::ECIESPoseidonEncryption, EncryptionScheme};
-    use snarkvm_curves::edwards_bls12::{EdwardsParameters, Fq};
+    use crate::{
+        encryption::{ECIESPoseidonDecryptor, ECIESPoseidonEncryption, ECIESPoseidonEncryptor},
+        DecryptionError,
+        EncryptionError,
+        EncryptionScheme,
+    };
+    use snarkvm_curves::{
+        edwards_bls12::{EdwardsAffine, EdwardsParameters, Fq},
+        AffineCurve,
+    };
     use snarkvm_fields::One;
-    use snarkvm_utilities::{test_crypto_rng, FromBytes, ToBytes};
+    use snarkvm_utilities::{test_crypto_rng, FromBytes, ToBytes, UniformRand};
 
     use std::ops::AddAssign;
 
@@ -43,7 +51,7 @@ mod ecies {
         let ciphertext = encryption.encrypt(&symmetric_key, &encoded_message);
         dbg!(ciphertext.len());
 
-        let candidate_message = encryption.decrypt(&symmetric_key, &ciphertext);
+        let candidate_message = encryption.decrypt_unauthenticated(&symmetric_key, &ciphertext);
         let decoded_message = TestEncryptionScheme::decode_message(&candidate_message).unwrap();
         assert_eq!(message, decoded_message);
     }
@@ -123,7 +131,7 @@ mod ecies {
         let ciphertext = encryption.encrypt(&symmetric_key, &encoded_message);
         dbg!(ciphertext.len());
 
-        let candidate_message = encryption.decrypt(&symmetric_key, &ciphertext);
+        let candidate_message = encryption.decrypt_unauthenticated(&symmetric_key, &ciphertext);
         let decoded_message = TestEncryptionScheme::decode_message(&candidate_message).unwrap();
         assert_eq!(message, decoded_message);
 
@@ -137,9 +145,245 @@ mod ecies {
             ciphertext[x].add_assign(Fq::one());
 
             // This should fail.
-            let candidate_message = encryption.decrypt(&symmetric_key, &ciphertext);
+            let candidate_message = encryption.decrypt_unauthenticated(&symmetric_key, &ciphertext);
             let decoded_message = TestEncryptionScheme::decode_message(&candidate_message).unwrap();
             assert_ne!(message, decoded_message);
         }
     }
+
+    #[test]
+    fn test_stream_encrypt_and_decrypt() {
+        let rng = &mut test_crypto_rng();
+        let encryption = TestEncryptionScheme::setup("stream_encryption");
+
+        let private_key = encryption.generate_private_key(rng);
+        let public_key = encryption.generate_public_key(&private_key);
+
+        // Include empty and single-element chunks, and chunks which straddle the Poseidon rate.
+        for chunk_sizes in [vec![], vec![0], vec![1], vec![1, 0, 1], vec![3, 0, 5, 1, 4, 9], vec![4, 4, 4], vec![17]] {
+            let message = (0..chunk_sizes.iter().sum()).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+
+            // Encrypt the message in chunks.
+            let mut encryptor = ECIESPoseidonEncryptor::new(&encryption, &public_key, rng);
+            let mut offset = 0;
+            let mut ciphertext_chunks = Vec::with_capacity(chunk_sizes.len());
+            for size in &chunk_sizes {
+                ciphertext_chunks.push(encryptor.encrypt_chunk(&message[offset..offset + size]));
+                offset += size;
+            }
+            let ciphertext_randomizer = encryptor.ciphertext_randomizer();
+            let tag = encryptor.finalize();
+
+            // Ensure the chunked ciphertext matches the one-shot ciphertext.
+            let symmetric_key = encryption.generate_symmetric_key(&private_key, ciphertext_randomizer).unwrap();
+            let ciphertext = ciphertext_chunks.concat();
+            assert_eq!(ciphertext, encryption.encrypt(&symmetric_key, &message));
+            assert_eq!(tag, encryption.authenticate(&symmetric_key, &ciphertext));
+
+            // Decrypt the ciphertext in the same chunks.
+            let mut decryptor = ECIESPoseidonDecryptor::new(&encryption, &private_key, ciphertext_randomizer).unwrap();
+            let candidate_message =
+                ciphertext_chunks.iter().map(|chunk| decryptor.decrypt_chunk(chunk)).collect::<Vec<_>>().concat();
+            decryptor.finalize(&tag).unwrap();
+            assert_eq!(message, candidate_message);
+
+            // Decrypt the ciphertext in one chunk.
+            let mut decryptor = ECIESPoseidonDecryptor::new(&encryption, &private_key, ciphertext_randomizer).unwrap();
+            assert_eq!(message, decryptor.decrypt_chunk(&ciphertext));
+            decryptor.finalize(&tag).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_stream_ciphertext_manipulation() {
+        let rng = &mut test_crypto_rng();
+        let encryption = TestEncryptionScheme::setup("stream_encryption");
+
+        let private_key = encryption.generate_private_key(rng);
+        let public_key = encryption.generate_public_key(&private_key);
+
+        let message = (0..10).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+        let mut encryptor = ECIESPoseidonEncryptor::new(&encryption, &public_key, rng);
+        let ciphertext = encryptor.encrypt_chunk(&message);
+        let ciphertext_randomizer = encryptor.ciphertext_randomizer();
+        let tag = encryptor.finalize();
+
+        let check = |ciphertext: &[Fq], tag: &Fq| {
+            let mut decryptor = ECIESPoseidonDecryptor::new(&encryption, &private_key, ciphertext_randomizer).unwrap();
+            decryptor.decrypt_chunk(ciphertext);
+            decryptor.finalize(tag)
+        };
+        assert!(check(&ciphertext, &tag).is_ok());
+
+        // Ensure a mutated ciphertext element fails.
+        for i in 0..ciphertext.len() {
+            let mut ciphertext = ciphertext.clone();
+            ciphertext[i].add_assign(Fq::one());
+            assert!(matches!(check(&ciphertext, &tag), Err(EncryptionError::InvalidCiphertextTag)));
+        }
+
+        // Ensure a truncated or extended ciphertext fails.
+        assert!(check(&ciphertext[..9], &tag).is_err());
+        assert!(check(&[&ciphertext[..], &[Fq::one()]].concat(), &tag).is_err());
+
+        // Ensure a mutated tag fails.
+        assert!(check(&ciphertext, &(tag + Fq::one())).is_err());
+
+        // Ensure a different private key fails.
+        let alternate_private_key = encryption.generate_private_key(rng);
+        let mut decryptor =
+            ECIESPoseidonDecryptor::new(&encryption, &alternate_private_key, ciphertext_randomizer).unwrap();
+        assert_ne!(message, decryptor.decrypt_chunk(&ciphertext));
+        assert!(decryptor.finalize(&tag).is_err());
+    }
+
+    #[test]
+    fn test_stream_large_message() {
+        let rng = &mut test_crypto_rng();
+        let encryption = TestEncryptionScheme::setup("stream_encryption");
+
+        let private_key = encryption.generate_private_key(rng);
+        let public_key = encryption.generate_public_key(&private_key);
+
+        const NUM_CHUNKS: usize = 64;
+        const CHUNK_SIZE: usize = 1024;
+
+        let mut encryptor = ECIESPoseidonEncryptor::new(&encryption, &public_key, rng);
+        let mut decryptor =
+            ECIESPoseidonDecryptor::new(&encryption, &private_key, encryptor.ciphertext_randomizer()).unwrap();
+
+        // Encrypt and decrypt one chunk at a time, so only a single chunk is held in memory.
+        for _ in 0..NUM_CHUNKS {
+            let message = (0..CHUNK_SIZE).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+            let ciphertext = encryptor.encrypt_chunk(&message);
+            assert_eq!(message, decryptor.decrypt_chunk(&ciphertext));
+        }
+        decryptor.finalize(&encryptor.finalize()).unwrap();
+    }
+
+    #[test]
+    fn test_authenticated_encrypt_and_decrypt() {
+        let rng = &mut test_crypto_rng();
+        let encryption = TestEncryptionScheme::setup("authenticated_encryption");
+
+        let private_key = encryption.generate_private_key(rng);
+        let public_key = encryption.generate_public_key(&private_key);
+
+        // Check lengths up to 3 times the Poseidon rate.
+        for num_elements in 0..=12 {
+            let (_randomness, ciphertext_randomizer, symmetric_key) =
+                encryption.generate_asymmetric_key(&public_key, rng);
+            let message = (0..num_elements).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+
+            let ciphertext = encryption.encrypt_authenticated(&symmetric_key, &message);
+            assert_eq!(num_elements + 1, ciphertext.len());
+
+            // Ensure the ciphertext extends the unauthenticated ciphertext.
+            assert_eq!(encryption.encrypt(&symmetric_key, &message), ciphertext[..num_elements]);
+            assert_eq!(message, encryption.decrypt_unauthenticated(&symmetric_key, &ciphertext[..num_elements]));
+
+            // Ensure the recipient decrypts the ciphertext from their private key.
+            let candidate_symmetric_key =
+                encryption.generate_symmetric_key(&private_key, ciphertext_randomizer).unwrap();
+            assert_eq!(message, encryption.decrypt_authenticated(&candidate_symmetric_key, &ciphertext).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_authenticated_ciphertext_manipulation() {
+        let rng = &mut test_crypto_rng();
+        let encryption = TestEncryptionScheme::setup("authenticated_encryption");
+
+        let private_key = encryption.generate_private_key(rng);
+        let public_key = encryption.generate_public_key(&private_key);
+        let (_randomness, ciphertext_randomizer, symmetric_key) = encryption.generate_asymmetric_key(&public_key, rng);
+
+        let message = (0..9).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+        let ciphertext = encryption.encrypt_authenticated(&symmetric_key, &message);
+
+        // Ensure tampering with any position, including the tag, fails.
+        for i in 0..ciphertext.len() {
+            let mut ciphertext = ciphertext.clone();
+            ciphertext[i].add_assign(Fq::one());
+            assert_eq!(
+                Err(DecryptionError::MacMismatch),
+                encryption.decrypt_authenticated(&symmetric_key, &ciphertext)
+            );
+        }
+
+        // Ensure truncation fails.
+        for i in 1..ciphertext.len() {
+            assert_eq!(
+                Err(DecryptionError::MacMismatch),
+                encryption.decrypt_authenticated(&symmetric_key, &ciphertext[..i])
+            );
+        }
+        assert_eq!(Err(DecryptionError::MissingMac), encryption.decrypt_authenticated(&symmetric_key, &[]));
+
+        // Ensure a wrong view key fails.
+        let alternate_private_key = encryption.generate_private_key(rng);
+        let alternate_symmetric_key =
+            encryption.generate_symmetric_key(&alternate_private_key, ciphertext_randomizer).unwrap();
+        assert_eq!(
+            Err(DecryptionError::MacMismatch),
+            encryption.decrypt_authenticated(&alternate_symmetric_key, &ciphertext)
+        );
+    }
+
+    #[test]
+    fn test_invalid_ciphertext_randomizer() {
+        let rng = &mut test_crypto_rng();
+        let encryption = TestEncryptionScheme::setup("invalid_ciphertext_randomizer");
+
+        let private_key = encryption.generate_private_key(rng);
+        let public_key = encryption.generate_public_key(&private_key);
+
+        // Ensure a valid ciphertext decrypts.
+        let (_randomness, ciphertext_randomizer, symmetric_key) = encryption.generate_asymmetric_key(&public_key, rng);
+        assert_eq!(symmetric_key, encryption.try_generate_symmetric_key(&private_key, ciphertext_randomizer).unwrap());
+        let message = TestEncryptionScheme::encode_message(b"a valid ciphertext").unwrap();
+        let ciphertext = encryption.encrypt_authenticated(&symmetric_key, &message);
+        let candidate_key = encryption.generate_symmetric_key(&private_key, ciphertext_randomizer).unwrap();
+        assert_eq!(message, encryption.decrypt_authenticated(&candidate_key, &ciphertext).unwrap());
+
+        // Sample a point on the curve whose torsion component has order 4, so that neither point
+        // with its x-coordinate is in the prime-order subgroup.
+        let off_subgroup = loop {
+            let x = Fq::rand(rng);
+            if let (Some(greatest), Some(least)) =
+                (EdwardsAffine::from_x_coordinate(x, true), EdwardsAffine::from_x_coordinate(x, false))
+            {
+                if !greatest.is_in_correct_subgroup_assuming_on_curve()
+                    && !least.is_in_correct_subgroup_assuming_on_curve()
+                {
+                    break x;
+                }
+            }
+        };
+        assert!(matches!(
+            encryption.try_generate_symmetric_key(&private_key, off_subgroup),
+            Err(EncryptionError::RandomizerNotInSubgroup)
+        ));
+        assert_eq!(None, encryption.generate_symmetric_key(&private_key, off_subgroup));
+        assert!(matches!(
+            ECIESPoseidonDecryptor::new(&encryption, &private_key, off_subgroup),
+            Err(EncryptionError::RandomizerNotInSubgroup)
+        ));
+
+        // Ensure an x-coordinate without a point on the curve is rejected.
+        let off_curve = loop {
+            let x = Fq::rand(rng);
+            if EdwardsAffine::from_x_coordinate(x, true).is_none() {
+                break x;
+            }
+        };
+        assert!(matches!(
+            encryption.try_generate_symmetric_key(&private_key, off_curve),
+            Err(EncryptionError::RandomizerNotOnCurve)
+        ));
+        assert_eq!(
+            "The ciphertext randomizer is not the x-coordinate of a point on the curve",
+            encryption.try_generate_symmetric_key(&private_key, off_curve).unwrap_err().to_string()
+        );
+    }
 }
@@ -22,6 +22,12 @@ pub enum EncryptionError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("Invalid ciphertext randomizer")]
+    InvalidCiphertextRandomizer,
+
+    #[error("Invalid ciphertext authentication tag")]
+    InvalidCiphertextTag,
+
     #[error("Invalid private key")]
     InvalidPrivateKey,
 
@@ -33,6 +39,12 @@ pub enum EncryptionError {
 
     #[error("{}", _0)]
     Message(String),
+
+    #[error("The ciphertext randomizer is not in the prime-order subgroup")]
+    RandomizerNotInSubgroup,
+
+    #[error("The ciphertext randomizer is not the x-coordinate of a point on the curve")]
+    RandomizerNotOnCurve,
 }
 
 impl From<std::io::Error> for EncryptionError {
@@ -20,6 +20,9 @@ pub use commitment::*;
 pub mod crh;
 pub use crh::*;
 
+pub mod decryption;
+pub use decryption::*;
+
 pub mod encryption;
 pub use encryption::*;
 
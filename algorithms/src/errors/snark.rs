@@ -16,6 +16,7 @@
 
 use snarkvm_fields::ConstraintFieldError;
 use snarkvm_r1cs::SynthesisError;
+use snarkvm_utilities::SerializationError;
 
 #[derive(Debug, Error)]
 pub enum SNARKError {
@@ -40,6 +41,9 @@ pub enum SNARKError {
     #[error("Batch size was zero; must be at least 1")]
     EmptyBatch,
 
+    #[error("The checksum of the serialized key does not match its contents")]
+    ChecksumMismatch,
+
     #[error("terminated")]
     Terminated,
 }
@@ -49,3 +53,15 @@ impl From<SynthesisError> for SNARKError {
         SNARKError::SynthesisError(error)
     }
 }
+
+impl From<std::io::Error> for SNARKError {
+    fn from(error: std::io::Error) -> Self {
+        SNARKError::Crate("std::io", format!("{:?}", error))
+    }
+}
+
+impl From<SerializationError> for SNARKError {
+    fn from(error: SerializationError) -> Self {
+        SNARKError::Crate("snarkvm_utilities::serialize", format!("{:?}", error))
+    }
+}
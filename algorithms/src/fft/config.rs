@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The default minimum domain size at which (I)FFTs run in parallel.
+pub const DEFAULT_MIN_PARALLEL_SIZE: usize = 1 << 10;
+
+/// The minimum domain size at which (I)FFTs run in parallel, for every (I)FFT without a configuration.
+static MIN_PARALLEL_SIZE: AtomicUsize = AtomicUsize::new(DEFAULT_MIN_PARALLEL_SIZE);
+
+///
+/// The configuration of the radix-2 (I)FFTs.
+///
+/// Below `min_parallel_size`, the cost of dispatching to a thread pool outweighs the butterflies,
+/// so the (I)FFT runs serially on the calling thread. The serial and parallel (I)FFTs give identical results.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FFTConfig {
+    /// The minimum domain size at which the (I)FFT runs in parallel.
+    pub min_parallel_size: usize,
+}
+
+impl Default for FFTConfig {
+    fn default() -> Self {
+        Self { min_parallel_size: DEFAULT_MIN_PARALLEL_SIZE }
+    }
+}
+
+impl FFTConfig {
+    /// Returns a configuration that runs every (I)FFT serially.
+    pub const fn serial() -> Self {
+        Self { min_parallel_size: usize::MAX }
+    }
+
+    /// Returns a configuration that runs every (I)FFT in parallel.
+    pub const fn parallel() -> Self {
+        Self { min_parallel_size: 0 }
+    }
+
+    /// Returns the configuration used by the (I)FFTs without a configuration.
+    pub fn global() -> Self {
+        Self { min_parallel_size: MIN_PARALLEL_SIZE.load(Ordering::Relaxed) }
+    }
+
+    /// Sets the configuration used by the (I)FFTs without a configuration.
+    pub fn set_global(self) {
+        MIN_PARALLEL_SIZE.store(self.min_parallel_size, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if an (I)FFT over a domain of the given size runs in parallel.
+    pub fn is_parallel(&self, size: usize) -> bool {
+        cfg!(feature = "parallel") && size >= self.min_parallel_size
+    }
+}
@@ -31,15 +31,30 @@ use crate::{
     cfg_into_iter,
     cfg_iter,
     cfg_iter_mut,
-    fft::{DomainCoeff, SparsePolynomial},
+    fft::{DomainCoeff, FFTConfig, SparsePolynomial},
 };
 use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
 #[cfg(feature = "parallel")]
 use snarkvm_utilities::max_available_threads;
-use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
+use snarkvm_utilities::{
+    error,
+    execute_with_max_available_threads,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
 
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
 use rand::Rng;
-use std::{borrow::Cow, fmt};
+use std::{
+    any::{Any, TypeId},
+    borrow::Cow,
+    collections::HashMap,
+    fmt,
+    sync::Arc,
+};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -75,10 +90,14 @@ pub fn log2(x: usize) -> u32 {
 #[cfg(feature = "parallel")]
 const MIN_PARALLEL_CHUNK_SIZE: usize = 1 << 7;
 
+/// The (I)FFT precomputations of the domains, by their type and domain size.
+static PRECOMPUTATION_CACHE: Lazy<RwLock<HashMap<(TypeId, u64), Arc<dyn Any + Send + Sync>>>> =
+    Lazy::new(Default::default);
+
 /// Defines a domain over which finite field (I)FFTs can be performed. Works
 /// only for fields that have a large multiplicative subgroup of size that is
 /// a power-of-2.
-#[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
 pub struct EvaluationDomain<F: FftField> {
     /// The size of the domain.
     pub size: u64,
@@ -166,9 +185,14 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an FFT, modifying the vector in place.
     pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
-        execute_with_max_available_threads(|| {
+        self.fft_in_place_with_config(coeffs, &FFTConfig::global())
+    }
+
+    /// Compute an FFT with the given configuration, modifying the vector in place.
+    pub fn fft_in_place_with_config<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>, config: &FFTConfig) {
+        self.execute_with_config(config, || {
             coeffs.resize(self.size(), T::zero());
-            self.in_order_fft_in_place(&mut *coeffs);
+            self.in_order_fft_in_place(&mut *coeffs, config);
         });
     }
 
@@ -182,45 +206,85 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Compute an IFFT, modifying the vector in place.
     #[inline]
     pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
-        execute_with_max_available_threads(|| {
+        self.ifft_in_place_with_config(evals, &FFTConfig::global())
+    }
+
+    /// Compute an IFFT with the given configuration, modifying the vector in place.
+    pub fn ifft_in_place_with_config<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>, config: &FFTConfig) {
+        self.execute_with_config(config, || {
             evals.resize(self.size(), T::zero());
-            self.in_order_ifft_in_place(&mut *evals);
+            self.in_order_ifft_in_place(&mut *evals, config);
         });
     }
 
     /// Compute an FFT over a coset of the domain.
     pub fn coset_fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        self.coset_fft_with_offset(coeffs, F::multiplicative_generator())
+    }
+
+    /// Compute an FFT over the coset `offset * H` of the domain `H`.
+    pub fn coset_fft_with_offset<T: DomainCoeff<F>>(&self, coeffs: &[T], offset: F) -> Vec<T> {
         let mut coeffs = coeffs.to_vec();
-        self.coset_fft_in_place(&mut coeffs);
+        self.coset_fft_in_place_with_offset(&mut coeffs, offset);
         coeffs
     }
 
     /// Compute an FFT over a coset of the domain, modifying the input vector
     /// in place.
     pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        self.coset_fft_in_place_with_offset(coeffs, F::multiplicative_generator())
+    }
+
+    /// Compute an FFT over the coset `offset * H` of the domain `H`, modifying the input vector in place.
+    pub fn coset_fft_in_place_with_offset<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>, offset: F) {
         execute_with_max_available_threads(|| {
-            Self::distribute_powers(coeffs, F::multiplicative_generator());
+            Self::distribute_powers(coeffs, offset);
             self.fft_in_place(coeffs);
         });
     }
 
     /// Compute an IFFT over a coset of the domain.
     pub fn coset_ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        self.coset_ifft_with_offset(evals, F::multiplicative_generator())
+    }
+
+    /// Compute an IFFT over the coset `offset * H` of the domain `H`.
+    ///
+    /// Panics if the offset is zero.
+    pub fn coset_ifft_with_offset<T: DomainCoeff<F>>(&self, evals: &[T], offset: F) -> Vec<T> {
         let mut evals = evals.to_vec();
-        self.coset_ifft_in_place(&mut evals);
+        self.coset_ifft_in_place_with_offset(&mut evals, offset);
         evals
     }
 
     /// Compute an IFFT over a coset of the domain, modifying the input vector in place.
     pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
-        execute_with_max_available_threads(|| {
+        self.coset_ifft_in_place_with_offset(evals, F::multiplicative_generator())
+    }
+
+    /// Compute an IFFT over the coset `offset * H` of the domain `H`, modifying the input vector in place.
+    ///
+    /// Panics if the offset is zero.
+    pub fn coset_ifft_in_place_with_offset<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>, offset: F) {
+        let offset_inv = offset.inverse().expect("The coset offset must be non-zero");
+        let config = FFTConfig::global();
+        self.execute_with_config(&config, || {
             evals.resize(self.size(), T::zero());
-            self.in_order_coset_ifft_in_place(&mut *evals);
+            self.in_order_coset_ifft_in_place(&mut *evals, offset_inv, &config);
         });
     }
 
+    /// Runs the given (I)FFT on the calling thread if the configuration is serial for this domain,
+    /// and on a thread pool of the available threads otherwise.
+    fn execute_with_config(&self, config: &FFTConfig, f: impl FnOnce() + Send) {
+        match config.is_parallel(self.size()) {
+            true => execute_with_max_available_threads(f),
+            false => f(),
+        }
+    }
+
     /// Multiply the `i`-th element of `coeffs` with `g^i`.
-    fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
+    pub(crate) fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
         Self::distribute_powers_and_mul_by_const(coeffs, g, F::one());
     }
 
@@ -255,39 +319,7 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Evaluate all the lagrange polynomials defined by this domain at the point
     /// `tau`.
     pub fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
-        // Evaluate all Lagrange polynomials
-        let size = self.size as usize;
-        let t_size = tau.pow(&[self.size]);
-        let one = F::one();
-        if t_size.is_one() {
-            let mut u = vec![F::zero(); size];
-            let mut omega_i = one;
-            for x in u.iter_mut().take(size) {
-                if omega_i == tau {
-                    *x = one;
-                    break;
-                }
-                omega_i *= &self.group_gen;
-            }
-            u
-        } else {
-            let mut l = (t_size - one) * self.size_inv;
-            let mut r = one;
-            let mut u = vec![F::zero(); size];
-            let mut ls = vec![F::zero(); size];
-            for i in 0..size {
-                u[i] = tau - r;
-                ls[i] = l;
-                l *= &self.group_gen;
-                r *= &self.group_gen;
-            }
-
-            batch_inversion(u.as_mut_slice());
-            cfg_iter_mut!(u).zip_eq(ls).for_each(|(tau_minus_r, l)| {
-                *tau_minus_r = l * *tau_minus_r;
-            });
-            u
-        }
+        lagrange_coefficients(self.size, self.size_inv, self.group_gen, tau)
     }
 
     /// Return the sparse vanishing polynomial.
@@ -302,16 +334,43 @@ impl<F: FftField> EvaluationDomain<F> {
         tau.pow(&[self.size]) - F::one()
     }
 
+    /// This evaluates the derivative of the vanishing polynomial for this domain at tau.
+    /// For multiplicative subgroups, this polynomial is `z'(X) = self.size * X^(self.size - 1)`.
+    pub fn evaluate_vanishing_polynomial_derivative(&self, tau: F) -> F {
+        self.size_as_field_element * tau.pow([self.size - 1])
+    }
+
+    /// Returns the evaluation of the vanishing polynomial over the coset `offset * H` of the domain `H`,
+    /// where it is the constant `offset^self.size - 1`.
+    pub fn evaluate_vanishing_polynomial_over_coset(&self, offset: F) -> F {
+        self.evaluate_vanishing_polynomial(offset)
+    }
+
+    /// Returns `true` if the coset `offset * H` of this domain `H` and the coset `other_offset * K`
+    /// of the domain `K` are disjoint.
+    ///
+    /// The smaller of `H` and `K` is a subgroup of the larger, of size `n`, so the cosets intersect
+    /// if and only if `offset^n == other_offset^n`.
+    pub fn cosets_are_disjoint(&self, offset: F, other: &Self, other_offset: F) -> bool {
+        let size = core::cmp::max(self.size, other.size);
+        offset.pow([size]) != other_offset.pow([size])
+    }
+
     /// Return an iterator over the elements of the domain.
     pub fn elements(&self) -> Elements<F> {
-        Elements { cur_elem: F::one(), cur_pow: 0, domain: *self }
+        Elements::new(self.size, self.group_gen)
+    }
+
+    /// Returns the element `g^index` of the domain, without computing the elements before it.
+    pub fn element(&self, index: usize) -> F {
+        self.group_gen.pow([index as u64])
     }
 
     /// The target polynomial is the zero polynomial in our
     /// evaluation domain, so we must perform division over
     /// a coset.
     pub fn divide_by_vanishing_poly_on_coset_in_place(&self, evals: &mut [F]) {
-        let i = self.evaluate_vanishing_polynomial(F::multiplicative_generator()).inverse().unwrap();
+        let i = self.evaluate_vanishing_polynomial_over_coset(F::multiplicative_generator()).inverse().unwrap();
 
         cfg_iter_mut!(evals).for_each(|eval| *eval *= &i);
     }
@@ -370,22 +429,59 @@ impl<F: FftField> EvaluationDomain<F> {
         })
     }
 
-    pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
-        let pc = self.precompute_fft();
-        self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc)
+    /// Returns the FFT precomputation of the domain, which is computed once and shared by every FFT over the domain.
+    pub(crate) fn cached_fft_precomputation(&self) -> Arc<FFTPrecomputation<F>> {
+        self.cached_precomputation(|pc: &FFTPrecomputation<F>| pc.domain == *self, || self.precompute_fft())
+    }
+
+    /// Returns the IFFT precomputation of the domain, which is computed once and shared by every IFFT over the domain.
+    pub(crate) fn cached_ifft_precomputation(&self) -> Arc<IFFTPrecomputation<F>> {
+        self.cached_precomputation(|pc: &IFFTPrecomputation<F>| pc.domain == *self, || self.precompute_ifft())
+    }
+
+    /// Returns the cached precomputation of type `P` for the size of the domain, if it is for this domain,
+    /// and otherwise computes the precomputation and caches it.
+    fn cached_precomputation<P: Any + Send + Sync>(
+        &self,
+        is_for_domain: impl Fn(&P) -> bool,
+        precompute: impl FnOnce() -> P,
+    ) -> Arc<P> {
+        let key = (TypeId::of::<P>(), self.size);
+
+        if let Some(pc) = PRECOMPUTATION_CACHE.read().get(&key) {
+            if let Ok(pc) = pc.clone().downcast::<P>() {
+                if is_for_domain(&pc) {
+                    return pc;
+                }
+            }
+        }
+
+        // Run the precomputation without holding the lock, as it may be slow.
+        let pc = Arc::new(precompute());
+        PRECOMPUTATION_CACHE.write().insert(key, pc.clone());
+        pc
+    }
+
+    pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], config: &FFTConfig) {
+        let pc = self.cached_fft_precomputation();
+        self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc, config)
     }
 
-    pub(crate) fn in_order_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
-        let pc = self.precompute_ifft();
-        self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc);
+    pub(crate) fn in_order_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], config: &FFTConfig) {
+        let pc = self.cached_ifft_precomputation();
+        self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc, config);
         cfg_iter_mut!(x_s).for_each(|val| *val *= self.size_inv);
     }
 
-    pub(crate) fn in_order_coset_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
-        let pc = self.precompute_ifft();
-        self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc);
-        let coset_shift = self.generator_inv;
-        Self::distribute_powers_and_mul_by_const(x_s, coset_shift, self.size_inv);
+    pub(crate) fn in_order_coset_ifft_in_place<T: DomainCoeff<F>>(
+        &self,
+        x_s: &mut [T],
+        offset_inv: F,
+        config: &FFTConfig,
+    ) {
+        let pc = self.cached_ifft_precomputation();
+        self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc, config);
+        Self::distribute_powers_and_mul_by_const(x_s, offset_inv, self.size_inv);
     }
 
     #[allow(unused)]
@@ -394,7 +490,7 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         pre_comp: &FFTPrecomputation<F>,
     ) {
-        self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp)
+        self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp, &FFTConfig::global())
     }
 
     pub(crate) fn out_order_fft_in_place_with_pc<T: DomainCoeff<F>>(
@@ -402,7 +498,7 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         pre_comp: &FFTPrecomputation<F>,
     ) {
-        self.fft_helper_in_place_with_pc(x_s, FFTOrder::IO, pre_comp)
+        self.fft_helper_in_place_with_pc(x_s, FFTOrder::IO, pre_comp, &FFTConfig::global())
     }
 
     pub(crate) fn in_order_ifft_in_place_with_pc<T: DomainCoeff<F>>(
@@ -410,7 +506,7 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         pre_comp: &IFFTPrecomputation<F>,
     ) {
-        self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp);
+        self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp, &FFTConfig::global());
         cfg_iter_mut!(x_s).for_each(|val| *val *= self.size_inv);
     }
 
@@ -419,7 +515,7 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         pre_comp: &IFFTPrecomputation<F>,
     ) {
-        self.ifft_helper_in_place_with_pc(x_s, FFTOrder::OI, pre_comp);
+        self.ifft_helper_in_place_with_pc(x_s, FFTOrder::OI, pre_comp, &FFTConfig::global());
         cfg_iter_mut!(x_s).for_each(|val| *val *= self.size_inv);
     }
 
@@ -429,7 +525,7 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         pre_comp: &IFFTPrecomputation<F>,
     ) {
-        self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp);
+        self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp, &FFTConfig::global());
         let coset_shift = self.generator_inv;
         Self::distribute_powers_and_mul_by_const(x_s, coset_shift, self.size_inv);
     }
@@ -439,16 +535,18 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         ord: FFTOrder,
         pre_comp: &FFTPrecomputation<F>,
+        config: &FFTConfig,
     ) {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
         let log_len = log2(x_s.len());
+        let max_threads = self.max_threads(config);
 
         if ord == OI {
-            self.oi_helper_with_roots(x_s, &pc.roots);
+            self.oi_helper_with_roots(x_s, &pc.roots, max_threads);
         } else {
-            self.io_helper_with_roots(x_s, &pc.roots);
+            self.io_helper_with_roots(x_s, &pc.roots, max_threads);
         }
 
         if ord == II {
@@ -464,20 +562,31 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         ord: FFTOrder,
         pre_comp: &IFFTPrecomputation<F>,
+        config: &FFTConfig,
     ) {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
         let log_len = log2(x_s.len());
+        let max_threads = self.max_threads(config);
 
         if ord == II {
             derange_helper(x_s, log_len);
         }
 
         if ord == IO {
-            self.io_helper_with_roots(x_s, &pc.inverse_roots);
+            self.io_helper_with_roots(x_s, &pc.inverse_roots, max_threads);
         } else {
-            self.oi_helper_with_roots(x_s, &pc.inverse_roots);
+            self.oi_helper_with_roots(x_s, &pc.inverse_roots, max_threads);
+        }
+    }
+
+    /// Returns the number of threads for the butterflies of an (I)FFT over the domain, which is 1 if it runs serially.
+    fn max_threads(&self, config: &FFTConfig) -> usize {
+        match config.is_parallel(self.size()) {
+            #[cfg(feature = "parallel")]
+            true => max_available_threads(),
+            _ => 1,
         }
     }
 
@@ -563,41 +672,53 @@ impl<F: FftField> EvaluationDomain<F> {
         *hi = neg;
     }
 
-    #[allow(clippy::too_many_arguments)]
+    /// Applies the butterfly `g` to every pair `(lo, hi)` in the layer, where `xi` is split into chunks
+    /// of `2 * gap` elements, and each chunk pairs its element `i` with its element `gap + i`, by `roots[i * step]`.
     fn apply_butterfly<T: DomainCoeff<F>, G: Fn(((&mut T, &mut T), &F)) + Copy + Sync + Send>(
         g: G,
         xi: &mut [T],
         roots: &[F],
         step: usize,
-        chunk_size: usize,
-        num_chunks: usize,
         max_threads: usize,
         gap: usize,
     ) {
-        cfg_chunks_mut!(xi, chunk_size).for_each(|cxi| {
-            let (lo, hi) = cxi.split_at_mut(gap);
-            // If the chunk is sufficiently big that parallelism helps,
-            // we parallelize the butterfly operation within the chunk.
-
-            if gap > MIN_GAP_SIZE_FOR_PARALLELISATION && num_chunks < max_threads {
-                cfg_iter_mut!(lo).zip(hi).zip(cfg_iter!(roots).step_by(step)).for_each(g);
-            } else {
-                lo.iter_mut().zip(hi).zip(roots.iter().step_by(step)).for_each(g);
+        let butterflies = |lo: &mut [T], hi: &mut [T], roots: &[F]| {
+            lo.iter_mut().zip(hi).zip(roots.iter().step_by(step)).for_each(g);
+        };
+
+        match max_threads > 1 {
+            // If there are fewer chunks than threads, split the butterflies of each chunk into blocks,
+            // so that every thread works on a contiguous block of `lo` and `hi`.
+            #[cfg(feature = "parallel")]
+            true if xi.len() / (2 * gap) < max_threads && gap > MIN_GAP_SIZE_FOR_PARALLELISATION => {
+                let block_size = core::cmp::max(gap / max_threads, MIN_GAP_SIZE_FOR_PARALLELISATION);
+                xi.chunks_mut(2 * gap).for_each(|cxi| {
+                    let (lo, hi) = cxi.split_at_mut(gap);
+                    lo.par_chunks_mut(block_size)
+                        .zip(hi.par_chunks_mut(block_size))
+                        .enumerate()
+                        .for_each(|(i, (lo, hi))| butterflies(lo, hi, &roots[i * block_size * step..]));
+                });
             }
-        });
+            // Otherwise, process the chunks in parallel.
+            #[cfg(feature = "parallel")]
+            true => xi.par_chunks_mut(2 * gap).for_each(|cxi| {
+                let (lo, hi) = cxi.split_at_mut(gap);
+                butterflies(lo, hi, roots);
+            }),
+            _ => xi.chunks_mut(2 * gap).for_each(|cxi| {
+                let (lo, hi) = cxi.split_at_mut(gap);
+                butterflies(lo, hi, roots);
+            }),
+        }
     }
 
-    fn io_helper_with_roots<T: DomainCoeff<F>>(&self, xi: &mut [T], roots: &[F]) {
+    fn io_helper_with_roots<T: DomainCoeff<F>>(&self, xi: &mut [T], roots: &[F], max_threads: usize) {
         let mut roots = std::borrow::Cow::Borrowed(roots);
 
         let mut step = 1;
         let mut first = true;
 
-        #[cfg(feature = "parallel")]
-        let max_threads = snarkvm_utilities::parallel::max_available_threads();
-        #[cfg(not(feature = "parallel"))]
-        let max_threads = 1;
-
         let mut gap = xi.len() / 2;
         while gap > 0 {
             // each butterfly cluster uses 2*gap positions
@@ -618,22 +739,13 @@ impl<F: FftField> EvaluationDomain<F> {
             }
             first = false;
 
-            Self::apply_butterfly(
-                Self::butterfly_fn_io,
-                xi,
-                &roots[..],
-                step,
-                chunk_size,
-                num_chunks,
-                max_threads,
-                gap,
-            );
+            Self::apply_butterfly(Self::butterfly_fn_io, xi, &roots[..], step, max_threads, gap);
 
             gap /= 2;
         }
     }
 
-    fn oi_helper_with_roots<T: DomainCoeff<F>>(&self, xi: &mut [T], roots_cache: &[F]) {
+    fn oi_helper_with_roots<T: DomainCoeff<F>>(&self, xi: &mut [T], roots_cache: &[F], max_threads: usize) {
         // The `cmp::min` is only necessary for the case where
         // `MIN_NUM_CHUNKS_FOR_COMPACTION = 1`. Else, notice that we compact
         // the roots cache by a stride of at least `MIN_NUM_CHUNKS_FOR_COMPACTION`.
@@ -642,11 +754,6 @@ impl<F: FftField> EvaluationDomain<F> {
             core::cmp::min(roots_cache.len() / 2, roots_cache.len() / MIN_NUM_CHUNKS_FOR_COMPACTION);
         let mut compacted_roots = vec![F::default(); compaction_max_size];
 
-        #[cfg(feature = "parallel")]
-        let max_threads = snarkvm_utilities::parallel::max_available_threads();
-        #[cfg(not(feature = "parallel"))]
-        let max_threads = 1;
-
         let mut gap = 1;
         while gap < xi.len() {
             // each butterfly cluster uses 2*gap positions
@@ -665,7 +772,7 @@ impl<F: FftField> EvaluationDomain<F> {
                 (roots_cache, num_chunks)
             };
 
-            Self::apply_butterfly(Self::butterfly_fn_oi, xi, roots, step, chunk_size, num_chunks, max_threads, gap);
+            Self::apply_butterfly(Self::butterfly_fn_oi, xi, roots, step, max_threads, gap);
 
             gap *= 2;
         }
@@ -754,27 +861,114 @@ pub(crate) fn compute_powers<F: Field>(size: usize, g: F) -> Vec<F> {
     res
 }
 
+/// Evaluates all the lagrange polynomials of the multiplicative subgroup of the given size
+/// and generator at the point `tau`.
+pub(crate) fn lagrange_coefficients<F: FftField>(size: u64, size_inv: F, group_gen: F, tau: F) -> Vec<F> {
+    // Evaluate all Lagrange polynomials
+    let t_size = tau.pow([size]);
+    let size = size as usize;
+    let one = F::one();
+    if t_size.is_one() {
+        let mut u = vec![F::zero(); size];
+        let mut omega_i = one;
+        for x in u.iter_mut().take(size) {
+            if omega_i == tau {
+                *x = one;
+                break;
+            }
+            omega_i *= &group_gen;
+        }
+        u
+    } else {
+        let mut l = (t_size - one) * size_inv;
+        let mut r = one;
+        let mut u = vec![F::zero(); size];
+        let mut ls = vec![F::zero(); size];
+        for i in 0..size {
+            u[i] = tau - r;
+            ls[i] = l;
+            l *= &group_gen;
+            r *= &group_gen;
+        }
+
+        batch_inversion(u.as_mut_slice());
+        cfg_iter_mut!(u).zip_eq(ls).for_each(|(tau_minus_r, l)| {
+            *tau_minus_r = l * *tau_minus_r;
+        });
+        u
+    }
+}
+
 /// An iterator over the elements of the domain.
 #[derive(Clone)]
 pub struct Elements<F: FftField> {
     cur_elem: F,
     cur_pow: u64,
-    domain: EvaluationDomain<F>,
+    size: u64,
+    group_gen: F,
+}
+
+impl<F: FftField> Elements<F> {
+    /// Returns an iterator over the multiplicative subgroup of the given size and generator.
+    pub(crate) fn new(size: u64, group_gen: F) -> Self {
+        Self { cur_elem: F::one(), cur_pow: 0, size, group_gen }
+    }
 }
 
 impl<F: FftField> Iterator for Elements<F> {
     type Item = F;
 
     fn next(&mut self) -> Option<F> {
-        if self.cur_pow == self.domain.size {
+        if self.cur_pow == self.size {
             None
         } else {
             let cur_elem = self.cur_elem;
-            self.cur_elem *= &self.domain.group_gen;
+            self.cur_elem *= &self.group_gen;
             self.cur_pow += 1;
             Some(cur_elem)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.size - self.cur_pow) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<F: FftField> ExactSizeIterator for Elements<F> {}
+
+impl<F: FftField> CanonicalSerialize for EvaluationDomain<F> {
+    /// Serializes the size of the domain, from which the rest of the domain is reconstructed.
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&self.size, writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.size.serialized_size()
+    }
+}
+
+impl<F: FftField> CanonicalDeserialize for EvaluationDomain<F> {
+    fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let size = u64::deserialize(reader)?;
+        // Reconstruct the domain, and ensure it has the serialized size.
+        match usize::try_from(size).ok().and_then(Self::new) {
+            Some(domain) if domain.size == size => Ok(domain),
+            _ => Err(SerializationError::InvalidData),
+        }
+    }
+}
+
+impl<F: FftField> FromBytes for EvaluationDomain<F> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize(&mut reader).map_err(|_| error("could not deserialize EvaluationDomain"))
+    }
+}
+
+impl<F: FftField> ToBytes for EvaluationDomain<F> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize(self, &mut writer).map_err(|_| error("could not serialize EvaluationDomain"))
+    }
 }
 
 /// An iterator over the elements of the domain.
@@ -864,6 +1058,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn vanishing_polynomial_evaluation_on_and_off_domain() {
+        let rng = &mut thread_rng();
+        for coeffs in 0..10 {
+            let domain = EvaluationDomain::<Fr>::new(coeffs).unwrap();
+            for point in domain.elements() {
+                assert!(domain.evaluate_vanishing_polynomial(point).is_zero());
+            }
+            let point = domain.sample_element_outside_domain(rng);
+            assert!(!domain.evaluate_vanishing_polynomial(point).is_zero());
+        }
+    }
+
+    #[test]
+    fn vanishing_polynomial_derivative_evaluation() {
+        let rng = &mut thread_rng();
+        for coeffs in 0..10 {
+            let domain = EvaluationDomain::<Fr>::new(coeffs).unwrap();
+
+            // Compare against the formal derivative of the vanishing polynomial.
+            let z = DensePolynomial::from(domain.vanishing_polynomial());
+            let z_derivative = DensePolynomial::from_coefficients_vec(
+                z.coeffs.iter().enumerate().skip(1).map(|(i, c)| Fr::from(i as u64) * c).collect(),
+            );
+            for _ in 0..10 {
+                let point = rng.gen();
+                assert_eq!(z_derivative.evaluate(point), domain.evaluate_vanishing_polynomial_derivative(point));
+            }
+
+            // On the domain, `z'(g) = n * g^(n - 1) = n / g`.
+            for point in domain.elements() {
+                assert_eq!(
+                    domain.evaluate_vanishing_polynomial_derivative(point),
+                    domain.size_as_field_element * point.inverse().unwrap()
+                );
+            }
+        }
+    }
+
     #[test]
     fn size_of_elements() {
         for coeffs in 1..10 {
@@ -909,6 +1142,18 @@ mod tests {
         }
     }
 
+    /// Test that the lagrange coefficients at a random point sum to one.
+    #[test]
+    fn lagrange_coefficients_sum_to_one() {
+        let rng = &mut thread_rng();
+        for domain_dimension in 0..10 {
+            let domain = EvaluationDomain::<Fr>::new(1 << domain_dimension).unwrap();
+            let point = domain.sample_element_outside_domain(rng);
+            let lagrange_coefficients = domain.evaluate_all_lagrange_coefficients(point);
+            assert_eq!(lagrange_coefficients.iter().sum::<Fr>(), Fr::one());
+        }
+    }
+
     /// Test that lagrange coefficients for a point in the domain is correct.
     #[test]
     fn systematic_lagrange_coefficients_test() {
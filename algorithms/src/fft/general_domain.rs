@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This module contains a `GeneralEvaluationDomain`, which dispatches between the radix-2
+//! `EvaluationDomain` and the `MixedRadixEvaluationDomain`, picking the smaller of the two.
+
+use crate::fft::{domain::Elements, DomainCoeff, EvaluationDomain, MixedRadixEvaluationDomain, SparsePolynomial};
+use snarkvm_fields::FftField;
+
+use rand::Rng;
+
+/// Dispatches a method call to the domain in `self`.
+macro_rules! dispatch {
+    ($self:expr, $domain:ident => $call:expr) => {
+        match $self {
+            Self::Radix2($domain) => $call,
+            Self::MixedRadix($domain) => $call,
+        }
+    };
+}
+
+/// Defines a domain over which finite field (I)FFTs can be performed, which is
+/// either a radix-2 domain, or a mixed-radix domain if it is smaller.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub enum GeneralEvaluationDomain<F: FftField> {
+    /// A domain of size `2^i`.
+    Radix2(EvaluationDomain<F>),
+    /// A domain of size `q^j * 2^i`, for the small subgroup base `q`.
+    MixedRadix(MixedRadixEvaluationDomain<F>),
+}
+
+impl<F: FftField> GeneralEvaluationDomain<F> {
+    /// Construct a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients.
+    ///
+    /// The mixed-radix domain is used if it is strictly smaller than the radix-2 domain.
+    pub fn new(num_coeffs: usize) -> Option<Self> {
+        let radix_2_size = EvaluationDomain::<F>::compute_size_of_domain(num_coeffs);
+        match MixedRadixEvaluationDomain::<F>::compute_size_of_domain(num_coeffs) {
+            Some(size) if radix_2_size.map_or(true, |radix_2_size| size < radix_2_size) => {
+                Some(Self::MixedRadix(MixedRadixEvaluationDomain::new(num_coeffs)?))
+            }
+            _ => Some(Self::Radix2(EvaluationDomain::new(num_coeffs)?)),
+        }
+    }
+
+    /// Return the size of a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients.
+    pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        Self::new(num_coeffs).map(|domain| domain.size())
+    }
+
+    /// Sample an element that is *not* in the domain.
+    pub fn sample_element_outside_domain<R: Rng>(&self, rng: &mut R) -> F {
+        dispatch!(self, domain => domain.sample_element_outside_domain(rng))
+    }
+
+    /// Return the size of `self`.
+    pub fn size(&self) -> usize {
+        dispatch!(self, domain => domain.size())
+    }
+
+    /// Return the inverse of the size of `self`, in the field.
+    pub fn size_inv(&self) -> F {
+        dispatch!(self, domain => domain.size_inv)
+    }
+
+    /// Return the generator of the subgroup.
+    pub fn group_gen(&self) -> F {
+        dispatch!(self, domain => domain.group_gen)
+    }
+
+    /// Compute an FFT.
+    pub fn fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        dispatch!(self, domain => domain.fft(coeffs))
+    }
+
+    /// Compute an FFT, modifying the vector in place.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        dispatch!(self, domain => domain.fft_in_place(coeffs))
+    }
+
+    /// Compute an IFFT.
+    pub fn ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        dispatch!(self, domain => domain.ifft(evals))
+    }
+
+    /// Compute an IFFT, modifying the vector in place.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        dispatch!(self, domain => domain.ifft_in_place(evals))
+    }
+
+    /// Compute an FFT over a coset of the domain.
+    pub fn coset_fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        dispatch!(self, domain => domain.coset_fft(coeffs))
+    }
+
+    /// Compute an FFT over the coset `offset * H` of the domain `H`.
+    pub fn coset_fft_with_offset<T: DomainCoeff<F>>(&self, coeffs: &[T], offset: F) -> Vec<T> {
+        dispatch!(self, domain => domain.coset_fft_with_offset(coeffs, offset))
+    }
+
+    /// Compute an FFT over a coset of the domain, modifying the input vector
+    /// in place.
+    pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        dispatch!(self, domain => domain.coset_fft_in_place(coeffs))
+    }
+
+    /// Compute an FFT over the coset `offset * H` of the domain `H`, modifying the input vector in place.
+    pub fn coset_fft_in_place_with_offset<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>, offset: F) {
+        dispatch!(self, domain => domain.coset_fft_in_place_with_offset(coeffs, offset))
+    }
+
+    /// Compute an IFFT over a coset of the domain.
+    pub fn coset_ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        dispatch!(self, domain => domain.coset_ifft(evals))
+    }
+
+    /// Compute an IFFT over the coset `offset * H` of the domain `H`.
+    pub fn coset_ifft_with_offset<T: DomainCoeff<F>>(&self, evals: &[T], offset: F) -> Vec<T> {
+        dispatch!(self, domain => domain.coset_ifft_with_offset(evals, offset))
+    }
+
+    /// Compute an IFFT over a coset of the domain, modifying the input vector in place.
+    pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        dispatch!(self, domain => domain.coset_ifft_in_place(evals))
+    }
+
+    /// Compute an IFFT over the coset `offset * H` of the domain `H`, modifying the input vector in place.
+    pub fn coset_ifft_in_place_with_offset<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>, offset: F) {
+        dispatch!(self, domain => domain.coset_ifft_in_place_with_offset(evals, offset))
+    }
+
+    /// Evaluate all the lagrange polynomials defined by this domain at the point
+    /// `tau`.
+    pub fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
+        dispatch!(self, domain => domain.evaluate_all_lagrange_coefficients(tau))
+    }
+
+    /// Return the sparse vanishing polynomial.
+    pub fn vanishing_polynomial(&self) -> SparsePolynomial<F> {
+        dispatch!(self, domain => domain.vanishing_polynomial())
+    }
+
+    /// This evaluates the vanishing polynomial for this domain at tau.
+    pub fn evaluate_vanishing_polynomial(&self, tau: F) -> F {
+        dispatch!(self, domain => domain.evaluate_vanishing_polynomial(tau))
+    }
+
+    /// This evaluates the derivative of the vanishing polynomial for this domain at tau.
+    pub fn evaluate_vanishing_polynomial_derivative(&self, tau: F) -> F {
+        dispatch!(self, domain => domain.evaluate_vanishing_polynomial_derivative(tau))
+    }
+
+    /// Returns the evaluation of the vanishing polynomial over the coset `offset * H` of the domain `H`.
+    pub fn evaluate_vanishing_polynomial_over_coset(&self, offset: F) -> F {
+        dispatch!(self, domain => domain.evaluate_vanishing_polynomial_over_coset(offset))
+    }
+
+    /// Return an iterator over the elements of the domain.
+    pub fn elements(&self) -> Elements<F> {
+        dispatch!(self, domain => domain.elements())
+    }
+
+    /// Returns the element `g^index` of the domain, without computing the elements before it.
+    pub fn element(&self, index: usize) -> F {
+        dispatch!(self, domain => domain.element(index))
+    }
+
+    /// The target polynomial is the zero polynomial in our
+    /// evaluation domain, so we must perform division over
+    /// a coset.
+    pub fn divide_by_vanishing_poly_on_coset_in_place(&self, evals: &mut [F]) {
+        dispatch!(self, domain => domain.divide_by_vanishing_poly_on_coset_in_place(evals))
+    }
+
+    /// Perform O(n) multiplication of two polynomials that are presented by their
+    /// evaluations in the domain.
+    /// Returns the evaluations of the product over the domain.
+    #[must_use]
+    pub fn mul_polynomials_in_evaluation_domain(&self, self_evals: &[F], other_evals: &[F]) -> Vec<F> {
+        dispatch!(self, domain => domain.mul_polynomials_in_evaluation_domain(self_evals, other_evals))
+    }
+}
+
+impl<F: FftField> From<EvaluationDomain<F>> for GeneralEvaluationDomain<F> {
+    fn from(domain: EvaluationDomain<F>) -> Self {
+        Self::Radix2(domain)
+    }
+}
+
+impl<F: FftField> From<MixedRadixEvaluationDomain<F>> for GeneralEvaluationDomain<F> {
+    fn from(domain: MixedRadixEvaluationDomain<F>) -> Self {
+        Self::MixedRadix(domain)
+    }
+}
@@ -0,0 +1,335 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This module contains a `MixedRadixEvaluationDomain`, whose size is `q^j * 2^i` for the small
+//! subgroup base `q` of the scalar field. For sizes just above a power of two, this domain is
+//! smaller than the radix-2 `EvaluationDomain`, which has to round up to the next power of two.
+
+use crate::{
+    cfg_into_iter,
+    cfg_iter_mut,
+    fft::{
+        domain::{compute_powers_serial, lagrange_coefficients, Elements},
+        DomainCoeff,
+        EvaluationDomain,
+        SparsePolynomial,
+    },
+};
+use snarkvm_fields::{FftField, FftParameters};
+use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
+
+use rand::Rng;
+use std::fmt;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(not(feature = "parallel"))]
+use itertools::Itertools;
+
+/// Defines a domain over which finite field (I)FFTs can be performed. Works
+/// only for fields that have a multiplicative subgroup of size `q^j * 2^i`,
+/// where `q` is the small subgroup base of the field.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MixedRadixEvaluationDomain<F: FftField> {
+    /// The size of the domain.
+    pub size: u64,
+    /// The 2-adicity of the size of the domain.
+    pub two_adicity: u32,
+    /// The `q`-adicity of the size of the domain, for the small subgroup base `q`.
+    pub small_subgroup_adicity: u32,
+    /// Size of the domain as a field element.
+    pub size_as_field_element: F,
+    /// Inverse of the size in the field.
+    pub size_inv: F,
+    /// A generator of the subgroup.
+    pub group_gen: F,
+    /// Inverse of the generator of the subgroup.
+    pub group_gen_inv: F,
+    /// Multiplicative generator of the finite field.
+    pub generator_inv: F,
+}
+
+impl<F: FftField> fmt::Debug for MixedRadixEvaluationDomain<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Mixed-radix multiplicative subgroup of size {}", self.size)
+    }
+}
+
+impl<F: FftField> MixedRadixEvaluationDomain<F> {
+    /// Sample an element that is *not* in the domain.
+    pub fn sample_element_outside_domain<R: Rng>(&self, rng: &mut R) -> F {
+        let mut t = F::rand(rng);
+        while self.evaluate_vanishing_polynomial(t).is_zero() {
+            t = F::rand(rng);
+        }
+        t
+    }
+
+    /// Construct a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients.
+    ///
+    /// Returns `None` if the field does not define a small subgroup base.
+    pub fn new(num_coeffs: usize) -> Option<Self> {
+        // Compute the size of our evaluation domain.
+        let size = Self::compute_size_of_domain(num_coeffs)?;
+        let small_subgroup_base = F::FftParameters::SMALL_SUBGROUP_BASE? as usize;
+        let small_subgroup_adicity = F::k_adicity(small_subgroup_base, size);
+        let two_adicity = F::k_adicity(2, size);
+
+        // Compute the generator for the multiplicative subgroup.
+        let group_gen = F::get_root_of_unity(size)?;
+
+        // Check that it is indeed the root of unity of order `size`.
+        debug_assert_eq!(group_gen.pow([size as u64]), F::one());
+
+        let size_as_field_element = F::from(size as u64);
+        let size_inv = size_as_field_element.inverse()?;
+
+        Some(MixedRadixEvaluationDomain {
+            size: size as u64,
+            two_adicity,
+            small_subgroup_adicity,
+            size_as_field_element,
+            size_inv,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+            generator_inv: F::multiplicative_generator().inverse()?,
+        })
+    }
+
+    /// Return the size of a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients.
+    ///
+    /// This is the smallest `q^j * 2^i` that is at least `num_coeffs`, for the small subgroup base `q`.
+    pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        let small_subgroup_base = F::FftParameters::SMALL_SUBGROUP_BASE? as usize;
+        let small_subgroup_adicity = F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY?;
+
+        let mut best = None;
+        let mut small_part = 1usize;
+        for _ in 0..=small_subgroup_adicity {
+            // Round up the remaining factor to the next power of two.
+            let two_part = ((num_coeffs + small_part - 1) / small_part).next_power_of_two();
+            if two_part.trailing_zeros() <= F::FftParameters::TWO_ADICITY {
+                if let Some(size) = two_part.checked_mul(small_part) {
+                    best = Some(best.map_or(size, |best: usize| best.min(size)));
+                }
+            }
+            small_part = match small_part.checked_mul(small_subgroup_base) {
+                Some(small_part) => small_part,
+                None => break,
+            };
+        }
+        best
+    }
+
+    /// Return the size of `self`.
+    pub fn size(&self) -> usize {
+        self.size as usize
+    }
+
+    /// Compute an FFT.
+    pub fn fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Compute an FFT, modifying the vector in place.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        execute_with_max_available_threads(|| {
+            coeffs.resize(self.size(), T::zero());
+            *coeffs = self.mixed_radix_fft(coeffs, self.group_gen, self.small_subgroup_adicity, false);
+        });
+    }
+
+    /// Compute an IFFT.
+    pub fn ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// Compute an IFFT, modifying the vector in place.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        execute_with_max_available_threads(|| {
+            evals.resize(self.size(), T::zero());
+            *evals = self.mixed_radix_fft(evals, self.group_gen_inv, self.small_subgroup_adicity, true);
+        });
+    }
+
+    /// Compute an FFT over a coset of the domain.
+    pub fn coset_fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        self.coset_fft_with_offset(coeffs, F::multiplicative_generator())
+    }
+
+    /// Compute an FFT over the coset `offset * H` of the domain `H`.
+    pub fn coset_fft_with_offset<T: DomainCoeff<F>>(&self, coeffs: &[T], offset: F) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.coset_fft_in_place_with_offset(&mut coeffs, offset);
+        coeffs
+    }
+
+    /// Compute an FFT over a coset of the domain, modifying the input vector
+    /// in place.
+    pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        self.coset_fft_in_place_with_offset(coeffs, F::multiplicative_generator())
+    }
+
+    /// Compute an FFT over the coset `offset * H` of the domain `H`, modifying the input vector in place.
+    pub fn coset_fft_in_place_with_offset<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>, offset: F) {
+        EvaluationDomain::<F>::distribute_powers(coeffs, offset);
+        self.fft_in_place(coeffs);
+    }
+
+    /// Compute an IFFT over a coset of the domain.
+    pub fn coset_ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        self.coset_ifft_with_offset(evals, F::multiplicative_generator())
+    }
+
+    /// Compute an IFFT over the coset `offset * H` of the domain `H`.
+    ///
+    /// Panics if the offset is zero.
+    pub fn coset_ifft_with_offset<T: DomainCoeff<F>>(&self, evals: &[T], offset: F) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.coset_ifft_in_place_with_offset(&mut evals, offset);
+        evals
+    }
+
+    /// Compute an IFFT over a coset of the domain, modifying the input vector in place.
+    pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        self.coset_ifft_in_place_with_offset(evals, F::multiplicative_generator())
+    }
+
+    /// Compute an IFFT over the coset `offset * H` of the domain `H`, modifying the input vector in place.
+    ///
+    /// Panics if the offset is zero.
+    pub fn coset_ifft_in_place_with_offset<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>, offset: F) {
+        let offset_inv = offset.inverse().expect("The coset offset must be non-zero");
+        self.ifft_in_place(evals);
+        EvaluationDomain::<F>::distribute_powers(evals, offset_inv);
+    }
+
+    /// Evaluate all the lagrange polynomials defined by this domain at the point
+    /// `tau`.
+    pub fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
+        lagrange_coefficients(self.size, self.size_inv, self.group_gen, tau)
+    }
+
+    /// Return the sparse vanishing polynomial.
+    pub fn vanishing_polynomial(&self) -> SparsePolynomial<F> {
+        let coeffs = [(0, -F::one()), (self.size(), F::one())];
+        SparsePolynomial::from_coefficients(coeffs)
+    }
+
+    /// This evaluates the vanishing polynomial for this domain at tau.
+    /// For multiplicative subgroups, this polynomial is `z(X) = X^self.size - 1`.
+    pub fn evaluate_vanishing_polynomial(&self, tau: F) -> F {
+        tau.pow([self.size]) - F::one()
+    }
+
+    /// This evaluates the derivative of the vanishing polynomial for this domain at tau.
+    /// For multiplicative subgroups, this polynomial is `z'(X) = self.size * X^(self.size - 1)`.
+    pub fn evaluate_vanishing_polynomial_derivative(&self, tau: F) -> F {
+        self.size_as_field_element * tau.pow([self.size - 1])
+    }
+
+    /// Returns the evaluation of the vanishing polynomial over the coset `offset * H` of the domain `H`,
+    /// where it is the constant `offset^self.size - 1`.
+    pub fn evaluate_vanishing_polynomial_over_coset(&self, offset: F) -> F {
+        self.evaluate_vanishing_polynomial(offset)
+    }
+
+    /// Return an iterator over the elements of the domain.
+    pub fn elements(&self) -> Elements<F> {
+        Elements::new(self.size, self.group_gen)
+    }
+
+    /// Returns the element `g^index` of the domain, without computing the elements before it.
+    pub fn element(&self, index: usize) -> F {
+        self.group_gen.pow([index as u64])
+    }
+
+    /// The target polynomial is the zero polynomial in our
+    /// evaluation domain, so we must perform division over
+    /// a coset.
+    pub fn divide_by_vanishing_poly_on_coset_in_place(&self, evals: &mut [F]) {
+        let i = self.evaluate_vanishing_polynomial_over_coset(F::multiplicative_generator()).inverse().unwrap();
+
+        cfg_iter_mut!(evals).for_each(|eval| *eval *= &i);
+    }
+
+    /// Perform O(n) multiplication of two polynomials that are presented by their
+    /// evaluations in the domain.
+    /// Returns the evaluations of the product over the domain.
+    #[must_use]
+    pub fn mul_polynomials_in_evaluation_domain(&self, self_evals: &[F], other_evals: &[F]) -> Vec<F> {
+        let mut result = self_evals.to_vec();
+
+        cfg_iter_mut!(result).zip_eq(other_evals).for_each(|(a, b)| *a *= b);
+
+        result
+    }
+
+    /// Returns the evaluations of the polynomial with coefficients `coeffs` at the powers of `root`,
+    /// a root of unity of order `coeffs.len() = q^small_subgroup_adicity * 2^i`.
+    ///
+    /// Each level splits the coefficients into `q` interleaved parts, and the radix-2 FFT
+    /// evaluates the parts once the size is a power of two. For the inverse FFT, every level
+    /// scales its output by `1 / q`, and the radix-2 IFFT by the inverse of its size.
+    fn mixed_radix_fft<T: DomainCoeff<F>>(
+        &self,
+        coeffs: &[T],
+        root: F,
+        small_subgroup_adicity: u32,
+        inverse: bool,
+    ) -> Vec<T> {
+        let size = coeffs.len();
+        if small_subgroup_adicity == 0 {
+            // The powers of the root are the radix-2 domain of this size, or their inverses.
+            let domain = EvaluationDomain::new(size).unwrap();
+            debug_assert_eq!(root, if inverse { domain.group_gen_inv } else { domain.group_gen });
+            return if inverse { domain.ifft(coeffs) } else { domain.fft(coeffs) };
+        }
+
+        let q = F::FftParameters::SMALL_SUBGROUP_BASE.unwrap() as usize;
+        let sub_size = size / q;
+
+        // Evaluate the `q` interleaved parts at the powers of `root^q`.
+        let sub_root = root.pow([q as u64]);
+        let parts = cfg_into_iter!(0..q)
+            .map(|j| {
+                let part = coeffs.iter().skip(j).step_by(q).copied().collect::<Vec<_>>();
+                self.mixed_radix_fft(&part, sub_root, small_subgroup_adicity - 1, inverse)
+            })
+            .collect::<Vec<_>>();
+
+        // Combine the parts, as `p(root^i) = sum_j root^(i * j) * p_j(root^(q * i))`.
+        let powers = compute_powers_serial(size, root);
+        let scale = if inverse { F::from(q as u64).inverse().unwrap() } else { F::one() };
+        let mut evals = vec![T::zero(); size];
+        cfg_iter_mut!(evals).enumerate().for_each(|(i, eval)| {
+            for (j, part) in parts.iter().enumerate() {
+                let mut term = part[i % sub_size];
+                term *= powers[(i * j) % size];
+                *eval += term;
+            }
+            *eval *= scale;
+        });
+        evals
+    }
+}
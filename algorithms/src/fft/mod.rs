@@ -17,12 +17,21 @@
 //! This crate implements functions for manipulating polynomials over finite fields,
 //! including FFTs.
 
+pub mod config;
+pub use config::FFTConfig;
+
 pub mod domain;
 pub use domain::EvaluationDomain;
 
 pub mod evaluations;
 pub use evaluations::Evaluations;
 
+pub mod general_domain;
+pub use general_domain::GeneralEvaluationDomain;
+
+pub mod mixed_radix;
+pub use mixed_radix::MixedRadixEvaluationDomain;
+
 pub mod polynomial;
 pub use polynomial::{DensePolynomial, Polynomial, SparsePolynomial};
 
@@ -16,12 +16,13 @@
 
 //! A polynomial represented in coefficient form.
 
-use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
+use crate::fft::{EvaluationDomain, Evaluations, GeneralEvaluationDomain, Polynomial};
 use snarkvm_fields::{Field, PrimeField};
 use snarkvm_utilities::{cfg_iter_mut, serialize::*};
 
 use rand::Rng;
 use std::{
+    borrow::Cow,
     fmt,
     ops::{Add, AddAssign, Deref, DerefMut, Div, Mul, MulAssign, Neg, Sub, SubAssign},
 };
@@ -34,6 +35,14 @@ use rayon::prelude::*;
 
 use super::PolyMultiplier;
 
+/// The number of coefficients in the smaller factor below which `Mul` uses schoolbook multiplication
+/// instead of FFTs.
+const NAIVE_MUL_THRESHOLD: usize = 1 << 6;
+
+/// The number of coefficients in the smaller factor below which `mul_karatsuba` uses schoolbook multiplication
+/// instead of splitting the factors further.
+const KARATSUBA_THRESHOLD: usize = 1 << 4;
+
 /// Stores a polynomial in coefficient form.
 #[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
 #[must_use]
@@ -125,13 +134,22 @@ impl<F: Field> DensePolynomial<F> {
         &self.coeffs
     }
 
+    /// Returns `self` with its leading zero coefficients removed.
+    fn truncated(&self) -> Cow<'_, Self> {
+        match self.coeffs.iter().rposition(|coeff| !coeff.is_zero()) {
+            Some(last) if last + 1 == self.coeffs.len() => Cow::Borrowed(self),
+            Some(last) => Cow::Owned(Self::from_coefficients_slice(&self.coeffs[..=last])),
+            None if self.coeffs.is_empty() => Cow::Borrowed(self),
+            None => Cow::Owned(Self::zero()),
+        }
+    }
+
     /// Perform a naive n^2 multiplication of `self` by `other`.
-    #[cfg(test)]
-    fn naive_mul(&self, other: &Self) -> Self {
+    pub fn naive_mul(&self, other: &Self) -> Self {
         if self.is_zero() || other.is_zero() {
             DensePolynomial::zero()
         } else {
-            let mut result = vec![F::zero(); self.degree() + other.degree() + 1];
+            let mut result = vec![F::zero(); self.coeffs.len() + other.coeffs.len() - 1];
             for (i, self_coeff) in self.coeffs.iter().enumerate() {
                 for (j, other_coeff) in other.coeffs.iter().enumerate() {
                     result[i + j] += *self_coeff * other_coeff;
@@ -140,26 +158,126 @@ impl<F: Field> DensePolynomial<F> {
             DensePolynomial::from_coefficients_vec(result)
         }
     }
+
+    ///
+    /// Multiplies `self` by `other` with Karatsuba's algorithm, in O(n^1.59) field multiplications.
+    ///
+    /// The factors are split in halves recursively, and multiplied with schoolbook multiplication
+    /// once the smaller factor has fewer than `KARATSUBA_THRESHOLD` coefficients. This does not
+    /// require an FFT-friendly field, and has no domain setup, so it suits medium-degree factors.
+    ///
+    pub fn mul_karatsuba(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            DensePolynomial::zero()
+        } else {
+            let mut result = vec![F::zero(); self.coeffs.len() + other.coeffs.len() - 1];
+            karatsuba(&self.coeffs, &other.coeffs, &mut result);
+            DensePolynomial::from_coefficients_vec(result)
+        }
+    }
+
+    ///
+    /// Divides `self` by `divisor`, and returns the quotient and remainder.
+    ///
+    /// The remainder has a degree strictly less than the degree of `divisor`.
+    /// Returns `None` if `divisor` is the zero polynomial.
+    ///
+    pub fn divide_with_remainder(&self, divisor: &Self) -> Option<(Self, Self)> {
+        if divisor.is_zero() {
+            return None;
+        }
+        let dividend = Polynomial::Dense(self.truncated());
+        let divisor = Polynomial::Dense(divisor.truncated());
+        dividend.divide_with_q_and_r(&divisor)
+    }
+}
+
+/// Adds the product of the nonempty coefficient slices `a` and `b` to `result`,
+/// which must hold at least `a.len() + b.len() - 1` coefficients.
+fn karatsuba<F: Field>(a: &[F], b: &[F], result: &mut [F]) {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+
+    if b.len() < KARATSUBA_THRESHOLD {
+        for (i, a_coeff) in a.iter().enumerate() {
+            for (r, b_coeff) in result[i..].iter_mut().zip(b) {
+                *r += *a_coeff * b_coeff;
+            }
+        }
+        return;
+    }
+
+    // Split both factors at the half of the larger factor, as a = a_0 + x^m a_1.
+    let m = (a.len() + 1) / 2;
+    if b.len() <= m {
+        // The smaller factor has no upper half, so the larger factor is multiplied in blocks of its size.
+        for (i, block) in a.chunks(b.len()).enumerate() {
+            karatsuba(block, b, &mut result[i * b.len()..]);
+        }
+        return;
+    }
+    let (a_0, a_1) = a.split_at(m);
+    let (b_0, b_1) = b.split_at(m);
+
+    // a * b = z_0 + x^m ((a_0 + a_1)(b_0 + b_1) - z_0 - z_2) + x^2m z_2, where z_0 = a_0 b_0 and z_2 = a_1 b_1.
+    let mut z_0 = vec![F::zero(); 2 * m - 1];
+    karatsuba(a_0, b_0, &mut z_0);
+    let mut z_2 = vec![F::zero(); a_1.len() + b_1.len() - 1];
+    karatsuba(a_1, b_1, &mut z_2);
+
+    let mut a_sum = a_0.to_vec();
+    a_sum.iter_mut().zip(a_1).for_each(|(s, c)| *s += c);
+    let mut b_sum = b_0.to_vec();
+    b_sum.iter_mut().zip(b_1).for_each(|(s, c)| *s += c);
+    let mut z_1 = vec![F::zero(); 2 * m - 1];
+    karatsuba(&a_sum, &b_sum, &mut z_1);
+    z_1.iter_mut().zip(&z_0).for_each(|(z, c)| *z -= c);
+    z_1.iter_mut().zip(&z_2).for_each(|(z, c)| *z -= c);
+
+    // The coefficients of z_1 beyond the degree of the product are zero, and are skipped.
+    result.iter_mut().zip(&z_0).for_each(|(r, c)| *r += c);
+    result[m..].iter_mut().zip(&z_1).for_each(|(r, c)| *r += c);
+    result[2 * m..].iter_mut().zip(&z_2).for_each(|(r, c)| *r += c);
 }
 
 impl<F: PrimeField> DensePolynomial<F> {
     /// Multiply `self` by the vanishing polynomial for the domain `domain`.
-    pub fn mul_by_vanishing_poly(&self, domain: EvaluationDomain<F>) -> DensePolynomial<F> {
+    pub fn mul_by_vanishing_poly(&self, domain: impl Into<GeneralEvaluationDomain<F>>) -> DensePolynomial<F> {
+        let domain = domain.into();
         let mut shifted = vec![F::zero(); domain.size()];
         shifted.extend_from_slice(&self.coeffs);
         crate::cfg_iter_mut!(shifted[..self.coeffs.len()]).zip_eq(&self.coeffs).for_each(|(s, c)| *s -= c);
         DensePolynomial::from_coefficients_vec(shifted)
     }
 
+    ///
     /// Divide `self` by the vanishing polynomial for the domain `domain`.
     /// Returns the quotient and remainder of the division.
+    ///
+    /// As the vanishing polynomial is `x^n - 1`, this runs in linear time, instead of using long division.
+    ///
     pub fn divide_by_vanishing_poly(
         &self,
-        domain: EvaluationDomain<F>,
+        domain: impl Into<GeneralEvaluationDomain<F>>,
     ) -> Option<(DensePolynomial<F>, DensePolynomial<F>)> {
-        let self_poly = Polynomial::from(self);
-        let vanishing_poly = Polynomial::from(domain.vanishing_polynomial());
-        self_poly.divide_with_q_and_r(&vanishing_poly)
+        let domain_size = domain.into().size();
+        let coeffs = &self.truncated().coeffs;
+
+        if coeffs.len() < domain_size {
+            return Some((DensePolynomial::zero(), DensePolynomial::from_coefficients_slice(coeffs)));
+        }
+
+        // The coefficient of `x^i` in the quotient is the sum of the coefficients of `x^(i + kn)` in `self`, for k >= 1,
+        // which is accumulated from the highest coefficient downwards.
+        let mut quotient = coeffs[domain_size..].to_vec();
+        for i in (0..quotient.len().saturating_sub(domain_size)).rev() {
+            let higher = quotient[i + domain_size];
+            quotient[i] += higher;
+        }
+        // The remainder is `self - quotient * (x^n - 1)`, whose low block is `self + quotient`.
+        let mut remainder = coeffs[..domain_size].to_vec();
+        cfg_iter_mut!(remainder).zip(&quotient).for_each(|(r, q)| *r += q);
+
+        Some((DensePolynomial::from_coefficients_vec(quotient), DensePolynomial::from_coefficients_vec(remainder)))
     }
 
     /// Evaluate `self` over `domain`.
@@ -396,13 +514,12 @@ impl<'a, 'b, F: Field> Div<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
 
     #[inline]
     fn div(self, divisor: &'a DensePolynomial<F>) -> DensePolynomial<F> {
-        let a: Polynomial<_> = self.into();
-        let b: Polynomial<_> = divisor.into();
-        a.divide_with_q_and_r(&b).expect("division failed").0
+        self.divide_with_remainder(divisor).expect("division failed").0
     }
 }
 
-/// Performs O(nlogn) multiplication of polynomials if F is smooth.
+/// Performs O(nlogn) multiplication of polynomials if F is smooth,
+/// falling back to schoolbook multiplication when either polynomial is small.
 impl<'a, 'b, F: PrimeField> Mul<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
     type Output = DensePolynomial<F>;
 
@@ -412,9 +529,13 @@ impl<'a, 'b, F: PrimeField> Mul<&'a DensePolynomial<F>> for &'b DensePolynomial<
         if self.is_zero() || other.is_zero() {
             DensePolynomial::zero()
         } else {
+            let (a, b) = (self.truncated(), other.truncated());
+            if a.coeffs.len().min(b.coeffs.len()) < NAIVE_MUL_THRESHOLD {
+                return a.naive_mul(&b);
+            }
             let mut m = PolyMultiplier::new();
-            m.add_polynomial_ref(self, "");
-            m.add_polynomial_ref(other, "");
+            m.add_polynomial_ref(&a, "");
+            m.add_polynomial_ref(&b, "");
             m.multiply().unwrap()
         }
     }
@@ -473,7 +594,7 @@ impl<F: Field> DerefMut for DensePolynomial<F> {
 
 #[cfg(test)]
 mod tests {
-    use crate::fft::polynomial::*;
+    use crate::fft::{polynomial::*, GeneralEvaluationDomain};
     use snarkvm_curves::bls12_377::Fr;
     use snarkvm_fields::{Field, One, Zero};
     use snarkvm_utilities::rand::UniformRand;
@@ -569,6 +690,100 @@ mod tests {
         }
     }
 
+    #[test]
+    fn divide_product_has_zero_remainder() {
+        let rng = &mut thread_rng();
+        for a_degree in [0, 1, 5, 63, 64, 100] {
+            for b_degree in [0, 1, 7, 63, 64, 90] {
+                let a = DensePolynomial::<Fr>::rand(a_degree, rng);
+                let b = DensePolynomial::<Fr>::rand(b_degree, rng);
+                let (quotient, remainder) = (&a * &b).divide_with_remainder(&b).unwrap();
+                assert_eq!(quotient, a);
+                assert!(remainder.is_zero());
+                assert_eq!(&(&a * &b) / &b, a);
+            }
+        }
+    }
+
+    #[test]
+    fn divide_with_remainder_random() {
+        let rng = &mut thread_rng();
+        for a_degree in 0..40 {
+            for b_degree in 0..40 {
+                let dividend = DensePolynomial::<Fr>::rand(a_degree, rng);
+                let divisor = DensePolynomial::<Fr>::rand(b_degree, rng);
+                let (quotient, remainder) = dividend.divide_with_remainder(&divisor).unwrap();
+                assert_eq!(dividend, &(&divisor * &quotient) + &remainder);
+                assert!(remainder.is_zero() || remainder.degree() < divisor.degree());
+            }
+        }
+    }
+
+    #[test]
+    fn zero_polynomial_operands() {
+        let rng = &mut thread_rng();
+        let zero = DensePolynomial::<Fr>::zero();
+        // A zero polynomial with explicit zero coefficients.
+        let padded_zero = DensePolynomial { coeffs: vec![Fr::zero(); 40] };
+        let p = DensePolynomial::<Fr>::rand(40, rng);
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+
+        for z in [&zero, &padded_zero] {
+            assert_eq!(z * &p, zero);
+            assert_eq!(&p * z, zero);
+            assert_eq!(z * z, zero);
+            assert_eq!(z.naive_mul(&p), zero);
+            assert_eq!(p.naive_mul(z), zero);
+
+            assert_eq!(z.divide_with_remainder(&p).unwrap(), (zero.clone(), zero.clone()));
+            assert!(p.divide_with_remainder(z).is_none());
+            assert!(z.divide_with_remainder(z).is_none());
+            assert_eq!(z.divide_by_vanishing_poly(domain).unwrap(), (zero.clone(), zero.clone()));
+        }
+    }
+
+    #[test]
+    fn leading_zero_coefficients_are_truncated() {
+        let rng = &mut thread_rng();
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+        for (a_degree, b_degree) in [(3, 2), (70, 80), (20, 5)] {
+            let a = DensePolynomial::<Fr>::rand(a_degree, rng);
+            let b = DensePolynomial::<Fr>::rand(b_degree, rng);
+            let pad = |p: &DensePolynomial<Fr>| {
+                let mut coeffs = p.coeffs.clone();
+                coeffs.resize(coeffs.len() + 7, Fr::zero());
+                DensePolynomial { coeffs }
+            };
+            let (padded_a, padded_b) = (pad(&a), pad(&b));
+
+            assert_eq!(&padded_a * &padded_b, &a * &b);
+            assert_eq!(padded_a.naive_mul(&padded_b), a.naive_mul(&b));
+            assert_eq!(padded_a.divide_with_remainder(&padded_b), a.divide_with_remainder(&b));
+            assert_eq!(padded_a.divide_by_vanishing_poly(domain), a.divide_by_vanishing_poly(domain));
+        }
+    }
+
+    #[test]
+    fn divide_by_vanishing_poly_matches_long_division() {
+        let rng = &mut thread_rng();
+        for size in 0..8 {
+            let domain = EvaluationDomain::<Fr>::new(1 << size).unwrap();
+            let vanishing_poly = domain.vanishing_polynomial().into();
+            for degree in [0, (1 << size) - 1, 1 << size, 3 << size, 70] {
+                let p = DensePolynomial::<Fr>::rand(degree, rng);
+                let (quotient, remainder) = p.divide_by_vanishing_poly(domain).unwrap();
+                assert_eq!((quotient.clone(), remainder.clone()), p.divide_with_remainder(&vanishing_poly).unwrap());
+                assert_eq!(p, &quotient.mul_by_vanishing_poly(domain) + &remainder);
+                assert!(remainder.degree() < domain.size());
+
+                // A polynomial that vanishes on the domain leaves no remainder.
+                let (quotient, remainder) = p.mul_by_vanishing_poly(domain).divide_by_vanishing_poly(domain).unwrap();
+                assert_eq!(quotient, p);
+                assert!(remainder.is_zero());
+            }
+        }
+    }
+
     #[test]
     fn evaluate_polynomials() {
         let rng = &mut thread_rng();
@@ -597,6 +812,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mul_karatsuba_random() {
+        let rng = &mut thread_rng();
+        for a_degree in (0..70).chain([100, 255, 256, 500]) {
+            for b_degree in (0..70).step_by(3).chain([127, 300]) {
+                let a = DensePolynomial::<Fr>::rand(a_degree, rng);
+                let b = DensePolynomial::<Fr>::rand(b_degree, rng);
+                assert_eq!(a.mul_karatsuba(&b), a.naive_mul(&b), "Degrees {a_degree} and {b_degree}");
+            }
+        }
+    }
+
+    #[test]
+    fn mul_karatsuba_with_zero_and_leading_zeros() {
+        let rng = &mut thread_rng();
+        let a = DensePolynomial::<Fr>::rand(40, rng);
+        assert!(a.mul_karatsuba(&DensePolynomial::zero()).is_zero());
+        assert!(DensePolynomial::zero().mul_karatsuba(&a).is_zero());
+
+        // Leading zero coefficients do not change the product.
+        let mut padded = a.clone();
+        padded.coeffs.resize(100, Fr::zero());
+        let b = DensePolynomial::<Fr>::rand(30, rng);
+        assert_eq!(padded.mul_karatsuba(&b), a.naive_mul(&b));
+    }
+
+    #[test]
+    fn mul_polynomials_over_mixed_radix_domain() {
+        let rng = &mut thread_rng();
+        // The products of these degrees fit in a mixed-radix domain smaller than the radix-2 domain.
+        for (a_degree, b_degree) in [(2, 2), (10, 12), (600, 700)] {
+            let a = DensePolynomial::<Fr>::rand(a_degree, rng);
+            let b = DensePolynomial::<Fr>::rand(b_degree, rng);
+            let domain = GeneralEvaluationDomain::<Fr>::new(a_degree + b_degree + 2).unwrap();
+            assert!(matches!(domain, GeneralEvaluationDomain::MixedRadix(_)));
+            assert_eq!(&a * &b, a.naive_mul(&b))
+        }
+    }
+
     #[test]
     fn mul_by_vanishing_poly() {
         let rng = &mut thread_rng();
@@ -16,7 +16,11 @@
 
 use std::{borrow::Borrow, collections::BTreeMap};
 
-use crate::fft::domain::{FFTPrecomputation, IFFTPrecomputation};
+use crate::fft::{
+    domain::{FFTPrecomputation, IFFTPrecomputation},
+    GeneralEvaluationDomain,
+    MixedRadixEvaluationDomain,
+};
 
 /// A struct that helps multiply a batch of polynomials
 use super::*;
@@ -72,6 +76,14 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
             Some(DensePolynomial::zero())
         } else {
             let degree = self.polynomials.iter().map(|(_, p)| p.degree() + 1).sum::<usize>();
+
+            // Without evaluations or precomputations fixed to a radix-2 domain, use the smaller domain.
+            if self.evaluations.is_empty() && self.fft_precomputation.is_none() {
+                if let GeneralEvaluationDomain::MixedRadix(domain) = GeneralEvaluationDomain::new(degree)? {
+                    return Some(Self::multiply_over_domain(self.polynomials, domain));
+                }
+            }
+
             let domain = EvaluationDomain::new(degree)?;
             if self.evaluations.iter().any(|(_, e)| e.domain() != domain) {
                 None
@@ -125,6 +137,26 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
         }
     }
 
+    /// Multiplies the given polynomials over the mixed-radix domain.
+    fn multiply_over_domain(
+        polynomials: Vec<(String, Cow<'a, DensePolynomial<F>>)>,
+        domain: MixedRadixEvaluationDomain<F>,
+    ) -> DensePolynomial<F> {
+        let mut pool = ExecutionPool::new();
+        for (_, p) in polynomials {
+            pool.add_job(move || domain.fft(&p.coeffs))
+        }
+        let result = pool
+            .execute_all()
+            .into_iter()
+            .reduce(|mut a, b| {
+                cfg_iter_mut!(a).zip(b).for_each(|(a, b)| *a *= b);
+                a
+            })
+            .unwrap();
+        DensePolynomial::from_coefficients_vec(domain.ifft(&result))
+    }
+
     pub fn element_wise_arithmetic_4_over_domain<T: Borrow<str>>(
         mut self,
         domain: EvaluationDomain<F>,
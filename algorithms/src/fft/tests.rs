@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::fft::{domain::*, DensePolynomial};
+use crate::fft::{domain::*, DensePolynomial, FFTConfig, GeneralEvaluationDomain, MixedRadixEvaluationDomain};
 use rand::Rng;
 use snarkvm_curves::bls12_377::{Fr, G1Projective};
 use snarkvm_fields::{FftField, Field, One, Zero};
@@ -282,6 +282,44 @@ fn parallel_fft_consistency() {
     test_consistency(rng, 10);
 }
 
+#[test]
+fn serial_and_parallel_fft_are_identical() {
+    let rng = &mut test_rng();
+    let (serial, parallel) = (FFTConfig::serial(), FFTConfig::parallel());
+
+    // The largest domains split the butterflies of a chunk across the threads.
+    for log_d in 0..16 {
+        let domain = EvaluationDomain::<Fr>::new(1 << log_d).unwrap();
+        let coeffs = (0..domain.size()).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        let (mut serial_evals, mut parallel_evals) = (coeffs.clone(), coeffs.clone());
+        domain.fft_in_place_with_config(&mut serial_evals, &serial);
+        domain.fft_in_place_with_config(&mut parallel_evals, &parallel);
+        assert_eq!(serial_evals, parallel_evals);
+        assert_eq!(domain.fft(&coeffs), serial_evals);
+
+        domain.ifft_in_place_with_config(&mut serial_evals, &serial);
+        domain.ifft_in_place_with_config(&mut parallel_evals, &parallel);
+        assert_eq!(coeffs, serial_evals);
+        assert_eq!(coeffs, parallel_evals);
+    }
+}
+
+#[test]
+fn fft_precomputation_is_cached() {
+    for log_d in [0, 1, 8, 12] {
+        let domain = EvaluationDomain::<Fr>::new(1 << log_d).unwrap();
+
+        let fft_pc = domain.cached_fft_precomputation();
+        assert!(std::sync::Arc::ptr_eq(&fft_pc, &domain.cached_fft_precomputation()));
+        assert_eq!(domain.precompute_fft(), *fft_pc);
+
+        let ifft_pc = domain.cached_ifft_precomputation();
+        assert!(std::sync::Arc::ptr_eq(&ifft_pc, &domain.cached_ifft_precomputation()));
+        assert_eq!(domain.precompute_ifft(), *ifft_pc);
+    }
+}
+
 #[test]
 fn fft_composition() {
     fn test_fft_composition<
@@ -343,3 +381,185 @@ fn evaluate_over_domain() {
         }
     }
 }
+
+#[test]
+fn mixed_radix_domain_size() {
+    // The smallest 3^j * 2^i at least the number of coefficients, for j at most 1.
+    for (num_coeffs, size) in [(0, 1), (1, 1), (3, 3), (5, 6), (7, 8), (13, 16), (17, 24), (25, 32), (1025, 1536)] {
+        assert_eq!(Some(size), MixedRadixEvaluationDomain::<Fr>::compute_size_of_domain(num_coeffs));
+        assert_eq!(size, MixedRadixEvaluationDomain::<Fr>::new(num_coeffs).unwrap().size());
+    }
+
+    // Ensure the general domain picks the mixed-radix domain only if it is smaller.
+    for (num_coeffs, is_mixed_radix, size) in
+        [(2, false, 2), (3, true, 3), (4, false, 4), (5, true, 6), (7, false, 8), ((1 << 20) + 1, true, 3 << 19)]
+    {
+        let domain = GeneralEvaluationDomain::<Fr>::new(num_coeffs).unwrap();
+        assert_eq!(is_mixed_radix, matches!(domain, GeneralEvaluationDomain::MixedRadix(_)));
+        assert_eq!(size, domain.size());
+    }
+
+    // Ensure fields without a small subgroup only have radix-2 domains.
+    type Fq = snarkvm_curves::bls12_377::Fq;
+    assert!(MixedRadixEvaluationDomain::<Fq>::new(5).is_none());
+    assert!(matches!(GeneralEvaluationDomain::<Fq>::new(5).unwrap(), GeneralEvaluationDomain::Radix2(_)));
+}
+
+#[test]
+fn mixed_radix_fft_correctness() {
+    let rng = &mut test_rng();
+
+    for size in [3, 6, 12, 48, 3 << 10] {
+        let domain = MixedRadixEvaluationDomain::<Fr>::new(size).unwrap();
+        assert_eq!(size, domain.size());
+        assert_eq!(size, domain.elements().count());
+
+        let rand_poly = DensePolynomial::<Fr>::rand(size - 1, rng);
+        let poly_evals = domain.fft(&rand_poly.coeffs);
+        let poly_coset_evals = domain.coset_fft(&rand_poly.coeffs);
+
+        // Ensure the evaluations are correct, on a sample of the domain for larger sizes.
+        for (i, x) in domain.elements().enumerate().step_by(size / 3 / 2 + 1) {
+            assert_eq!(poly_evals[i], rand_poly.evaluate(x));
+            assert_eq!(poly_coset_evals[i], rand_poly.evaluate(Fr::multiplicative_generator() * x));
+            assert!(domain.evaluate_vanishing_polynomial(x).is_zero());
+        }
+
+        // Ensure the FFTs round trip.
+        assert_eq!(rand_poly, DensePolynomial::from_coefficients_vec(domain.ifft(&poly_evals)));
+        assert_eq!(rand_poly, DensePolynomial::from_coefficients_vec(domain.coset_ifft(&poly_coset_evals)));
+
+        let v = (0..size).map(|_| G1Projective::rand(rng)).collect::<Vec<_>>();
+        assert_eq!(v, domain.fft(&domain.ifft(&v)));
+        assert_eq!(v, domain.coset_ifft(&domain.coset_fft(&v)));
+    }
+}
+
+#[test]
+fn mixed_radix_lagrange_coefficients_test() {
+    let rng = &mut test_rng();
+
+    for size in [3, 6, 24, 96] {
+        let domain = GeneralEvaluationDomain::<Fr>::new(size).unwrap();
+        assert!(matches!(domain, GeneralEvaluationDomain::MixedRadix(_)));
+
+        // Ensure the interpolation of the evaluations agrees with the Lagrange evaluation at a random point.
+        let evals = (0..size).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let poly = DensePolynomial::from_coefficients_vec(domain.ifft(&evals));
+        let point = domain.sample_element_outside_domain(rng);
+        let lagrange_coeffs = domain.evaluate_all_lagrange_coefficients(point);
+        let interpolated_eval = evals.iter().zip(&lagrange_coeffs).map(|(e, l)| *e * l).sum::<Fr>();
+        assert_eq!(poly.evaluate(point), interpolated_eval);
+
+        // Ensure the vanishing polynomial matches its evaluation, and divides its multiples.
+        let z = domain.vanishing_polynomial();
+        assert_eq!(z.evaluate(point), domain.evaluate_vanishing_polynomial(point));
+        let (q, r) = poly.mul_by_vanishing_poly(domain).divide_by_vanishing_poly(domain).unwrap();
+        assert_eq!(poly, q);
+        assert!(r.is_zero());
+    }
+}
+
+#[test]
+fn coset_fft_with_offset_correctness() {
+    let rng = &mut test_rng();
+
+    for size in [1, 2, 3, 8, 12, 256, 3 << 8] {
+        let domain = GeneralEvaluationDomain::<Fr>::new(size).unwrap();
+        assert_eq!(size, domain.size());
+
+        for offset in [Fr::multiplicative_generator(), Fr::rand(rng)] {
+            // Ensure the coset evaluations are the evaluations at `offset * omega^i`.
+            let poly = DensePolynomial::<Fr>::rand(size - 1, rng);
+            let evals = domain.coset_fft_with_offset(&poly.coeffs, offset);
+            let coset = domain.elements().map(|element| offset * element).collect::<Vec<_>>();
+            assert_eq!(coset.iter().map(|point| poly.evaluate(*point)).collect::<Vec<_>>(), evals);
+
+            // Ensure the coset IFFT round trips.
+            assert_eq!(poly.coeffs, domain.coset_ifft_with_offset(&evals, offset)[..poly.coeffs.len()]);
+
+            // Ensure the vanishing polynomial is constant over the coset.
+            let vanishing = domain.evaluate_vanishing_polynomial_over_coset(offset);
+            assert!(coset.iter().all(|point| domain.evaluate_vanishing_polynomial(*point) == vanishing));
+        }
+
+        // Ensure the default offset is the multiplicative generator.
+        let poly = DensePolynomial::<Fr>::rand(size - 1, rng);
+        let evals = domain.coset_fft(&poly.coeffs);
+        assert_eq!(domain.coset_fft_with_offset(&poly.coeffs, Fr::multiplicative_generator()), evals);
+        assert_eq!(domain.coset_ifft_with_offset(&evals, Fr::multiplicative_generator()), domain.coset_ifft(&evals));
+    }
+}
+
+#[test]
+fn coset_collisions() {
+    let small = EvaluationDomain::<Fr>::new(8).unwrap();
+    let large = EvaluationDomain::<Fr>::new(16).unwrap();
+    let g = Fr::multiplicative_generator();
+
+    // The default cosets of two domains collide.
+    assert!(!small.cosets_are_disjoint(g, &large, g));
+    assert!(small.elements().any(|element| large.elements().any(|other| g * element == g * other)));
+
+    // Ensure another offset for one of the domains separates the cosets, and each coset from both domains.
+    assert!(small.cosets_are_disjoint(g, &large, g.square()));
+    assert!(large.cosets_are_disjoint(g.square(), &small, g));
+    assert!(small.elements().all(|element| large.elements().all(|other| g * element != g.square() * other)));
+    for (domain, offset) in [(small, g), (large, g.square())] {
+        assert!(domain.cosets_are_disjoint(offset, &small, Fr::one()));
+        assert!(domain.cosets_are_disjoint(offset, &large, Fr::one()));
+    }
+
+    // Ensure offsets that differ by an element of the larger domain collide.
+    assert!(!small.cosets_are_disjoint(g, &large, g * large.group_gen));
+}
+
+#[test]
+fn elements_are_lazy_and_exact_size() {
+    for size in [1, 2, 8, 256, 1 << 12] {
+        let domain = EvaluationDomain::<Fr>::new(size).unwrap();
+        let expected = (0..size).map(|i| domain.group_gen.pow([i as u64])).collect::<Vec<_>>();
+
+        let mut elements = domain.elements();
+        assert_eq!(size, elements.len());
+        elements.next();
+        assert_eq!(size - 1, elements.len());
+        assert_eq!(expected, domain.elements().collect::<Vec<_>>());
+
+        for index in [0, size / 2, size - 1] {
+            assert_eq!(expected[index], domain.element(index));
+        }
+        assert_eq!(Fr::one(), domain.element(size));
+    }
+
+    let domain = GeneralEvaluationDomain::<Fr>::new(12).unwrap();
+    let elements = domain.elements().collect::<Vec<_>>();
+    assert_eq!(12, elements.len());
+    assert!((0..12).all(|index| elements[index] == domain.element(index)));
+}
+
+#[test]
+fn domain_serialization() {
+    use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, FromBytes, ToBytes};
+
+    for size in [1, 2, 8, 1 << 20] {
+        let domain = EvaluationDomain::<Fr>::new(size).unwrap();
+
+        // Ensure only the size is serialized, and the domain is reconstructed from it.
+        let mut bytes = vec![];
+        domain.serialize(&mut bytes).unwrap();
+        assert_eq!(8, bytes.len());
+        assert_eq!(domain.serialized_size(), bytes.len());
+        assert_eq!(domain, EvaluationDomain::deserialize(&mut &bytes[..]).unwrap());
+
+        assert_eq!(bytes, domain.to_bytes_le().unwrap());
+        assert_eq!(domain, EvaluationDomain::read_le(&bytes[..]).unwrap());
+    }
+
+    // Ensure sizes that are not a power of two, or exceed the two-adicity of the field, fail.
+    for size in [0u64, 3, 12, 1 << 48] {
+        let bytes = size.to_bytes_le().unwrap();
+        assert!(EvaluationDomain::<Fr>::deserialize(&mut &bytes[..]).is_err());
+        assert!(EvaluationDomain::<Fr>::read_le(&bytes[..]).is_err());
+    }
+}
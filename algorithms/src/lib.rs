@@ -29,6 +29,9 @@ extern crate thiserror;
 
 pub use snarkvm_utilities::{cfg_chunks, cfg_chunks_mut, cfg_into_iter, cfg_iter, cfg_iter_mut, cfg_reduce};
 
+#[cfg(feature = "accumulator")]
+pub mod accumulator;
+
 #[cfg(feature = "commitment")]
 pub mod commitment;
 
@@ -59,12 +62,24 @@ pub mod polycommit;
 #[cfg(feature = "prf")]
 pub mod prf;
 
+#[cfg(feature = "range_proof")]
+pub mod range_proof;
+
+#[cfg(feature = "shamir")]
+pub mod shamir;
+
 #[cfg(feature = "signature")]
 pub mod signature;
 
 #[cfg(feature = "snark")]
 pub mod snark;
 
+#[cfg(feature = "vdf")]
+pub mod vdf;
+
+#[cfg(feature = "vrf")]
+pub mod vrf;
+
 pub mod traits;
 pub use traits::*;
 
@@ -0,0 +1,230 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::{MerklePath, MerkleTreeDigest},
+    traits::MerkleParameters,
+};
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use std::{
+    io::{Read, Result as IoResult, Write},
+    sync::Arc,
+};
+
+///
+/// An append-only Merkle tree of depth `P::DEPTH`, which only stores the frontier of its rightmost leaf.
+///
+/// The root after each append is equal to the root of the dense `MerkleTree` of the same leaves.
+/// As in the dense tree, the leaves are padded with empty hashes up to the next power of two,
+/// and the resulting subtree is padded with empty hashes up to the depth of the tree.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IncrementalMerkleTree<P: MerkleParameters> {
+    /// The number of appended leaves.
+    num_leaves: u64,
+    /// The hash of the most recently appended leaf.
+    last_leaf_hash: MerkleTreeDigest<P>,
+    /// For each height, the most recent complete left subtree, which is the left sibling on the rightmost path.
+    frontier: Vec<MerkleTreeDigest<P>>,
+    /// The computed root of the tree.
+    root: MerkleTreeDigest<P>,
+    /// The hashes of the empty subtrees, from the empty leaf up to a tree of depth `P::DEPTH`.
+    empty_hashes: Vec<MerkleTreeDigest<P>>,
+    /// The Merkle tree parameters (e.g. the hash function).
+    parameters: Arc<P>,
+}
+
+impl<P: MerkleParameters> IncrementalMerkleTree<P> {
+    pub const DEPTH: usize = P::DEPTH;
+
+    /// Initializes an empty incremental Merkle tree.
+    pub fn new(parameters: Arc<P>) -> Result<Self, MerkleError> {
+        let empty_hash = parameters.hash_empty()?;
+        Self::from_frontier(parameters, 0, empty_hash, vec![empty_hash; Self::DEPTH])
+    }
+
+    #[inline]
+    pub fn root(&self) -> &MerkleTreeDigest<P> {
+        &self.root
+    }
+
+    /// Returns the number of appended leaves.
+    #[inline]
+    pub fn num_leaves(&self) -> u64 {
+        self.num_leaves
+    }
+
+    /// Appends a leaf to the tree.
+    pub fn append<L: ToBytes>(&mut self, leaf: &L) -> Result<(), MerkleError> {
+        // Ensure the tree is not full.
+        let index = self.num_leaves;
+        if Self::DEPTH < 64 && index >> Self::DEPTH != 0 {
+            return Err(MerkleError::InvalidTreeDepth(Self::DEPTH + 1, Self::DEPTH));
+        }
+
+        let leaf_hash = self.parameters.hash_leaf(leaf)?;
+
+        // Store the subtree that is completed by this leaf, for as long as it is a left child.
+        let mut current_hash = leaf_hash;
+        for height in 0..Self::DEPTH {
+            if (index >> height) & 1 == 0 {
+                self.frontier[height] = current_hash;
+                break;
+            }
+            current_hash = self.parameters.hash_inner_node(&self.frontier[height], &current_hash)?;
+        }
+
+        self.num_leaves += 1;
+        self.last_leaf_hash = leaf_hash;
+        self.root = self.compute_root()?;
+        Ok(())
+    }
+
+    /// Returns a proof of membership for the most recently appended leaf.
+    pub fn generate_last_leaf_proof(&self) -> Result<MerklePath<P>, MerkleError> {
+        let index = match self.num_leaves.checked_sub(1) {
+            Some(index) => index,
+            None => return Err(MerkleError::MissingLeaf("The incremental Merkle tree is empty".to_string())),
+        };
+        let path = (0..Self::DEPTH).map(|height| self.sibling(index, height)).collect();
+        Ok(MerklePath { parameters: self.parameters.clone(), path, leaf_index: index })
+    }
+
+    /// Initializes a tree from its frontier, and computes its root.
+    fn from_frontier(
+        parameters: Arc<P>,
+        num_leaves: u64,
+        last_leaf_hash: MerkleTreeDigest<P>,
+        frontier: Vec<MerkleTreeDigest<P>>,
+    ) -> Result<Self, MerkleError> {
+        if frontier.len() != Self::DEPTH {
+            return Err(MerkleError::InvalidTreeDepth(frontier.len(), Self::DEPTH));
+        }
+        if Self::DEPTH < 64 && num_leaves > 1 << Self::DEPTH {
+            return Err(MerkleError::InvalidTreeDepth(Self::DEPTH + 1, Self::DEPTH));
+        }
+
+        // Compute the hash of an empty subtree at each height.
+        let mut empty_hashes = Vec::with_capacity(Self::DEPTH + 1);
+        empty_hashes.push(parameters.hash_empty()?);
+        for height in 0..Self::DEPTH {
+            empty_hashes.push(parameters.hash_inner_node(&empty_hashes[height], &empty_hashes[height])?);
+        }
+
+        let mut tree = Self { num_leaves, last_leaf_hash, frontier, root: empty_hashes[0], empty_hashes, parameters };
+        tree.root = tree.compute_root()?;
+        Ok(tree)
+    }
+
+    /// Returns the height of the subtree that holds the leaves, before it is padded to `P::DEPTH`.
+    fn subtree_depth(&self) -> usize {
+        match self.num_leaves {
+            0 => 0,
+            num_leaves => (u64::BITS - (num_leaves - 1).leading_zeros()) as usize,
+        }
+    }
+
+    /// Returns the sibling at the given height on the path of the leaf at the given index,
+    /// which must be the most recently appended leaf.
+    fn sibling(&self, index: u64, height: usize) -> MerkleTreeDigest<P> {
+        match height < self.subtree_depth() {
+            // Left siblings are on the frontier, and right siblings are empty subtrees.
+            true => match (index >> height) & 1 == 1 {
+                true => self.frontier[height],
+                false => self.empty_hashes[height],
+            },
+            // Above the subtree, the sibling is the empty hash, as in the dense tree.
+            false => self.empty_hashes[0],
+        }
+    }
+
+    /// Returns the root, from the path of the most recently appended leaf.
+    fn compute_root(&self) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        let index = self.num_leaves.saturating_sub(1);
+        let mut current_hash = match self.num_leaves {
+            0 => self.empty_hashes[0],
+            _ => self.last_leaf_hash,
+        };
+        for height in 0..Self::DEPTH {
+            let sibling_hash = self.sibling(index, height);
+            current_hash = match (index >> height) & 1 == 0 {
+                true => self.parameters.hash_inner_node(&current_hash, &sibling_hash)?,
+                false => self.parameters.hash_inner_node(&sibling_hash, &current_hash)?,
+            };
+        }
+        Ok(current_hash)
+    }
+}
+
+impl<P: MerkleParameters> FromBytes for IncrementalMerkleTree<P> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // As in `MerklePath`, the parameters are derived from the setup message.
+        let parameters = {
+            // Decode the setup message size.
+            let setup_message_length = u16::read_le(&mut reader)?;
+
+            let mut setup_message_bytes = vec![0u8; setup_message_length as usize];
+            reader.read_exact(&mut setup_message_bytes)?;
+            let setup_message = String::from_utf8(setup_message_bytes)
+                .map_err(|_| error("Failed to parse setup message for Merkle parameters"))?;
+
+            Arc::new(P::setup(&setup_message))
+        };
+
+        let num_leaves: u64 = FromBytes::read_le(&mut reader)?;
+        let last_leaf_hash = FromBytes::read_le(&mut reader)?;
+
+        // Decode the frontier.
+        let frontier_length: u8 = FromBytes::read_le(&mut reader)?;
+        let mut frontier = Vec::with_capacity(frontier_length as usize);
+        for _ in 0..frontier_length {
+            frontier.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        Self::from_frontier(parameters, num_leaves, last_leaf_hash, frontier).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<P: MerkleParameters> ToBytes for IncrementalMerkleTree<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let setup_message_bytes: &[u8] = self.parameters.setup_message().as_bytes();
+
+        // Ensure the setup message size is within bounds.
+        if setup_message_bytes.len() > (u16::MAX as usize) {
+            return Err(error(format!("Merkle tree setup message cannot exceed {} bytes", u16::MAX)));
+        }
+
+        // Encode the setup message.
+        (setup_message_bytes.len() as u16).write_le(&mut writer)?;
+        setup_message_bytes.write_le(&mut writer)?;
+
+        self.num_leaves.write_le(&mut writer)?;
+        self.last_leaf_hash.write_le(&mut writer)?;
+
+        // Ensure the frontier length is within bounds.
+        if self.frontier.len() > (u8::MAX as usize) {
+            return Err(error(format!("Merkle tree depth cannot exceed {}", u8::MAX)));
+        }
+
+        // Encode the frontier.
+        (self.frontier.len() as u8).write_le(&mut writer)?;
+        self.frontier.write_le(&mut writer)
+    }
+}
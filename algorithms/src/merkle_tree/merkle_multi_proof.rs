@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::MerkleTreeDigest,
+    traits::MerkleParameters,
+};
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use std::{
+    io::{Read, Result as IoResult, Write},
+    sync::Arc,
+};
+
+///
+/// Stores the hashes needed to open several leaves of a Merkle tree at once.
+///
+/// The leaf indices are sorted and distinct. The siblings are ordered from the leaves to the root,
+/// and from left to right within each level. A sibling is omitted when it is itself an ancestor of
+/// an opened leaf, so that each hash is stored at most once.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleMultiProof<P: MerkleParameters> {
+    pub parameters: Arc<P>,
+    pub leaf_indices: Vec<u64>,
+    pub siblings: Vec<MerkleTreeDigest<P>>,
+}
+
+impl<P: MerkleParameters> MerkleMultiProof<P> {
+    ///
+    /// Returns `true` if the given leaves are in the tree with the given root, at the given indices.
+    ///
+    /// The leaves may be given in any order, and may repeat, but must cover exactly the leaf indices of the proof.
+    ///
+    pub fn verify_batch<L: ToBytes>(
+        &self,
+        root_hash: &MerkleTreeDigest<P>,
+        leaves_with_indices: &[(usize, L)],
+    ) -> Result<bool, MerkleError> {
+        // Hash the leaves, and sort them by index.
+        let mut nodes = leaves_with_indices
+            .iter()
+            .map(|(index, leaf)| Ok((*index as u64, self.parameters.hash_leaf(leaf)?)))
+            .collect::<Result<Vec<_>, MerkleError>>()?;
+        nodes.sort_by_key(|(index, _)| *index);
+
+        // Remove repeated leaves, and ensure each index is opened with a single leaf.
+        nodes.dedup();
+        if nodes.iter().map(|(index, _)| index).ne(self.leaf_indices.iter()) {
+            return Ok(false);
+        }
+        if nodes.is_empty() || P::DEPTH < 64 && nodes.iter().any(|(index, _)| index >> P::DEPTH != 0) {
+            return Ok(false);
+        }
+
+        // Compute the nodes of each level from the leaves to the root, consuming the siblings in order.
+        let mut siblings = self.siblings.iter();
+        for _ in 0..P::DEPTH {
+            let mut parents = Vec::with_capacity(nodes.len());
+            let mut i = 0;
+            while i < nodes.len() {
+                let (index, hash) = nodes[i];
+                let (left, right) = match nodes.get(i + 1) {
+                    // The sibling is known, as it is the next node.
+                    Some((next_index, next_hash)) if index & 1 == 0 && *next_index == index + 1 => {
+                        i += 1;
+                        (hash, *next_hash)
+                    }
+                    // Otherwise, the sibling is the next hash of the proof.
+                    _ => match siblings.next() {
+                        Some(sibling) if index & 1 == 0 => (hash, *sibling),
+                        Some(sibling) => (*sibling, hash),
+                        None => return Ok(false),
+                    },
+                };
+                parents.push((index >> 1, self.parameters.hash_inner_node(&left, &right)?));
+                i += 1;
+            }
+            nodes = parents;
+        }
+
+        // Ensure every sibling is consumed, and the final hash is the root.
+        Ok(siblings.next().is_none() && nodes.len() == 1 && &nodes[0].1 == root_hash)
+    }
+}
+
+impl<P: MerkleParameters> FromBytes for MerkleMultiProof<P> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // As in `MerklePath`, the parameters are derived from the setup message.
+        let parameters = {
+            // Decode the setup message size.
+            let setup_message_length = u16::read_le(&mut reader)?;
+
+            let mut setup_message_bytes = vec![0u8; setup_message_length as usize];
+            reader.read_exact(&mut setup_message_bytes)?;
+            let setup_message = String::from_utf8(setup_message_bytes)
+                .map_err(|_| error("Failed to parse setup message for Merkle parameters"))?;
+
+            Arc::new(P::setup(&setup_message))
+        };
+
+        // Decode the leaf indices.
+        let num_leaf_indices: u32 = FromBytes::read_le(&mut reader)?;
+        let mut leaf_indices = Vec::with_capacity(num_leaf_indices as usize);
+        for _ in 0..num_leaf_indices {
+            leaf_indices.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        // Decode the siblings.
+        let num_siblings: u32 = FromBytes::read_le(&mut reader)?;
+        let mut siblings = Vec::with_capacity(num_siblings as usize);
+        for _ in 0..num_siblings {
+            siblings.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        Ok(Self { parameters, leaf_indices, siblings })
+    }
+}
+
+impl<P: MerkleParameters> ToBytes for MerkleMultiProof<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let setup_message_bytes: &[u8] = self.parameters.setup_message().as_bytes();
+
+        // Ensure the setup message size is within bounds.
+        if setup_message_bytes.len() > (u16::MAX as usize) {
+            return Err(error(format!("Merkle multi-proof setup message cannot exceed {} bytes", u16::MAX)));
+        }
+
+        // Encode the setup message.
+        (setup_message_bytes.len() as u16).write_le(&mut writer)?;
+        setup_message_bytes.write_le(&mut writer)?;
+
+        // Ensure the number of leaf indices and siblings are within bounds.
+        if self.leaf_indices.len() > (u32::MAX as usize) || self.siblings.len() > (u32::MAX as usize) {
+            return Err(error(format!("Merkle multi-proof cannot exceed {} leaves or siblings", u32::MAX)));
+        }
+
+        // Encode the leaf indices.
+        (self.leaf_indices.len() as u32).write_le(&mut writer)?;
+        self.leaf_indices.write_le(&mut writer)?;
+
+        // Encode the siblings.
+        (self.siblings.len() as u32).write_le(&mut writer)?;
+        self.siblings.write_le(&mut writer)
+    }
+}
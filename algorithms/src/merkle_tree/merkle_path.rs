@@ -45,12 +45,24 @@ impl<P: MerkleParameters> MerklePath<P> {
     }
 
     pub fn verify<L: ToBytes>(&self, root_hash: &MerkleTreeDigest<P>, leaf: &L) -> Result<bool, MerkleError> {
+        self.verify_leaf_hash(root_hash, &self.parameters.hash_leaf::<L>(leaf)?)
+    }
+
+    /// Returns `true` if the leaf at `self.leaf_index` is empty in the tree with the given root.
+    pub fn verify_non_membership(&self, root_hash: &MerkleTreeDigest<P>) -> Result<bool, MerkleError> {
+        self.verify_leaf_hash(root_hash, &self.parameters.hash_empty()?)
+    }
+
+    /// Returns `true` if the leaf at `self.leaf_index` has the given hash in the tree with the given root.
+    pub fn verify_leaf_hash(
+        &self,
+        root_hash: &MerkleTreeDigest<P>,
+        claimed_leaf_hash: &MerkleTreeDigest<P>,
+    ) -> Result<bool, MerkleError> {
         // Check that the given leaf matches the leaf in the membership proof.
         if self.path.len() == P::DEPTH {
-            let claimed_leaf_hash = self.parameters.hash_leaf::<L>(leaf)?;
-
             let mut index = self.leaf_index;
-            let mut curr_path_node = claimed_leaf_hash;
+            let mut curr_path_node = *claimed_leaf_hash;
 
             // Check levels between leaf level and root.
             for level in 0..self.path.len() {
@@ -94,6 +106,16 @@ impl<P: MerkleParameters> MerklePath<P> {
         Ok((*left_bytes, *right_bytes))
     }
 
+    /// Returns the number of bytes in the encoding of a Merkle path of the given depth, for the given parameters.
+    ///
+    /// The encoding consists of the length-prefixed setup message, the length-prefixed siblings
+    /// (ordered from the leaf to the root), and the leaf index as a little-endian `u64`.
+    pub fn size_in_bytes(parameters: &P, depth: usize) -> Result<usize> {
+        let setup_message_size = 2 + parameters.setup_message().len();
+        let path_size = 1 + depth * MerkleTreeDigest::<P>::default().to_bytes_le()?.len();
+        Ok(setup_message_size + path_size + 8)
+    }
+
     /// The position of on_path node in `leaf_and_sibling_hash` and `non_leaf_and_sibling_hash_path`.
     /// `position[i]` is 0 (false) iff `i`th on-path node from top to bottom is on the left.
     ///
@@ -135,15 +157,22 @@ impl<P: MerkleParameters> FromBytes for MerklePath<P> {
             Arc::new(P::setup(&setup_message))
         };
 
-        // Decode the Merkle path depth.
+        // Decode the Merkle path depth, and ensure it matches the depth of the tree.
         let path_length: u8 = FromBytes::read_le(&mut reader)?;
+        if path_length as usize != P::DEPTH {
+            return Err(error(format!("Merkle path depth must be {}, found {}", P::DEPTH, path_length)));
+        }
 
         let mut path = Vec::with_capacity(path_length as usize);
         for _ in 0..path_length {
             path.push(FromBytes::read_le(&mut reader)?);
         }
 
+        // Decode the leaf index, and ensure it is within the tree.
         let leaf_index: u64 = FromBytes::read_le(&mut reader)?;
+        if P::DEPTH < 64 && leaf_index >> P::DEPTH != 0 {
+            return Err(error(format!("Merkle path leaf index {} exceeds depth {}", leaf_index, P::DEPTH)));
+        }
 
         Ok(Self { parameters, path, leaf_index })
     }
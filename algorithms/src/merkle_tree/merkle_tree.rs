@@ -16,15 +16,19 @@
 
 use crate::{
     errors::MerkleError,
-    merkle_tree::{MerklePath, MerkleTreeDigest},
+    merkle_tree::{MerkleMultiProof, MerklePath, MerkleTreeDigest},
     traits::{MerkleParameters, CRH},
 };
 use snarkvm_utilities::ToBytes;
-use std::sync::Arc;
+use std::{collections::BTreeSet, sync::Arc};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// The minimum number of hashes in a row of the Merkle tree for the row to be hashed in parallel.
+/// Smaller rows are hashed serially, as the overhead of parallelism outweighs its benefit.
+pub const MERKLE_TREE_PARALLEL_THRESHOLD: usize = 64;
+
 #[derive(Default)]
 pub struct MerkleTree<P: MerkleParameters> {
     /// The computed root of the full Merkle tree.
@@ -44,6 +48,18 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
     pub const DEPTH: usize = P::DEPTH;
 
     pub fn new<L: ToBytes + Send + Sync>(parameters: Arc<P>, leaves: &[L]) -> Result<Self, MerkleError> {
+        Self::new_with_parallel_threshold(parameters, leaves, MERKLE_TREE_PARALLEL_THRESHOLD)
+    }
+
+    /// Returns a new Merkle tree over the given leaves, where the rows of the tree
+    /// with at least `parallel_threshold` hashes are hashed in parallel.
+    ///
+    /// The resulting tree is independent of the threshold.
+    pub fn new_with_parallel_threshold<L: ToBytes + Send + Sync>(
+        parameters: Arc<P>,
+        leaves: &[L],
+        parallel_threshold: usize,
+    ) -> Result<Self, MerkleError> {
         let new_time = start_timer!(|| "MerkleTree::new");
 
         let last_level_size = leaves.len().next_power_of_two();
@@ -69,7 +85,7 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         // Compute and store the hash values for each leaf.
         let last_level_index = level_indices.pop().unwrap_or(0);
 
-        let subsection = Self::hash_leaf_row(&*parameters, leaves)?;
+        let subsection = Self::hash_leaf_row(&*parameters, leaves, parallel_threshold)?;
         if !subsection.is_empty() {
             tree[last_level_index..last_level_index + subsection.len()].copy_from_slice(&subsection[..]);
         }
@@ -82,7 +98,7 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
             let hashings =
                 (start_index..upper_bound).map(|i| (&tree[left_child(i)], &tree[right_child(i)])).collect::<Vec<_>>();
 
-            let subsection = Self::hash_two_to_one_row(&*parameters, &hashings[..])?;
+            let subsection = Self::hash_two_to_one_row(&*parameters, &hashings[..], parallel_threshold)?;
             if !subsection.is_empty() {
                 tree[start_index..start_index + subsection.len()].copy_from_slice(&subsection[..]);
             }
@@ -144,7 +160,7 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         tree[last_level_index..][..start_index].clone_from_slice(&self.hashed_leaves()[..start_index]);
 
         // The new leaves require hashing.
-        let subsection = Self::hash_leaf_row(&*self.parameters, new_leaves)?;
+        let subsection = Self::hash_leaf_row(&*self.parameters, new_leaves, MERKLE_TREE_PARALLEL_THRESHOLD)?;
         if !subsection.is_empty() {
             tree[last_level_index + start_index..last_level_index + start_index + subsection.len()]
                 .copy_from_slice(&subsection[..]);
@@ -284,23 +300,158 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         }
     }
 
+    ///
+    /// Returns a proof of membership for the leaves at the given indices.
+    ///
+    /// Repeated indices are opened once. Each sibling that is needed by the openings is stored once,
+    /// and siblings that are computed from the opened leaves are omitted.
+    ///
+    pub fn prove_batch(&self, indices: &[usize]) -> Result<MerkleMultiProof<P>, MerkleError> {
+        let prove_time = start_timer!(|| "MerkleTree::prove_batch");
+
+        // Ensure each index is within the last level of the tree.
+        if let Some(index) = indices.iter().find(|index| **index >= self.hashed_leaves().len()) {
+            return Err(MerkleError::IncorrectLeafIndex(*index));
+        }
+
+        let mut leaf_indices = indices.iter().map(|index| *index as u64).collect::<Vec<_>>();
+        leaf_indices.sort_unstable();
+        leaf_indices.dedup();
+        if leaf_indices.is_empty() {
+            return Err(MerkleError::Message("Merkle multi-proofs require at least one leaf".to_string()));
+        }
+
+        // Collect the siblings of each level from the leaves to the root, skipping siblings that are computed.
+        let tree_depth = tree_depth(self.tree.len());
+        let empty_hash = self.parameters.hash_empty()?;
+        let mut siblings = vec![];
+        let mut nodes = leaf_indices.clone();
+        for height in 0..Self::DEPTH {
+            let mut parents = Vec::with_capacity(nodes.len());
+            let mut i = 0;
+            while i < nodes.len() {
+                let index = nodes[i];
+                match nodes.get(i + 1) {
+                    Some(next_index) if index & 1 == 0 && *next_index == index + 1 => i += 1,
+                    // Above the full tree, the sibling is the empty hash, as in `generate_proof`.
+                    _ => siblings.push(match height < tree_depth {
+                        true => self.tree[(1 << (tree_depth - height)) - 1 + (index ^ 1) as usize],
+                        false => empty_hash,
+                    }),
+                }
+                parents.push(index >> 1);
+                i += 1;
+            }
+            nodes = parents;
+        }
+
+        end_timer!(prove_time);
+
+        Ok(MerkleMultiProof { parameters: self.parameters.clone(), leaf_indices, siblings })
+    }
+
+    /// Updates the leaf at the given index, and returns the new root.
+    pub fn update_leaf<L: ToBytes + Send + Sync>(
+        &mut self,
+        index: usize,
+        leaf: &L,
+    ) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        self.update_leaves(&[(index, leaf)])
+    }
+
+    ///
+    /// Updates the leaves at the given indices, and returns the new root.
+    ///
+    /// Only the ancestors of the updated leaves are recomputed, and each ancestor is recomputed once.
+    /// If an index appears more than once, the last update is kept. Each index must be within the
+    /// last level of the tree, as `rebuild` is required to extend the tree.
+    ///
+    pub fn update_leaves<L: ToBytes + Send + Sync>(
+        &mut self,
+        updates: &[(usize, L)],
+    ) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        let update_time = start_timer!(|| "MerkleTree::update_leaves");
+
+        // Ensure each index is within the last level of the tree.
+        if let Some((index, _)) = updates.iter().find(|(index, _)| *index >= self.hashed_leaves().len()) {
+            return Err(MerkleError::IncorrectLeafIndex(*index));
+        }
+
+        // Compute the hashes of the new leaves, before altering the tree.
+        let leaves = updates.iter().map(|(_, leaf)| leaf).collect::<Vec<_>>();
+        let leaf_hashes = Self::hash_leaf_row(&*self.parameters, &leaves, MERKLE_TREE_PARALLEL_THRESHOLD)?;
+
+        // Store the new leaves, and collect their parents.
+        let mut indices = BTreeSet::new();
+        for ((index, _), leaf_hash) in updates.iter().zip(leaf_hashes) {
+            let tree_index = self.hashed_leaves_index + index;
+            self.tree[tree_index] = leaf_hash;
+            indices.extend(parent(tree_index));
+        }
+
+        // Recompute the ancestors of the new leaves, one level at a time.
+        while !indices.is_empty() {
+            let mut parents = BTreeSet::new();
+            for &index in &indices {
+                self.tree[index] =
+                    self.parameters.hash_inner_node(&self.tree[left_child(index)], &self.tree[right_child(index)])?;
+                parents.extend(parent(index));
+            }
+            indices = parents;
+        }
+
+        // Recompute the padding above the tree, if the tree has changed.
+        if !updates.is_empty() {
+            let empty_hash = self.parameters.hash_empty()?;
+            let mut current_depth = tree_depth(self.tree.len());
+            let mut current_hash = self.tree[0];
+            self.padding_tree.clear();
+            while current_depth < Self::DEPTH {
+                current_hash = self.parameters.hash_inner_node(&current_hash, &empty_hash)?;
+
+                // do not pad at the top-level of the tree
+                if current_depth < Self::DEPTH - 1 {
+                    self.padding_tree.push((current_hash, empty_hash));
+                }
+                current_depth += 1;
+            }
+            self.root = current_hash;
+        }
+
+        end_timer!(update_time);
+
+        Ok(self.root)
+    }
+
+    #[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
     fn hash_leaf_row<L: ToBytes + Send + Sync>(
         parameters: &P,
         leaves: &[L],
+        parallel_threshold: usize,
     ) -> Result<Vec<<<P as MerkleParameters>::LeafCRH as CRH>::Output>, MerkleError> {
         match leaves.len() {
             0 => Ok(vec![]),
-            _ => crate::cfg_iter!(leaves).map(|leaf| parameters.hash_leaf(&leaf)).collect(),
+            #[cfg(feature = "parallel")]
+            num_leaves if num_leaves >= parallel_threshold => {
+                leaves.par_iter().map(|leaf| parameters.hash_leaf(&leaf)).collect()
+            }
+            _ => leaves.iter().map(|leaf| parameters.hash_leaf(&leaf)).collect(),
         }
     }
 
+    #[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
     fn hash_two_to_one_row<L: ToBytes + Send + Sync>(
         parameters: &P,
         inner: &[L],
+        parallel_threshold: usize,
     ) -> Result<Vec<<<P as MerkleParameters>::TwoToOneCRH as CRH>::Output>, MerkleError> {
         match inner.len() {
             0 => Ok(vec![]),
-            _ => crate::cfg_iter!(inner).map(|inner_nodes| parameters.hash_two_to_one(&inner_nodes)).collect(),
+            #[cfg(feature = "parallel")]
+            num_nodes if num_nodes >= parallel_threshold => {
+                inner.par_iter().map(|inner_nodes| parameters.hash_two_to_one(&inner_nodes)).collect()
+            }
+            _ => inner.iter().map(|inner_nodes| parameters.hash_two_to_one(&inner_nodes)).collect(),
         }
     }
 }
@@ -14,9 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod incremental_merkle_tree;
+pub use incremental_merkle_tree::*;
+
 pub mod masked_merkle_tree_parameters;
 pub use masked_merkle_tree_parameters::*;
 
+pub mod merkle_multi_proof;
+pub use merkle_multi_proof::*;
+
 pub mod merkle_path;
 pub use merkle_path::*;
 
@@ -26,5 +32,11 @@ pub use merkle_tree::*;
 pub mod merkle_tree_parameters;
 pub use merkle_tree_parameters::*;
 
+pub mod nested_merkle_tree;
+pub use nested_merkle_tree::*;
+
+pub mod sparse_merkle_tree;
+pub use sparse_merkle_tree::*;
+
 #[cfg(test)]
 pub mod tests;
@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::{MerklePath, MerkleTree, MerkleTreeDigest},
+    traits::MerkleParameters,
+};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::{
+    io::{Read, Result as IoResult, Write},
+    sync::Arc,
+};
+
+///
+/// A two-layer Merkle tree, where each leaf of the outer tree is the root of an inner tree.
+///
+/// The layers may use different parameters. A leaf is identified by the index of its inner tree,
+/// and its index within the inner tree.
+///
+pub struct NestedMerkleTree<Outer: MerkleParameters, Inner: MerkleParameters> {
+    /// The outer tree, whose leaves are the roots of the inner trees.
+    outer_tree: MerkleTree<Outer>,
+    /// The inner trees, in the order of their roots in the outer tree.
+    inner_trees: Vec<MerkleTree<Inner>>,
+    /// The Merkle tree parameters of the outer tree.
+    outer_parameters: Arc<Outer>,
+    /// The Merkle tree parameters of the inner trees.
+    inner_parameters: Arc<Inner>,
+}
+
+impl<Outer: MerkleParameters, Inner: MerkleParameters> NestedMerkleTree<Outer, Inner> {
+    /// Returns a new nested Merkle tree, with an inner tree for each of the given sets of leaves.
+    pub fn new<L: ToBytes + Send + Sync>(
+        outer_parameters: Arc<Outer>,
+        inner_parameters: Arc<Inner>,
+        subtrees: &[Vec<L>],
+    ) -> Result<Self, MerkleError> {
+        let inner_trees = subtrees
+            .iter()
+            .map(|leaves| MerkleTree::new(inner_parameters.clone(), leaves))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let inner_roots = inner_trees.iter().map(|tree| *tree.root()).collect::<Vec<_>>();
+        let outer_tree = MerkleTree::new(outer_parameters.clone(), &inner_roots)?;
+
+        Ok(Self { outer_tree, inner_trees, outer_parameters, inner_parameters })
+    }
+
+    #[inline]
+    pub fn root(&self) -> &MerkleTreeDigest<Outer> {
+        self.outer_tree.root()
+    }
+
+    /// Returns the outer tree.
+    #[inline]
+    pub fn outer_tree(&self) -> &MerkleTree<Outer> {
+        &self.outer_tree
+    }
+
+    /// Returns the inner tree at the given index, if it exists.
+    #[inline]
+    pub fn inner_tree(&self, subtree_index: usize) -> Option<&MerkleTree<Inner>> {
+        self.inner_trees.get(subtree_index)
+    }
+
+    /// Returns the number of inner trees.
+    #[inline]
+    pub fn num_subtrees(&self) -> usize {
+        self.inner_trees.len()
+    }
+
+    /// Returns a path for the given leaf, at the given index of the given inner tree.
+    pub fn generate_proof<L: ToBytes>(
+        &self,
+        subtree_index: usize,
+        leaf_index: usize,
+        leaf: &L,
+    ) -> Result<NestedMerklePath<Outer, Inner>, MerkleError> {
+        let inner_tree = self.inner_trees.get(subtree_index).ok_or(MerkleError::IncorrectLeafIndex(subtree_index))?;
+        let inner_root = *inner_tree.root();
+
+        let inner = inner_tree.generate_proof(leaf_index, leaf)?;
+        let outer = self.outer_tree.generate_proof(subtree_index, &inner_root)?;
+
+        Ok(NestedMerklePath { inner, inner_root, outer })
+    }
+
+    /// Returns `true` if the given path was produced with the parameters of this tree,
+    /// and opens the given leaf under the root of this tree.
+    pub fn verify<L: ToBytes>(&self, path: &NestedMerklePath<Outer, Inner>, leaf: &L) -> Result<bool, MerkleError> {
+        // Ensure the path uses the parameters of each layer of this tree.
+        if path.outer.parameters != self.outer_parameters || path.inner.parameters != self.inner_parameters {
+            return Ok(false);
+        }
+
+        path.verify(self.root(), leaf)
+    }
+
+    ///
+    /// Updates the leaf at the given index of the given inner tree, and returns the new root.
+    ///
+    /// Only the ancestors of the leaf in its inner tree, and of the inner root in the outer tree, are recomputed.
+    ///
+    pub fn update_leaf<L: ToBytes + Send + Sync>(
+        &mut self,
+        subtree_index: usize,
+        leaf_index: usize,
+        leaf: &L,
+    ) -> Result<MerkleTreeDigest<Outer>, MerkleError> {
+        let inner_tree =
+            self.inner_trees.get_mut(subtree_index).ok_or(MerkleError::IncorrectLeafIndex(subtree_index))?;
+        let inner_root = inner_tree.update_leaf(leaf_index, leaf)?;
+        self.outer_tree.update_leaf(subtree_index, &inner_root)
+    }
+}
+
+///
+/// A path for a leaf of a `NestedMerkleTree`, which consists of the path of the leaf in its inner tree,
+/// the root of the inner tree, and the path of the inner root in the outer tree.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NestedMerklePath<Outer: MerkleParameters, Inner: MerkleParameters> {
+    pub inner: MerklePath<Inner>,
+    pub inner_root: MerkleTreeDigest<Inner>,
+    pub outer: MerklePath<Outer>,
+}
+
+impl<Outer: MerkleParameters, Inner: MerkleParameters> NestedMerklePath<Outer, Inner> {
+    /// Returns `true` if the given leaf is in the nested tree with the given root.
+    pub fn verify<L: ToBytes>(&self, root_hash: &MerkleTreeDigest<Outer>, leaf: &L) -> Result<bool, MerkleError> {
+        Ok(self.inner.verify(&self.inner_root, leaf)? && self.outer.verify(root_hash, &self.inner_root)?)
+    }
+
+    /// Returns the index of the inner tree of the leaf.
+    pub fn subtree_index(&self) -> u64 {
+        self.outer.leaf_index
+    }
+
+    /// Returns the index of the leaf in its inner tree.
+    pub fn leaf_index(&self) -> u64 {
+        self.inner.leaf_index
+    }
+}
+
+impl<Outer: MerkleParameters, Inner: MerkleParameters> ToBytes for NestedMerklePath<Outer, Inner> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.inner.write_le(&mut writer)?;
+        self.inner_root.write_le(&mut writer)?;
+        self.outer.write_le(writer)
+    }
+}
+
+impl<Outer: MerkleParameters, Inner: MerkleParameters> FromBytes for NestedMerklePath<Outer, Inner> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let inner = FromBytes::read_le(&mut reader)?;
+        let inner_root = FromBytes::read_le(&mut reader)?;
+        let outer = FromBytes::read_le(reader)?;
+
+        Ok(Self { inner, inner_root, outer })
+    }
+}
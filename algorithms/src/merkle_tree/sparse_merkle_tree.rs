@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::{MerklePath, MerkleTreeDigest},
+    traits::MerkleParameters,
+};
+use snarkvm_utilities::ToBytes;
+
+use std::{collections::HashMap, sync::Arc};
+
+/// The maximum depth of a sparse Merkle tree, as leaf indices are 64 bits.
+pub const SPARSE_MERKLE_TREE_MAX_DEPTH: usize = 64;
+
+///
+/// A sparse Merkle tree of depth `P::DEPTH`, which only stores its non-empty nodes.
+///
+/// An empty leaf is `P::hash_empty()`, or the hash of a default leaf, and an empty subtree is the hash
+/// of two empty subtrees of the level below. The hashes of the empty subtrees are precomputed once per level.
+/// The root matches the dense `MerkleTree` whenever the dense tree has `P::DEPTH` levels of leaves,
+/// and the proofs of both trees are verified by `MerklePath`.
+///
+#[derive(Clone, Debug)]
+pub struct SparseMerkleTree<P: MerkleParameters> {
+    /// The hashes of the empty subtrees, from the empty leaf up to the empty root.
+    empty_hashes: Vec<MerkleTreeDigest<P>>,
+    /// The non-empty nodes, keyed by their height above the leaves and their index at that height.
+    nodes: HashMap<(usize, u64), MerkleTreeDigest<P>>,
+    /// The Merkle tree parameters (e.g. the hash function).
+    parameters: Arc<P>,
+}
+
+impl<P: MerkleParameters> SparseMerkleTree<P> {
+    pub const DEPTH: usize = P::DEPTH;
+
+    /// Initializes an empty sparse Merkle tree.
+    pub fn new(parameters: Arc<P>) -> Result<Self, MerkleError> {
+        let empty_leaf_hash = parameters.hash_empty()?;
+        Self::new_with_empty_leaf_hash(parameters, empty_leaf_hash)
+    }
+
+    ///
+    /// Initializes a sparse Merkle tree where every leaf is the given default leaf.
+    ///
+    /// The root matches the dense `MerkleTree` of `2^P::DEPTH` default leaves, and the proofs
+    /// that a leaf is the default leaf are checked by `MerklePath::verify` on the default leaf.
+    ///
+    pub fn new_with_default_leaf<L: ToBytes>(parameters: Arc<P>, default_leaf: &L) -> Result<Self, MerkleError> {
+        let empty_leaf_hash = parameters.hash_leaf(default_leaf)?;
+        Self::new_with_empty_leaf_hash(parameters, empty_leaf_hash)
+    }
+
+    /// Initializes a sparse Merkle tree where every leaf has the given hash.
+    fn new_with_empty_leaf_hash(parameters: Arc<P>, empty_leaf_hash: MerkleTreeDigest<P>) -> Result<Self, MerkleError> {
+        if Self::DEPTH > SPARSE_MERKLE_TREE_MAX_DEPTH {
+            return Err(MerkleError::InvalidTreeDepth(Self::DEPTH, SPARSE_MERKLE_TREE_MAX_DEPTH));
+        }
+
+        // Compute the hash of an empty subtree at each height.
+        let mut empty_hashes = Vec::with_capacity(Self::DEPTH + 1);
+        empty_hashes.push(empty_leaf_hash);
+        for height in 0..Self::DEPTH {
+            empty_hashes.push(parameters.hash_inner_node(&empty_hashes[height], &empty_hashes[height])?);
+        }
+
+        Ok(Self { empty_hashes, nodes: HashMap::new(), parameters })
+    }
+
+    #[inline]
+    pub fn root(&self) -> &MerkleTreeDigest<P> {
+        self.node(Self::DEPTH, 0)
+    }
+
+    /// Returns the hash of an empty subtree at the given height above the leaves.
+    #[inline]
+    pub fn empty_hash(&self, height: usize) -> Option<&MerkleTreeDigest<P>> {
+        self.empty_hashes.get(height)
+    }
+
+    /// Returns the number of non-empty leaves.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.keys().filter(|(height, _)| *height == 0).count()
+    }
+
+    /// Returns `true` if every leaf is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the hash of the leaf at the given index, if it is non-empty.
+    #[inline]
+    pub fn get(&self, index: u64) -> Option<&MerkleTreeDigest<P>> {
+        self.nodes.get(&(0, index))
+    }
+
+    /// Inserts a leaf at the given index, which must be empty.
+    pub fn insert<L: ToBytes>(&mut self, index: u64, leaf: &L) -> Result<(), MerkleError> {
+        Self::check_index(index)?;
+        if self.get(index).is_some() {
+            return Err(MerkleError::Message(format!("Leaf {index} is already in the sparse Merkle tree")));
+        }
+        self.set_leaf(index, leaf)
+    }
+
+    /// Updates the leaf at the given index, which must be non-empty.
+    pub fn update<L: ToBytes>(&mut self, index: u64, leaf: &L) -> Result<(), MerkleError> {
+        Self::check_index(index)?;
+        if self.get(index).is_none() {
+            return Err(MerkleError::MissingLeaf(format!("Leaf {index} is not in the sparse Merkle tree")));
+        }
+        self.set_leaf(index, leaf)
+    }
+
+    /// Returns a proof that the non-empty leaf at the given index is in the tree.
+    pub fn generate_proof(&self, index: u64) -> Result<MerklePath<P>, MerkleError> {
+        Self::check_index(index)?;
+        if self.get(index).is_none() {
+            return Err(MerkleError::MissingLeaf(format!("Leaf {index} is not in the sparse Merkle tree")));
+        }
+        Ok(self.path(index))
+    }
+
+    /// Returns a proof that the leaf at the given index is empty, which is checked by `verify_non_membership`,
+    /// or by `verify` on the default leaf.
+    pub fn generate_non_membership_proof(&self, index: u64) -> Result<MerklePath<P>, MerkleError> {
+        Self::check_index(index)?;
+        if self.get(index).is_some() {
+            return Err(MerkleError::Message(format!("Leaf {index} is in the sparse Merkle tree")));
+        }
+        Ok(self.path(index))
+    }
+
+    /// Returns the node at the given height and index, which is an empty subtree if it is not stored.
+    #[inline]
+    fn node(&self, height: usize, index: u64) -> &MerkleTreeDigest<P> {
+        self.nodes.get(&(height, index)).unwrap_or(&self.empty_hashes[height])
+    }
+
+    /// Sets the leaf at the given index, and recomputes its ancestors.
+    fn set_leaf<L: ToBytes>(&mut self, index: u64, leaf: &L) -> Result<(), MerkleError> {
+        let mut current_hash = self.parameters.hash_leaf(leaf)?;
+        let mut current_index = index;
+        self.nodes.insert((0, current_index), current_hash);
+
+        for height in 0..Self::DEPTH {
+            let sibling_hash = self.node(height, current_index ^ 1);
+            current_hash = match current_index & 1 == 0 {
+                true => self.parameters.hash_inner_node(&current_hash, sibling_hash)?,
+                false => self.parameters.hash_inner_node(sibling_hash, &current_hash)?,
+            };
+            current_index >>= 1;
+            self.nodes.insert((height + 1, current_index), current_hash);
+        }
+        Ok(())
+    }
+
+    /// Returns the Merkle path of the leaf at the given index.
+    fn path(&self, index: u64) -> MerklePath<P> {
+        let path = (0..Self::DEPTH).map(|height| *self.node(height, (index >> height) ^ 1)).collect();
+        MerklePath { parameters: self.parameters.clone(), path, leaf_index: index }
+    }
+
+    /// Ensures the given index is a leaf of the tree.
+    #[inline]
+    fn check_index(index: u64) -> Result<(), MerkleError> {
+        match Self::DEPTH < SPARSE_MERKLE_TREE_MAX_DEPTH && index >> Self::DEPTH != 0 {
+            true => Err(MerkleError::IncorrectLeafIndex(index as usize)),
+            false => Ok(()),
+        }
+    }
+}
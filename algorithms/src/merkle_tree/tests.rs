@@ -16,12 +16,22 @@
 
 use crate::{
     crh::{PedersenCRH, PedersenCompressedCRH},
-    merkle_tree::{MerklePath, MerkleTree, MerkleTreeParameters},
+    errors::MerkleError,
+    merkle_tree::{
+        IncrementalMerkleTree,
+        MerkleMultiProof,
+        MerklePath,
+        MerkleTree,
+        MerkleTreeParameters,
+        NestedMerklePath,
+        NestedMerkleTree,
+        SparseMerkleTree,
+    },
     traits::{MerkleParameters, CRH},
 };
 use snarkvm_utilities::{to_bytes_le, FromBytes, ToBytes};
 
-use rand::{thread_rng, Rng};
+use rand::{seq::SliceRandom, thread_rng, Rng};
 
 use std::sync::Arc;
 
@@ -209,6 +219,519 @@ fn run_merkle_path_bincode_test<P: MerkleParameters>() {
     merkle_path_bincode_test::<P, _>(&leaves, parameters);
 }
 
+/// Checks the encoding of Merkle paths, including its size and the rejection of malformed inputs.
+fn merkle_path_encoding_test<P: MerkleParameters>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let leaves = generate_random_leaves!(3, 8);
+    let tree = MerkleTree::<P>::new(parameters.clone(), &leaves).unwrap();
+
+    for (i, leaf) in leaves.iter().enumerate() {
+        let proof = tree.generate_proof(i, leaf).unwrap();
+        let bytes = proof.to_bytes_le().unwrap();
+        assert_eq!(MerklePath::<P>::size_in_bytes(&parameters, P::DEPTH).unwrap(), bytes.len());
+
+        // Ensure the path round trips.
+        let candidate = MerklePath::<P>::read_le(&bytes[..]).unwrap();
+        assert_eq!(proof, candidate);
+        assert!(candidate.verify(tree.root(), leaf).unwrap());
+
+        // Ensure truncated inputs are rejected.
+        for length in 0..bytes.len() {
+            assert!(MerklePath::<P>::read_le(&bytes[..length]).is_err());
+        }
+    }
+
+    // Ensure a path with the wrong depth is rejected.
+    let mut proof = tree.generate_proof(0, &leaves[0]).unwrap();
+    proof.path.pop();
+    assert!(MerklePath::<P>::read_le(&proof.to_bytes_le().unwrap()[..]).is_err());
+
+    // Ensure a leaf index outside of the tree is rejected.
+    if P::DEPTH < 64 {
+        let mut proof = tree.generate_proof(0, &leaves[0]).unwrap();
+        proof.leaf_index = 1 << P::DEPTH;
+        assert!(MerklePath::<P>::read_le(&proof.to_bytes_le().unwrap()[..]).is_err());
+    }
+}
+
+/// Checks that Merkle trees hashed serially and in parallel are identical.
+fn merkle_tree_parallel_threshold_test<P: MerkleParameters>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let mut rng = thread_rng();
+
+    for _ in 0..5 {
+        let num_leaves = rng.gen_range(1..200);
+        let leaves = generate_random_leaves!(num_leaves, 8);
+
+        let serial = MerkleTree::<P>::new_with_parallel_threshold(parameters.clone(), &leaves, usize::MAX).unwrap();
+        let parallel = MerkleTree::<P>::new_with_parallel_threshold(parameters.clone(), &leaves, 0).unwrap();
+        let default = MerkleTree::<P>::new(parameters.clone(), &leaves).unwrap();
+
+        assert_eq!(serial.root(), parallel.root());
+        assert_eq!(serial.root(), default.root());
+        assert_eq!(serial.hashed_leaves(), parallel.hashed_leaves());
+    }
+}
+
+/// Checks that nested Merkle paths verify, reject misuse, and match a rebuilt tree after updates.
+fn nested_merkle_tree_test<Outer: MerkleParameters, Inner: MerkleParameters>() {
+    let outer_parameters = Arc::new(Outer::setup("nested_merkle_tree_outer"));
+    let inner_parameters = Arc::new(Inner::setup("nested_merkle_tree_inner"));
+    let mut rng = thread_rng();
+
+    let mut subtrees = (0..5).map(|_| generate_random_leaves!(rng.gen_range(1..8), 8)).collect::<Vec<_>>();
+    let mut tree = NestedMerkleTree::new(outer_parameters.clone(), inner_parameters.clone(), &subtrees).unwrap();
+
+    // Ensure the paths of random leaves verify.
+    for _ in 0..10 {
+        let subtree_index = rng.gen_range(0..subtrees.len());
+        let leaf_index = rng.gen_range(0..subtrees[subtree_index].len());
+        let leaf = &subtrees[subtree_index][leaf_index];
+
+        let path = tree.generate_proof(subtree_index, leaf_index, leaf).unwrap();
+        assert_eq!(subtree_index as u64, path.subtree_index());
+        assert_eq!(leaf_index as u64, path.leaf_index());
+        assert!(path.verify(tree.root(), leaf).unwrap());
+        assert!(tree.verify(&path, leaf).unwrap());
+        assert!(!path.verify(tree.root(), &[0xffu8; 8]).unwrap());
+
+        // Ensure the path round trips.
+        let candidate = NestedMerklePath::<Outer, Inner>::read_le(&path.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(path, candidate);
+    }
+
+    // Ensure paths from a tree with different inner parameters are rejected.
+    let other_parameters = Arc::new(Inner::setup("nested_merkle_tree_other"));
+    let other_tree = NestedMerkleTree::new(outer_parameters.clone(), other_parameters, &subtrees).unwrap();
+    let path = tree.generate_proof(0, 0, &subtrees[0][0]).unwrap();
+    let other_path = other_tree.generate_proof(0, 0, &subtrees[0][0]).unwrap();
+    assert!(!tree.verify(&other_path, &subtrees[0][0]).unwrap());
+    assert!(!other_tree.verify(&path, &subtrees[0][0]).unwrap());
+
+    // Ensure a path stitched from the layers of different trees is rejected.
+    let stitched_path =
+        NestedMerklePath { inner: other_path.inner, inner_root: other_path.inner_root, outer: path.outer };
+    assert!(!stitched_path.verify(tree.root(), &subtrees[0][0]).unwrap());
+
+    // Ensure updating leaves matches rebuilding the tree.
+    for _ in 0..10 {
+        let subtree_index = rng.gen_range(0..subtrees.len());
+        let leaf_index = rng.gen_range(0..subtrees[subtree_index].len());
+        let leaf = generate_random_leaves!(1, 8)[0];
+        subtrees[subtree_index][leaf_index] = leaf;
+
+        let root = tree.update_leaf(subtree_index, leaf_index, &leaf).unwrap();
+        let expected = NestedMerkleTree::new(outer_parameters.clone(), inner_parameters.clone(), &subtrees).unwrap();
+        assert_eq!(expected.root(), &root);
+        assert_eq!(expected.root(), tree.root());
+
+        let path = tree.generate_proof(subtree_index, leaf_index, &leaf).unwrap();
+        assert!(tree.verify(&path, &leaf).unwrap());
+    }
+}
+
+/// Checks that batches of leaf updates match a Merkle tree built from scratch.
+fn merkle_tree_update_leaves_test<P: MerkleParameters>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let mut rng = thread_rng();
+
+    let mut leaves = generate_random_leaves!(13, 8);
+    let mut tree = MerkleTree::<P>::new(parameters.clone(), &leaves).unwrap();
+    let num_leaves = leaves.len();
+
+    let random_updates = |rng: &mut rand::rngs::ThreadRng, indices: &[usize]| {
+        indices.iter().map(|index| (*index, rng.gen::<[u8; 8]>())).collect::<Vec<_>>()
+    };
+    let random_indices = (0..6).map(|_| rng.gen_range(0..num_leaves)).collect::<Vec<_>>();
+    let batches = [
+        // An empty update set.
+        vec![],
+        // Adjacent leaves that share a parent.
+        random_updates(&mut rng, &[4, 5]),
+        // The first and last leaves.
+        random_updates(&mut rng, &[0, num_leaves - 1]),
+        // A repeated index, where the last update is kept.
+        random_updates(&mut rng, &[7, 7, 2]),
+        // Random indices.
+        random_updates(&mut rng, &random_indices),
+        // Every leaf.
+        random_updates(&mut rng, &(0..num_leaves).collect::<Vec<_>>()),
+    ];
+
+    for updates in batches {
+        for (index, leaf) in &updates {
+            leaves[*index] = *leaf;
+        }
+        let root = tree.update_leaves(&updates).unwrap();
+
+        // Compare the root and the proofs to a tree built from scratch.
+        let expected = MerkleTree::<P>::new(parameters.clone(), &leaves).unwrap();
+        assert_eq!(expected.root(), &root);
+        assert_eq!(expected.root(), tree.root());
+        assert_eq!(expected.tree(), tree.tree());
+        for _ in 0..4 {
+            let index = rng.gen_range(0..num_leaves);
+            let proof = tree.generate_proof(index, &leaves[index]).unwrap();
+            assert_eq!(expected.generate_proof(index, &leaves[index]).unwrap(), proof);
+            assert!(proof.verify(&root, &leaves[index]).unwrap());
+        }
+    }
+
+    // Update a single leaf.
+    leaves[3] = rng.gen();
+    let root = tree.update_leaf(3, &leaves[3]).unwrap();
+    assert_eq!(MerkleTree::<P>::new(parameters, &leaves).unwrap().root(), &root);
+    assert!(tree.generate_proof(3, &leaves[3]).unwrap().verify(&root, &leaves[3]).unwrap());
+
+    // Ensure the index is within the last level of the tree.
+    assert!(tree.update_leaf(tree.hashed_leaves().len(), &leaves[0]).is_err());
+    assert_eq!(&root, tree.root());
+}
+
+fn merkle_tree_update_leaf_sequence_test<P: MerkleParameters>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let mut rng = thread_rng();
+
+    let mut leaves = generate_random_leaves!(8, 8);
+    let mut tree = MerkleTree::<P>::new(parameters.clone(), &leaves).unwrap();
+
+    // Update every leaf one at a time, in a random order.
+    let mut indices = (0..leaves.len()).collect::<Vec<_>>();
+    indices.shuffle(&mut rng);
+    for index in indices {
+        leaves[index] = rng.gen();
+        let root = tree.update_leaf(index, &leaves[index]).unwrap();
+
+        // Each intermediate root must match a tree rebuilt from the current leaves.
+        let expected = MerkleTree::<P>::new(parameters.clone(), &leaves).unwrap();
+        assert_eq!(expected.root(), &root);
+        assert_eq!(expected.tree(), tree.tree());
+    }
+
+    // An out-of-range index is rejected, and leaves the tree unchanged.
+    let root = *tree.root();
+    assert!(matches!(tree.update_leaf(leaves.len(), &leaves[0]), Err(MerkleError::IncorrectLeafIndex(_))));
+    assert!(tree.update_leaf(usize::MAX, &leaves[0]).is_err());
+    assert_eq!(&root, tree.root());
+}
+
+/// Checks that each append to an incremental Merkle tree matches a dense Merkle tree of the same leaves.
+fn incremental_merkle_tree_test<P: MerkleParameters>(num_leaves: usize) {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let leaves = generate_random_leaves!(num_leaves, 8);
+
+    let mut tree = IncrementalMerkleTree::<P>::new(parameters.clone()).unwrap();
+    assert!(tree.generate_last_leaf_proof().is_err());
+    let empty = MerkleTree::<P>::new(parameters.clone(), &Vec::<[u8; 8]>::new()).unwrap();
+    assert_eq!(empty.root(), tree.root());
+
+    for (i, leaf) in leaves.iter().enumerate() {
+        tree.append(leaf).unwrap();
+        assert_eq!(i as u64 + 1, tree.num_leaves());
+
+        // Compare the root and the proof of the last leaf to the dense tree.
+        let expected = MerkleTree::<P>::new(parameters.clone(), &leaves[..=i]).unwrap();
+        assert_eq!(expected.root(), tree.root());
+        let proof = tree.generate_last_leaf_proof().unwrap();
+        assert_eq!(expected.generate_proof(i, leaf).unwrap(), proof);
+        assert!(proof.verify(tree.root(), leaf).unwrap());
+
+        // Persist and resume the tree.
+        let bytes = tree.to_bytes_le().unwrap();
+        let resumed = IncrementalMerkleTree::<P>::read_le(&bytes[..]).unwrap();
+        assert_eq!(tree, resumed);
+        tree = resumed;
+    }
+}
+
+/// Checks that an incremental Merkle tree rejects appends once it is full.
+fn incremental_merkle_tree_full_test<P: MerkleParameters>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let leaves = generate_random_leaves!(1 << P::DEPTH, 8);
+
+    let mut tree = IncrementalMerkleTree::<P>::new(parameters.clone()).unwrap();
+    for leaf in &leaves {
+        tree.append(leaf).unwrap();
+    }
+    assert_eq!(MerkleTree::<P>::new(parameters, &leaves).unwrap().root(), tree.root());
+
+    // Ensure the tree is unchanged by the failed append.
+    let root = *tree.root();
+    assert!(tree.append(&leaves[0]).is_err());
+    assert_eq!(&root, tree.root());
+    assert_eq!(leaves.len() as u64, tree.num_leaves());
+}
+
+/// Checks the batch openings of a Merkle tree against its single openings.
+fn merkle_multi_proof_test<P: MerkleParameters>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let mut rng = thread_rng();
+
+    let leaves = generate_random_leaves!(100, 8);
+    let tree = MerkleTree::<P>::new(parameters, &leaves).unwrap();
+    let root = tree.root();
+
+    let open = |indices: &[usize]| indices.iter().map(|index| (*index, leaves[*index])).collect::<Vec<_>>();
+
+    // A random subset of indices, with repetitions, which are opened once.
+    let indices = (0..20).map(|_| rng.gen_range(0..leaves.len())).collect::<Vec<_>>();
+    let proof = tree.prove_batch(&indices).unwrap();
+    let mut expected_indices = indices.iter().map(|index| *index as u64).collect::<Vec<_>>();
+    expected_indices.sort_unstable();
+    expected_indices.dedup();
+    assert_eq!(expected_indices, proof.leaf_indices);
+    assert!(proof.verify_batch(root, &open(&indices)).unwrap());
+    for index in &indices {
+        // Cross-verify with the single openings.
+        assert!(tree.generate_proof(*index, &leaves[*index]).unwrap().verify(root, &leaves[*index]).unwrap());
+    }
+
+    // A single leaf, whose siblings are the path of its single opening.
+    let index = rng.gen_range(0..leaves.len());
+    let proof = tree.prove_batch(&[index]).unwrap();
+    assert_eq!(tree.generate_proof(index, &leaves[index]).unwrap().path, proof.siblings);
+    assert!(proof.verify_batch(root, &open(&[index])).unwrap());
+
+    // All of the leaves, in reverse order.
+    let indices = (0..leaves.len()).rev().collect::<Vec<_>>();
+    let proof = tree.prove_batch(&indices).unwrap();
+    assert!(proof.verify_batch(root, &open(&indices)).unwrap());
+
+    // Reject a missing leaf, an extra leaf, a conflicting leaf, a wrong leaf, and a wrong root.
+    let indices = [3, 4, 50];
+    let proof = tree.prove_batch(&indices).unwrap();
+    assert!(proof.verify_batch(root, &open(&indices)).unwrap());
+    assert!(!proof.verify_batch(root, &open(&[3, 4])).unwrap());
+    assert!(!proof.verify_batch(root, &open(&[3, 4, 5, 50])).unwrap());
+    assert!(!proof.verify_batch(root, &[open(&indices), vec![(4, leaves[5])]].concat()).unwrap());
+    assert!(!proof.verify_batch(root, &[(3, leaves[3]), (4, leaves[5]), (50, leaves[50])]).unwrap());
+    assert!(!proof.verify_batch(&Default::default(), &open(&indices)).unwrap());
+
+    // Reject a tampered sibling, and a truncated or extended proof.
+    for i in 0..proof.siblings.len() {
+        let mut tampered = proof.clone();
+        tampered.siblings[i] = *root;
+        assert!(!tampered.verify_batch(root, &open(&indices)).unwrap());
+    }
+    let mut tampered = proof.clone();
+    tampered.siblings.pop();
+    assert!(!tampered.verify_batch(root, &open(&indices)).unwrap());
+    let mut tampered = proof.clone();
+    tampered.siblings.push(Default::default());
+    assert!(!tampered.verify_batch(root, &open(&indices)).unwrap());
+
+    // Reject an empty set of indices, and an index outside of the tree.
+    assert!(tree.prove_batch(&[]).is_err());
+    assert!(tree.prove_batch(&[tree.hashed_leaves().len()]).is_err());
+
+    // Serialize and deserialize the proof.
+    let bytes = proof.to_bytes_le().unwrap();
+    assert_eq!(proof, MerkleMultiProof::<P>::read_le(&bytes[..]).unwrap());
+}
+
+/// Checks that a batch opening of adjacent leaves is smaller than their single openings.
+fn merkle_multi_proof_size_test<P: MerkleParameters>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+
+    let leaves = generate_random_leaves!(1000, 8);
+    let tree = MerkleTree::<P>::new(parameters, &leaves).unwrap();
+
+    let indices = (400..500).collect::<Vec<_>>();
+    let proof = tree.prove_batch(&indices).unwrap();
+    let leaves_with_indices = indices.iter().map(|index| (*index, leaves[*index])).collect::<Vec<_>>();
+    assert!(proof.verify_batch(tree.root(), &leaves_with_indices).unwrap());
+
+    // The batch opening of 100 adjacent leaves is smaller than two single openings.
+    let single_size = tree.generate_proof(400, &leaves[400]).unwrap().to_bytes_le().unwrap().len();
+    let batch_size = proof.to_bytes_le().unwrap().len();
+    assert!(batch_size < 2 * single_size, "{batch_size} >= 2 * {single_size}");
+}
+
+/// Checks that random inserts and updates of a sparse Merkle tree match a dense Merkle tree of the final leaves.
+fn sparse_merkle_tree_matches_dense_test<P: MerkleParameters>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let mut rng = thread_rng();
+
+    // Use enough leaves for the dense tree to span the full depth.
+    let capacity = 1u64 << P::DEPTH;
+    let num_leaves = rng.gen_range(capacity / 2 + 1..=capacity);
+    let mut leaves = generate_random_leaves!(num_leaves as usize, 8);
+
+    // Insert the leaves in a random order, interleaved with random updates.
+    let mut tree = SparseMerkleTree::<P>::new(parameters.clone()).unwrap();
+    let mut order = (0..num_leaves).collect::<Vec<_>>();
+    order.sort_by_key(|_| rng.gen::<u64>());
+    for (i, index) in order.iter().enumerate() {
+        tree.insert(*index, &leaves[*index as usize]).unwrap();
+        assert!(tree.insert(*index, &leaves[*index as usize]).is_err());
+
+        let updated = order[rng.gen_range(0..=i)] as usize;
+        leaves[updated] = rng.gen();
+        tree.update(updated as u64, &leaves[updated]).unwrap();
+    }
+    assert_eq!(num_leaves as usize, tree.len());
+
+    // Check the root and the proofs against the dense tree.
+    let dense_tree = generate_merkle_tree(&leaves, &*parameters);
+    assert_eq!(dense_tree.root(), tree.root());
+    for (index, leaf) in leaves.iter().enumerate() {
+        assert_eq!(Some(&parameters.hash_leaf(leaf).unwrap()), tree.get(index as u64));
+        let proof = tree.generate_proof(index as u64).unwrap();
+        assert_eq!(dense_tree.generate_proof(index, leaf).unwrap(), proof);
+        assert!(proof.verify(tree.root(), leaf).unwrap());
+    }
+
+    // Check the empty leaves.
+    for index in num_leaves..capacity {
+        assert!(tree.get(index).is_none());
+        assert!(tree.update(index, &leaves[0]).is_err());
+        assert!(tree.generate_proof(index).is_err());
+        let proof = tree.generate_non_membership_proof(index).unwrap();
+        assert!(proof.verify_non_membership(dense_tree.root()).unwrap());
+    }
+
+    // Ensure the leaf index is bounded by the depth.
+    assert!(tree.insert(capacity, &leaves[0]).is_err());
+}
+
+/// Checks that a sparse Merkle tree with a default leaf matches a dense Merkle tree filled with the default leaf.
+fn sparse_merkle_tree_default_leaf_test<P: MerkleParameters>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let mut rng = thread_rng();
+
+    let default_leaf = [7u8; 8];
+    let mut leaves = vec![default_leaf; 1 << P::DEPTH];
+    let mut tree = SparseMerkleTree::<P>::new_with_default_leaf(parameters.clone(), &default_leaf).unwrap();
+    assert_eq!(generate_merkle_tree(&leaves, &*parameters).root(), tree.root());
+
+    // Ensure the empty subtree hashes are the roots of dense trees of default leaves.
+    let mut empty_hash = parameters.hash_leaf(&default_leaf).unwrap();
+    for height in 0..=P::DEPTH {
+        assert_eq!(Some(&empty_hash), tree.empty_hash(height));
+        empty_hash = parameters.hash_inner_node(&empty_hash, &empty_hash).unwrap();
+    }
+    assert!(tree.empty_hash(P::DEPTH + 1).is_none());
+
+    // Insert random leaves at random indices.
+    for _ in 0..leaves.len() / 4 {
+        let index = rng.gen_range(0..leaves.len());
+        if tree.get(index as u64).is_none() {
+            leaves[index] = rng.gen();
+            tree.insert(index as u64, &leaves[index]).unwrap();
+        }
+    }
+
+    // Check the root and the proofs against the dense tree.
+    let dense_tree = generate_merkle_tree(&leaves, &*parameters);
+    assert_eq!(dense_tree.root(), tree.root());
+    for (index, leaf) in leaves.iter().enumerate() {
+        let proof = match tree.get(index as u64) {
+            Some(_) => tree.generate_proof(index as u64).unwrap(),
+            None => {
+                // An absent leaf is the default leaf, and is not the empty leaf of `P`.
+                let proof = tree.generate_non_membership_proof(index as u64).unwrap();
+                assert!(!proof.verify_non_membership(tree.root()).unwrap());
+                proof
+            }
+        };
+        assert_eq!(dense_tree.generate_proof(index, leaf).unwrap(), proof);
+        assert!(proof.verify(tree.root(), leaf).unwrap());
+    }
+}
+
+/// Checks the non-membership proofs of a sparse Merkle tree.
+fn sparse_merkle_tree_non_membership_test<P: MerkleParameters>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let mut rng = thread_rng();
+
+    // Every leaf of an empty tree is absent.
+    let mut tree = SparseMerkleTree::<P>::new(parameters).unwrap();
+    assert!(tree.is_empty());
+    let index = rng.gen_range(0..1u64 << P::DEPTH);
+    assert!(tree.generate_non_membership_proof(index).unwrap().verify_non_membership(tree.root()).unwrap());
+
+    let leaves = generate_random_leaves!(4, 8);
+    for (i, leaf) in leaves.iter().enumerate() {
+        tree.insert(2 * i as u64, leaf).unwrap();
+    }
+
+    for (i, leaf) in leaves.iter().enumerate() {
+        // A present leaf has no non-membership proof, and its membership proof does not prove absence.
+        let (present, absent) = (2 * i as u64, 2 * i as u64 + 1);
+        assert!(tree.generate_non_membership_proof(present).is_err());
+        assert!(!tree.generate_proof(present).unwrap().verify_non_membership(tree.root()).unwrap());
+
+        // An absent leaf has a non-membership proof, which does not prove the presence of a leaf.
+        let proof = tree.generate_non_membership_proof(absent).unwrap();
+        assert!(proof.verify_non_membership(tree.root()).unwrap());
+        assert!(!proof.verify(tree.root(), leaf).unwrap());
+    }
+}
+
+/// Checks that tampered proofs of a sparse Merkle tree are rejected.
+fn sparse_merkle_tree_tampering_test<P: MerkleParameters>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let mut tree = SparseMerkleTree::<P>::new(parameters).unwrap();
+
+    let leaves = generate_random_leaves!(4, 8);
+    for (i, leaf) in leaves.iter().enumerate() {
+        tree.insert(i as u64, leaf).unwrap();
+    }
+
+    let proof = tree.generate_proof(1).unwrap();
+    assert!(proof.verify(tree.root(), &leaves[1]).unwrap());
+
+    // Tamper with the leaf.
+    assert!(!proof.verify(tree.root(), &leaves[2]).unwrap());
+
+    // Tamper with the leaf index.
+    let mut tampered = proof.clone();
+    tampered.leaf_index = 0;
+    assert!(!tampered.verify(tree.root(), &leaves[1]).unwrap());
+
+    // Tamper with each sibling.
+    for level in 0..P::DEPTH {
+        let mut tampered = proof.clone();
+        tampered.path[level] = tampered.path[(level + 1) % P::DEPTH];
+        assert!(!tampered.verify(tree.root(), &leaves[1]).unwrap());
+    }
+
+    // Truncate the path.
+    let mut tampered = proof.clone();
+    tampered.path.pop();
+    assert!(!tampered.verify(tree.root(), &leaves[1]).unwrap());
+
+    // Use a stale root.
+    let root = *tree.root();
+    tree.update(3, &leaves[0]).unwrap();
+    assert!(proof.verify(&root, &leaves[1]).unwrap());
+    assert!(!proof.verify(tree.root(), &leaves[1]).unwrap());
+}
+
+/// Checks the inserts, updates, and proofs of a sparse Merkle tree with 64-bit leaf indices.
+fn sparse_merkle_tree_depth_64_test<P: MerkleParameters>() {
+    assert_eq!(64, P::DEPTH);
+
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let mut rng = thread_rng();
+    let mut tree = SparseMerkleTree::<P>::new(parameters).unwrap();
+
+    let mut leaves = generate_random_leaves!(4, 8);
+    let indices = [0, u64::MAX, rng.gen(), rng.gen()];
+    for (index, leaf) in indices.iter().zip(&leaves) {
+        tree.insert(*index, leaf).unwrap();
+    }
+
+    leaves[1] = rng.gen();
+    tree.update(u64::MAX, &leaves[1]).unwrap();
+
+    for (index, leaf) in indices.iter().zip(&leaves) {
+        assert!(tree.generate_proof(*index).unwrap().verify(tree.root(), leaf).unwrap());
+    }
+    assert!(tree.generate_non_membership_proof(1).unwrap().verify_non_membership(tree.root()).unwrap());
+    assert!(tree.generate_non_membership_proof(u64::MAX - 1).unwrap().verify_non_membership(tree.root()).unwrap());
+}
+
 mod pedersen_crh_on_projective {
     use super::*;
     use snarkvm_curves::edwards_bls12::EdwardsProjective as Edwards;
@@ -262,6 +785,113 @@ mod pedersen_crh_on_projective {
         type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 32>;
         run_merkle_path_bincode_test::<MTParameters>();
     }
+
+    #[test]
+    fn merkle_path_encoding_test() {
+        super::merkle_path_encoding_test::<MerkleTreeParameters<LeafCRH, TwoToOneCRH, 2>>();
+        super::merkle_path_encoding_test::<MerkleTreeParameters<LeafCRH, TwoToOneCRH, 8>>();
+        super::merkle_path_encoding_test::<MerkleTreeParameters<LeafCRH, TwoToOneCRH, 32>>();
+    }
+
+    #[test]
+    fn merkle_path_golden_bytes_test() {
+        type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 2>;
+
+        let parameters = Arc::new(MTParameters::setup("merkle_tree_test"));
+        let leaves = (0..4u8).map(|i| [i; 8]).collect::<Vec<_>>();
+        let tree = MerkleTree::<MTParameters>::new(parameters, &leaves).unwrap();
+        let proof = tree.generate_proof(2, &leaves[2]).unwrap();
+
+        let expected = concat!(
+            "10006d65726b6c655f747265655f74657374021c9872d77b4c7aa20f7fc978e4f975ed3781429fb1c6e040eb72c9935d49",
+            "fb0a5fcb563a83e378f1df4a7199766ffaa7774434a6a9a7510c52026e3a924f9e05c23d2d37e275b95961f32fc2dcea3d",
+            "9119662e6b1521bd6d8484c3687c896c0f60664d8690c2ff8a96a5da9581d6e2733d3b64efecb23b26f6af47e4c623b80b",
+            "0200000000000000"
+        );
+        assert_eq!(expected, hex::encode(proof.to_bytes_le().unwrap()));
+        assert_eq!(proof, MerklePath::read_le(&hex::decode(expected).unwrap()[..]).unwrap());
+    }
+
+    #[test]
+    fn incremental_merkle_tree_test() {
+        type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 32>;
+        for num_leaves in [1, 2, 3, 8, 17] {
+            super::incremental_merkle_tree_test::<MTParameters>(num_leaves);
+        }
+    }
+
+    #[test]
+    fn incremental_merkle_tree_full_test() {
+        type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 3>;
+        super::incremental_merkle_tree_full_test::<MTParameters>();
+    }
+
+    #[test]
+    fn merkle_multi_proof_test() {
+        type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 32>;
+        super::merkle_multi_proof_test::<MTParameters>();
+    }
+
+    #[test]
+    fn merkle_multi_proof_size_test() {
+        type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 32>;
+        super::merkle_multi_proof_size_test::<MTParameters>();
+    }
+
+    #[test]
+    fn merkle_tree_parallel_threshold_test() {
+        type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 32>;
+        super::merkle_tree_parallel_threshold_test::<MTParameters>();
+    }
+
+    #[test]
+    fn nested_merkle_tree_test() {
+        type OuterParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 8>;
+        type InnerParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 4>;
+        super::nested_merkle_tree_test::<OuterParameters, InnerParameters>();
+    }
+
+    #[test]
+    fn merkle_tree_update_leaves_test() {
+        type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 32>;
+        super::merkle_tree_update_leaves_test::<MTParameters>();
+    }
+
+    #[test]
+    fn merkle_tree_update_leaf_sequence_test() {
+        type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 32>;
+        super::merkle_tree_update_leaf_sequence_test::<MTParameters>();
+    }
+
+    #[test]
+    fn sparse_merkle_tree_matches_dense_test() {
+        type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 4>;
+        super::sparse_merkle_tree_matches_dense_test::<MTParameters>();
+    }
+
+    #[test]
+    fn sparse_merkle_tree_default_leaf_test() {
+        type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 4>;
+        super::sparse_merkle_tree_default_leaf_test::<MTParameters>();
+    }
+
+    #[test]
+    fn sparse_merkle_tree_non_membership_test() {
+        type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 32>;
+        super::sparse_merkle_tree_non_membership_test::<MTParameters>();
+    }
+
+    #[test]
+    fn sparse_merkle_tree_tampering_test() {
+        type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 32>;
+        super::sparse_merkle_tree_tampering_test::<MTParameters>();
+    }
+
+    #[test]
+    fn sparse_merkle_tree_depth_64_test() {
+        type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 64>;
+        super::sparse_merkle_tree_depth_64_test::<MTParameters>();
+    }
 }
 
 mod pedersen_compressed_crh_on_projective {
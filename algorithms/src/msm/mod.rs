@@ -17,6 +17,9 @@
 pub mod fixed_base;
 pub use fixed_base::*;
 
+pub mod precomputed;
+pub use precomputed::*;
+
 #[cfg(test)]
 pub mod tests;
 
@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{
+    cfg_chunks,
+    cfg_iter,
+    error,
+    io::{Read, Result as IoResult, Write},
+    BigInteger,
+    FromBytes,
+    ToBytes,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The maximum number of bits in a window of the precomputation.
+pub const MAX_PRECOMPUTED_WINDOW_BITS: usize = 24;
+
+pub struct FixedBaseMSM;
+
+impl FixedBaseMSM {
+    ///
+    /// Returns the precomputation for MSMs over the given bases, with windows of `window_bits` bits.
+    ///
+    /// For each base `P`, the precomputation stores the shifted multiples `2^(i * window_bits) P`, for each
+    /// of the `ceil(b / window_bits)` windows of a `b`-bit scalar, which takes `num_bases * ceil(b / window_bits)`
+    /// points. Each MSM then needs `2^window_bits` buckets, and about `num_scalars * ceil(b / window_bits) +
+    /// 2^(window_bits + 1)` additions, instead of one pass over the buckets for each window.
+    ///
+    /// A larger window uses less memory, and fewer additions per scalar, but more additions to sum the buckets.
+    /// For example, for `2^16` bases with 253-bit scalars, a window of 16 bits stores 16 points per base.
+    ///
+    pub fn precompute<G: AffineCurve>(bases: &[G], window_bits: usize) -> PrecomputedBases<G> {
+        assert!(
+            (1..=MAX_PRECOMPUTED_WINDOW_BITS).contains(&window_bits),
+            "The window must be between 1 and {MAX_PRECOMPUTED_WINDOW_BITS} bits"
+        );
+        let num_windows = num_windows::<G>(window_bits);
+
+        // Compute 2^(i * window_bits) P for each base P and window i.
+        let shifted_bases = cfg_iter!(bases)
+            .flat_map(|base| {
+                let mut shifted = Vec::with_capacity(num_windows);
+                let mut base = base.to_projective();
+                for _ in 0..num_windows {
+                    shifted.push(base);
+                    for _ in 0..window_bits {
+                        base.double_in_place();
+                    }
+                }
+                shifted
+            })
+            .collect::<Vec<_>>();
+
+        PrecomputedBases {
+            bases: G::Projective::batch_normalization_into_affine(shifted_bases),
+            num_bases: bases.len(),
+            window_bits,
+        }
+    }
+}
+
+///
+/// The shifted multiples of a fixed set of bases, for repeated MSMs over the bases.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrecomputedBases<G: AffineCurve> {
+    /// The shifted multiples of each base, in order of the bases, and then of the windows.
+    bases: Vec<G>,
+    num_bases: usize,
+    window_bits: usize,
+}
+
+impl<G: AffineCurve> PrecomputedBases<G> {
+    /// Returns the number of bases.
+    pub fn num_bases(&self) -> usize {
+        self.num_bases
+    }
+
+    /// Returns the number of bits in a window.
+    pub fn window_bits(&self) -> usize {
+        self.window_bits
+    }
+
+    ///
+    /// Returns the MSM of the bases with the given scalars.
+    ///
+    /// As in the variable base MSM, any bases or scalars beyond the length of the shorter input are ignored.
+    ///
+    pub fn msm(&self, scalars: &[G::ScalarField]) -> G::Projective {
+        let num_windows = num_windows::<G>(self.window_bits);
+        let scalars = &scalars[..scalars.len().min(self.num_bases)];
+
+        // Split the terms into a chunk for each thread, which each sum into their own buckets.
+        #[cfg(feature = "parallel")]
+        let num_chunks = snarkvm_utilities::max_available_threads();
+        #[cfg(not(feature = "parallel"))]
+        let num_chunks = 1;
+        let chunk_size = ((scalars.len() + num_chunks - 1) / num_chunks).max(1 << self.window_bits);
+
+        cfg_chunks!(scalars, chunk_size)
+            .zip(cfg_chunks!(self.bases, chunk_size * num_windows))
+            .map(|(scalars, bases)| self.msm_chunk(scalars, bases))
+            .sum()
+    }
+
+    /// Returns the MSM of the given scalars with the given shifted multiples of their bases.
+    fn msm_chunk(&self, scalars: &[G::ScalarField], bases: &[G]) -> G::Projective {
+        let num_windows = num_windows::<G>(self.window_bits);
+
+        // Add the shifted multiple of each base to the bucket of the digit of its scalar in the window.
+        // (Recall that `buckets` doesn't have a zero bucket.)
+        let mut buckets = vec![G::Projective::zero(); (1 << self.window_bits) - 1];
+        for (scalar, bases) in scalars.iter().zip(bases.chunks(num_windows)) {
+            let mut scalar = scalar.to_repr();
+            for base in bases {
+                let digit = scalar.as_ref()[0] % (1 << self.window_bits);
+                if digit != 0 {
+                    buckets[(digit - 1) as usize].add_assign_mixed(base);
+                }
+                scalar.divn(self.window_bits as u32);
+            }
+        }
+
+        // Sum the buckets, weighted by their digits.
+        let mut result = G::Projective::zero();
+        let mut running_sum = G::Projective::zero();
+        for bucket in buckets.into_iter().rev() {
+            running_sum += bucket;
+            result += running_sum;
+        }
+        result
+    }
+}
+
+impl<G: AffineCurve> FromBytes for PrecomputedBases<G> {
+    /// Reads the precomputation, such as one cached with its bases from `write_le`.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let num_bases = u32::read_le(&mut reader)? as usize;
+        let window_bits = u8::read_le(&mut reader)? as usize;
+        if !(1..=MAX_PRECOMPUTED_WINDOW_BITS).contains(&window_bits) {
+            return Err(error("Invalid window in the precomputed bases"));
+        }
+
+        let num_windows = num_windows::<G>(window_bits);
+        let mut bases = Vec::with_capacity(num_bases * num_windows);
+        for _ in 0..num_bases * num_windows {
+            let base = G::read_le(&mut reader)?;
+            if !base.is_zero() && (!base.is_on_curve() || !base.is_in_correct_subgroup_assuming_on_curve()) {
+                return Err(error("Invalid base in the precomputed bases"));
+            }
+            bases.push(base);
+        }
+
+        Ok(Self { bases, num_bases, window_bits })
+    }
+}
+
+impl<G: AffineCurve> ToBytes for PrecomputedBases<G> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.num_bases as u32).write_le(&mut writer)?;
+        (self.window_bits as u8).write_le(&mut writer)?;
+        for base in &self.bases {
+            base.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the number of windows of `window_bits` bits in a scalar.
+fn num_windows<G: AffineCurve>(window_bits: usize) -> usize {
+    let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+    (num_bits + window_bits - 1) / window_bits
+}
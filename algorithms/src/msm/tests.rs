@@ -16,15 +16,19 @@
 
 use crate::msm::*;
 use snarkvm_curves::{
-    bls12_377::{Fr, G1Projective},
+    bls12_377::{Fr, G1Affine, G1Projective},
     traits::{AffineCurve, ProjectiveCurve},
 };
-use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_fields::{One, PrimeField, Zero};
 use snarkvm_utilities::{
     rand::{test_rng, UniformRand},
     BitIteratorBE,
+    FromBytes,
+    ToBytes,
 };
 
+use rand::Rng;
+
 fn naive_variable_base_msm<G: AffineCurve>(
     bases: &[G],
     scalars: &[<G::ScalarField as PrimeField>::BigInteger],
@@ -66,3 +70,250 @@ fn variable_base_test_with_bls12_unequal_numbers() {
 
     assert_eq!(naive.to_affine(), fast.to_affine());
 }
+
+/// Returns `size` random terms, where each scalar is zero with probability `zero_percent / 100`,
+/// and each base is the identity with probability `zero_percent / 200`.
+fn sparse_scalar_bases(
+    rng: &mut impl Rng,
+    size: usize,
+    zero_percent: u32,
+) -> (Vec<G1Affine>, Vec<<Fr as PrimeField>::BigInteger>) {
+    let bases = (0..size)
+        .map(|_| match rng.gen_range(0..200) < zero_percent {
+            true => G1Affine::zero(),
+            false => G1Projective::rand(rng).to_affine(),
+        })
+        .collect();
+    let scalars = (0..size)
+        .map(|_| match rng.gen_range(0..100) < zero_percent {
+            true => Fr::zero().to_repr(),
+            false => Fr::rand(rng).to_repr(),
+        })
+        .collect();
+    (bases, scalars)
+}
+
+#[test]
+fn variable_base_test_with_sparse_terms() {
+    let mut rng = test_rng();
+
+    for (size, zero_percent) in
+        [(1, 30), (2, 50), (3, 90), (31, 30), (100, 60), (1 << 10, 30), (1 << 12, 90), (1 << 16, 99)]
+    {
+        let (g, v) = sparse_scalar_bases(&mut rng, size, zero_percent);
+
+        let naive = naive_variable_base_msm(g.as_slice(), v.as_slice());
+        let fast = VariableBase::msm(g.as_slice(), v.as_slice());
+        assert_eq!(naive.to_affine(), fast.to_affine());
+
+        // Ensure every window size agrees with the tuned window size.
+        for window_size in [1, 4, 9] {
+            let config = MSMConfig::new(Some(window_size), None, None).unwrap();
+            assert_eq!(fast, VariableBase::msm_with_config(g.as_slice(), v.as_slice(), &config));
+        }
+    }
+}
+
+#[test]
+fn variable_base_test_with_zero_terms() {
+    let mut rng = test_rng();
+
+    // Ensure an empty input, zero scalars, and identity bases all return the identity.
+    assert!(VariableBase::msm::<G1Affine>(&[], &[]).is_zero());
+
+    let g = (0..16).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+    let v = vec![Fr::zero().to_repr(); 16];
+    assert!(VariableBase::msm(g.as_slice(), v.as_slice()).is_zero());
+
+    let g = vec![G1Affine::zero(); 16];
+    let v = (0..16).map(|_| Fr::rand(&mut rng).to_repr()).collect::<Vec<_>>();
+    assert!(VariableBase::msm(g.as_slice(), v.as_slice()).is_zero());
+}
+
+#[test]
+fn variable_base_test_with_small_scalars() {
+    let mut rng = test_rng();
+
+    // Ensure the bit length of the largest scalar is used, rather than that of the field.
+    let g = (0..100).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+    let v = (0..100u64).map(|i| Fr::from(i * i).to_repr()).collect::<Vec<_>>();
+
+    let naive = naive_variable_base_msm(g.as_slice(), v.as_slice());
+    let fast = VariableBase::msm(g.as_slice(), v.as_slice());
+    assert_eq!(naive.to_affine(), fast.to_affine());
+}
+
+#[test]
+fn precomputed_base_test_with_bls12() {
+    let mut rng = test_rng();
+
+    for size in [0, 1, 2, 100, 1 << 10] {
+        let (g, v) = sparse_scalar_bases(&mut rng, size, 10);
+        let scalars = v.iter().map(|scalar| Fr::from_repr(*scalar).unwrap()).collect::<Vec<_>>();
+        let expected = VariableBase::msm(g.as_slice(), v.as_slice());
+
+        for window_bits in [1, 3, 8, 13] {
+            let precomputed = FixedBaseMSM::precompute(g.as_slice(), window_bits);
+            assert_eq!(size, precomputed.num_bases());
+            assert_eq!(expected, precomputed.msm(&scalars));
+
+            // Ensure a prefix of the scalars uses a prefix of the bases.
+            let half = size / 2;
+            assert_eq!(VariableBase::msm(&g[..half], &v[..half]), precomputed.msm(&scalars[..half]));
+        }
+    }
+}
+
+#[test]
+fn precomputed_base_test_with_identity_bases() {
+    let mut rng = test_rng();
+
+    let g = vec![G1Affine::zero(); 16];
+    let scalars = (0..16).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+    assert!(FixedBaseMSM::precompute(&g, 4).msm(&scalars).is_zero());
+
+    let scalars = vec![Fr::zero(); 16];
+    let g = (0..16).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+    assert!(FixedBaseMSM::precompute(&g, 4).msm(&scalars).is_zero());
+}
+
+#[test]
+fn precomputed_base_test_serialization() {
+    let mut rng = test_rng();
+
+    let (g, v) = sparse_scalar_bases(&mut rng, 100, 10);
+    let scalars = v.iter().map(|scalar| Fr::from_repr(*scalar).unwrap()).collect::<Vec<_>>();
+    let precomputed = FixedBaseMSM::precompute(g.as_slice(), 6);
+
+    let bytes = precomputed.to_bytes_le().unwrap();
+    let candidate = PrecomputedBases::<G1Affine>::read_le(&bytes[..]).unwrap();
+    assert_eq!(precomputed, candidate);
+    assert_eq!(precomputed.msm(&scalars), candidate.msm(&scalars));
+
+    // Ensure truncated bytes and an invalid window fail.
+    assert!(PrecomputedBases::<G1Affine>::read_le(&bytes[..bytes.len() - 1]).is_err());
+    let mut invalid = bytes;
+    invalid[4] = 0;
+    assert!(PrecomputedBases::<G1Affine>::read_le(&invalid[..]).is_err());
+}
+
+#[test]
+fn variable_base_test_multi_scalar_mul_batched() {
+    let mut rng = test_rng();
+
+    for (num_vectors, size) in [(1, 1), (2, 37), (5, 100), (16, 1 << 10), (3, 0), (0, 100)] {
+        let (g, _) = sparse_scalar_bases(&mut rng, size, 10);
+        let scalar_vecs =
+            (0..num_vectors).map(|i| sparse_scalar_bases(&mut rng, size, i * 10 % 100).1).collect::<Vec<_>>();
+        let scalar_slices = scalar_vecs.iter().map(|scalars| scalars.as_slice()).collect::<Vec<_>>();
+
+        // Ensure the batched MSM matches an MSM for each scalar vector.
+        let expected = scalar_vecs.iter().map(|v| VariableBase::msm(g.as_slice(), v.as_slice())).collect::<Vec<_>>();
+        assert_eq!(expected, VariableBase::multi_scalar_mul_batched(g.as_slice(), &scalar_slices).unwrap());
+    }
+
+    // Ensure every scalar vector must have the same length as the bases.
+    let (g, v) = sparse_scalar_bases(&mut rng, 100, 10);
+    assert!(VariableBase::multi_scalar_mul_batched(g.as_slice(), &[v.as_slice(), &v[..99]]).is_err());
+    assert!(VariableBase::multi_scalar_mul_batched(&g[..99], &[v.as_slice()]).is_err());
+}
+
+/// Ensures the MSM with signed digits matches the MSM with unsigned digits, for random scalars,
+/// scalars close to the modulus, and single terms.
+fn check_signed_digits<G: AffineCurve>(sizes: &[usize]) {
+    let mut rng = test_rng();
+    let signed = |window_size| MSMConfig::new(window_size, Some(true), None).unwrap();
+    let unsigned = |window_size| MSMConfig::new(window_size, Some(false), None).unwrap();
+
+    for &size in sizes {
+        let g = (0..size).map(|_| G::Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+        let mut scalars = (0..size).map(|_| G::ScalarField::rand(&mut rng)).collect::<Vec<_>>();
+        for (i, scalar) in scalars.iter_mut().enumerate().take(size / 2) {
+            // The modulus minus a small value, whose top window carries in the signed recoding.
+            *scalar = -G::ScalarField::from((i + 1) as u128);
+        }
+        let v = scalars.iter().map(|scalar| scalar.to_repr()).collect::<Vec<_>>();
+
+        let expected = VariableBase::msm_with_config(&g, &v, &unsigned(None));
+        if size <= 16 {
+            assert_eq!(naive_variable_base_msm(&g, &v), expected);
+        }
+        for window_size in [None, Some(2), Some(3), Some(7), Some(13)] {
+            assert_eq!(expected, VariableBase::msm_with_config(&g, &v, &signed(window_size)));
+            assert_eq!(expected, VariableBase::msm_with_config(&g, &v, &unsigned(window_size)));
+        }
+    }
+}
+
+#[test]
+fn variable_base_test_signed_digits_with_bls12() {
+    check_signed_digits::<G1Affine>(&[1, 2, 16, 1000]);
+    check_signed_digits::<snarkvm_curves::bls12_377::G2Affine>(&[1, 2, 16, 200]);
+}
+
+#[test]
+fn variable_base_test_signed_digits_with_edwards() {
+    check_signed_digits::<snarkvm_curves::edwards_bls12::EdwardsAffine>(&[1, 2, 16, 1000]);
+    check_signed_digits::<snarkvm_curves::edwards_bw6::EdwardsAffine>(&[1, 2, 16, 200]);
+}
+
+/// Ensures the MSM over small scalars matches the MSM after lifting the scalars into the scalar field.
+fn check_small_scalars<G: AffineCurve>(sizes: &[usize]) {
+    let mut rng = test_rng();
+
+    for &size in sizes {
+        let g = (0..size).map(|_| G::Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+        let mut scalars = (0..size).map(|i| rng.gen::<u64>() >> (i % 64)).collect::<Vec<_>>();
+        if size >= 2 {
+            scalars[0] = 0;
+            scalars[1] = u64::MAX;
+        }
+        let v = scalars.iter().map(|scalar| G::ScalarField::from(*scalar as u128).to_repr()).collect::<Vec<_>>();
+        let expected = VariableBase::msm(&g, &v);
+        assert_eq!(expected, VariableBase::msm_u64(&g, &scalars));
+
+        // Ensure the generic MSM dispatches to the small scalars, given a bound on their bit length.
+        let config = MSMConfig::new(None, None, Some(64)).unwrap();
+        assert_eq!(expected, VariableBase::msm_with_config(&g, &v, &config));
+
+        // Ensure a scalar that exceeds the bit length is not truncated.
+        let mut v_wide = v.clone();
+        v_wide[0] = G::ScalarField::from(u128::MAX).to_repr();
+        let expected_wide = VariableBase::msm(&g, &v_wide);
+        assert_eq!(expected_wide, VariableBase::msm_with_config(&g, &v_wide, &config));
+
+        let mut scalars = (0..size).map(|i| rng.gen::<u128>() >> (i % 128)).collect::<Vec<_>>();
+        if size >= 2 {
+            scalars[0] = u128::MAX;
+            scalars[1] = 0;
+        }
+        let v = scalars.iter().map(|scalar| G::ScalarField::from(*scalar).to_repr()).collect::<Vec<_>>();
+        let expected = VariableBase::msm(&g, &v);
+        assert_eq!(expected, VariableBase::msm_u128(&g, &scalars));
+
+        let config = MSMConfig::new(None, None, Some(128)).unwrap();
+        assert_eq!(expected, VariableBase::msm_with_config(&g, &v, &config));
+
+        // Ensure a scalar that exceeds the bit length is not truncated.
+        let mut v_wide = v.clone();
+        v_wide[0] = (-G::ScalarField::one()).to_repr();
+        let expected_wide = VariableBase::msm(&g, &v_wide);
+        assert_eq!(expected_wide, VariableBase::msm_with_config(&g, &v_wide, &config));
+    }
+
+    // Ensure zero scalars and empty inputs return zero.
+    let g = (0..10).map(|_| G::Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+    assert!(VariableBase::msm_u64(&g, &[0u64; 10]).is_zero());
+    assert!(VariableBase::msm_u128::<G>(&[], &[]).is_zero());
+}
+
+#[test]
+fn variable_base_test_small_scalars_with_bls12() {
+    check_small_scalars::<G1Affine>(&[1, 2, 16, 1000]);
+    check_small_scalars::<snarkvm_curves::bls12_377::G2Affine>(&[1, 2, 100]);
+}
+
+#[test]
+fn variable_base_test_small_scalars_with_edwards() {
+    check_small_scalars::<snarkvm_curves::edwards_bls12::EdwardsAffine>(&[1, 2, 16, 1000]);
+}
@@ -15,6 +15,9 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod batched;
+mod multi;
+mod signed;
+mod small;
 mod standard;
 
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
@@ -24,9 +27,12 @@ mod cuda;
 pub mod prefetch;
 
 use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::BigInteger;
 
+use anyhow::{ensure, Result};
 use core::any::TypeId;
+use std::borrow::Cow;
 
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
 use core::sync::atomic::{AtomicBool, Ordering};
@@ -34,15 +40,101 @@ use core::sync::atomic::{AtomicBool, Ordering};
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
 static HAS_CUDA_FAILED: AtomicBool = AtomicBool::new(false);
 
+///
+/// The configuration of the variable-base MSM.
+///
+/// By default, the window size of Pippenger's algorithm is selected from a table, by the number of
+/// non-zero terms and the bit length of the largest scalar. The scalars are recoded into signed digits,
+/// which halves the number of buckets, except for BLS12-377 G1, whose batched addition is faster.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MSMConfig {
+    /// If set, the window size to use for every MSM, in place of the table.
+    window_size: Option<usize>,
+    /// If set, whether to recode the scalars into signed digits, in place of the choice for the curve.
+    signed_digits: Option<bool>,
+    /// If set, the bit length of the scalars to expect. Up to 128 bits, the MSM runs over `u128` scalars
+    /// instead of big integers, when every scalar fits in the bit length.
+    max_scalar_bits: Option<usize>,
+}
+
+/// The maximum window size of an `MSMConfig`, so that the digits of a window, signed or not, fit in an `i64`.
+const MAX_WINDOW_SIZE: usize = 62;
+
+impl MSMConfig {
+    /// Initializes a new configuration of the variable-base MSM.
+    /// Returns an error if the window size exceeds 62 bits.
+    pub fn new(
+        window_size: Option<usize>,
+        signed_digits: Option<bool>,
+        max_scalar_bits: Option<usize>,
+    ) -> Result<Self> {
+        if let Some(window_size) = window_size {
+            ensure!(
+                window_size <= MAX_WINDOW_SIZE,
+                "The window size is {window_size} bits, but the maximum is {MAX_WINDOW_SIZE} bits"
+            );
+        }
+        Ok(Self { window_size, signed_digits, max_scalar_bits })
+    }
+
+    /// Returns the window size for an MSM of `num_terms` non-zero terms, with scalars of at most `num_bits` bits.
+    pub fn window_size(&self, num_terms: usize, num_bits: usize) -> usize {
+        match self.window_size {
+            Some(window_size) => window_size.max(1),
+            None => window_size_from_table(num_terms, num_bits),
+        }
+    }
+}
+
 pub struct VariableBase;
 
 impl VariableBase {
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        Self::msm_with_config(bases, scalars, &MSMConfig::default())
+    }
+
+    ///
+    /// Returns the MSM of the given bases and scalars, using the window size from the given configuration.
+    ///
+    /// Terms with a zero scalar or an identity base are skipped, and if no terms remain, the identity is returned.
+    ///
+    pub fn msm_with_config<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+        config: &MSMConfig,
+    ) -> G::Projective {
+        // If every scalar fits in the expected bit length, of at most 128 bits, use the MSM over small scalars.
+        // Otherwise, the scalars would be truncated, so the MSM falls back to the full bit length.
+        if let Some(max_scalar_bits) = config.max_scalar_bits.filter(|bits| *bits <= 128) {
+            if scalars.iter().all(|scalar| scalar.num_bits() as usize <= max_scalar_bits) {
+                let scalars = scalars
+                    .iter()
+                    .map(|scalar| {
+                        let limbs = scalar.as_ref();
+                        limbs[0] as u128 | (limbs.get(1).copied().unwrap_or(0) as u128) << 64
+                    })
+                    .collect::<Vec<_>>();
+                return Self::msm_small(bases, &scalars, config);
+            }
+        }
+
+        // Filter out the terms with a zero scalar or an identity base.
+        let (bases, scalars) = filter_zero_terms(bases, scalars);
+        if scalars.is_empty() {
+            return G::Projective::zero();
+        }
+
+        // Select the window size, from the number of terms and the bit length of the largest scalar.
+        let num_bits = scalars.iter().map(|scalar| scalar.num_bits() as usize).max().unwrap_or(0);
+        let c = config.window_size(scalars.len(), num_bits);
+
         // For BLS12-377, we perform variable base MSM using a batched addition technique.
-        if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
+        let is_bls12_377_g1 = TypeId::of::<G>() == TypeId::of::<G1Affine>();
+        if is_bls12_377_g1 && config.signed_digits != Some(true) {
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
             if !HAS_CUDA_FAILED.load(Ordering::SeqCst) {
-                match cuda::msm_cuda(bases, scalars) {
+                match cuda::msm_cuda(&bases, &scalars) {
                     Ok(x) => return x,
                     Err(_e) => {
                         HAS_CUDA_FAILED.store(true, Ordering::SeqCst);
@@ -50,11 +142,82 @@ impl VariableBase {
                     }
                 }
             }
-            batched::msm(bases, scalars)
+            batched::msm(&bases, &scalars, c, num_bits)
         }
-        // For all other curves, we perform variable base MSM using Pippenger's algorithm.
-        else {
-            standard::msm(bases, scalars)
+        // For all other curves, we perform variable base MSM using Pippenger's algorithm, with signed digits.
+        else if config.signed_digits.unwrap_or(true) {
+            signed::msm(&bases, &scalars, c.max(2), num_bits)
+        } else {
+            standard::msm(&bases, &scalars, c, num_bits)
+        }
+    }
+
+    ///
+    /// Returns the MSM of the given bases and 64-bit scalars.
+    ///
+    /// The windows are sized to the bit length of the largest scalar, and the scalars are never converted
+    /// into big integers, which suits small scalars such as batch verification weights.
+    ///
+    pub fn msm_u64<G: AffineCurve>(bases: &[G], scalars: &[u64]) -> G::Projective {
+        Self::msm_small(bases, scalars, &MSMConfig::default())
+    }
+
+    ///
+    /// Returns the MSM of the given bases and 128-bit scalars, as in `msm_u64`.
+    ///
+    pub fn msm_u128<G: AffineCurve>(bases: &[G], scalars: &[u128]) -> G::Projective {
+        Self::msm_small(bases, scalars, &MSMConfig::default())
+    }
+
+    /// Returns the MSM of the given bases and scalars of at most 128 bits.
+    fn msm_small<G: AffineCurve, S: Copy + Into<u128> + Send + Sync>(
+        bases: &[G],
+        scalars: &[S],
+        config: &MSMConfig,
+    ) -> G::Projective {
+        let num_terms = bases.len().min(scalars.len());
+        let (bases, scalars) = (&bases[..num_terms], &scalars[..num_terms]);
+
+        // Select the window size, from the number of terms and the bit length of the largest scalar.
+        let num_bits =
+            (128 - scalars.iter().fold(0u128, |acc, scalar| acc | (*scalar).into()).leading_zeros()) as usize;
+        if num_bits == 0 {
+            return G::Projective::zero();
+        }
+        let c = config.window_size(num_terms, num_bits);
+
+        small::msm(bases, scalars, c, num_bits)
+    }
+
+    ///
+    /// Returns the MSM of the bases with each of the given scalar vectors, in one pass over the bases.
+    ///
+    /// Each window of the bases is read once for all of the scalar vectors, which saves memory bandwidth
+    /// over an MSM for each scalar vector, such as when committing to many polynomials with the same SRS.
+    ///
+    /// Returns an error if a scalar vector does not have the same length as the bases.
+    ///
+    pub fn multi_scalar_mul_batched<G: AffineCurve>(
+        bases: &[G],
+        scalar_vecs: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+    ) -> Result<Vec<G::Projective>> {
+        for (index, scalars) in scalar_vecs.iter().enumerate() {
+            ensure!(
+                scalars.len() == bases.len(),
+                "Scalar vector {index} has {} scalars, but there are {} bases",
+                scalars.len(),
+                bases.len()
+            );
+        }
+
+        // Select the window size, from the number of terms and the bit length of the largest scalar.
+        let num_bits = scalar_vecs.iter().flat_map(|scalars| scalars.iter()).map(|s| s.num_bits() as usize).max();
+        match num_bits {
+            Some(num_bits) if num_bits > 0 => {
+                let c = MSMConfig::default().window_size(bases.len(), num_bits);
+                Ok(multi::msm(bases, scalar_vecs, c, num_bits))
+            }
+            _ => Ok(vec![G::Projective::zero(); scalar_vecs.len()]),
         }
     }
 
@@ -78,6 +241,50 @@ impl VariableBase {
     }
 }
 
+/// Returns the terms with a non-zero scalar and a non-identity base, borrowing the inputs if every term is kept.
+///
+/// As in the MSM, any bases or scalars beyond the length of the shorter input are ignored.
+fn filter_zero_terms<'a, G: AffineCurve>(
+    bases: &'a [G],
+    scalars: &'a [<G::ScalarField as PrimeField>::BigInteger],
+) -> (Cow<'a, [G]>, Cow<'a, [<G::ScalarField as PrimeField>::BigInteger]>) {
+    let num_terms = bases.len().min(scalars.len());
+    let (bases, scalars) = (&bases[..num_terms], &scalars[..num_terms]);
+
+    let is_zero_term =
+        |(base, scalar): (&G, &<G::ScalarField as PrimeField>::BigInteger)| scalar.is_zero() || base.is_zero();
+    match bases.iter().zip(scalars).any(is_zero_term) {
+        true => {
+            let (bases, scalars) = bases.iter().zip(scalars).filter(|term| !is_zero_term(*term)).unzip();
+            (Cow::Owned(bases), Cow::Owned(scalars))
+        }
+        false => (Cow::Borrowed(bases), Cow::Borrowed(scalars)),
+    }
+}
+
+/// Returns the window size for `num_terms` terms with scalars of at most `num_bits` bits.
+///
+/// The table is indexed by `floor(log2(num_terms))`, and was measured on BLS12-377 and Edwards-BLS12
+/// with 253-bit scalars. As Pippenger's algorithm costs about `ceil(num_bits / c) * (num_terms + 2^(c + 1))`
+/// group additions, the window size is then lowered for as long as this keeps the number of windows.
+fn window_size_from_table(num_terms: usize, num_bits: usize) -> usize {
+    const WINDOW_SIZES: [usize; 18] = [1, 2, 2, 3, 3, 4, 4, 5, 6, 6, 7, 7, 8, 9, 10, 11, 12, 13];
+
+    let log_terms = (usize::BITS - num_terms.max(1).leading_zeros() - 1) as usize;
+    let mut window_size = match WINDOW_SIZES.get(log_terms) {
+        Some(window_size) => *window_size,
+        None => crate::msm::ln_without_floats(num_terms) + 2,
+    };
+
+    let num_bits = num_bits.max(1);
+    while window_size > 1
+        && (num_bits + window_size - 2) / (window_size - 1) == (num_bits + window_size - 1) / window_size
+    {
+        window_size -= 1;
+    }
+    window_size
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,13 +312,37 @@ mod tests {
         let naive_b = VariableBase::msm_naive_parallel(bases.as_slice(), scalars.as_slice());
         assert_eq!(naive_a, naive_b);
 
-        let candidate = standard::msm(bases.as_slice(), scalars.as_slice());
+        let candidate = standard::msm(bases.as_slice(), scalars.as_slice(), 8, 253);
         assert_eq!(naive_a, candidate);
 
-        let candidate = batched::msm(bases.as_slice(), scalars.as_slice());
+        let candidate = batched::msm(bases.as_slice(), scalars.as_slice(), 8, 253);
         assert_eq!(naive_a, candidate);
     }
 
+    #[test]
+    fn test_window_size() {
+        let config = MSMConfig::default();
+        assert_eq!(1, config.window_size(0, 253));
+        assert_eq!(1, config.window_size(1, 253));
+        assert_eq!(7, config.window_size(1 << 11, 253));
+        assert_eq!(9, config.window_size(1 << 13, 253));
+        assert_eq!(crate::msm::ln_without_floats(1 << 20) + 2, config.window_size(1 << 20, 253));
+
+        // Ensure the window size is lowered when it does not reduce the number of windows.
+        assert_eq!(8, config.window_size(1 << 13, 16));
+        assert_eq!(1, config.window_size(1 << 13, 1));
+
+        // Ensure the window size from the configuration takes precedence.
+        let config = MSMConfig::new(Some(4), None, None).unwrap();
+        assert_eq!(4, config.window_size(1 << 13, 253));
+        assert_eq!(4, config.window_size(1 << 13, 1));
+
+        // Ensure a window size above the maximum is rejected.
+        assert!(MSMConfig::new(Some(MAX_WINDOW_SIZE), None, None).is_ok());
+        assert!(MSMConfig::new(Some(MAX_WINDOW_SIZE + 1), None, None).is_err());
+        assert!(MSMConfig::new(Some(64), None, None).is_err());
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
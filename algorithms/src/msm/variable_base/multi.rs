@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_into_iter, BigInteger};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Returns the sum of each scalar vector in the window starting at `w_start`, where every base is read once
+/// for all of the scalar vectors.
+fn multi_window<G: AffineCurve>(
+    bases: &[G],
+    scalar_vecs: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+    w_start: usize,
+    c: usize,
+) -> Vec<G::Projective> {
+    // We don't need the "zero" bucket, so we only have 2^c - 1 buckets for each scalar vector.
+    let mut buckets = vec![vec![G::Projective::zero(); (1 << c) - 1]; scalar_vecs.len()];
+    for (i, base) in bases.iter().enumerate().filter(|(_, base)| !base.is_zero()) {
+        for (scalars, buckets) in scalar_vecs.iter().zip(&mut buckets) {
+            // We right-shift by w_start, and mod the remaining bits by the window size.
+            let mut scalar = scalars[i];
+            scalar.divn(w_start as u32);
+            let scalar = scalar.as_ref()[0] % (1 << c);
+
+            if scalar != 0 {
+                buckets[(scalar - 1) as usize].add_assign_mixed(base);
+            }
+        }
+    }
+
+    // Sum the buckets of each scalar vector, weighted by their digits.
+    cfg_into_iter!(buckets)
+        .map(|buckets| {
+            let mut res = G::Projective::zero();
+            let mut running_sum = G::Projective::zero();
+            for bucket in buckets.into_iter().rev() {
+                running_sum += bucket;
+                res += running_sum;
+            }
+            res
+        })
+        .collect()
+}
+
+///
+/// Returns the MSM of the bases with each of the scalar vectors, with windows of `c` bits over the lowest
+/// `num_bits` bits of the scalars. Each scalar vector must have the same length as the bases.
+///
+pub(super) fn msm<G: AffineCurve>(
+    bases: &[G],
+    scalar_vecs: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+    c: usize,
+    num_bits: usize,
+) -> Vec<G::Projective> {
+    // We divide up the bits 0..num_bits into windows of size `c`, and in parallel process each such window,
+    // for all of the scalar vectors at once.
+    let window_sums: Vec<_> =
+        cfg_into_iter!(0..num_bits).step_by(c).map(|w_start| multi_window(bases, scalar_vecs, w_start, c)).collect();
+
+    // We're traversing windows from high to low, for each scalar vector.
+    cfg_into_iter!(0..scalar_vecs.len())
+        .map(|index| {
+            window_sums.iter().rev().fold(G::Projective::zero(), |mut total, sums| {
+                for _ in 0..c {
+                    total.double_in_place();
+                }
+                total + sums[index]
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_chunks_mut, cfg_into_iter, cfg_iter};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Returns the `c` bits of the given limbs, starting from bit `start`.
+fn window_bits(limbs: &[u64], start: usize, c: usize) -> u64 {
+    let (limb, shift) = (start / 64, start % 64);
+    let mut bits = limbs.get(limb).map_or(0, |limb| limb >> shift);
+    if shift > 0 && shift + c > 64 {
+        bits |= limbs.get(limb + 1).map_or(0, |limb| limb << (64 - shift));
+    }
+    bits & ((1 << c) - 1)
+}
+
+/// Returns the signed digits of the scalar in `num_windows` windows of `c` bits, from the lowest window.
+///
+/// Each digit is in `(-2^(c - 1), 2^(c - 1)]`, where a digit above the range borrows `2^c` from the next window.
+/// As the highest window covers the top bits of the scalar with a bit to spare, its digit is at most `2^(c - 1)`,
+/// and absorbs the final carry.
+fn signed_digits<B: AsRef<[u64]>>(scalar: &B, c: usize, num_windows: usize, digits: &mut [i64]) {
+    let half = 1 << (c - 1);
+    let mut carry = 0;
+    for (window, digit) in digits.iter_mut().enumerate().take(num_windows) {
+        let raw = window_bits(scalar.as_ref(), window * c, c) + carry;
+        if raw > half && window + 1 < num_windows {
+            *digit = raw as i64 - (1 << c);
+            carry = 1;
+        } else {
+            *digit = raw as i64;
+            carry = 0;
+        }
+    }
+    debug_assert_eq!(carry, 0);
+}
+
+/// Returns the sum of the window at `window`, with a bucket for each digit magnitude in `1..=2^(c - 1)`.
+fn signed_window<G: AffineCurve>(
+    bases: &[G],
+    digits: &[i64],
+    num_windows: usize,
+    window: usize,
+    c: usize,
+) -> G::Projective {
+    // A negative digit adds the negation of the base to the bucket of its magnitude,
+    // so that only 2^(c - 1) buckets are needed.
+    let mut buckets = vec![G::Projective::zero(); 1 << (c - 1)];
+    for (base, digits) in bases.iter().zip(digits.chunks(num_windows)) {
+        let digit = digits[window];
+        if digit > 0 {
+            buckets[(digit - 1) as usize].add_assign_mixed(base);
+        } else if digit < 0 {
+            buckets[(-digit - 1) as usize].add_assign_mixed(&base.neg());
+        }
+    }
+
+    let mut res = G::Projective::zero();
+    let mut running_sum = G::Projective::zero();
+    for bucket in buckets.into_iter().rev() {
+        running_sum += bucket;
+        res += running_sum;
+    }
+    res
+}
+
+///
+/// Returns the MSM of the given bases and scalars, with signed digits in windows of `c` bits over the lowest
+/// `num_bits` bits of the scalars. The window size must be at least 2.
+///
+pub(super) fn msm<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    c: usize,
+    num_bits: usize,
+) -> G::Projective {
+    debug_assert!(c >= 2);
+
+    // Recode each scalar into signed digits, with an extra window for the final carry if the top window is full.
+    let num_windows = num_bits / c + 1;
+    let mut digits = vec![0i64; scalars.len() * num_windows];
+    cfg_iter!(scalars)
+        .zip(cfg_chunks_mut!(digits, num_windows))
+        .for_each(|(scalar, digits)| signed_digits(scalar, c, num_windows, digits));
+
+    // Process each window in parallel.
+    let window_sums: Vec<_> =
+        cfg_into_iter!(0..num_windows).map(|window| signed_window(bases, &digits, num_windows, window, c)).collect();
+
+    // We're traversing windows from high to low.
+    window_sums.into_iter().rev().fold(G::Projective::zero(), |mut total, sum_i| {
+        for _ in 0..c {
+            total.double_in_place();
+        }
+        total + sum_i
+    })
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::Zero;
+use snarkvm_utilities::cfg_into_iter;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Returns the sum of the window starting at `w_start`, for scalars of at most 128 bits.
+fn small_window<G: AffineCurve, S: Copy + Into<u128>>(
+    bases: &[G],
+    scalars: &[S],
+    w_start: usize,
+    c: usize,
+) -> G::Projective {
+    // We don't need the "zero" bucket, so we only have 2^c - 1 buckets.
+    let mut buckets = vec![G::Projective::zero(); (1 << c) - 1];
+    for (base, scalar) in bases.iter().zip(scalars) {
+        // We right-shift by w_start, and mod the remaining bits by the window size.
+        let digit = ((*scalar).into() >> w_start) as usize & ((1 << c) - 1);
+        if digit != 0 {
+            buckets[digit - 1].add_assign_mixed(base);
+        }
+    }
+
+    let mut res = G::Projective::zero();
+    let mut running_sum = G::Projective::zero();
+    for bucket in buckets.into_iter().rev() {
+        running_sum += bucket;
+        res += running_sum;
+    }
+    res
+}
+
+///
+/// Returns the MSM of the given bases and scalars of at most 128 bits, with windows of `c` bits
+/// over the lowest `num_bits` bits of the scalars.
+///
+pub(super) fn msm<G: AffineCurve, S: Copy + Into<u128> + Send + Sync>(
+    bases: &[G],
+    scalars: &[S],
+    c: usize,
+    num_bits: usize,
+) -> G::Projective {
+    debug_assert!(num_bits <= 128);
+
+    // We divide up the bits 0..num_bits into windows of size `c`, and in parallel process each such window.
+    let window_sums: Vec<_> =
+        cfg_into_iter!(0..num_bits).step_by(c).map(|w_start| small_window(bases, scalars, w_start, c)).collect();
+
+    // We're traversing windows from high to low.
+    window_sums.into_iter().rev().fold(G::Projective::zero(), |mut total, sum_i| {
+        for _ in 0..c {
+            total.double_in_place();
+        }
+        total + sum_i
+    })
+}
@@ -77,15 +77,15 @@ fn standard_window<G: AffineCurve>(
     (res, window_size)
 }
 
-pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
-    // Determine the bucket size `c` (chosen empirically).
-    let c = match scalars.len() < 32 {
-        true => 1,
-        false => crate::msm::ln_without_floats(scalars.len()) + 2,
-    };
-
-    let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
-
+///
+/// Returns the MSM of the given bases and scalars, with windows of `c` bits over the lowest `num_bits` bits of the scalars.
+///
+pub(super) fn msm<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    c: usize,
+    num_bits: usize,
+) -> G::Projective {
     // Each window is of size `c`.
     // We divide up the bits 0..num_bits into windows of size `c`, and
     // in parallel process each such window.
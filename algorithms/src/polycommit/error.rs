@@ -67,8 +67,13 @@ pub enum PCError {
     /// The lagrange basis is larger than the supported degree,
     LagrangeBasisSizeIsTooLarge,
 
-    /// The degree provided to `trim` was too large.
-    TrimmingDegreeTooLarge,
+    /// The degree provided to `trim` was larger than the maximum degree of the universal parameters.
+    TrimmingDegreeTooLarge {
+        /// The degree provided to `trim`.
+        degree: usize,
+        /// The maximum degree of the universal parameters.
+        max_degree: usize,
+    },
 
     /// The provided equation contained multiple polynomials, of which least one
     /// had a strict degree bound.
@@ -81,6 +86,14 @@ pub enum PCError {
     /// had a strict degree bound.
     UnsupportedLagrangeBasisSize(usize),
 
+    /// The number of evaluations does not match the size of the Lagrange basis.
+    IncorrectNumberOfEvaluations {
+        /// The number of evaluations.
+        num_evaluations: usize,
+        /// The size of the domain of the Lagrange basis.
+        domain_size: usize,
+    },
+
     /// The degree bound for the `index`-th polynomial passed to `commit`, `open`
     /// or `check` was incorrect, that is, `degree_bound >= poly_degree` or
     /// `degree_bound <= max_degree`.
@@ -95,6 +108,14 @@ pub enum PCError {
         label: String,
     },
 
+    /// The index opened in a vector commitment is outside the vector.
+    IndexOutOfRange {
+        /// The opened index.
+        index: usize,
+        /// The length of the vector.
+        length: usize,
+    },
+
     Terminated,
 }
 
@@ -132,7 +153,11 @@ impl core::fmt::Display for PCError {
                 "the degree of the hiding poly ({:?}) is not less than the maximum number of powers in `Powers` ({:?})",
                 hiding_poly_degree, num_powers
             ),
-            PCError::TrimmingDegreeTooLarge => write!(f, "the degree provided to `trim` was too large"),
+            PCError::TrimmingDegreeTooLarge { degree, max_degree } => write!(
+                f,
+                "the degree provided to `trim` ({:?}) is greater than the maximum degree of the parameters ({:?})",
+                degree, max_degree
+            ),
             PCError::EquationHasDegreeBounds(e) => {
                 write!(f, "the eqaution \"{}\" contained degree-bounded polynomials", e)
             }
@@ -148,6 +173,11 @@ impl core::fmt::Display for PCError {
             PCError::LagrangeBasisSizeIsTooLarge => {
                 write!(f, "the Lagrange Basis size larger than max supported degree")
             }
+            PCError::IncorrectNumberOfEvaluations { num_evaluations, domain_size } => write!(
+                f,
+                "the number of evaluations ({:?}) does not match the size of the domain ({:?})",
+                num_evaluations, domain_size
+            ),
             PCError::IncorrectDegreeBound { poly_degree, degree_bound, supported_degree, label } => write!(
                 f,
                 "the degree bound ({:?}) for the polynomial {} \
@@ -155,6 +185,9 @@ impl core::fmt::Display for PCError {
                  supported degree ({:?})",
                 degree_bound, label, poly_degree, supported_degree
             ),
+            PCError::IndexOutOfRange { index, length } => {
+                write!(f, "the index ({:?}) is outside the vector of length {:?}", index, length)
+            }
             PCError::Terminated => write!(f, "terminated"),
         }
     }
@@ -47,6 +47,9 @@ use rayon::prelude::*;
 mod data_structures;
 pub use data_structures::*;
 
+mod vector;
+pub use vector::*;
+
 use super::sonic_pc::LabeledPolynomialWithBasis;
 
 #[derive(Debug, PartialEq, Eq)]
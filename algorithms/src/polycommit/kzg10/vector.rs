@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    fft::{DensePolynomial, EvaluationDomain},
+    polycommit::{
+        kzg10::{Commitment, Powers, Proof, Randomness, UniversalParams, VerifierKey, KZG10},
+        PCError,
+    },
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::Field;
+
+use core::sync::atomic::AtomicBool;
+use std::borrow::Cow;
+
+/// `VectorCommitment` commits to a vector of field elements, and opens it at a single index.
+///
+/// The vector is encoded as the evaluations of a polynomial over a multiplicative subgroup,
+/// and the opening at an index is a KZG10 evaluation proof at the corresponding element of the subgroup.
+#[derive(Clone, Debug)]
+pub struct VectorCommitment<E: PairingEngine> {
+    /// The powers used to commit to and open the polynomial.
+    powers: Powers<'static, E>,
+    /// The key used to check the openings.
+    vk: VerifierKey<E>,
+    /// The subgroup over which the vector is encoded.
+    domain: EvaluationDomain<E::Fr>,
+}
+
+impl<E: PairingEngine> VectorCommitment<E> {
+    /// Specializes the universal parameters for vectors of up to `max_length` elements.
+    pub fn setup(pp: &UniversalParams<E>, max_length: usize) -> Result<Self, PCError> {
+        let domain = EvaluationDomain::new(max_length.max(2)).ok_or(PCError::LagrangeBasisSizeIsTooLarge)?;
+        if domain.size() - 1 > pp.max_degree() {
+            pp.download_up_to(domain.size()).map_err(|_| PCError::TrimmingDegreeTooLarge {
+                degree: domain.size() - 1,
+                max_degree: pp.max_degree(),
+            })?;
+        }
+
+        let powers = Powers {
+            powers_of_beta_g: Cow::Owned(pp.powers_of_beta_g(0, domain.size())),
+            powers_of_beta_times_gamma_g: Cow::Owned(vec![]),
+        };
+        let vk = VerifierKey {
+            g: pp.power_of_beta_g(0),
+            gamma_g: pp.get_powers_times_gamma_g()[&0],
+            h: pp.h,
+            beta_h: pp.beta_h,
+            prepared_h: pp.prepared_h.clone(),
+            prepared_beta_h: pp.prepared_beta_h.clone(),
+        };
+        Ok(Self { powers, vk, domain })
+    }
+
+    /// Returns the maximum length of a committed vector.
+    pub fn max_length(&self) -> usize {
+        self.domain.size()
+    }
+
+    /// Outputs a commitment to `vector`, which is zero-filled to the maximum length.
+    pub fn commit(&self, vector: &[E::Fr]) -> Result<CommittedVector<'_, E>, PCError> {
+        if vector.len() > self.max_length() {
+            return Err(PCError::TooManyCoefficients { num_coefficients: vector.len(), num_powers: self.max_length() });
+        }
+
+        // Interpolate the polynomial whose evaluations over the subgroup are the vector.
+        let polynomial = DensePolynomial::from_coefficients_vec(self.domain.ifft(vector));
+        let (commitment, _) = KZG10::commit(&self.powers, &(&polynomial).into(), None, &AtomicBool::new(false), None)?;
+        Ok(CommittedVector { scheme: self, polynomial, commitment })
+    }
+
+    /// Verifies that `value` is the element at `index` of the vector committed inside `commitment`.
+    pub fn verify(&self, commitment: &Commitment<E>, index: usize, value: E::Fr, proof: &Proof<E>) -> bool {
+        // Ensure the index is in the vector, and the proof is not hiding.
+        if index >= self.max_length() || proof.is_hiding() {
+            return false;
+        }
+        matches!(KZG10::check(&self.vk, commitment, self.point(index), value, proof), Ok(true))
+    }
+
+    /// Returns the element of the subgroup at which the vector is opened at `index`.
+    fn point(&self, index: usize) -> E::Fr {
+        self.domain.group_gen.pow([index as u64])
+    }
+}
+
+/// `CommittedVector` is a vector committed by `VectorCommitment::commit`, which can be opened at an index.
+#[derive(Clone, Debug)]
+pub struct CommittedVector<'a, E: PairingEngine> {
+    /// The scheme the vector is committed with.
+    scheme: &'a VectorCommitment<E>,
+    /// The polynomial whose evaluations over the subgroup are the vector.
+    polynomial: DensePolynomial<E::Fr>,
+    /// The commitment to the polynomial.
+    commitment: Commitment<E>,
+}
+
+impl<E: PairingEngine> CommittedVector<'_, E> {
+    /// Returns the commitment to the vector.
+    pub fn commitment(&self) -> Commitment<E> {
+        self.commitment
+    }
+
+    /// Outputs a proof of the element at `index` of the vector.
+    pub fn open(&self, index: usize) -> Result<Proof<E>, PCError> {
+        if index >= self.scheme.max_length() {
+            return Err(PCError::IndexOutOfRange { index, length: self.scheme.max_length() });
+        }
+        KZG10::open(&self.scheme.powers, &self.polynomial, self.scheme.point(index), &Randomness::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polycommit::kzg10::KZG10DegreeBoundsConfig;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_fields::One;
+    use snarkvm_utilities::rand::{test_rng, UniformRand};
+
+    type TestVectorCommitment = VectorCommitment<Bls12_377>;
+
+    fn setup(max_length: usize) -> TestVectorCommitment {
+        let pp = KZG10::setup(max_length, &KZG10DegreeBoundsConfig::NONE, false, &mut test_rng()).unwrap();
+        TestVectorCommitment::setup(&pp, max_length).unwrap()
+    }
+
+    #[test]
+    fn test_vector_commitment_open() {
+        let rng = &mut test_rng();
+        let scheme = setup(16);
+
+        for length in [0, 1, 5, 16] {
+            let vector = (0..length).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            let committed = scheme.commit(&vector).unwrap();
+            let commitment = committed.commitment();
+
+            // Ensure every index opens to its element, and the zero-filled tail opens to zero.
+            for index in 0..scheme.max_length() {
+                let value = vector.get(index).copied().unwrap_or_default();
+                let proof = committed.open(index).unwrap();
+                assert!(scheme.verify(&commitment, index, value, &proof));
+            }
+        }
+
+        // Ensure a vector longer than the maximum length, or an index outside the vector, fails.
+        assert!(scheme.commit(&vec![Fr::one(); 17]).is_err());
+        assert!(scheme.commit(&[Fr::one()]).unwrap().open(16).is_err());
+    }
+
+    #[test]
+    fn test_vector_commitment_tampered_opening() {
+        let rng = &mut test_rng();
+        let scheme = setup(8);
+
+        let vector = (0..8).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let committed = scheme.commit(&vector).unwrap();
+        let commitment = committed.commitment();
+        let proof = committed.open(3).unwrap();
+        assert!(scheme.verify(&commitment, 3, vector[3], &proof));
+
+        // Ensure a wrong value, a wrong index, or an index outside the vector fails.
+        assert!(!scheme.verify(&commitment, 3, vector[3] + Fr::one(), &proof));
+        assert!(!scheme.verify(&commitment, 3, vector[4], &proof));
+        assert!(!scheme.verify(&commitment, 4, vector[3], &proof));
+        assert!(!scheme.verify(&commitment, 3 + 8, vector[3], &proof));
+
+        // Ensure a proof against another commitment fails.
+        let mut other = vector.clone();
+        other[0] += Fr::one();
+        let other = scheme.commit(&other).unwrap();
+        assert!(!scheme.verify(&other.commitment(), 3, vector[3], &proof));
+
+        // Ensure a hiding proof fails.
+        let hiding = Proof { random_v: Some(Fr::one()), ..proof };
+        assert!(!scheme.verify(&commitment, 3, vector[3], &hiding));
+    }
+}
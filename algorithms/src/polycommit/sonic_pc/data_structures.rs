@@ -17,13 +17,14 @@
 use crate::{
     crypto_hash::sha256::sha256,
     fft::EvaluationDomain,
-    polycommit::kzg10,
+    msm::VariableBase,
+    polycommit::{kzg10, PCError},
     snark::marlin::{FiatShamirError, FiatShamirRng},
     Prepare,
 };
 use hashbrown::HashMap;
-use snarkvm_curves::{PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
+use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
 use std::{
@@ -359,6 +360,53 @@ impl<E: PairingEngine> CommitterKey<E> {
             domain,
         })
     }
+
+    ///
+    /// Returns a key that commits to polynomials given by their evaluations over `domain`.
+    ///
+    /// The basis is taken from `self` if it was trimmed for `domain`, and is otherwise computed
+    /// with an inverse FFT over the powers of beta, which requires `domain` to fit in the key.
+    ///
+    pub fn to_lagrange_basis(&self, domain: EvaluationDomain<E::Fr>) -> Result<LagrangeCommitterKey<E>, PCError> {
+        if let Some(basis) = self.lagrange_bases_at_beta_g.get(&domain.size()) {
+            return Ok(LagrangeCommitterKey { lagrange_basis_at_beta_g: basis.clone() });
+        }
+        if domain.size() > self.powers_of_beta_g.len() {
+            return Err(PCError::LagrangeBasisSizeIsTooLarge);
+        }
+        let powers = self.powers_of_beta_g[..domain.size()].iter().map(|p| p.to_projective()).collect::<Vec<_>>();
+        let basis = E::G1Projective::batch_normalization_into_affine(domain.ifft(&powers));
+        Ok(LagrangeCommitterKey { lagrange_basis_at_beta_g: basis })
+    }
+}
+
+/// `LagrangeCommitterKey` commits to polynomials given by their evaluations over a domain,
+/// without interpolating them first.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct LagrangeCommitterKey<E: PairingEngine> {
+    /// The Lagrange basis polynomials of the domain, evaluated at beta, times the generator of G1.
+    lagrange_basis_at_beta_g: Vec<E::G1Affine>,
+}
+
+impl<E: PairingEngine> LagrangeCommitterKey<E> {
+    /// Returns the size of the domain that `self` commits over.
+    pub fn size(&self) -> usize {
+        self.lagrange_basis_at_beta_g.len()
+    }
+
+    /// Outputs a (non-hiding) commitment to the polynomial with the given evaluations over the domain.
+    /// The commitment is identical to a commitment to the interpolated polynomial.
+    pub fn commit_evaluations(&self, evaluations: &[E::Fr]) -> Result<Commitment<E>, PCError> {
+        if evaluations.len() != self.size() {
+            return Err(PCError::IncorrectNumberOfEvaluations {
+                num_evaluations: evaluations.len(),
+                domain_size: self.size(),
+            });
+        }
+        let evaluations = evaluations.iter().map(|e| e.to_repr()).collect::<Vec<_>>();
+        let commitment = VariableBase::msm(&self.lagrange_basis_at_beta_g, &evaluations);
+        Ok(kzg10::Commitment(commitment.into()))
+    }
 }
 
 impl<E: PairingEngine> CommitterKey<E> {
@@ -497,6 +545,11 @@ impl<E: PairingEngine> BatchProof<E> {
     pub fn is_hiding(&self) -> bool {
         self.0.iter().any(|c| c.is_hiding())
     }
+
+    /// Returns the evaluation proofs, one for each point in the query set.
+    pub fn proofs(&self) -> &[kzg10::Proof<E>] {
+        &self.0
+    }
 }
 
 impl<E: PairingEngine> BatchProof<E> {
@@ -511,6 +564,39 @@ impl<E: PairingEngine> BatchProof<E> {
     }
 }
 
+///
+/// The pairing inputs of a batch check, accumulated before the final pairing product is computed.
+///
+/// Checks under the same verifier key can be combined with `add_scaled`, so that they are verified
+/// together with a single pairing product.
+///
+#[derive(Clone, Debug)]
+pub(crate) struct AccumulatedCheck<E: PairingEngine> {
+    pub(crate) combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    pub(crate) combined_witness: E::G1Projective,
+    pub(crate) combined_adjusted_witness: E::G1Projective,
+}
+
+impl<E: PairingEngine> AccumulatedCheck<E> {
+    /// Returns an accumulator that holds no checks.
+    pub(crate) fn empty() -> Self {
+        Self {
+            combined_comms: BTreeMap::new(),
+            combined_witness: E::G1Projective::zero(),
+            combined_adjusted_witness: E::G1Projective::zero(),
+        }
+    }
+
+    /// Adds `other`, scaled by `randomizer`, to `self`.
+    pub(crate) fn add_scaled(&mut self, other: &Self, randomizer: E::Fr) {
+        for (degree_bound, comm) in &other.combined_comms {
+            *self.combined_comms.entry(*degree_bound).or_insert_with(E::G1Projective::zero) += *comm * randomizer;
+        }
+        self.combined_witness += other.combined_witness * randomizer;
+        self.combined_adjusted_witness += other.combined_adjusted_witness * randomizer;
+    }
+}
+
 /// Labels a `LabeledPolynomial` or a `LabeledCommitment`.
 pub type PolynomialLabel = String;
 
@@ -39,6 +39,9 @@ pub use data_structures::*;
 mod polynomial;
 pub use polynomial::*;
 
+mod trim_cache;
+pub use trim_cache::*;
+
 /// Polynomial commitment based on [\[KZG10\]][kzg], with degree enforcement and
 /// batching taken from [[MBKM19, “Sonic”]][sonic] (more precisely, their
 /// counterparts in [[Gabizon19, “AuroraLight”]][al] that avoid negative G1 powers).
@@ -69,7 +72,9 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         let trim_time = start_timer!(|| "Trimming public parameters");
         let mut max_degree = pp.max_degree();
         if supported_degree > max_degree {
-            pp.download_up_to(supported_degree).map_err(|_| PCError::TrimmingDegreeTooLarge)?;
+            // The powers are downloaded up to a number of powers, which is one more than the degree.
+            pp.download_up_to(supported_degree + 1)
+                .map_err(|_| PCError::TrimmingDegreeTooLarge { degree: supported_degree, max_degree })?;
             max_degree = pp.max_degree();
         }
 
@@ -398,6 +403,8 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         batch_proof
     }
 
+    /// Checks a proof produced by `batch_open`, combining the openings at every point in the query set
+    /// with random challenges, so that they are verified together with a single pairing product.
     pub fn batch_check<'a>(
         vk: &VerifierKey<E>,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
@@ -406,6 +413,22 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let check = Self::accumulate_batch_check(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_elems(check, vk)
+    }
+
+    /// Accumulates the pairing inputs of `batch_check` without computing the final pairing product.
+    pub(crate) fn accumulate_batch_check<'a>(
+        vk: &VerifierKey<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<AccumulatedCheck<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -462,7 +485,7 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             randomizer = batch_kzg_check_fs_rng.squeeze_short_nonnative_field_element()?;
         }
 
-        Self::check_elems(combined_comms, combined_witness, combined_adjusted_witness, vk)
+        Ok(AccumulatedCheck { combined_comms, combined_witness, combined_adjusted_witness })
     }
 
     pub fn open_combinations<'a>(
@@ -554,6 +577,24 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let check =
+            Self::accumulate_combinations(vk, linear_combinations, commitments, query_set, evaluations, proof, fs_rng)?;
+        Self::check_elems(check, vk)
+    }
+
+    /// Accumulates the pairing inputs of `check_combinations` without computing the final pairing product.
+    pub(crate) fn accumulate_combinations<'a>(
+        vk: &VerifierKey<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<AccumulatedCheck<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -609,7 +650,7 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::accumulate_batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
     }
 }
 
@@ -707,33 +748,43 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         end_timer!(acc_time);
     }
 
-    #[allow(clippy::type_complexity)]
-    fn check_elems(
-        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
-        combined_witness: E::G1Projective,
-        combined_adjusted_witness: E::G1Projective,
-        vk: &VerifierKey<E>,
+    /// Computes the pairing product of an accumulated check, and returns whether it is one.
+    pub(crate) fn check_elems(check: AccumulatedCheck<E>, vk: &VerifierKey<E>) -> Result<bool, PCError> {
+        Self::check_elems_batch([(check, vk)])
+    }
+
+    ///
+    /// Computes the pairing product of several accumulated checks, each under its own verifier key,
+    /// and returns whether it is one.
+    ///
+    /// The checks must have been scaled by random scalars, so that invalid checks cannot cancel each other out.
+    ///
+    pub(crate) fn check_elems_batch<'a>(
+        checks: impl IntoIterator<Item = (AccumulatedCheck<E>, &'a VerifierKey<E>)>,
     ) -> Result<bool, PCError> {
         let check_time = start_timer!(|| "Checking elems");
-        let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
-        let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
-
-        for (degree_bound, comm) in combined_comms.into_iter() {
-            let shift_power = if let Some(degree_bound) = degree_bound {
-                vk.get_prepared_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
-            } else {
-                vk.vk.prepared_h.clone()
-            };
+        let mut g1_projective_elems = Vec::new();
+        let mut g2_prepared_elems = Vec::new();
+
+        for (check, vk) in checks {
+            let AccumulatedCheck { combined_comms, combined_witness, combined_adjusted_witness } = check;
+            for (degree_bound, comm) in combined_comms.into_iter() {
+                let shift_power = if let Some(degree_bound) = degree_bound {
+                    vk.get_prepared_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
+                } else {
+                    vk.vk.prepared_h.clone()
+                };
 
-            g1_projective_elems.push(comm);
-            g2_prepared_elems.push(shift_power);
-        }
+                g1_projective_elems.push(comm);
+                g2_prepared_elems.push(shift_power);
+            }
 
-        g1_projective_elems.push(-combined_adjusted_witness);
-        g2_prepared_elems.push(vk.vk.prepared_h.clone());
+            g1_projective_elems.push(-combined_adjusted_witness);
+            g2_prepared_elems.push(vk.vk.prepared_h.clone());
 
-        g1_projective_elems.push(-combined_witness);
-        g2_prepared_elems.push(vk.vk.prepared_beta_h.clone());
+            g1_projective_elems.push(-combined_witness);
+            g2_prepared_elems.push(vk.vk.prepared_beta_h.clone());
+        }
 
         let g1_prepared_elems_iter = E::G1Projective::batch_normalization_into_affine(g1_projective_elems)
             .into_iter()
@@ -784,6 +835,16 @@ mod tests {
         assert_eq!(&ck_bytes, &ck_recovered_bytes);
     }
 
+    #[test]
+    fn test_batch_open() {
+        batch_open_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+    }
+
+    #[test]
+    fn test_lagrange_committer_key() {
+        lagrange_committer_key_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+    }
+
     #[test]
     fn test_single_poly() {
         single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
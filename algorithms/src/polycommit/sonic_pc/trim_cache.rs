@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{CommitterKey, SonicKZG10, UniversalParams, VerifierKey};
+use crate::{polycommit::PCError, snark::marlin::FiatShamirRng};
+use snarkvm_curves::PairingEngine;
+
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use parking_lot::RwLock;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+/// The parameters that a pair of committer and verifier keys are trimmed for.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct TrimParameters {
+    supported_degree: usize,
+    supported_lagrange_sizes: BTreeSet<usize>,
+    supported_hiding_bound: usize,
+    enforced_degree_bounds: Option<BTreeSet<usize>>,
+}
+
+///
+/// `TrimCache` memoizes the committer and verifier keys trimmed from a universal SRS.
+///
+/// Keys are trimmed on the first request for a given set of parameters, and are shared behind
+/// an `Arc` afterwards, so that circuits of the same size do not re-trim or copy the powers.
+///
+#[derive(Debug)]
+pub struct TrimCache<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> {
+    universal_params: UniversalParams<E>,
+    keys: RwLock<BTreeMap<TrimParameters, (Arc<CommitterKey<E>>, Arc<VerifierKey<E>>)>>,
+    num_trims: AtomicUsize,
+    _sponge: PhantomData<S>,
+}
+
+impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> TrimCache<E, S> {
+    /// Initializes an empty cache for keys trimmed from `universal_params`.
+    pub fn new(universal_params: UniversalParams<E>) -> Self {
+        Self { universal_params, keys: Default::default(), num_trims: AtomicUsize::new(0), _sponge: PhantomData }
+    }
+
+    /// Returns the universal parameters that keys are trimmed from.
+    pub fn universal_params(&self) -> &UniversalParams<E> {
+        &self.universal_params
+    }
+
+    ///
+    /// Returns the keys trimmed from the universal parameters, as in `SonicKZG10::trim`.
+    ///
+    /// The keys are trimmed on the first request for the given parameters, and are served from the cache
+    /// afterwards. The order of `supported_lagrange_sizes` and `enforced_degree_bounds` does not matter.
+    ///
+    pub fn trim(
+        &self,
+        supported_degree: usize,
+        supported_lagrange_sizes: impl IntoIterator<Item = usize>,
+        supported_hiding_bound: usize,
+        enforced_degree_bounds: Option<&[usize]>,
+    ) -> Result<(Arc<CommitterKey<E>>, Arc<VerifierKey<E>>), PCError> {
+        let parameters = TrimParameters {
+            supported_degree,
+            supported_lagrange_sizes: supported_lagrange_sizes.into_iter().collect(),
+            supported_hiding_bound,
+            enforced_degree_bounds: enforced_degree_bounds.map(|bounds| bounds.iter().copied().collect()),
+        };
+        if let Some((committer_key, verifier_key)) = self.keys.read().get(&parameters) {
+            return Ok((committer_key.clone(), verifier_key.clone()));
+        }
+
+        // The lock is not held while trimming, so a concurrent request may trim the same keys,
+        // in which case the keys that were inserted first are kept.
+        let enforced_degree_bounds =
+            parameters.enforced_degree_bounds.as_ref().map(|bounds| bounds.iter().copied().collect::<Vec<_>>());
+        let (committer_key, verifier_key) = SonicKZG10::<E, S>::trim(
+            &self.universal_params,
+            supported_degree,
+            parameters.supported_lagrange_sizes.iter().copied(),
+            supported_hiding_bound,
+            enforced_degree_bounds.as_deref(),
+        )?;
+        self.num_trims.fetch_add(1, Ordering::Relaxed);
+
+        let (committer_key, verifier_key) =
+            self.keys.write().entry(parameters).or_insert((Arc::new(committer_key), Arc::new(verifier_key))).clone();
+        Ok((committer_key, verifier_key))
+    }
+
+    /// Returns the number of times that keys were trimmed, which is the number of cache misses.
+    pub fn num_trims(&self) -> usize {
+        self.num_trims.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crypto_hash::PoseidonSponge,
+        fft::DensePolynomial,
+        polycommit::sonic_pc::LabeledPolynomial,
+        snark::marlin::FiatShamirAlgebraicSpongeRng,
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_utilities::{rand::test_rng, ToBytes};
+
+    type Sponge = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>;
+    type PC = SonicKZG10<Bls12_377, Sponge>;
+
+    #[test]
+    fn test_trim_cache_hits() {
+        let rng = &mut test_rng();
+        let cache = TrimCache::<Bls12_377, Sponge>::new(PC::setup(64, rng).unwrap());
+
+        let (ck, vk) = cache.trim(32, [16], 1, Some(&[4, 8])).unwrap();
+        assert_eq!(cache.num_trims(), 1);
+
+        // The same parameters, in any order, are served from the cache.
+        let (cached_ck, cached_vk) = cache.trim(32, [16], 1, Some(&[8, 4, 8])).unwrap();
+        assert_eq!(cache.num_trims(), 1);
+        assert!(Arc::ptr_eq(&ck, &cached_ck));
+        assert!(Arc::ptr_eq(&vk, &cached_vk));
+
+        // Any change in the parameters trims new keys.
+        cache.trim(16, [16], 1, Some(&[4, 8])).unwrap();
+        cache.trim(32, [8, 16], 1, Some(&[4, 8])).unwrap();
+        cache.trim(32, [16], 0, Some(&[4, 8])).unwrap();
+        cache.trim(32, [16], 1, Some(&[4])).unwrap();
+        cache.trim(32, [16], 1, None).unwrap();
+        assert_eq!(cache.num_trims(), 6);
+
+        cache.trim(16, [16], 1, Some(&[4, 8])).unwrap();
+        assert_eq!(cache.num_trims(), 6);
+    }
+
+    #[test]
+    fn test_trim_cache_matches_trim() {
+        let rng = &mut test_rng();
+        let pp = PC::setup(64, rng).unwrap();
+        let cache = TrimCache::<Bls12_377, Sponge>::new(pp.clone());
+
+        for (supported_degree, enforced_degree_bounds) in [(64, None), (20, Some(vec![5, 10, 20])), (7, Some(vec![3]))]
+        {
+            let (ck, vk) = cache.trim(supported_degree, None, 0, enforced_degree_bounds.as_deref()).unwrap();
+            let (fresh_ck, fresh_vk) =
+                PC::trim(&pp, supported_degree, None, 0, enforced_degree_bounds.as_deref()).unwrap();
+            assert_eq!(ck.to_bytes_le().unwrap(), fresh_ck.to_bytes_le().unwrap());
+            assert_eq!(*vk, fresh_vk);
+
+            // Commitments, including those with enforced degree bounds, match those made with fresh keys.
+            let degree_bounds = enforced_degree_bounds.unwrap_or_default();
+            let polynomials = (0..4)
+                .map(|i| {
+                    let degree_bound = degree_bounds.get(i).copied();
+                    let degree = degree_bound.unwrap_or(supported_degree);
+                    LabeledPolynomial::new(format!("Test{}", i), DensePolynomial::rand(degree, rng), degree_bound, None)
+                })
+                .collect::<Vec<_>>();
+            let (comms, _) = PC::commit(&ck, polynomials.iter().map(Into::into), None).unwrap();
+            let (fresh_comms, _) = PC::commit(&fresh_ck, polynomials.iter().map(Into::into), None).unwrap();
+            assert_eq!(comms, fresh_comms);
+        }
+    }
+
+    #[test]
+    fn test_trim_cache_degree_too_large() {
+        let rng = &mut test_rng();
+        let cache = TrimCache::<Bls12_377, Sponge>::new(PC::setup(64, rng).unwrap());
+        let max_degree = cache.universal_params().max_degree();
+
+        let result = cache.trim(max_degree + 1, None, 0, None);
+        assert!(
+            matches!(result, Err(PCError::TrimmingDegreeTooLarge { degree, max_degree: max }) if degree == max_degree + 1 && max == max_degree)
+        );
+        assert_eq!(cache.num_trims(), 0);
+    }
+}
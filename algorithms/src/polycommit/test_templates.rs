@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::atomic::AtomicBool};
 
 use super::sonic_pc::{
     BatchLCProof,
@@ -22,23 +22,29 @@ use super::sonic_pc::{
     Commitment,
     Evaluations,
     LabeledCommitment,
+    LagrangeCommitterKey,
     QuerySet,
     Randomness,
     SonicKZG10,
     VerifierKey,
 };
 use crate::{
-    fft::DensePolynomial,
+    fft::{DensePolynomial, EvaluationDomain},
     polycommit::{
+        kzg10,
         sonic_pc::{LabeledPolynomial, LabeledPolynomialWithBasis, LinearCombination},
         PCError,
     },
     snark::marlin::FiatShamirRng,
 };
 use itertools::Itertools;
-use snarkvm_curves::PairingEngine;
+use snarkvm_curves::{PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
-use snarkvm_utilities::{rand::test_rng, UniformRand};
+use snarkvm_utilities::{
+    rand::test_rng,
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    UniformRand,
+};
 
 use rand::{
     distributions::{self, Distribution},
@@ -222,6 +228,104 @@ pub fn lagrange_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>(
     Ok(test_components)
 }
 
+/// Opens several polynomials at several points with a single witness per point,
+/// and checks the openings with a single pairing product.
+pub fn batch_open_test<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>() -> Result<(), PCError> {
+    let rng = &mut test_rng();
+    let max_degree = 64;
+    let pp = SonicKZG10::<E, S>::setup(max_degree, rng)?;
+
+    for (num_polynomials, num_points, hiding_bound) in [(1, 1, None), (4, 1, Some(1)), (4, 3, Some(3)), (6, 2, None)] {
+        let supported_degree = distributions::Uniform::from(1..=max_degree).sample(rng);
+        let polynomials = (0..num_polynomials)
+            .map(|i| {
+                let degree = distributions::Uniform::from(0..=supported_degree).sample(rng);
+                LabeledPolynomial::new(format!("Test{}", i), DensePolynomial::rand(degree, rng), None, hiding_bound)
+            })
+            .collect::<Vec<_>>();
+        let (ck, vk) = SonicKZG10::<E, S>::trim(&pp, supported_degree, None, hiding_bound.unwrap_or(0), None)?;
+        let (comms, rands) = SonicKZG10::<E, S>::commit(&ck, polynomials.iter().map(Into::into), Some(rng))?;
+
+        // Query every polynomial at every point.
+        let mut query_set = QuerySet::new();
+        let mut values = Evaluations::new();
+        for point_id in 0..num_points {
+            let point = E::Fr::rand(rng);
+            for polynomial in &polynomials {
+                query_set.insert((polynomial.label().to_string(), (format!("rand_{}", point_id), point)));
+                values.insert((polynomial.label().to_string(), point), polynomial.evaluate(point));
+            }
+        }
+
+        let proof = SonicKZG10::batch_open(&ck, &polynomials, &comms, &query_set, &rands, &mut S::new())?;
+        assert!(SonicKZG10::batch_check(&vk, &comms, &query_set, &values, &proof, &mut S::new())?);
+
+        // There is one witness per point, regardless of the number of polynomials.
+        assert_eq!(proof.0.len(), num_points);
+        assert_eq!(proof.serialized_size(), 8 + num_points * proof.0[0].serialized_size());
+
+        // Tampering with any evaluation invalidates the proof.
+        for key in values.keys() {
+            let mut tampered_values = values.clone();
+            *tampered_values.get_mut(key).unwrap() += E::Fr::one();
+            assert!(!SonicKZG10::batch_check(&vk, &comms, &query_set, &tampered_values, &proof, &mut S::new())?);
+        }
+
+        // For one polynomial at one point, the witness is the single opening scaled by the opening challenge.
+        if num_polynomials == 1 && num_points == 1 && hiding_bound.is_none() {
+            let ((_, point), value) = values.iter().next().unwrap();
+            let polynomial = polynomials[0].polynomial().as_dense().unwrap();
+            let single_proof = kzg10::KZG10::open(&ck.powers(), polynomial, *point, &rands[0])?;
+            assert!(kzg10::KZG10::check(&vk.vk, comms[0].commitment(), *point, *value, &single_proof)?);
+
+            let challenge = S::new().squeeze_short_nonnative_field_element()?;
+            assert_eq!(proof.0[0].w, (single_proof.w * challenge).to_affine());
+        }
+    }
+    Ok(())
+}
+
+pub fn lagrange_committer_key_test<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>() -> Result<(), PCError> {
+    let rng = &mut test_rng();
+    let max_degree = 64;
+    let pp = SonicKZG10::<E, S>::setup(max_degree, rng)?;
+
+    for log_size in 1..=5 {
+        let domain = EvaluationDomain::<E::Fr>::new(1 << log_size).unwrap();
+        let (ck, _) = SonicKZG10::<E, S>::trim(&pp, max_degree, None, 0, None)?;
+        let lagrange_ck = ck.to_lagrange_basis(domain)?;
+        assert_eq!(lagrange_ck.size(), domain.size());
+
+        // The basis computed from the powers of beta matches the one computed when trimming.
+        let (trimmed_ck, _) = SonicKZG10::<E, S>::trim(&pp, max_degree, [domain.size()], 0, None)?;
+        assert_eq!(trimmed_ck.to_lagrange_basis(domain)?, lagrange_ck);
+
+        // Committing to the evaluations gives the commitment to the interpolated polynomial.
+        for _ in 0..4 {
+            let polynomial = DensePolynomial::<E::Fr>::rand(domain.size() - 1, rng);
+            let evaluations = polynomial.evaluate_over_domain_by_ref(domain).evaluations;
+            let (expected, _) =
+                kzg10::KZG10::commit(&ck.powers(), &(&polynomial).into(), None, &AtomicBool::new(false), None)?;
+            assert_eq!(lagrange_ck.commit_evaluations(&evaluations)?, expected);
+        }
+
+        // The number of evaluations must match the size of the domain.
+        let result = lagrange_ck.commit_evaluations(&vec![E::Fr::one(); domain.size() + 1]);
+        assert!(matches!(result, Err(PCError::IncorrectNumberOfEvaluations { .. })));
+
+        let mut bytes = Vec::new();
+        lagrange_ck.serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), lagrange_ck.serialized_size());
+        assert_eq!(LagrangeCommitterKey::<E>::deserialize(&mut &bytes[..]).unwrap(), lagrange_ck);
+    }
+
+    // The domain must fit in the committer key.
+    let (ck, _) = SonicKZG10::<E, S>::trim(&pp, 7, None, 0, None)?;
+    let domain = EvaluationDomain::<E::Fr>::new(16).unwrap();
+    assert!(matches!(ck.to_lagrange_basis(domain), Err(PCError::LagrangeBasisSizeIsTooLarge)));
+    Ok(())
+}
+
 fn test_template<E, S>(info: TestInfo) -> Result<Vec<TestComponents<E, S>>, PCError>
 where
     E: PairingEngine,
@@ -14,11 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{crypto_hash::Poseidon, PRF};
+use crate::{
+    crypto_hash::{Poseidon, PoseidonSponge},
+    AlgebraicSponge,
+    PRF,
+};
 use snarkvm_fields::PrimeField;
 
 use std::marker::PhantomData;
 
+///
+/// A PRF from the Poseidon sponge, which absorbs `(seed, input.len(), input)`.
+///
+/// Multiple outputs are squeezed from the same sponge, so `evaluate_many` and `evaluate_bits`
+/// absorb the seed and input once, and the first output of `evaluate_many` equals `evaluate`.
+///
 #[derive(Clone)]
 pub struct PoseidonPRF<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool>(PhantomData<F>);
 
@@ -38,4 +48,90 @@ impl<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool> PRF
         // Evaluate the preimage.
         Poseidon::<F, RATE, OPTIMIZED_FOR_WEIGHTS>::setup().evaluate(&preimage)
     }
+
+    fn evaluate_many(seed: &Self::Seed, input: &Self::Input, count: usize) -> Vec<Self::Output> {
+        Self::sponge(seed, input).squeeze(count).to_vec()
+    }
+
+    ///
+    /// Returns `num_bits` bits, squeezed from the sponge with the highest bit of each field element discarded.
+    ///
+    fn evaluate_bits(seed: &Self::Seed, input: &Self::Input, num_bits: usize) -> Vec<bool> {
+        Self::sponge(seed, input).squeeze_bits(num_bits)
+    }
+}
+
+impl<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool> PoseidonPRF<F, RATE, OPTIMIZED_FOR_WEIGHTS> {
+    /// Returns a sponge that has absorbed the preimage `(seed, input.len(), input)`.
+    fn sponge(seed: &F, input: &[F]) -> PoseidonSponge<F, RATE, 1> {
+        let poseidon = Poseidon::<F, RATE, OPTIMIZED_FOR_WEIGHTS>::setup();
+        let mut sponge = PoseidonSponge::<F, RATE, 1>::new(poseidon.parameters());
+        sponge.absorb(&[*seed, F::from(input.len() as u128)]);
+        sponge.absorb(input);
+        sponge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    type TestPRF = PoseidonPRF<Fr, 4, false>;
+
+    #[test]
+    fn test_poseidon_prf_evaluate_many() {
+        let rng = &mut test_rng();
+
+        for count in [1, 3, 4, 5, 12] {
+            let seed = Fr::rand(rng);
+            let input = (0..count).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+            let outputs = TestPRF::evaluate_many(&seed, &input, count);
+            assert_eq!(count, outputs.len());
+            assert_eq!(outputs, TestPRF::evaluate_many(&seed, &input, count));
+
+            // Ensure the first output is the single output, and fewer outputs are a prefix.
+            assert_eq!(TestPRF::evaluate(&seed, &input), outputs[0]);
+            assert_eq!(outputs[..count - 1], TestPRF::evaluate_many(&seed, &input, count - 1)[..]);
+
+            // Ensure the outputs are distinct.
+            for (i, output) in outputs.iter().enumerate() {
+                assert!(!outputs[i + 1..].contains(output));
+            }
+        }
+    }
+
+    #[test]
+    fn test_poseidon_prf_evaluate_bits() {
+        let rng = &mut test_rng();
+        let seed = Fr::rand(rng);
+        let input = vec![Fr::rand(rng)];
+
+        let bits = TestPRF::evaluate_bits(&seed, &input, 600);
+        assert_eq!(600, bits.len());
+        assert_eq!(bits, TestPRF::evaluate_bits(&seed, &input, 600));
+        assert_eq!(bits[..100], TestPRF::evaluate_bits(&seed, &input, 100)[..]);
+        assert_ne!(bits, TestPRF::evaluate_bits(&Fr::rand(rng), &input, 600));
+    }
+
+    #[test]
+    fn test_poseidon_prf_vectors() {
+        let seed = Fr::from(1u128);
+        let input = vec![Fr::from(2u128), Fr::from(3u128)];
+
+        let expected = [
+            "5768602809690914720260047588033069038961968641771867312984090057809172485337",
+            "6971707042758735858906604157969929218741142306669942782463275060290596043072",
+            "5199725530076440136227892823670454529111656504951272804048415072260098883568",
+        ];
+        let outputs = TestPRF::evaluate_many(&seed, &input, 3);
+        assert_eq!(expected[..], outputs.iter().map(|output| output.to_string()).collect::<Vec<_>>()[..]);
+        assert_eq!(expected[0], TestPRF::evaluate(&seed, &input).to_string());
+
+        let expected_bits = [1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0];
+        let bits = TestPRF::evaluate_bits(&seed, &input, 16);
+        assert_eq!(expected_bits[..], bits.iter().map(|bit| *bit as u8).collect::<Vec<_>>()[..]);
+    }
 }
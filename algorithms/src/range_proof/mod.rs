@@ -0,0 +1,417 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A Bulletproofs range proof, which shows that a Pedersen commitment `V := g^v h^gamma`
+//! opens to a value `v` in `[0, 2^n)`, without revealing the value.
+//!
+//! The prover commits to the bits of the value, and reduces the range check to an inner product
+//! of two vectors of length `n`, which is proven with an inner product argument of `2 * log2(n)` points.
+//! The proof is made non-interactive with the transcript.
+
+use crate::{
+    crh::setup_cached,
+    crypto_hash::{hash_to_curve, Transcript},
+    msm::VariableBase,
+};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::{anyhow, ensure, Result};
+use rand::{CryptoRng, Rng};
+
+#[cfg(test)]
+mod tests;
+
+/// The transcript label, which separates range proofs from other uses of the transcript.
+const RANGE_PROOF_LABEL: u128 = 4;
+
+/// The maximum bit length of the range.
+pub const MAX_RANGE_PROOF_BITS: usize = 64;
+
+///
+/// A proof that a Pedersen commitment opens to a value in `[0, 2^n)`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeProof<G: AffineCurve> {
+    /// The commitment to the bits of the value.
+    pub bits_commitment: G,
+    /// The commitment to the blinding vectors of the bits.
+    pub blinding_commitment: G,
+    /// The commitments to the coefficients of `X` and `X^2` in `t(X) := <l(X), r(X)>`.
+    pub t_commitments: [G; 2],
+    /// The blinding factor of the evaluation `t(x)`.
+    pub t_blinding: G::ScalarField,
+    /// The blinding factor of the bit and blinding commitments.
+    pub mu: G::ScalarField,
+    /// The evaluation `t(x)`.
+    pub t_evaluation: G::ScalarField,
+    /// The left commitments of each round of the inner product argument.
+    pub left: Vec<G>,
+    /// The right commitments of each round of the inner product argument.
+    pub right: Vec<G>,
+    /// The final scalars of the inner product argument.
+    pub a: G::ScalarField,
+    pub b: G::ScalarField,
+}
+
+impl<G: AffineCurve> FromBytes for RangeProof<G> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let bits_commitment = G::read_le(&mut reader)?;
+        let blinding_commitment = G::read_le(&mut reader)?;
+        let t_commitments = [G::read_le(&mut reader)?, G::read_le(&mut reader)?];
+        let t_blinding = G::ScalarField::read_le(&mut reader)?;
+        let mu = G::ScalarField::read_le(&mut reader)?;
+        let t_evaluation = G::ScalarField::read_le(&mut reader)?;
+
+        let num_rounds = u8::read_le(&mut reader)? as usize;
+        if (1 << num_rounds) > MAX_RANGE_PROOF_BITS {
+            return Err(error("Invalid number of rounds in a range proof"));
+        }
+        let mut left = Vec::with_capacity(num_rounds);
+        let mut right = Vec::with_capacity(num_rounds);
+        for _ in 0..num_rounds {
+            left.push(G::read_le(&mut reader)?);
+            right.push(G::read_le(&mut reader)?);
+        }
+
+        let a = G::ScalarField::read_le(&mut reader)?;
+        let b = G::ScalarField::read_le(&mut reader)?;
+
+        Ok(Self {
+            bits_commitment,
+            blinding_commitment,
+            t_commitments,
+            t_blinding,
+            mu,
+            t_evaluation,
+            left,
+            right,
+            a,
+            b,
+        })
+    }
+}
+
+impl<G: AffineCurve> ToBytes for RangeProof<G> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.bits_commitment.write_le(&mut writer)?;
+        self.blinding_commitment.write_le(&mut writer)?;
+        self.t_commitments[0].write_le(&mut writer)?;
+        self.t_commitments[1].write_le(&mut writer)?;
+        self.t_blinding.write_le(&mut writer)?;
+        self.mu.write_le(&mut writer)?;
+        self.t_evaluation.write_le(&mut writer)?;
+
+        (self.left.len() as u8).write_le(&mut writer)?;
+        for (left, right) in self.left.iter().zip(&self.right) {
+            left.write_le(&mut writer)?;
+            right.write_le(&mut writer)?;
+        }
+
+        self.a.write_le(&mut writer)?;
+        self.b.write_le(&mut writer)
+    }
+}
+
+/// The bases of the range proof.
+#[derive(Clone)]
+struct Generators<G: AffineCurve> {
+    /// The base of the value in the commitment.
+    g: G,
+    /// The base of the randomness in the commitment.
+    h: G,
+    /// The base of the inner product, in the inner product argument.
+    u: G,
+    /// The bases of the left vector.
+    g_vec: Vec<G>,
+    /// The bases of the right vector.
+    h_vec: Vec<G>,
+}
+
+impl<G: AffineCurve> Generators<G> {
+    /// Returns the bases for a range of `num_bits` bits, which are derived by hashing to the curve.
+    /// The bases for the maximum range are derived once, and cached for the rest of the process.
+    fn setup(num_bits: usize) -> Self {
+        let mut generators = setup_cached("AleoRangeProof0", || {
+            let base = |name: &str| hash_to_curve::<G>(&format!("AleoRangeProof0 {name}")).0;
+            Self {
+                g: base("value"),
+                h: base("randomness"),
+                u: base("inner product"),
+                g_vec: (0..MAX_RANGE_PROOF_BITS).map(|i| base(&format!("left at {i}"))).collect(),
+                h_vec: (0..MAX_RANGE_PROOF_BITS).map(|i| base(&format!("right at {i}"))).collect(),
+            }
+        });
+        generators.g_vec.truncate(num_bits);
+        generators.h_vec.truncate(num_bits);
+        generators
+    }
+}
+
+///
+/// Returns the Pedersen commitment `g^value h^randomness` to the given value.
+///
+pub fn commit<G: AffineCurve>(value: u64, randomness: &G::ScalarField) -> G {
+    let generators = Generators::<G>::setup(0);
+    (generators.g * G::ScalarField::from(value as u128) + generators.h * *randomness).to_affine()
+}
+
+///
+/// Returns a proof that the commitment to `value` under `randomness` opens to a value in `[0, 2^num_bits)`.
+///
+/// Returns an error if the value is not in the range, or if `num_bits` is not a power of two up to 64.
+///
+pub fn prove<G: AffineCurve, R: Rng + CryptoRng>(
+    value: u64,
+    randomness: &G::ScalarField,
+    num_bits: usize,
+    rng: &mut R,
+) -> Result<RangeProof<G>> {
+    check_num_bits(num_bits)?;
+    ensure!(num_bits == 64 || value >> num_bits == 0, "The value {value} is not in the range [0, 2^{num_bits})");
+
+    let bits = (0..num_bits).map(|i| (value >> i) & 1 == 1).collect::<Vec<_>>();
+    prove_bits(&commit(value, randomness), &bits, randomness, rng)
+}
+
+///
+/// Returns `true` if the proof shows that the commitment opens to a value in `[0, 2^num_bits)`.
+///
+pub fn verify<G: AffineCurve>(commitment: &G, proof: &RangeProof<G>, num_bits: usize) -> bool {
+    verify_inner(commitment, proof, num_bits).unwrap_or(false)
+}
+
+/// Ensures the number of bits is a power of two, up to the maximum.
+fn check_num_bits(num_bits: usize) -> Result<()> {
+    ensure!(
+        num_bits.is_power_of_two() && num_bits <= MAX_RANGE_PROOF_BITS,
+        "The range proof bit length must be a power of two up to {MAX_RANGE_PROOF_BITS}, found {num_bits}"
+    );
+    Ok(())
+}
+
+/// Returns a range proof for the given commitment, from the given bits of its value and its randomness.
+///
+/// The bits are not checked against the commitment, so that a proof for the wrong value can be tested.
+fn prove_bits<G: AffineCurve, R: Rng + CryptoRng>(
+    commitment: &G,
+    bits: &[bool],
+    randomness: &G::ScalarField,
+    rng: &mut R,
+) -> Result<RangeProof<G>> {
+    let num_bits = bits.len();
+    check_num_bits(num_bits)?;
+    let generators = Generators::<G>::setup(num_bits);
+    let (g, h) = (generators.g, generators.h);
+
+    let mut transcript = Transcript::<G>::new();
+    transcript.absorb_field(&G::ScalarField::from(RANGE_PROOF_LABEL));
+    transcript.absorb_field(&G::ScalarField::from(num_bits as u128));
+    transcript.absorb_point(commitment)?;
+
+    // Commit to the bits a_L of the value, and to a_R := a_L - 1, which are zero vectors if and only if a_L is binary.
+    let a_l =
+        bits.iter().map(|bit| if *bit { G::ScalarField::one() } else { G::ScalarField::zero() }).collect::<Vec<_>>();
+    let a_r = a_l.iter().map(|bit| *bit - G::ScalarField::one()).collect::<Vec<_>>();
+    let alpha = G::ScalarField::rand(rng);
+    let bits_commitment = (h * alpha + msm(&generators.g_vec, &a_l) + msm(&generators.h_vec, &a_r)).to_affine();
+
+    // Commit to the blinding vectors s_L and s_R.
+    let s_l = (0..num_bits).map(|_| G::ScalarField::rand(rng)).collect::<Vec<_>>();
+    let s_r = (0..num_bits).map(|_| G::ScalarField::rand(rng)).collect::<Vec<_>>();
+    let rho = G::ScalarField::rand(rng);
+    let blinding_commitment = (h * rho + msm(&generators.g_vec, &s_l) + msm(&generators.h_vec, &s_r)).to_affine();
+
+    transcript.absorb_point(&bits_commitment)?;
+    transcript.absorb_point(&blinding_commitment)?;
+    let (y, z) = (transcript.challenge(), transcript.challenge());
+    let (y_powers, two_powers) = (powers(y, num_bits), powers(G::ScalarField::from(2u128), num_bits));
+
+    // Compute l(X) := (a_L - z) + s_L X and r(X) := y^n * (a_R + z + s_R X) + z^2 2^n.
+    let z_squared = z.square();
+    let l_0 = a_l.iter().map(|a| *a - z).collect::<Vec<_>>();
+    let l_1 = s_l;
+    let r_0 = (0..num_bits).map(|i| y_powers[i] * (a_r[i] + z) + z_squared * two_powers[i]).collect::<Vec<_>>();
+    let r_1 = (0..num_bits).map(|i| y_powers[i] * s_r[i]).collect::<Vec<_>>();
+
+    // Commit to the coefficients t_1 and t_2 of t(X) := <l(X), r(X)>.
+    let t_1 = inner_product(&l_0, &r_1) + inner_product(&l_1, &r_0);
+    let t_2 = inner_product(&l_1, &r_1);
+    let (tau_1, tau_2) = (G::ScalarField::rand(rng), G::ScalarField::rand(rng));
+    let t_commitments = [(g * t_1 + h * tau_1).to_affine(), (g * t_2 + h * tau_2).to_affine()];
+
+    transcript.absorb_point(&t_commitments[0])?;
+    transcript.absorb_point(&t_commitments[1])?;
+    let x = transcript.challenge();
+
+    // Evaluate l(x), r(x), and t(x), with their blinding factors.
+    let l = l_0.iter().zip(&l_1).map(|(l_0, l_1)| *l_0 + x * l_1).collect::<Vec<_>>();
+    let r = r_0.iter().zip(&r_1).map(|(r_0, r_1)| *r_0 + x * r_1).collect::<Vec<_>>();
+    let t_evaluation = inner_product(&l, &r);
+    let t_blinding = tau_2 * x.square() + tau_1 * x + z_squared * randomness;
+    let mu = alpha + rho * x;
+
+    transcript.absorb_field(&t_blinding);
+    transcript.absorb_field(&mu);
+    transcript.absorb_field(&t_evaluation);
+    let q = (generators.u * transcript.challenge()).to_affine();
+
+    // Prove <l, r> == t(x) with the inner product argument, over the bases h_i^(y^-i).
+    let h_vec = scale_bases(&generators.h_vec, &inverse_powers(y, num_bits)?);
+    let (mut g_vec, mut h_vec, mut a, mut b) = (generators.g_vec, h_vec, l, r);
+    let (mut left, mut right) = (Vec::new(), Vec::new());
+    while a.len() > 1 {
+        let half = a.len() / 2;
+        let (a_lo, a_hi) = a.split_at(half);
+        let (b_lo, b_hi) = b.split_at(half);
+        let (g_lo, g_hi) = g_vec.split_at(half);
+        let (h_lo, h_hi) = h_vec.split_at(half);
+
+        let l_j = msm(g_hi, a_lo) + msm(h_lo, b_hi) + q * inner_product(a_lo, b_hi);
+        let r_j = msm(g_lo, a_hi) + msm(h_hi, b_lo) + q * inner_product(a_hi, b_lo);
+        let (l_j, r_j) = (l_j.to_affine(), r_j.to_affine());
+        transcript.absorb_point(&l_j)?;
+        transcript.absorb_point(&r_j)?;
+        left.push(l_j);
+        right.push(r_j);
+
+        let u = transcript.challenge();
+        let u_inv = u.inverse().ok_or_else(|| anyhow!("The inner product challenge is zero"))?;
+
+        a = a_lo.iter().zip(a_hi).map(|(lo, hi)| *lo * u + *hi * u_inv).collect();
+        b = b_lo.iter().zip(b_hi).map(|(lo, hi)| *lo * u_inv + *hi * u).collect();
+        g_vec = fold_bases(g_lo, g_hi, u_inv, u);
+        h_vec = fold_bases(h_lo, h_hi, u, u_inv);
+    }
+
+    Ok(RangeProof {
+        bits_commitment,
+        blinding_commitment,
+        t_commitments,
+        t_blinding,
+        mu,
+        t_evaluation,
+        left,
+        right,
+        a: a[0],
+        b: b[0],
+    })
+}
+
+/// Returns `Ok(true)` if the range proof is valid, and an error if it is malformed.
+fn verify_inner<G: AffineCurve>(commitment: &G, proof: &RangeProof<G>, num_bits: usize) -> Result<bool> {
+    check_num_bits(num_bits)?;
+    let num_rounds = num_bits.trailing_zeros() as usize;
+    ensure!(proof.left.len() == num_rounds && proof.right.len() == num_rounds, "Incorrect number of rounds");
+    let generators = Generators::<G>::setup(num_bits);
+    let (g, h) = (generators.g, generators.h);
+
+    let mut transcript = Transcript::<G>::new();
+    transcript.absorb_field(&G::ScalarField::from(RANGE_PROOF_LABEL));
+    transcript.absorb_field(&G::ScalarField::from(num_bits as u128));
+    transcript.absorb_point(commitment)?;
+    transcript.absorb_point(&proof.bits_commitment)?;
+    transcript.absorb_point(&proof.blinding_commitment)?;
+    let (y, z) = (transcript.challenge(), transcript.challenge());
+    transcript.absorb_point(&proof.t_commitments[0])?;
+    transcript.absorb_point(&proof.t_commitments[1])?;
+    let x = transcript.challenge();
+    transcript.absorb_field(&proof.t_blinding);
+    transcript.absorb_field(&proof.mu);
+    transcript.absorb_field(&proof.t_evaluation);
+    let q = (generators.u * transcript.challenge()).to_affine();
+
+    // Check g^t(x) h^tau_x == V^(z^2) g^delta(y, z) T_1^x T_2^(x^2),
+    // where delta(y, z) := (z - z^2) <1, y^n> - z^3 <1, 2^n>.
+    let (y_powers, two_powers) = (powers(y, num_bits), powers(G::ScalarField::from(2u128), num_bits));
+    let z_squared = z.square();
+    let delta = (z - z_squared) * y_powers.iter().sum::<G::ScalarField>()
+        - z_squared * z * two_powers.iter().sum::<G::ScalarField>();
+    if g * proof.t_evaluation + h * proof.t_blinding
+        != *commitment * z_squared + g * delta + proof.t_commitments[0] * x + proof.t_commitments[1] * x.square()
+    {
+        return Ok(false);
+    }
+
+    // Compute the commitment P := A S^x g^-z h'^(z y^n + z^2 2^n) h^-mu q^t(x) to l(x) and r(x),
+    // over the bases h'_i := h_i^(y^-i).
+    let h_vec = scale_bases(&generators.h_vec, &inverse_powers(y, num_bits)?);
+    let h_scalars = (0..num_bits).map(|i| z * y_powers[i] + z_squared * two_powers[i]).collect::<Vec<_>>();
+    let mut p = proof.bits_commitment.to_projective() + proof.blinding_commitment * x
+        - msm(&generators.g_vec, &vec![z; num_bits])
+        + msm(&h_vec, &h_scalars)
+        - h * proof.mu
+        + q * proof.t_evaluation;
+
+    // Fold the commitment and the bases, and check the final round of the inner product argument.
+    let (mut g_vec, mut h_vec) = (generators.g_vec, h_vec);
+    for (l_j, r_j) in proof.left.iter().zip(&proof.right) {
+        transcript.absorb_point(l_j)?;
+        transcript.absorb_point(r_j)?;
+        let u = transcript.challenge();
+        let u_inv = u.inverse().ok_or_else(|| anyhow!("The inner product challenge is zero"))?;
+        p += *l_j * u.square() + *r_j * u_inv.square();
+
+        let half = g_vec.len() / 2;
+        g_vec = fold_bases(&g_vec[..half], &g_vec[half..], u_inv, u);
+        h_vec = fold_bases(&h_vec[..half], &h_vec[half..], u, u_inv);
+    }
+
+    Ok(p == g_vec[0] * proof.a + h_vec[0] * proof.b + q * (proof.a * proof.b))
+}
+
+/// Returns the MSM of the given bases and scalars.
+fn msm<G: AffineCurve>(bases: &[G], scalars: &[G::ScalarField]) -> G::Projective {
+    let scalars = scalars.iter().map(|scalar| scalar.to_repr()).collect::<Vec<_>>();
+    VariableBase::msm(bases, &scalars)
+}
+
+/// Returns the inner product of the given vectors.
+fn inner_product<F: Field>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b).map(|(a, b)| *a * b).sum()
+}
+
+/// Returns `[1, x, x^2, ..., x^(n - 1)]`.
+fn powers<F: Field>(x: F, n: usize) -> Vec<F> {
+    core::iter::successors(Some(F::one()), |power| Some(*power * x)).take(n).collect()
+}
+
+/// Returns `[1, x^-1, x^-2, ..., x^-(n - 1)]`.
+fn inverse_powers<F: Field>(x: F, n: usize) -> Result<Vec<F>> {
+    let x_inv = x.inverse().ok_or_else(|| anyhow!("The challenge is zero"))?;
+    Ok(powers(x_inv, n))
+}
+
+/// Returns `[base_i^scalar_i]`.
+fn scale_bases<G: AffineCurve>(bases: &[G], scalars: &[G::ScalarField]) -> Vec<G> {
+    let bases = bases.iter().zip(scalars).map(|(base, scalar)| *base * *scalar).collect();
+    G::Projective::batch_normalization_into_affine(bases)
+}
+
+/// Returns `[lo_i^lo_scalar hi_i^hi_scalar]`, which halves the number of bases in a round of the inner product argument.
+fn fold_bases<G: AffineCurve>(lo: &[G], hi: &[G], lo_scalar: G::ScalarField, hi_scalar: G::ScalarField) -> Vec<G> {
+    let bases = lo.iter().zip(hi).map(|(lo, hi)| *lo * lo_scalar + *hi * hi_scalar).collect();
+    G::Projective::batch_normalization_into_affine(bases)
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_curves::edwards_bls12::{EdwardsAffine, Fr};
+use snarkvm_utilities::test_crypto_rng;
+
+#[test]
+fn test_range_proof() {
+    let rng = &mut test_crypto_rng();
+
+    for num_bits in [1, 8, 32, 64] {
+        let max = if num_bits == 64 { u64::MAX } else { (1 << num_bits) - 1 };
+        for value in [0, 1, max / 2, max, rng.gen_range(0..=max)] {
+            let randomness = Fr::rand(rng);
+            let commitment = commit::<EdwardsAffine>(value, &randomness);
+
+            let proof = prove::<EdwardsAffine, _>(value, &randomness, num_bits, rng).unwrap();
+            assert!(verify(&commitment, &proof, num_bits));
+
+            // Ensure the proof round trips through its byte representation.
+            let candidate = RangeProof::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+            assert_eq!(proof, candidate);
+            assert!(verify(&commitment, &candidate, num_bits));
+
+            // Ensure the proof is bound to the commitment and to the range.
+            assert!(!verify(&commit(value, &(randomness + Fr::one())), &proof, num_bits));
+            assert!(!verify(&commitment, &proof, num_bits * 2));
+        }
+    }
+}
+
+#[test]
+fn test_range_proof_out_of_range() {
+    let rng = &mut test_crypto_rng();
+    let randomness = Fr::rand(rng);
+
+    // Ensure an out-of-range value is rejected by the prover.
+    for (value, num_bits) in [(2, 1), (256, 8), (u64::MAX, 32), (1 << 32, 32)] {
+        assert!(prove::<EdwardsAffine, _>(value, &randomness, num_bits, rng).is_err());
+    }
+
+    // Ensure a proof from the low bits of an out-of-range value fails to verify.
+    for value in [256u64, 300, 1 << 40] {
+        let commitment = commit::<EdwardsAffine>(value, &randomness);
+        let bits = (0..8).map(|i| (value >> i) & 1 == 1).collect::<Vec<_>>();
+        let proof = prove_bits(&commitment, &bits, &randomness, rng).unwrap();
+        assert!(!verify(&commitment, &proof, 8));
+    }
+}
+
+#[test]
+fn test_range_proof_tampered() {
+    let rng = &mut test_crypto_rng();
+    let randomness = Fr::rand(rng);
+    let commitment = commit::<EdwardsAffine>(42, &randomness);
+    let proof = prove::<EdwardsAffine, _>(42, &randomness, 8, rng).unwrap();
+    assert!(verify(&commitment, &proof, 8));
+
+    let tampered = RangeProof { t_evaluation: proof.t_evaluation + Fr::one(), ..proof.clone() };
+    assert!(!verify(&commitment, &tampered, 8));
+    let tampered = RangeProof { a: proof.a + Fr::one(), ..proof.clone() };
+    assert!(!verify(&commitment, &tampered, 8));
+    let tampered = RangeProof { left: proof.right.clone(), right: proof.left.clone(), ..proof.clone() };
+    assert!(!verify(&commitment, &tampered, 8));
+    let tampered = RangeProof { left: proof.left[1..].to_vec(), ..proof.clone() };
+    assert!(!verify(&commitment, &tampered, 8));
+
+    // Ensure an invalid bit length is rejected.
+    for num_bits in [0, 3, 128] {
+        assert!(prove::<EdwardsAffine, _>(0, &randomness, num_bits, rng).is_err());
+        assert!(!verify(&commitment, &proof, num_bits));
+    }
+}
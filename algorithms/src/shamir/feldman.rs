@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Feldman verifiable secret sharing, where the dealer publishes a commitment `G^a_k` to each
+//! coefficient `a_k` of the sharing polynomial, so each shareholder can check their share.
+//!
+//! Note that the commitments reveal `G^secret`, so the secret is only hidden computationally.
+
+use crate::shamir::{evaluate_shares, sample_coefficients};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::Zero;
+use snarkvm_utilities::ToBits;
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+
+///
+/// Returns `n` shares of the given secret, such that any `t` of them reconstruct the secret,
+/// and the commitments `G^a_k` to the `t` coefficients of the sharing polynomial.
+///
+pub fn split_verifiable<G: AffineCurve, R: Rng + CryptoRng>(
+    secret: &G::ScalarField,
+    t: usize,
+    n: usize,
+    rng: &mut R,
+) -> Result<(Vec<(usize, G::ScalarField)>, Vec<G>)> {
+    let coefficients = sample_coefficients(secret, t, n, rng)?;
+
+    // Commit to the coefficients, which are secret.
+    let generator = G::prime_subgroup_generator().to_projective();
+    let commitments = coefficients
+        .iter()
+        .map(|coefficient| generator.mul_bits_constant_time(coefficient.to_bits_be().into_iter()))
+        .collect::<Vec<_>>();
+
+    Ok((evaluate_shares(&coefficients, n), G::Projective::batch_normalization_into_affine(commitments)))
+}
+
+///
+/// Returns `true` if the share at the given index is consistent with the commitments, by checking
+/// `G^share == prod_k commitments[k]^(index^k)`.
+///
+pub fn verify_share<G: AffineCurve>(index: usize, share: &G::ScalarField, commitments: &[G]) -> bool {
+    if index == 0 || commitments.is_empty() {
+        return false;
+    }
+
+    // Evaluate the committed polynomial at the index, in the exponent, by Horner's rule.
+    let index = G::ScalarField::from(index as u128);
+    let candidate =
+        commitments.iter().rev().fold(G::Projective::zero(), |acc, commitment| (acc * index).add_mixed(commitment));
+
+    G::prime_subgroup_generator() * *share == candidate
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Shamir secret sharing over a prime field.
+//!
+//! A secret is split into `n` shares, which are evaluations of a random polynomial of degree `t - 1`
+//! with the secret as its constant term, at the indices `1..=n`. Any `t` shares reconstruct the secret
+//! by Lagrange interpolation at zero, while fewer than `t` shares reveal nothing about it.
+
+use crate::fft::DensePolynomial;
+use snarkvm_fields::{batch_inversion, PrimeField};
+
+use anyhow::{ensure, Result};
+use rand::{CryptoRng, Rng};
+
+pub mod feldman;
+pub use feldman::*;
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Returns `n` shares of the given secret, such that any `t` of them reconstruct the secret.
+/// Each share is a pair `(i, f(i))` for `i` in `1..=n`, where `f` has degree `t - 1` and `f(0)` is the secret.
+///
+pub fn split<F: PrimeField, R: Rng + CryptoRng>(
+    secret: &F,
+    t: usize,
+    n: usize,
+    rng: &mut R,
+) -> Result<Vec<(usize, F)>> {
+    let coefficients = sample_coefficients(secret, t, n, rng)?;
+    Ok(evaluate_shares(&coefficients, n))
+}
+
+///
+/// Returns the secret, interpolated at zero from the given shares.
+///
+/// Note that if fewer than `t` shares are given, the result is unrelated to the secret.
+///
+pub fn reconstruct<F: PrimeField>(shares: &[(usize, F)]) -> Result<F> {
+    ensure!(!shares.is_empty(), "At least one share is required to reconstruct the secret");
+
+    // Ensure the indices are non-zero and distinct.
+    for (position, (i, _)) in shares.iter().enumerate() {
+        ensure!(*i != 0, "The share index must be non-zero");
+        ensure!(shares[..position].iter().all(|(j, _)| i != j), "The share index {} is duplicated", i);
+    }
+
+    let indices = shares.iter().map(|(i, _)| F::from(*i as u128)).collect::<Vec<_>>();
+
+    // Compute the Lagrange coefficients at zero, prod_{j != i} x_j / (x_j - x_i).
+    let (numerators, mut denominators): (Vec<F>, Vec<F>) = indices
+        .iter()
+        .enumerate()
+        .map(|(i, x_i)| {
+            let others = indices.iter().enumerate().filter(|(j, _)| i != *j).map(|(_, x_j)| x_j);
+            others.fold((F::one(), F::one()), |(numerator, denominator), x_j| {
+                (numerator * x_j, denominator * (*x_j - x_i))
+            })
+        })
+        .unzip();
+    batch_inversion(&mut denominators);
+
+    Ok(shares
+        .iter()
+        .zip(numerators.into_iter().zip(denominators))
+        .map(|((_, share), (numerator, denominator))| numerator * denominator * share)
+        .sum())
+}
+
+/// Returns the coefficients of a random polynomial of degree `t - 1`, with the secret as the constant term.
+fn sample_coefficients<F: PrimeField, R: Rng + CryptoRng>(
+    secret: &F,
+    t: usize,
+    n: usize,
+    rng: &mut R,
+) -> Result<Vec<F>> {
+    ensure!(t > 0, "The threshold must be at least 1");
+    ensure!(t <= n, "The threshold ({}) exceeds the number of shares ({})", t, n);
+
+    let mut coefficients = vec![*secret];
+    coefficients.extend((1..t).map(|_| F::rand(rng)));
+    Ok(coefficients)
+}
+
+/// Returns the shares `(i, f(i))` for `i` in `1..=n`, where `f` has the given coefficients.
+fn evaluate_shares<F: PrimeField>(coefficients: &[F], n: usize) -> Vec<(usize, F)> {
+    let polynomial = DensePolynomial::from_coefficients_slice(coefficients);
+    (1..=n).map(|i| (i, polynomial.evaluate(F::from(i as u128)))).collect()
+}
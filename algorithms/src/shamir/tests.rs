@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_curves::{
+    bls12_377::{Fr, G1Affine, G1Projective},
+    AffineCurve,
+    ProjectiveCurve,
+};
+use snarkvm_fields::One;
+use snarkvm_utilities::{test_crypto_rng, UniformRand};
+
+use rand::seq::SliceRandom;
+
+#[test]
+fn test_shamir_reconstruct() {
+    let rng = &mut test_crypto_rng();
+
+    for (t, n) in [(1, 1), (1, 5), (2, 3), (3, 5), (5, 5), (7, 10)] {
+        let secret = Fr::rand(rng);
+        let mut shares = split(&secret, t, n, rng).unwrap();
+        assert_eq!(n, shares.len());
+
+        // Ensure any random subset of t or more shares reconstructs the secret.
+        for _ in 0..10 {
+            shares.shuffle(rng);
+            let num_shares = rng.gen_range(t..=n);
+            assert_eq!(secret, reconstruct(&shares[..num_shares]).unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_shamir_below_threshold() {
+    let rng = &mut test_crypto_rng();
+
+    for (t, n) in [(2, 3), (3, 5), (5, 5), (7, 10)] {
+        let secret = Fr::rand(rng);
+        let mut shares = split(&secret, t, n, rng).unwrap();
+
+        // Ensure any random subset of t - 1 shares does not reconstruct the secret.
+        for _ in 0..10 {
+            shares.shuffle(rng);
+            assert_ne!(secret, reconstruct(&shares[..t - 1]).unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_shamir_invalid_parameters() {
+    let rng = &mut test_crypto_rng();
+    let secret = Fr::rand(rng);
+
+    assert!(split(&secret, 0, 3, rng).is_err());
+    assert!(split(&secret, 4, 3, rng).is_err());
+
+    let shares = split(&secret, 2, 3, rng).unwrap();
+    assert!(reconstruct::<Fr>(&[]).is_err());
+    assert!(reconstruct(&[shares[0], shares[0]]).is_err());
+    assert!(reconstruct(&[(0, shares[0].1), shares[1]]).is_err());
+}
+
+#[test]
+fn test_feldman_verify_share() {
+    let rng = &mut test_crypto_rng();
+
+    for (t, n) in [(1, 1), (2, 3), (3, 5), (7, 10)] {
+        let secret = Fr::rand(rng);
+        let (shares, commitments) = split_verifiable::<G1Affine, _>(&secret, t, n, rng).unwrap();
+        assert_eq!(t, commitments.len());
+        assert_eq!(G1Affine::prime_subgroup_generator() * secret, commitments[0]);
+
+        // Ensure the honest shares verify, and reconstruct the secret.
+        for (index, share) in &shares {
+            assert!(verify_share(*index, share, &commitments));
+        }
+        assert_eq!(secret, reconstruct(&shares[..t]).unwrap());
+    }
+}
+
+#[test]
+fn test_feldman_tampered_share() {
+    let rng = &mut test_crypto_rng();
+
+    let (shares, commitments) = split_verifiable::<G1Affine, _>(&Fr::rand(rng), 3, 5, rng).unwrap();
+    let (index, share) = shares[1];
+
+    // Ensure a tampered share, or a share at the wrong index, fails.
+    assert!(!verify_share(index, &(share + Fr::one()), &commitments));
+    assert!(!verify_share(index + 1, &share, &commitments));
+    assert!(!verify_share(0, &share, &commitments));
+
+    // Ensure tampered commitments fail.
+    let mut tampered = commitments.clone();
+    tampered[2] = (tampered[2].to_projective() + G1Projective::prime_subgroup_generator()).to_affine();
+    assert!(!verify_share(index, &share, &tampered));
+    assert!(!verify_share(index, &share, &commitments[..2]));
+}
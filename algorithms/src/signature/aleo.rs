@@ -26,7 +26,7 @@ use snarkvm_curves::{
     ProjectiveCurve,
     TwistedEdwardsParameters,
 };
-use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_fields::{Field, One, PrimeField, ToConstraintField};
 use snarkvm_utilities::{
     io::{Read, Result as IoResult, Write},
     rand::UniformRand,
@@ -201,6 +201,161 @@ where
         message: &[bool],
         rng: &mut R,
     ) -> Result<Self::Signature> {
+        // Construct the message input (message length, message).
+        let mut input = vec![TE::BaseField::from(message.len() as u128)];
+        input.extend_from_slice(&message.to_field_elements()?);
+
+        self.sign_with_input(private_key, &input, rng)
+    }
+
+    ///
+    /// Verifies (c == c') && (public_key == G^sk_sig G^r_sig G^sk_prf) where:
+    ///     c' := Hash(G^sk_sig G^r_sig G^sk_prf, G^s G^sk_sig^c, message)
+    ///
+    fn verify(&self, public_key: &Self::PublicKey, message: &[bool], signature: &Self::Signature) -> Result<bool> {
+        // Construct the message input (message length, message).
+        let mut input = vec![TE::BaseField::from(message.len() as u128)];
+        input.extend_from_slice(&message.to_field_elements()?);
+
+        self.verify_with_input(public_key, &input, signature)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> SignatureSchemeOperations for AleoSignatureScheme<TE>
+where
+    TE::BaseField: PrimeField,
+{
+    type AffineCurve = TEAffine<TE>;
+    type BaseField = TE::BaseField;
+    type ScalarField = TE::ScalarField;
+    type Signature = AleoSignature<TE>;
+
+    fn pk_sig(signature: &Self::Signature) -> Result<Self::AffineCurve> {
+        Self::recover_from_x_coordinate(&signature.root_public_key)
+    }
+
+    fn pr_sig(signature: &Self::Signature) -> Result<Self::AffineCurve> {
+        Self::recover_from_x_coordinate(&signature.root_randomizer)
+    }
+
+    fn g_scalar_multiply(&self, scalar: &Self::ScalarField) -> <Self::AffineCurve as AffineCurve>::Projective {
+        self.g_bases
+            .iter()
+            .zip_eq(&scalar.to_bits_le())
+            .filter_map(|(base, bit)| match bit {
+                true => Some(base),
+                false => None,
+            })
+            .sum::<TEProjective<TE>>()
+    }
+
+    fn hash_to_scalar_field(&self, input: &[Self::BaseField]) -> Self::ScalarField {
+        // Use Poseidon as a random oracle.
+        let output = self.poseidon.evaluate(input);
+
+        // Truncate the output to CAPACITY bits (1 bit less than MODULUS_BITS) in the scalar field.
+        let mut bits = output.to_bits_le();
+        bits.resize(TE::ScalarField::size_in_data_bits(), false);
+
+        // Output the scalar field.
+        let biginteger = <TE::ScalarField as PrimeField>::BigInteger::from_bits_le(&bits);
+        match <TE::ScalarField as PrimeField>::from_repr(biginteger) {
+            // We know this case will always work, because we truncate the output to CAPACITY bits in the scalar field.
+            Some(scalar) => scalar,
+            _ => panic!("Failed to hash input into scalar field"),
+        }
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> AleoSignatureScheme<TE>
+where
+    TE::BaseField: PrimeField,
+{
+    ///
+    /// Returns a signature on the given message under the given domain, where:
+    ///     c := Hash(G^sk_sig G^r_sig G^sk_prf, G^r, -1, domain length, domain, message length, message)
+    ///
+    /// A signature under one domain does not verify under another domain, or with `verify`.
+    ///
+    pub fn sign_with_domain<R: Rng + CryptoRng>(
+        &self,
+        private_key: &(TE::ScalarField, TE::ScalarField),
+        domain: &[u8],
+        message: &[TE::BaseField],
+        rng: &mut R,
+    ) -> Result<AleoSignature<TE>> {
+        self.sign_with_input(private_key, &Self::domain_input(domain, message)?, rng)
+    }
+
+    ///
+    /// Verifies a signature from `sign_with_domain` on the given message under the given domain.
+    ///
+    pub fn verify_with_domain(
+        &self,
+        public_key: &TEAffine<TE>,
+        domain: &[u8],
+        message: &[TE::BaseField],
+        signature: &AleoSignature<TE>,
+    ) -> Result<bool> {
+        self.verify_with_input(public_key, &Self::domain_input(domain, message)?, signature)
+    }
+
+    ///
+    /// Returns a signature on a digest of the message, which the caller computes, where:
+    ///     c := Hash(G^sk_sig G^r_sig G^sk_prf, G^r, -2, digest)
+    ///
+    pub fn sign_prehashed<R: Rng + CryptoRng>(
+        &self,
+        private_key: &(TE::ScalarField, TE::ScalarField),
+        digest: TE::BaseField,
+        rng: &mut R,
+    ) -> Result<AleoSignature<TE>> {
+        self.sign_with_input(private_key, &Self::prehashed_input(digest), rng)
+    }
+
+    ///
+    /// Verifies a signature from `sign_prehashed` on the given digest.
+    ///
+    pub fn verify_prehashed(
+        &self,
+        public_key: &TEAffine<TE>,
+        digest: TE::BaseField,
+        signature: &AleoSignature<TE>,
+    ) -> Result<bool> {
+        self.verify_with_input(public_key, &Self::prehashed_input(digest), signature)
+    }
+
+    ///
+    /// Returns the challenge input (-1, domain length, domain, message length, message).
+    ///
+    /// The tag is never a valid message length in `sign`, so the two derivations do not collide.
+    ///
+    fn domain_input(domain: &[u8], message: &[TE::BaseField]) -> Result<Vec<TE::BaseField>> {
+        let mut input = vec![-TE::BaseField::one(), TE::BaseField::from(domain.len() as u128)];
+        input.extend_from_slice(&domain.to_field_elements()?);
+        input.push(TE::BaseField::from(message.len() as u128));
+        input.extend_from_slice(message);
+        Ok(input)
+    }
+
+    ///
+    /// Returns the challenge input (-2, digest).
+    ///
+    fn prehashed_input(digest: TE::BaseField) -> Vec<TE::BaseField> {
+        vec![-TE::BaseField::one().double(), digest]
+    }
+
+    ///
+    /// Returns signature (c, s, G^sk_sig, G^r_sig), where:
+    ///     c := Hash(G^sk_sig G^r_sig G^sk_prf, G^r, input)
+    ///     s := r - c * sk_sig
+    ///
+    fn sign_with_input<R: Rng + CryptoRng>(
+        &self,
+        private_key: &(TE::ScalarField, TE::ScalarField),
+        input: &[TE::BaseField],
+        rng: &mut R,
+    ) -> Result<AleoSignature<TE>> {
         // Sample a random scalar field element.
         let r = TE::ScalarField::rand(rng);
 
@@ -231,12 +386,11 @@ where
 
         // Compute the verifier challenge.
         let verifier_challenge = {
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
+            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, input).
             let mut preimage = vec![];
             preimage.extend_from_slice(&public_key.to_x_coordinate().to_field_elements()?);
             preimage.extend_from_slice(&g_r_affine.to_x_coordinate().to_field_elements()?);
-            preimage.push(TE::BaseField::from(message.len() as u128));
-            preimage.extend_from_slice(&message.to_field_elements()?);
+            preimage.extend_from_slice(input);
 
             // Hash to derive the verifier challenge.
             self.hash_to_scalar_field(&preimage)
@@ -255,9 +409,14 @@ where
 
     ///
     /// Verifies (c == c') && (public_key == G^sk_sig G^r_sig G^sk_prf) where:
-    ///     c' := Hash(G^sk_sig G^r_sig G^sk_prf, G^s G^sk_sig^c, message)
+    ///     c' := Hash(G^sk_sig G^r_sig G^sk_prf, G^s G^sk_sig^c, input)
     ///
-    fn verify(&self, public_key: &Self::PublicKey, message: &[bool], signature: &Self::Signature) -> Result<bool> {
+    fn verify_with_input(
+        &self,
+        public_key: &TEAffine<TE>,
+        input: &[TE::BaseField],
+        signature: &AleoSignature<TE>,
+    ) -> Result<bool> {
         // Extract the signature contents.
         let AleoSignature { prover_response, verifier_challenge, root_public_key, root_randomizer } = signature;
 
@@ -272,12 +431,11 @@ where
 
         // Compute the candidate verifier challenge.
         let candidate_verifier_challenge = {
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
+            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, input).
             let mut preimage = vec![];
             preimage.extend_from_slice(&public_key.to_x_coordinate().to_field_elements()?);
             preimage.extend_from_slice(&g_r.to_x_coordinate().to_field_elements()?);
-            preimage.push(TE::BaseField::from(message.len() as u128));
-            preimage.extend_from_slice(&message.to_field_elements()?);
+            preimage.extend_from_slice(input);
 
             // Hash to derive the verifier challenge.
             self.hash_to_scalar_field(&preimage)
@@ -300,58 +458,7 @@ where
 
         Ok(*verifier_challenge == candidate_verifier_challenge && *public_key == candidate_public_key)
     }
-}
-
-impl<TE: TwistedEdwardsParameters> SignatureSchemeOperations for AleoSignatureScheme<TE>
-where
-    TE::BaseField: PrimeField,
-{
-    type AffineCurve = TEAffine<TE>;
-    type BaseField = TE::BaseField;
-    type ScalarField = TE::ScalarField;
-    type Signature = AleoSignature<TE>;
-
-    fn pk_sig(signature: &Self::Signature) -> Result<Self::AffineCurve> {
-        Self::recover_from_x_coordinate(&signature.root_public_key)
-    }
-
-    fn pr_sig(signature: &Self::Signature) -> Result<Self::AffineCurve> {
-        Self::recover_from_x_coordinate(&signature.root_randomizer)
-    }
-
-    fn g_scalar_multiply(&self, scalar: &Self::ScalarField) -> <Self::AffineCurve as AffineCurve>::Projective {
-        self.g_bases
-            .iter()
-            .zip_eq(&scalar.to_bits_le())
-            .filter_map(|(base, bit)| match bit {
-                true => Some(base),
-                false => None,
-            })
-            .sum::<TEProjective<TE>>()
-    }
-
-    fn hash_to_scalar_field(&self, input: &[Self::BaseField]) -> Self::ScalarField {
-        // Use Poseidon as a random oracle.
-        let output = self.poseidon.evaluate(input);
-
-        // Truncate the output to CAPACITY bits (1 bit less than MODULUS_BITS) in the scalar field.
-        let mut bits = output.to_bits_le();
-        bits.resize(TE::ScalarField::size_in_data_bits(), false);
-
-        // Output the scalar field.
-        let biginteger = <TE::ScalarField as PrimeField>::BigInteger::from_bits_le(&bits);
-        match <TE::ScalarField as PrimeField>::from_repr(biginteger) {
-            // We know this case will always work, because we truncate the output to CAPACITY bits in the scalar field.
-            Some(scalar) => scalar,
-            _ => panic!("Failed to hash input into scalar field"),
-        }
-    }
-}
 
-impl<TE: TwistedEdwardsParameters> AleoSignatureScheme<TE>
-where
-    TE::BaseField: PrimeField,
-{
     fn scalar_multiply(&self, base: TEAffine<TE>, scalar: &TE::ScalarField) -> TEProjective<TE> {
         base * *scalar
     }
@@ -382,6 +489,9 @@ mod tests {
     };
     use snarkvm_utilities::test_crypto_rng;
 
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
     fn sign_and_verify<S: SignatureScheme>(message: &[bool]) {
         let rng = &mut test_crypto_rng();
         let signature_scheme = S::setup("sign_and_verify");
@@ -419,4 +529,78 @@ mod tests {
         sign_and_verify::<TestSignature>(&message.as_bytes().to_bits_le());
         failed_verification::<TestSignature>(&message.as_bytes().to_bits_le(), &b"Bad message".to_bits_le());
     }
+
+    #[test]
+    fn test_aleo_signature_with_domain() {
+        type TestSignature = AleoSignatureScheme<EdwardsBls12>;
+
+        let rng = &mut test_crypto_rng();
+        let signature_scheme = TestSignature::setup("test_aleo_signature_with_domain");
+
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let message = (0..5).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+
+        let signature = signature_scheme.sign_with_domain(&private_key, b"first", &message, rng).unwrap();
+        assert!(signature_scheme.verify_with_domain(&public_key, b"first", &message, &signature).unwrap());
+
+        // Ensure the signature does not verify under another domain, or on another message.
+        assert!(!signature_scheme.verify_with_domain(&public_key, b"second", &message, &signature).unwrap());
+        assert!(!signature_scheme.verify_with_domain(&public_key, b"", &message, &signature).unwrap());
+        assert!(!signature_scheme.verify_with_domain(&public_key, b"first", &message[1..], &signature).unwrap());
+
+        // Ensure the signature does not verify as a prehashed signature.
+        assert!(!signature_scheme.verify_prehashed(&public_key, message[0], &signature).unwrap());
+    }
+
+    #[test]
+    fn test_aleo_signature_prehashed() {
+        type TestSignature = AleoSignatureScheme<EdwardsBls12>;
+
+        let rng = &mut test_crypto_rng();
+        let signature_scheme = TestSignature::setup("test_aleo_signature_prehashed");
+
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let digest = UniformRand::rand(rng);
+
+        let signature = signature_scheme.sign_prehashed(&private_key, digest, rng).unwrap();
+        assert!(signature_scheme.verify_prehashed(&public_key, digest, &signature).unwrap());
+
+        // Ensure the signature does not verify on another digest, or as a domain-separated signature.
+        assert!(!signature_scheme.verify_prehashed(&public_key, digest.double(), &signature).unwrap());
+        assert!(!signature_scheme.verify_with_domain(&public_key, b"", &[digest], &signature).unwrap());
+    }
+
+    #[test]
+    fn test_aleo_signature_challenge_vectors() {
+        type TestSignature = AleoSignatureScheme<EdwardsBls12>;
+
+        let signature_scheme = TestSignature::setup("test_aleo_signature_challenge_vectors");
+        let message = b"Hi, I am an Aleo signature!";
+
+        let challenge = |sign: &dyn Fn(&mut ChaChaRng) -> AleoSignature<EdwardsBls12>| {
+            sign(&mut ChaChaRng::seed_from_u64(1231275789u64)).verifier_challenge.to_string()
+        };
+        let private_key = signature_scheme.generate_private_key(&mut ChaChaRng::seed_from_u64(1231275789u64));
+
+        // Ensure `sign` is unchanged.
+        assert_eq!(
+            challenge(&|rng| signature_scheme.sign(&private_key, &message.to_bits_le(), rng).unwrap()),
+            "844951888490606788698076973869999858463817724094463660004261000881629408350"
+        );
+
+        // Ensure the domain-separated challenge derivation is pinned.
+        let elements = message.to_field_elements().unwrap();
+        assert_eq!(
+            challenge(&|rng| signature_scheme.sign_with_domain(&private_key, b"aleo", &elements, rng).unwrap()),
+            "811336691489179709467212250785678975659783984802829848479068548669462330133"
+        );
+
+        // Ensure the prehashed challenge derivation is pinned.
+        assert_eq!(
+            challenge(&|rng| signature_scheme.sign_prehashed(&private_key, elements[0], rng).unwrap()),
+            "402176491415242659672138225875875801204147598419184998535904005224746345125"
+        );
+    }
 }
@@ -0,0 +1,299 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{crypto_hash::hash_to_curve, SignatureError};
+use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::{rand::UniformRand, ToBits, ToBytes};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+use std::collections::HashSet;
+
+/// The domains for hashing messages and public keys to G1.
+const MESSAGE_DOMAIN: &str = "AleoBLS";
+const POSSESSION_DOMAIN: &str = "AleoBLSPoP";
+
+///
+/// A BLS signature scheme, with signatures in G1 and public keys in G2, where:
+///     public_key := G2^sk
+///     signature := H(message)^sk, where H hashes the message to G1
+///
+/// Signatures on distinct messages aggregate by addition, and verify with one product of pairings.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BLSSignatureScheme<E: PairingEngine> {
+    generator: E::G2Affine,
+}
+
+impl<E: PairingEngine> BLSSignatureScheme<E> {
+    pub fn setup(message: &str) -> Self {
+        let (generator, _, _) = hash_to_curve::<E::G2Affine>(message);
+        Self { generator }
+    }
+
+    pub fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> E::Fr {
+        E::Fr::rand(rng)
+    }
+
+    ///
+    /// Returns the public key as G2^sk.
+    ///
+    pub fn generate_public_key(&self, private_key: &E::Fr) -> E::G2Affine {
+        self.generator.to_projective().mul_bits_constant_time(private_key.to_bits_be().into_iter()).to_affine()
+    }
+
+    ///
+    /// Returns the signature H(message)^sk.
+    ///
+    pub fn sign(&self, private_key: &E::Fr, message: &[u8]) -> E::G1Affine {
+        Self::hash_to_g1(MESSAGE_DOMAIN, message)
+            .to_projective()
+            .mul_bits_constant_time(private_key.to_bits_be().into_iter())
+            .to_affine()
+    }
+
+    ///
+    /// Verifies e(signature, G2) == e(H(message), public_key).
+    ///
+    pub fn verify(&self, public_key: &E::G2Affine, message: &[u8], signature: &E::G1Affine) -> Result<bool> {
+        self.verify_aggregate(&[*public_key], &[message], signature)
+    }
+
+    ///
+    /// Returns the aggregate of the given signatures, as their sum.
+    ///
+    pub fn aggregate(signatures: &[E::G1Affine]) -> E::G1Affine {
+        signatures.iter().map(|signature| signature.to_projective()).sum::<E::G1Projective>().to_affine()
+    }
+
+    ///
+    /// Verifies e(aggregate, G2) == e(H(m_1), pk_1) * ... * e(H(m_n), pk_n).
+    ///
+    /// The messages must be distinct, as otherwise an adversary may choose a public key as a function of
+    /// another public key (a rogue-key attack), and forge an aggregate signature on their shared message.
+    ///
+    pub fn verify_aggregate(
+        &self,
+        public_keys: &[E::G2Affine],
+        messages: &[&[u8]],
+        aggregate: &E::G1Affine,
+    ) -> Result<bool> {
+        if public_keys.len() != messages.len() {
+            return Err(SignatureError::Message(format!(
+                "Mismatching number of public keys ({}) and messages ({})",
+                public_keys.len(),
+                messages.len()
+            ))
+            .into());
+        }
+
+        // Ensure there is at least one message, and that the messages are distinct.
+        if messages.is_empty() || messages.iter().collect::<HashSet<_>>().len() != messages.len() {
+            return Ok(false);
+        }
+
+        Ok(self.check_pairing(MESSAGE_DOMAIN, public_keys, messages, aggregate))
+    }
+
+    ///
+    /// Returns a proof of possession of the private key, as a signature H'(public_key)^sk,
+    /// where H' hashes the encoding of the public key to G1 under a domain distinct from messages.
+    ///
+    pub fn prove_possession(&self, private_key: &E::Fr) -> Result<E::G1Affine> {
+        let public_key = self.generate_public_key(private_key);
+        Ok(Self::hash_to_g1(POSSESSION_DOMAIN, &public_key.to_bytes_le()?)
+            .to_projective()
+            .mul_bits_constant_time(private_key.to_bits_be().into_iter())
+            .to_affine())
+    }
+
+    ///
+    /// Verifies e(proof, G2) == e(H'(public_key), public_key).
+    ///
+    /// Public keys should be registered only with a valid proof of possession, which ensures
+    /// the registrant knows the private key, and thus did not derive it from another public key.
+    ///
+    pub fn verify_possession(&self, public_key: &E::G2Affine, proof: &E::G1Affine) -> Result<bool> {
+        let encoding = public_key.to_bytes_le()?;
+        Ok(self.check_pairing(POSSESSION_DOMAIN, &[*public_key], &[&encoding], proof))
+    }
+
+    ///
+    /// Returns `true` if e(aggregate, G2) == e(H(m_1), pk_1) * ... * e(H(m_n), pk_n),
+    /// the public keys are non-zero, and every point is in the prime-order subgroup.
+    ///
+    fn check_pairing(
+        &self,
+        domain: &str,
+        public_keys: &[E::G2Affine],
+        messages: &[&[u8]],
+        aggregate: &E::G1Affine,
+    ) -> bool {
+        // Ensure the public keys and the aggregate are non-zero elements of the prime-order subgroups.
+        if public_keys
+            .iter()
+            .any(|public_key| public_key.is_zero() || !public_key.is_in_correct_subgroup_assuming_on_curve())
+            || !aggregate.is_in_correct_subgroup_assuming_on_curve()
+        {
+            return false;
+        }
+
+        // Compute e(aggregate, -G2) * e(H(m_1), pk_1) * ... * e(H(m_n), pk_n).
+        let mut pairs = Vec::with_capacity(messages.len() + 1);
+        pairs.push((aggregate.prepare(), (-self.generator).prepare()));
+        for (public_key, message) in public_keys.iter().zip(messages) {
+            pairs.push((Self::hash_to_g1(domain, message).prepare(), public_key.prepare()));
+        }
+
+        E::product_of_pairings(pairs.iter().map(|(a, b)| (a, b))).is_one()
+    }
+
+    /// Returns the message hashed to G1 under the given domain.
+    fn hash_to_g1(domain: &str, message: &[u8]) -> E::G1Affine {
+        let (h, _, _) = hash_to_curve::<E::G1Affine>(&format!("{} {}", domain, hex::encode(message)));
+        h
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Bls12_377, G1Affine, G2Affine};
+    use snarkvm_utilities::test_crypto_rng;
+
+    type TestSignature = BLSSignatureScheme<Bls12_377>;
+
+    #[test]
+    fn test_bls_sign_and_verify() {
+        let rng = &mut test_crypto_rng();
+        let scheme = TestSignature::setup("test_bls_sign_and_verify");
+
+        let private_key = scheme.generate_private_key(rng);
+        let public_key = scheme.generate_public_key(&private_key);
+        let signature = scheme.sign(&private_key, b"message");
+
+        assert!(scheme.verify(&public_key, b"message", &signature).unwrap());
+        assert!(!scheme.verify(&public_key, b"other message", &signature).unwrap());
+
+        let other_public_key = scheme.generate_public_key(&scheme.generate_private_key(rng));
+        assert!(!scheme.verify(&other_public_key, b"message", &signature).unwrap());
+    }
+
+    #[test]
+    fn test_bls_distinct_message_aggregation() {
+        let rng = &mut test_crypto_rng();
+        let scheme = TestSignature::setup("test_bls_distinct_message_aggregation");
+
+        let private_keys = (0..5).map(|_| scheme.generate_private_key(rng)).collect::<Vec<_>>();
+        let public_keys = private_keys.iter().map(|sk| scheme.generate_public_key(sk)).collect::<Vec<_>>();
+        let messages = (0..5).map(|i| format!("message {}", i).into_bytes()).collect::<Vec<_>>();
+        let messages = messages.iter().map(|message| &message[..]).collect::<Vec<_>>();
+
+        let signatures = private_keys.iter().zip(&messages).map(|(sk, m)| scheme.sign(sk, m)).collect::<Vec<_>>();
+        let aggregate = TestSignature::aggregate(&signatures);
+        assert!(scheme.verify_aggregate(&public_keys, &messages, &aggregate).unwrap());
+
+        // Ensure a missing signature, a swapped message, or a mismatched length fails.
+        assert!(!scheme
+            .verify_aggregate(&public_keys, &messages, &TestSignature::aggregate(&signatures[1..]))
+            .unwrap());
+        let mut swapped = messages.clone();
+        swapped.swap(0, 1);
+        assert!(!scheme.verify_aggregate(&public_keys, &swapped, &aggregate).unwrap());
+        assert!(scheme.verify_aggregate(&public_keys[1..], &messages, &aggregate).is_err());
+    }
+
+    #[test]
+    fn test_bls_same_message_aggregation() {
+        let rng = &mut test_crypto_rng();
+        let scheme = TestSignature::setup("test_bls_same_message_aggregation");
+
+        let private_keys = (0..3).map(|_| scheme.generate_private_key(rng)).collect::<Vec<_>>();
+        let public_keys = private_keys.iter().map(|sk| scheme.generate_public_key(sk)).collect::<Vec<_>>();
+        let signatures = private_keys.iter().map(|sk| scheme.sign(sk, b"message")).collect::<Vec<_>>();
+
+        // Ensure each signature is valid on its own.
+        for (public_key, signature) in public_keys.iter().zip(&signatures) {
+            assert!(scheme.verify(public_key, b"message", signature).unwrap());
+        }
+
+        // Ensure an aggregate over a repeated message is rejected, even though it is well-formed.
+        let aggregate = TestSignature::aggregate(&signatures);
+        assert!(!scheme.verify_aggregate(&public_keys, &[&b"message"[..]; 3], &aggregate).unwrap());
+    }
+
+    #[test]
+    fn test_bls_rogue_key_rejection() {
+        let rng = &mut test_crypto_rng();
+        let scheme = TestSignature::setup("test_bls_rogue_key_rejection");
+
+        let victim_public_key = scheme.generate_public_key(&scheme.generate_private_key(rng));
+
+        // The adversary chooses rogue_public_key := G2^x - victim_public_key, without knowing its private key.
+        let x = scheme.generate_private_key(rng);
+        let rogue_public_key =
+            (scheme.generate_public_key(&x).to_projective() - victim_public_key.to_projective()).to_affine();
+
+        // The adversary's forgery, H(m)^x, satisfies e(forgery, G2) == e(H(m), victim_public_key + rogue_public_key).
+        let forgery = scheme.sign(&x, b"message");
+        let combined_public_key = (victim_public_key.to_projective() + rogue_public_key.to_projective()).to_affine();
+        assert!(scheme.verify(&combined_public_key, b"message", &forgery).unwrap());
+
+        // Ensure the forgery is rejected as an aggregate signature by the victim and the adversary.
+        let public_keys = [victim_public_key, rogue_public_key];
+        assert!(!scheme.verify_aggregate(&public_keys, &[&b"message"[..]; 2], &forgery).unwrap());
+
+        // Ensure the forgery is rejected for any distinct messages.
+        assert!(!scheme.verify_aggregate(&public_keys, &[&b"message"[..], &b"other message"[..]], &forgery).unwrap());
+
+        // Ensure the identity is rejected as a public key, as it verifies the identity on every message.
+        assert!(!scheme.verify(&G2Affine::zero(), b"message", &G1Affine::zero()).unwrap());
+    }
+
+    #[test]
+    fn test_bls_proof_of_possession() {
+        let rng = &mut test_crypto_rng();
+        let scheme = TestSignature::setup("test_bls_proof_of_possession");
+
+        let private_key = scheme.generate_private_key(rng);
+        let public_key = scheme.generate_public_key(&private_key);
+        let proof = scheme.prove_possession(&private_key).unwrap();
+        assert!(scheme.verify_possession(&public_key, &proof).unwrap());
+
+        // Ensure a proof from a different key is rejected.
+        let other_proof = scheme.prove_possession(&scheme.generate_private_key(rng)).unwrap();
+        assert!(!scheme.verify_possession(&public_key, &other_proof).unwrap());
+
+        // Ensure a signature on the encoding of the public key is not a proof of possession.
+        let signature = scheme.sign(&private_key, &public_key.to_bytes_le().unwrap());
+        assert!(!scheme.verify_possession(&public_key, &signature).unwrap());
+    }
+
+    #[test]
+    fn test_bls_rogue_key_proof_of_possession() {
+        let rng = &mut test_crypto_rng();
+        let scheme = TestSignature::setup("test_bls_rogue_key_proof_of_possession");
+
+        let victim_public_key = scheme.generate_public_key(&scheme.generate_private_key(rng));
+
+        // The adversary cannot prove possession of rogue_public_key := G2^x - victim_public_key.
+        let x = scheme.generate_private_key(rng);
+        let rogue_public_key =
+            (scheme.generate_public_key(&x).to_projective() - victim_public_key.to_projective()).to_affine();
+        assert!(!scheme.verify_possession(&rogue_public_key, &scheme.prove_possession(&x).unwrap()).unwrap());
+    }
+}
@@ -16,3 +16,6 @@
 
 pub mod aleo;
 pub use aleo::*;
+
+pub mod bls;
+pub use bls::*;
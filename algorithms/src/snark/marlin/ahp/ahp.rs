@@ -381,24 +381,23 @@ impl<F: PrimeField> UnnormalizedBivariateLagrangePoly<F> for EvaluationDomain<F>
         x: F,
         domain: &EvaluationDomain<F>,
     ) -> Vec<F> {
-        use snarkvm_utilities::{cfg_iter, cfg_iter_mut};
+        use snarkvm_utilities::cfg_iter_mut;
 
         #[cfg(feature = "parallel")]
         use rayon::prelude::*;
 
         let vanish_x = self.evaluate_vanishing_polynomial(x);
-        let elements = domain.elements().collect::<Vec<_>>();
 
-        let mut denoms = cfg_iter!(elements).map(|e| x - e).collect::<Vec<_>>();
+        let mut denoms = domain.elements().map(|e| x - e).collect::<Vec<_>>();
         if domain.size() <= self.size() {
             snarkvm_fields::batch_inversion_and_mul(&mut denoms, &vanish_x);
         } else {
             snarkvm_fields::batch_inversion(&mut denoms);
             let ratio = domain.size() / self.size();
             let mut numerators = vec![vanish_x; domain.size()];
-            cfg_iter_mut!(numerators).zip_eq(elements).enumerate().for_each(|(i, (n, e))| {
+            cfg_iter_mut!(numerators).enumerate().for_each(|(i, n)| {
                 if i % ratio != 0 {
-                    *n -= self.evaluate_vanishing_polynomial(e);
+                    *n -= self.evaluate_vanishing_polynomial(domain.element(i));
                 }
             });
             cfg_iter_mut!(denoms).zip_eq(numerators).for_each(|(d, e)| *d *= e);
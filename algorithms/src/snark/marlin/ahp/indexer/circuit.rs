@@ -17,12 +17,13 @@
 use core::marker::PhantomData;
 
 use crate::{
+    crypto_hash::sha256::sha256,
     fft::{
         domain::{FFTPrecomputation, IFFTPrecomputation},
         EvaluationDomain,
     },
     polycommit::sonic_pc::LabeledPolynomial,
-    snark::marlin::{ahp::matrices::MatrixArithmetization, AHPForR1CS, CircuitInfo, MarlinMode, Matrix},
+    snark::marlin::{ahp::matrices::MatrixArithmetization, AHPForR1CS, CircuitHash, CircuitInfo, MarlinMode, Matrix},
 };
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{serialize::*, SerializationError};
@@ -68,6 +69,26 @@ impl<F: PrimeField, MM: MarlinMode> Circuit<F, MM> {
         crate::fft::EvaluationDomain::<F>::new(self.index_info.num_constraints).unwrap().size()
     }
 
+    /// Returns the hash of the index info and the R1CS matrices, which do not depend on the witness.
+    pub fn hash(&self) -> Result<CircuitHash, SerializationError> {
+        Self::hash_matrices(&self.index_info, &self.a, &self.b, &self.c)
+    }
+
+    /// Returns the hash of the given index info and R1CS matrices, as `Self::hash` computes it.
+    pub(crate) fn hash_matrices(
+        index_info: &CircuitInfo<F>,
+        a: &Matrix<F>,
+        b: &Matrix<F>,
+        c: &Matrix<F>,
+    ) -> Result<CircuitHash, SerializationError> {
+        let mut bytes = Vec::new();
+        index_info.serialize(&mut bytes)?;
+        a.serialize(&mut bytes)?;
+        b.serialize(&mut bytes)?;
+        c.serialize(&mut bytes)?;
+        Ok(CircuitHash(sha256(&bytes)))
+    }
+
     /// Iterate over the indexed polynomials.
     pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
         [
@@ -19,8 +19,8 @@ use crate::{
     polycommit::sonic_pc::{PolynomialInfo, PolynomialLabel},
     snark::marlin::{
         ahp::{
-            indexer::{Circuit, CircuitInfo, ConstraintSystem as IndexerConstraintSystem},
-            matrices::arithmetize_matrix,
+            indexer::{Circuit, CircuitInfo, ConstraintSystem as IndexerConstraintSystem, Matrix},
+            matrices::{arithmetize_matrix, matrix_evals},
             AHPError,
             AHPForR1CS,
         },
@@ -28,6 +28,7 @@ use crate::{
         MarlinMode,
     },
 };
+use itertools::Itertools;
 use snarkvm_fields::PrimeField;
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
 
@@ -41,7 +42,68 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// Generate the index for this constraint system.
     pub fn index<C: ConstraintSynthesizer<F>>(c: &C) -> Result<Circuit<F, MM>, AHPError> {
         let index_time = start_timer!(|| "AHP::Index");
+        let (index_info, a, b, c) = Self::index_matrices(c)?;
+        let index = Self::index_from_matrices(index_info, a, b, c)?;
+        end_timer!(index_time);
+        Ok(index)
+    }
+
+    /// Generate the index from the R1CS matrices returned by `index_matrices`.
+    pub(crate) fn index_from_matrices(
+        index_info: CircuitInfo<F>,
+        a: Matrix<F>,
+        b: Matrix<F>,
+        c: Matrix<F>,
+    ) -> Result<Circuit<F, MM>, AHPError> {
+        let constraint_domain =
+            EvaluationDomain::new(index_info.num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let input_domain =
+            EvaluationDomain::new(index_info.num_public_inputs).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        let non_zero_a_domain =
+            EvaluationDomain::new(index_info.num_non_zero_a).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let non_zero_b_domain =
+            EvaluationDomain::new(index_info.num_non_zero_b).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let non_zero_c_domain =
+            EvaluationDomain::new(index_info.num_non_zero_c).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        let joint_arithmetization_time = start_timer!(|| "Arithmetizing A");
+        let a_arith = arithmetize_matrix(&a, "a", non_zero_a_domain, constraint_domain, input_domain);
+        let b_arith = arithmetize_matrix(&b, "b", non_zero_b_domain, constraint_domain, input_domain);
+        let c_arith = arithmetize_matrix(&c, "c", non_zero_c_domain, constraint_domain, input_domain);
+        end_timer!(joint_arithmetization_time);
+
+        let fft_precomp_time = start_timer!(|| "Precomputing roots of unity");
+
+        let (fft_precomputation, ifft_precomputation) = Self::fft_precomputation(
+            constraint_domain.size(),
+            non_zero_a_domain.size(),
+            non_zero_b_domain.size(),
+            non_zero_c_domain.size(),
+        )
+        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        end_timer!(fft_precomp_time);
+
+        Ok(Circuit {
+            index_info,
+            a,
+            b,
+            c,
+            a_arith,
+            b_arith,
+            c_arith,
+            fft_precomputation,
+            ifft_precomputation,
+            mode: PhantomData,
+        })
+    }
 
+    /// Generate the R1CS matrices for this constraint system, padded to be square, along with their info.
+    /// This is the part of the index that the hash of a circuit is computed from.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn index_matrices<C: ConstraintSynthesizer<F>>(
+        c: &C,
+    ) -> Result<(CircuitInfo<F>, Matrix<F>, Matrix<F>, Matrix<F>), AHPError> {
         let constraint_time = start_timer!(|| "Generating constraints");
         let mut ics = IndexerConstraintSystem::new();
         c.generate_constraints(&mut ics)?;
@@ -97,48 +159,43 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             f: PhantomData,
         };
 
+        Ok((index_info, a, b, c))
+    }
+
+    /// Evaluate the index polynomials of the given R1CS matrices at `point`, without interpolating them.
+    /// This takes time linear in the size of the index, and is used to check a certificate of a verifying key.
+    pub(crate) fn evaluate_index_polynomials(
+        index_info: &CircuitInfo<F>,
+        a: &Matrix<F>,
+        b: &Matrix<F>,
+        c: &Matrix<F>,
+        point: F,
+    ) -> Result<BTreeMap<PolynomialLabel, F>, AHPError> {
         let constraint_domain =
-            EvaluationDomain::new(num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+            EvaluationDomain::new(index_info.num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
         let input_domain =
-            EvaluationDomain::new(num_padded_public_variables).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-
-        let non_zero_a_domain =
-            EvaluationDomain::new(num_non_zero_a).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-        let non_zero_b_domain =
-            EvaluationDomain::new(num_non_zero_b).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-        let non_zero_c_domain =
-            EvaluationDomain::new(num_non_zero_c).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-
-        let joint_arithmetization_time = start_timer!(|| "Arithmetizing A");
-        let a_arith = arithmetize_matrix(&a, "a", non_zero_a_domain, constraint_domain, input_domain);
-        let b_arith = arithmetize_matrix(&b, "b", non_zero_b_domain, constraint_domain, input_domain);
-        let c_arith = arithmetize_matrix(&c, "c", non_zero_c_domain, constraint_domain, input_domain);
-        end_timer!(joint_arithmetization_time);
-
-        let fft_precomp_time = start_timer!(|| "Precomputing roots of unity");
-
-        let (fft_precomputation, ifft_precomputation) = Self::fft_precomputation(
-            constraint_domain.size(),
-            non_zero_a_domain.size(),
-            non_zero_b_domain.size(),
-            non_zero_c_domain.size(),
-        )
-        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-        end_timer!(fft_precomp_time);
-
-        end_timer!(index_time);
-        Ok(Circuit {
-            index_info,
-            a,
-            b,
-            c,
-            a_arith,
-            b_arith,
-            c_arith,
-            fft_precomputation,
-            ifft_precomputation,
-            mode: PhantomData,
-        })
+            EvaluationDomain::new(index_info.num_public_inputs).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        let mut evaluations = BTreeMap::new();
+        for (label, matrix, num_non_zero) in [
+            ("a", a, index_info.num_non_zero_a),
+            ("b", b, index_info.num_non_zero_b),
+            ("c", c, index_info.num_non_zero_c),
+        ] {
+            let non_zero_domain =
+                EvaluationDomain::new(num_non_zero).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+            let evals = matrix_evals(matrix, non_zero_domain, constraint_domain, input_domain);
+
+            // Each polynomial is the interpolation of its evaluations on `K`.
+            let lagrange_coefficients = non_zero_domain.evaluate_all_lagrange_coefficients(point);
+            let evaluate = |evals: &[F]| evals.iter().zip_eq(&lagrange_coefficients).map(|(e, l)| *e * l).sum::<F>();
+
+            evaluations.insert(format!("row_{label}"), evaluate(&evals.row.evaluations));
+            evaluations.insert(format!("col_{label}"), evaluate(&evals.col.evaluations));
+            evaluations.insert(format!("val_{label}"), evaluate(&evals.val.evaluations));
+            evaluations.insert(format!("row_col_{label}"), evaluate(&evals.row_col.evaluations));
+        }
+        Ok(evaluations)
     }
 
     pub fn index_polynomial_info() -> BTreeMap<PolynomialLabel, PolynomialInfo> {
@@ -129,6 +129,34 @@ pub(crate) fn arithmetize_matrix<F: PrimeField>(
 ) -> MatrixArithmetization<F> {
     let matrix_time = start_timer!(|| "Computing row, col, and val LDEs");
 
+    let evals_on_K = matrix_evals(matrix, non_zero_domain, constraint_domain, input_domain);
+
+    let interpolate_time = start_timer!(|| "Interpolating on K");
+    let row = evals_on_K.row.clone().interpolate();
+    let col = evals_on_K.col.clone().interpolate();
+    let val = evals_on_K.val.clone().interpolate();
+    let row_col = evals_on_K.row_col.clone().interpolate();
+    end_timer!(interpolate_time);
+
+    end_timer!(matrix_time);
+
+    MatrixArithmetization {
+        row: LabeledPolynomial::new("row_".to_string() + label, row, None, None),
+        col: LabeledPolynomial::new("col_".to_string() + label, col, None, None),
+        val: LabeledPolynomial::new("val_".to_string() + label, val, None, None),
+        row_col: LabeledPolynomial::new("row_col_".to_string() + label, row_col, None, None),
+        evals_on_K,
+    }
+}
+
+/// Computes the evaluations on `K` of the `row`, `col`, `row_col`, and `val` polynomials of M^*,
+/// without interpolating them.
+pub(crate) fn matrix_evals<F: PrimeField>(
+    matrix: &Matrix<F>,
+    non_zero_domain: EvaluationDomain<F>,
+    constraint_domain: EvaluationDomain<F>,
+    input_domain: EvaluationDomain<F>,
+) -> MatrixEvals<F> {
     let elems: Vec<_> = constraint_domain.elements().collect();
 
     let lde_evals_time = start_timer!(|| "Computing row, col and val evals");
@@ -180,30 +208,11 @@ pub(crate) fn arithmetize_matrix<F: PrimeField>(
 
     let row_col_vec: Vec<_> = row_vec.iter().zip_eq(&col_vec).map(|(row, col)| *row * col).collect();
 
-    let interpolate_time = start_timer!(|| "Interpolating on K");
-    let row_evals_on_K = EvaluationsOnDomain::from_vec_and_domain(row_vec, non_zero_domain);
-    let col_evals_on_K = EvaluationsOnDomain::from_vec_and_domain(col_vec, non_zero_domain);
-    let val_evals_on_K = EvaluationsOnDomain::from_vec_and_domain(val_vec, non_zero_domain);
-    let row_col_evals_on_K = EvaluationsOnDomain::from_vec_and_domain(row_col_vec, non_zero_domain);
-
-    let row = row_evals_on_K.clone().interpolate();
-    let col = col_evals_on_K.clone().interpolate();
-    let val = val_evals_on_K.clone().interpolate();
-    let row_col = row_col_evals_on_K.clone().interpolate();
-
-    end_timer!(interpolate_time);
-
-    end_timer!(matrix_time);
-
-    let evals_on_K =
-        MatrixEvals { row: row_evals_on_K, col: col_evals_on_K, row_col: row_col_evals_on_K, val: val_evals_on_K };
-
-    MatrixArithmetization {
-        row: LabeledPolynomial::new("row_".to_string() + label, row, None, None),
-        col: LabeledPolynomial::new("col_".to_string() + label, col, None, None),
-        val: LabeledPolynomial::new("val_".to_string() + label, val, None, None),
-        row_col: LabeledPolynomial::new("row_col_".to_string() + label, row_col, None, None),
-        evals_on_K,
+    MatrixEvals {
+        row: EvaluationsOnDomain::from_vec_and_domain(row_vec, non_zero_domain),
+        col: EvaluationsOnDomain::from_vec_and_domain(col_vec, non_zero_domain),
+        row_col: EvaluationsOnDomain::from_vec_and_domain(row_col_vec, non_zero_domain),
+        val: EvaluationsOnDomain::from_vec_and_domain(val_vec, non_zero_domain),
     }
 }
 
@@ -154,6 +154,12 @@ impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
         self.padded_public_variables.clone()
     }
 
+    /// Get the number of padded public and private variables in the entire batch.
+    #[cfg(feature = "prover-metrics")]
+    pub fn num_assigned_variables(&self) -> usize {
+        self.padded_public_variables.iter().chain(&self.private_variables).map(Vec::len).sum()
+    }
+
     pub fn fft_precomputation(&self) -> &FFTPrecomputation<F> {
         &self.index.fft_precomputation
     }
@@ -15,11 +15,14 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    crypto_hash::sha256::sha256,
     polycommit::sonic_pc,
     snark::marlin::{ahp::indexer::*, CircuitVerifyingKey, MarlinMode},
+    SNARKError,
 };
 use snarkvm_curves::PairingEngine;
 use snarkvm_utilities::{
+    error,
     io::{self, Read, Write},
     serialize::*,
     FromBytes,
@@ -39,8 +42,56 @@ pub struct CircuitProvingKey<E: PairingEngine, MM: MarlinMode> {
     pub committer_key: sonic_pc::CommitterKey<E>,
 }
 
+/// The size of the checksum appended by `CircuitProvingKey::to_bytes_checked`.
+const CHECKSUM_SIZE: usize = 32;
+
+/// The magic bytes that begin a serialized proving key, ahead of its format version.
+/// As a little-endian integer, they exceed any number of public inputs, which begins
+/// a proving key serialized without a header.
+const HEADER_MAGIC: [u8; 8] = *b"MARLINPK";
+
+/// The version of the format that `CircuitProvingKey::write_le` serializes a proving key in.
+const VERSION: u16 = 1;
+
+impl<E: PairingEngine, MM: MarlinMode> CircuitProvingKey<E, MM> {
+    /// Returns the serialized proving key, followed by the SHA-256 hash of the serialized key.
+    pub fn to_bytes_checked(&self) -> Result<Vec<u8>, SNARKError> {
+        let mut bytes = Vec::new();
+        self.write_le(&mut bytes)?;
+        let checksum = sha256(&bytes);
+        bytes.extend_from_slice(&checksum);
+        Ok(bytes)
+    }
+
+    ///
+    /// Returns the proving key serialized in `bytes` by `to_bytes_checked`.
+    ///
+    /// The hash of the serialized key is checked before it is deserialized, so a truncated or
+    /// corrupted key is rejected with `SNARKError::ChecksumMismatch`.
+    ///
+    pub fn from_bytes_checked(bytes: &[u8]) -> Result<Self, SNARKError> {
+        if bytes.len() < CHECKSUM_SIZE {
+            return Err(SNARKError::ChecksumMismatch);
+        }
+        let (key_bytes, checksum) = bytes.split_at(bytes.len() - CHECKSUM_SIZE);
+        if sha256(key_bytes) != checksum {
+            return Err(SNARKError::ChecksumMismatch);
+        }
+
+        let mut reader = key_bytes;
+        let circuit_proving_key = Self::read_le(&mut reader)?;
+        if !reader.is_empty() {
+            return Err(SNARKError::Message(format!("{} trailing bytes after the proving key", reader.len())));
+        }
+        Ok(circuit_proving_key)
+    }
+}
+
 impl<E: PairingEngine, MM: MarlinMode> ToBytes for CircuitProvingKey<E, MM> {
     fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        HEADER_MAGIC.write_le(&mut writer)?;
+        VERSION.write_le(&mut writer)?;
+
         CanonicalSerialize::serialize(&self.circuit_verifying_key, &mut writer)?;
         CanonicalSerialize::serialize(&self.circuit_commitment_randomness, &mut writer)?;
         CanonicalSerialize::serialize(&self.circuit, &mut writer)?;
@@ -50,8 +101,29 @@ impl<E: PairingEngine, MM: MarlinMode> ToBytes for CircuitProvingKey<E, MM> {
 }
 
 impl<E: PairingEngine, MM: MarlinMode> FromBytes for CircuitProvingKey<E, MM> {
+    ///
+    /// Reads a proving key with a header, as written by `write_le`, or without a header,
+    /// as written before the header was introduced. A key of an unknown version is rejected.
+    ///
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        let magic: [u8; 8] = FromBytes::read_le(&mut reader)?;
+        if magic != HEADER_MAGIC {
+            // A key without a header begins with the bytes that were read as the magic.
+            return Self::read_fields((&magic[..]).chain(reader));
+        }
+
+        let version: u16 = FromBytes::read_le(&mut reader)?;
+        if version != VERSION {
+            return Err(error(format!("Unsupported proving key version {} (expected {})", version, VERSION)));
+        }
+        Self::read_fields(reader)
+    }
+}
+
+impl<E: PairingEngine, MM: MarlinMode> CircuitProvingKey<E, MM> {
+    /// Reads the fields of a proving key, which follow its header.
+    fn read_fields<R: Read>(mut reader: R) -> io::Result<Self> {
         let circuit_verifying_key = CanonicalDeserialize::deserialize(&mut reader)?;
         let circuit_commitment_randomness = CanonicalDeserialize::deserialize(&mut reader)?;
         let circuit = CanonicalDeserialize::deserialize(&mut reader)?;
@@ -14,6 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+/// The Marlin certificate of a circuit verifying key.
+pub(super) mod certificate;
+pub use certificate::*;
+
+/// The hash of a Marlin circuit.
+pub(super) mod circuit_hash;
+pub use circuit_hash::*;
+
 /// The Marlin circuit proving key.
 pub(super) mod circuit_proving_key;
 pub use circuit_proving_key::*;
@@ -26,10 +34,24 @@ pub use circuit_verifying_key::*;
 pub(super) mod prepared_circuit_verifying_key;
 pub use prepared_circuit_verifying_key::*;
 
+/// The Marlin public inputs, prepared for verification.
+pub(super) mod prepared_inputs;
+pub use prepared_inputs::*;
+
 /// The Marlin zkSNARK proof.
 pub(super) mod proof;
 pub use proof::*;
 
+/// The Marlin prover report.
+#[cfg(feature = "prover-metrics")]
+pub(super) mod prover_report;
+#[cfg(feature = "prover-metrics")]
+pub use prover_report::*;
+
+/// The Marlin proving key cache.
+pub(super) mod proving_key_cache;
+pub use proving_key_cache::*;
+
 /// The Marlin universal SRS.
 pub(super) mod universal_srs;
 pub use universal_srs::*;
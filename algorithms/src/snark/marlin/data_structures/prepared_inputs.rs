@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{polycommit::sonic_pc, snark::marlin::FiatShamirRng};
+use snarkvm_curves::PairingEngine;
+
+/// Public inputs that have been formatted for, and absorbed into the Fiat-Shamir sponge of, a circuit verifying key.
+/// Verifying several proofs against the same inputs and circuit reuses this work.
+#[derive(Clone, Debug)]
+pub struct PreparedInputs<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>> {
+    /// The commitments to the index polynomials of the circuit the inputs were prepared for.
+    pub(crate) circuit_commitments: Vec<sonic_pc::Commitment<E>>,
+    /// The public inputs of each instance, without the constraint system formatting.
+    pub(crate) public_inputs: Vec<Vec<E::Fr>>,
+    /// The sponge, after absorbing the circuit commitments and the padded public inputs.
+    pub(crate) sponge: FS,
+}
+
+impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>> PreparedInputs<E, FS> {
+    /// Returns the number of instances the inputs were prepared for.
+    pub fn batch_size(&self) -> usize {
+        self.public_inputs.len()
+    }
+}
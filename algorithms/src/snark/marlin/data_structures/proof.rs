@@ -19,10 +19,14 @@ use crate::{polycommit::sonic_pc, snark::marlin::ahp};
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{
+    bits_from_bytes_le,
+    bytes_from_bits_le,
     error,
     io::{self, Read, Write},
     serialize::*,
+    FromBits,
     FromBytes,
+    ToBits,
     ToBytes,
 };
 
@@ -264,6 +268,43 @@ impl<F: PrimeField> Evaluations<F> {
 }
 
 impl<F: PrimeField> Evaluations<F> {
+    /// Returns the number of bytes in the packed evaluations of a proof for `batch_size` instances.
+    fn packed_size(batch_size: usize) -> usize {
+        ((batch_size + 4) * F::size_in_bits() + 7) / 8
+    }
+
+    /// Serializes the evaluations with `F::size_in_bits()` bits each, padded with zeros to a byte.
+    fn serialize_packed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        let bits = self
+            .to_field_elements()
+            .iter()
+            .flat_map(|eval| eval.to_repr().to_bits_le().into_iter().take(F::size_in_bits()))
+            .collect::<Vec<_>>();
+        writer.write_all(&bytes_from_bits_le(&bits))?;
+        Ok(())
+    }
+
+    /// Deserializes the packed evaluations of a proof for `batch_size` instances.
+    fn deserialize_packed<R: Read>(batch_size: usize, reader: &mut R) -> Result<Self, SerializationError> {
+        let mut bytes = vec![0u8; Self::packed_size(batch_size)];
+        reader.read_exact(&mut bytes)?;
+        let bits = bits_from_bytes_le(&bytes).collect::<Vec<_>>();
+
+        let num_evals = batch_size + 4;
+        let (eval_bits, padding) = bits.split_at(num_evals * F::size_in_bits());
+        // Ensure the encoding is canonical.
+        if padding.iter().any(|bit| *bit) {
+            return Err(SerializationError::InvalidData);
+        }
+        let mut evals = eval_bits
+            .chunks(F::size_in_bits())
+            .map(|bits| F::from_repr(F::BigInteger::from_bits_le(bits)).ok_or(SerializationError::NotInField))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let [g_1_eval, g_a_eval, g_b_eval, g_c_eval]: [F; 4] = evals.split_off(batch_size).try_into().unwrap();
+        Ok(Self { z_b_evals: evals, g_1_eval, g_a_eval, g_b_eval, g_c_eval })
+    }
+
     pub(crate) fn from_map(map: &std::collections::BTreeMap<String, F>, batch_size: usize) -> Self {
         let z_b_evals = map.iter().filter_map(|(k, v)| k.starts_with("z_b_").then(|| *v)).collect::<Vec<_>>();
         assert_eq!(z_b_evals.len(), batch_size);
@@ -323,6 +364,97 @@ impl<E: PairingEngine> Proof<E> {
     ) -> Self {
         Self { batch_size, commitments, evaluations, msg, pc_proof }
     }
+
+    /// Returns the number of bytes in the serialized proof.
+    pub fn size_in_bytes(&self) -> usize {
+        CanonicalSerialize::serialized_size(self)
+    }
+
+    /// Returns the number of bytes in the proof serialized with `serialize_compressed`.
+    pub fn compressed_size(&self) -> usize {
+        let mut size = 1;
+        size += CanonicalSerialize::serialized_size(&self.batch_size);
+        size += Commitments::serialized_size(&self.commitments);
+        size += Evaluations::<E::Fr>::packed_size(self.batch_size);
+        size += CanonicalSerialize::serialized_size(&self.msg);
+        size += CanonicalSerialize::serialized_size(&self.pc_proof);
+        size
+    }
+
+    ///
+    /// Serializes the proof in the given encoding, preceded by the one-byte tag of the encoding.
+    ///
+    /// The uncompressed encoding is the one of `CanonicalSerialize::serialize_uncompressed`.
+    /// The compressed encoding compresses every group element, and packs the evaluations
+    /// into `E::Fr::size_in_bits()` bits each.
+    ///
+    pub fn serialize_with_encoding<W: Write>(
+        &self,
+        encoding: ProofEncoding,
+        writer: &mut W,
+    ) -> Result<(), SerializationError> {
+        (encoding as u8).serialize(writer)?;
+        match encoding {
+            ProofEncoding::Uncompressed => CanonicalSerialize::serialize_uncompressed(self, writer),
+            ProofEncoding::Compressed => {
+                CanonicalSerialize::serialize(&self.batch_size, writer)?;
+                Commitments::serialize(&self.commitments, writer)?;
+                Evaluations::serialize_packed(&self.evaluations, writer)?;
+                CanonicalSerialize::serialize(&self.msg, writer)?;
+                CanonicalSerialize::serialize(&self.pc_proof, writer)?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Serializes the proof in the compressed encoding, preceded by its tag.
+    pub fn serialize_compressed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.serialize_with_encoding(ProofEncoding::Compressed, writer)
+    }
+
+    ///
+    /// Deserializes a proof written by `serialize_compressed`, or by `serialize_with_encoding`
+    /// in either encoding, as distinguished by its tag.
+    ///
+    /// Every group element is checked to be on the curve and in the prime-order subgroup,
+    /// and every packed evaluation is checked to be a canonical field element.
+    ///
+    pub fn deserialize_compressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        match ProofEncoding::from_tag(CanonicalDeserialize::deserialize(reader)?)? {
+            ProofEncoding::Uncompressed => CanonicalDeserialize::deserialize_uncompressed(reader),
+            ProofEncoding::Compressed => {
+                let batch_size = CanonicalDeserialize::deserialize(reader)?;
+                Ok(Proof {
+                    batch_size,
+                    commitments: Commitments::deserialize(batch_size, reader)?,
+                    evaluations: Evaluations::deserialize_packed(batch_size, reader)?,
+                    msg: CanonicalDeserialize::deserialize(reader)?,
+                    pc_proof: CanonicalDeserialize::deserialize(reader)?,
+                })
+            }
+        }
+    }
+}
+
+/// The encodings of a proof, as distinguished by a one-byte tag.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ProofEncoding {
+    /// Uncompressed group elements and full-width field elements.
+    Uncompressed = 0,
+    /// Compressed group elements and packed evaluations.
+    Compressed = 1,
+}
+
+impl ProofEncoding {
+    /// Returns the encoding with the given tag.
+    fn from_tag(tag: u8) -> Result<Self, SerializationError> {
+        match tag {
+            0 => Ok(Self::Uncompressed),
+            1 => Ok(Self::Compressed),
+            _ => Err(SerializationError::InvalidData),
+        }
+    }
 }
 
 impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
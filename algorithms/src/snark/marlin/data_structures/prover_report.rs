@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::polycommit::sonic_pc::LabeledPolynomial;
+use snarkvm_fields::Field;
+
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// The phases of the Marlin prover, in the order in which they run.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum ProverPhase {
+    /// Indexes the circuit, and commits to the index polynomials, when generating the circuit keys.
+    Indexing,
+    /// Synthesizes the witness of each circuit in the batch.
+    WitnessSynthesis,
+    /// Computes and commits to the first round oracles, which encode the witness.
+    FirstRound,
+    /// Computes and commits to the second round oracles.
+    SecondRound,
+    /// Computes and commits to the third round oracles.
+    ThirdRound,
+    /// Computes and commits to the fourth round oracles.
+    FourthRound,
+    /// Evaluates the oracles at the query set, and proves the evaluations.
+    Opening,
+}
+
+/// Measurements of one phase of the Marlin prover.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PhaseReport {
+    /// The phase of the prover.
+    pub phase: ProverPhase,
+    /// The wall-clock time spent in the phase.
+    pub time: Duration,
+    /// The number of bytes in the field elements of the witnesses or polynomials that the phase produces.
+    /// This is the size of the output of the phase, not its peak allocation.
+    pub output_bytes: usize,
+}
+
+/// Measurements of each phase of the Marlin prover, produced with the `prover-metrics` feature.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ProverReport {
+    /// The measurements of each phase, in the order in which they ran.
+    pub phases: Vec<PhaseReport>,
+    /// The wall-clock time spent in the prover.
+    pub total_time: Duration,
+    /// The number of bytes in the serialized proof, if the report is of a proof.
+    pub proof_size: Option<usize>,
+}
+
+impl ProverReport {
+    /// Returns the measurements of the given phase, if it ran.
+    pub fn phase(&self, phase: ProverPhase) -> Option<&PhaseReport> {
+        self.phases.iter().find(|report| report.phase == phase)
+    }
+
+    /// Returns the total wall-clock time spent in the phases.
+    pub fn phases_time(&self) -> Duration {
+        self.phases.iter().map(|report| report.time).sum()
+    }
+}
+
+/// Records the phases of the Marlin prover into a `ProverReport`.
+pub(crate) struct ProverReportBuilder {
+    start: Instant,
+    phase_start: Instant,
+    phases: Vec<PhaseReport>,
+}
+
+impl ProverReportBuilder {
+    /// Starts the report, and its first phase.
+    pub(crate) fn new() -> Self {
+        let start = Instant::now();
+        Self { start, phase_start: start, phases: Vec::new() }
+    }
+
+    /// Ends the current phase as `phase`, and starts the next phase.
+    pub(crate) fn record(&mut self, phase: ProverPhase, output_bytes: usize) {
+        let now = Instant::now();
+        self.phases.push(PhaseReport { phase, time: now - self.phase_start, output_bytes });
+        self.phase_start = now;
+    }
+
+    /// Ends the report, with the size of the proof if the report is of a proof.
+    pub(crate) fn finish(self, proof_size: Option<usize>) -> ProverReport {
+        ProverReport { phases: self.phases, total_time: self.start.elapsed(), proof_size }
+    }
+}
+
+/// Returns the number of bytes in the coefficients of the given polynomials, up to their degrees.
+pub(crate) fn polynomial_bytes<'a, F: Field>(polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<F>>) -> usize {
+    polynomials.into_iter().map(|polynomial| polynomial.polynomial().degree() + 1).sum::<usize>()
+        * core::mem::size_of::<F>()
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::snark::marlin::{CircuitHash, CircuitProvingKey, MarlinMode, UniversalSRS};
+use snarkvm_curves::PairingEngine;
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+use parking_lot::RwLock;
+use std::{collections::BTreeMap, sync::Arc};
+
+///
+/// `ProvingKeyCache` memoizes the circuit proving keys generated from a universal SRS.
+///
+/// Keys are cached under the hash of the R1CS matrices of their circuit, and are shared behind
+/// an `Arc`, so that repeated proofs of the same circuit do not index and commit to it again.
+/// See `MarlinSNARK::circuit_setup_cached`.
+///
+#[derive(Debug)]
+pub struct ProvingKeyCache<E: PairingEngine, MM: MarlinMode> {
+    universal_srs: UniversalSRS<E>,
+    keys: RwLock<BTreeMap<CircuitHash, Arc<CircuitProvingKey<E, MM>>>>,
+    num_hits: AtomicUsize,
+    num_misses: AtomicUsize,
+}
+
+impl<E: PairingEngine, MM: MarlinMode> ProvingKeyCache<E, MM> {
+    /// Initializes an empty cache for proving keys generated from `universal_srs`.
+    pub fn new(universal_srs: UniversalSRS<E>) -> Self {
+        Self { universal_srs, keys: Default::default(), num_hits: AtomicUsize::new(0), num_misses: AtomicUsize::new(0) }
+    }
+
+    /// Returns the universal SRS that proving keys are generated from.
+    pub fn universal_srs(&self) -> &UniversalSRS<E> {
+        &self.universal_srs
+    }
+
+    /// Returns the proving key for the circuit with hash `circuit_hash`, if it is cached.
+    pub fn get(&self, circuit_hash: &CircuitHash) -> Option<Arc<CircuitProvingKey<E, MM>>> {
+        let circuit_proving_key = self.keys.read().get(circuit_hash).cloned();
+        match circuit_proving_key.is_some() {
+            true => self.num_hits.fetch_add(1, Ordering::Relaxed),
+            false => self.num_misses.fetch_add(1, Ordering::Relaxed),
+        };
+        circuit_proving_key
+    }
+
+    /// Inserts the proving key for the circuit with hash `circuit_hash`, and returns the cached key.
+    /// If a concurrent setup already inserted a key for this circuit, that key is kept.
+    pub(crate) fn insert(
+        &self,
+        circuit_hash: CircuitHash,
+        circuit_proving_key: CircuitProvingKey<E, MM>,
+    ) -> Arc<CircuitProvingKey<E, MM>> {
+        self.keys.write().entry(circuit_hash).or_insert_with(|| Arc::new(circuit_proving_key)).clone()
+    }
+
+    /// Returns the number of cached proving keys.
+    pub fn len(&self) -> usize {
+        self.keys.read().len()
+    }
+
+    /// Returns `true` if no proving keys are cached.
+    pub fn is_empty(&self) -> bool {
+        self.keys.read().is_empty()
+    }
+
+    /// Returns the number of lookups that found a cached proving key.
+    pub fn num_hits(&self) -> usize {
+        self.num_hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of lookups that did not find a cached proving key.
+    pub fn num_misses(&self) -> usize {
+        self.num_misses.load(Ordering::Relaxed)
+    }
+}
@@ -22,7 +22,7 @@ use crate::{
     },
     traits::DefaultCapacityAlgebraicSponge,
 };
-use snarkvm_fields::{FieldParameters, PrimeField, ToConstraintField};
+use snarkvm_fields::{PrimeField, ToConstraintField};
 use snarkvm_utilities::{BigInteger, FromBits, ToBits};
 
 use core::marker::PhantomData;
@@ -104,6 +104,14 @@ where
     fn squeeze_short_nonnative_field_elements(&mut self, num: usize) -> Result<Vec<TargetField>, FiatShamirError> {
         Ok(Self::get_elements_from_sponge(&mut self.s, num, true))
     }
+
+    fn squeeze_128_bit_challenges(&mut self, num: usize) -> Result<Vec<TargetField>, FiatShamirError> {
+        let bits = Self::get_bits_from_sponge(&mut self.s, 128 * num);
+        Ok(bits
+            .chunks_exact(128)
+            .map(|bits| TargetField::from_repr(TargetField::BigInteger::from_bits_be(bits)).unwrap())
+            .collect())
+    }
 }
 
 impl<TargetField: PrimeField, BaseField: PrimeField, S: DefaultCapacityAlgebraicSponge<BaseField, 6>>
@@ -212,21 +220,7 @@ impl<TargetField: PrimeField, BaseField: PrimeField, S: DefaultCapacityAlgebraic
     /// obtain random bits from hashchain.
     /// not guaranteed to be uniformly distributed, should only be used in certain situations.
     pub fn get_bits_from_sponge(sponge: &mut S, num_bits: usize) -> Vec<bool> {
-        let bits_per_element = BaseField::size_in_bits() - 1;
-        let num_elements = (num_bits + bits_per_element - 1) / bits_per_element;
-
-        let src_elements = sponge.squeeze(num_elements);
-        let mut dest_bits = Vec::<bool>::with_capacity(num_elements * bits_per_element);
-
-        let skip = (BaseField::Parameters::REPR_SHAVE_BITS + 1) as usize;
-        for elem in src_elements.iter() {
-            // discard the highest bit
-            let elem_bits = elem.to_repr().to_bits_be();
-            dest_bits.extend_from_slice(&elem_bits[skip..]);
-        }
-        dest_bits.truncate(num_bits);
-
-        dest_bits
+        sponge.squeeze_bits(num_bits)
     }
 
     /// obtain random elements from hashchain.
@@ -236,36 +230,45 @@ impl<TargetField: PrimeField, BaseField: PrimeField, S: DefaultCapacityAlgebraic
         num_elements: usize,
         outputs_short_elements: bool,
     ) -> Vec<TargetField> {
-        let num_bits_per_nonnative = if outputs_short_elements {
-            168
-        } else {
-            TargetField::size_in_bits() - 1 // also omit the highest bit
-        };
-        let bits = Self::get_bits_from_sponge(sponge, num_bits_per_nonnative * num_elements);
-
-        let mut lookup_table = Vec::<TargetField>::new();
-        let mut cur = TargetField::one();
-        for _ in 0..num_bits_per_nonnative {
-            lookup_table.push(cur);
-            cur.double_in_place();
+        match outputs_short_elements {
+            true => sponge.squeeze_short_nonnative_field_elements(num_elements),
+            false => sponge.squeeze_nonnative_field_elements(num_elements),
         }
+    }
+}
 
-        let dest_elements = bits
-            .chunks_exact(num_bits_per_nonnative)
-            .map(|per_nonnative_bits| {
-                // technically, this can be done via BigInterger::from_bits; here, we use this method for consistency with the gadget counterpart
-                let mut res = TargetField::zero();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto_hash::PoseidonSponge;
 
-                for (i, bit) in per_nonnative_bits.iter().rev().enumerate() {
-                    if *bit {
-                        res += &lookup_table[i];
-                    }
-                }
-                res
-            })
-            .collect::<Vec<_>>();
-        debug_assert_eq!(dest_elements.len(), num_elements);
+    use snarkvm_curves::bls12_377::{Fq, Fr};
+    use snarkvm_utilities::ToBits;
+
+    type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>;
 
-        dest_elements
+    #[test]
+    fn test_challenge_golden_vectors() {
+        let mut fs_rng = FS::new();
+        fs_rng.absorb_bytes(b"snarkVM");
+        fs_rng.absorb_nonnative_field_elements([Fr::from(42u64)], OptimizationType::Weight);
+        fs_rng.absorb_native_field_elements(&[Fq::from(7u64)]);
+
+        let challenges = fs_rng.squeeze_128_bit_challenges(2).unwrap();
+        let elements = fs_rng.squeeze_nonnative_field_elements(1, OptimizationType::Weight).unwrap();
+        let short_elements = fs_rng.squeeze_short_nonnative_field_elements(1).unwrap();
+        let native_elements = fs_rng.squeeze_native_field_elements(1).unwrap();
+
+        for challenge in &challenges {
+            assert!(challenge.to_repr().to_bits_le()[128..].iter().all(|bit| !bit));
+        }
+        assert_eq!(challenges[0].to_string(), "307164965157779751221978561703687240285");
+        assert_eq!(challenges[1].to_string(), "159953824328802803316781019308255658812");
+        assert_eq!(
+            elements[0].to_string(),
+            "3695164813640160530528025661834876702657315731406195282283695157467238969926"
+        );
+        assert_eq!(short_elements[0].to_string(), "234576623385517545590034278043409779024410322770741");
+        assert_eq!(native_elements[0].to_string(), "157397826552394677971732887539263310557370822448233185449759622298437141151461066758608478219155999108160054494959");
     }
 }
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::AleoXof,
+    snark::marlin::{fiat_shamir::FiatShamirRng, params::OptimizationType, FiatShamirError},
+};
+use snarkvm_fields::{PrimeField, ToConstraintField};
+
+use core::marker::PhantomData;
+use smallvec::SmallVec;
+
+/// The personalization used to absorb into the transcript state.
+const ABSORB_PERSONALIZATION: &[u8; 8] = b"AleoFSab";
+/// The personalization used to squeeze from the transcript state.
+const SQUEEZE_PERSONALIZATION: &[u8; 8] = b"AleoFSsq";
+/// The number of bytes in the transcript state.
+const STATE_SIZE: usize = 32;
+
+/// Implements a Fiat-Shamir based Rng from the BLAKE2Xs extendable-output function.
+/// Each absorb hashes the new message with the current state into a new state.
+/// Each squeeze expands the current state into a new state, followed by the requested bytes.
+#[derive(Clone, Debug)]
+pub struct FiatShamirBlake2XsRng<TargetField: PrimeField, BaseField: PrimeField> {
+    /// The transcript state.
+    state: Option<[u8; STATE_SIZE]>,
+    #[doc(hidden)]
+    _phantom: PhantomData<(TargetField, BaseField)>,
+}
+
+impl<TargetField: PrimeField, BaseField: PrimeField> FiatShamirRng<TargetField, BaseField>
+    for FiatShamirBlake2XsRng<TargetField, BaseField>
+{
+    type Parameters = ();
+
+    fn new() -> Self {
+        Self { state: None, _phantom: PhantomData }
+    }
+
+    fn absorb_nonnative_field_elements(&mut self, elems: impl IntoIterator<Item = TargetField>, _: OptimizationType) {
+        let mut bytes = Vec::new();
+        for elem in elems {
+            elem.write_le(&mut bytes).expect("failed to convert to bytes");
+        }
+        self.absorb_bytes(&bytes);
+    }
+
+    fn absorb_native_field_elements<T: ToConstraintField<BaseField>>(&mut self, src: &[T]) {
+        let mut bytes = Vec::new();
+        for elem in src.iter() {
+            for elem in elem.to_field_elements().unwrap() {
+                elem.write_le(&mut bytes).expect("failed to convert to bytes");
+            }
+        }
+        self.absorb_bytes(&bytes);
+    }
+
+    fn absorb_bytes(&mut self, elements: &[u8]) {
+        // If a state exists, prefix the message with the existing state.
+        let mut bytes = self.state.map(|state| state.to_vec()).unwrap_or_default();
+        bytes.extend_from_slice(elements);
+
+        let mut state = [0u8; STATE_SIZE];
+        state.copy_from_slice(&AleoXof::evaluate(&bytes, STATE_SIZE, ABSORB_PERSONALIZATION));
+        self.state = Some(state);
+    }
+
+    fn squeeze_nonnative_field_elements(
+        &mut self,
+        num: usize,
+        _: OptimizationType,
+    ) -> Result<Vec<TargetField>, FiatShamirError> {
+        self.squeeze_field_elements(num)
+    }
+
+    fn squeeze_native_field_elements(&mut self, num: usize) -> Result<SmallVec<[BaseField; 10]>, FiatShamirError> {
+        Ok(self.squeeze_field_elements(num)?.into())
+    }
+
+    fn squeeze_short_nonnative_field_elements(&mut self, num: usize) -> Result<Vec<TargetField>, FiatShamirError> {
+        self.squeeze_elements_of_bytes(num, 21)
+    }
+
+    fn squeeze_128_bit_challenges(&mut self, num: usize) -> Result<Vec<TargetField>, FiatShamirError> {
+        self.squeeze_elements_of_bytes(num, 16)
+    }
+}
+
+impl<TargetField: PrimeField, BaseField: PrimeField> FiatShamirBlake2XsRng<TargetField, BaseField> {
+    /// Returns `num` pseudorandom bytes, and ratchets the transcript state forward.
+    fn squeeze_bytes(&mut self, num: usize) -> Result<Vec<u8>, FiatShamirError> {
+        // Ensure the RNG is initialized.
+        let state = match &mut self.state {
+            Some(state) => state,
+            None => return Err(FiatShamirError::UninitializedRNG),
+        };
+
+        let mut output = AleoXof::evaluate(&state[..], STATE_SIZE + num, SQUEEZE_PERSONALIZATION);
+        state.copy_from_slice(&output[..STATE_SIZE]);
+        Ok(output.split_off(STATE_SIZE))
+    }
+
+    /// Returns `num` field elements, each reduced from 128 more bits than the field size,
+    /// so that the elements are statistically close to uniform.
+    fn squeeze_field_elements<F: PrimeField>(&mut self, num: usize) -> Result<Vec<F>, FiatShamirError> {
+        let num_bytes = (F::size_in_bits() + 128 + 7) / 8;
+        Ok(self.squeeze_bytes(num * num_bytes)?.chunks_exact(num_bytes).map(F::from_bytes_le_mod_order).collect())
+    }
+
+    /// Returns `num` field elements, each from `num_bytes` bytes, which must be fewer than the field size.
+    fn squeeze_elements_of_bytes(&mut self, num: usize, num_bytes: usize) -> Result<Vec<TargetField>, FiatShamirError> {
+        debug_assert!(8 * num_bytes < TargetField::size_in_bits());
+        let bytes = self.squeeze_bytes(num * num_bytes)?;
+        Ok(bytes.chunks_exact(num_bytes).map(|bytes| TargetField::from_random_bytes(bytes).unwrap()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use snarkvm_curves::bls12_377::{Fq, Fr};
+    use snarkvm_utilities::ToBits;
+
+    type FS = FiatShamirBlake2XsRng<Fr, Fq>;
+
+    #[test]
+    fn test_challenge_golden_vectors() {
+        let mut fs_rng = FS::new();
+        fs_rng.absorb_bytes(b"snarkVM");
+        fs_rng.absorb_nonnative_field_elements([Fr::from(42u64)], OptimizationType::Weight);
+        fs_rng.absorb_native_field_elements(&[Fq::from(7u64)]);
+
+        let challenges = fs_rng.squeeze_128_bit_challenges(2).unwrap();
+        let elements = fs_rng.squeeze_nonnative_field_elements(1, OptimizationType::Weight).unwrap();
+        let short_elements = fs_rng.squeeze_short_nonnative_field_elements(1).unwrap();
+        let native_elements = fs_rng.squeeze_native_field_elements(1).unwrap();
+
+        for challenge in &challenges {
+            assert!(challenge.to_repr().to_bits_le()[128..].iter().all(|bit| !bit));
+        }
+        assert_eq!(challenges[0].to_string(), "249337429070801616099697726798787137476");
+        assert_eq!(challenges[1].to_string(), "24119509889134645063752510554875677745");
+        assert_eq!(
+            elements[0].to_string(),
+            "6092483509838337202343826625343020255345402563377256329239486595428206133939"
+        );
+        assert_eq!(short_elements[0].to_string(), "322687273460408961219049311496794334954380249203223");
+        assert_eq!(native_elements[0].to_string(), "52071973515517324058852161319015089037115636943678483314281523575575542404310679491565674534647456682662931490042");
+    }
+}
@@ -133,6 +133,22 @@ impl<TargetField: PrimeField, BaseField: PrimeField, D: Digest + Clone + Debug>
         }
         Ok(res)
     }
+
+    fn squeeze_128_bit_challenges(&mut self, num: usize) -> Result<Vec<TargetField>, FiatShamirError> {
+        // Ensure the RNG is initialized.
+        let rng = match &mut self.r {
+            Some(rng) => rng,
+            None => return Err(FiatShamirError::UninitializedRNG),
+        };
+
+        let mut res = Vec::<TargetField>::new();
+        for _ in 0..num {
+            let mut x = [0u8; 16];
+            rng.fill_bytes(&mut x);
+            res.push(TargetField::from_random_bytes(&x).unwrap());
+        }
+        Ok(res)
+    }
 }
 
 #[cfg(test)]
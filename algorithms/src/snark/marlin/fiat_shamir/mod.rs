@@ -22,6 +22,10 @@ pub use errors::*;
 mod fiat_shamir_algebraic_sponge;
 pub use fiat_shamir_algebraic_sponge::*;
 
+/// Fiat-Shamir BLAKE2Xs RNG.
+mod fiat_shamir_blake2xs;
+pub use fiat_shamir_blake2xs::*;
+
 /// Fiat-Shamir ChaCha RNG.
 mod fiat_shamir_chacha;
 pub use fiat_shamir_chacha::*;
@@ -55,6 +55,9 @@ pub trait FiatShamirRng<TargetField: PrimeField, BaseField: PrimeField>: Clone +
     /// Takes out field elements of 168 bits.
     fn squeeze_short_nonnative_field_elements(&mut self, num: usize) -> Result<Vec<TargetField>, FiatShamirError>;
 
+    /// Takes out challenges of 128 bits, as field elements.
+    fn squeeze_128_bit_challenges(&mut self, num: usize) -> Result<Vec<TargetField>, FiatShamirError>;
+
     /// Takes out a field element of 168 bits.
     fn squeeze_short_nonnative_field_element(&mut self) -> Result<TargetField, FiatShamirError> {
         self.squeeze_short_nonnative_field_elements(1).map(|v| v[0])
@@ -15,20 +15,37 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    crypto_hash::PoseidonSponge,
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, Randomness, SonicKZG10},
+    polycommit::sonic_pc::{
+        self,
+        AccumulatedCheck,
+        Commitment,
+        Evaluations,
+        LabeledCommitment,
+        LinearCombination,
+        QuerySet,
+        Randomness,
+        SonicKZG10,
+    },
     snark::marlin::{
-        ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
-        fiat_shamir::traits::FiatShamirRng,
+        ahp::{AHPError, AHPForR1CS, Circuit, EvaluationsProvider},
+        fiat_shamir::{traits::FiatShamirRng, FiatShamirAlgebraicSpongeRng},
         params::OptimizationType,
         proof,
         prover,
         witness_label,
+        Certificate,
+        CircuitHash,
         CircuitProvingKey,
         CircuitVerifyingKey,
         MarlinError,
+        MarlinHidingMode,
         MarlinMode,
+        PreparedCircuitVerifyingKey,
+        PreparedInputs,
         Proof,
+        ProvingKeyCache,
         UniversalSRS,
     },
     Prepare,
@@ -37,14 +54,21 @@ use crate::{
     SRS,
 };
 use itertools::Itertools;
-use rand::{CryptoRng, Rng};
+use rand::{CryptoRng, Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use rand_core::RngCore;
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
 use snarkvm_r1cs::ConstraintSynthesizer;
-use snarkvm_utilities::{to_bytes_le, ToBytes};
+use snarkvm_utilities::{to_bytes_le, FromBytes, ToBytes};
+
+use std::{borrow::Borrow, collections::BTreeMap, fs, path::Path, sync::Arc};
 
-use std::{borrow::Borrow, sync::Arc};
+#[cfg(feature = "prover-metrics")]
+use crate::snark::marlin::{
+    data_structures::prover_report::{polynomial_bytes, ProverReportBuilder},
+    ProverReport,
+};
 
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
@@ -54,13 +78,33 @@ use core::{
     sync::atomic::{AtomicBool, Ordering},
 };
 
+/// Ends a phase of the prover in the given `ProverReportBuilder`, with the `prover-metrics` feature.
+/// Without the feature, neither the phase nor its output size is evaluated.
+macro_rules! record_phase {
+    ($report:ident, $phase:ident, $output_bytes:expr) => {
+        #[cfg(feature = "prover-metrics")]
+        $report.record(crate::snark::marlin::ProverPhase::$phase, $output_bytes);
+    };
+}
+
+/// The label of the linear combination of index polynomials that a certificate opens.
+const CERTIFICATE_LC_LABEL: &str = "circuit_check";
+
 /// The Marlin proof system.
+///
+/// The transcript is generic over the Fiat-Shamir RNG `FS`, and defaults to the Poseidon sponge,
+/// which is efficient to verify recursively. `FiatShamirBlake2XsRng` and `FiatShamirChaChaRng`
+/// are cheaper natively. A proof only verifies under the Fiat-Shamir RNG it was created with.
 #[derive(Clone, Debug)]
 pub struct MarlinSNARK<
     E: PairingEngine,
-    FS: FiatShamirRng<E::Fr, E::Fq>,
-    MM: MarlinMode,
-    Input: ToConstraintField<E::Fr> + ?Sized,
+    FS: FiatShamirRng<E::Fr, E::Fq> = FiatShamirAlgebraicSpongeRng<
+        <E as PairingEngine>::Fr,
+        <E as PairingEngine>::Fq,
+        PoseidonSponge<<E as PairingEngine>::Fq, 6, 1>,
+    >,
+    MM: MarlinMode = MarlinHidingMode,
+    Input: ToConstraintField<E::Fr> + ?Sized = [<E as PairingEngine>::Fr],
 >(#[doc(hidden)] PhantomData<(E, FS, MM, Input)>);
 
 impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: ToConstraintField<E::Fr> + ?Sized>
@@ -94,12 +138,219 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
     pub fn circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
         universal_srs: &UniversalSRS<E>,
         circuit: &C,
+    ) -> Result<(CircuitProvingKey<E, MM>, CircuitVerifyingKey<E, MM>), SNARKError> {
+        // TODO: Add check that c is in the correct mode.
+        let index = AHPForR1CS::<_, MM>::index(circuit)?;
+        Self::circuit_setup_from_index(universal_srs, index)
+    }
+
+    ///
+    /// Returns the hash of the structure of `circuit`.
+    ///
+    /// The hash is computed from the R1CS matrices, so it changes with the constraints of the circuit,
+    /// but not with the values of its witness.
+    ///
+    pub fn circuit_hash<C: ConstraintSynthesizer<E::Fr>>(circuit: &C) -> Result<CircuitHash, SNARKError> {
+        let (index_info, a, b, c) = AHPForR1CS::<_, MM>::index_matrices(circuit)?;
+        Ok(Circuit::<_, MM>::hash_matrices(&index_info, &a, &b, &c)?)
+    }
+
+    ///
+    /// Returns the circuit proving and verifying keys from `cache`, or generates them with `circuit_setup`
+    /// from the universal SRS of `cache` and inserts them into `cache` if they are not cached.
+    ///
+    /// On a cache hit, only the R1CS matrices of `circuit` are generated, to compute its hash,
+    /// so the arithmetization of the matrices and the commitments to the index are skipped.
+    ///
+    #[allow(clippy::type_complexity)]
+    pub fn circuit_setup_cached<C: ConstraintSynthesizer<E::Fr>>(
+        cache: &ProvingKeyCache<E, MM>,
+        circuit: &C,
+    ) -> Result<(Arc<CircuitProvingKey<E, MM>>, CircuitVerifyingKey<E, MM>), SNARKError> {
+        let circuit_hash = Self::circuit_hash(circuit)?;
+        if let Some(circuit_proving_key) = cache.get(&circuit_hash) {
+            let circuit_verifying_key = circuit_proving_key.circuit_verifying_key.clone();
+            return Ok((circuit_proving_key, circuit_verifying_key));
+        }
+
+        let (circuit_proving_key, _) = Self::circuit_setup(cache.universal_srs(), circuit)?;
+        let circuit_proving_key = cache.insert(circuit_hash, circuit_proving_key);
+        let circuit_verifying_key = circuit_proving_key.circuit_verifying_key.clone();
+        Ok((circuit_proving_key, circuit_verifying_key))
+    }
+
+    ///
+    /// Returns a certificate that `circuit_verifying_key` was derived from the circuit with the hash `circuit_hash`.
+    ///
+    /// The certificate opens a random linear combination of the index polynomials at a point derived
+    /// from the verifying key and the circuit hash, so it consists of a single batched evaluation proof.
+    ///
+    pub fn certify(
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        circuit_hash: &CircuitHash,
+    ) -> Result<Certificate<E>, SNARKError> {
+        if circuit_proving_key.circuit_verifying_key != *circuit_verifying_key {
+            return Err(SNARKError::Message("The verifying key was not derived from the proving key".to_string()));
+        }
+        if circuit_proving_key.circuit.hash()? != *circuit_hash {
+            return Err(SNARKError::Message(
+                "The proving key was derived from a circuit with another hash".to_string(),
+            ));
+        }
+
+        let (mut sponge, point, linear_combination) =
+            Self::init_sponge_for_certificate(circuit_verifying_key, circuit_hash)?;
+        let query_set = QuerySet::from_iter([(CERTIFICATE_LC_LABEL.to_string(), ("point".to_string(), point))]);
+
+        // The commitments are sorted by label, and are paired with the index polynomials by their labels.
+        let commitments = Self::index_commitments(circuit_verifying_key)
+            .into_iter()
+            .map(|commitment| (commitment.label().to_string(), commitment))
+            .collect::<BTreeMap<_, _>>();
+        let polynomials = circuit_proving_key.circuit.iter().collect::<Vec<_>>();
+        let commitments = polynomials.iter().map(|polynomial| &commitments[polynomial.label()]);
+
+        let pc_proof = SonicKZG10::<E, FS>::open_combinations(
+            &circuit_proving_key.committer_key,
+            [&linear_combination],
+            polynomials.iter().copied(),
+            commitments,
+            &query_set,
+            &circuit_proving_key.circuit_commitment_randomness,
+            &mut sponge,
+        )?;
+        Ok(Certificate::new(pc_proof))
+    }
+
+    ///
+    /// Returns `true` if `certificate` certifies that `circuit_verifying_key` was derived from `circuit`.
+    ///
+    /// Only the R1CS matrices of `circuit` are generated, to compute its hash and to evaluate the index
+    /// polynomials at a single point, so the arithmetization of the matrices and the commitments to
+    /// the index are skipped.
+    ///
+    /// The verifier takes the circuit rather than only its hash. The hash is a SHA-256 digest of the
+    /// matrices, from which the evaluations of the index polynomials cannot be computed, and an evaluation
+    /// supplied by the prover would only show that the commitments open to some index, not to the index
+    /// of the circuit with that hash.
+    ///
+    pub fn verify_certificate<C: ConstraintSynthesizer<E::Fr>>(
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        circuit: &C,
+        certificate: &Certificate<E>,
+    ) -> Result<bool, SNARKError> {
+        let (index_info, a, b, c) = AHPForR1CS::<_, MM>::index_matrices(circuit)?;
+        if index_info != circuit_verifying_key.circuit_info {
+            return Ok(false);
+        }
+        let circuit_hash = Circuit::<_, MM>::hash_matrices(&index_info, &a, &b, &c)?;
+
+        let (mut sponge, point, linear_combination) =
+            Self::init_sponge_for_certificate(circuit_verifying_key, &circuit_hash)?;
+        let query_set = QuerySet::from_iter([(CERTIFICATE_LC_LABEL.to_string(), ("point".to_string(), point))]);
+
+        let index_evaluations = AHPForR1CS::<_, MM>::evaluate_index_polynomials(&index_info, &a, &b, &c, point)?;
+        let mut evaluation = E::Fr::zero();
+        for (coeff, label) in linear_combination.iter() {
+            let label: &String = label
+                .try_into()
+                .map_err(|_| SNARKError::Message("The certificate combination has a constant term".to_string()))?;
+            let index_evaluation = index_evaluations.get(label).ok_or_else(|| {
+                SNARKError::Message(format!("The index polynomial {} of the certificate was not evaluated", label))
+            })?;
+            evaluation += *coeff * index_evaluation;
+        }
+        let evaluations = Evaluations::from_iter([((CERTIFICATE_LC_LABEL.to_string(), point), evaluation)]);
+
+        Ok(SonicKZG10::<E, FS>::check_combinations(
+            &circuit_verifying_key.verifier_key,
+            [&linear_combination],
+            &Self::index_commitments(circuit_verifying_key),
+            &query_set,
+            &evaluations,
+            &certificate.pc_proof,
+            &mut sponge,
+        )?)
+    }
+
+    ///
+    /// Loads the circuit proving and verifying keys from `cache_dir`, or generates them with `circuit_setup`
+    /// and stores them in `cache_dir` if they are not cached.
+    ///
+    /// The keys are cached under the hash of the circuit, so a circuit whose constraints have changed
+    /// misses the cache. Keys that were cached for another SRS or Marlin mode are regenerated.
+    /// On a cache hit, only the R1CS matrices of `circuit` are generated, to compute its hash,
+    /// so the arithmetization of the matrices and the commitments to the index are skipped.
+    ///
+    #[allow(clippy::type_complexity)]
+    pub fn load_or_generate<C: ConstraintSynthesizer<E::Fr>>(
+        circuit: &C,
+        universal_srs: &UniversalSRS<E>,
+        cache_dir: impl AsRef<Path>,
+    ) -> Result<(CircuitProvingKey<E, MM>, CircuitVerifyingKey<E, MM>), SNARKError> {
+        let (index_info, a, b, c) = AHPForR1CS::<_, MM>::index_matrices(circuit)?;
+        let circuit_hash = Circuit::<_, MM>::hash_matrices(&index_info, &a, &b, &c)?;
+        let path = cache_dir.as_ref().join(format!("{}.pk", circuit_hash));
+
+        if let Ok(bytes) = fs::read(&path) {
+            if let Some(circuit_proving_key) = Self::read_cached_proving_key(&bytes, circuit_hash, universal_srs) {
+                let circuit_verifying_key = circuit_proving_key.circuit_verifying_key.clone();
+                return Ok((circuit_proving_key, circuit_verifying_key));
+            }
+        }
+
+        let index = AHPForR1CS::<_, MM>::index_from_matrices(index_info, a, b, c)?;
+        let (circuit_proving_key, circuit_verifying_key) = Self::circuit_setup_from_index(universal_srs, index)?;
+
+        let mut bytes = Vec::new();
+        circuit_hash.write_le(&mut bytes)?;
+        MM::ZK.write_le(&mut bytes)?;
+        universal_srs.h.write_le(&mut bytes)?;
+        universal_srs.beta_h.write_le(&mut bytes)?;
+        circuit_proving_key.write_le(&mut bytes)?;
+
+        // The key is written to a temporary file and then renamed, so that readers never see a partial key.
+        fs::create_dir_all(&cache_dir)?;
+        let temporary_path = path.with_extension(format!("pk.{}.tmp", std::process::id()));
+        fs::write(&temporary_path, bytes)?;
+        fs::rename(&temporary_path, &path)?;
+
+        Ok((circuit_proving_key, circuit_verifying_key))
+    }
+
+    /// Reads a proving key stored by `load_or_generate`, if it was generated for the circuit with
+    /// hash `circuit_hash` from `universal_srs`.
+    fn read_cached_proving_key(
+        mut reader: &[u8],
+        circuit_hash: CircuitHash,
+        universal_srs: &UniversalSRS<E>,
+    ) -> Option<CircuitProvingKey<E, MM>> {
+        let cached_circuit_hash = CircuitHash::read_le(&mut reader).ok()?;
+        let zk = bool::read_le(&mut reader).ok()?;
+        let h = E::G2Affine::read_le(&mut reader).ok()?;
+        let beta_h = E::G2Affine::read_le(&mut reader).ok()?;
+        if cached_circuit_hash != circuit_hash || zk != MM::ZK || h != universal_srs.h || beta_h != universal_srs.beta_h
+        {
+            return None;
+        }
+
+        let circuit_proving_key = CircuitProvingKey::<E, MM>::read_le(&mut reader).ok()?;
+        match circuit_proving_key.circuit.hash() {
+            Ok(hash) if hash == circuit_hash => Some(circuit_proving_key),
+            _ => None,
+        }
+    }
+
+    /// Generates the circuit proving and verifying keys for an indexed circuit.
+    #[allow(clippy::type_complexity)]
+    fn circuit_setup_from_index(
+        universal_srs: &UniversalSRS<E>,
+        index: Circuit<E::Fr, MM>,
     ) -> Result<(CircuitProvingKey<E, MM>, CircuitVerifyingKey<E, MM>), SNARKError> {
         let index_time = start_timer!(|| "Marlin::CircuitSetup");
 
-        // TODO: Add check that c is in the correct mode.
         // Increase the universal SRS size to support the circuit size.
-        let index = AHPForR1CS::<_, MM>::index(circuit)?;
         if universal_srs.max_degree() < index.max_degree() {
             universal_srs
                 .increase_degree(index.max_degree())
@@ -144,6 +395,388 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
         Ok((circuit_proving_key, circuit_verifying_key))
     }
 
+    /// Produces a proof for a batch of circuits, along with the size of the proof, and the time spent, and the
+    /// bytes in the polynomials produced, in each phase of the prover.
+    #[cfg(feature = "prover-metrics")]
+    pub fn prove_batch_with_report<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        circuits: &[C],
+        zk_rng: &mut R,
+    ) -> Result<(Proof<E>, ProverReport), SNARKError> {
+        let mut report = ProverReportBuilder::new();
+        let proof = Self::prove_batch_with_recorder(
+            circuit_proving_key,
+            circuits,
+            &AtomicBool::new(false),
+            zk_rng,
+            &mut report,
+        )?;
+        let proof_size = proof.size_in_bytes();
+        Ok((proof, report.finish(Some(proof_size))))
+    }
+
+    /// Produces a proof for a circuit, along with the size of the proof, and the time spent, and the
+    /// bytes in the polynomials produced, in each phase of the prover.
+    #[cfg(feature = "prover-metrics")]
+    pub fn prove_with_report<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        circuit: &C,
+        zk_rng: &mut R,
+    ) -> Result<(Proof<E>, ProverReport), SNARKError> {
+        Self::prove_batch_with_report(circuit_proving_key, std::slice::from_ref(circuit), zk_rng)
+    }
+
+    /// Generates the circuit proving and verifying keys, along with the time spent, and the bytes
+    /// in the index polynomials produced, in the indexing phase.
+    #[cfg(feature = "prover-metrics")]
+    #[allow(clippy::type_complexity)]
+    pub fn circuit_setup_with_report<C: ConstraintSynthesizer<E::Fr>>(
+        universal_srs: &UniversalSRS<E>,
+        circuit: &C,
+    ) -> Result<(CircuitProvingKey<E, MM>, CircuitVerifyingKey<E, MM>, ProverReport), SNARKError> {
+        let mut report = ProverReportBuilder::new();
+        let (circuit_proving_key, circuit_verifying_key) = Self::circuit_setup(universal_srs, circuit)?;
+        record_phase!(report, Indexing, polynomial_bytes(circuit_proving_key.circuit.iter()));
+        Ok((circuit_proving_key, circuit_verifying_key, report.finish(None)))
+    }
+
+    ///
+    /// Produces a proof for a circuit, with the zero-knowledge randomness drawn from a ChaCha20 RNG seeded
+    /// with `seed`. Proving twice with the same seed and witness produces byte-identical proofs,
+    /// so a prover failure can be replayed from its seed. With the `log_seed` feature, the seed is printed.
+    ///
+    /// # Warning
+    ///
+    /// The proofs of two witnesses that were proven with the same seed share their randomness,
+    /// so a seed must never be reused. Outside of reproducing failures, use `prove` with a cryptographic RNG.
+    ///
+    pub fn prove_deterministic<C: ConstraintSynthesizer<E::Fr>>(
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        circuit: &C,
+        seed: [u8; 32],
+    ) -> Result<Proof<E>, SNARKError> {
+        #[cfg(feature = "log_seed")]
+        eprintln!("Proving with the seed {}", hex::encode(seed));
+
+        Self::prove(circuit_proving_key, circuit, &mut ChaCha20Rng::from_seed(seed))
+    }
+
+    /// Prepares the public inputs of a single instance for repeated verification against `circuit_verifying_key`.
+    pub fn prepare_inputs<B: Borrow<Input>>(
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        public_input: B,
+    ) -> Result<PreparedInputs<E, FS>, SNARKError> {
+        Self::prepare_batch_inputs(circuit_verifying_key, &[public_input])
+    }
+
+    ///
+    /// Prepares the public inputs of a batch of instances for repeated verification against `circuit_verifying_key`.
+    ///
+    /// This pads and formats the inputs, and absorbs them into the Fiat-Shamir sponge along with the circuit
+    /// commitments, as this work depends only on the inputs and the circuit.
+    ///
+    pub fn prepare_batch_inputs<B: Borrow<Input>>(
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        public_inputs: &[B],
+    ) -> Result<PreparedInputs<E, FS>, SNARKError> {
+        if public_inputs.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+
+        let input_domain =
+            EvaluationDomain::<E::Fr>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
+
+        let (padded_public_inputs, public_inputs): (Vec<_>, Vec<_>) = {
+            public_inputs
+                .iter()
+                .map(|input| {
+                    let input = input.borrow().to_field_elements().unwrap();
+                    let mut new_input = vec![E::Fr::one()];
+                    new_input.extend_from_slice(&input);
+                    new_input.resize(input.len().max(input_domain.size()), E::Fr::zero());
+                    if cfg!(debug_assertions) {
+                        println!("Number of padded public variables: {}", new_input.len());
+                    }
+                    let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
+                    (new_input, unformatted)
+                })
+                .unzip()
+        };
+
+        let sponge =
+            Self::init_sponge(public_inputs.len(), &circuit_verifying_key.circuit_commitments, &padded_public_inputs);
+
+        Ok(PreparedInputs {
+            circuit_commitments: circuit_verifying_key.circuit_commitments.clone(),
+            public_inputs,
+            sponge,
+        })
+    }
+
+    /// Verifies a proof against public inputs that were prepared with `prepare_inputs` or `prepare_batch_inputs`.
+    pub fn verify_with_prepared_inputs(
+        prepared_verifying_key: &PreparedCircuitVerifyingKey<E, MM>,
+        prepared_inputs: &PreparedInputs<E, FS>,
+        proof: &Proof<E>,
+    ) -> Result<bool, SNARKError> {
+        let circuit_verifying_key = &prepared_verifying_key.orig_vk;
+        let verifier_time = start_timer!(|| "Marlin::Verify");
+
+        let check = match Self::accumulate_check(circuit_verifying_key, prepared_inputs, proof)? {
+            Some((check, _)) => check,
+            None => return Ok(false),
+        };
+        let evaluations_are_correct = SonicKZG10::<E, FS>::check_elems(check, &circuit_verifying_key.verifier_key)?;
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("SonicKZG10::<E, FS>::Check failed");
+        }
+        end_timer!(verifier_time, || format!(
+            " SonicKZG10::<E, FS>::Check for AHP Verifier linear equations: {}",
+            evaluations_are_correct
+        ));
+        Ok(evaluations_are_correct)
+    }
+
+    ///
+    /// Verifies a batch of proofs under the same verifying key, where each proof is for a single instance.
+    ///
+    /// The pairing checks of the proofs are combined with random scalars, derived from all of the proofs,
+    /// so that they are verified together with a single pairing product. The batch is valid only if every
+    /// proof is valid, and an empty batch is trivially valid.
+    ///
+    pub fn verify_proofs<B: Borrow<Input>>(
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        instances: &[(B, &Proof<E>)],
+    ) -> Result<bool, SNARKError> {
+        let instances = instances
+            .iter()
+            .map(|(public_input, proof)| (circuit_verifying_key, public_input.borrow(), *proof))
+            .collect::<Vec<_>>();
+        Self::verify_proofs_with_keys(&instances)
+    }
+
+    ///
+    /// Verifies a batch of proofs, where each proof is for a single instance under its own verifying key.
+    ///
+    /// The Fiat-Shamir and AHP checks are run for each proof, and the pairing checks of the proofs are combined
+    /// with random scalars, derived from all of the proofs. Proofs whose circuits share a polynomial commitment
+    /// verifier key share their pairing terms, and all terms are verified together with a single pairing product.
+    /// The batch is valid only if every proof is valid, and an empty batch is trivially valid.
+    ///
+    /// To identify the invalid proofs of a batch, use `find_invalid_proofs`.
+    ///
+    pub fn verify_proofs_with_keys(
+        instances: &[(&CircuitVerifyingKey<E, MM>, &Input, &Proof<E>)],
+    ) -> Result<bool, SNARKError> {
+        if instances.is_empty() {
+            return Ok(true);
+        }
+        let verifier_time = start_timer!(|| format!("Marlin::VerifyProofs with {} proofs", instances.len()));
+
+        let mut checks = Vec::with_capacity(instances.len());
+        let mut batch_sponge = FS::new();
+        for (circuit_verifying_key, public_input, proof) in instances {
+            let prepared_inputs = Self::prepare_inputs(*circuit_verifying_key, *public_input)?;
+            let (check, mut sponge) = match Self::accumulate_check(circuit_verifying_key, &prepared_inputs, proof)? {
+                Some(result) => result,
+                None => return Ok(false),
+            };
+            // The sponge has absorbed everything but the opening proof, so absorbing it binds the digest to the proof.
+            proof.pc_proof.proof.absorb_into_sponge(&mut sponge).map_err(MarlinError::from)?;
+            let digest = sponge.squeeze_short_nonnative_field_element().map_err(MarlinError::from)?;
+            batch_sponge.absorb_nonnative_field_elements([digest], OptimizationType::Weight);
+            checks.push(check);
+        }
+
+        let randomizers =
+            batch_sponge.squeeze_short_nonnative_field_elements(checks.len()).map_err(MarlinError::from)?;
+        let mut combined_checks: Vec<(AccumulatedCheck<E>, &sonic_pc::VerifierKey<E>)> = Vec::new();
+        for ((circuit_verifying_key, ..), (check, randomizer)) in
+            instances.iter().zip_eq(checks.iter().zip_eq(randomizers))
+        {
+            let verifier_key = &circuit_verifying_key.verifier_key;
+            match combined_checks.iter_mut().find(|(_, key)| *key == verifier_key) {
+                Some((combined_check, _)) => combined_check.add_scaled(check, randomizer),
+                None => {
+                    let mut combined_check = AccumulatedCheck::empty();
+                    combined_check.add_scaled(check, randomizer);
+                    combined_checks.push((combined_check, verifier_key));
+                }
+            }
+        }
+        let result = SonicKZG10::<E, FS>::check_elems_batch(combined_checks)?;
+
+        end_timer!(verifier_time);
+        Ok(result)
+    }
+
+    ///
+    /// Returns the indices of the invalid proofs in a batch of proofs, where each proof is for a single instance
+    /// under its own verifying key.
+    ///
+    /// The batch is first verified with `verify_proofs_with_keys`, and only if it is invalid are the proofs
+    /// verified one at a time, to identify the invalid ones.
+    ///
+    pub fn find_invalid_proofs(
+        instances: &[(&CircuitVerifyingKey<E, MM>, &Input, &Proof<E>)],
+    ) -> Result<Vec<usize>, SNARKError> {
+        if Self::verify_proofs_with_keys(instances)? {
+            return Ok(vec![]);
+        }
+
+        let mut invalid_proofs = Vec::new();
+        for (index, instance) in instances.iter().enumerate() {
+            if !Self::verify_proofs_with_keys(&[*instance])? {
+                invalid_proofs.push(index);
+            }
+        }
+        Ok(invalid_proofs)
+    }
+
+    ///
+    /// Runs the verifier up to the final pairing check, and returns the accumulated pairing inputs
+    /// along with the verifier's sponge.
+    ///
+    /// Returns `None` if the proof does not match the zero-knowledge mode `MM`.
+    ///
+    fn accumulate_check(
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        prepared_inputs: &PreparedInputs<E, FS>,
+        proof: &Proof<E>,
+    ) -> Result<Option<(AccumulatedCheck<E>, FS)>, SNARKError> {
+        if prepared_inputs.circuit_commitments != circuit_verifying_key.circuit_commitments {
+            return Err(SNARKError::Message("The inputs were prepared for a different circuit".to_string()));
+        }
+
+        let comms = &proof.commitments;
+        let proof_has_correct_zk_mode = if MM::ZK {
+            proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
+        } else {
+            !proof.pc_proof.is_hiding() & comms.mask_poly.is_none()
+        };
+        if !proof_has_correct_zk_mode {
+            eprintln!(
+                "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
+                proof.pc_proof.is_hiding()
+            );
+            return Ok(None);
+        }
+
+        let batch_size = prepared_inputs.batch_size();
+
+        let first_round_info = AHPForR1CS::<E::Fr, MM>::first_round_polynomial_info(batch_size);
+        let mut first_commitments = comms
+            .witness_commitments
+            .iter()
+            .enumerate()
+            .flat_map(|(i, c)| {
+                [
+                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("w", i)], c.w),
+                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_a", i)], c.z_a),
+                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_b", i)], c.z_b),
+                ]
+            })
+            .collect::<Vec<_>>();
+        if MM::ZK {
+            first_commitments.push(LabeledCommitment::new_with_info(
+                first_round_info.get("mask_poly").unwrap(),
+                comms.mask_poly.unwrap(),
+            ));
+        }
+
+        let second_round_info =
+            AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(&circuit_verifying_key.circuit_info);
+        let second_commitments = [
+            LabeledCommitment::new_with_info(&second_round_info["g_1"], comms.g_1),
+            LabeledCommitment::new_with_info(&second_round_info["h_1"], comms.h_1),
+        ];
+        let third_round_info =
+            AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(&circuit_verifying_key.circuit_info);
+        let third_commitments = [
+            LabeledCommitment::new_with_info(&third_round_info["g_a"], comms.g_a),
+            LabeledCommitment::new_with_info(&third_round_info["g_b"], comms.g_b),
+            LabeledCommitment::new_with_info(&third_round_info["g_c"], comms.g_c),
+        ];
+        let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
+        let fourth_commitments = [LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)];
+
+        let public_inputs = &prepared_inputs.public_inputs;
+        let mut sponge = prepared_inputs.sponge.clone();
+
+        // --------------------------------------------------------------------
+        // First round
+        Self::absorb_labeled(&first_commitments, &mut sponge);
+        let (_, verifier_state) =
+            AHPForR1CS::<_, MM>::verifier_first_round(circuit_verifying_key.circuit_info, batch_size, &mut sponge)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Second round
+        Self::absorb_labeled(&second_commitments, &mut sponge);
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Third round
+        Self::absorb_labeled_with_msg(&third_commitments, &proof.msg, &mut sponge);
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Fourth round
+        Self::absorb_labeled(&fourth_commitments, &mut sponge);
+        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
+        // --------------------------------------------------------------------
+
+        // Collect degree bounds for commitments. Indexed polynomials have *no*
+        // degree bounds because we know the committed index polynomial has the
+        // correct degree.
+
+        // Gather commitments in one vector.
+        let commitments: Vec<_> = Self::index_commitments(circuit_verifying_key)
+            .into_iter()
+            .chain(first_commitments)
+            .chain(second_commitments)
+            .chain(third_commitments)
+            .chain(fourth_commitments)
+            .collect();
+
+        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
+
+        sponge.absorb_nonnative_field_elements(proof.evaluations.to_field_elements(), OptimizationType::Weight);
+
+        let mut evaluations = Evaluations::new();
+
+        for (label, (_point_name, q)) in query_set.to_set() {
+            if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
+                evaluations.insert((label, q), E::Fr::zero());
+            } else {
+                let eval = proof.evaluations.get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
+                evaluations.insert((label, q), eval);
+            }
+        }
+
+        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
+            public_inputs,
+            &evaluations,
+            &proof.msg,
+            &verifier_state,
+        )?;
+
+        let check = SonicKZG10::<E, FS>::accumulate_combinations(
+            &circuit_verifying_key.verifier_key,
+            lc_s.values(),
+            &commitments,
+            &query_set.to_set(),
+            &evaluations,
+            &proof.pc_proof,
+            &mut sponge,
+        )?;
+        Ok(Some((check, sponge)))
+    }
+
     fn terminate(terminator: &AtomicBool) -> Result<(), MarlinError> {
         if terminator.load(Ordering::Relaxed) { Err(MarlinError::Terminated) } else { Ok(()) }
     }
@@ -163,6 +796,43 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
         sponge
     }
 
+    /// Returns the sponge for a certificate of `circuit_verifying_key`, along with the point at which
+    /// the certificate opens the index polynomials and the random linear combination that it opens.
+    ///
+    /// This is shared by `certify` and `verify_certificate`, so both absorb the same verifying key.
+    fn init_sponge_for_certificate(
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        circuit_hash: &CircuitHash,
+    ) -> Result<(FS, E::Fr, LinearCombination<E::Fr>), MarlinError> {
+        let mut sponge = FS::new();
+        sponge.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME].unwrap());
+        sponge.absorb_bytes(CERTIFICATE_LC_LABEL.as_bytes());
+        sponge.absorb_bytes(&circuit_hash.0);
+        // Bind the certificate to the whole verifying key, including its circuit info and its verifier key,
+        // as well as to the commitments to the index polynomials.
+        sponge.absorb_bytes(&to_bytes_le![circuit_verifying_key].unwrap());
+        sponge.absorb_native_field_elements(&circuit_verifying_key.circuit_commitments);
+
+        let labels = AHPForR1CS::<E::Fr, MM>::index_polynomial_info().into_keys().collect::<Vec<_>>();
+        let mut challenges = sponge.squeeze_nonnative_field_elements(labels.len(), OptimizationType::Weight)?;
+        let point = challenges.pop().unwrap();
+
+        // The first index polynomial has the coefficient one.
+        let coefficients = core::iter::once(E::Fr::one()).chain(challenges);
+        let linear_combination = LinearCombination::new(CERTIFICATE_LC_LABEL, coefficients.zip_eq(labels));
+        Ok((sponge, point, linear_combination))
+    }
+
+    /// Returns the commitments to the index polynomials in `circuit_verifying_key`, labeled in order.
+    fn index_commitments(circuit_verifying_key: &CircuitVerifyingKey<E, MM>) -> Vec<LabeledCommitment<Commitment<E>>> {
+        circuit_verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .collect()
+    }
+
     fn absorb_labeled_with_msg(
         comms: &[LabeledCommitment<Commitment<E>>],
         message: &prover::ThirdMessage<E::Fr>,
@@ -185,54 +855,15 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
         Self::absorb(commitments, sponge);
         sponge.absorb_nonnative_field_elements([msg.sum_a, msg.sum_b, msg.sum_c], OptimizationType::Weight);
     }
-}
-
-impl<E: PairingEngine, FS, MM, Input> SNARK for MarlinSNARK<E, FS, MM, Input>
-where
-    E::Fr: PrimeField,
-    E::Fq: PrimeField,
-    FS: FiatShamirRng<E::Fr, E::Fq>,
-    MM: MarlinMode,
-    Input: ToConstraintField<E::Fr> + ?Sized,
-{
-    type BaseField = E::Fq;
-    type Proof = Proof<E>;
-    type ProvingKey = CircuitProvingKey<E, MM>;
-    type ScalarField = E::Fr;
-    type UniversalSetupConfig = usize;
-    type UniversalSetupParameters = UniversalSRS<E>;
-    type VerifierInput = Input;
-    type VerifyingKey = CircuitVerifyingKey<E, MM>;
-
-    fn universal_setup<R: Rng + CryptoRng>(
-        max_degree: &Self::UniversalSetupConfig,
-        rng: &mut R,
-    ) -> Result<Self::UniversalSetupParameters, SNARKError> {
-        let setup_time = start_timer!(|| { format!("Marlin::UniversalSetup with max_degree {}", max_degree,) });
-
-        let srs = SonicKZG10::<E, FS>::setup(*max_degree, rng).map_err(Into::into);
-        end_timer!(setup_time);
-        srs
-    }
-
-    fn setup<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
-        circuit: &C,
-        srs: &mut SRS<R, Self::UniversalSetupParameters>,
-    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
-        match srs {
-            SRS::CircuitSpecific(rng) => Self::circuit_specific_setup(circuit, rng),
-            SRS::Universal(srs) => Self::circuit_setup(srs, circuit),
-        }
-        .map_err(SNARKError::from)
-    }
 
-    #[allow(clippy::only_used_in_recursion)]
-    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+    /// Produces a proof for a batch of circuits, recording each phase of the prover with the `prover-metrics` feature.
+    fn prove_batch_with_recorder<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
         circuit_proving_key: &CircuitProvingKey<E, MM>,
         circuits: &[C],
         terminator: &AtomicBool,
         zk_rng: &mut R,
-    ) -> Result<Self::Proof, SNARKError> {
+        #[cfg(feature = "prover-metrics")] report: &mut ProverReportBuilder,
+    ) -> Result<Proof<E>, SNARKError> {
         let prover_time = start_timer!(|| "Marlin::Prover");
         let batch_size = circuits.len();
         if batch_size == 0 {
@@ -251,6 +882,7 @@ where
             &circuit_proving_key.circuit_verifying_key.circuit_commitments,
             &padded_public_input,
         );
+        record_phase!(report, WitnessSynthesis, prover_state.num_assigned_variables() * core::mem::size_of::<E::Fr>());
 
         // --------------------------------------------------------------------
         // First round
@@ -278,6 +910,10 @@ where
             batch_size,
             &mut sponge,
         )?;
+        record_phase!(report, FirstRound, {
+            let oracles = prover_state.first_round_oracles.as_ref().unwrap();
+            polynomial_bytes(oracles.batches.iter().flat_map(|b| b.iter_for_open()).chain(oracles.mask_poly.as_ref()))
+        });
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -302,6 +938,7 @@ where
 
         let (verifier_second_msg, verifier_state) =
             AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
+        record_phase!(report, SecondRound, polynomial_bytes(second_oracles.iter()));
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -326,6 +963,7 @@ where
 
         let (verifier_third_msg, verifier_state) =
             AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
+        record_phase!(report, ThirdRound, polynomial_bytes(third_oracles.iter()));
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -349,6 +987,7 @@ where
         Self::absorb_labeled(&fourth_commitments, &mut sponge);
 
         let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
+        record_phase!(report, FourthRound, polynomial_bytes(fourth_oracles.iter()));
         // --------------------------------------------------------------------
 
         Self::terminate(terminator)?;
@@ -450,12 +1089,18 @@ where
         let pc_proof = SonicKZG10::<E, FS>::open_combinations(
             &circuit_proving_key.committer_key,
             lc_s.values(),
-            polynomials,
+            polynomials.iter().copied(),
             &labeled_commitments,
             &query_set.to_set(),
             &commitment_randomnesses,
             &mut sponge,
         )?;
+        // The opening combines the polynomials of each linear combination into one polynomial.
+        record_phase!(
+            report,
+            Opening,
+            lc_s.len() * polynomials.iter().map(|p| polynomial_bytes([*p])).max().unwrap_or_default()
+        );
 
         Self::terminate(terminator)?;
 
@@ -465,175 +1110,76 @@ where
 
         Ok(proof)
     }
+}
 
-    fn verify_batch_prepared<B: Borrow<Self::VerifierInput>>(
-        prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
-        public_inputs: &[B],
-        proof: &Self::Proof,
-    ) -> Result<bool, SNARKError> {
-        let circuit_verifying_key = &prepared_verifying_key.orig_vk;
-        if public_inputs.is_empty() {
-            return Err(SNARKError::EmptyBatch);
-        }
-        let verifier_time = start_timer!(|| "Marlin::Verify");
+impl<E: PairingEngine, FS, MM, Input> SNARK for MarlinSNARK<E, FS, MM, Input>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: FiatShamirRng<E::Fr, E::Fq>,
+    MM: MarlinMode,
+    Input: ToConstraintField<E::Fr> + ?Sized,
+{
+    type BaseField = E::Fq;
+    type Proof = Proof<E>;
+    type ProvingKey = CircuitProvingKey<E, MM>;
+    type ScalarField = E::Fr;
+    type UniversalSetupConfig = usize;
+    type UniversalSetupParameters = UniversalSRS<E>;
+    type VerifierInput = Input;
+    type VerifyingKey = CircuitVerifyingKey<E, MM>;
 
-        let comms = &proof.commitments;
-        let proof_has_correct_zk_mode = if MM::ZK {
-            proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
-        } else {
-            !proof.pc_proof.is_hiding() & comms.mask_poly.is_none()
-        };
-        if !proof_has_correct_zk_mode {
-            eprintln!(
-                "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
-                proof.pc_proof.is_hiding()
-            );
-            return Ok(false);
-        }
+    fn universal_setup<R: Rng + CryptoRng>(
+        max_degree: &Self::UniversalSetupConfig,
+        rng: &mut R,
+    ) -> Result<Self::UniversalSetupParameters, SNARKError> {
+        let setup_time = start_timer!(|| { format!("Marlin::UniversalSetup with max_degree {}", max_degree,) });
 
-        let batch_size = public_inputs.len();
+        let srs = SonicKZG10::<E, FS>::setup(*max_degree, rng).map_err(Into::into);
+        end_timer!(setup_time);
+        srs
+    }
 
-        let first_round_info = AHPForR1CS::<E::Fr, MM>::first_round_polynomial_info(batch_size);
-        let mut first_commitments = comms
-            .witness_commitments
-            .iter()
-            .enumerate()
-            .flat_map(|(i, c)| {
-                [
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("w", i)], c.w),
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_a", i)], c.z_a),
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_b", i)], c.z_b),
-                ]
-            })
-            .collect::<Vec<_>>();
-        if MM::ZK {
-            first_commitments.push(LabeledCommitment::new_with_info(
-                first_round_info.get("mask_poly").unwrap(),
-                comms.mask_poly.unwrap(),
-            ));
+    fn setup<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        circuit: &C,
+        srs: &mut SRS<R, Self::UniversalSetupParameters>,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
+        match srs {
+            SRS::CircuitSpecific(rng) => Self::circuit_specific_setup(circuit, rng),
+            SRS::Universal(srs) => Self::circuit_setup(srs, circuit),
         }
+    }
 
-        let second_round_info =
-            AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(&circuit_verifying_key.circuit_info);
-        let second_commitments = [
-            LabeledCommitment::new_with_info(&second_round_info["g_1"], comms.g_1),
-            LabeledCommitment::new_with_info(&second_round_info["h_1"], comms.h_1),
-        ];
-        let third_round_info =
-            AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(&circuit_verifying_key.circuit_info);
-        let third_commitments = [
-            LabeledCommitment::new_with_info(&third_round_info["g_a"], comms.g_a),
-            LabeledCommitment::new_with_info(&third_round_info["g_b"], comms.g_b),
-            LabeledCommitment::new_with_info(&third_round_info["g_c"], comms.g_c),
-        ];
-        let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
-        let fourth_commitments = [LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)];
-
-        let input_domain =
-            EvaluationDomain::<E::Fr>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
-
-        let (padded_public_inputs, public_inputs): (Vec<_>, Vec<_>) = {
-            public_inputs
-                .iter()
-                .map(|input| {
-                    let input = input.borrow().to_field_elements().unwrap();
-                    let mut new_input = vec![E::Fr::one()];
-                    new_input.extend_from_slice(&input);
-                    new_input.resize(input.len().max(input_domain.size()), E::Fr::zero());
-                    if cfg!(debug_assertions) {
-                        println!("Number of padded public variables: {}", new_input.len());
-                    }
-                    let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
-                    (new_input, unformatted)
-                })
-                .unzip()
-        };
-
-        let mut sponge =
-            Self::init_sponge(batch_size, &circuit_verifying_key.circuit_commitments, &padded_public_inputs);
-
-        // --------------------------------------------------------------------
-        // First round
-        Self::absorb_labeled(&first_commitments, &mut sponge);
-        let (_, verifier_state) =
-            AHPForR1CS::<_, MM>::verifier_first_round(circuit_verifying_key.circuit_info, batch_size, &mut sponge)?;
-        // --------------------------------------------------------------------
-
-        // --------------------------------------------------------------------
-        // Second round
-        Self::absorb_labeled(&second_commitments, &mut sponge);
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
-        // --------------------------------------------------------------------
-
-        // --------------------------------------------------------------------
-        // Third round
-        Self::absorb_labeled_with_msg(&third_commitments, &proof.msg, &mut sponge);
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
-        // --------------------------------------------------------------------
-
-        // --------------------------------------------------------------------
-        // Fourth round
-        Self::absorb_labeled(&fourth_commitments, &mut sponge);
-        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
-        // --------------------------------------------------------------------
-
-        // Collect degree bounds for commitments. Indexed polynomials have *no*
-        // degree bounds because we know the committed index polynomial has the
-        // correct degree.
-
-        // Gather commitments in one vector.
-        let commitments: Vec<_> = circuit_verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .chain(first_commitments)
-            .chain(second_commitments)
-            .chain(third_commitments)
-            .chain(fourth_commitments)
-            .collect();
-
-        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
-
-        sponge.absorb_nonnative_field_elements(proof.evaluations.to_field_elements(), OptimizationType::Weight);
-
-        let mut evaluations = Evaluations::new();
-
-        for (label, (_point_name, q)) in query_set.to_set() {
-            if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
-                evaluations.insert((label, q), E::Fr::zero());
-            } else {
-                let eval = proof.evaluations.get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
-                evaluations.insert((label, q), eval);
-            }
+    #[allow(clippy::only_used_in_recursion)]
+    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        circuits: &[C],
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        #[cfg(feature = "prover-metrics")]
+        {
+            Self::prove_batch_with_recorder(
+                circuit_proving_key,
+                circuits,
+                terminator,
+                zk_rng,
+                &mut ProverReportBuilder::new(),
+            )
         }
-
-        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
-            &public_inputs,
-            &evaluations,
-            &proof.msg,
-            &verifier_state,
-        )?;
-
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
-            &circuit_verifying_key.verifier_key,
-            lc_s.values(),
-            &commitments,
-            &query_set.to_set(),
-            &evaluations,
-            &proof.pc_proof,
-            &mut sponge,
-        )?;
-
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::<E, FS>::Check failed");
+        #[cfg(not(feature = "prover-metrics"))]
+        {
+            Self::prove_batch_with_recorder(circuit_proving_key, circuits, terminator, zk_rng)
         }
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::<E, FS>::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+    }
+
+    fn verify_batch_prepared<B: Borrow<Self::VerifierInput>>(
+        prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
+        public_inputs: &[B],
+        proof: &Self::Proof,
+    ) -> Result<bool, SNARKError> {
+        let prepared_inputs = Self::prepare_batch_inputs(&prepared_verifying_key.orig_vk, public_inputs)?;
+        Self::verify_with_prepared_inputs(prepared_verifying_key, &prepared_inputs, proof)
     }
 }
 
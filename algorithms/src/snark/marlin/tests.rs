@@ -185,6 +185,625 @@ mod marlin {
                     assert_eq!(index_vk, serde_json::from_str(&candidate_string).unwrap());
                 }
 
+                pub(crate) fn test_prepared_inputs(num_constraints: usize, num_variables: usize) {
+                    use crate::Prepare;
+
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    let a = Fr::rand(rng);
+                    let b = Fr::rand(rng);
+                    let (c, d) = (a * b, a * b * b);
+                    let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                    let prepared_vk = index_vk.prepare();
+
+                    // The prepared inputs are reused across several proofs, and match the normal verifier.
+                    let prepared_inputs = $marlin_inst::prepare_inputs(&index_vk, [c, d]).unwrap();
+                    let wrong_inputs = $marlin_inst::prepare_inputs(&index_vk, [a, a]).unwrap();
+                    for _ in 0..3 {
+                        let proof = $marlin_inst::prove(&index_pk, &circ, rng).unwrap();
+                        let expected = $marlin_inst::verify(&index_vk, [c, d], &proof).unwrap();
+                        assert!(expected);
+                        assert_eq!(
+                            expected,
+                            $marlin_inst::verify_with_prepared_inputs(&prepared_vk, &prepared_inputs, &proof).unwrap()
+                        );
+                        assert!(
+                            !$marlin_inst::verify_with_prepared_inputs(&prepared_vk, &wrong_inputs, &proof).unwrap()
+                        );
+                    }
+
+                    // The prepared inputs of a batch match the normal batch verifier.
+                    let circuits = vec![circ; 3];
+                    let inputs = vec![[c, d]; 3];
+                    let proof = $marlin_inst::prove_batch(&index_pk, &circuits, rng).unwrap();
+                    let prepared_inputs = $marlin_inst::prepare_batch_inputs(&index_vk, &inputs).unwrap();
+                    assert_eq!(prepared_inputs.batch_size(), 3);
+                    assert!($marlin_inst::verify_batch(&index_vk, &inputs, &proof).unwrap());
+                    assert!($marlin_inst::verify_with_prepared_inputs(&prepared_vk, &prepared_inputs, &proof).unwrap());
+                    assert!($marlin_inst::prepare_batch_inputs(&index_vk, &Vec::<[Fr; 2]>::new()).is_err());
+
+                    // Inputs prepared for another circuit are rejected.
+                    let other_circ =
+                        Circuit { a: Some(a), b: Some(b), num_constraints: num_constraints + 1, num_variables };
+                    let (_, other_vk) = $marlin_inst::circuit_setup(&universal_srs, &other_circ).unwrap();
+                    let other_inputs = $marlin_inst::prepare_inputs(&other_vk, [c, d]).unwrap();
+                    assert!($marlin_inst::verify_with_prepared_inputs(&prepared_vk, &other_inputs, &proof).is_err());
+                }
+
+                pub(crate) fn test_verify_proofs(num_constraints: usize, num_variables: usize) {
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    let circ =
+                        Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+
+                    let mut inputs = Vec::new();
+                    let mut proofs = Vec::new();
+                    for _ in 0..4 {
+                        let a = Fr::rand(rng);
+                        let b = Fr::rand(rng);
+                        let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+                        inputs.push([a * b, a * b * b]);
+                        proofs.push($marlin_inst::prove(&index_pk, &circ, rng).unwrap());
+                    }
+
+                    // A batch of valid proofs verifies, as does the empty batch.
+                    let instances =
+                        inputs.iter().zip(&proofs).map(|(input, proof)| (*input, proof)).collect::<Vec<_>>();
+                    assert!($marlin_inst::verify_proofs(&index_vk, &instances).unwrap());
+                    assert!($marlin_inst::verify_proofs(&index_vk, &instances[..1]).unwrap());
+                    assert!($marlin_inst::verify_proofs::<[Fr; 2]>(&index_vk, &[]).unwrap());
+
+                    // A single invalid proof fails the whole batch, wherever it is.
+                    for i in 0..instances.len() {
+                        let mut invalid_instances = instances.clone();
+                        invalid_instances[i].0 = [Fr::rand(rng), Fr::rand(rng)];
+                        assert!(!$marlin_inst::verify_proofs(&index_vk, &invalid_instances).unwrap());
+                    }
+
+                    // Valid proofs paired with the wrong inputs fail the batch.
+                    let mut swapped_instances = instances.clone();
+                    swapped_instances.swap(0, 1);
+                    swapped_instances[0].0 = inputs[0];
+                    swapped_instances[1].0 = inputs[1];
+                    assert!(!$marlin_inst::verify_proofs(&index_vk, &swapped_instances).unwrap());
+                }
+
+                pub(crate) fn test_verify_proofs_with_keys(num_constraints: usize, num_variables: usize) {
+                    use crate::snark::marlin::Proof;
+
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(200, 50, 600).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+                    let other_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    // Two circuits of different sizes under one SRS, and a third circuit under another SRS.
+                    let circuit_sizes = [
+                        (&universal_srs, num_constraints),
+                        (&universal_srs, 4 * num_constraints),
+                        (&other_srs, num_constraints),
+                    ];
+                    let mut keys = Vec::new();
+                    for (srs, num_constraints) in circuit_sizes {
+                        let circ =
+                            Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+                        let (index_pk, index_vk) = $marlin_inst::circuit_setup(srs, &circ).unwrap();
+                        keys.push((index_pk, index_vk, num_constraints));
+                    }
+                    assert_ne!(keys[0].1.verifier_key, keys[1].1.verifier_key);
+
+                    let mut instances = Vec::new();
+                    for i in 0..6 {
+                        let (index_pk, index_vk, num_constraints) = &keys[i % keys.len()];
+                        let a = Fr::rand(rng);
+                        let b = Fr::rand(rng);
+                        let circ = Circuit { a: Some(a), b: Some(b), num_constraints: *num_constraints, num_variables };
+                        let proof = $marlin_inst::prove(index_pk, &circ, rng).unwrap();
+                        instances.push((index_vk, vec![a * b, a * b * b], proof));
+                    }
+                    #[allow(clippy::type_complexity)]
+                    fn batch<'a>(
+                        instances: &'a [(&CircuitVerifyingKey<Bls12_377, $marlin_mode>, Vec<Fr>, Proof<Bls12_377>)],
+                    ) -> Vec<(&'a CircuitVerifyingKey<Bls12_377, $marlin_mode>, &'a [Fr], &'a Proof<Bls12_377>)> {
+                        instances.iter().map(|(vk, input, proof)| (*vk, &input[..], proof)).collect()
+                    }
+
+                    // A batch of valid proofs under mixed verifying keys verifies, as does the empty batch.
+                    assert!($marlin_inst::verify_proofs_with_keys(&batch(&instances)).unwrap());
+                    assert!($marlin_inst::find_invalid_proofs(&batch(&instances)).unwrap().is_empty());
+                    assert!($marlin_inst::verify_proofs_with_keys(&[]).unwrap());
+
+                    // A single proof is verified as by `verify`.
+                    for (vk, input, proof) in &instances {
+                        let invalid_input = vec![Fr::rand(rng), Fr::rand(rng)];
+                        for input in [input, &invalid_input] {
+                            assert_eq!(
+                                $marlin_inst::verify_proofs_with_keys(&[(*vk, &input[..], proof)]).unwrap(),
+                                $marlin_inst::verify(vk, &input[..], proof).unwrap()
+                            );
+                        }
+                    }
+
+                    // A single invalid proof fails the batch, and is identified.
+                    for i in 0..instances.len() {
+                        let mut invalid_instances = instances.clone();
+                        invalid_instances[i].1 = vec![Fr::rand(rng), Fr::rand(rng)];
+                        assert!(!$marlin_inst::verify_proofs_with_keys(&batch(&invalid_instances)).unwrap());
+                        assert_eq!($marlin_inst::find_invalid_proofs(&batch(&invalid_instances)).unwrap(), vec![i]);
+                    }
+
+                    // A valid proof under the verifying key of another circuit is identified.
+                    let mut invalid_instances = instances.clone();
+                    invalid_instances[0].0 = instances[1].0;
+                    assert!(!$marlin_inst::verify_proofs_with_keys(&batch(&invalid_instances)).unwrap());
+                    assert_eq!($marlin_inst::find_invalid_proofs(&batch(&invalid_instances)).unwrap(), vec![0]);
+                }
+
+                pub(crate) fn test_proving_key_cache(num_constraints: usize, num_variables: usize) {
+                    use snarkvm_utilities::ToBytes;
+                    use std::{
+                        fs,
+                        time::{Duration, SystemTime},
+                    };
+
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    let cache_dir = std::env::temp_dir().join(format!(
+                        "marlin_proving_key_cache_{}_{}",
+                        stringify!($test_struct),
+                        std::process::id()
+                    ));
+                    let _ = fs::remove_dir_all(&cache_dir);
+
+                    let a = Fr::rand(rng);
+                    let b = Fr::rand(rng);
+                    let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+                    let (index_pk, index_vk) =
+                        $marlin_inst::load_or_generate(&circ, &universal_srs, &cache_dir).unwrap();
+                    let (expected_pk, expected_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                    assert_eq!(index_pk.to_bytes_le().unwrap(), expected_pk.to_bytes_le().unwrap());
+                    assert_eq!(index_vk, expected_vk);
+
+                    let circuit_hash = $marlin_inst::circuit_hash(&circ).unwrap();
+                    let path = cache_dir.join(format!("{}.pk", circuit_hash));
+                    assert_eq!(fs::read_dir(&cache_dir).unwrap().count(), 1);
+
+                    // An identical circuit, even with another witness, is loaded without rewriting the cache.
+                    let cached_time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+                    fs::File::options().write(true).open(&path).unwrap().set_modified(cached_time).unwrap();
+                    let other_circ =
+                        Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+                    assert_eq!($marlin_inst::circuit_hash(&other_circ).unwrap(), circuit_hash);
+                    let (cached_pk, cached_vk) =
+                        $marlin_inst::load_or_generate(&other_circ, &universal_srs, &cache_dir).unwrap();
+                    assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), cached_time);
+                    assert_eq!(cached_pk.to_bytes_le().unwrap(), index_pk.to_bytes_le().unwrap());
+                    assert_eq!(cached_vk, index_vk);
+
+                    let proof = $marlin_inst::prove(&cached_pk, &circ, rng).unwrap();
+                    assert!($marlin_inst::verify(&cached_vk, [a * b, a * b * b], &proof).unwrap());
+
+                    // A modified circuit has another hash, and misses the cache.
+                    let modified_circ =
+                        Circuit { a: Some(a), b: Some(b), num_constraints: num_constraints + 1, num_variables };
+                    assert_ne!($marlin_inst::circuit_hash(&modified_circ).unwrap(), circuit_hash);
+                    let (_, modified_vk) =
+                        $marlin_inst::load_or_generate(&modified_circ, &universal_srs, &cache_dir).unwrap();
+                    assert_ne!(modified_vk, index_vk);
+                    assert_eq!(fs::read_dir(&cache_dir).unwrap().count(), 2);
+
+                    // A corrupted or foreign cache entry is regenerated.
+                    let bytes = fs::read(&path).unwrap();
+                    fs::write(&path, &bytes[..bytes.len() / 2]).unwrap();
+                    let (_, regenerated_vk) =
+                        $marlin_inst::load_or_generate(&circ, &universal_srs, &cache_dir).unwrap();
+                    assert_eq!(regenerated_vk, index_vk);
+                    assert_eq!(fs::read(&path).unwrap(), bytes);
+
+                    let other_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+                    let (_, other_vk) = $marlin_inst::load_or_generate(&circ, &other_srs, &cache_dir).unwrap();
+                    assert_ne!(other_vk, index_vk);
+                    assert_ne!(fs::read(&path).unwrap(), bytes);
+
+                    fs::remove_dir_all(&cache_dir).unwrap();
+                }
+
+                pub(crate) fn test_mmap_srs(num_constraints: usize, num_variables: usize) {
+                    use crate::polycommit::kzg10::UniversalParams;
+                    use snarkvm_parameters::testnet3::PowersOfG;
+                    use snarkvm_utilities::{rand::test_crypto_rng_fixed, ToBytes};
+
+                    use parking_lot::RwLock;
+                    use std::{fs, sync::Arc};
+
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    let a = Fr::rand(rng);
+                    let b = Fr::rand(rng);
+                    let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                    let proof = $marlin_inst::prove(&index_pk, &circ, &mut test_crypto_rng_fixed()).unwrap();
+
+                    let path = std::env::temp_dir().join(format!(
+                        "marlin_mmap_srs_{}_{}.srs",
+                        stringify!($test_struct),
+                        std::process::id()
+                    ));
+                    universal_srs.powers.write().write_srs_file(&path).unwrap();
+
+                    // Use a small chunk size, so that the key spans many chunks.
+                    for verify_digest in [true, false] {
+                        let powers = PowersOfG::open_mmap(path.clone(), verify_digest, 64).unwrap();
+                        let mmap_srs =
+                            UniversalParams { powers: Arc::new(RwLock::new(powers)), ..universal_srs.clone() };
+
+                        let (mmap_pk, mmap_vk) = $marlin_inst::circuit_setup(&mmap_srs, &circ).unwrap();
+                        assert_eq!(mmap_pk.to_bytes_le().unwrap(), index_pk.to_bytes_le().unwrap());
+                        assert_eq!(mmap_vk, index_vk);
+
+                        let mmap_proof = $marlin_inst::prove(&mmap_pk, &circ, &mut test_crypto_rng_fixed()).unwrap();
+                        assert_eq!(mmap_proof, proof);
+                        assert!($marlin_inst::verify(&mmap_vk, [a * b, a * b * b], &mmap_proof).unwrap());
+                        assert!($marlin_inst::verify(&index_vk, [a * b, a * b * b], &mmap_proof).unwrap());
+                    }
+
+                    fs::remove_file(&path).unwrap();
+                }
+
+                pub(crate) fn test_proving_key_checked_serialization(num_constraints: usize, num_variables: usize) {
+                    use crate::{snark::marlin::CircuitProvingKey, SNARKError};
+                    use snarkvm_utilities::ToBytes;
+
+                    type ProvingKey = CircuitProvingKey<Bls12_377, $marlin_mode>;
+
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    let a = Fr::rand(rng);
+                    let b = Fr::rand(rng);
+                    let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+
+                    let bytes = index_pk.to_bytes_checked().unwrap();
+                    let candidate_pk = ProvingKey::from_bytes_checked(&bytes).unwrap();
+                    assert_eq!(candidate_pk.to_bytes_le().unwrap(), index_pk.to_bytes_le().unwrap());
+
+                    let proof = $marlin_inst::prove(&candidate_pk, &circ, rng).unwrap();
+                    assert!($marlin_inst::verify(&index_vk, [a * b, a * b * b], &proof).unwrap());
+
+                    // Flipping a single byte of the key or of its checksum is detected.
+                    for index in [0, bytes.len() / 2, bytes.len() - 1] {
+                        let mut corrupted = bytes.clone();
+                        corrupted[index] ^= 1;
+                        let result = ProvingKey::from_bytes_checked(&corrupted);
+                        assert!(matches!(result, Err(SNARKError::ChecksumMismatch)));
+                    }
+
+                    // So is a truncated key.
+                    for len in [0, bytes.len() / 2, bytes.len() - 1] {
+                        let result = ProvingKey::from_bytes_checked(&bytes[..len]);
+                        assert!(matches!(result, Err(SNARKError::ChecksumMismatch)));
+                    }
+                }
+
+                pub(crate) fn test_proving_key_versioned_serialization(num_constraints: usize, num_variables: usize) {
+                    use crate::snark::marlin::CircuitProvingKey;
+                    use snarkvm_utilities::{FromBytes, ToBytes};
+
+                    type ProvingKey = CircuitProvingKey<Bls12_377, $marlin_mode>;
+
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    let a = Fr::rand(rng);
+                    let b = Fr::rand(rng);
+                    let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+
+                    // The key begins with its header, and round trips.
+                    let bytes = index_pk.to_bytes_le().unwrap();
+                    assert_eq!(&bytes[..10], b"MARLINPK\x01\x00");
+                    let candidate_pk = ProvingKey::read_le(&bytes[..]).unwrap();
+                    assert_eq!(candidate_pk.to_bytes_le().unwrap(), bytes);
+
+                    let proof = $marlin_inst::prove(&candidate_pk, &circ, rng).unwrap();
+                    assert!($marlin_inst::verify(&index_vk, [a * b, a * b * b], &proof).unwrap());
+
+                    // A key serialized without a header is still read.
+                    let legacy_pk = ProvingKey::read_le(&bytes[10..]).unwrap();
+                    assert_eq!(legacy_pk.to_bytes_le().unwrap(), bytes);
+
+                    // A key of an unknown version is rejected.
+                    let mut unknown_version = bytes.clone();
+                    unknown_version[8] = 2;
+                    assert!(ProvingKey::read_le(&unknown_version[..]).is_err());
+                }
+
+                pub(crate) fn test_proving_key_memory_cache(num_constraints: usize, num_variables: usize) {
+                    use crate::snark::marlin::ProvingKeyCache;
+                    use snarkvm_utilities::ToBytes;
+                    use std::sync::Arc;
+
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+                    let cache = Arc::new(ProvingKeyCache::<Bls12_377, $marlin_mode>::new(universal_srs.clone()));
+
+                    let circ =
+                        Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup_cached(&cache, &circ).unwrap();
+                    assert_eq!((cache.len(), cache.num_hits(), cache.num_misses()), (1, 0, 1));
+
+                    let (expected_pk, expected_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                    assert_eq!(index_pk.to_bytes_le().unwrap(), expected_pk.to_bytes_le().unwrap());
+                    assert_eq!(index_vk, expected_vk);
+
+                    // Proofs of the same circuit, with other witnesses, reuse the cached key.
+                    for _ in 0..2 {
+                        let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+                        let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+                        let (cached_pk, cached_vk) = $marlin_inst::circuit_setup_cached(&cache, &circ).unwrap();
+                        assert!(Arc::ptr_eq(&cached_pk, &index_pk));
+
+                        let proof = $marlin_inst::prove(&cached_pk, &circ, rng).unwrap();
+                        assert!($marlin_inst::verify(&cached_vk, [a * b, a * b * b], &proof).unwrap());
+                    }
+                    assert_eq!((cache.len(), cache.num_hits(), cache.num_misses()), (1, 2, 1));
+
+                    // The cache is shared across threads.
+                    let handles = (0..4)
+                        .map(|_| {
+                            let cache = cache.clone();
+                            std::thread::spawn(move || $marlin_inst::circuit_setup_cached(&cache, &circ).unwrap().0)
+                        })
+                        .collect::<Vec<_>>();
+                    for handle in handles {
+                        assert!(Arc::ptr_eq(&handle.join().unwrap(), &index_pk));
+                    }
+                    assert_eq!((cache.len(), cache.num_hits(), cache.num_misses()), (1, 6, 1));
+
+                    // A modified circuit has another hash, and misses the cache.
+                    let modified_circ = Circuit { num_constraints: num_constraints + 1, ..circ };
+                    let modified_hash = $marlin_inst::circuit_hash(&modified_circ).unwrap();
+                    assert_ne!(modified_hash, $marlin_inst::circuit_hash(&circ).unwrap());
+                    assert!(cache.get(&modified_hash).is_none());
+
+                    let (modified_pk, modified_vk) =
+                        $marlin_inst::circuit_setup_cached(&cache, &modified_circ).unwrap();
+                    assert!(!Arc::ptr_eq(&modified_pk, &index_pk));
+                    assert_ne!(modified_vk, index_vk);
+                    assert_eq!((cache.len(), cache.num_hits(), cache.num_misses()), (2, 6, 3));
+                }
+
+                pub(crate) fn test_verifying_key_certificate(num_constraints: usize, num_variables: usize) {
+                    use crate::snark::marlin::Certificate;
+                    use snarkvm_utilities::{FromBytes, ToBytes};
+
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    let circ =
+                        Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+                    let circuit_hash = $marlin_inst::circuit_hash(&circ).unwrap();
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+
+                    // An honest certificate verifies, and is a single evaluation proof.
+                    let certificate = $marlin_inst::certify(&index_pk, &index_vk, &circuit_hash).unwrap();
+                    assert!($marlin_inst::verify_certificate(&index_vk, &circ, &certificate).unwrap());
+                    assert!(certificate.size_in_bytes() < 256);
+
+                    // The certificate round trips.
+                    let bytes = certificate.to_bytes_le().unwrap();
+                    assert_eq!(bytes.len(), certificate.size_in_bytes());
+                    let candidate_certificate = Certificate::read_le(&bytes[..]).unwrap();
+                    assert_eq!(candidate_certificate, certificate);
+                    assert!($marlin_inst::verify_certificate(&index_vk, &circ, &candidate_certificate).unwrap());
+
+                    // The certificate does not verify for another circuit.
+                    let modified_circ = Circuit { num_constraints: num_constraints + 1, ..circ };
+                    assert!(!$marlin_inst::verify_certificate(&index_vk, &modified_circ, &certificate).unwrap());
+
+                    // Nor for a verifying key of another circuit.
+                    let (modified_pk, modified_vk) =
+                        $marlin_inst::circuit_setup(&universal_srs, &modified_circ).unwrap();
+                    assert!(!$marlin_inst::verify_certificate(&modified_vk, &circ, &certificate).unwrap());
+
+                    // Nor for the verifying key of another circuit, relabeled with the circuit info of this circuit,
+                    // which only the commitments of the verifying key tell apart.
+                    let mut relabeled_vk = modified_vk.clone();
+                    relabeled_vk.circuit_info = index_vk.circuit_info.clone();
+                    assert!(!$marlin_inst::verify_certificate(&relabeled_vk, &circ, &certificate).unwrap());
+
+                    // Nor for a tampered verifying key.
+                    let mut tampered_vk = index_vk.clone();
+                    tampered_vk.circuit_commitments[0] = index_vk.circuit_commitments[3];
+                    assert_ne!(tampered_vk, index_vk);
+                    assert!(!$marlin_inst::verify_certificate(&tampered_vk, &circ, &certificate).unwrap());
+
+                    // Nor does a certificate of another circuit verify for this circuit.
+                    let modified_hash = $marlin_inst::circuit_hash(&modified_circ).unwrap();
+                    let modified_certificate =
+                        $marlin_inst::certify(&modified_pk, &modified_vk, &modified_hash).unwrap();
+                    assert!(!$marlin_inst::verify_certificate(&index_vk, &circ, &modified_certificate).unwrap());
+
+                    // A key cannot be certified under the hash of another circuit.
+                    assert!($marlin_inst::certify(&index_pk, &index_vk, &modified_hash).is_err());
+                    assert!($marlin_inst::certify(&index_pk, &modified_vk, &circuit_hash).is_err());
+                }
+
+                pub(crate) fn test_deterministic_proof(num_constraints: usize, num_variables: usize) {
+                    use crate::snark::marlin::MarlinMode;
+                    use snarkvm_utilities::ToBytes;
+
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+                    let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+
+                    // Proofs with the same seed are byte-identical, and verify.
+                    let seed = [7u8; 32];
+                    let proof = $marlin_inst::prove_deterministic(&index_pk, &circ, seed).unwrap();
+                    let replayed_proof = $marlin_inst::prove_deterministic(&index_pk, &circ, seed).unwrap();
+                    assert_eq!(proof.to_bytes_le().unwrap(), replayed_proof.to_bytes_le().unwrap());
+                    assert!($marlin_inst::verify(&index_vk, [a * b, a * b * b], &proof).unwrap());
+
+                    // In hiding mode, another seed randomizes the proof.
+                    let other_proof = $marlin_inst::prove_deterministic(&index_pk, &circ, [8u8; 32]).unwrap();
+                    assert!($marlin_inst::verify(&index_vk, [a * b, a * b * b], &other_proof).unwrap());
+                    if <$marlin_mode as MarlinMode>::ZK {
+                        assert_ne!(proof.to_bytes_le().unwrap(), other_proof.to_bytes_le().unwrap());
+                    }
+                }
+
+                #[cfg(feature = "prover-metrics")]
+                pub(crate) fn test_prover_report(num_constraints: usize, num_variables: usize) {
+                    use crate::snark::marlin::ProverPhase;
+
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+                    let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+                    let (index_pk, index_vk, setup_report) =
+                        $marlin_inst::circuit_setup_with_report(&universal_srs, &circ).unwrap();
+
+                    // The setup reports the indexing phase.
+                    let setup_phases = setup_report.phases.iter().map(|phase| phase.phase).collect::<Vec<_>>();
+                    assert_eq!(setup_phases, [ProverPhase::Indexing]);
+                    assert!(setup_report.phase(ProverPhase::Indexing).unwrap().output_bytes > 0);
+                    assert!(setup_report.phases_time() <= setup_report.total_time);
+                    assert_eq!(setup_report.proof_size, None);
+
+                    let (proof, report) = $marlin_inst::prove_with_report(&index_pk, &circ, rng).unwrap();
+                    assert!($marlin_inst::verify(&index_vk, [a * b, a * b * b], &proof).unwrap());
+                    assert_eq!(report.proof_size, Some(proof.size_in_bytes()));
+
+                    // Every phase is reported, in order.
+                    let phases = report.phases.iter().map(|phase| phase.phase).collect::<Vec<_>>();
+                    assert_eq!(phases, [
+                        ProverPhase::WitnessSynthesis,
+                        ProverPhase::FirstRound,
+                        ProverPhase::SecondRound,
+                        ProverPhase::ThirdRound,
+                        ProverPhase::FourthRound,
+                        ProverPhase::Opening
+                    ]);
+                    assert!(report.phases.iter().all(|phase| phase.output_bytes > 0));
+
+                    // The phases cover the prover, up to the construction of the proof.
+                    assert!(report.phases_time() <= report.total_time);
+                    assert!(report.phases_time() >= report.total_time.mul_f64(0.9));
+
+                    // The report serializes to JSON.
+                    let json = serde_json::to_value(&report).unwrap();
+                    assert_eq!(json["phases"][1]["phase"], "FirstRound");
+                    assert_eq!(json["phases"].as_array().unwrap().len(), 6);
+                }
+
+                pub(crate) fn test_compressed_proof(num_constraints: usize, num_variables: usize) {
+                    use crate::snark::marlin::{Proof, ProofEncoding};
+                    use snarkvm_utilities::CanonicalSerialize;
+
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    let circ =
+                        Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+
+                    for batch_size in [1, 2, 3] {
+                        let mut circuits = Vec::new();
+                        let mut inputs = Vec::new();
+                        for _ in 0..batch_size {
+                            let a = Fr::rand(rng);
+                            let b = Fr::rand(rng);
+                            circuits.push(Circuit { a: Some(a), b: Some(b), num_constraints, num_variables });
+                            inputs.push(vec![a * b, a * b * b]);
+                        }
+                        let proof = $marlin_inst::prove_batch(&index_pk, &circuits, rng).unwrap();
+
+                        let mut compressed = Vec::new();
+                        proof.serialize_compressed(&mut compressed).unwrap();
+                        let mut uncompressed = Vec::new();
+                        proof.serialize_with_encoding(ProofEncoding::Uncompressed, &mut uncompressed).unwrap();
+                        assert_eq!(compressed.len(), proof.compressed_size());
+                        assert_eq!(uncompressed.len(), 1 + CanonicalSerialize::uncompressed_size(&proof));
+                        assert!(compressed.len() <= proof.size_in_bytes());
+                        assert!(compressed.len() < uncompressed.len());
+
+                        // Both encodings round trip, and the decoded proof verifies.
+                        for bytes in [&compressed, &uncompressed] {
+                            let reader = &mut &bytes[..];
+                            let candidate_proof = Proof::deserialize_compressed(reader).unwrap();
+                            assert!(reader.is_empty());
+                            assert_eq!(candidate_proof, proof);
+                            assert!($marlin_inst::verify_batch(&index_vk, &inputs, &candidate_proof).unwrap());
+                        }
+
+                        // A flipped compression tag, or an unknown tag, is rejected.
+                        let mut flipped = compressed.clone();
+                        flipped[0] = ProofEncoding::Uncompressed as u8;
+                        assert!(Proof::<Bls12_377>::deserialize_compressed(&mut &flipped[..]).is_err());
+                        flipped[0] = 2;
+                        assert!(Proof::<Bls12_377>::deserialize_compressed(&mut &flipped[..]).is_err());
+                    }
+                }
+
+                pub(crate) fn test_proof_size(num_constraints: usize, num_variables: usize) {
+                    use snarkvm_utilities::ToBytes;
+
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    let circ =
+                        Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+                    let (index_pk, _index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+
+                    for batch_size in [1, 2, 4] {
+                        let circuits = vec![circ.clone(); batch_size];
+                        let proof = $marlin_inst::prove_batch(&index_pk, &circuits, rng).unwrap();
+                        assert_eq!(proof.size_in_bytes(), proof.to_bytes_le().unwrap().len());
+
+                        #[cfg(feature = "prover-metrics")]
+                        {
+                            let (proof, report) =
+                                $marlin_inst::prove_batch_with_report(&index_pk, &circuits, rng).unwrap();
+                            assert_eq!(report.proof_size, Some(proof.to_bytes_le().unwrap().len()));
+                        }
+                    }
+                }
+
                 pub(crate) fn test_bincode(num_constraints: usize, num_variables: usize) {
                     use snarkvm_utilities::{FromBytes, ToBytes};
 
@@ -290,6 +909,144 @@ mod marlin {
         SonicPCTest::test_bincode(num_constraints, num_variables);
         SonicPCPoswTest::test_bincode(num_constraints, num_variables);
     }
+
+    #[test]
+    fn verify_with_prepared_inputs() {
+        SonicPCTest::test_prepared_inputs(25, 25);
+        SonicPCPoswTest::test_prepared_inputs(25, 25);
+    }
+
+    #[test]
+    fn verify_proofs_in_a_batch() {
+        SonicPCTest::test_verify_proofs(25, 25);
+        SonicPCPoswTest::test_verify_proofs(25, 25);
+    }
+
+    #[test]
+    fn verify_proofs_with_keys_in_a_batch() {
+        SonicPCTest::test_verify_proofs_with_keys(25, 25);
+        SonicPCPoswTest::test_verify_proofs_with_keys(25, 25);
+    }
+
+    #[test]
+    fn proving_key_cache() {
+        SonicPCTest::test_proving_key_cache(25, 25);
+        SonicPCPoswTest::test_proving_key_cache(25, 25);
+    }
+
+    #[test]
+    fn mmap_srs_proves_identically() {
+        SonicPCTest::test_mmap_srs(25, 25);
+        SonicPCPoswTest::test_mmap_srs(25, 25);
+    }
+
+    #[test]
+    fn proving_key_checked_serialization() {
+        SonicPCTest::test_proving_key_checked_serialization(25, 25);
+        SonicPCPoswTest::test_proving_key_checked_serialization(25, 25);
+    }
+
+    #[test]
+    fn proving_key_versioned_serialization() {
+        SonicPCTest::test_proving_key_versioned_serialization(25, 25);
+        SonicPCPoswTest::test_proving_key_versioned_serialization(25, 25);
+    }
+
+    #[test]
+    fn proving_key_memory_cache() {
+        SonicPCTest::test_proving_key_memory_cache(25, 25);
+        SonicPCPoswTest::test_proving_key_memory_cache(25, 25);
+    }
+
+    #[test]
+    fn verifying_key_certificate() {
+        SonicPCTest::test_verifying_key_certificate(25, 25);
+        SonicPCPoswTest::test_verifying_key_certificate(25, 25);
+    }
+
+    #[test]
+    fn deterministic_proof() {
+        SonicPCTest::test_deterministic_proof(25, 25);
+        SonicPCPoswTest::test_deterministic_proof(25, 25);
+    }
+
+    #[cfg(feature = "prover-metrics")]
+    #[test]
+    fn prover_report() {
+        SonicPCTest::test_prover_report(25, 25);
+        SonicPCPoswTest::test_prover_report(25, 25);
+    }
+
+    #[test]
+    fn compressed_proof_serialization() {
+        SonicPCTest::test_compressed_proof(25, 25);
+        SonicPCPoswTest::test_compressed_proof(25, 25);
+    }
+
+    #[test]
+    fn proof_size_matches_serialized_length() {
+        SonicPCTest::test_proof_size(25, 25);
+        SonicPCPoswTest::test_proof_size(25, 25);
+    }
+
+    #[test]
+    fn prove_and_verify_with_each_fiat_shamir_rng() {
+        use crate::{
+            crypto_hash::PoseidonSponge,
+            snark::marlin::{
+                CircuitProvingKey,
+                FiatShamirAlgebraicSpongeRng,
+                FiatShamirBlake2XsRng,
+                FiatShamirRng,
+                Proof,
+            },
+        };
+
+        type PoseidonRng = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>;
+        type Blake2XsRng = FiatShamirBlake2XsRng<Fr, Fq>;
+        type ChaChaRng = FiatShamirChaChaRng<Fr, Fq, Blake2s256>;
+
+        fn prove<FS: FiatShamirRng<Fr, Fq>>(
+            proving_key: &CircuitProvingKey<Bls12_377, MarlinHidingMode>,
+            circuit: &Circuit<Fr>,
+        ) -> Proof<Bls12_377> {
+            MarlinSNARK::<Bls12_377, FS>::prove(proving_key, circuit, &mut test_crypto_rng()).unwrap()
+        }
+
+        fn verify<FS: FiatShamirRng<Fr, Fq>>(
+            verifying_key: &CircuitVerifyingKey<Bls12_377, MarlinHidingMode>,
+            inputs: [Fr; 2],
+            proof: &Proof<Bls12_377>,
+        ) -> bool {
+            // A proof from another Fiat-Shamir RNG may fail to verify with an error, or return `false`.
+            MarlinSNARK::<Bls12_377, FS>::verify(verifying_key, inputs, proof).unwrap_or(false)
+        }
+
+        let rng = &mut test_crypto_rng();
+
+        // The default Fiat-Shamir RNG is the Poseidon sponge.
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinSNARK::<Bls12_377>::universal_setup(&max_degree, rng).unwrap();
+
+        let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+        let circuit = Circuit { a: Some(a), b: Some(b), num_constraints: 25, num_variables: 25 };
+        let inputs = [a * b, a * b * b];
+        let (index_pk, index_vk) = MarlinSNARK::<Bls12_377>::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let proofs = [
+            prove::<PoseidonRng>(&index_pk, &circuit),
+            prove::<Blake2XsRng>(&index_pk, &circuit),
+            prove::<ChaChaRng>(&index_pk, &circuit),
+        ];
+        let verifiers = [verify::<PoseidonRng>, verify::<Blake2XsRng>, verify::<ChaChaRng>];
+
+        for (i, proof) in proofs.iter().enumerate() {
+            for (j, verify) in verifiers.iter().enumerate() {
+                assert_eq!(verify(&index_vk, inputs, proof), i == j, "Proof {i} under verifier {j}");
+            }
+        }
+        assert!(MarlinSNARK::<Bls12_377>::verify(&index_vk, inputs, &proofs[0]).unwrap());
+    }
 }
 
 mod marlin_recursion {
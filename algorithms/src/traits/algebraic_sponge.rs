@@ -15,7 +15,8 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use smallvec::SmallVec;
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{FieldParameters, PrimeField};
+use snarkvm_utilities::ToBits;
 
 use core::fmt::Debug;
 
@@ -34,6 +35,81 @@ pub trait AlgebraicSponge<F: PrimeField, const RATE: usize, const CAPACITY: usiz
 
     /// Squeeze `num_elements` field elements from the sponge.
     fn squeeze(&mut self, num_elements: usize) -> SmallVec<[F; 10]>;
+
+    /// Squeeze `num_bits` bits from the sponge, discarding the highest bit of each field element.
+    /// The bits are not guaranteed to be uniformly distributed, and should only be used in certain situations.
+    fn squeeze_bits(&mut self, num_bits: usize) -> Vec<bool> {
+        let bits_per_element = F::size_in_bits() - 1;
+        let num_elements = (num_bits + bits_per_element - 1) / bits_per_element;
+
+        let src_elements = self.squeeze(num_elements);
+        let mut dest_bits = Vec::<bool>::with_capacity(num_elements * bits_per_element);
+
+        let skip = (F::Parameters::REPR_SHAVE_BITS + 1) as usize;
+        for elem in src_elements.iter() {
+            // discard the highest bit
+            let elem_bits = elem.to_repr().to_bits_be();
+            dest_bits.extend_from_slice(&elem_bits[skip..]);
+        }
+        dest_bits.truncate(num_bits);
+
+        dest_bits
+    }
+
+    /// Squeeze `num_elements` non-native field elements from the sponge,
+    /// each from `TargetField::size_in_bits() - 1` bits.
+    fn squeeze_nonnative_field_elements<TargetField: PrimeField>(&mut self, num_elements: usize) -> Vec<TargetField> {
+        squeeze_nonnative_field_elements(self, num_elements, TargetField::size_in_bits() - 1)
+    }
+
+    /// Squeeze `num_elements` short non-native field elements from the sponge, each from 168 bits.
+    fn squeeze_short_nonnative_field_elements<TargetField: PrimeField>(
+        &mut self,
+        num_elements: usize,
+    ) -> Vec<TargetField> {
+        squeeze_nonnative_field_elements(self, num_elements, 168)
+    }
+}
+
+/// Squeezes `num_elements` non-native field elements from the sponge, each from `num_bits_per_nonnative` bits.
+fn squeeze_nonnative_field_elements<
+    F: PrimeField,
+    TargetField: PrimeField,
+    S: AlgebraicSponge<F, RATE, CAPACITY>,
+    const RATE: usize,
+    const CAPACITY: usize,
+>(
+    sponge: &mut S,
+    num_elements: usize,
+    num_bits_per_nonnative: usize,
+) -> Vec<TargetField> {
+    let bits = sponge.squeeze_bits(num_bits_per_nonnative * num_elements);
+
+    let mut lookup_table = Vec::<TargetField>::new();
+    let mut cur = TargetField::one();
+    for _ in 0..num_bits_per_nonnative {
+        lookup_table.push(cur);
+        cur.double_in_place();
+    }
+
+    let dest_elements = bits
+        .chunks_exact(num_bits_per_nonnative)
+        .map(|per_nonnative_bits| {
+            // technically, this can be done via BigInterger::from_bits;
+            // here, we use this method for consistency with the gadget counterpart
+            let mut res = TargetField::zero();
+
+            for (i, bit) in per_nonnative_bits.iter().rev().enumerate() {
+                if *bit {
+                    res += &lookup_table[i];
+                }
+            }
+            res
+        })
+        .collect::<Vec<_>>();
+    debug_assert_eq!(dest_elements.len(), num_elements);
+
+    dest_elements
 }
 
 pub trait DefaultCapacityAlgebraicSponge<F: PrimeField, const RATE: usize>: AlgebraicSponge<F, RATE, 1> {
@@ -34,3 +34,30 @@ pub trait CommitmentScheme: Sized + Clone {
 
     fn parameters(&self) -> Self::Parameters;
 }
+
+///
+/// A commitment scheme that is additively homomorphic, where the sum of two commitments is a commitment
+/// to the sum of their messages, under the sum of their randomness.
+///
+/// Messages are added window-wise, as integers. Schemes that compress their output, such as BHP, are not homomorphic.
+///
+pub trait HomomorphicCommitment: CommitmentScheme {
+    /// Returns the commitment to the sum of the openings of `first` and `second`.
+    fn add(first: &Self::Output, second: &Self::Output) -> Self::Output;
+
+    /// Returns the commitment to the opening of `commitment`, multiplied by `scalar`.
+    fn scalar_mul(commitment: &Self::Output, scalar: &Self::Randomness) -> Self::Output;
+
+    /// Returns the randomness of the sum of two commitments.
+    fn add_randomness(first: &Self::Randomness, second: &Self::Randomness) -> Self::Randomness;
+
+    /// Returns the randomness of a commitment multiplied by `scalar`.
+    fn scalar_mul_randomness(randomness: &Self::Randomness, scalar: &Self::Randomness) -> Self::Randomness;
+
+    /// Returns the commitments to each of the given inputs, under the corresponding randomness.
+    fn commit_many(
+        &self,
+        inputs: &[Vec<bool>],
+        randomness: &[Self::Randomness],
+    ) -> Result<Vec<Self::Output>, CommitmentError>;
+}
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::errors::CRHError;
+use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{FromBytes, ToBits, ToBytes};
 
 use std::{
@@ -28,11 +29,18 @@ pub trait CRH: Clone + Debug + PartialEq + Eq + Send + Sync {
 
     fn setup(message: &str) -> Self;
 
+    /// Returns the hash of the given bits. The other entry points convert their input to bits, and call this.
     fn hash(&self, input: &[bool]) -> Result<Self::Output, CRHError>;
 
+    /// Returns the hash of the given bytes, as the little-endian bits of each byte in order.
     fn hash_bytes(&self, input: &[u8]) -> Result<Self::Output, CRHError> {
         self.hash(&input.to_bits_le())
     }
 
+    /// Returns the hash of the given field elements, as the `MODULUS_BITS` little-endian bits of each element in order.
+    fn hash_field_elements<F: PrimeField>(&self, input: &[F]) -> Result<Self::Output, CRHError> {
+        self.hash(&input.iter().flat_map(|element| element.to_bits_le()).collect::<Vec<_>>())
+    }
+
     fn parameters(&self) -> &Self::Parameters;
 }
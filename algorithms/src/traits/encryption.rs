@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::EncryptionError;
+use crate::{DecryptionError, EncryptionError};
 use snarkvm_utilities::{rand::UniformRand, FromBytes, ToBits, ToBytes};
 
 use rand::{CryptoRng, Rng};
@@ -56,7 +56,23 @@ pub trait EncryptionScheme: Sized + Debug + Clone + PartialEq + Eq {
 
     fn encrypt(&self, symmetric_key: &Self::SymmetricKey, message: &[Self::MessageType]) -> Vec<Self::MessageType>;
 
-    fn decrypt(&self, symmetric_key: &Self::SymmetricKey, ciphertext: &[Self::MessageType]) -> Vec<Self::MessageType>;
+    fn encrypt_authenticated(
+        &self,
+        symmetric_key: &Self::SymmetricKey,
+        message: &[Self::MessageType],
+    ) -> Vec<Self::MessageType>;
+
+    fn decrypt_authenticated(
+        &self,
+        symmetric_key: &Self::SymmetricKey,
+        ciphertext: &[Self::MessageType],
+    ) -> Result<Vec<Self::MessageType>, DecryptionError>;
+
+    fn decrypt_unauthenticated(
+        &self,
+        symmetric_key: &Self::SymmetricKey,
+        ciphertext: &[Self::MessageType],
+    ) -> Vec<Self::MessageType>;
 
     fn parameters(&self) -> &<Self as EncryptionScheme>::Parameters;
 
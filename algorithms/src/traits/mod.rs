@@ -37,3 +37,6 @@ pub use signature::*;
 
 pub mod snark;
 pub use snark::*;
+
+pub mod vrf;
+pub use vrf::*;
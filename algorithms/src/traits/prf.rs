@@ -24,4 +24,10 @@ pub trait PRF {
     type Seed: FromBytes + ToBytes + PartialEq + Eq + Clone + Default + Debug;
 
     fn evaluate(seed: &Self::Seed, input: &Self::Input) -> Self::Output;
+
+    /// Returns `count` outputs for the given seed and input, where the first output equals `evaluate`.
+    fn evaluate_many(seed: &Self::Seed, input: &Self::Input, count: usize) -> Vec<Self::Output>;
+
+    /// Returns `num_bits` output bits for the given seed and input.
+    fn evaluate_bits(seed: &Self::Seed, input: &Self::Input, num_bits: usize) -> Vec<bool>;
 }
@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+use std::{fmt::Debug, hash::Hash};
+
+pub trait VRF: Sized + Debug + Clone + Eq + Send + Sync {
+    type PrivateKey: Clone + Debug + Default + ToBytes + FromBytes + PartialEq + Eq;
+    type PublicKey: Clone + Debug + Default + ToBytes + FromBytes + Hash + Eq + Send + Sync;
+    type Output: Clone + Debug + Default + ToBytes + FromBytes + Hash + Eq + Send + Sync;
+    type Proof: Clone + Debug + Default + ToBytes + FromBytes + PartialEq + Eq + Send + Sync;
+
+    fn setup(message: &str) -> Self;
+
+    fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::PrivateKey;
+
+    fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Self::PublicKey;
+
+    fn prove(&self, private_key: &Self::PrivateKey, input: &[u8]) -> Result<(Self::Output, Self::Proof)>;
+
+    fn verify(
+        &self,
+        public_key: &Self::PublicKey,
+        input: &[u8],
+        output: &Self::Output,
+        proof: &Self::Proof,
+    ) -> Result<bool>;
+}
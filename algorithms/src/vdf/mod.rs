@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A verifiable delay function from sequential squaring in an RSA group, with a Wesolowski proof.
+//!
+//! The input `x` is hashed to an element `g` of the integers modulo the RSA-2048 challenge number `N`,
+//! whose factorization, and therefore the order of the group, is unknown. The output is `y := g^(2^T)`
+//! for `T` iterations, and the proof is `pi := g^floor(2^T / l)` for a 128-bit prime `l` derived from `(g, y, T)`.
+//! The verifier checks `pi^l * g^(2^T mod l) == y`, which costs two short exponentiations, instead of `T` squarings.
+//!
+//! As `-1` is an element of known order, an element is identified with its negation, and encoded as the
+//! smaller of `a` and `N - a`. Otherwise, negating an honest output and its proof would yield a second valid pair.
+
+use crate::{crh::setup_cached, crypto_hash::Poseidon};
+use snarkvm_fields::PrimeField;
+
+use num_bigint::BigUint;
+use once_cell::sync::Lazy;
+
+#[cfg(test)]
+mod tests;
+
+/// The RSA-2048 challenge number, whose factorization is unknown.
+const RSA_2048: &[u8] = b"25195908475657893494027183240048398571429282126204032027777137836043662020707595556264018525880784406918290641249515082189298559149176184502808489120072844992687392807287776735971418347270261896375014971824691165077613379859095700097330459748808428401797429100642458691817195118746121515172654632282216869987549182422433637259085141865462043576798423387184774447920739934236584823824281198163815010674810451660377306056201619676256133844143603833904414952634432190114657544454178424020924616515723350778707749817125772467962926386356373289912154831438167899885040445364023527381951378636564391212010397122822120720357";
+
+/// The modulus of the group.
+static MODULUS: Lazy<BigUint> = Lazy::new(|| BigUint::parse_bytes(RSA_2048, 10).unwrap());
+
+///
+/// Returns the output `g^(2^T)` and its proof, for the group element `g` of the input `x` and `T` iterations.
+///
+pub fn evaluate<F: PrimeField>(input: &F, iterations: u64) -> (BigUint, BigUint) {
+    let generator = hash_to_group(input);
+
+    // Compute the output by sequential squaring.
+    let mut output = generator.clone();
+    for _ in 0..iterations {
+        output = &output * &output % &*MODULUS;
+    }
+    let output = canonical(output);
+
+    // Compute the proof g^floor(2^T / l) by long division, one bit of the quotient per iteration.
+    let challenge = challenge_prime::<F>(&generator, &output, iterations);
+    let mut proof = BigUint::from(1u8);
+    let mut remainder = BigUint::from(1u8);
+    for _ in 0..iterations {
+        remainder <<= 1;
+        proof = &proof * &proof % &*MODULUS;
+        if remainder >= challenge {
+            remainder -= &challenge;
+            proof = proof * &generator % &*MODULUS;
+        }
+    }
+
+    (output, canonical(proof))
+}
+
+///
+/// Returns `true` if the output is `g^(2^T)` for the group element `g` of the input `x` and `T` iterations,
+/// by checking `proof^l * g^(2^T mod l) == output`.
+///
+pub fn verify<F: PrimeField>(input: &F, output: &BigUint, proof: &BigUint, iterations: u64) -> bool {
+    // Ensure the output and proof are encoded canonically.
+    if !is_canonical(output) || !is_canonical(proof) {
+        return false;
+    }
+
+    let generator = hash_to_group(input);
+    let challenge = challenge_prime::<F>(&generator, output, iterations);
+
+    let remainder = BigUint::from(2u8).modpow(&BigUint::from(iterations), &challenge);
+
+    let candidate = proof.modpow(&challenge, &MODULUS) * generator.modpow(&remainder, &MODULUS) % &*MODULUS;
+    canonical(candidate) == *output
+}
+
+/// Returns the smaller of `a` and `N - a`, which encodes both `a` and its negation.
+fn canonical(element: BigUint) -> BigUint {
+    let negation = &*MODULUS - &element;
+    element.min(negation)
+}
+
+/// Returns `true` if the given element is nonzero, and the smaller of itself and its negation.
+fn is_canonical(element: &BigUint) -> bool {
+    *element > BigUint::from(0u8) && element * 2u8 < *MODULUS
+}
+
+/// Returns the Poseidon instance used to hash into the group and to derive challenges.
+fn poseidon<F: PrimeField>() -> Poseidon<F, 4, false> {
+    setup_cached("VDF Poseidon", Poseidon::setup)
+}
+
+/// Returns the group element for the given input, from the field elements squeezed from its hash.
+fn hash_to_group<F: PrimeField>(input: &F) -> BigUint {
+    // Squeeze enough field elements for the sample to exceed the size of the modulus by at least 128 bits.
+    let num_elements = (MODULUS.bits() as usize + 128 + F::size_in_data_bits() - 1) / F::size_in_data_bits();
+
+    let bytes = poseidon::<F>()
+        .evaluate_many(&[*input], num_elements)
+        .iter()
+        .flat_map(|element| element.to_repr().as_ref().iter().flat_map(|limb| limb.to_le_bytes()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    canonical(BigUint::from_bytes_le(&bytes) % &*MODULUS)
+}
+
+/// Returns the 128-bit prime challenge, as the first prime at or after a 128-bit hash of `(g, y, T)` with its top bit set.
+fn challenge_prime<F: PrimeField>(generator: &BigUint, output: &BigUint, iterations: u64) -> BigUint {
+    // Pack the little-endian bytes of `g` and `y` into field elements, so that each chunk fits in the field.
+    let chunk_size = F::size_in_data_bits() / 8;
+    let preimage = [generator, output]
+        .iter()
+        .flat_map(|element| {
+            element.to_bytes_le().chunks(chunk_size).map(F::from_bytes_le_mod_order).collect::<Vec<_>>()
+        })
+        .chain([F::from(iterations as u128)])
+        .collect::<Vec<_>>();
+    let digest = poseidon::<F>().evaluate_with_len(&preimage);
+
+    // Take the low 128 bits of the digest, and set the top and bottom bits.
+    let digest = digest.to_repr().as_ref().iter().take(2).flat_map(|limb| limb.to_le_bytes()).collect::<Vec<_>>();
+    let mut candidate = BigUint::from_bytes_le(&digest) | BigUint::from(1u128 << 127) | BigUint::from(1u8);
+    while !is_prime(&candidate) {
+        candidate += 2u8;
+    }
+    candidate
+}
+
+/// Returns `true` if the given number is prime, by a Miller-Rabin test with the first 20 primes as bases.
+/// The challenge is a hash output, so a composite that passes every base is found with negligible probability.
+fn is_prime(n: &BigUint) -> bool {
+    const BASES: [u8; 20] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71];
+
+    let one = BigUint::from(1u8);
+    if *n < BigUint::from(2u8) {
+        return false;
+    }
+    for base in BASES {
+        if n % base == BigUint::from(0u8) {
+            return *n == BigUint::from(base);
+        }
+    }
+
+    // Write n - 1 = d * 2^s, for an odd d.
+    let n_minus_one = n - &one;
+    let s = n_minus_one.trailing_zeros().unwrap();
+    let d = &n_minus_one >> s;
+
+    BASES.iter().all(|base| {
+        let mut x = BigUint::from(*base).modpow(&d, n);
+        if x == one || x == n_minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = &x * &x % n;
+            if x == n_minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
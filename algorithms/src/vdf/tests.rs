@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_utilities::{test_rng, UniformRand};
+
+#[test]
+fn test_vdf_evaluate() {
+    let rng = &mut test_rng();
+
+    for iterations in [0, 1, 2, 63, 64, 65, 1000] {
+        let input = Fr::rand(rng);
+        let (output, proof) = evaluate(&input, iterations);
+
+        // Ensure the output is g^(2^T), by repeated squaring.
+        let mut expected = hash_to_group(&input);
+        (0..iterations).for_each(|_| expected = &expected * &expected % &*MODULUS);
+        assert_eq!(canonical(expected), output);
+
+        assert!(verify(&input, &output, &proof, iterations));
+    }
+}
+
+#[test]
+fn test_vdf_wrong_iterations() {
+    let rng = &mut test_rng();
+    let input = Fr::rand(rng);
+
+    // Ensure the output and proof of T iterations fail for T - 1 and T + 1 iterations.
+    let (output, proof) = evaluate(&input, 100);
+    assert!(!verify(&input, &output, &proof, 99));
+    assert!(!verify(&input, &output, &proof, 101));
+
+    // Ensure an honest evaluation with the wrong number of iterations fails.
+    let (output, proof) = evaluate(&input, 99);
+    assert!(!verify(&input, &output, &proof, 100));
+}
+
+#[test]
+fn test_vdf_forgery() {
+    let rng = &mut test_rng();
+    let input = Fr::rand(rng);
+    let (output, proof) = evaluate(&input, 100);
+
+    // Ensure a forged output, proof, or input fails.
+    let one = BigUint::from(1u8);
+    assert!(!verify(&input, &(&output + &one), &proof, 100));
+    assert!(!verify(&input, &hash_to_group(&Fr::rand(rng)), &proof, 100));
+    assert!(!verify(&input, &output, &(&proof + &one), 100));
+    assert!(!verify(&Fr::rand(rng), &output, &proof, 100));
+
+    // Ensure negating the output and proof, which yields another solution of the verification equation, fails.
+    assert!(!verify(&input, &(&*MODULUS - &output), &(&*MODULUS - &proof), 100));
+}
+
+#[test]
+fn test_vdf_forgery_with_guessed_order() {
+    let rng = &mut test_rng();
+    // Ensure `2^T` exceeds the guessed order, so that the reduction below skips squarings.
+    let iterations = 4096;
+
+    // Forge an output and proof without the sequential squarings, by reducing the exponents modulo a guessed
+    // group order, as an evaluator could in a group of known order. Here, the guessed order is `N - 1`.
+    let order = &*MODULUS - BigUint::from(1u8);
+
+    for _ in 0..10 {
+        let input = Fr::rand(rng);
+        let generator = hash_to_group(&input);
+
+        let exponent = BigUint::from(1u8) << iterations;
+        let output = canonical(generator.modpow(&(&exponent % &order), &MODULUS));
+        let challenge = challenge_prime::<Fr>(&generator, &output, iterations);
+        let proof = canonical(generator.modpow(&(&exponent / &challenge % &order), &MODULUS));
+
+        assert!(!verify(&input, &output, &proof, iterations));
+    }
+}
+
+#[test]
+fn test_challenge_prime() {
+    let rng = &mut test_rng();
+
+    for _ in 0..10 {
+        let generator = hash_to_group(&Fr::rand(rng));
+        let challenge = challenge_prime::<Fr>(&generator, &generator, 100);
+        assert_eq!(challenge.bits(), 128);
+        assert!(is_prime(&challenge));
+    }
+}
+
+#[test]
+fn test_is_prime() {
+    let primes: [u128; 9] = [2, 3, 5, 37, 41, 7919, 2_147_483_647, 18_446_744_073_709_551_557, (1 << 127) - 1];
+    // The last composite is a strong pseudoprime to the first 12 primes as bases.
+    let composites: [u128; 8] =
+        [0, 1, 4, 9, 561, 3_215_031_751, 18_446_744_073_709_551_615, 3_317_044_064_679_887_385_961_981];
+
+    assert!(primes.iter().all(|n| is_prime(&BigUint::from(*n))));
+    assert!(!composites.iter().any(|n| is_prime(&BigUint::from(*n))));
+}
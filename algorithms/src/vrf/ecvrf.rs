@@ -0,0 +1,262 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::{hash_to_curve, Transcript},
+    VRF,
+};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+
+/// The transcript labels, which separate the nonce, challenge, and output derivations.
+const NONCE_LABEL: u128 = 0;
+const CHALLENGE_LABEL: u128 = 1;
+const OUTPUT_LABEL: u128 = 2;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ECVRFProof<G: AffineCurve> {
+    /// The VRF point, `H^sk`, where `H` is the input hashed to the curve.
+    pub gamma: G,
+    pub challenge: G::ScalarField,
+    pub response: G::ScalarField,
+}
+
+impl<G: AffineCurve> FromBytes for ECVRFProof<G> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let gamma = G::read_le(&mut reader)?;
+        let challenge = G::ScalarField::read_le(&mut reader)?;
+        let response = G::ScalarField::read_le(&mut reader)?;
+
+        Ok(Self { gamma, challenge, response })
+    }
+}
+
+impl<G: AffineCurve> ToBytes for ECVRFProof<G> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.gamma.write_le(&mut writer)?;
+        self.challenge.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+///
+/// A verifiable random function, in the style of ECVRF, where the proof is a
+/// Chaum-Pedersen proof that `log_G(public_key) == log_H(gamma)`, and `H` is the input hashed to the curve.
+///
+/// The nonce is derived from the private key and `H`, so `prove` is deterministic.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ECVRF<G: AffineCurve> {
+    generator: G,
+}
+
+impl<G: AffineCurve> VRF for ECVRF<G> {
+    type Output = G::ScalarField;
+    type PrivateKey = G::ScalarField;
+    type Proof = ECVRFProof<G>;
+    type PublicKey = G;
+
+    fn setup(message: &str) -> Self {
+        let (generator, _, _) = hash_to_curve::<G>(message);
+        Self { generator }
+    }
+
+    fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::PrivateKey {
+        G::ScalarField::rand(rng)
+    }
+
+    ///
+    /// Returns the public key as G^sk.
+    ///
+    fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Self::PublicKey {
+        self.generator.to_projective().mul_bits_constant_time(private_key.to_bits_be().into_iter()).to_affine()
+    }
+
+    ///
+    /// Returns the output Hash(gamma), and the proof (gamma, c, s), where:
+    ///     gamma := H^sk
+    ///     c := Hash(G, H, G^sk, gamma, G^k, H^k)
+    ///     s := k - c * sk
+    ///
+    fn prove(&self, private_key: &Self::PrivateKey, input: &[u8]) -> Result<(Self::Output, Self::Proof)> {
+        // Hash the input to the curve.
+        let h = Self::hash_to_curve(input);
+
+        // Compute G^sk and H^sk.
+        let public_key = self.generate_public_key(private_key);
+        let gamma = h.to_projective().mul_bits_constant_time(private_key.to_bits_be().into_iter()).to_affine();
+
+        // Derive the nonce k := Hash(sk, H).
+        let nonce = {
+            let mut transcript = Transcript::<G>::new();
+            transcript.absorb_field(&G::ScalarField::from(NONCE_LABEL));
+            transcript.absorb_field(private_key);
+            transcript.absorb_point(&h)?;
+            transcript.challenge()
+        };
+
+        // Compute G^k and H^k.
+        let nonce_bits = nonce.to_bits_be();
+        let g_k = self.generator.to_projective().mul_bits_constant_time(nonce_bits.iter().copied()).to_affine();
+        let h_k = h.to_projective().mul_bits_constant_time(nonce_bits.into_iter()).to_affine();
+
+        // Compute the challenge and the response.
+        let challenge = self.challenge(&h, &public_key, &gamma, &g_k, &h_k)?;
+        let response = nonce - (challenge * private_key);
+
+        Ok((Self::output(&gamma)?, ECVRFProof { gamma, challenge, response }))
+    }
+
+    ///
+    /// Verifies (c == c') && (output == Hash(gamma)), where:
+    ///     c' := Hash(G, H, public_key, gamma, G^s public_key^c, H^s gamma^c)
+    ///
+    fn verify(
+        &self,
+        public_key: &Self::PublicKey,
+        input: &[u8],
+        output: &Self::Output,
+        proof: &Self::Proof,
+    ) -> Result<bool> {
+        let ECVRFProof { gamma, challenge, response } = proof;
+
+        // Ensure gamma is a non-zero element of the prime-order subgroup.
+        if gamma.is_zero() || !gamma.is_in_correct_subgroup_assuming_on_curve() {
+            return Ok(false);
+        }
+
+        // Hash the input to the curve.
+        let h = Self::hash_to_curve(input);
+
+        // Compute G^k := G^s public_key^c and H^k := H^s gamma^c.
+        let g_k = (self.generator * *response + *public_key * *challenge).to_affine();
+        let h_k = (h * *response + *gamma * *challenge).to_affine();
+
+        // Compute the candidate challenge.
+        let candidate_challenge = self.challenge(&h, public_key, gamma, &g_k, &h_k)?;
+
+        Ok(*challenge == candidate_challenge && *output == Self::output(gamma)?)
+    }
+}
+
+impl<G: AffineCurve> ECVRF<G> {
+    /// Returns the input hashed to the curve.
+    fn hash_to_curve(input: &[u8]) -> G {
+        let (h, _, _) = hash_to_curve::<G>(&format!("AleoVRF {}", hex::encode(input)));
+        h
+    }
+
+    /// Returns the challenge Hash(G, H, public_key, gamma, G^k, H^k).
+    fn challenge(&self, h: &G, public_key: &G, gamma: &G, g_k: &G, h_k: &G) -> Result<G::ScalarField> {
+        let mut transcript = Transcript::<G>::new();
+        transcript.absorb_field(&G::ScalarField::from(CHALLENGE_LABEL));
+        for point in [&self.generator, h, public_key, gamma, g_k, h_k] {
+            transcript.absorb_point(point)?;
+        }
+        Ok(transcript.challenge())
+    }
+
+    /// Returns the output Hash(gamma).
+    fn output(gamma: &G) -> Result<G::ScalarField> {
+        let mut transcript = Transcript::<G>::new();
+        transcript.absorb_field(&G::ScalarField::from(OUTPUT_LABEL));
+        transcript.absorb_point(gamma)?;
+        Ok(transcript.challenge())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Fr, G1Affine, G1Projective};
+    use snarkvm_fields::Zero;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type TestVRF = ECVRF<G1Affine>;
+
+    #[test]
+    fn test_ecvrf_determinism() {
+        let rng = &mut test_crypto_rng();
+        let vrf = TestVRF::setup("test_ecvrf_determinism");
+        let private_key = vrf.generate_private_key(rng);
+
+        let (output, proof) = vrf.prove(&private_key, b"round 1").unwrap();
+        assert_eq!((output, proof), vrf.prove(&private_key, b"round 1").unwrap());
+
+        // Ensure the output depends on the input and the private key.
+        assert_ne!(output, vrf.prove(&private_key, b"round 2").unwrap().0);
+        assert_ne!(output, vrf.prove(&vrf.generate_private_key(rng), b"round 1").unwrap().0);
+    }
+
+    #[test]
+    fn test_ecvrf_verify() {
+        let rng = &mut test_crypto_rng();
+        let vrf = TestVRF::setup("test_ecvrf_verify");
+
+        for _ in 0..10 {
+            let private_key = vrf.generate_private_key(rng);
+            let public_key = vrf.generate_public_key(&private_key);
+            let input: [u8; 32] = rng.gen();
+
+            let (output, proof) = vrf.prove(&private_key, &input).unwrap();
+            assert!(vrf.verify(&public_key, &input, &output, &proof).unwrap());
+
+            // Ensure the proof round trips through its byte representation.
+            let candidate = ECVRFProof::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+            assert!(vrf.verify(&public_key, &input, &output, &candidate).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_ecvrf_forgery() {
+        let rng = &mut test_crypto_rng();
+        let vrf = TestVRF::setup("test_ecvrf_forgery");
+
+        let private_key = vrf.generate_private_key(rng);
+        let public_key = vrf.generate_public_key(&private_key);
+        let (output, proof) = vrf.prove(&private_key, b"input").unwrap();
+
+        // Ensure a wrong output fails.
+        assert!(!vrf.verify(&public_key, b"input", &Fr::rand(rng), &proof).unwrap());
+
+        // Ensure a wrong input or public key fails.
+        assert!(!vrf.verify(&public_key, b"other input", &output, &proof).unwrap());
+        let other_public_key = vrf.generate_public_key(&vrf.generate_private_key(rng));
+        assert!(!vrf.verify(&other_public_key, b"input", &output, &proof).unwrap());
+
+        // Ensure a tampered proof fails.
+        let gamma = (proof.gamma.to_projective() + G1Projective::rand(rng)).to_affine();
+        let tampered = ECVRFProof { gamma, ..proof };
+        assert!(!vrf.verify(&public_key, b"input", &TestVRF::output(&gamma).unwrap(), &tampered).unwrap());
+        let tampered = ECVRFProof { response: proof.response + Fr::from(1u128), ..proof };
+        assert!(!vrf.verify(&public_key, b"input", &output, &tampered).unwrap());
+
+        // Ensure the zero point is rejected.
+        let tampered = ECVRFProof { gamma: G1Affine::zero(), ..proof };
+        assert!(!vrf.verify(&public_key, b"input", &output, &tampered).unwrap());
+    }
+}
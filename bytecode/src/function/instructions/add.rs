@@ -22,11 +22,11 @@ use crate::{
 use snarkvm_circuits::{
     count,
     AddChecked,
+    CircuitType,
     Count,
     Field,
     Group,
     Literal,
-    LiteralType,
     Metrics,
     Parser,
     ParserResult,
@@ -110,10 +110,10 @@ impl<P: Program> Operation<P> for Add<P> {
 }
 
 impl<P: Program> Metrics<Self> for Add<P> {
-    type Case = (LiteralType<P::Environment>, LiteralType<P::Environment>);
+    type Case = (Literal<P::Environment>, Literal<P::Environment>);
 
     fn count(case: &Self::Case) -> Count {
-        crate::match_count!(match AddCircuit::count(case) {
+        crate::match_count_by_value!(match AddCircuit::count(case) {
             (Field, Field) => Field,
             (Group, Group) => Group,
             (I8, I8) => I8,
@@ -157,6 +157,32 @@ macro_rules! match_count {
     }};
 }
 
+/// Creates a match statement that produces the count for a binary instruction, from its operands.
+/// This is used for instructions whose count depends on the values of constant operands.
+///
+/// ## Example
+/// ```ignore
+/// match_count_by_value!(
+///     match AddWrappedCircuit::count(case) {
+///         (I8, I8) => I8,
+///         (U8, U8) => U8,
+///     }
+/// )
+/// ```
+#[macro_export]
+macro_rules! match_count_by_value {
+    (match $operation:tt::$macro_:ident($case:expr) { $( ($input_a:ident, $input_b:ident) => $output:ident, )+ }) => {{
+        match $case {
+            $(
+                (Literal::$input_a(a), Literal::$input_b(b)) => {
+                    $macro_!($input_a<P::Environment>, $operation<$input_b<P::Environment>, Output = $output<P::Environment>>, &(CircuitType::from(a).into(), CircuitType::from(b).into()))
+                }
+            ),+
+            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+        }
+    }};
+}
+
 pub trait Opcode {
     ///
     /// Returns the opcode of the operation.
@@ -21,10 +21,10 @@ use crate::{
 };
 use snarkvm_circuits::{
     count,
+    CircuitType,
     Count,
     Field,
     Literal,
-    LiteralType,
     Metrics,
     MulChecked,
     Parser,
@@ -108,10 +108,10 @@ impl<P: Program> Operation<P> for Mul<P> {
 }
 
 impl<P: Program> Metrics<Self> for Mul<P> {
-    type Case = (LiteralType<P::Environment>, LiteralType<P::Environment>);
+    type Case = (Literal<P::Environment>, Literal<P::Environment>);
 
     fn count(case: &Self::Case) -> Count {
-        crate::match_count!(match MulCircuit::count(case) {
+        crate::match_count_by_value!(match MulCircuit::count(case) {
             (Field, Field) => Field,
             // (Group, Scalar) => Group,
             // (Scalar, Group) => Group,
@@ -171,7 +171,7 @@ impl<P: Program> Into<Instruction<P>> for Mul<P> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{function::Register, test_instruction_halts, test_modes, Identifier, Process};
+    use crate::{binary_instruction_test, function::Register, test_instruction_halts, test_modes, Identifier, Process};
 
     type P = Process;
 
@@ -404,16 +404,20 @@ mod tests {
         );
     }
 
-    test_modes!(i8, Mul, "1i8", "2i8", "2i8");
-    test_modes!(i16, Mul, "1i16", "2i16", "2i16");
-    test_modes!(i32, Mul, "1i32", "2i32", "2i32");
-    test_modes!(i64, Mul, "1i64", "2i64", "2i64");
-    test_modes!(i128, Mul, "1i128", "2i128", "2i128");
-    test_modes!(u8, Mul, "1u8", "2u8", "2u8");
-    test_modes!(u16, Mul, "1u16", "2u16", "2u16");
-    test_modes!(u32, Mul, "1u32", "2u32", "2u32");
-    test_modes!(u64, Mul, "1u64", "2u64", "2u64");
-    test_modes!(u128, Mul, "1u128", "2u128", "2u128");
+    test_modes!(i8, Mul, "2i8", "3i8", "6i8");
+    test_modes!(i16, Mul, "2i16", "3i16", "6i16");
+    test_modes!(i32, Mul, "2i32", "3i32", "6i32");
+    test_modes!(i64, Mul, "2i64", "3i64", "6i64");
+    test_modes!(i128, Mul, "2i128", "3i128", "6i128");
+    test_modes!(u8, Mul, "2u8", "3u8", "6u8");
+    test_modes!(u16, Mul, "2u16", "3u16", "6u16");
+    test_modes!(u32, Mul, "2u32", "3u32", "6u32");
+    test_modes!(u64, Mul, "2u64", "3u64", "6u64");
+    test_modes!(u128, Mul, "2u128", "3u128", "6u128");
+
+    // Multiplying by the constant one yields the other operand.
+    binary_instruction_test!(i8_constant_one_times_public, Mul, "1i8.constant", "2i8.public", "2i8.public");
+    binary_instruction_test!(u8_public_times_constant_one, Mul, "2u8.public", "1u8.constant", "2u8.public");
 
     test_instruction_halts!(
         i8_overflow_halts,
@@ -21,9 +21,9 @@ use crate::{
 };
 use snarkvm_circuits::{
     count,
+    CircuitType,
     Count,
     Literal,
-    LiteralType,
     Metrics,
     Parser,
     ParserResult,
@@ -104,10 +104,10 @@ impl<P: Program> Operation<P> for SubWrapped<P> {
 }
 
 impl<P: Program> Metrics<Self> for SubWrapped<P> {
-    type Case = (LiteralType<P::Environment>, LiteralType<P::Environment>);
+    type Case = (Literal<P::Environment>, Literal<P::Environment>);
 
     fn count(case: &Self::Case) -> Count {
-        crate::match_count!(match SubWrappedCircuit::count(case) {
+        crate::match_count_by_value!(match SubWrappedCircuit::count(case) {
             (I8, I8) => I8,
             (I16, I16) => I16,
             (I32, I32) => I32,
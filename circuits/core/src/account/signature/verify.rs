@@ -20,7 +20,7 @@ impl<A: Aleo> Signature<A> {
     /// Returns `true` if the signature is valid for the given `address` and `message`.
     pub fn verify(&self, address: &Address<A>, message: &[Literal<A>]) -> Boolean<A> {
         // Compute G^sk_sig^c.
-        let pk_sig_c = &self.pk_sig * &self.verifier_challenge;
+        let pk_sig_c = self.pk_sig.mul_windowed(&self.verifier_challenge);
 
         // Compute G^r := G^s G^sk_sig^c.
         let g_r = A::g_scalar_multiply(&self.prover_response) + pk_sig_c;
@@ -155,6 +155,87 @@ pub(crate) mod tests {
         }
     }
 
+    /// Returns the given scalar with the bit at `index` flipped.
+    fn flip_bit(value: &NativeScalarField, index: usize) -> NativeScalarField {
+        let power = NativeScalarField::from(2u64).pow([index as u64]);
+        match snarkvm_utilities::ToBits::to_bits_le(value)[index] {
+            true => *value - power,
+            false => *value + power,
+        }
+    }
+
+    fn check_verify_tampered(mode: Mode) {
+        let rng = &mut test_rng();
+        let native = Circuit::native_signature_scheme();
+
+        // Sample a random message.
+        let message = vec![
+            Literal::Field(Field::new(mode, UniformRand::rand(rng))),
+            Literal::Scalar(Scalar::new(mode, UniformRand::rand(rng))),
+        ];
+        let message_bits = message.to_bits_le().eject_value();
+
+        // Generate the private key, and a valid signature on the message.
+        let (sk_sig, r_sig, address) = generate_private_key_and_address();
+        let signature = generate_signature((&sk_sig, &r_sig), &address, &message);
+
+        // Generate an unrelated address.
+        let (_, _, other_address) = generate_private_key_and_address();
+
+        // Tamper with the message.
+        let other_message = vec![
+            Literal::Field(Field::new(mode, UniformRand::rand(rng))),
+            Literal::Scalar(Scalar::new(mode, UniformRand::rand(rng))),
+        ];
+        let other_message_bits = other_message.to_bits_le().eject_value();
+
+        // Tamper with the prover response.
+        let mut bad_response = signature;
+        bad_response.prover_response += NativeScalarField::one();
+
+        // Tamper with the verifier challenge.
+        let mut bad_challenge = signature;
+        bad_challenge.verifier_challenge += NativeScalarField::one();
+
+        // Flip a random bit in the prover response and in the verifier challenge.
+        let mut flipped_response = signature;
+        flipped_response.prover_response = flip_bit(&signature.prover_response, u32::rand(rng) as usize % 250);
+        let mut flipped_challenge = signature;
+        flipped_challenge.verifier_challenge = flip_bit(&signature.verifier_challenge, u32::rand(rng) as usize % 250);
+
+        for (name, native_address, native_message, native_signature, circuit_message) in [
+            ("Valid", &address, &message_bits, &signature, &message),
+            ("Address", &other_address, &message_bits, &signature, &message),
+            ("Message", &address, &other_message_bits, &signature, &other_message),
+            ("Response", &address, &message_bits, &bad_response, &message),
+            ("Challenge", &address, &message_bits, &bad_challenge, &message),
+            ("FlippedResponse", &address, &message_bits, &flipped_response, &message),
+            ("FlippedChallenge", &address, &message_bits, &flipped_challenge, &message),
+        ] {
+            // Compute the expected verdict natively.
+            let expected = native.verify(native_address, native_message, native_signature).expect("Failed to verify");
+            assert_eq!(name == "Valid", expected);
+
+            let circuit_address = Address::<Circuit>::new(mode, *native_address);
+            let circuit_signature = Signature::<Circuit>::new(
+                mode,
+                (
+                    native_signature.prover_response,
+                    native_signature.verifier_challenge,
+                    native_signature.root_public_key().expect("Failed to recover").to_x_coordinate(),
+                    native_signature.root_randomizer().expect("Failed to recover").to_x_coordinate(),
+                ),
+            );
+
+            Circuit::scope(format!("{} {}", mode, name), || {
+                let candidate = circuit_signature.verify(&circuit_address, circuit_message);
+                assert_eq!(expected, candidate.eject_value(), "{name}");
+                assert!(Circuit::is_satisfied_in_scope(), "{name}");
+            });
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_verify_constant() {
         check_verify(Mode::Constant, 4265, 0, 0, 0);
@@ -162,11 +243,18 @@ pub(crate) mod tests {
 
     #[test]
     fn test_verify_public() {
-        check_verify(Mode::Public, 1757, 0, 7322, 7327);
+        check_verify(Mode::Public, 1510, 0, 6835, 6840);
     }
 
     #[test]
     fn test_verify_private() {
-        check_verify(Mode::Private, 1757, 0, 7322, 7327);
+        check_verify(Mode::Private, 1510, 0, 6835, 6840);
+    }
+
+    #[test]
+    fn test_verify_tampered() {
+        check_verify_tampered(Mode::Constant);
+        check_verify_tampered(Mode::Public);
+        check_verify_tampered(Mode::Private);
     }
 }
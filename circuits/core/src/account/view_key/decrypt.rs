@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> ViewKey<A> {
+    ///
+    /// Returns the record view key, given the x-coordinate of the ciphertext randomizer `G^r`.
+    /// The record view key is the x-coordinate of the shared secret `(G^r)^view_key`.
+    ///
+    pub fn to_record_view_key(&self, ciphertext_randomizer: &Field<A>) -> Field<A> {
+        Group::from_x_coordinate(ciphertext_randomizer.clone()).mul_windowed(&self.0).to_x_coordinate()
+    }
+
+    ///
+    /// Returns `true` if the record ciphertext decrypts to the given plaintext under this view key,
+    /// and the first plaintext element is the x-coordinate of the given owner address.
+    ///
+    pub fn verify_decryption(
+        &self,
+        owner: &Address<A>,
+        ciphertext_randomizer: &Field<A>,
+        ciphertext: &[Field<A>],
+        plaintext: &[Field<A>],
+    ) -> Boolean<A> {
+        // Ensure the ciphertext and plaintext are of equal length.
+        if ciphertext.len() != plaintext.len() {
+            A::halt("The ciphertext and plaintext must be of equal length")
+        }
+        // Ensure the plaintext contains the owner.
+        if plaintext.is_empty() {
+            A::halt("The plaintext must contain the record owner")
+        }
+
+        // Decrypt the ciphertext with the record view key.
+        let record_view_key = self.to_record_view_key(ciphertext_randomizer);
+        let candidate_plaintext = A::decrypt_ecies(&record_view_key, ciphertext);
+
+        // Check the decrypted plaintext matches the claimed plaintext.
+        let is_plaintext_valid = candidate_plaintext
+            .iter()
+            .zip_eq(plaintext)
+            .fold(Boolean::constant(true), |is_valid, (candidate, expected)| is_valid & candidate.is_equal(expected));
+
+        // Check the record owner matches the given address.
+        let is_owner_valid = plaintext[0].is_equal(&owner.to_group().to_x_coordinate());
+
+        is_plaintext_valid & is_owner_valid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Devnet as Circuit;
+    use snarkvm_algorithms::EncryptionScheme;
+    use snarkvm_curves::AffineCurve;
+    use snarkvm_utilities::{test_crypto_rng, test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 10;
+
+    type NativeAffine = <Circuit as Environment>::Affine;
+    type NativeBaseField = <Circuit as Environment>::BaseField;
+    type NativeScalarField = <Circuit as Environment>::ScalarField;
+    type NativeEncryption =
+        snarkvm_algorithms::encryption::ECIESPoseidonEncryption<<Circuit as Environment>::AffineParameters>;
+
+    /// Returns a random view key, owner, ciphertext randomizer, ciphertext, and plaintext, encrypted natively.
+    fn sample_record() -> (NativeScalarField, NativeAffine, NativeBaseField, Vec<NativeBaseField>, Vec<NativeBaseField>) {
+        let native = Circuit::native_encryption_scheme();
+        let rng = &mut test_crypto_rng();
+
+        // Sample the view key and owner.
+        let view_key: NativeScalarField = UniformRand::rand(rng);
+        let owner: NativeAffine = UniformRand::rand(rng);
+
+        // Encode the record contents, as done for native records.
+        let value = u64::rand(rng).to_le_bytes();
+        let payload = (0..64).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+        let mut plaintext = vec![owner.to_x_coordinate()];
+        plaintext.extend(NativeEncryption::encode_message(&value).unwrap());
+        plaintext.extend(NativeEncryption::encode_message(&payload).unwrap());
+
+        // Encrypt the record.
+        let public_key = native.generate_public_key(&view_key);
+        let (_, ciphertext_randomizer, record_view_key) = native.generate_asymmetric_key(&public_key, rng);
+        assert_eq!(Some(record_view_key), native.generate_symmetric_key(&view_key, ciphertext_randomizer));
+        let ciphertext = native.encrypt(&record_view_key, &plaintext);
+
+        (view_key, owner, ciphertext_randomizer, ciphertext, plaintext)
+    }
+
+    fn check_verify_decryption(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        // Initialize the thread-local ECIES gadget, so that its setup is not counted in the scopes below.
+        Circuit::decrypt_ecies(&Field::zero(), &[Field::zero()]);
+        Circuit::reset();
+
+        for i in 0..ITERATIONS {
+            let (view_key, owner, ciphertext_randomizer, ciphertext, plaintext) = sample_record();
+
+            let view_key = ViewKey::<Circuit>::new(mode, view_key);
+            let owner = Address::<Circuit>::new(mode, owner);
+            let ciphertext_randomizer = Field::new(mode, ciphertext_randomizer);
+            let ciphertext: Vec<Field<_>> = Inject::new(mode, ciphertext);
+            let plaintext: Vec<Field<_>> = Inject::new(mode, plaintext);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = view_key.verify_decryption(&owner, &ciphertext_randomizer, &ciphertext, &plaintext);
+                assert!(candidate.eject_value());
+                match mode.is_constant() {
+                    // Note: The number of constants depends on the sampled record, so it is an upper bound.
+                    true => assert_scope!(<=num_constants, <=num_public, <=num_private, <=num_constraints),
+                    false => assert_scope!(num_constants, num_public, num_private, num_constraints),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_verify_decryption_tampered(mode: Mode) {
+        let rng = &mut test_rng();
+        let (view_key, owner, ciphertext_randomizer, ciphertext, plaintext) = sample_record();
+
+        // Tamper with one ciphertext element.
+        let mut tampered_ciphertext = ciphertext.clone();
+        let index = u32::rand(rng) as usize % tampered_ciphertext.len();
+        tampered_ciphertext[index] += NativeBaseField::one();
+
+        // Sample a different view key and owner.
+        let other_view_key: NativeScalarField = UniformRand::rand(rng);
+        let other_owner: NativeAffine = UniformRand::rand(rng);
+
+        for (name, view_key, owner, ciphertext) in [
+            ("Ciphertext", view_key, owner, &tampered_ciphertext),
+            ("ViewKey", other_view_key, owner, &ciphertext),
+            ("Owner", view_key, other_owner, &ciphertext),
+        ] {
+            let view_key = ViewKey::<Circuit>::new(mode, view_key);
+            let owner = Address::<Circuit>::new(mode, owner);
+            let ciphertext_randomizer = Field::new(mode, ciphertext_randomizer);
+            let ciphertext: Vec<Field<_>> = Inject::new(mode, ciphertext.clone());
+            let plaintext: Vec<Field<_>> = Inject::new(mode, plaintext.clone());
+
+            Circuit::scope(format!("{mode} {name}"), || {
+                let candidate = view_key.verify_decryption(&owner, &ciphertext_randomizer, &ciphertext, &plaintext);
+                assert!(!candidate.eject_value(), "{name}");
+                assert!(Circuit::is_satisfied_in_scope(), "{name}");
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_verify_decryption_constant() {
+        check_verify_decryption(Mode::Constant, 1759, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_verify_decryption_public() {
+        check_verify_decryption(Mode::Public, 505, 0, 3475, 3481);
+    }
+
+    #[test]
+    fn test_verify_decryption_private() {
+        check_verify_decryption(Mode::Private, 505, 0, 3475, 3481);
+    }
+
+    #[test]
+    fn test_verify_decryption_tampered() {
+        check_verify_decryption_tampered(Mode::Constant);
+        check_verify_decryption_tampered(Mode::Public);
+        check_verify_decryption_tampered(Mode::Private);
+    }
+}
@@ -14,11 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod decrypt;
+
 #[cfg(test)]
 use snarkvm_circuits_types::environment::assert_scope;
 
 use crate::Aleo;
-use snarkvm_circuits_types::{environment::prelude::*, Scalar};
+use snarkvm_circuits_types::{environment::prelude::*, Address, Boolean, Field, Group, Scalar};
 
 /// The account view key is able to decrypt records and ciphertext messages.
 pub struct ViewKey<A: Aleo>(Scalar<A>);
@@ -90,4 +90,112 @@ mod tests {
     fn test_commitment_private() {
         check_commitment::<32, 48>(Mode::Private, 631, 0, 9404, 9404);
     }
+
+    fn check_commitment_variant<const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>(
+        circuit: &BHP<Circuit, NUM_WINDOWS, WINDOW_SIZE>,
+        mode: Mode,
+        num_input_bits: usize,
+    ) {
+        // Initialize the native BHP commitment.
+        let native = BHPCommitment::<Projective, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+
+        for i in 0..ITERATIONS {
+            // Sample a random input and randomizer.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            let randomness = ScalarField::rand(&mut test_rng());
+            // Compute the expected commitment.
+            let expected = native.commit(&input, &randomness).expect("Failed to commit to native input");
+            // Prepare the circuit input and randomness.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let circuit_randomness: Scalar<_> = Inject::new(mode, randomness);
+
+            Circuit::scope(format!("BHP {mode} {num_input_bits} {i}"), || {
+                // Perform the commit operation.
+                let candidate = circuit.commit(&circuit_input, &circuit_randomness);
+                assert_scope!();
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_commitment_variant_count<const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>(
+        circuit: &BHP<Circuit, NUM_WINDOWS, WINDOW_SIZE>,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        // Determine the maximum number of inputs.
+        let num_input_bits = NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE;
+
+        // Prepare a private input and randomizer.
+        let input = (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+        let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input);
+        let circuit_randomness = Scalar::<Circuit>::new(Mode::Private, ScalarField::rand(&mut test_rng()));
+
+        Circuit::scope("BHP commit", || {
+            let _candidate = circuit.commit(&circuit_input, &circuit_randomness);
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    fn check_variant<const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>(circuit: BHP<Circuit, NUM_WINDOWS, WINDOW_SIZE>) {
+        // Check the empty input, a single window, an intermediate input, and the largest allowed input.
+        let num_input_bits = NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE;
+        for size in [0, WINDOW_SIZE * BHP_CHUNK_SIZE, num_input_bits / 2 + 1, num_input_bits] {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_commitment_variant(&circuit, mode, size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_commitment_bhp256() {
+        let circuit = BHP256::<Circuit>::setup(MESSAGE);
+        check_commitment_variant_count(&circuit, 511, 0, 1944, 1944);
+        check_variant(circuit);
+    }
+
+    #[test]
+    fn test_commitment_bhp512() {
+        let circuit = BHP512::<Circuit>::setup(MESSAGE);
+        check_commitment_variant_count(&circuit, 515, 0, 2376, 2376);
+        check_variant(circuit);
+    }
+
+    #[test]
+    fn test_commitment_bhp768() {
+        let circuit = BHP768::<Circuit>::setup(MESSAGE);
+        check_commitment_variant_count(&circuit, 527, 0, 2832, 2832);
+        check_variant(circuit);
+    }
+
+    #[test]
+    fn test_commitment_bhp1024() {
+        let circuit = BHP1024::<Circuit>::setup(MESSAGE);
+        check_commitment_variant_count(&circuit, 527, 0, 3252, 3252);
+        check_variant(circuit);
+    }
+
+    fn check_commitment_exceeds_capacity<const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>() {
+        let num_input_bits = NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE + 1;
+        let randomness = ScalarField::rand(&mut test_rng());
+
+        // Ensure the native BHP commitment rejects the input as well.
+        let native = BHPCommitment::<Projective, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        assert!(native.commit(&vec![true; num_input_bits], &randomness).is_err());
+
+        let circuit = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; num_input_bits]);
+        let randomness = Scalar::<Circuit>::new(Mode::Private, randomness);
+        let _candidate = circuit.commit(&input, &randomness);
+    }
+
+    #[test]
+    #[should_panic(expected = "Inputs to this BHP variant cannot exceed 258 bits")]
+    fn test_commitment_exceeds_capacity() {
+        check_commitment_exceeds_capacity::<2, 43>();
+    }
 }
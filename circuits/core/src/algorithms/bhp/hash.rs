@@ -84,4 +84,93 @@ mod tests {
     fn test_hash_private() {
         check_hash::<32, 48>(Mode::Private, 129, 0, 7898, 7898);
     }
+
+    fn check_hash_variant<const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>(
+        circuit: BHP<Circuit, NUM_WINDOWS, WINDOW_SIZE>,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        // Initialize the native BHP hash.
+        let native = BHPCRH::<Projective, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        // Determine the maximum number of inputs.
+        let num_input_bits = NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE;
+
+        // Check every input up to one window past the first, an intermediate input, and the largest allowed input.
+        let sizes = (0..=WINDOW_SIZE * BHP_CHUNK_SIZE + 1).chain([num_input_bits / 2 + 1, num_input_bits]);
+        for size in sizes {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                // Sample a random input.
+                let input = (0..size).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+                // Compute the expected hash.
+                let expected = native.hash(&input).expect("Failed to hash native input");
+                // Prepare the circuit input.
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+                Circuit::scope(format!("BHP {mode} {size}"), || {
+                    // Perform the hash operation.
+                    let candidate = circuit.hash(&circuit_input);
+                    assert_eq!(expected, candidate.eject_value());
+                    match (mode, size == num_input_bits) {
+                        (Mode::Private, true) => assert_scope!(num_constants, num_public, num_private, num_constraints),
+                        _ => assert_scope!(),
+                    }
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_hash_bhp256() {
+        check_hash_variant(BHP256::<Circuit>::setup(MESSAGE), 9, 0, 438, 438);
+    }
+
+    #[test]
+    fn test_hash_bhp512() {
+        check_hash_variant(BHP512::<Circuit>::setup(MESSAGE), 13, 0, 870, 870);
+    }
+
+    #[test]
+    fn test_hash_bhp768() {
+        check_hash_variant(BHP768::<Circuit>::setup(MESSAGE), 25, 0, 1326, 1326);
+    }
+
+    #[test]
+    fn test_hash_bhp1024() {
+        check_hash_variant(BHP1024::<Circuit>::setup(MESSAGE), 25, 0, 1746, 1746);
+    }
+
+    #[test]
+    fn test_hash_empty_input() {
+        // Ensure the native BHP hash hashes the empty input to the x-coordinate of the identity.
+        let native = BHPCRH::<Projective, 2, 43>::setup(MESSAGE);
+        let expected = native.hash(&[]).unwrap();
+        assert!(expected.is_zero());
+
+        // Ensure the BHP gadget matches the native BHP hash, at no cost.
+        Circuit::scope("BHP empty", || {
+            let candidate = BHP256::<Circuit>::setup(MESSAGE).hash(&[]);
+            assert_eq!(expected, candidate.eject_value());
+        });
+        Circuit::reset();
+    }
+
+    fn check_hash_exceeds_capacity<const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>() {
+        // Ensure the native BHP hash rejects the input as well.
+        let num_input_bits = NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE + 1;
+        let native = BHPCRH::<Projective, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        assert!(native.hash(&vec![true; num_input_bits]).is_err());
+
+        let circuit = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; num_input_bits]);
+        let _candidate = circuit.hash(&input);
+    }
+
+    #[test]
+    #[should_panic(expected = "Inputs to this BHP variant cannot exceed 258 bits")]
+    fn test_hash_exceeds_capacity() {
+        check_hash_exceeds_capacity::<2, 43>();
+    }
 }
@@ -27,11 +27,6 @@ impl<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> HashUnc
     /// This uncompressed variant of the BHP hash function is provided to support
     /// the BHP commitment scheme, as it is typically not used by applications.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Self::Output {
-        // Ensure the input size is at least the window size.
-        if input.len() <= WINDOW_SIZE * BHP_CHUNK_SIZE {
-            E::halt(format!("Inputs to this BHP variant must be greater than {} bits", WINDOW_SIZE * BHP_CHUNK_SIZE))
-        }
-
         // Ensure the input size is within the parameter size.
         let mut input = input.to_vec();
         match input.len() <= NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE {
@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Keccak256<E> {
+    /// Returns the Keccak-256 digest of the given input.
+    /// The input is a sequence of bytes, and the output is a sequence of 32 bytes,
+    /// where each byte is given in little-endian bits.
+    pub fn hash(&self, input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Initialize the state.
+        let mut state = vec![U64::zero(); NUM_LANES];
+
+        // Absorb each block of the padded input, where each lane is 8 little-endian bytes.
+        for block in Self::pad(input).chunks(RATE_IN_BITS) {
+            for (lane, bits) in state.iter_mut().zip(block.chunks(64)) {
+                *lane = &*lane ^ &U64::from_bits_le(bits);
+            }
+            self.permute(&mut state);
+        }
+
+        // Squeeze the digest from the first lanes.
+        state.iter().flat_map(|lane| lane.to_bits_le()).take(KECCAK256_DIGEST_SIZE_IN_BITS).collect()
+    }
+
+    /// Returns the Keccak-256 digest of the given input, packed into base field elements.
+    /// Each field element stores (up to) `E::BaseField::size_in_data_bits()` bits of the digest.
+    pub fn hash_to_fields(&self, input: &[Boolean<E>]) -> Vec<Field<E>> {
+        self.hash(input).chunks(E::BaseField::size_in_data_bits()).map(Field::from_bits_le).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::ToBits as _;
+
+    /// The known-answer vectors, including messages at the boundary of the rate, and over multiple blocks.
+    const VECTORS: [(&str, &str); 4] = [
+        ("", "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"),
+        ("abc", "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+        (
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "34367dc248bbd832f4e3e69dfaac2f92638bd0bbd18f2912ba4ef454919cf446",
+        ),
+        (
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "a6c4d403279fe3e0af03729caada8374b5ca54d8065329a3ebcaeb4b60aa386e",
+        ),
+    ];
+
+    /// Returns the bytes of the given hex string.
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn check_hash(
+        mode: Mode,
+        message: &str,
+        expected: &str,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let keccak256 = Keccak256::<Circuit>::new();
+        let input: Vec<Boolean<_>> = Inject::new(mode, message.as_bytes().to_bits_le());
+
+        Circuit::scope(format!("Keccak-256 {mode} {}", message.len()), || {
+            let candidate = keccak256.hash(&input);
+            assert_eq!(KECCAK256_DIGEST_SIZE_IN_BITS, candidate.len());
+            assert_eq!(from_hex(expected).to_bits_le(), candidate.eject_value());
+
+            let case = format!("mode = {mode}, length = {}", message.len());
+            assert_scope!(case, num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_hash_constant() {
+        // The constant case folds entirely into constants.
+        for (message, expected) in VECTORS {
+            check_hash(Mode::Constant, message, expected, 64, 0, 0, 0);
+        }
+    }
+
+    #[test]
+    fn test_hash_public() {
+        check_hash(Mode::Public, VECTORS[0].0, VECTORS[0].1, 64, 0, 0, 0);
+        check_hash(Mode::Public, VECTORS[1].0, VECTORS[1].1, 64, 0, 145053, 145053);
+    }
+
+    #[test]
+    fn test_hash_private() {
+        check_hash(Mode::Private, VECTORS[0].0, VECTORS[0].1, 64, 0, 0, 0);
+        check_hash(Mode::Private, VECTORS[1].0, VECTORS[1].1, 64, 0, 145053, 145053);
+        check_hash(Mode::Private, VECTORS[2].0, VECTORS[2].1, 64, 0, 152560, 152560);
+        check_hash(Mode::Private, VECTORS[3].0, VECTORS[3].1, 64, 0, 306176, 306176);
+    }
+
+    #[test]
+    fn test_hash_to_fields() {
+        let keccak256 = Keccak256::<Circuit>::new();
+        let (message, _) = VECTORS[1];
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, message.as_bytes().to_bits_le());
+
+        let digest = keccak256.hash(&input).eject_value();
+        let candidate = keccak256.hash_to_fields(&input);
+
+        // Ensure the field elements pack the digest bits.
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        assert_eq!((KECCAK256_DIGEST_SIZE_IN_BITS + size_in_data_bits - 1) / size_in_data_bits, candidate.len());
+        for (field, bits) in candidate.iter().zip_eq(digest.chunks(size_in_data_bits)) {
+            assert_eq!(&field.eject_value().to_bits_le()[..bits.len()], bits);
+        }
+        Circuit::reset();
+    }
+}
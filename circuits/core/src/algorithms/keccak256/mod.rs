@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+#[cfg(test)]
+use snarkvm_circuits_environment::assert_scope;
+
+use snarkvm_circuits_types::prelude::*;
+
+/// The number of bits absorbed into the state per permutation, for a 256-bit digest.
+const RATE_IN_BITS: usize = 1088;
+/// The number of bits in a Keccak-256 digest.
+pub const KECCAK256_DIGEST_SIZE_IN_BITS: usize = 256;
+/// The number of 64-bit lanes in the state.
+const NUM_LANES: usize = 25;
+
+/// The round constants, applied to the first lane in the iota step.
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808A, 0x8000000080008000, 0x000000000000808B, 0x0000000080000001,
+    0x8000000080008081, 0x8000000000008009, 0x000000000000008A, 0x0000000000000088, 0x0000000080008009, 0x000000008000000A,
+    0x000000008000808B, 0x800000000000008B, 0x8000000000008089, 0x8000000000008003, 0x8000000000008002, 0x8000000000000080,
+    0x000000000000800A, 0x800000008000000A, 0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+/// The rotation offsets of the rho step, indexed by `[x][y]`.
+const ROTATION_OFFSETS: [[usize; 5]; 5] =
+    [[0, 36, 3, 41, 18], [1, 44, 10, 45, 2], [62, 6, 43, 15, 61], [28, 55, 25, 21, 56], [27, 20, 39, 8, 14]];
+
+/// Keccak-256 is the hash function used by Ethereum, which is the Keccak sponge with a capacity
+/// of 512 bits and the original `pad10*1` padding (this differs from the padding of SHA3-256).
+/// The hash operates on 64-bit lanes, using the bitwise operations of `U64`.
+pub struct Keccak256<E: Environment> {
+    /// The round constants.
+    round_constants: Vec<U64<E>>,
+}
+
+impl<E: Environment> Keccak256<E> {
+    /// Initializes a new instance of Keccak-256.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self { round_constants: ROUND_CONSTANTS.iter().map(|lane| U64::constant(*lane)).collect() }
+    }
+
+    /// Returns the message padded to a multiple of the rate, with the `pad10*1` rule.
+    /// The message is appended with a `1` bit, followed by zeros, and a final `1` bit.
+    fn pad(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Ensure the input is a sequence of bytes.
+        if input.len() % 8 != 0 {
+            E::halt("Keccak-256 requires the input to be a sequence of bytes")
+        }
+
+        let mut padded = input.to_vec();
+        padded.push(Boolean::constant(true));
+        while padded.len() % RATE_IN_BITS != RATE_IN_BITS - 1 {
+            padded.push(Boolean::constant(false));
+        }
+        padded.push(Boolean::constant(true));
+        padded
+    }
+
+    /// Applies the Keccak-f[1600] permutation to the given state, where the lane `(x, y)` is at index `x + 5y`.
+    fn permute(&self, state: &mut [U64<E>]) {
+        for round_constant in &self.round_constants {
+            // Theta: XOR each lane with the parities of two neighboring columns.
+            let parities = (0..5)
+                .map(|x| &(&(&(&state[x] ^ &state[x + 5]) ^ &state[x + 10]) ^ &state[x + 15]) ^ &state[x + 20])
+                .collect::<Vec<_>>();
+            for x in 0..5 {
+                let d = &parities[(x + 4) % 5] ^ &rotate_left(&parities[(x + 1) % 5], 1);
+                for y in 0..5 {
+                    state[x + 5 * y] = &state[x + 5 * y] ^ &d;
+                }
+            }
+
+            // Rho and pi: Rotate each lane, and move it to a new position.
+            let mut permuted = state.to_vec();
+            for x in 0..5 {
+                for y in 0..5 {
+                    permuted[y + 5 * ((2 * x + 3 * y) % 5)] = rotate_left(&state[x + 5 * y], ROTATION_OFFSETS[x][y]);
+                }
+            }
+
+            // Chi: XOR each lane with the conjunction of the negated next lane and the lane after it.
+            for x in 0..5 {
+                for y in 0..5 {
+                    let next = !permuted[(x + 1) % 5 + 5 * y].clone() & &permuted[(x + 2) % 5 + 5 * y];
+                    state[x + 5 * y] = &permuted[x + 5 * y] ^ &next;
+                }
+            }
+
+            // Iota: XOR the first lane with the round constant.
+            state[0] = &state[0] ^ round_constant;
+        }
+    }
+}
+
+/// Returns the lane rotated left by `n` bits.
+/// The rotation is a rewiring of the bits, and does not introduce any constraints.
+fn rotate_left<E: Environment>(lane: &U64<E>, n: usize) -> U64<E> {
+    let bits_le = lane.to_bits_le();
+    U64::from_bits_le(&[&bits_le[64 - n..], &bits_le[..64 - n]].concat())
+}
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod to_root;
+mod verify;
 
 use crate::traits::Hash;
 use snarkvm_circuits_environment::prelude::*;
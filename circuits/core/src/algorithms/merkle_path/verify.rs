@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::traits::Hash;
+
+impl<E: Environment, TwoToOneCRH: Hash> MerklePath<E, TwoToOneCRH> {
+    /// Returns `true` if the merkle path proves the membership of `leaf` in the tree with the given `root`.
+    pub fn verify<LeafCRH: Hash<Output = TwoToOneCRH::Output>>(
+        &self,
+        leaf_crh: &LeafCRH,
+        two_to_one_crh: &TwoToOneCRH,
+        root: &TwoToOneCRH::Output,
+        leaf: &[LeafCRH::Input],
+    ) -> Boolean<E>
+    where
+        <<TwoToOneCRH as Hash>::Output as Ternary>::Boolean: From<Boolean<E>>,
+        Vec<<TwoToOneCRH as Hash>::Input>: From<Vec<<<TwoToOneCRH as Hash>::Output as ToBits>::Boolean>>,
+        <TwoToOneCRH as Hash>::Output: Equal<Output = Boolean<E>>,
+    {
+        self.to_root(leaf_crh, two_to_one_crh, leaf).is_equal(root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{Poseidon4, BHP512};
+
+    use snarkvm_algorithms::{
+        crh::{PoseidonCRH, BHPCRH},
+        merkle_tree::{MaskedMerkleTreeParameters, MerkleTree},
+        traits::MerkleParameters,
+        CRH,
+    };
+    use snarkvm_circuits_environment::{assert_scope, Circuit, Mode};
+    use snarkvm_circuits_types::Field;
+    use snarkvm_curves::{bls12_377::Fr, edwards_bls12::EdwardsProjective};
+    use snarkvm_fields::PrimeField;
+    use snarkvm_utilities::{test_rng, ToBits, UniformRand};
+
+    use std::sync::Arc;
+
+    const ITERATIONS: usize = 4;
+    const NUM_LEAVES: usize = 16;
+    const MESSAGE: &str = "Merkle path test";
+
+    /// The number of field elements in the input of the Poseidon CRH, which fits the 512 bits of two children.
+    const POSEIDON_INPUT_SIZE_FE: usize = 3;
+
+    type NativeBHP = BHPCRH<EdwardsProjective, 3, 57>;
+    type NativePoseidon = PoseidonCRH<Fr, POSEIDON_INPUT_SIZE_FE>;
+    type BHPParameters<const DEPTH: usize> = MaskedMerkleTreeParameters<NativeBHP, NativeBHP, DEPTH>;
+    type PoseidonParameters<const DEPTH: usize> = MaskedMerkleTreeParameters<NativePoseidon, NativePoseidon, DEPTH>;
+
+    /// The Poseidon hash of a sequence of bits, as computed by the native `PoseidonCRH`.
+    struct PoseidonCRHGadget(Poseidon4<Circuit>);
+
+    impl Hash for PoseidonCRHGadget {
+        type Input = Boolean<Circuit>;
+        type Output = Field<Circuit>;
+
+        fn hash(&self, input: &[Self::Input]) -> Self::Output {
+            // Pad the input to the input size, and pack it into field elements.
+            let num_data_bits = Fr::size_in_data_bits();
+            let mut input = input.to_vec();
+            input.resize(POSEIDON_INPUT_SIZE_FE * num_data_bits, Boolean::constant(false));
+            let input = input.chunks(num_data_bits).map(Field::from_bits_le).collect::<Vec<_>>();
+
+            self.0.hash(&input)
+        }
+    }
+
+    /// Returns a native merkle tree over random leaves, and a random leaf index.
+    fn sample_tree<P: MerkleParameters>() -> (MerkleTree<P>, Vec<Fr>, usize)
+    where
+        P::LeafCRH: CRH<Output = Fr>,
+    {
+        let mut rng = test_rng();
+        let leaves = (0..NUM_LEAVES).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let merkle_tree = MerkleTree::new(Arc::new(P::setup(MESSAGE)), &leaves).unwrap();
+        let index = u32::rand(&mut rng) as usize % NUM_LEAVES;
+        (merkle_tree, leaves, index)
+    }
+
+    /// Returns the given leaf as circuit bits, padded to its byte representation to match the native merkle tree.
+    fn leaf_bits(mode: Mode, leaf: &Fr) -> Vec<Boolean<Circuit>> {
+        let mut leaf_bits = leaf.to_bits_le();
+        leaf_bits.resize((leaf_bits.len() + 7) / 8 * 8, false);
+        Inject::new(mode, leaf_bits)
+    }
+
+    fn check_verify<P: MerkleParameters, H: Hash<Input = Boolean<Circuit>, Output = Field<Circuit>>>(
+        crh: &H,
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) where
+        P::LeafCRH: CRH<Output = Fr>,
+    {
+        let depth = P::DEPTH;
+
+        for i in 0..ITERATIONS {
+            // Sample a random leaf, and generate its merkle path natively.
+            let (merkle_tree, leaves, index) = sample_tree::<P>();
+            let root = merkle_tree.root();
+            let leaf = &leaves[index];
+            let proof = merkle_tree.generate_proof(index, leaf).unwrap();
+            assert!(proof.verify(root, leaf).unwrap());
+
+            let traversal = proof.position_list().collect::<Vec<_>>();
+            let merkle_path = MerklePath::<Circuit, H>::new(mode, (traversal, proof.path.clone()));
+            let circuit_root = Field::<Circuit>::new(mode, *root);
+            let circuit_leaf = leaf_bits(mode, leaf);
+
+            Circuit::scope(format!("{mode} {MESSAGE} {depth} {i}"), || {
+                let candidate = merkle_path.verify(crh, crh, &circuit_root, &circuit_leaf);
+                assert!(candidate.eject_value());
+
+                let case = format!("mode = {mode}, depth = {depth}");
+                assert_scope!(case, num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_verify_fails_on_corrupted_sibling<
+        P: MerkleParameters,
+        H: Hash<Input = Boolean<Circuit>, Output = Field<Circuit>>,
+    >(
+        crh: &H,
+        mode: Mode,
+    ) where
+        P::LeafCRH: CRH<Output = Fr>,
+    {
+        let depth = P::DEPTH;
+
+        // Sample a random leaf, and corrupt a random sibling in its merkle path.
+        let (merkle_tree, leaves, index) = sample_tree::<P>();
+        let root = merkle_tree.root();
+        let leaf = &leaves[index];
+        let mut proof = merkle_tree.generate_proof(index, leaf).unwrap();
+        let level = u32::rand(&mut test_rng()) as usize % depth;
+        proof.path[level] += Fr::from(1u64);
+        assert!(!proof.verify(root, leaf).unwrap());
+
+        let traversal = proof.position_list().collect::<Vec<_>>();
+        let merkle_path = MerklePath::<Circuit, H>::new(mode, (traversal, proof.path.clone()));
+        let circuit_root = Field::<Circuit>::new(mode, *root);
+        let circuit_leaf = leaf_bits(mode, leaf);
+
+        Circuit::scope(format!("{mode} {MESSAGE} {depth} corrupted"), || {
+            let candidate = merkle_path.verify(crh, crh, &circuit_root, &circuit_leaf);
+            assert!(!candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify_depth_4() {
+        let bhp = BHP512::<Circuit>::setup(MESSAGE);
+        check_verify::<BHPParameters<4>, _>(&bhp, Mode::Public, 66, 0, 5935, 5944);
+        check_verify::<BHPParameters<4>, _>(&bhp, Mode::Private, 66, 0, 5935, 5944);
+    }
+
+    #[test]
+    fn test_verify_depth_16() {
+        let bhp = BHP512::<Circuit>::setup(MESSAGE);
+        check_verify::<BHPParameters<16>, _>(&bhp, Mode::Public, 234, 0, 22423, 22456);
+        check_verify::<BHPParameters<16>, _>(&bhp, Mode::Private, 234, 0, 22423, 22456);
+    }
+
+    #[test]
+    fn test_verify_depth_32() {
+        let bhp = BHP512::<Circuit>::setup(MESSAGE);
+        check_verify::<BHPParameters<32>, _>(&bhp, Mode::Public, 458, 0, 44407, 44472);
+        check_verify::<BHPParameters<32>, _>(&bhp, Mode::Private, 458, 0, 44407, 44472);
+    }
+
+    #[test]
+    fn test_verify_poseidon_depth_4() {
+        let poseidon = PoseidonCRHGadget(Poseidon4::new());
+        check_verify::<PoseidonParameters<4>, _>(&poseidon, Mode::Public, 1, 0, 3754, 3763);
+        check_verify::<PoseidonParameters<4>, _>(&poseidon, Mode::Private, 1, 0, 3754, 3763);
+    }
+
+    #[test]
+    fn test_verify_poseidon_depth_16() {
+        let poseidon = PoseidonCRHGadget(Poseidon4::new());
+        check_verify::<PoseidonParameters<16>, _>(&poseidon, Mode::Public, 1, 0, 13990, 14023);
+        check_verify::<PoseidonParameters<16>, _>(&poseidon, Mode::Private, 1, 0, 13990, 14023);
+    }
+
+    #[test]
+    fn test_verify_poseidon_depth_32() {
+        let poseidon = PoseidonCRHGadget(Poseidon4::new());
+        check_verify::<PoseidonParameters<32>, _>(&poseidon, Mode::Public, 1, 0, 27638, 27703);
+        check_verify::<PoseidonParameters<32>, _>(&poseidon, Mode::Private, 1, 0, 27638, 27703);
+    }
+
+    #[test]
+    fn test_verify_fails_on_corrupted_sibling() {
+        let bhp = BHP512::<Circuit>::setup(MESSAGE);
+        let poseidon = PoseidonCRHGadget(Poseidon4::new());
+        for mode in [Mode::Public, Mode::Private] {
+            check_verify_fails_on_corrupted_sibling::<BHPParameters<4>, _>(&bhp, mode);
+            check_verify_fails_on_corrupted_sibling::<BHPParameters<16>, _>(&bhp, mode);
+            check_verify_fails_on_corrupted_sibling::<BHPParameters<32>, _>(&bhp, mode);
+            check_verify_fails_on_corrupted_sibling::<PoseidonParameters<4>, _>(&poseidon, mode);
+            check_verify_fails_on_corrupted_sibling::<PoseidonParameters<16>, _>(&poseidon, mode);
+            check_verify_fails_on_corrupted_sibling::<PoseidonParameters<32>, _>(&poseidon, mode);
+        }
+    }
+}
@@ -17,6 +17,9 @@
 pub mod bhp;
 pub use bhp::*;
 
+pub mod keccak256;
+pub use keccak256::*;
+
 pub mod merkle_path;
 pub use merkle_path::*;
 
@@ -25,3 +28,6 @@ pub use pedersen::*;
 
 pub mod poseidon;
 pub use poseidon::*;
+
+pub mod sha256;
+pub use sha256::*;
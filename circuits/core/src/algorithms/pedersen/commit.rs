@@ -206,6 +206,88 @@ mod tests {
         check_commitment::<5, WINDOW_SIZE_MULTIPLIER>(Mode::Private);
     }
 
+    fn check_commitment_variant<const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>(
+        circuit: &Pedersen<Circuit, NUM_WINDOWS, WINDOW_SIZE>,
+        mode: Mode,
+        num_input_bits: usize,
+    ) {
+        // Initialize the native Pedersen commitment.
+        let native = NativePedersenCommitment::<Projective, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+
+        for i in 0..ITERATIONS {
+            // Sample a random input and randomizer.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            let randomness = ScalarField::rand(&mut test_rng());
+            // Compute the expected commitment.
+            let expected = native.commit(&input, &randomness).expect("Failed to commit to native input");
+            // Prepare the circuit input and randomness.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let circuit_randomness: Scalar<_> = Inject::new(mode, randomness);
+
+            Circuit::scope(format!("Pedersen {mode} {num_input_bits} {i}"), || {
+                // Perform the commit operation.
+                let candidate = circuit.commit(&circuit_input, &circuit_randomness);
+                assert_eq!(expected.to_x_coordinate(), candidate.eject_value());
+
+                // Check constraint counts and output mode on full-size inputs.
+                // Note: Padding bits select the zero element, so the additions they feed into are skipped.
+                if num_input_bits == NUM_WINDOWS * WINDOW_SIZE {
+                    let input_modes = circuit_input.iter().map(|b| b.eject_mode()).collect::<Vec<_>>();
+                    let randomness_modes =
+                        circuit_randomness.to_bits_le().iter().map(|b| b.eject_mode()).collect::<Vec<_>>();
+                    assert_count!(
+                        Pedersen<Circuit, NUM_WINDOWS, WINDOW_SIZE>,
+                        Commit<Input = Boolean<Circuit>, Output = Field<Circuit>, Randomness = Scalar<Circuit>>,
+                        &(input_modes.clone(), randomness_modes.clone())
+                    );
+                    assert_output_mode!(
+                        Pedersen<Circuit, NUM_WINDOWS, WINDOW_SIZE>,
+                        Commit<Input = Boolean<Circuit>, Output = Field<Circuit>, Randomness = Scalar<Circuit>>,
+                        &(input_modes, randomness_modes),
+                        candidate
+                    );
+                }
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_commitment_pedersen64() {
+        let circuit = Pedersen64::<Circuit>::setup(MESSAGE);
+        // Check the empty input, a partial input, and the full input.
+        for num_input_bits in [0, 1, 32, 64] {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_commitment_variant(&circuit, mode, num_input_bits);
+            }
+        }
+    }
+
+    #[test]
+    fn test_commitment_pedersen128() {
+        let circuit = Pedersen128::<Circuit>::setup(MESSAGE);
+        // Check the empty input, a partial input, and the full input.
+        for num_input_bits in [0, 1, 64, 128] {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_commitment_variant(&circuit, mode, num_input_bits);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The Pedersen hash input cannot exceed 64 bits")]
+    fn test_commitment_exceeds_capacity() {
+        // Ensure the native Pedersen commitment rejects the input as well.
+        let native = NativePedersenCommitment::<Projective, 1, 64>::setup(MESSAGE);
+        assert!(native.commit(&[true; 65], &ScalarField::rand(&mut test_rng())).is_err());
+
+        let circuit = Pedersen64::<Circuit>::setup(MESSAGE);
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; 65]);
+        let randomness = Scalar::<Circuit>::new(Mode::Private, ScalarField::rand(&mut test_rng()));
+        let _candidate = circuit.commit(&input, &randomness);
+    }
+
     fn check_homomorphic_addition<
         C: Display + Eject + Add<Output = C> + ToBits<Boolean = Boolean<Circuit>>,
         P: Commit<Input = Boolean<Circuit>, Randomness = Scalar<Circuit>, Output = Field<Circuit>>
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm_algorithms::crh::PEDERSEN_LENGTH_BITS;
+
+impl<E: Environment, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> Pedersen<E, NUM_WINDOWS, WINDOW_SIZE> {
+    ///
+    /// Returns the Pedersen hash of the given variable-length input as an affine group element.
+    ///
+    /// The input is followed by its length as a 64-bit little-endian block, and zero-filled to the
+    /// window boundary, matching `PedersenCRH::hash_with_len_padding`. As the length of the input
+    /// is known when synthesizing the circuit, the length block is constant.
+    ///
+    pub fn hash_uncompressed_with_len_padding(&self, input: &[Boolean<E>]) -> Group<E> {
+        // Ensure the padded input size is within the parameter size.
+        if input.len() + PEDERSEN_LENGTH_BITS > WINDOW_SIZE * NUM_WINDOWS {
+            E::halt(format!(
+                "The Pedersen hash input cannot exceed {} bits.",
+                WINDOW_SIZE * NUM_WINDOWS - PEDERSEN_LENGTH_BITS
+            ))
+        }
+
+        // Append the length of the input, and hash the padded input.
+        let length = (0..PEDERSEN_LENGTH_BITS).map(|i| Boolean::constant((input.len() as u64 >> i) & 1 == 1));
+        self.hash_uncompressed(&input.iter().cloned().chain(length).collect::<Vec<_>>())
+    }
+
+    ///
+    /// Returns the Pedersen hash of the given variable-length input as a field element.
+    /// See `hash_uncompressed_with_len_padding` for the padding of the input.
+    ///
+    pub fn hash_with_len_padding(&self, input: &[Boolean<E>]) -> Field<E> {
+        self.hash_uncompressed_with_len_padding(input).to_x_coordinate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::{
+        crh::{PedersenCRH, PedersenCompressedCRH},
+        CRH,
+    };
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_curves::AffineCurve;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const MESSAGE: &str = "PedersenCircuit0";
+    const NUM_WINDOWS: usize = 2;
+    const WINDOW_SIZE: usize = 64;
+    const CAPACITY: usize = NUM_WINDOWS * WINDOW_SIZE;
+
+    type Projective = <<Circuit as Environment>::Affine as AffineCurve>::Projective;
+
+    fn check_hash_with_len_padding(mode: Mode) {
+        // Initialize the Pedersen hash.
+        let native = PedersenCRH::<Projective, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        let native_compressed = PedersenCompressedCRH::<Projective, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+
+        for num_bits in [0, 1, CAPACITY - 65, CAPACITY - 64] {
+            // Sample a random input.
+            let input = (0..num_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            // Compute the expected hashes.
+            let expected = native.hash_with_len_padding(&input).expect("Failed to hash native input");
+            let expected_compressed =
+                native_compressed.hash_with_len_padding(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Pedersen {mode} {num_bits}"), || {
+                // Perform the hash operations.
+                let candidate = circuit.hash_uncompressed_with_len_padding(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+                let candidate = circuit.hash_with_len_padding(&circuit_input);
+                assert_eq!(expected_compressed, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_with_len_padding_constant() {
+        check_hash_with_len_padding(Mode::Constant);
+    }
+
+    #[test]
+    fn test_hash_with_len_padding_public() {
+        check_hash_with_len_padding(Mode::Public);
+    }
+
+    #[test]
+    fn test_hash_with_len_padding_private() {
+        check_hash_with_len_padding(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hash_with_len_padding_exceeds_capacity() {
+        let circuit = Pedersen::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE);
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; CAPACITY - 63]);
+        circuit.hash_with_len_padding(&input);
+    }
+}
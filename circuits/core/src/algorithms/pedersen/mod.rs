@@ -19,6 +19,7 @@ pub use commit::*;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
+mod hash_with_len_padding;
 
 #[cfg(test)]
 use snarkvm_circuits_environment::{assert_count, assert_output_mode, assert_scope};
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    ///
+    /// Asserts that `a` and `b` are equal element-wise, using a single random linear combination.
+    ///
+    /// The challenge is derived in-circuit from the hash of `a` and `b`, so that it can not be chosen
+    /// after `a` and `b`. Modeling Poseidon as a random oracle, each attempt to satisfy the assertion
+    /// with `a != b` succeeds with probability at most `(n - 1) / |F|`, for vectors of length `n`.
+    ///
+    pub fn assert_eq_batched(&self, a: &[Field<E>], b: &[Field<E>]) {
+        // Derive the challenge from both vectors.
+        let challenge = self.hash(&[a, b].concat());
+
+        Field::assert_eq_batched(a, b, &challenge);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 10;
+    const RATE: usize = 4;
+
+    fn inject_vectors(
+        values: &[<Circuit as Environment>::BaseField],
+        mode_a: Mode,
+        mode_b: Mode,
+    ) -> (Vec<Field<Circuit>>, Vec<Field<Circuit>>) {
+        let a = values.iter().map(|value| Field::new(mode_a, *value)).collect();
+        let b = values.iter().map(|value| Field::new(mode_b, *value)).collect();
+        (a, b)
+    }
+
+    #[test]
+    fn test_assert_eq_batched() {
+        let rng = &mut test_rng();
+        let poseidon = Poseidon::<Circuit, RATE>::new();
+
+        for length in [0, 1, 16] {
+            let values = (0..length).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+            let (a, b) = inject_vectors(&values, Mode::Public, Mode::Private);
+
+            Circuit::scope(format!("Length {length}"), || {
+                poseidon.assert_eq_batched(&a, &b);
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_assert_eq_batched_mismatch() {
+        let rng = &mut test_rng();
+        let poseidon = Poseidon::<Circuit, RATE>::new();
+
+        for i in 0..ITERATIONS {
+            let values = (0..ITERATIONS).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+            let (a, mut b) = inject_vectors(&values, Mode::Private, Mode::Private);
+            // Alter a single element.
+            b[i] = Field::new(Mode::Private, UniformRand::rand(rng));
+
+            Circuit::scope(format!("Mismatch {i}"), || {
+                poseidon.assert_eq_batched(&a, &b);
+                assert!(!Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_assert_eq_element_wise() {
+        let rng = &mut test_rng();
+
+        // Compare against asserting equality element-wise.
+        for length in [1, 16] {
+            let values = (0..length).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+            let (a, b) = inject_vectors(&values, Mode::Public, Mode::Private);
+
+            Circuit::scope(format!("Element-wise {length}"), || {
+                a.iter().zip(&b).for_each(|(a_i, b_i)| Circuit::assert_eq(a_i, b_i));
+                assert_scope!(0, 0, 0, length as u64);
+            });
+            Circuit::reset();
+        }
+    }
+}
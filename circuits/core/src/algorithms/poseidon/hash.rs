@@ -34,20 +34,22 @@ impl<E: Environment, const RATE: usize> Hash for Poseidon<E, RATE> {
 }
 
 impl<E: Environment, const RATE: usize> Metrics<dyn Hash<Input = Field<E>, Output = Field<E>>> for Poseidon<E, RATE> {
-    type Case = ();
+    type Case = Vec<Mode>;
 
-    fn count(_parameter: &Self::Case) -> Count {
-        todo!()
+    #[inline]
+    fn count(case: &Self::Case) -> Count {
+        count!(Poseidon<E, RATE>, HashMany<Input = Field<E>, Output = Field<E>>, &(case.clone(), 1))
     }
 }
 
 impl<E: Environment, const RATE: usize> OutputMode<dyn Hash<Input = Field<E>, Output = Field<E>>>
     for Poseidon<E, RATE>
 {
-    type Case = ();
+    type Case = Vec<Mode>;
 
-    fn output_mode(_parameter: &Self::Case) -> Mode {
-        todo!()
+    #[inline]
+    fn output_mode(case: &Self::Case) -> Mode {
+        output_mode!(Poseidon<E, RATE>, HashMany<Input = Field<E>, Output = Field<E>>, &(case.clone(), 1))
     }
 }
 
@@ -289,4 +291,61 @@ mod tests {
         check_hash(Mode::Private, 9, 0, 0, 1060, 1060);
         check_hash(Mode::Private, 10, 0, 0, 1060, 1060);
     }
+
+    fn check_hash_metrics<const RATE: usize>(modes: &[Mode]) {
+        let rng = &mut test_rng();
+        let native_poseidon = NativePoseidon::<_, RATE, OPTIMIZED_FOR_WEIGHTS>::setup();
+        let poseidon = Poseidon::<_, RATE>::new();
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input =
+                (0..modes.len()).map(|_| <Circuit as Environment>::BaseField::rand(rng)).collect::<Vec<_>>();
+            let input =
+                native_input.iter().zip_eq(modes).map(|(v, mode)| Field::<Circuit>::new(*mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native_poseidon.evaluate(&native_input);
+            // Compute the circuit hash.
+            Circuit::scope(format!("Poseidon{RATE} {modes:?} {i}"), || {
+                let candidate = poseidon.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(Poseidon<Circuit, RATE>, Hash<Input = Field<Circuit>, Output = Field<Circuit>>, &modes.to_vec());
+                assert_output_mode!(
+                    Poseidon<Circuit, RATE>,
+                    Hash<Input = Field<Circuit>, Output = Field<Circuit>>,
+                    &modes.to_vec(),
+                    candidate
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn run_hash_metrics<const RATE: usize>() {
+        for num_inputs in 0..=(2 * RATE + 1) {
+            // Check each mode individually.
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_hash_metrics::<RATE>(&vec![mode; num_inputs]);
+            }
+            // Check a mix of modes.
+            let modes = [Mode::Constant, Mode::Public, Mode::Private];
+            check_hash_metrics::<RATE>(&(0..num_inputs).map(|i| modes[i % 3]).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_hash_rate_2() {
+        run_hash_metrics::<2>();
+    }
+
+    #[test]
+    fn test_hash_rate_4() {
+        run_hash_metrics::<4>();
+    }
+
+    #[test]
+    fn test_hash_rate_8() {
+        run_hash_metrics::<8>();
+    }
 }
@@ -35,21 +35,84 @@ impl<E: Environment, const RATE: usize> HashMany for Poseidon<E, RATE> {
 impl<E: Environment, const RATE: usize> Metrics<dyn HashMany<Input = Field<E>, Output = Field<E>>>
     for Poseidon<E, RATE>
 {
-    type Case = ();
+    type Case = (Vec<Mode>, usize);
 
-    fn count(_parameter: &Self::Case) -> Count {
-        todo!()
+    fn count(case: &Self::Case) -> Count {
+        let (modes, num_outputs) = case;
+
+        // Retrieve the round parameters for the given rate.
+        let parameters = match E::BaseField::default_poseidon_parameters::<RATE>(OPTIMIZED_FOR_WEIGHTS) {
+            Some(parameters) => parameters,
+            None => E::halt("Failed to initialize the Poseidon hash function"),
+        };
+
+        // Calculate the cost of an S-box (x^alpha) on a variable as follows:
+        //   `num_squares` := number of remaining bits after the first nonzero bit (from MSB -> LSB)
+        //   `num_multiplications` := number of `true` bits after the first nonzero bit (from MSB -> LSB)
+        let num_squares = (u64::BITS - parameters.alpha.leading_zeros()).saturating_sub(1) as u64;
+        let num_multiplications = parameters.alpha.count_ones().saturating_sub(1) as u64;
+        let s_box_count = num_squares * count!(Field<E>, Square<Output = Field<E>>, &Mode::Private)
+            + num_multiplications * count!(Field<E>, Mul<Field<E>, Output = Field<E>>, &(Mode::Private, Mode::Private));
+
+        // Track whether each state element is a constant, as only variables incur a cost in the S-boxes.
+        let mut is_constant = vec![true; RATE + CAPACITY];
+        let mut total_count = Count::zero();
+
+        // Absorb the input, permuting the state between each chunk of `RATE` elements.
+        for (i, chunk) in modes.chunks(RATE).enumerate() {
+            if i != 0 {
+                total_count = total_count
+                    + permute_count(&mut is_constant, parameters.full_rounds, parameters.partial_rounds, s_box_count);
+            }
+            for (j, mode) in chunk.iter().enumerate() {
+                is_constant[CAPACITY + j] &= mode.is_constant();
+            }
+        }
+
+        // Squeeze the output, permuting the state once for each chunk of `RATE` elements.
+        for _ in 0..(num_outputs + RATE - 1) / RATE {
+            total_count = total_count
+                + permute_count(&mut is_constant, parameters.full_rounds, parameters.partial_rounds, s_box_count);
+        }
+
+        total_count
     }
 }
 
 impl<E: Environment, const RATE: usize> OutputMode<dyn HashMany<Input = Field<E>, Output = Field<E>>>
     for Poseidon<E, RATE>
 {
-    type Case = ();
+    type Case = (Vec<Mode>, usize);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.0.iter().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+/// Returns the cost of a single permutation, and updates whether each state element is a constant in-place.
+fn permute_count(is_constant: &mut [bool], full_rounds: usize, partial_rounds: usize, s_box_count: Count) -> Count {
+    // Determine the partial rounds range bound.
+    let full_rounds_over_2 = full_rounds / 2;
+    let partial_round_range = full_rounds_over_2..(full_rounds_over_2 + partial_rounds);
 
-    fn output_mode(_parameter: &Self::Case) -> Mode {
-        todo!()
+    let mut total_count = Count::zero();
+    for i in 0..(partial_rounds + full_rounds) {
+        // Full rounds apply the S-box to every element of state, while partial rounds apply it to just the first.
+        let num_variables = match partial_round_range.contains(&i) {
+            true => !is_constant[0] as u64,
+            false => is_constant.iter().filter(|is_constant| !**is_constant).count() as u64,
+        };
+        total_count = total_count + num_variables * s_box_count;
+
+        // The MDS matrix mixes every element of state, so a single variable makes every element a variable.
+        if is_constant.iter().any(|is_constant| !is_constant) {
+            is_constant.iter_mut().for_each(|is_constant| *is_constant = false);
+        }
     }
+    total_count
 }
 
 #[cfg(test)]
@@ -149,4 +212,69 @@ mod tests {
             check_hash_many(Mode::Private, 6, num_outputs, 0, 0, 1060, 1060);
         }
     }
+
+    fn check_hash_many_metrics<const RATE: usize>(modes: &[Mode], num_outputs: usize) {
+        let rng = &mut test_rng();
+        let native_poseidon = NativePoseidon::<_, RATE, OPTIMIZED_FOR_WEIGHTS>::setup();
+        let poseidon = Poseidon::<_, RATE>::new();
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input =
+                (0..modes.len()).map(|_| <Circuit as Environment>::BaseField::rand(rng)).collect::<Vec<_>>();
+            let input =
+                native_input.iter().zip_eq(modes).map(|(v, mode)| Field::<Circuit>::new(*mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native_poseidon.evaluate_many(&native_input, num_outputs);
+            // Compute the circuit hash.
+            Circuit::scope(format!("Poseidon{RATE} {modes:?} {i} {num_outputs}"), || {
+                let candidate = poseidon.hash_many(&input, num_outputs);
+                for (expected_element, candidate_element) in expected.iter().zip_eq(&candidate) {
+                    assert_eq!(*expected_element, candidate_element.eject_value());
+                }
+                let case = (modes.to_vec(), num_outputs);
+                assert_count!(Poseidon<Circuit, RATE>, HashMany<Input = Field<Circuit>, Output = Field<Circuit>>, &case);
+                for candidate_element in candidate {
+                    assert_output_mode!(
+                        Poseidon<Circuit, RATE>,
+                        HashMany<Input = Field<Circuit>, Output = Field<Circuit>>,
+                        &case,
+                        candidate_element
+                    );
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn run_hash_many_metrics<const RATE: usize>() {
+        for num_inputs in 0..=(2 * RATE + 1) {
+            for num_outputs in [0, 1, RATE, RATE + 1, 2 * RATE + 1] {
+                // Check each mode individually.
+                for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                    check_hash_many_metrics::<RATE>(&vec![mode; num_inputs], num_outputs);
+                }
+                // Check a mix of modes.
+                let modes = [Mode::Constant, Mode::Public, Mode::Private];
+                let modes = (0..num_inputs).map(|i| modes[i % 3]).collect::<Vec<_>>();
+                check_hash_many_metrics::<RATE>(&modes, num_outputs);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hash_many_rate_2() {
+        run_hash_many_metrics::<2>();
+    }
+
+    #[test]
+    fn test_hash_many_rate_4() {
+        run_hash_many_metrics::<4>();
+    }
+
+    #[test]
+    fn test_hash_many_rate_8() {
+        run_hash_many_metrics::<8>();
+    }
 }
@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+pub use snarkvm_algorithms::crypto_hash::MAX_HASH_TO_GROUP_ATTEMPTS;
+use snarkvm_curves::TwistedEdwardsParameters;
+use snarkvm_utilities::BitIteratorBE;
+
+impl<E: Environment, const RATE: usize> HashToGroup for Poseidon<E, RATE> {
+    type Input = Field<E>;
+    type Output = Group<E>;
+
+    ///
+    /// Returns a group element from hashing the input, matching the native `Poseidon::hash_to_group`.
+    ///
+    /// The input is hashed to a digest, and for each attempt `i`, the hash of `(digest, i)` is used
+    /// as a candidate x-coordinate. The output is the first candidate that is on the curve, and is
+    /// non-zero once the cofactor is cleared. Every attempt is evaluated, so that the circuit is fixed.
+    ///
+    /// As in `hash_to_curve`, the point of the selected attempt uses the greatest y-coordinate,
+    /// which is enforced once, after the selection.
+    ///
+    #[inline]
+    fn hash_to_group(&self, input: &[Self::Input]) -> Self::Output {
+        // Hash the input to the base field.
+        let digest = self.hash(input);
+
+        let mut output = Group::zero();
+        let mut y_coordinate = Field::zero();
+        let mut is_found = Boolean::constant(false);
+
+        for attempt in 0..MAX_HASH_TO_GROUP_ATTEMPTS {
+            // Hash the digest and the attempt to a candidate x-coordinate.
+            let x = self.hash(&[digest.clone(), Field::constant(E::BaseField::from(attempt as u128))]);
+
+            // Recover the point for the x-coordinate, and clear its cofactor.
+            let (point, is_on_curve) = Group::from_x_coordinate_flagged(x);
+            let candidate = mul_by_cofactor(&point);
+
+            // Select the candidate if it is valid, and no prior candidate was valid.
+            let is_valid = is_on_curve & !candidate.is_equal(&Group::zero());
+            let is_selected = &is_valid & !&is_found;
+            output = Group::ternary(&is_selected, &candidate, &output);
+            y_coordinate = Field::ternary(&is_selected, &point.to_y_coordinate(), &y_coordinate);
+            is_found |= is_valid;
+        }
+
+        // Ensure a valid candidate was found.
+        E::assert(is_found);
+        // Ensure the selected point has the greatest y-coordinate, as the output is negated otherwise.
+        E::assert(y_coordinate.is_greater_than(&(-&y_coordinate)));
+
+        output
+    }
+}
+
+/// Returns the given group element multiplied by the (constant) cofactor of the curve.
+fn mul_by_cofactor<E: Environment>(point: &Group<E>) -> Group<E> {
+    BitIteratorBE::new_without_leading_zeros(E::AffineParameters::COFACTOR).fold(Group::zero(), |output, bit| {
+        let output = output.double();
+        match bit {
+            true => output + point,
+            false => output,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::crypto_hash::Poseidon as NativePoseidon;
+    use snarkvm_circuits_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 3;
+    const RATE: usize = 4;
+
+    type NativeField = <Circuit as Environment>::BaseField;
+    type NativeGroup = <Circuit as Environment>::Affine;
+
+    fn check_hash_to_group(
+        mode: Mode,
+        num_inputs: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let rng = &mut test_rng();
+        let native_poseidon = NativePoseidon::<_, RATE, OPTIMIZED_FOR_WEIGHTS>::setup();
+        let poseidon = Poseidon::<_, RATE>::new();
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| NativeField::rand(rng)).collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash to group.
+            let (expected, _) = native_poseidon.hash_to_group::<NativeGroup>(&native_input);
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("Poseidon {mode} {i}"), || {
+                let candidate = poseidon.hash_to_group(&input);
+                assert_eq!(expected, candidate.eject_value());
+                match mode.is_constant() {
+                    // The number of constants depends on the attempt that is selected.
+                    true => assert_scope!(<=num_constants, <=num_public, <=num_private, <=num_constraints),
+                    false => {
+                        let case = format!("(mode = {mode}, num_inputs = {num_inputs})");
+                        assert_scope!(case, num_constants, num_public, num_private, num_constraints)
+                    }
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_to_group_constant() {
+        check_hash_to_group(Mode::Constant, 1, 2100, 0, 0, 0);
+        check_hash_to_group(Mode::Constant, 4, 2100, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_hash_to_group_public() {
+        check_hash_to_group(Mode::Public, 1, 768, 0, 48563, 48823);
+        check_hash_to_group(Mode::Public, 4, 768, 0, 48578, 48838);
+    }
+
+    #[test]
+    fn test_hash_to_group_private() {
+        check_hash_to_group(Mode::Private, 1, 768, 0, 48563, 48823);
+        check_hash_to_group(Mode::Private, 4, 768, 0, 48578, 48838);
+    }
+
+    #[test]
+    fn test_hash_to_group_multiple_attempts() {
+        let rng = &mut test_rng();
+        let native_poseidon = NativePoseidon::<_, RATE, OPTIMIZED_FOR_WEIGHTS>::setup();
+        let poseidon = Poseidon::<Circuit, RATE>::new();
+
+        // Find an input for which the first attempt fails.
+        let (native_input, expected) = loop {
+            let native_input = vec![NativeField::rand(rng)];
+            let (expected, attempt) = native_poseidon.hash_to_group::<NativeGroup>(&native_input);
+            if attempt > 0 {
+                break (native_input, expected);
+            }
+        };
+
+        let input = native_input.iter().map(|v| Field::<Circuit>::new(Mode::Private, *v)).collect::<Vec<_>>();
+        Circuit::scope("Poseidon multiple attempts", || {
+            let candidate = poseidon.hash_to_group(&input);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+    }
+}
@@ -33,23 +33,28 @@ impl<E: Environment, const RATE: usize> HashToScalar for Poseidon<E, RATE> {
     }
 }
 
-impl<E: Environment, const RATE: usize> Metrics<dyn HashToScalar<Input = Field<E>, Scalar = Field<E>>>
+impl<E: Environment, const RATE: usize> Metrics<dyn HashToScalar<Input = Field<E>, Scalar = Scalar<E>>>
     for Poseidon<E, RATE>
 {
-    type Case = ();
+    type Case = Vec<Mode>;
 
-    fn count(_parameter: &Self::Case) -> Count {
-        todo!()
+    #[inline]
+    fn count(case: &Self::Case) -> Count {
+        // Hash the input to the base field, and decompose the output into bits.
+        let output_mode = output_mode!(Poseidon<E, RATE>, Hash<Input = Field<E>, Output = Field<E>>, case);
+        count!(Poseidon<E, RATE>, Hash<Input = Field<E>, Output = Field<E>>, case)
+            + count!(Field<E>, ToBits<Boolean = Boolean<E>>, &output_mode)
     }
 }
 
-impl<E: Environment, const RATE: usize> OutputMode<dyn HashToScalar<Input = Field<E>, Scalar = Field<E>>>
+impl<E: Environment, const RATE: usize> OutputMode<dyn HashToScalar<Input = Field<E>, Scalar = Scalar<E>>>
     for Poseidon<E, RATE>
 {
-    type Case = ();
+    type Case = Vec<Mode>;
 
-    fn output_mode(_case: &Self::Case) -> Mode {
-        todo!()
+    #[inline]
+    fn output_mode(case: &Self::Case) -> Mode {
+        output_mode!(Poseidon<E, RATE>, Hash<Input = Field<E>, Output = Field<E>>, case)
     }
 }
 
@@ -145,4 +150,66 @@ mod tests {
         check_hash_to_scalar(Mode::Private, 9, 0, 0, 1313, 1314);
         check_hash_to_scalar(Mode::Private, 10, 0, 0, 1313, 1314);
     }
+
+    fn check_hash_to_scalar_metrics<const RATE: usize>(modes: &[Mode]) {
+        let rng = &mut test_rng();
+        let native_poseidon = NativePoseidon::<_, RATE, OPTIMIZED_FOR_WEIGHTS>::setup();
+        let poseidon = Poseidon::<_, RATE>::new();
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input =
+                (0..modes.len()).map(|_| <Circuit as Environment>::BaseField::rand(rng)).collect::<Vec<_>>();
+            let input =
+                native_input.iter().zip_eq(modes).map(|(v, mode)| Field::<Circuit>::new(*mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash to scalar.
+            let expected = {
+                // Truncate the output to CAPACITY bits (1 bit less than MODULUS_BITS) in the scalar field.
+                let mut bits = native_poseidon.evaluate(&native_input).to_bits_le();
+                bits.resize(<Circuit as Environment>::ScalarField::size_in_data_bits(), false);
+                let biginteger = <<Circuit as Environment>::ScalarField as PrimeField>::BigInteger::from_bits_le(&bits);
+                <<Circuit as Environment>::ScalarField as PrimeField>::from_repr(biginteger).unwrap()
+            };
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("Poseidon{RATE} {modes:?} {i}"), || {
+                let candidate = poseidon.hash_to_scalar(&input);
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(
+                    Poseidon<Circuit, RATE>,
+                    HashToScalar<Input = Field<Circuit>, Scalar = Scalar<Circuit>>,
+                    &modes.to_vec()
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn run_hash_to_scalar_metrics<const RATE: usize>() {
+        for num_inputs in 0..=(2 * RATE + 1) {
+            // Check each mode individually.
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_hash_to_scalar_metrics::<RATE>(&vec![mode; num_inputs]);
+            }
+            // Check a mix of modes.
+            let modes = [Mode::Constant, Mode::Public, Mode::Private];
+            check_hash_to_scalar_metrics::<RATE>(&(0..num_inputs).map(|i| modes[i % 3]).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_hash_to_scalar_rate_2() {
+        run_hash_to_scalar_metrics::<2>();
+    }
+
+    #[test]
+    fn test_hash_to_scalar_rate_4() {
+        run_hash_to_scalar_metrics::<4>();
+    }
+
+    #[test]
+    fn test_hash_to_scalar_rate_8() {
+        run_hash_to_scalar_metrics::<8>();
+    }
 }
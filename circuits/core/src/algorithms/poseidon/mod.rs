@@ -14,18 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod assert_eq_batched;
 pub mod ecies;
 pub mod hash;
 pub mod hash_many;
+pub mod hash_to_group;
 pub mod hash_to_scalar;
 pub mod prf;
 
 #[cfg(test)]
-use snarkvm_circuits_types::environment::assert_scope;
+use snarkvm_circuits_types::environment::{assert_count, assert_output_mode, assert_scope};
 
-use crate::{Hash, HashMany, HashToScalar, PRF};
+use crate::{Hash, HashMany, HashToGroup, HashToScalar, PRF};
 use snarkvm_algorithms::DuplexSpongeMode;
-use snarkvm_circuits_types::{environment::prelude::*, Field, Scalar};
+use snarkvm_circuits_types::{environment::prelude::*, Boolean, Field, Group, Scalar};
 use snarkvm_fields::PoseidonDefaultField;
 
 const OPTIMIZED_FOR_WEIGHTS: bool = false;
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Sha256<E> {
+    /// Returns the SHA-256 digest of the given input.
+    /// The input is a sequence of bytes, and the output is a sequence of 32 bytes,
+    /// where each byte is given in little-endian bits.
+    pub fn hash(&self, input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Initialize the state.
+        let mut state = self.initial_state.clone();
+
+        // Compress each block of the padded input.
+        for block in Self::pad(input).chunks(BLOCK_SIZE_IN_BITS) {
+            self.compress(&mut state, block);
+        }
+
+        // Output the state words as big-endian bytes.
+        state.iter().flat_map(word_to_bits_be_bytes).collect()
+    }
+
+    /// Returns the SHA-256 digest of the given input, packed into base field elements.
+    /// Each field element stores (up to) `E::BaseField::size_in_data_bits()` bits of the digest.
+    pub fn hash_to_fields(&self, input: &[Boolean<E>]) -> Vec<Field<E>> {
+        self.hash(input).chunks(E::BaseField::size_in_data_bits()).map(Field::from_bits_le).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::ToBits as _;
+
+    /// The known-answer vectors from FIPS 180-4, and a message at the boundary of the padding.
+    const VECTORS: [(&str, &str); 5] = [
+        ("", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+        ("abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+        (
+            "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        ),
+        (
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318",
+        ),
+        (
+            "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+            "cf5b16a778af8380036ce59e7b0492370b249b11e8f07a51afac45037afee9d1",
+        ),
+    ];
+
+    /// Returns the bytes of the given hex string.
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn check_hash(
+        mode: Mode,
+        message: &str,
+        expected: &str,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let sha256 = Sha256::<Circuit>::new();
+        let input: Vec<Boolean<_>> = Inject::new(mode, message.as_bytes().to_bits_le());
+
+        Circuit::scope(format!("SHA-256 {mode} {}", message.len()), || {
+            let candidate = sha256.hash(&input);
+            assert_eq!(SHA256_DIGEST_SIZE_IN_BITS, candidate.len());
+            assert_eq!(from_hex(expected).to_bits_le(), candidate.eject_value());
+
+            let case = format!("mode = {mode}, length = {}", message.len());
+            assert_scope!(case, num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_hash_constant() {
+        // The constant case folds entirely into constants.
+        check_hash(Mode::Constant, VECTORS[0].0, VECTORS[0].1, 19200, 0, 0, 0);
+        check_hash(Mode::Constant, VECTORS[1].0, VECTORS[1].1, 19200, 0, 0, 0);
+        check_hash(Mode::Constant, VECTORS[2].0, VECTORS[2].1, 38400, 0, 0, 0);
+        check_hash(Mode::Constant, VECTORS[3].0, VECTORS[3].1, 19200, 0, 0, 0);
+        check_hash(Mode::Constant, VECTORS[4].0, VECTORS[4].1, 38400, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_hash_public() {
+        check_hash(Mode::Public, VECTORS[0].0, VECTORS[0].1, 19200, 0, 0, 0);
+        check_hash(Mode::Public, VECTORS[1].0, VECTORS[1].1, 960, 0, 45974, 46521);
+        check_hash(Mode::Public, VECTORS[2].0, VECTORS[2].1, 4768, 0, 87631, 88660);
+        check_hash(Mode::Public, VECTORS[3].0, VECTORS[3].1, 160, 0, 48580, 49171);
+        check_hash(Mode::Public, VECTORS[4].0, VECTORS[4].1, 224, 0, 98243, 99430);
+    }
+
+    #[test]
+    fn test_hash_private() {
+        check_hash(Mode::Private, VECTORS[0].0, VECTORS[0].1, 19200, 0, 0, 0);
+        check_hash(Mode::Private, VECTORS[1].0, VECTORS[1].1, 960, 0, 45974, 46521);
+        check_hash(Mode::Private, VECTORS[2].0, VECTORS[2].1, 4768, 0, 87631, 88660);
+        check_hash(Mode::Private, VECTORS[3].0, VECTORS[3].1, 160, 0, 48580, 49171);
+        check_hash(Mode::Private, VECTORS[4].0, VECTORS[4].1, 224, 0, 98243, 99430);
+    }
+
+    #[test]
+    fn test_hash_to_fields() {
+        let sha256 = Sha256::<Circuit>::new();
+        let (message, _) = VECTORS[1];
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, message.as_bytes().to_bits_le());
+
+        let digest = sha256.hash(&input).eject_value();
+        let candidate = sha256.hash_to_fields(&input);
+
+        // Ensure the field elements pack the digest bits.
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        assert_eq!((SHA256_DIGEST_SIZE_IN_BITS + size_in_data_bits - 1) / size_in_data_bits, candidate.len());
+        for (field, bits) in candidate.iter().zip_eq(digest.chunks(size_in_data_bits)) {
+            assert_eq!(&field.eject_value().to_bits_le()[..bits.len()], bits);
+        }
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+#[cfg(test)]
+use snarkvm_circuits_environment::assert_scope;
+
+use snarkvm_circuits_types::prelude::*;
+
+/// The number of bits in a SHA-256 message block.
+const BLOCK_SIZE_IN_BITS: usize = 512;
+/// The number of bits in a SHA-256 digest.
+pub const SHA256_DIGEST_SIZE_IN_BITS: usize = 256;
+
+/// The initial hash value, given by the first 32 bits of the fractional parts of the square roots of the first 8 primes.
+const INITIAL_STATE: [u32; 8] =
+    [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+/// The round constants, given by the first 32 bits of the fractional parts of the cube roots of the first 64 primes.
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98, 0x12835b01,
+    0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc,
+    0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147,
+    0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08,
+    0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208,
+    0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// SHA-256 is the standard hash function from the SHA-2 family, defined in FIPS 180-4.
+/// The hash operates on 32-bit words, using the wrapped addition and bitwise operations of `U32`.
+pub struct Sha256<E: Environment> {
+    /// The initial hash value.
+    initial_state: Vec<U32<E>>,
+    /// The round constants.
+    round_constants: Vec<U32<E>>,
+}
+
+impl<E: Environment> Sha256<E> {
+    /// Initializes a new instance of SHA-256.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            initial_state: INITIAL_STATE.iter().map(|word| U32::constant(*word)).collect(),
+            round_constants: ROUND_CONSTANTS.iter().map(|word| U32::constant(*word)).collect(),
+        }
+    }
+
+    /// Returns the message padded to a multiple of the block size, as specified in FIPS 180-4.
+    /// The message is appended with a `1` bit, followed by zeros, and the 64-bit big-endian message length.
+    fn pad(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Ensure the input is a sequence of bytes.
+        if input.len() % 8 != 0 {
+            E::halt("SHA-256 requires the input to be a sequence of bytes")
+        }
+
+        let mut padded = input.to_vec();
+        // Append the byte `0x80`.
+        padded.extend(bytes_to_bits_le(&[0x80]));
+        // Append zero bytes, until the length is 64 bits short of a multiple of the block size.
+        while padded.len() % BLOCK_SIZE_IN_BITS != BLOCK_SIZE_IN_BITS - 64 {
+            padded.extend(bytes_to_bits_le(&[0x00]));
+        }
+        // Append the message length in bits, as a 64-bit big-endian integer.
+        padded.extend(bytes_to_bits_le(&(input.len() as u64).to_be_bytes()));
+        padded
+    }
+
+    /// Applies the SHA-256 compression function to the given state and message block.
+    fn compress(&self, state: &mut [U32<E>], block: &[Boolean<E>]) {
+        // Prepare the message schedule.
+        let mut schedule = block.chunks(32).map(word_from_bits_be_bytes).collect::<Vec<_>>();
+        for t in 16..64 {
+            let s0 = rotate_right(&schedule[t - 15], 7)
+                ^ rotate_right(&schedule[t - 15], 18)
+                ^ shift_right(&schedule[t - 15], 3);
+            let s1 = rotate_right(&schedule[t - 2], 17)
+                ^ rotate_right(&schedule[t - 2], 19)
+                ^ shift_right(&schedule[t - 2], 10);
+            let word = schedule[t - 16].add_wrapped(&s0).add_wrapped(&schedule[t - 7]).add_wrapped(&s1);
+            schedule.push(word);
+        }
+
+        // Initialize the working variables.
+        let (mut a, mut b, mut c, mut d) = (state[0].clone(), state[1].clone(), state[2].clone(), state[3].clone());
+        let (mut e, mut f, mut g, mut h) = (state[4].clone(), state[5].clone(), state[6].clone(), state[7].clone());
+
+        for (word, constant) in schedule.iter().zip_eq(&self.round_constants) {
+            // Compute Σ1(e), Ch(e, f, g), Σ0(a), and Maj(a, b, c).
+            let sigma_1 = rotate_right(&e, 6) ^ rotate_right(&e, 11) ^ rotate_right(&e, 25);
+            let choice = (&e & &f) ^ (!e.clone() & &g);
+            let sigma_0 = rotate_right(&a, 2) ^ rotate_right(&a, 13) ^ rotate_right(&a, 22);
+            let majority = (&a & &b) ^ (&a & &c) ^ (&b & &c);
+
+            let temp_1 = h.add_wrapped(&sigma_1).add_wrapped(&choice).add_wrapped(constant).add_wrapped(word);
+            let temp_2 = sigma_0.add_wrapped(&majority);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.add_wrapped(&temp_1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp_1.add_wrapped(&temp_2);
+        }
+
+        // Add the working variables into the state.
+        for (word, variable) in state.iter_mut().zip_eq([a, b, c, d, e, f, g, h]) {
+            *word = word.add_wrapped(&variable);
+        }
+    }
+}
+
+/// Returns the constant little-endian bits of each of the given bytes.
+fn bytes_to_bits_le<E: Environment>(bytes: &[u8]) -> Vec<Boolean<E>> {
+    bytes.iter().flat_map(|byte| (0..8).map(move |i| Boolean::constant((byte >> i) & 1 == 1))).collect()
+}
+
+/// Returns the word given by 4 big-endian bytes, where each byte is given in little-endian bits.
+fn word_from_bits_be_bytes<E: Environment>(bits: &[Boolean<E>]) -> U32<E> {
+    U32::from_bits_le(&bits.chunks(8).rev().flatten().cloned().collect::<Vec<_>>())
+}
+
+/// Returns the 4 big-endian bytes of the word, where each byte is given in little-endian bits.
+fn word_to_bits_be_bytes<E: Environment>(word: &U32<E>) -> Vec<Boolean<E>> {
+    word.to_bits_le().chunks(8).rev().flatten().cloned().collect()
+}
+
+/// Returns the word rotated right by `n` bits.
+/// The rotation is a rewiring of the bits, and does not introduce any constraints.
+fn rotate_right<E: Environment>(word: &U32<E>, n: usize) -> U32<E> {
+    let bits_le = word.to_bits_le();
+    U32::from_bits_le(&[&bits_le[n..], &bits_le[..n]].concat())
+}
+
+/// Returns the word shifted right by `n` bits.
+/// The shift is a rewiring of the bits, and does not introduce any constraints.
+fn shift_right<E: Environment>(word: &U32<E>, n: usize) -> U32<E> {
+    U32::from_bits_le(&word.to_bits_le()[n..])
+}
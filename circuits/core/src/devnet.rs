@@ -16,6 +16,7 @@
 
 use crate::{
     algorithms::{
+        ecies::ECIESPoseidonEncryption,
         Pedersen1024,
         Pedersen128,
         Pedersen256,
@@ -27,16 +28,18 @@ use crate::{
         BHP1024,
         BHP256,
         BHP512,
+        BHP768,
     },
     Aleo,
     Commit,
     Hash,
+    HashToGroup,
     HashToScalar,
     PRF,
 };
 use snarkvm_algorithms::crypto_hash::hash_to_curve;
 use snarkvm_circuits_types::{
-    environment::{prelude::*, Circuit},
+    environment::{prelude::*, Circuit, R1CS},
     Boolean,
     Field,
     Group,
@@ -59,6 +62,8 @@ thread_local! {
     static BHP_256: BHP256<Devnet> = BHP256::<Devnet>::setup("AleoBHP256");
     /// The BHP gadget, which can take an input of up to 512 bits.
     static BHP_512: BHP512<Devnet> = BHP512::<Devnet>::setup("AleoBHP512");
+    /// The BHP gadget, which can take an input of up to 768 bits.
+    static BHP_768: BHP768<Devnet> = BHP768::<Devnet>::setup("AleoBHP768");
     /// The BHP gadget, which can take an input of up to 1024 bits.
     static BHP_1024: BHP1024<Devnet> = BHP1024::<Devnet>::setup("AleoBHP1024");
 
@@ -73,6 +78,9 @@ thread_local! {
     /// The Pedersen gadget, which can take an input of up to 1024 bits.
     static PEDERSEN_1024: Pedersen1024<Devnet> = Pedersen1024::<Devnet>::setup("AleoPedersen1024");
 
+    /// The ECIES symmetric encryption gadget, using Poseidon with a rate of 4.
+    static ECIES: ECIESPoseidonEncryption<Devnet, 4> = ECIESPoseidonEncryption::<Devnet, 4>::setup();
+
     /// The Poseidon hash function, using a rate of 2.
     static POSEIDON_2: Poseidon2<Devnet> = Poseidon2::<Devnet>::new();
     /// The Poseidon hash function, using a rate of 4.
@@ -104,6 +112,13 @@ impl Devnet {
         bases
     }
 
+    /// Returns a native encryption scheme.
+    #[cfg(test)]
+    pub fn native_encryption_scheme()
+    -> snarkvm_algorithms::encryption::ECIESPoseidonEncryption<<E as Environment>::AffineParameters> {
+        snarkvm_algorithms::EncryptionScheme::setup(ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT)
+    }
+
     /// Returns a native signature scheme.
     #[cfg(test)]
     pub fn native_signature_scheme()
@@ -123,6 +138,11 @@ impl Aleo for Devnet {
         BHP_512.with(|bhp| bhp.commit(input, randomizer))
     }
 
+    /// Returns a BHP commitment for the given (up to) 768-bit input and randomness.
+    fn commit_bhp768(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
+        BHP_768.with(|bhp| bhp.commit(input, randomizer))
+    }
+
     /// Returns a BHP commitment for the given (up to) 1024-bit input and randomness.
     fn commit_bhp1024(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
         BHP_1024.with(|bhp| bhp.commit(input, randomizer))
@@ -153,6 +173,11 @@ impl Aleo for Devnet {
         PEDERSEN_1024.with(|pedersen| pedersen.commit(input, randomizer))
     }
 
+    /// Returns the decryption of the given ciphertext, under the given symmetric key.
+    fn decrypt_ecies(symmetric_key: &Field<Self>, ciphertext: &[Field<Self>]) -> Vec<Field<Self>> {
+        ECIES.with(|ecies| ecies.decrypt(symmetric_key.clone(), ciphertext))
+    }
+
     /// Returns the scalar multiplication on the group bases.
     #[inline]
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
@@ -169,6 +194,11 @@ impl Aleo for Devnet {
         POSEIDON_4.with(|poseidon| poseidon.hash_to_scalar(input))
     }
 
+    /// Returns a hash on the group for the given input.
+    fn hash_to_group(input: &[Field<Self>]) -> Group<Self> {
+        POSEIDON_4.with(|poseidon| poseidon.hash_to_group(input))
+    }
+
     /// Returns the BHP hash for a given (up to) 256-bit input.
     fn hash_bhp256(input: &[Boolean<Self>]) -> Field<Self> {
         BHP_256.with(|bhp| bhp.hash(input))
@@ -179,6 +209,11 @@ impl Aleo for Devnet {
         BHP_512.with(|bhp| bhp.hash(input))
     }
 
+    /// Returns the BHP hash for a given (up to) 768-bit input.
+    fn hash_bhp768(input: &[Boolean<Self>]) -> Field<Self> {
+        BHP_768.with(|bhp| bhp.hash(input))
+    }
+
     /// Returns the BHP hash for a given (up to) 1024-bit input.
     fn hash_bhp1024(input: &[Boolean<Self>]) -> Field<Self> {
         BHP_1024.with(|bhp| bhp.hash(input))
@@ -269,6 +304,16 @@ impl Environment for Devnet {
         E::new_witness(mode, logic)
     }
 
+    /// Returns new witnesses of the given mode, whose values are computed by the given closures in parallel.
+    fn new_witnesses_in_parallel<Fn, Output>(mode: Mode, logics: Vec<Fn>) -> Vec<Output>
+    where
+        Fn: FnOnce() -> Output::Primitive + Send,
+        Output: Inject,
+        Output::Primitive: Send,
+    {
+        E::new_witnesses_in_parallel(mode, logics)
+    }
+
     /// Enters a new scope for the environment.
     fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
     where
@@ -288,6 +333,12 @@ impl Environment for Devnet {
         E::enforce(constraint)
     }
 
+    /// Removes the constraints that are syntactically identical to an earlier constraint,
+    /// and returns the number of constraints that were removed.
+    fn deduplicate_constraints() -> u64 {
+        E::deduplicate_constraints()
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         E::is_satisfied()
@@ -359,6 +410,16 @@ impl Environment for Devnet {
         E::halt(message)
     }
 
+    /// Returns the R1CS of the circuit, and initializes an empty environment.
+    fn eject_r1cs_and_reset() -> R1CS<Self::BaseField> {
+        E::eject_r1cs_and_reset()
+    }
+
+    /// Replaces the circuit with the given R1CS.
+    fn inject_r1cs(r1cs: R1CS<Self::BaseField>) {
+        E::inject_r1cs(r1cs)
+    }
+
     /// Clears the circuit and initializes an empty environment.
     fn reset() {
         E::reset()
@@ -23,6 +23,9 @@ pub mod algorithms;
 pub mod devnet;
 pub use devnet::*;
 
+pub mod nonnative;
+pub use nonnative::*;
+
 pub mod traits;
 pub use traits::*;
 
@@ -35,6 +38,9 @@ pub trait Aleo: Environment {
     /// Returns a BHP commitment for the given (up to) 512-bit input and randomness.
     fn commit_bhp512(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self>;
 
+    /// Returns a BHP commitment for the given (up to) 768-bit input and randomness.
+    fn commit_bhp768(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self>;
+
     /// Returns a BHP commitment for the given (up to) 1024-bit input and randomness.
     fn commit_bhp1024(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self>;
 
@@ -53,6 +59,9 @@ pub trait Aleo: Environment {
     /// Returns a Pedersen commitment for the given (up to) 1024-bit input and randomness.
     fn commit_ped1024(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self>;
 
+    /// Returns the decryption of the given ciphertext, under the given symmetric key.
+    fn decrypt_ecies(symmetric_key: &Field<Self>, ciphertext: &[Field<Self>]) -> Vec<Field<Self>>;
+
     /// Returns the scalar multiplication on the group bases.
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self>;
 
@@ -62,12 +71,18 @@ pub trait Aleo: Environment {
     /// Returns the BHP hash for a given (up to) 512-bit input.
     fn hash_bhp512(input: &[Boolean<Self>]) -> Field<Self>;
 
+    /// Returns the BHP hash for a given (up to) 768-bit input.
+    fn hash_bhp768(input: &[Boolean<Self>]) -> Field<Self>;
+
     /// Returns the BHP hash for a given (up to) 1024-bit input.
     fn hash_bhp1024(input: &[Boolean<Self>]) -> Field<Self>;
 
     /// Returns a hash on the scalar field for the given input.
     fn hash_to_scalar(input: &[Field<Self>]) -> Scalar<Self>;
 
+    /// Returns a hash on the group for the given input.
+    fn hash_to_group(input: &[Field<Self>]) -> Group<Self>;
+
     /// Returns the Pedersen hash for a given (up to) 64-bit input.
     fn hash_ped64(input: &[Boolean<Self>]) -> Field<Self>;
 
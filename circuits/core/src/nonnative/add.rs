@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, F: PrimeField> Add<NonNativeField<E, F>> for NonNativeField<E, F> {
+    type Output = NonNativeField<E, F>;
+
+    fn add(self, other: NonNativeField<E, F>) -> Self::Output {
+        &self + &other
+    }
+}
+
+impl<E: Environment, F: PrimeField> Add<&NonNativeField<E, F>> for NonNativeField<E, F> {
+    type Output = NonNativeField<E, F>;
+
+    fn add(self, other: &NonNativeField<E, F>) -> Self::Output {
+        &self + other
+    }
+}
+
+impl<E: Environment, F: PrimeField> Add<&NonNativeField<E, F>> for &NonNativeField<E, F> {
+    type Output = NonNativeField<E, F>;
+
+    ///
+    /// Returns the sum of `self` and `other`, by adding the limbs pairwise.
+    /// The sum is left unnormalized, unless its limbs would overflow the base field.
+    ///
+    fn add(self, other: &NonNativeField<E, F>) -> Self::Output {
+        // If the limbs of the sum may overflow, reduce the summands first.
+        if self.limb_bits.max(other.limb_bits) + 1 > NonNativeField::<E, F>::max_limb_bits() {
+            return &self.reduce() + &other.reduce();
+        }
+
+        let limbs = self.limbs.iter().zip_eq(&other.limbs).map(|(a, b)| a + b).collect();
+        NonNativeField { limbs, limb_bits: self.limb_bits.max(other.limb_bits) + 1, _field: PhantomData }
+    }
+}
+
+impl<E: Environment, F: PrimeField> AddAssign<NonNativeField<E, F>> for NonNativeField<E, F> {
+    fn add_assign(&mut self, other: NonNativeField<E, F>) {
+        *self += &other;
+    }
+}
+
+impl<E: Environment, F: PrimeField> AddAssign<&NonNativeField<E, F>> for NonNativeField<E, F> {
+    fn add_assign(&mut self, other: &NonNativeField<E, F>) {
+        *self = &*self + other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_curves::bls12_377::Fq;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_add(mode_a: Mode, mode_b: Mode) {
+        for i in 0..ITERATIONS {
+            let first = Fq::rand(&mut test_rng());
+            let second = Fq::rand(&mut test_rng());
+
+            let a = BW6_761Fr::<Circuit>::new(mode_a, first);
+            let b = BW6_761Fr::<Circuit>::new(mode_b, second);
+
+            Circuit::scope(format!("Add: {mode_a} + {mode_b} {i}"), || {
+                let candidate = &a + &b;
+                assert_eq!(first + second, candidate.eject_value());
+                assert!(!candidate.is_normalized());
+                // The limbs are added without any constraints.
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_add() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_add(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_chain() {
+        let mut rng = test_rng();
+
+        let mut expected = Fq::rand(&mut rng);
+        let mut candidate = BW6_761Fr::<Circuit>::new(Mode::Private, expected);
+
+        // Add enough summands to force the limbs to be reduced along the way.
+        for _ in 0..300 {
+            let summand = Fq::rand(&mut rng);
+            expected += summand;
+            candidate += BW6_761Fr::new(Mode::Private, summand);
+            assert_eq!(expected, candidate.eject_value());
+        }
+        assert_eq!(expected, candidate.reduce().eject_value());
+        assert!(Circuit::is_satisfied());
+    }
+
+    #[test]
+    fn test_add_worst_case_carries() {
+        // The largest element, whose limbs are all carried out of upon addition.
+        let minus_one = -Fq::one();
+
+        let mut expected = minus_one;
+        let mut candidate = BW6_761Fr::<Circuit>::new(Mode::Private, minus_one);
+        for _ in 0..10 {
+            expected += minus_one;
+            candidate += BW6_761Fr::new(Mode::Private, minus_one);
+            assert_eq!(expected, candidate.eject_value());
+        }
+
+        Circuit::scope("Reduce", || {
+            let candidate = candidate.reduce();
+            assert_eq!(expected, candidate.eject_value());
+            assert!(candidate.is_normalized());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+    }
+}
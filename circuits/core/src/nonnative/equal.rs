@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, F: PrimeField> Equal<Self> for NonNativeField<E, F> {
+    type Output = Boolean<E>;
+
+    ///
+    /// Returns `true` if `self` and `other` are equal modulo `F`.
+    ///
+    /// As normalized elements are canonical, the reduced limbs are compared directly,
+    /// after packing as many limbs as fit into each base field element.
+    ///
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        let (this, that) = (self.reduce(), other.reduce());
+
+        // Pack the limbs into base field elements, without exceeding the capacity of the base field.
+        let limbs_per_chunk = (E::BaseField::size_in_bits() - 1) / LIMB_BITS;
+        let shift = Field::constant(to_base_field::<E>(&(BigUint::from(1u64) << LIMB_BITS)));
+        let pack = |limbs: &[Field<E>]| {
+            limbs.iter().rev().fold(Field::zero(), |accumulator, limb| accumulator * &shift + limb)
+        };
+
+        this.limbs
+            .chunks(limbs_per_chunk)
+            .zip_eq(that.limbs.chunks(limbs_per_chunk))
+            .fold(Boolean::constant(true), |is_equal, (a, b)| is_equal & pack(a).is_equal(&pack(b)))
+    }
+
+    ///
+    /// Returns `true` if `self` and `other` are *not* equal modulo `F`.
+    ///
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_curves::bls12_377::Fq;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_is_equal(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        for i in 0..ITERATIONS {
+            let first = Fq::rand(&mut test_rng());
+            let second = Fq::rand(&mut test_rng());
+
+            let a = BW6_761Fr::<Circuit>::new(mode_a, first);
+            let b = BW6_761Fr::<Circuit>::new(mode_b, second);
+            let c = BW6_761Fr::<Circuit>::new(mode_b, first);
+
+            Circuit::scope(format!("IsEqual: {mode_a} == {mode_b} {i}"), || {
+                let candidate = a.is_equal(&b);
+                assert_eq!(first == second, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+
+            Circuit::scope(format!("IsEqual: {mode_a} == {mode_b} {i} (equal)"), || {
+                let candidate = a.is_equal(&c);
+                assert!(candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_constant_is_equal_to_constant() {
+        check_is_equal(Mode::Constant, Mode::Constant, 3, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_constant_is_equal_to_private() {
+        check_is_equal(Mode::Constant, Mode::Private, 1, 0, 5, 7);
+    }
+
+    #[test]
+    fn test_public_is_equal_to_private() {
+        check_is_equal(Mode::Public, Mode::Private, 1, 0, 5, 7);
+    }
+
+    #[test]
+    fn test_private_is_equal_to_private() {
+        check_is_equal(Mode::Private, Mode::Private, 1, 0, 5, 7);
+    }
+
+    #[test]
+    fn test_is_equal_unnormalized() {
+        let mut rng = test_rng();
+
+        for i in 0..ITERATIONS {
+            let (x, y) = (Fq::rand(&mut rng), Fq::rand(&mut rng));
+
+            // The unnormalized sum `x + y` and the product `(x + y) * 1` are equal modulo `F`.
+            let sum = BW6_761Fr::<Circuit>::new(Mode::Private, x) + BW6_761Fr::new(Mode::Private, y);
+            let product = &sum * &BW6_761Fr::new(Mode::Private, Fq::one());
+
+            Circuit::scope(format!("IsEqual unnormalized {i}"), || {
+                assert!(sum.is_equal(&product).eject_value());
+                assert!(!sum.is_not_equal(&product).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+}
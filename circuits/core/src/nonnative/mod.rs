@@ -0,0 +1,224 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod add;
+pub mod equal;
+pub mod mul;
+pub mod reduce;
+
+#[cfg(test)]
+use snarkvm_circuits_types::environment::assert_scope;
+
+use snarkvm_circuits_types::{environment::prelude::*, Boolean, Field};
+use snarkvm_utilities::BigInteger;
+
+use core::marker::PhantomData;
+use num_bigint::{BigInt, BigUint};
+
+/// The number of bits in each limb of a normalized non-native field element.
+pub const LIMB_BITS: usize = 64;
+
+/// An element of the BW6-761 scalar field, which is the base field of BLS12-377.
+pub type BW6_761Fr<E> = NonNativeField<E, snarkvm_curves::bls12_377::Fq>;
+
+///
+/// A non-native field element, which emulates an element of the prime field `F`
+/// using little-endian limbs of the native base field.
+///
+/// An element is normalized if each limb is at most `LIMB_BITS` bits, and its value is less than
+/// the modulus of `F`. Additions are deferred, leaving the limbs unnormalized, until the element
+/// is reduced, multiplied, or compared.
+///
+#[derive(Clone)]
+pub struct NonNativeField<E: Environment, F: PrimeField> {
+    /// The little-endian limbs of the element.
+    limbs: Vec<Field<E>>,
+    /// An upper bound on the number of bits in each limb.
+    limb_bits: usize,
+    /// The emulated prime field.
+    _field: PhantomData<F>,
+}
+
+impl<E: Environment, F: PrimeField> NonNativeField<E, F> {
+    /// Returns the number of limbs in a normalized element.
+    pub fn num_limbs() -> usize {
+        (F::size_in_bits() + LIMB_BITS - 1) / LIMB_BITS
+    }
+
+    /// Returns the limbs of the element.
+    pub fn limbs(&self) -> &[Field<E>] {
+        &self.limbs
+    }
+
+    /// Returns `true` if the limbs of the element are normalized.
+    pub fn is_normalized(&self) -> bool {
+        self.limb_bits == LIMB_BITS
+    }
+
+    /// Returns the largest limb size that can be enforced without overflowing the base field.
+    fn max_limb_bits() -> usize {
+        E::BaseField::size_in_bits() - 3
+    }
+
+    /// Returns the modulus of `F`.
+    fn modulus() -> BigUint {
+        F::modulus().to_biguint()
+    }
+
+    /// Returns the value of the element as an integer, without reducing it modulo `F`.
+    fn eject_integer(&self) -> BigUint {
+        self.limbs.iter().rev().fold(BigUint::from(0u64), |accumulator, limb| {
+            (accumulator << LIMB_BITS) + limb.eject_value().to_repr().to_biguint()
+        })
+    }
+}
+
+impl<E: Environment, F: PrimeField> Inject for NonNativeField<E, F> {
+    type Primitive = F;
+
+    ///
+    /// Initializes a new non-native field element from a primitive field value.
+    /// If the mode is not constant, each limb is range checked, and the element is enforced to be canonical.
+    ///
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        let limbs = to_limbs(&value.to_repr().to_biguint(), Self::num_limbs())
+            .iter()
+            .map(|limb| Field::new(mode, to_base_field::<E>(limb)))
+            .collect::<Vec<_>>();
+
+        let element = Self { limbs, limb_bits: LIMB_BITS, _field: PhantomData };
+        if !mode.is_constant() {
+            element.enforce_canonical();
+        }
+        element
+    }
+}
+
+impl<E: Environment, F: PrimeField> Eject for NonNativeField<E, F> {
+    type Primitive = F;
+
+    ///
+    /// Ejects the mode of the non-native field element.
+    ///
+    fn eject_mode(&self) -> Mode {
+        self.limbs.eject_mode()
+    }
+
+    ///
+    /// Ejects the non-native field element as a primitive field value.
+    ///
+    fn eject_value(&self) -> Self::Primitive {
+        to_target_field(&(self.eject_integer() % Self::modulus()))
+    }
+}
+
+impl<E: Environment, F: PrimeField> Debug for NonNativeField<E, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.eject_value())
+    }
+}
+
+impl<E: Environment, F: PrimeField> Display for NonNativeField<E, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.eject_value(), self.eject_mode())
+    }
+}
+
+/// Returns the little-endian limbs of the given integer.
+fn to_limbs(value: &BigUint, num_limbs: usize) -> Vec<BigUint> {
+    let mask = (BigUint::from(1u64) << LIMB_BITS) - 1u64;
+    (0..num_limbs).map(|i| (value >> (i * LIMB_BITS)) & &mask).collect()
+}
+
+/// Returns the given integer as a base field element.
+fn to_base_field<E: Environment>(value: &BigUint) -> E::BaseField {
+    E::BaseField::from_bytes_le_mod_order(&value.to_bytes_le())
+}
+
+/// Returns the given integer as a base field element, where negative integers are negated base field elements.
+fn to_signed_base_field<E: Environment>(value: &BigInt) -> E::BaseField {
+    let magnitude = to_base_field::<E>(value.magnitude());
+    match value.sign() {
+        num_bigint::Sign::Minus => -magnitude,
+        _ => magnitude,
+    }
+}
+
+/// Returns the smallest `k` such that `2^k >= n`.
+fn ceil_log2(n: usize) -> usize {
+    (usize::BITS - n.saturating_sub(1).leading_zeros()) as usize
+}
+
+/// Returns the given integer as an element of the prime field `F`.
+fn to_target_field<F: PrimeField>(value: &BigUint) -> F {
+    F::from_bytes_le_mod_order(&value.to_bytes_le())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_curves::bls12_377::Fq;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_new(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        for i in 0..ITERATIONS {
+            let expected = Fq::rand(&mut test_rng());
+
+            Circuit::scope(format!("New {mode} {i}"), || {
+                let candidate = BW6_761Fr::<Circuit>::new(mode, expected);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(candidate.is_normalized());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_num_limbs() {
+        assert_eq!(6, BW6_761Fr::<Circuit>::num_limbs());
+    }
+
+    #[test]
+    fn test_new_constant() {
+        check_new(Mode::Constant, 6, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_new_public() {
+        check_new(Mode::Public, 8, 6, 794, 806);
+    }
+
+    #[test]
+    fn test_new_private() {
+        check_new(Mode::Private, 8, 0, 800, 806);
+    }
+
+    #[test]
+    fn test_new_edge_cases() {
+        for (name, value) in [("Zero", Fq::zero()), ("One", Fq::one()), ("MinusOne", -Fq::one())] {
+            Circuit::scope(name, || {
+                let candidate = BW6_761Fr::<Circuit>::new(Mode::Private, value);
+                assert_eq!(value, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, F: PrimeField> Mul<NonNativeField<E, F>> for NonNativeField<E, F> {
+    type Output = NonNativeField<E, F>;
+
+    fn mul(self, other: NonNativeField<E, F>) -> Self::Output {
+        &self * &other
+    }
+}
+
+impl<E: Environment, F: PrimeField> Mul<&NonNativeField<E, F>> for NonNativeField<E, F> {
+    type Output = NonNativeField<E, F>;
+
+    fn mul(self, other: &NonNativeField<E, F>) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<E: Environment, F: PrimeField> Mul<&NonNativeField<E, F>> for &NonNativeField<E, F> {
+    type Output = NonNativeField<E, F>;
+
+    ///
+    /// Returns the normalized product of `self` and `other`.
+    ///
+    /// The limbs of the integer product are computed as the convolution of the limbs,
+    /// which is then reduced by witnessing its quotient and remainder by the modulus.
+    ///
+    fn mul(self, other: &NonNativeField<E, F>) -> Self::Output {
+        // If both elements are constant, compute the product natively.
+        if self.is_constant() && other.is_constant() {
+            return NonNativeField::new(Mode::Constant, self.eject_value() * other.eject_value());
+        }
+
+        // If the limbs of the product may overflow, reduce the factors first.
+        let product_limb_bits = self.limb_bits + other.limb_bits + ceil_log2(self.limbs.len().min(other.limbs.len()));
+        if product_limb_bits > NonNativeField::<E, F>::max_limb_bits() {
+            return &self.reduce() * &other.reduce();
+        }
+
+        // Compute the limbs of the integer product.
+        let mut limbs = vec![Field::zero(); self.limbs.len() + other.limbs.len() - 1];
+        for (i, a) in self.limbs.iter().enumerate() {
+            for (j, b) in other.limbs.iter().enumerate() {
+                limbs[i + j] += a * b;
+            }
+        }
+
+        NonNativeField { limbs, limb_bits: product_limb_bits, _field: PhantomData }.reduce()
+    }
+}
+
+impl<E: Environment, F: PrimeField> MulAssign<NonNativeField<E, F>> for NonNativeField<E, F> {
+    fn mul_assign(&mut self, other: NonNativeField<E, F>) {
+        *self *= &other;
+    }
+}
+
+impl<E: Environment, F: PrimeField> MulAssign<&NonNativeField<E, F>> for NonNativeField<E, F> {
+    fn mul_assign(&mut self, other: &NonNativeField<E, F>) {
+        *self = &*self * other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_curves::bls12_377::Fq;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_mul(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        for i in 0..ITERATIONS {
+            let first = Fq::rand(&mut test_rng());
+            let second = Fq::rand(&mut test_rng());
+
+            let a = BW6_761Fr::<Circuit>::new(mode_a, first);
+            let b = BW6_761Fr::<Circuit>::new(mode_b, second);
+
+            Circuit::scope(format!("Mul: {mode_a} * {mode_b} {i}"), || {
+                let candidate = &a * &b;
+                assert_eq!(first * second, candidate.eject_value());
+                assert!(candidate.is_normalized());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_constant_times_constant() {
+        check_mul(Mode::Constant, Mode::Constant, 6, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_constant_times_public() {
+        check_mul(Mode::Constant, Mode::Public, 52, 0, 1984, 2002);
+    }
+
+    #[test]
+    fn test_constant_times_private() {
+        check_mul(Mode::Constant, Mode::Private, 52, 0, 1984, 2002);
+    }
+
+    #[test]
+    fn test_public_times_public() {
+        check_mul(Mode::Public, Mode::Public, 52, 0, 2020, 2038);
+    }
+
+    #[test]
+    fn test_public_times_private() {
+        check_mul(Mode::Public, Mode::Private, 52, 0, 2020, 2038);
+    }
+
+    #[test]
+    fn test_private_times_private() {
+        check_mul(Mode::Private, Mode::Private, 52, 0, 2020, 2038);
+    }
+
+    #[test]
+    fn test_mul_add_chain() {
+        let mut rng = test_rng();
+
+        let mut expected = Fq::rand(&mut rng);
+        let mut candidate = BW6_761Fr::<Circuit>::new(Mode::Private, expected);
+
+        // Alternate between unnormalized sums and products.
+        for _ in 0..50 {
+            let (summand, factor) = (Fq::rand(&mut rng), Fq::rand(&mut rng));
+            expected = (expected + summand) * factor;
+            candidate = (candidate + BW6_761Fr::new(Mode::Private, summand)) * BW6_761Fr::new(Mode::Public, factor);
+            assert_eq!(expected, candidate.eject_value());
+        }
+        assert!(Circuit::is_satisfied());
+    }
+
+    #[test]
+    fn test_mul_of_sums() {
+        let mut rng = test_rng();
+
+        // Multiply two long sums, which forces the factors to be reduced before the product.
+        let (mut first, mut second) = (Fq::zero(), Fq::zero());
+        let mut a = BW6_761Fr::<Circuit>::new(Mode::Constant, Fq::zero());
+        let mut b = BW6_761Fr::<Circuit>::new(Mode::Constant, Fq::zero());
+        for _ in 0..100 {
+            let (x, y) = (Fq::rand(&mut rng), Fq::rand(&mut rng));
+            first += x;
+            second += y;
+            a += BW6_761Fr::new(Mode::Private, x);
+            b += BW6_761Fr::new(Mode::Private, y);
+        }
+
+        Circuit::scope("Mul of sums", || {
+            let candidate = &a * &b;
+            assert_eq!(first * second, candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+    }
+
+    #[test]
+    fn test_mul_worst_case_carries() {
+        let minus_one = -Fq::one();
+        let largest_limbs = Fq::from_bytes_le_mod_order(&[0xff; 47]);
+
+        for (name, first, second) in [
+            ("MinusOne * MinusOne", minus_one, minus_one),
+            ("MinusOne * LargestLimbs", minus_one, largest_limbs),
+            ("LargestLimbs * LargestLimbs", largest_limbs, largest_limbs),
+            ("Zero * MinusOne", Fq::zero(), minus_one),
+            ("One * MinusOne", Fq::one(), minus_one),
+        ] {
+            let a = BW6_761Fr::<Circuit>::new(Mode::Private, first);
+            let b = BW6_761Fr::<Circuit>::new(Mode::Private, second);
+
+            Circuit::scope(name, || {
+                let candidate = &a * &b;
+                assert_eq!(first * second, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+}
@@ -0,0 +1,215 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, F: PrimeField> NonNativeField<E, F> {
+    ///
+    /// Returns the normalized element that is equal to `self` modulo `F`.
+    ///
+    /// The quotient `q` and remainder `r` of `self` by the modulus `p` are witnessed,
+    /// and the limbs of `self == q * p + r` are enforced to be equal, propagating the carries
+    /// between limbs. The remainder is enforced to be canonical.
+    ///
+    pub fn reduce(&self) -> Self {
+        // If the element is already normalized, return it.
+        if self.is_normalized() {
+            return self.clone();
+        }
+
+        // If the element is constant, reduce it natively.
+        if self.is_constant() {
+            return Self::new(Mode::Constant, self.eject_value());
+        }
+
+        // Compute the quotient and remainder natively.
+        let modulus = Self::modulus();
+        let value = self.eject_integer();
+        let (quotient, remainder) = (&value / &modulus, &value % &modulus);
+
+        // Determine the number of bits in the quotient, from the upper bound of `self`.
+        let value_bits = self.limb_bits + (self.limbs.len() - 1) * LIMB_BITS + 1;
+        let quotient_bits = value_bits.saturating_sub(modulus.bits() as usize - 1).max(1);
+        let num_quotient_limbs = (quotient_bits + LIMB_BITS - 1) / LIMB_BITS;
+
+        // Witness the quotient, and range check each of its limbs.
+        let quotient = to_limbs(&quotient, num_quotient_limbs)
+            .iter()
+            .enumerate()
+            .map(|(i, limb)| {
+                let limb = Field::new(Mode::Private, to_base_field::<E>(limb));
+                limb.to_lower_bits_le(LIMB_BITS.min(quotient_bits - i * LIMB_BITS));
+                limb
+            })
+            .collect::<Vec<_>>();
+
+        // Witness the remainder, and enforce that it is canonical.
+        let remainder = Self::new(Mode::Private, to_target_field(&remainder));
+
+        // Compute the limbs of `q * p + r`.
+        let modulus = to_limbs(&modulus, Self::num_limbs()).iter().map(to_base_field::<E>).collect::<Vec<_>>();
+        let mut expected = vec![Field::zero(); num_quotient_limbs + modulus.len() - 1];
+        for (i, quotient_limb) in quotient.iter().enumerate() {
+            for (j, modulus_limb) in modulus.iter().enumerate() {
+                expected[i + j] += quotient_limb * Field::constant(*modulus_limb);
+            }
+        }
+        for (i, remainder_limb) in remainder.limbs.iter().enumerate() {
+            expected[i] += remainder_limb;
+        }
+
+        // Ensure `self == q * p + r`.
+        let expected_bits = 2 * LIMB_BITS + ceil_log2(num_quotient_limbs.min(modulus.len())) + 1;
+        enforce_equal_integers(&self.limbs, &expected, self.limb_bits.max(expected_bits));
+
+        remainder
+    }
+
+    /// Enforces that each limb of `self` is at most `LIMB_BITS` bits, and `self` is less than the modulus.
+    pub(super) fn enforce_canonical(&self) {
+        // Range check each limb.
+        for limb in &self.limbs {
+            limb.to_lower_bits_le(LIMB_BITS);
+        }
+
+        // Witness the difference `(p - 1) - self`, and range check each of its limbs.
+        let modulus_minus_one = Self::modulus() - 1u64;
+        let difference = to_limbs(&(&modulus_minus_one - self.eject_integer()), Self::num_limbs())
+            .iter()
+            .map(|limb| {
+                let limb = Field::new(Mode::Private, to_base_field::<E>(limb));
+                limb.to_lower_bits_le(LIMB_BITS);
+                limb
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure `self + difference == p - 1`, which implies `self < p`.
+        let sum = self.limbs.iter().zip_eq(&difference).map(|(limb, difference)| limb + difference).collect::<Vec<_>>();
+        let modulus_minus_one = to_limbs(&modulus_minus_one, Self::num_limbs())
+            .iter()
+            .map(|limb| Field::constant(to_base_field::<E>(limb)))
+            .collect::<Vec<_>>();
+        enforce_equal_integers(&sum, &modulus_minus_one, LIMB_BITS + 1);
+    }
+}
+
+///
+/// Enforces that the integers represented by the given little-endian limbs are equal,
+/// where every limb is less than `2^limb_bits`.
+///
+/// For each position `k`, the carry `c_k` satisfies `a_k - b_k + c_{k-1} == c_k * 2^LIMB_BITS`,
+/// and the final carry is zero. Each carry is witnessed with an offset, so that it is range checked as
+/// a non-negative value. As the limbs are less than `2^limb_bits`, no equation overflows the base field.
+///
+fn enforce_equal_integers<E: Environment>(a: &[Field<E>], b: &[Field<E>], limb_bits: usize) {
+    // Ensure the equations do not overflow the base field.
+    if limb_bits + 2 >= E::BaseField::size_in_bits() {
+        E::halt(format!("Attempted to compare {limb_bits}-bit limbs, which overflow the base field"))
+    }
+
+    // Pad the limbs to the same length.
+    let num_limbs = a.len().max(b.len());
+    let pad = |limbs: &[Field<E>]| {
+        limbs.iter().cloned().chain(core::iter::repeat(Field::zero())).take(num_limbs).collect::<Vec<_>>()
+    };
+    let (a, b) = (pad(a), pad(b));
+
+    // Each carry is less than `2^carry_bits` in absolute value.
+    let carry_bits = (limb_bits + 1).saturating_sub(LIMB_BITS).max(1);
+    let offset = BigInt::from(1u64) << carry_bits;
+    let offset_field = Field::constant(to_signed_base_field::<E>(&offset));
+    let shift = Field::constant(to_base_field::<E>(&(BigUint::from(1u64) << LIMB_BITS)));
+
+    // Compute the carries natively.
+    let to_integer = |limb: &Field<E>| BigInt::from(limb.eject_value().to_repr().to_biguint());
+    let mut carry = BigInt::from(0u64);
+
+    let mut previous_carry = Field::zero();
+    for (k, (a_k, b_k)) in a.iter().zip_eq(&b).enumerate() {
+        let sum = a_k - b_k + &previous_carry;
+
+        if k + 1 == num_limbs {
+            // Ensure the final carry is zero.
+            E::assert_eq(&sum, Field::<E>::zero());
+        } else {
+            // Compute the carry for this position natively.
+            carry = (to_integer(a_k) - to_integer(b_k) + carry) >> LIMB_BITS;
+
+            // Witness the offset carry, and range check it.
+            let offset_carry = Field::new(Mode::Private, to_signed_base_field::<E>(&(&carry + &offset)));
+            offset_carry.to_lower_bits_le(carry_bits + 1);
+
+            // Ensure `a_k - b_k + c_{k-1} == c_k * 2^LIMB_BITS`.
+            let next_carry = &offset_carry - &offset_field;
+            E::assert_eq(&sum, &next_carry * &shift);
+            previous_carry = next_carry;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_curves::bls12_377::Fq;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_reduce(mode_a: Mode, mode_b: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        for i in 0..ITERATIONS {
+            let first = Fq::rand(&mut test_rng());
+            let second = Fq::rand(&mut test_rng());
+
+            let sum = BW6_761Fr::<Circuit>::new(mode_a, first) + BW6_761Fr::new(mode_b, second);
+
+            Circuit::scope(format!("Reduce: {mode_a} + {mode_b} {i}"), || {
+                let candidate = sum.reduce();
+                assert_eq!(first + second, candidate.eject_value());
+                assert!(candidate.is_normalized());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_reduce_constant() {
+        check_reduce(Mode::Constant, Mode::Constant, 6, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_reduce_public() {
+        check_reduce(Mode::Public, Mode::Public, 16, 0, 1151, 1163);
+    }
+
+    #[test]
+    fn test_reduce_private() {
+        check_reduce(Mode::Private, Mode::Private, 16, 0, 1151, 1163);
+    }
+
+    #[test]
+    fn test_reduce_normalized() {
+        let expected = Fq::rand(&mut test_rng());
+        let a = BW6_761Fr::<Circuit>::new(Mode::Private, expected);
+
+        Circuit::scope("Reduce", || {
+            let candidate = a.reduce();
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+    }
+}
@@ -54,6 +54,15 @@ pub trait HashMany {
     fn hash_many(&self, input: &[Self::Input], num_outputs: usize) -> Vec<Self::Output>;
 }
 
+/// A trait for a hash function that projects the value to a group element.
+pub trait HashToGroup {
+    type Input;
+    type Output;
+
+    /// Returns the hash of the given input.
+    fn hash_to_group(&self, input: &[Self::Input]) -> Self::Output;
+}
+
 /// A trait for a hash function that projects the value to a scalar.
 pub trait HashToScalar {
     type Input;
@@ -21,6 +21,7 @@ use snarkvm_curves::{
 };
 
 use core::{cell::RefCell, fmt};
+use rayon::prelude::*;
 use std::rc::Rc;
 
 thread_local! {
@@ -84,6 +85,30 @@ impl Environment for Circuit {
         })
     }
 
+    /// Returns new witnesses of the given mode, whose values are computed by the given closures in parallel.
+    fn new_witnesses_in_parallel<Fn, Output>(mode: Mode, logics: Vec<Fn>) -> Vec<Output>
+    where
+        Fn: FnOnce() -> Output::Primitive + Send,
+        Output: Inject,
+        Output::Primitive: Send,
+    {
+        // Run the logic on the thread pool, setting each thread to witness mode.
+        let values = logics
+            .into_par_iter()
+            .map(|logic| {
+                IN_WITNESS.with(|in_witness| {
+                    let previous = (**in_witness).replace(true);
+                    let value = logic();
+                    *(**in_witness).borrow_mut() = previous;
+                    value
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Inject the witnesses in order, to assign them exactly as sequential witness generation would.
+        values.into_iter().map(|value| Inject::new(mode, value)).collect()
+    }
+
     // /// Appends the given scope to the current environment.
     // fn push_scope(name: &str) {
     //     CIRCUIT.with(|circuit| {
@@ -152,10 +177,20 @@ impl Environment for Circuit {
             if !(*(**in_witness).borrow()) {
                 CIRCUIT.with(|circuit| {
                     let (a, b, c) = constraint();
-                    let (a, b, c) = (a.into(), b.into(), c.into());
+                    let (mut a, mut b, mut c) = (a.into(), b.into(), c.into());
+
+                    // Drop the terms with a zero coefficient, before the constraint is recorded.
+                    a.normalize();
+                    b.normalize();
+                    c.normalize();
 
                     // Ensure the constraint is not comprised of constants.
                     match a.is_constant() && b.is_constant() && c.is_constant() {
+                        // A constant constraint that does not hold is recorded, so the circuit is not satisfied.
+                        true if a.value() * b.value() != c.value() => {
+                            let constraint = Constraint((**circuit).borrow().scope(), a, b, c);
+                            (**circuit).borrow_mut().enforce(constraint)
+                        }
                         true => {
                             // Disabled for now until better control handling for this can be defined (using scope).
 
@@ -179,6 +214,12 @@ impl Environment for Circuit {
         })
     }
 
+    /// Removes the constraints that are syntactically identical to an earlier constraint,
+    /// and returns the number of constraints that were removed.
+    fn deduplicate_constraints() -> u64 {
+        CIRCUIT.with(|circuit| (**circuit).borrow_mut().deduplicate_constraints())
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied())
@@ -264,6 +305,23 @@ impl Environment for Circuit {
         panic!("{}", &error)
     }
 
+    /// Returns the R1CS of the circuit, and initializes an empty environment.
+    fn eject_r1cs_and_reset() -> R1CS<Self::BaseField> {
+        CIRCUIT.with(|circuit| core::mem::replace(&mut *(**circuit).borrow_mut(), R1CS::<Self::BaseField>::new()))
+    }
+
+    /// Replaces the circuit with the given R1CS.
+    fn inject_r1cs(r1cs: R1CS<Self::BaseField>) {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !(*(**in_witness).borrow()) {
+                CIRCUIT.with(|circuit| *(**circuit).borrow_mut() = r1cs)
+            } else {
+                Self::halt("Tried to inject a circuit in witness mode")
+            }
+        })
+    }
+
     /// Clears the circuit and initializes an empty environment.
     fn reset() {
         CIRCUIT.with(|circuit| {
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Inject, LinearCombination, Mode, Variable};
+use crate::{Inject, LinearCombination, Mode, Variable, R1CS};
 use snarkvm_curves::{AffineCurve, TwistedEdwardsParameters};
 use snarkvm_fields::traits::*;
 
@@ -41,6 +41,14 @@ pub trait Environment: Copy + Clone + fmt::Debug + fmt::Display + Eq + PartialEq
     /// Returns a new witness of the given mode and value.
     fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, value: Fn) -> Output;
 
+    /// Returns new witnesses of the given mode, whose values are computed by the given closures in parallel.
+    /// The closures must not access the environment, and the witnesses are injected in their order.
+    fn new_witnesses_in_parallel<Fn, Output>(mode: Mode, logics: Vec<Fn>) -> Vec<Output>
+    where
+        Fn: FnOnce() -> Output::Primitive + Send,
+        Output: Inject,
+        Output::Primitive: Send;
+
     /// Enters a new scope for the environment.
     fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
     where
@@ -68,6 +76,10 @@ pub trait Environment: Copy + Clone + fmt::Debug + fmt::Display + Eq + PartialEq
         Self::enforce(|| (a, Self::one(), b))
     }
 
+    /// Removes the constraints that are syntactically identical to an earlier constraint,
+    /// and returns the number of constraints that were removed.
+    fn deduplicate_constraints() -> u64;
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool;
 
@@ -129,6 +141,201 @@ pub trait Environment: Copy + Clone + fmt::Debug + fmt::Display + Eq + PartialEq
         panic!("{}", message.into())
     }
 
+    /// Returns the R1CS of the current environment, and initializes an empty environment.
+    fn eject_r1cs_and_reset() -> R1CS<Self::BaseField>;
+
+    /// Replaces the current environment with the given R1CS, such as one produced by `R1CS::merge`.
+    fn inject_r1cs(r1cs: R1CS<Self::BaseField>);
+
     /// Clears and initializes an empty environment.
     fn reset();
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{circuit::CIRCUIT, *};
+    use snarkvm_curves::edwards_bls12::Fq;
+    use snarkvm_fields::{Field, One, Zero};
+
+    const NUM_SUBCIRCUITS: u64 = 16;
+
+    /// A variable, injected into `Circuit`.
+    struct Witness(Variable<Fq>);
+
+    impl Inject for Witness {
+        type Primitive = Fq;
+
+        fn new(mode: Mode, value: Self::Primitive) -> Self {
+            Self(Circuit::new_variable(mode, value))
+        }
+    }
+
+    /// Returns the value of `x^(2^16) * x^(-1)`, purposefully computed inefficiently.
+    fn compute(x: Fq) -> Fq {
+        (0..16).fold(x, |acc, _| acc.square()) * x.inverse().unwrap()
+    }
+
+    /// Synthesizes independent sub-circuits, each of which enforces `x * y == x^(2^16)` for a witness `y`,
+    /// and returns the public variables, private variables, and constraints of the environment.
+    fn synthesize(parallel: bool) -> (Vec<Variable<Fq>>, Vec<Variable<Fq>>, String) {
+        Circuit::reset();
+
+        let inputs = (0..NUM_SUBCIRCUITS).map(|i| Witness::new(Mode::Private, Fq::from(i + 2))).collect::<Vec<_>>();
+        let values = inputs.iter().map(|x| x.0.value()).collect::<Vec<_>>();
+
+        let outputs: Vec<Witness> = match parallel {
+            true => {
+                Circuit::new_witnesses_in_parallel(Mode::Private, values.iter().map(|x| move || compute(*x)).collect())
+            }
+            false => values.iter().map(|x| Circuit::new_witness(Mode::Private, || compute(*x))).collect(),
+        };
+
+        for ((x, y), value) in inputs.iter().zip(&outputs).zip(&values) {
+            let power = Witness::new(Mode::Public, (0..16).fold(*value, |acc, _| acc.square()));
+            Circuit::enforce(|| (x.0.clone(), y.0.clone(), power.0));
+        }
+        assert!(Circuit::is_satisfied());
+
+        let assignment = CIRCUIT.with(|circuit| {
+            let circuit = (**circuit).borrow();
+            (circuit.to_public_variables().clone(), circuit.to_private_variables().clone())
+        });
+        (assignment.0, assignment.1, Circuit.to_string())
+    }
+
+    #[test]
+    fn test_new_witnesses_in_parallel() {
+        let (sequential_public, sequential_private, sequential_constraints) = synthesize(false);
+        let (parallel_public, parallel_private, parallel_constraints) = synthesize(true);
+
+        assert_eq!(sequential_public.len() as u64, NUM_SUBCIRCUITS + 1);
+        assert_eq!(sequential_private.len() as u64, 2 * NUM_SUBCIRCUITS);
+        assert_eq!(sequential_public, parallel_public);
+        assert_eq!(sequential_private, parallel_private);
+        assert_eq!(sequential_constraints, parallel_constraints);
+
+        // The environment can be used as usual afterwards.
+        let x = Witness::new(Mode::Private, Fq::one());
+        assert_eq!(Circuit::num_private(), 2 * NUM_SUBCIRCUITS + 1);
+        assert_eq!(x.0.index(), 2 * NUM_SUBCIRCUITS);
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_deduplicate_constraints() {
+        Circuit::reset();
+
+        let x = Circuit::new_variable(Mode::Private, Fq::from(3u64));
+        let y = Circuit::new_variable(Mode::Private, Fq::from(5u64));
+        let z = Circuit::new_variable(Mode::Public, Fq::from(15u64));
+
+        // Enforce `x * y == z` three times, `x + y == y + x` twice with reordered terms, and `y * x == z` once.
+        for _ in 0..3 {
+            Circuit::enforce(|| (x.clone(), y.clone(), z.clone()));
+        }
+        Circuit::enforce(|| (x.clone() + &y, Circuit::one(), y.clone() + &x));
+        Circuit::enforce(|| (y.clone() + &x, Circuit::one(), x.clone() + &y));
+        Circuit::enforce(|| (y.clone(), x.clone(), z.clone()));
+        assert_eq!(Circuit::num_constraints(), 6);
+        assert!(Circuit::is_satisfied());
+
+        assert_eq!(Circuit::deduplicate_constraints(), 3);
+        assert_eq!(Circuit::num_constraints(), 3);
+        assert_eq!(Circuit::num_gates(), 1 + 3 + 1);
+        assert!(Circuit::is_satisfied());
+
+        // A second pass finds nothing to remove.
+        assert_eq!(Circuit::deduplicate_constraints(), 0);
+
+        // An unsatisfied system remains unsatisfied.
+        let w = Circuit::new_variable(Mode::Private, Fq::from(16u64));
+        Circuit::enforce(|| (x.clone(), y.clone(), w.clone()));
+        Circuit::enforce(|| (x.clone(), y.clone(), w.clone()));
+        assert!(!Circuit::is_satisfied());
+        assert_eq!(Circuit::deduplicate_constraints(), 1);
+        assert_eq!(Circuit::num_constraints(), 4);
+        assert!(!Circuit::is_satisfied());
+
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_enforce_normalizes_terms() {
+        Circuit::reset();
+
+        let x = Circuit::new_variable(Mode::Private, Fq::from(3u64));
+        let y = Circuit::new_variable(Mode::Private, Fq::from(5u64));
+        let z = Circuit::new_variable(Mode::Public, Fq::from(15u64));
+
+        // Enforce `(0 * y + x) * y == z` with a zero-coefficient term in `A`.
+        let a = LinearCombination::from(&y) * Fq::zero() + &x;
+        assert_eq!(format!("{a:?}"), "Constant(0) + Private(0, 3) + 0 * Private(1, 5)");
+        Circuit::enforce(|| (a, y.clone(), z.clone()));
+        assert!(Circuit::is_satisfied());
+
+        // The recorded constraint only refers to `x` in `A`.
+        CIRCUIT.with(|circuit| {
+            let circuit = (**circuit).borrow();
+            let (a, b, c) = circuit.to_constraints()[0].to_terms();
+            assert_eq!(format!("{a:?}"), "Constant(0) + Private(0, 3)");
+            assert_eq!(format!("{b:?}"), "Constant(0) + Private(1, 5)");
+            assert_eq!(format!("{c:?}"), "Constant(0) + Public(1, 15)");
+            assert_eq!(a.value(), Fq::from(3u64));
+        });
+        assert_eq!(Circuit::num_gates(), 1);
+
+        // A constraint whose terms all vanish is constant, and is not recorded.
+        Circuit::enforce(|| (LinearCombination::from(&x) * Fq::zero(), Circuit::one(), Circuit::zero()));
+        assert_eq!(Circuit::num_constraints(), 1);
+        assert!(Circuit::is_satisfied());
+
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_enforce_unsatisfied_constant_terms() {
+        Circuit::reset();
+
+        let x = Circuit::new_variable(Mode::Private, Fq::from(3u64));
+
+        // Enforce `(0 * x + 1) * 1 == 0`, whose terms all vanish, but which does not hold.
+        let a = LinearCombination::from(&x) * Fq::zero() + Circuit::one();
+        Circuit::enforce(|| (a, Circuit::one(), Circuit::zero()));
+
+        // The constraint is recorded, and the circuit is not satisfied.
+        assert_eq!(Circuit::num_constraints(), 1);
+        assert!(!Circuit::is_satisfied());
+
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_merge_subcircuits() {
+        Circuit::reset();
+
+        // Synthesizes `x * y == z` as an independent circuit, and returns its R1CS.
+        let synthesize = |x: u64, y: u64| {
+            let x = Circuit::new_variable(Mode::Private, Fq::from(x));
+            let y = Circuit::new_variable(Mode::Private, Fq::from(y));
+            let z = Circuit::new_variable(Mode::Public, x.value() * y.value());
+            Circuit::enforce(|| (x, y, z));
+            Circuit::eject_r1cs_and_reset()
+        };
+
+        let first = synthesize(3, 5);
+        let second = synthesize(7, 11);
+        let num_constraints = first.num_constraints() + second.num_constraints();
+
+        Circuit::inject_r1cs(first.merge(second));
+        assert!(Circuit::is_satisfied());
+        assert_eq!(Circuit::num_constraints(), num_constraints);
+        assert_eq!(Circuit::num_public(), 3);
+        assert_eq!(Circuit::num_private(), 4);
+
+        // New variables are allocated after the variables of both subcircuits.
+        let w = Circuit::new_variable(Mode::Private, Fq::one());
+        assert_eq!(w.index(), 4);
+
+        Circuit::reset();
+    }
+}
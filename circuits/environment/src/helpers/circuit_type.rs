@@ -57,6 +57,14 @@ impl<T: Eject> CircuitType<T> {
             CircuitType::Private => Mode::Private,
         }
     }
+
+    /// Returns `true` if the circuit is a constant whose value satisfies the given condition.
+    pub fn is_constant_and(&self, condition: impl FnOnce(T::Primitive) -> bool) -> bool {
+        match self {
+            CircuitType::Constant(constant) => condition(constant.eject_value()),
+            _ => false,
+        }
+    }
 }
 
 /// Initializes a new `CircuitType` from a circuit.
@@ -84,3 +92,10 @@ impl<T: Eject + Clone> From<&T> for CircuitType<T> {
         }
     }
 }
+
+/// Returns the mode of the given `CircuitType`.
+impl<T: Eject> From<CircuitType<T>> for Mode {
+    fn from(circuit_type: CircuitType<T>) -> Self {
+        circuit_type.mode()
+    }
+}
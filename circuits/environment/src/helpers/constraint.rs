@@ -47,6 +47,21 @@ impl<F: PrimeField> Constraint<F> {
         }
     }
 
+    /// Returns the terms `(a, b, c)` in a canonical form, where two constraints
+    /// are syntactically identical if and only if their canonical forms are equal.
+    pub(crate) fn to_canonical_terms(&self) -> [(F, Vec<(Variable<F>, F)>); 3] {
+        let canonicalize = |lc: &LinearCombination<F>| {
+            let mut terms = lc
+                .to_terms()
+                .iter()
+                .map(|(variable, coefficient)| (variable.clone(), *coefficient))
+                .collect::<Vec<_>>();
+            terms.sort_unstable();
+            (lc.to_constant(), terms)
+        };
+        [canonicalize(&self.1), canonicalize(&self.2), canonicalize(&self.3)]
+    }
+
     /// Returns a reference to the terms `(a, b, c)`.
     pub(crate) fn to_terms(&self) -> (&LinearCombination<F>, &LinearCombination<F>, &LinearCombination<F>) {
         (&self.1, &self.2, &self.3)
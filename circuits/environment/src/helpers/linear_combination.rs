@@ -116,6 +116,23 @@ impl<F: PrimeField> LinearCombination<F> {
         }
     }
 
+    /// Removes the terms with a zero coefficient, which can arise from scaling by zero.
+    /// Terms are already collected by variable, so this leaves exactly one term per variable
+    /// with a nonzero coefficient, and does not change the value of the linear combination.
+    pub(crate) fn normalize(&mut self) {
+        self.terms.retain(|_, coefficient| !coefficient.is_zero());
+    }
+
+    /// Returns a copy of the linear combination, with each variable replaced by `remap(variable)`.
+    /// The remap must be injective and preserve the value of each variable.
+    pub(super) fn remap_variables<R: Fn(&Variable<F>) -> Variable<F>>(&self, remap: R) -> Self {
+        Self {
+            constant: self.constant,
+            terms: self.terms.iter().map(|(variable, coefficient)| (remap(variable), *coefficient)).collect(),
+            value: self.value,
+        }
+    }
+
     /// Returns only the constant value (excluding the terms) in the linear combination.
     pub(super) fn to_constant(&self) -> F {
         self.constant
@@ -540,6 +557,29 @@ mod tests {
         assert_eq!(four, *candidate_coefficient);
     }
 
+    #[test]
+    fn test_normalize() {
+        let zero = <Circuit as Environment>::BaseField::zero();
+        let one = <Circuit as Environment>::BaseField::one();
+        let two = one + one;
+
+        let x = Variable::Private(0, Rc::new(two));
+        let y = Variable::Private(1, Rc::new(one));
+        let z = Variable::Public(1, Rc::new(two));
+
+        // Compute 0 * x + y + y + z - z, where `x` is scaled by zero, `y` is repeated, and `z` cancels.
+        let candidate = LinearCombination::from(&x) * zero + &y + &y + &z - &z;
+        assert_eq!(two, candidate.value());
+        assert_eq!(2, candidate.terms.len());
+
+        let mut normalized = candidate.clone();
+        normalized.normalize();
+        assert_eq!(candidate.value(), normalized.value());
+        assert_eq!(candidate.constant, normalized.constant);
+        assert_eq!(1, normalized.terms.len());
+        assert_eq!(Some(&two), normalized.terms.get(&y));
+    }
+
     #[test]
     fn test_debug() {
         let one_public = &Circuit::new_variable(Mode::Public, <Circuit as Environment>::BaseField::one());
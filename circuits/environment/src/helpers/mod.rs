@@ -37,5 +37,5 @@ pub use mode::*;
 pub mod variable;
 pub use variable::*;
 
-pub(super) mod r1cs;
-pub(super) use r1cs::*;
+pub mod r1cs;
+pub use r1cs::*;
@@ -20,12 +20,12 @@ use crate::{
 };
 
 use core::fmt;
-use std::rc::Rc;
+use std::{collections::HashSet, rc::Rc};
 
 pub type Scope = String;
 
 #[derive(Debug)]
-pub(crate) struct R1CS<F: PrimeField> {
+pub struct R1CS<F: PrimeField> {
     constants: Vec<Variable<F>>,
     public: Vec<Variable<F>>,
     private: Vec<Variable<F>>,
@@ -88,6 +88,64 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.add_constraint(constraint);
     }
 
+    /// Removes all constraints that are syntactically identical to an earlier constraint,
+    /// and returns the number of constraints that were removed.
+    ///
+    /// Note: the scope counters are not updated, as they record the constraints as enforced.
+    pub(crate) fn deduplicate_constraints(&mut self) -> u64 {
+        let num_constraints = self.constraints.len();
+
+        let mut seen = HashSet::with_capacity(num_constraints);
+        self.constraints.retain(|constraint| seen.insert(constraint.to_canonical_terms()));
+        self.gates = self.constraints.iter().map(|constraint| constraint.num_gates()).sum();
+
+        (num_constraints - self.constraints.len()) as u64
+    }
+
+    /// Merges the independent constraint system `other` into this constraint system,
+    /// and returns the combined constraint system.
+    ///
+    /// The public and private variables of `other` are appended after those of `self`,
+    /// and the constraints of `other` are remapped to their new indices, so the variables
+    /// of the two systems are disjoint. The public variable for `one` is shared by both systems.
+    /// The combined system is satisfied if and only if both systems are satisfied.
+    ///
+    /// Note: the members of `other` are counted in the current scope of `self`.
+    pub fn merge(mut self, other: Self) -> Self {
+        // The public variable for `one` in `other` is mapped to the one in `self`.
+        let public_offset = self.public.len() as u64 - 1;
+        let private_offset = self.private.len() as u64;
+        let remap = |variable: &Variable<F>| match variable {
+            Variable::Constant(value) => Variable::Constant(value.clone()),
+            Variable::Public(0, value) => Variable::Public(0, value.clone()),
+            Variable::Public(index, value) => Variable::Public(index + public_offset, value.clone()),
+            Variable::Private(index, value) => Variable::Private(index + private_offset, value.clone()),
+        };
+
+        for variable in &other.constants {
+            self.constants.push(variable.clone());
+            self.counter.increment_constant();
+        }
+        for variable in other.public.iter().skip(1) {
+            self.public.push(remap(variable));
+            self.counter.increment_public();
+        }
+        for variable in &other.private {
+            self.private.push(remap(variable));
+            self.counter.increment_private();
+        }
+        for Constraint(scope, a, b, c) in other.constraints {
+            self.enforce(Constraint(
+                scope,
+                a.remap_variables(remap),
+                b.remap_variables(remap),
+                c.remap_variables(remap),
+            ));
+        }
+
+        self
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     pub(crate) fn is_satisfied(&self) -> bool {
         self.constraints.iter().all(|constraint| constraint.is_satisfied())
@@ -180,3 +238,63 @@ impl<F: PrimeField> fmt::Display for R1CS<F> {
         write!(f, "{}", output)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::Fq;
+
+    /// Returns a constraint system enforcing `x * y == z` and `x * 2 == x + x`,
+    /// where `x` and `y` are private, `z` is public, and `2` is a constant.
+    fn multiplication_system(x: u64, y: u64, z: u64) -> R1CS<Fq> {
+        let mut r1cs = R1CS::new();
+        let x = r1cs.new_private(Fq::from(x));
+        let y = r1cs.new_private(Fq::from(y));
+        let z = r1cs.new_public(Fq::from(z));
+        let two = r1cs.new_constant(Fq::from(2u64));
+        r1cs.enforce(Constraint("mul".to_string(), (&x).into(), y.into(), z.into()));
+        r1cs.enforce(Constraint("double".to_string(), (&x).into(), two.into(), &x + &x));
+        r1cs
+    }
+
+    #[test]
+    fn test_merge() {
+        let first = multiplication_system(3, 5, 15);
+        let second = multiplication_system(7, 11, 77);
+        assert!(first.is_satisfied());
+        assert!(second.is_satisfied());
+
+        let num_constraints = first.num_constraints() + second.num_constraints();
+        let merged = first.merge(second);
+        assert!(merged.is_satisfied());
+        assert_eq!(merged.num_constraints(), num_constraints);
+        assert_eq!(merged.num_constants(), 2);
+        assert_eq!(merged.num_public(), 3);
+        assert_eq!(merged.num_private(), 4);
+
+        // Ensure the variables of the merged system are indexed consecutively, without collisions.
+        let public_indices = merged.to_public_variables().iter().map(|v| v.index()).collect::<Vec<_>>();
+        assert_eq!(public_indices, vec![0, 1, 2]);
+        let private_indices = merged.to_private_variables().iter().map(|v| v.index()).collect::<Vec<_>>();
+        assert_eq!(private_indices, vec![0, 1, 2, 3]);
+
+        // Ensure the constraints of the second system refer to its remapped variables.
+        let (a, b, c) = merged.to_constraints()[2].to_terms();
+        let expected = [(Variable::Private(2, Rc::new(Fq::from(7u64))), Fq::one())];
+        assert!(a.to_terms().iter().map(|(v, c)| (v.clone(), *c)).eq(expected));
+        assert_eq!(b.to_terms().keys().next().unwrap().index(), 3);
+        assert_eq!(c.to_terms().keys().next().unwrap().index(), 2);
+        assert!(c.to_terms().keys().next().unwrap().is_public());
+    }
+
+    #[test]
+    fn test_merge_unsatisfied() {
+        let satisfied = multiplication_system(3, 5, 15);
+        let unsatisfied = multiplication_system(7, 11, 78);
+        assert!(!unsatisfied.is_satisfied());
+
+        let merged = satisfied.merge(unsatisfied);
+        assert_eq!(merged.num_constraints(), 4);
+        assert!(!merged.is_satisfied());
+    }
+}
@@ -285,4 +285,60 @@ mod tests {
         let b = Boolean::<Circuit>::new(Mode::Private, true);
         check_and("true AND true", expected, a, b, 0, 0, 1, 1);
     }
+
+    #[test]
+    fn test_and_with_constant_folds() {
+        for mode in [Mode::Public, Mode::Private] {
+            for value in [false, true] {
+                let a = Boolean::<Circuit>::new(mode, value);
+                let identity = Boolean::<Circuit>::new(Mode::Constant, true);
+                let absorbing = Boolean::<Circuit>::new(Mode::Constant, false);
+
+                // `a AND true` is free, and yields `a`.
+                Circuit::scope(format!("{} AND true", mode), || {
+                    for candidate in [&a & &identity, &identity & &a] {
+                        assert_eq!(value, candidate.eject_value());
+                        assert_eq!(mode, candidate.eject_mode());
+                    }
+                    assert_scope!(0, 0, 0, 0);
+                });
+
+                // `a AND false` is free, and yields the constant `false`.
+                Circuit::scope(format!("{} AND false", mode), || {
+                    for candidate in [&a & &absorbing, &absorbing & &a] {
+                        assert!(!candidate.eject_value());
+                        assert_eq!(Mode::Constant, candidate.eject_mode());
+                    }
+                    assert_scope!(0, 0, 0, 0);
+                });
+
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_and_with_false() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for value in [false, true] {
+                    let a = Boolean::<Circuit>::new(mode_a, value);
+                    let b = Boolean::<Circuit>::new(mode_b, false);
+
+                    // `a AND false` is free, unless both operands are variables.
+                    let (num_private, num_constraints) = match mode_a.is_constant() || mode_b.is_constant() {
+                        true => (0, 0),
+                        false => (1, 1),
+                    };
+
+                    let name = format!("{} {} AND {} false", mode_a, value, mode_b);
+                    check_and(&name, false, a.clone(), b.clone(), 0, 0, num_private, num_constraints);
+                    let name = format!("{} false AND {} {}", mode_b, mode_a, value);
+                    check_and(&name, false, b, a, 0, 0, num_private, num_constraints);
+
+                    Circuit::reset();
+                }
+            }
+        }
+    }
 }
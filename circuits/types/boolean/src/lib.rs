@@ -206,7 +206,8 @@ mod tests {
             // Ensure `a` is either 0 or 1:
             // (1 - a) * a = 0
             Circuit::enforce(|| (Circuit::one() - &candidate, candidate, Circuit::zero()));
-            assert_eq!(0, Circuit::num_constraints());
+            assert_eq!(1, Circuit::num_constraints());
+            assert!(!Circuit::is_satisfied());
 
             Circuit::reset();
         }
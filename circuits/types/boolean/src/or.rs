@@ -286,4 +286,35 @@ mod tests {
         let b = Boolean::<Circuit>::new(Mode::Private, true);
         check_or("true OR true", expected, a, b, 0, 0, 1, 1);
     }
+
+    #[test]
+    fn test_or_with_constant_folds() {
+        for mode in [Mode::Public, Mode::Private] {
+            for value in [false, true] {
+                let a = Boolean::<Circuit>::new(mode, value);
+                let identity = Boolean::<Circuit>::new(Mode::Constant, false);
+                let absorbing = Boolean::<Circuit>::new(Mode::Constant, true);
+
+                // `a OR false` is free, and yields `a`.
+                Circuit::scope(format!("{} OR false", mode), || {
+                    for candidate in [&a | &identity, &identity | &a] {
+                        assert_eq!(value, candidate.eject_value());
+                        assert_eq!(mode, candidate.eject_mode());
+                    }
+                    assert_scope!(0, 0, 0, 0);
+                });
+
+                // `a OR true` is free, and yields the constant `true`.
+                Circuit::scope(format!("{} OR true", mode), || {
+                    for candidate in [&a | &absorbing, &absorbing | &a] {
+                        assert!(candidate.eject_value());
+                        assert_eq!(Mode::Constant, candidate.eject_mode());
+                    }
+                    assert_scope!(0, 0, 0, 0);
+                });
+
+                Circuit::reset();
+            }
+        }
+    }
 }
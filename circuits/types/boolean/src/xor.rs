@@ -303,4 +303,25 @@ mod tests {
         let b = Boolean::<Circuit>::new(Mode::Private, true);
         check_xor("true != true", expected, a, b, 0, 0, 1, 1);
     }
+
+    #[test]
+    fn test_xor_with_constant_false_folds() {
+        for mode in [Mode::Public, Mode::Private] {
+            for value in [false, true] {
+                let a = Boolean::<Circuit>::new(mode, value);
+                let identity = Boolean::<Circuit>::new(Mode::Constant, false);
+
+                // `a XOR false` is free, and yields `a`.
+                Circuit::scope(format!("{} XOR false", mode), || {
+                    for candidate in [&a ^ &identity, &identity ^ &a] {
+                        assert_eq!(value, candidate.eject_value());
+                        assert_eq!(mode, candidate.eject_mode());
+                    }
+                    assert_scope!(0, 0, 0, 0);
+                });
+
+                Circuit::reset();
+            }
+        }
+    }
 }
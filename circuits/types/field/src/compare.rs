@@ -21,6 +21,11 @@ impl<E: Environment> Compare<Field<E>> for Field<E> {
 
     /// Returns `true` if `self` is less than `other`.
     fn is_less_than(&self, other: &Self) -> Self::Output {
+        // If both operands are constant, the comparison is a constant.
+        if self.is_constant() && other.is_constant() {
+            return witness!(|self, other| self < other);
+        }
+
         let mut is_less_than = Boolean::constant(false);
         let mut are_previous_bits_equal = Boolean::constant(true);
 
@@ -97,7 +102,7 @@ mod tests {
 
     #[test]
     fn test_constant_is_less_than_constant() {
-        check_is_less_than(Mode::Constant, Mode::Constant, 506, 0, 0, 0);
+        check_is_less_than(Mode::Constant, Mode::Constant, 1, 0, 0, 0);
     }
 
     // TODO (howardwu): These variate in num_constraints.
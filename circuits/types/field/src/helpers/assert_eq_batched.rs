@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    ///
+    /// Asserts that `a` and `b` are equal element-wise, by asserting that the random linear combination
+    /// `Σ challenge^i * (a_i - b_i)` is zero, which is evaluated with Horner's method.
+    ///
+    /// If `a` and `b` differ, the combination is a non-zero polynomial of degree less than `n` in the challenge,
+    /// so the assertion holds with probability at most `(n - 1) / |F|` over a uniformly random challenge.
+    /// The challenge must be sampled independently of `a` and `b` for this bound to apply.
+    ///
+    /// This method costs `n` constraints for a non-constant challenge, and 1 constraint for a constant challenge.
+    ///
+    pub fn assert_eq_batched(a: &[Field<E>], b: &[Field<E>], challenge: &Field<E>) {
+        // Ensure the vectors are of equal length.
+        if a.len() != b.len() {
+            E::halt(format!("Attempted to batch assert equality of vectors of lengths {} and {}", a.len(), b.len()))
+        }
+
+        // Compute `Σ challenge^i * (a_i - b_i)`, starting from the highest power.
+        let combination = a
+            .iter()
+            .rev()
+            .zip_eq(b.iter().rev())
+            .fold(Field::zero(), |accumulator, (a_i, b_i)| accumulator * challenge + (a_i - b_i));
+
+        E::assert_eq(combination, Field::<E>::zero());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_assert_eq_batched(
+        mode_a: Mode,
+        mode_b: Mode,
+        mode_challenge: Mode,
+        length: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            let values = (0..length).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+            let a = values.iter().map(|value| Field::<Circuit>::new(mode_a, *value)).collect::<Vec<_>>();
+            let b = values.iter().map(|value| Field::<Circuit>::new(mode_b, *value)).collect::<Vec<_>>();
+            let challenge = Field::<Circuit>::new(mode_challenge, UniformRand::rand(rng));
+
+            Circuit::scope(format!("{mode_a} == {mode_b} with {mode_challenge} challenge {i}"), || {
+                Field::assert_eq_batched(&a, &b, &challenge);
+                assert!(Circuit::is_satisfied_in_scope());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_assert_eq_batched() {
+        check_assert_eq_batched(Mode::Constant, Mode::Constant, Mode::Constant, 32, 0, 0, 0, 0);
+        check_assert_eq_batched(Mode::Public, Mode::Private, Mode::Constant, 32, 0, 0, 0, 1);
+        check_assert_eq_batched(Mode::Public, Mode::Private, Mode::Public, 32, 0, 0, 31, 32);
+        check_assert_eq_batched(Mode::Private, Mode::Private, Mode::Private, 32, 0, 0, 31, 32);
+    }
+
+    #[test]
+    fn test_assert_eq_batched_empty() {
+        check_assert_eq_batched(Mode::Private, Mode::Private, Mode::Private, 0, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_assert_eq_batched_mismatch() {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            let values = (0..32).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+            let a = values.iter().map(|value| Field::<Circuit>::new(Mode::Private, *value)).collect::<Vec<_>>();
+            let mut b = values.iter().map(|value| Field::<Circuit>::new(Mode::Private, *value)).collect::<Vec<_>>();
+            // Alter a single element.
+            b[i as usize] = Field::new(Mode::Private, UniformRand::rand(rng));
+            let challenge = Field::<Circuit>::new(Mode::Public, UniformRand::rand(rng));
+
+            Circuit::scope(format!("Mismatch {i}"), || {
+                Field::assert_eq_batched(&a, &b, &challenge);
+                assert!(!Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_assert_eq_element_wise() {
+        let rng = &mut test_rng();
+
+        // Compare against asserting equality element-wise.
+        let values = (0..32).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+        let a = values.iter().map(|value| Field::<Circuit>::new(Mode::Public, *value)).collect::<Vec<_>>();
+        let b = values.iter().map(|value| Field::<Circuit>::new(Mode::Private, *value)).collect::<Vec<_>>();
+
+        Circuit::scope("Element-wise", || {
+            a.iter().zip_eq(&b).for_each(|(a_i, b_i)| Circuit::assert_eq(a_i, b_i));
+            assert_scope!(0, 0, 0, 32);
+        });
+        Circuit::reset();
+    }
+}
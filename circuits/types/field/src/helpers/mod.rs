@@ -16,6 +16,7 @@
 
 use super::*;
 
+pub mod assert_eq_batched;
 pub mod double;
 pub mod from_bits;
 pub mod from_boolean;
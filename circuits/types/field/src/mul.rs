@@ -234,6 +234,30 @@ mod tests {
         run_test(Mode::Private, Mode::Private);
     }
 
+    #[test]
+    fn test_mul_by_zero() {
+        let zero = <Circuit as Environment>::BaseField::zero();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                let a = Field::<Circuit>::new(mode_a, UniformRand::rand(&mut test_rng()));
+                let b = Field::<Circuit>::new(mode_b, zero);
+
+                // `a * 0` and `0 * a` are free, unless both operands are variables.
+                Circuit::scope(format!("{} * {} zero", mode_a, mode_b), || {
+                    for candidate in [&a * &b, &b * &a] {
+                        assert_eq!(zero, candidate.eject_value());
+                    }
+                    match mode_a.is_constant() || mode_b.is_constant() {
+                        true => assert_scope!(0, 0, 0, 0),
+                        false => assert_scope!(0, 0, 2, 2),
+                    }
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
     #[test]
     fn test_mul_matches() {
         // Sample two random elements.
@@ -25,6 +25,53 @@ impl<E: Environment> Group<E> {
 
         Self::from_xy_coordinates(x, y)
     }
+
+    ///
+    /// Returns an affine group element on the curve from a given x-coordinate field element,
+    /// and a boolean that is `true` if the x-coordinate corresponds to a point on the curve.
+    /// If there is no such point, the zero group element is returned.
+    ///
+    /// Unlike `Group::from_x_coordinate`, the point is not required to be in the prime-order subgroup.
+    /// The non-existence of a point is proven by witnessing a square root of `n * y^2`,
+    /// where `n` is a fixed quadratic non-residue in the base field.
+    ///
+    pub fn from_x_coordinate_flagged(x: Field<E>) -> (Self, Boolean<E>) {
+        let a = Field::constant(E::AffineParameters::COEFF_A);
+        let d = Field::constant(E::AffineParameters::COEFF_D);
+        // The 2-adic root of unity generates the 2-Sylow subgroup, and is a quadratic non-residue.
+        let non_residue = E::BaseField::two_adic_root_of_unity();
+
+        // Determine if the x-coordinate corresponds to a point on the curve.
+        let is_on_curve: Boolean<E> = witness!(|x| E::Affine::from_x_coordinate(x, true).is_some());
+
+        // Witness the (greatest) y-coordinate, or a square root of `n * y^2` if there is no such point.
+        let y: Field<E> = witness!(|x| match E::Affine::from_x_coordinate(x, true) {
+            Some(point) => point.to_y_coordinate(),
+            None => {
+                let x2 = x.square();
+                let y2 = (E::AffineParameters::COEFF_A * x2 - E::BaseField::one())
+                    / (E::AffineParameters::COEFF_D * x2 - E::BaseField::one());
+                square_root::<E::AffineParameters>(non_residue * y2)
+            }
+        });
+
+        // Ensure y^2 * (dx^2 - 1) = (ax^2 - 1) * (is_on_curve ? 1 : n).
+        let x2 = x.square();
+        let multiplier = Field::ternary(&is_on_curve, &Field::one(), &Field::constant(non_residue));
+        E::enforce(|| (y.square(), (d * &x2) - Field::one(), ((a * x2) - Field::one()) * multiplier));
+
+        // Select the point if it is on the curve, or the zero group element otherwise.
+        let point = Group::ternary(&is_on_curve, &Group { x, y }, &Group::zero());
+        (point, is_on_curve)
+    }
+}
+
+/// Returns a square root of the given quadratic residue in the base field of the curve.
+fn square_root<P: TwistedEdwardsParameters>(element: P::BaseField) -> P::BaseField {
+    match element.sqrt() {
+        Some(root) => root,
+        None => P::BaseField::zero(),
+    }
 }
 
 #[cfg(test)]
@@ -76,4 +123,54 @@ mod tests {
     fn test_from_x_coordinate_private() {
         check_from_x_coordinate(Mode::Private, 2, 0, 3, 3);
     }
+
+    fn check_from_x_coordinate_flagged(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut num_on_curve = 0;
+        for i in 0..ITERATIONS {
+            // Sample a random x-coordinate, which corresponds to a point on the curve with probability 1/2.
+            let x: <Circuit as Environment>::BaseField = UniformRand::rand(&mut test_rng());
+            let expected = <Circuit as Environment>::Affine::from_x_coordinate(x, true);
+
+            let x_coordinate = Field::new(mode, x);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let (candidate, is_on_curve) = Group::<Circuit>::from_x_coordinate_flagged(x_coordinate);
+                assert_eq!(expected.is_some(), is_on_curve.eject_value());
+                match expected {
+                    // The y-coordinate is either of the two square roots.
+                    Some(expected) => {
+                        assert_eq!(expected.to_x_coordinate(), candidate.eject_value().to_x_coordinate());
+                        assert!(candidate.eject_value().is_on_curve());
+                        num_on_curve += 1;
+                    }
+                    None => assert!(candidate.eject_value().is_zero()),
+                }
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+        // Ensure both cases were exercised.
+        assert!(num_on_curve > 0 && num_on_curve < ITERATIONS);
+    }
+
+    #[test]
+    fn test_from_x_coordinate_flagged_constant() {
+        check_from_x_coordinate_flagged(Mode::Constant, 5, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_x_coordinate_flagged_public() {
+        check_from_x_coordinate_flagged(Mode::Public, 3, 0, 7, 7);
+    }
+
+    #[test]
+    fn test_from_x_coordinate_flagged_private() {
+        check_from_x_coordinate_flagged(Mode::Private, 3, 0, 7, 7);
+    }
 }
@@ -23,6 +23,8 @@ pub mod add;
 pub mod double;
 pub mod equal;
 pub mod mul;
+pub mod mul_by_generator;
+pub mod mul_windowed;
 pub mod neg;
 pub mod sub;
 pub mod ternary;
@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm_curves::ProjectiveCurve;
+
+use core::{
+    any::{Any, TypeId},
+    cell::RefCell,
+};
+use std::{collections::HashMap, rc::Rc};
+
+/// The number of scalar bits consumed by each lookup into the generator table.
+const WINDOW_SIZE: usize = 2;
+
+thread_local! {
+    /// The native generator tables, for each curve, which are computed on the first multiplication by the generator.
+    static GENERATOR_TABLES: RefCell<HashMap<TypeId, Rc<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+impl<E: Environment> Group<E> {
+    ///
+    /// Returns `scalar * G`, where `G` is the prime subgroup generator.
+    ///
+    /// For each window of 2 bits, the multiples `[0, 1, 2, 3] * 4^i * G` are precomputed natively
+    /// and injected as constants, so the product reduces to one lookup and one addition per window,
+    /// without any doublings.
+    ///
+    pub fn mul_by_generator(scalar: &Scalar<E>) -> Self {
+        // If the scalar is constant, compute the product natively.
+        if scalar.is_constant() {
+            let generator = E::Affine::prime_subgroup_generator();
+            let bits_be = scalar.to_bits_be().iter().map(|bit| bit.eject_value()).collect::<Vec<_>>();
+            return Group::constant(generator.mul_bits(bits_be.into_iter()).to_affine());
+        }
+
+        let mut output = Group::zero();
+        for (window, table) in scalar.to_bits_le().chunks(WINDOW_SIZE).zip_eq(Self::generator_table()) {
+            // Select the multiple corresponding to the window.
+            let multiple = match window {
+                [lsb] => Group::ternary(lsb, &table[1], &table[0]),
+                [lsb, msb] => {
+                    let select_low = Group::ternary(lsb, &table[1], &table[0]);
+                    let select_high = Group::ternary(lsb, &table[3], &table[2]);
+                    Group::ternary(msb, &select_high, &select_low)
+                }
+                _ => E::halt("Malformed window detected during multiplication by the generator"),
+            };
+
+            output = &output + &multiple;
+        }
+        output
+    }
+
+    /// Returns the constant lookup tables `[0, 1, 2, 3] * 4^i * G`, for each window `i` of the scalar.
+    fn generator_table() -> Vec<[Self; 1 << WINDOW_SIZE]> {
+        Self::native_generator_table()
+            .iter()
+            .map(|table| {
+                [Group::zero(), Group::constant(table[0]), Group::constant(table[1]), Group::constant(table[2])]
+            })
+            .collect()
+    }
+
+    /// Returns the native multiples `[1, 2, 3] * 4^i * G`, for each window `i` of the scalar.
+    /// The multiples are computed once per thread for each curve, and are cached for subsequent calls.
+    fn native_generator_table() -> Rc<Vec<[E::Affine; (1 << WINDOW_SIZE) - 1]>> {
+        GENERATOR_TABLES.with(|tables| {
+            let table = tables
+                .borrow_mut()
+                .entry(TypeId::of::<E::Affine>())
+                .or_insert_with(|| Rc::new(Self::compute_native_generator_table()))
+                .clone();
+            match table.downcast() {
+                Ok(table) => table,
+                Err(_) => E::halt("Mismatching generator table detected during multiplication by the generator"),
+            }
+        })
+    }
+
+    /// Computes the native multiples `[1, 2, 3] * 4^i * G`, for each window `i` of the scalar.
+    fn compute_native_generator_table() -> Vec<[E::Affine; (1 << WINDOW_SIZE) - 1]> {
+        let num_windows = (E::ScalarField::size_in_bits() + WINDOW_SIZE - 1) / WINDOW_SIZE;
+
+        let mut base = E::Affine::prime_subgroup_generator().to_projective();
+        let mut tables = Vec::with_capacity(num_windows);
+        for _ in 0..num_windows {
+            let double = base.double();
+            let triple = double + base;
+            tables.push([base.to_affine(), double.to_affine(), triple.to_affine()]);
+
+            // Shift the base by the window size.
+            for _ in 0..WINDOW_SIZE {
+                base.double_in_place();
+            }
+        }
+        tables
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_mul_by_generator(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let generator = <Circuit as Environment>::Affine::prime_subgroup_generator();
+
+        for i in 0..ITERATIONS {
+            let scalar: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+            let expected = (generator * scalar).to_affine();
+
+            let a = Scalar::<Circuit>::new(mode, scalar);
+
+            Circuit::scope(format!("MulByGenerator: {mode} {i}"), || {
+                let candidate = Group::mul_by_generator(&a);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_mul_by_generator_constant() {
+        check_mul_by_generator(Mode::Constant, 4, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_mul_by_generator_public() {
+        check_mul_by_generator(Mode::Public, 1762, 0, 1000, 1000);
+    }
+
+    #[test]
+    fn test_mul_by_generator_private() {
+        check_mul_by_generator(Mode::Private, 1762, 0, 1000, 1000);
+    }
+
+    #[test]
+    fn test_mul_by_generator_edge_cases() {
+        let generator = <Circuit as Environment>::Affine::prime_subgroup_generator();
+
+        for (name, scalar) in [
+            ("Zero", <Circuit as Environment>::ScalarField::zero()),
+            ("One", <Circuit as Environment>::ScalarField::one()),
+            ("MinusOne", -<Circuit as Environment>::ScalarField::one()),
+        ] {
+            let a = Scalar::<Circuit>::new(Mode::Private, scalar);
+            Circuit::scope(name, || {
+                let candidate = Group::mul_by_generator(&a);
+                assert_eq!((generator * scalar).to_affine(), candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_mul_by_generator_is_cheaper() {
+        let generator = <Circuit as Environment>::Affine::prime_subgroup_generator();
+        let scalar: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+
+        let a = Scalar::<Circuit>::new(Mode::Private, scalar);
+
+        // Measure the cost of the variable-base multiplication on a constant generator.
+        let variable_base = Circuit::scope("Mul", || {
+            let candidate = Group::constant(generator) * &a;
+            assert_eq!((generator * scalar).to_affine(), candidate.eject_value());
+            Circuit::num_constraints_in_scope()
+        });
+        Circuit::reset();
+
+        // Measure the cost of the fixed-base multiplication.
+        let fixed_base = Circuit::scope("MulByGenerator", || {
+            let candidate = Group::mul_by_generator(&a);
+            assert_eq!((generator * scalar).to_affine(), candidate.eject_value());
+            Circuit::num_constraints_in_scope()
+        });
+        Circuit::reset();
+
+        // Ensure the fixed-base multiplication uses fewer constraints.
+        assert!(fixed_base < variable_base);
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of scalar bits consumed in each iteration of the windowed multiplication.
+const WINDOW_SIZE: usize = 2;
+
+impl<E: Environment> Group<E> {
+    /// Returns `self * scalar`, using a windowed double-and-add over the scalar bits.
+    pub fn mul_windowed(&self, scalar: &Scalar<E>) -> Self {
+        self.mul_windowed_bits_be(&scalar.to_bits_be())
+    }
+
+    ///
+    /// Returns `self * bits_be`, using a windowed double-and-add over the given big-endian bits.
+    ///
+    /// The multiples `[0, 1, 2, 3] * self` are precomputed once, and each window of 2 bits
+    /// selects its multiple from this table. In comparison to `Group::mul`, this performs one
+    /// addition per window instead of one addition per bit.
+    ///
+    pub fn mul_windowed_bits_be(&self, bits_be: &[Boolean<E>]) -> Self {
+        // If `self` and the bits are constant, the double-and-add folds entirely into constants.
+        if self.is_constant() && bits_be.iter().all(|bit| bit.is_constant()) {
+            return self * bits_be;
+        }
+
+        // Precompute the table of multiples: `[0, 1, 2, 3] * self`.
+        let double = self.double();
+        let triple = &double + self;
+        let table = [Group::zero(), self.clone(), double, triple];
+
+        // Pad the bits with leading zeros, so that they divide evenly into windows.
+        let num_padding_bits = (WINDOW_SIZE - bits_be.len() % WINDOW_SIZE) % WINDOW_SIZE;
+        let bits_be = core::iter::repeat(Boolean::constant(false))
+            .take(num_padding_bits)
+            .chain(bits_be.iter().cloned())
+            .collect::<Vec<_>>();
+
+        let mut output = Group::zero();
+        for window in bits_be.chunks(WINDOW_SIZE) {
+            // Shift the output by the window size.
+            output = output.double().double();
+
+            // Select the multiple corresponding to the window, from the most significant bit.
+            let (msb, lsb) = (&window[0], &window[1]);
+            let select_low = Group::ternary(lsb, &table[1], &table[0]);
+            let select_high = Group::ternary(lsb, &table[3], &table[2]);
+            let multiple = Group::ternary(msb, &select_high, &select_low);
+
+            output = &output + &multiple;
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_curves::ProjectiveCurve;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_mul_windowed(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        for i in 0..ITERATIONS {
+            let base: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+            let scalar: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+            let expected = (base * scalar).to_affine();
+
+            let a = Group::<Circuit>::new(mode_a, base);
+            let b = Scalar::<Circuit>::new(mode_b, scalar);
+
+            Circuit::scope(format!("MulWindowed: {mode_a} * {mode_b} {i}"), || {
+                let candidate = a.mul_windowed(&b);
+                assert_eq!(expected, candidate.eject_value(), "({} * {})", a.eject_value(), b.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[allow(clippy::identity_op)]
+    #[test]
+    fn test_constant_times_scalar_constant() {
+        use snarkvm_utilities::BigInteger;
+
+        for i in 0..ITERATIONS {
+            let base: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+            let scalar: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+            let expected = (base * scalar).to_affine();
+
+            // The constant case defers to the double-and-add in `Group::mul`.
+            let num_nonzero_bits = scalar.to_repr().to_biguint().bits();
+            let num_constant =
+                (3 /* DOUBLE private */ + 4/* public ADD private */ + 0/* TERNARY */) * (num_nonzero_bits - 1);
+
+            let a = Group::<Circuit>::new(Mode::Constant, base);
+            let b = Scalar::<Circuit>::new(Mode::Constant, scalar);
+
+            Circuit::scope(format!("MulWindowed: Constant * Constant {i}"), || {
+                let candidate = a.mul_windowed(&b);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constant, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_constant_times_scalar_public() {
+        check_mul_windowed(Mode::Constant, Mode::Public, 507, 0, 2250, 2250);
+    }
+
+    #[test]
+    fn test_constant_times_scalar_private() {
+        check_mul_windowed(Mode::Constant, Mode::Private, 507, 0, 2250, 2250);
+    }
+
+    #[test]
+    fn test_public_times_scalar_public() {
+        check_mul_windowed(Mode::Public, Mode::Public, 503, 0, 2765, 2765);
+    }
+
+    #[test]
+    fn test_public_times_scalar_private() {
+        check_mul_windowed(Mode::Public, Mode::Private, 503, 0, 2765, 2765);
+    }
+
+    #[test]
+    fn test_private_times_scalar_public() {
+        check_mul_windowed(Mode::Private, Mode::Public, 503, 0, 2765, 2765);
+    }
+
+    #[test]
+    fn test_private_times_scalar_private() {
+        check_mul_windowed(Mode::Private, Mode::Private, 503, 0, 2765, 2765);
+    }
+
+    #[test]
+    fn test_mul_windowed_is_cheaper() {
+        let base: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+        let scalar: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+
+        let a = Group::<Circuit>::new(Mode::Private, base);
+        let b = Scalar::<Circuit>::new(Mode::Private, scalar);
+
+        // Measure the cost of the naive double-and-add.
+        let naive = Circuit::scope("Mul", || {
+            let candidate = &a * &b;
+            assert_eq!((base * scalar).to_affine(), candidate.eject_value());
+            Circuit::num_constraints_in_scope()
+        });
+        Circuit::reset();
+
+        // Measure the cost of the windowed multiplication.
+        let windowed = Circuit::scope("MulWindowed", || {
+            let candidate = a.mul_windowed(&b);
+            assert_eq!((base * scalar).to_affine(), candidate.eject_value());
+            Circuit::num_constraints_in_scope()
+        });
+        Circuit::reset();
+
+        // Ensure the windowed multiplication uses fewer constraints.
+        assert!(windowed < naive);
+    }
+}
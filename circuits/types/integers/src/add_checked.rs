@@ -75,6 +75,12 @@ impl<E: Environment, I: IntegerType> AddChecked<Self> for Integer<E, I> {
                 Some(value) => Integer::constant(value),
                 None => E::halt("Integer overflow on addition of two constants"),
             }
+        } else if self.is_constant() && self.eject_value().is_zero() {
+            // If `self` is the constant zero, the sum is `other`, which cannot overflow.
+            other.clone()
+        } else if other.is_constant() && other.eject_value().is_zero() {
+            // If `other` is the constant zero, the sum is `self`, which cannot overflow.
+            self.clone()
         } else {
             // Instead of adding the bits of `self` and `other` directly, the integers are
             // converted into a field elements, and summed, before converting back to integers.
@@ -110,7 +116,7 @@ impl<E: Environment, I: IntegerType> AddChecked<Self> for Integer<E, I> {
 }
 
 impl<E: Environment, I: IntegerType> Metrics<dyn Add<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn count(case: &Self::Case) -> Count {
         <Self as Metrics<dyn AddChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(case)
@@ -118,7 +124,7 @@ impl<E: Environment, I: IntegerType> Metrics<dyn Add<Integer<E, I>, Output = Int
 }
 
 impl<E: Environment, I: IntegerType> OutputMode<dyn Add<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn output_mode(case: &Self::Case) -> Mode {
         <Self as OutputMode<dyn AddChecked<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(case)
@@ -126,18 +132,26 @@ impl<E: Environment, I: IntegerType> OutputMode<dyn Add<Integer<E, I>, Output =
 }
 
 impl<E: Environment, I: IntegerType> Metrics<dyn AddChecked<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn count(case: &Self::Case) -> Count {
+        // Determine if either operand is the constant zero.
+        let is_identity =
+            case.0.is_constant_and(|value| value.is_zero()) || case.1.is_constant_and(|value| value.is_zero());
+
         match I::is_signed() {
-            true => match (case.0, case.1) {
+            true => match (case.0.mode(), case.1.mode()) {
                 (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                // Adding the constant zero is free.
+                _ if is_identity => Count::is(0, 0, 0, 0),
                 (Mode::Constant, _) => Count::is(0, 0, I::BITS + 2, I::BITS + 4),
                 (_, Mode::Constant) => Count::is(0, 0, I::BITS + 3, I::BITS + 5),
                 (_, _) => Count::is(0, 0, I::BITS + 4, I::BITS + 6),
             },
-            false => match (case.0, case.1) {
+            false => match (case.0.mode(), case.1.mode()) {
                 (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                // Adding the constant zero is free.
+                _ if is_identity => Count::is(0, 0, 0, 0),
                 (_, _) => Count::is(0, 0, I::BITS + 1, I::BITS + 3),
             },
         }
@@ -147,11 +161,14 @@ impl<E: Environment, I: IntegerType> Metrics<dyn AddChecked<Integer<E, I>, Outpu
 impl<E: Environment, I: IntegerType> OutputMode<dyn AddChecked<Integer<E, I>, Output = Integer<E, I>>>
     for Integer<E, I>
 {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        match (case.0, case.1) {
+        match (case.0.mode(), case.1.mode()) {
             (Mode::Constant, Mode::Constant) => Mode::Constant,
+            // Adding the constant zero preserves the mode of the other operand.
+            (Mode::Constant, mode_b) if case.0.is_constant_and(|value| value.is_zero()) => mode_b,
+            (mode_a, Mode::Constant) if case.1.is_constant_and(|value| value.is_zero()) => mode_a,
             (_, _) => Mode::Private,
         }
     }
@@ -176,14 +193,25 @@ mod tests {
             Some(expected) => Circuit::scope(name, || {
                 let candidate = a.add_checked(&b);
                 assert_eq!(expected, candidate.eject_value());
-                assert_count!(Add(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
-                assert_output_mode!(Add(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+                assert_count!(Add(Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&a), CircuitType::from(&b)));
+                assert_output_mode!(Add(Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&a), CircuitType::from(&b)), candidate);
+                // Adding the constant zero is free, and yields the other operand.
+                match (CircuitType::from(&a), CircuitType::from(&b)) {
+                    (CircuitType::Constant(_), CircuitType::Constant(_)) => (),
+                    (case_a, _) if case_a.is_constant_and(|value| value.is_zero()) => {
+                        check_operation_folds(&b, &candidate)
+                    }
+                    (_, case_b) if case_b.is_constant_and(|value| value.is_zero()) => {
+                        check_operation_folds(&a, &candidate)
+                    }
+                    _ => (),
+                }
             }),
             None => match mode_a.is_constant() && mode_b.is_constant() {
                 true => check_operation_halts(&a, &b, Integer::add_checked),
                 false => Circuit::scope(name, || {
                     let _candidate = a.add_checked(&b);
-                    assert_count_fails!(Add(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                    assert_count_fails!(Add(Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&a), CircuitType::from(&b)));
                 }),
             },
         }
@@ -198,6 +226,10 @@ mod tests {
             let name = format!("Add: {} + {} {}", mode_a, mode_b, i);
             check_add(&name, first, second, mode_a, mode_b);
             check_add(&name, second, first, mode_a, mode_b); // Commute the operation.
+
+            let name = format!("Add Identity: {} + {} {}", mode_a, mode_b, i);
+            check_add(&name, first, I::zero(), mode_a, mode_b);
+            check_add(&name, I::zero(), first, mode_a, mode_b); // Commute the operation.
         }
 
         // Overflow
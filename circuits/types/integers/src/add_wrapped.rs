@@ -25,6 +25,12 @@ impl<E: Environment, I: IntegerType> AddWrapped<Self> for Integer<E, I> {
         if self.is_constant() && other.is_constant() {
             // Compute the sum and return the new constant.
             Integer::new(Mode::Constant, self.eject_value().wrapping_add(&other.eject_value()))
+        } else if self.is_constant() && self.eject_value().is_zero() {
+            // If `self` is the constant zero, the sum is `other`.
+            other.clone()
+        } else if other.is_constant() && other.eject_value().is_zero() {
+            // If `other` is the constant zero, the sum is `self`.
+            self.clone()
         } else {
             // Instead of adding the bits of `self` and `other` directly, the integers are
             // converted into a field elements, and summed, before converting back to integers.
@@ -43,11 +49,17 @@ impl<E: Environment, I: IntegerType> AddWrapped<Self> for Integer<E, I> {
 }
 
 impl<E: Environment, I: IntegerType> Metrics<dyn AddWrapped<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn count(case: &Self::Case) -> Count {
-        match (case.0, case.1) {
+        // Determine if either operand is the constant zero.
+        let is_identity =
+            case.0.is_constant_and(|value| value.is_zero()) || case.1.is_constant_and(|value| value.is_zero());
+
+        match (case.0.mode(), case.1.mode()) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+            // Adding the constant zero is free.
+            _ if is_identity => Count::is(0, 0, 0, 0),
             (_, _) => Count::is(0, 0, I::BITS + 1, I::BITS + 2),
         }
     }
@@ -56,11 +68,14 @@ impl<E: Environment, I: IntegerType> Metrics<dyn AddWrapped<Integer<E, I>, Outpu
 impl<E: Environment, I: IntegerType> OutputMode<dyn AddWrapped<Integer<E, I>, Output = Integer<E, I>>>
     for Integer<E, I>
 {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        match (case.0, case.1) {
+        match (case.0.mode(), case.1.mode()) {
             (Mode::Constant, Mode::Constant) => Mode::Constant,
+            // Adding the constant zero preserves the mode of the other operand.
+            (Mode::Constant, mode_b) if case.0.is_constant_and(|value| value.is_zero()) => mode_b,
+            (mode_a, Mode::Constant) if case.1.is_constant_and(|value| value.is_zero()) => mode_a,
             (_, _) => Mode::Private,
         }
     }
@@ -71,6 +86,7 @@ mod tests {
     use super::*;
     use snarkvm_circuits_environment::Circuit;
     use snarkvm_utilities::{test_rng, UniformRand};
+    use test_utilities::*;
 
     use core::ops::RangeInclusive;
 
@@ -83,8 +99,16 @@ mod tests {
         Circuit::scope(name, || {
             let candidate = a.add_wrapped(&b);
             assert_eq!(expected, candidate.eject_value());
-            assert_count!(AddWrapped(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
-            assert_output_mode!(AddWrapped(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+            let case = (CircuitType::from(&a), CircuitType::from(&b));
+            assert_count!(AddWrapped(Integer<I>, Integer<I>) => Integer<I>, &case);
+            assert_output_mode!(AddWrapped(Integer<I>, Integer<I>) => Integer<I>, &case, candidate);
+            // Adding the constant zero is free, and yields the other operand.
+            match (CircuitType::from(&a), CircuitType::from(&b)) {
+                (CircuitType::Constant(_), CircuitType::Constant(_)) => (),
+                (case_a, _) if case_a.is_constant_and(|value| value.is_zero()) => check_operation_folds(&b, &candidate),
+                (_, case_b) if case_b.is_constant_and(|value| value.is_zero()) => check_operation_folds(&a, &candidate),
+                _ => (),
+            }
         });
         Circuit::reset();
     }
@@ -97,6 +121,10 @@ mod tests {
             let name = format!("Add: {} + {} {}", mode_a, mode_b, i);
             check_add(&name, first, second, mode_a, mode_b);
             check_add(&name, second, first, mode_a, mode_b); // Commute the operation.
+
+            let name = format!("Add Identity: {} + {} {}", mode_a, mode_b, i);
+            check_add(&name, first, I::zero(), mode_a, mode_b);
+            check_add(&name, I::zero(), first, mode_a, mode_b); // Commute the operation.
         }
 
         // Overflow
@@ -156,6 +156,24 @@ mod tests {
 
             let name = format!("Compare: ({}, {}) - {}th iteration", mode_a, mode_b, i);
             check_compare(&name, first, second, mode_a, mode_b);
+            check_compare(&name, second, first, mode_a, mode_b); // Commute the operation.
+            check_compare(&name, first, first, mode_a, mode_b); // Compare against itself.
+        }
+
+        // Check the boundary cases.
+        check_compare("MIN < MAX", I::MIN, I::MAX, mode_a, mode_b);
+        check_compare("MAX < MIN", I::MAX, I::MIN, mode_a, mode_b);
+        check_compare("MIN < MIN", I::MIN, I::MIN, mode_a, mode_b);
+        check_compare("MAX < MAX", I::MAX, I::MAX, mode_a, mode_b);
+        check_compare("0 < MAX", I::zero(), I::MAX, mode_a, mode_b);
+        check_compare("MIN < 0", I::MIN, I::zero(), mode_a, mode_b);
+        check_compare("MAX - 1 < MAX", I::MAX - I::one(), I::MAX, mode_a, mode_b);
+        check_compare("MIN < MIN + 1", I::MIN, I::MIN + I::one(), mode_a, mode_b);
+
+        // Check the sign boundary for signed integers.
+        if I::is_signed() {
+            check_compare("-1 < 0", I::zero() - I::one(), I::zero(), mode_a, mode_b);
+            check_compare("0 < -1", I::zero(), I::zero() - I::one(), mode_a, mode_b);
         }
     }
 
@@ -183,6 +201,6 @@ mod tests {
     test_integer_binary!(run_test, u64, compare_with);
     test_integer_binary!(run_test, u128, compare_with);
 
-    test_integer_binary!(#[ignore], run_exhaustive_test, u8, bitand, exhaustive);
-    test_integer_binary!(#[ignore], run_exhaustive_test, i8, bitand, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, compare_with, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, compare_with, exhaustive);
 }
@@ -490,6 +490,9 @@ mod tests {
 
 #[cfg(test)]
 mod test_utilities {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+
     use core::panic::UnwindSafe;
 
     /// A generic template for an integer test case.
@@ -711,6 +714,14 @@ mod test_utilities {
         assert!(result.is_err());
     }
 
+    /// Checks that an operation with an identity (or absorbing) constant operand is free, and yields `expected` unchanged.
+    /// Note: This must be called within the scope of the operation.
+    pub fn check_operation_folds<I: IntegerType>(expected: &Integer<Circuit, I>, candidate: &Integer<Circuit, I>) {
+        assert_eq!(expected.eject_value(), candidate.eject_value());
+        assert_eq!(expected.eject_mode(), candidate.eject_mode());
+        assert_scope!(0, 0, 0, 0);
+    }
+
     pub fn check_unary_operation_halts<IN: UnwindSafe, OUT>(input: IN, operation: impl FnOnce(IN) -> OUT + UnwindSafe) {
         let result = std::panic::catch_unwind(|| operation(input));
         assert!(result.is_err());
@@ -64,18 +64,18 @@ impl<E: Environment, I: IntegerType> MulAssign<&Integer<E, I>> for Integer<E, I>
 }
 
 impl<E: Environment, I: IntegerType> Metrics<dyn Mul<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn count(case: &Self::Case) -> Count {
-        <Self as Metrics<dyn DivChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(case)
+        <Self as Metrics<dyn MulChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(case)
     }
 }
 
 impl<E: Environment, I: IntegerType> OutputMode<dyn Mul<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        <Self as OutputMode<dyn DivChecked<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(case)
+        <Self as OutputMode<dyn MulChecked<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(case)
     }
 }
 
@@ -85,7 +85,19 @@ impl<E: Environment, I: IntegerType> MulChecked<Self> for Integer<E, I> {
     #[inline]
     fn mul_checked(&self, other: &Integer<E, I>) -> Self::Output {
         // Determine the variable mode.
-        if self.is_constant() && other.is_constant() {
+        if self.is_constant() && self.eject_value().is_zero() {
+            // If `self` is the constant zero, the product is `self`.
+            self.clone()
+        } else if other.is_constant() && other.eject_value().is_zero() {
+            // If `other` is the constant zero, the product is `other`.
+            other.clone()
+        } else if self.is_constant() && self.eject_value().is_one() {
+            // If `self` is the constant one, the product is `other`, which cannot overflow.
+            other.clone()
+        } else if other.is_constant() && other.eject_value().is_one() {
+            // If `other` is the constant one, the product is `self`, which cannot overflow.
+            self.clone()
+        } else if self.is_constant() && other.is_constant() {
             // Compute the product and return the new constant.
             match self.eject_value().checked_mul(&other.eject_value()) {
                 Some(value) => Integer::new(Mode::Constant, value),
@@ -185,14 +197,21 @@ impl<E: Environment, I: IntegerType> Integer<E, I> {
 }
 
 impl<E: Environment, I: IntegerType> Metrics<dyn MulChecked<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn count(case: &Self::Case) -> Count {
+        // Determine if either operand is the constant zero or the constant one.
+        let is_zero =
+            case.0.is_constant_and(|value| value.is_zero()) || case.1.is_constant_and(|value| value.is_zero());
+        let is_one = case.0.is_constant_and(|value| value.is_one()) || case.1.is_constant_and(|value| value.is_one());
+
         // Case 1 - 2 integers fit in 1 field element (u8, u16, u32, u64, i8, i16, i32, i64).
         if 2 * I::BITS < (E::BaseField::size_in_bits() - 1) as u64 {
             match I::is_signed() {
                 // Signed case
-                true => match (case.0, case.1) {
+                true => match (case.0.mode(), case.1.mode()) {
+                    // Multiplying by the constant zero or one is free.
+                    _ if is_zero || is_one => Count::is(0, 0, 0, 0),
                     (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
                     (Mode::Constant, _) | (_, Mode::Constant) => {
                         Count::is(4 * I::BITS, 0, (8 * I::BITS) + 5, (8 * I::BITS) + 9)
@@ -200,7 +219,9 @@ impl<E: Environment, I: IntegerType> Metrics<dyn MulChecked<Integer<E, I>, Outpu
                     (_, _) => Count::is(3 * I::BITS, 0, (10 * I::BITS) + 8, (10 * I::BITS) + 13),
                 },
                 // Unsigned case
-                false => match (case.0, case.1) {
+                false => match (case.0.mode(), case.1.mode()) {
+                    // Multiplying by the constant zero or one is free.
+                    _ if is_zero || is_one => Count::is(0, 0, 0, 0),
                     (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
                     (Mode::Constant, _) | (_, Mode::Constant) => Count::is(0, 0, (3 * I::BITS) - 1, (3 * I::BITS) + 1),
                     (_, _) => Count::is(0, 0, 3 * I::BITS, (3 * I::BITS) + 2),
@@ -211,7 +232,9 @@ impl<E: Environment, I: IntegerType> Metrics<dyn MulChecked<Integer<E, I>, Outpu
         else if (I::BITS + I::BITS / 2) < (E::BaseField::size_in_bits() - 1) as u64 {
             match I::is_signed() {
                 // Signed case
-                true => match (case.0, case.1) {
+                true => match (case.0.mode(), case.1.mode()) {
+                    // Multiplying by the constant zero or one is free.
+                    _ if is_zero || is_one => Count::is(0, 0, 0, 0),
                     (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
                     (Mode::Constant, _) | (_, Mode::Constant) => {
                         Count::is(4 * I::BITS, 0, (9 * I::BITS) + 7, (9 * I::BITS) + 12)
@@ -219,7 +242,9 @@ impl<E: Environment, I: IntegerType> Metrics<dyn MulChecked<Integer<E, I>, Outpu
                     (_, _) => Count::is(3 * I::BITS, 0, (11 * I::BITS) + 13, (11 * I::BITS) + 19),
                 },
                 // Unsigned case
-                false => match (case.0, case.1) {
+                false => match (case.0.mode(), case.1.mode()) {
+                    // Multiplying by the constant zero or one is free.
+                    _ if is_zero || is_one => Count::is(0, 0, 0, 0),
                     (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
                     (Mode::Constant, _) | (_, Mode::Constant) => Count::is(0, 0, (4 * I::BITS) + 1, (4 * I::BITS) + 4),
                     (_, _) => Count::is(0, 0, (4 * I::BITS) + 5, (4 * I::BITS) + 8),
@@ -234,11 +259,18 @@ impl<E: Environment, I: IntegerType> Metrics<dyn MulChecked<Integer<E, I>, Outpu
 impl<E: Environment, I: IntegerType> OutputMode<dyn MulChecked<Integer<E, I>, Output = Integer<E, I>>>
     for Integer<E, I>
 {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        match (case.0, case.1) {
+        match (case.0.mode(), case.1.mode()) {
             (Mode::Constant, Mode::Constant) => Mode::Constant,
+            // Multiplying by the constant zero yields the constant zero.
+            _ if case.0.is_constant_and(|value| value.is_zero()) || case.1.is_constant_and(|value| value.is_zero()) => {
+                Mode::Constant
+            }
+            // Multiplying by the constant one preserves the mode of the other operand.
+            (Mode::Constant, mode_b) if case.0.is_constant_and(|value| value.is_one()) => mode_b,
+            (mode_a, Mode::Constant) if case.1.is_constant_and(|value| value.is_one()) => mode_a,
             (_, _) => Mode::Private,
         }
     }
@@ -262,14 +294,30 @@ mod tests {
             Some(expected) => Circuit::scope(name, || {
                 let candidate = a.mul_checked(&b);
                 assert_eq!(expected, candidate.eject_value());
-                assert_count!(MulChecked(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
-                assert_output_mode!(MulChecked(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+                assert_count!(MulChecked(Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&a), CircuitType::from(&b)));
+                assert_output_mode!(MulChecked(Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&a), CircuitType::from(&b)), candidate);
+                // Multiplying by the constant zero or one is free, and yields the zero or the other operand, respectively.
+                match (CircuitType::from(&a), CircuitType::from(&b)) {
+                    (case_a, _) if case_a.is_constant_and(|value| value.is_zero()) => {
+                        check_operation_folds(&a, &candidate)
+                    }
+                    (_, case_b) if case_b.is_constant_and(|value| value.is_zero()) => {
+                        check_operation_folds(&b, &candidate)
+                    }
+                    (case_a, _) if case_a.is_constant_and(|value| value.is_one()) => {
+                        check_operation_folds(&b, &candidate)
+                    }
+                    (_, case_b) if case_b.is_constant_and(|value| value.is_one()) => {
+                        check_operation_folds(&a, &candidate)
+                    }
+                    _ => (),
+                }
             }),
             None => match (mode_a, mode_b) {
                 (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, Integer::mul_checked),
                 _ => Circuit::scope(name, || {
                     let _candidate = a.mul_checked(&b);
-                    assert_count_fails!(MulChecked(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                    assert_count_fails!(MulChecked(Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&a), CircuitType::from(&b)));
                 }),
             },
         }
@@ -22,7 +22,19 @@ impl<E: Environment, I: IntegerType> MulWrapped<Self> for Integer<E, I> {
     #[inline]
     fn mul_wrapped(&self, other: &Integer<E, I>) -> Self::Output {
         // Determine the variable mode.
-        if self.is_constant() && other.is_constant() {
+        if self.is_constant() && self.eject_value().is_zero() {
+            // If `self` is the constant zero, the product is `self`.
+            self.clone()
+        } else if other.is_constant() && other.eject_value().is_zero() {
+            // If `other` is the constant zero, the product is `other`.
+            other.clone()
+        } else if self.is_constant() && self.eject_value().is_one() {
+            // If `self` is the constant one, the product is `other`.
+            other.clone()
+        } else if other.is_constant() && other.eject_value().is_one() {
+            // If `other` is the constant one, the product is `self`.
+            self.clone()
+        } else if self.is_constant() && other.is_constant() {
             // Compute the product and return the new constant.
             witness!(|self, other| self.wrapping_mul(&other))
         } else {
@@ -57,10 +69,17 @@ impl<E: Environment, I: IntegerType> MulWrapped<Self> for Integer<E, I> {
 }
 
 impl<E: Environment, I: IntegerType> Metrics<dyn MulWrapped<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn count(case: &Self::Case) -> Count {
-        match (case.0, case.1) {
+        // Determine if either operand is the constant zero or the constant one.
+        let is_zero =
+            case.0.is_constant_and(|value| value.is_zero()) || case.1.is_constant_and(|value| value.is_zero());
+        let is_one = case.0.is_constant_and(|value| value.is_one()) || case.1.is_constant_and(|value| value.is_one());
+
+        match (case.0.mode(), case.1.mode()) {
+            // Multiplying by the constant zero or one is free.
+            _ if is_zero || is_one => Count::is(0, 0, 0, 0),
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
             (Mode::Constant, _) | (_, Mode::Constant) => {
                 Count::is(0, 0, I::BITS + (I::BITS / 2) + 1, I::BITS + (I::BITS / 2) + 2)
@@ -73,11 +92,18 @@ impl<E: Environment, I: IntegerType> Metrics<dyn MulWrapped<Integer<E, I>, Outpu
 impl<E: Environment, I: IntegerType> OutputMode<dyn MulWrapped<Integer<E, I>, Output = Integer<E, I>>>
     for Integer<E, I>
 {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        match (case.0, case.1) {
+        match (case.0.mode(), case.1.mode()) {
             (Mode::Constant, Mode::Constant) => Mode::Constant,
+            // Multiplying by the constant zero yields the constant zero.
+            _ if case.0.is_constant_and(|value| value.is_zero()) || case.1.is_constant_and(|value| value.is_zero()) => {
+                Mode::Constant
+            }
+            // Multiplying by the constant one preserves the mode of the other operand.
+            (Mode::Constant, mode_b) if case.0.is_constant_and(|value| value.is_one()) => mode_b,
+            (mode_a, Mode::Constant) if case.1.is_constant_and(|value| value.is_one()) => mode_a,
             (_, _) => Mode::Private,
         }
     }
@@ -88,6 +114,7 @@ mod tests {
     use super::*;
     use snarkvm_circuits_environment::Circuit;
     use snarkvm_utilities::{test_rng, UniformRand};
+    use test_utilities::*;
 
     use core::ops::RangeInclusive;
 
@@ -100,8 +127,17 @@ mod tests {
         Circuit::scope(name, || {
             let candidate = a.mul_wrapped(&b);
             assert_eq!(expected, candidate.eject_value());
-            assert_count!(MulWrapped(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
-            assert_output_mode!(MulWrapped(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+            let case = (CircuitType::from(&a), CircuitType::from(&b));
+            assert_count!(MulWrapped(Integer<I>, Integer<I>) => Integer<I>, &case);
+            assert_output_mode!(MulWrapped(Integer<I>, Integer<I>) => Integer<I>, &case, candidate);
+            // Multiplying by the constant zero or one is free, and yields the zero or the other operand, respectively.
+            match (CircuitType::from(&a), CircuitType::from(&b)) {
+                (case_a, _) if case_a.is_constant_and(|value| value.is_zero()) => check_operation_folds(&a, &candidate),
+                (_, case_b) if case_b.is_constant_and(|value| value.is_zero()) => check_operation_folds(&b, &candidate),
+                (case_a, _) if case_a.is_constant_and(|value| value.is_one()) => check_operation_folds(&b, &candidate),
+                (_, case_b) if case_b.is_constant_and(|value| value.is_one()) => check_operation_folds(&a, &candidate),
+                _ => (),
+            }
         });
         Circuit::reset();
     }
@@ -39,7 +39,10 @@ impl<E: Environment, I: IntegerType, M: Magnitude> PowChecked<Integer<E, M>> for
             for bit in other.bits_le.iter().rev() {
                 result = (&result).mul_checked(&result);
 
-                let result_times_self = if I::is_signed() {
+                let result_times_self = if result.is_constant() && result.eject_value().is_one() {
+                    // If `result` is the constant one, the product is `self`, which cannot overflow.
+                    self.clone()
+                } else if I::is_signed() {
                     // Multiply the absolute value of `self` and `other` in the base field.
                     // Note that it is safe to use abs_wrapped since we want I::MIN to be interpreted as an unsigned number.
                     let (product, carry) = Self::mul_with_carry(&(&result).abs_wrapped(), &self.abs_wrapped());
@@ -92,13 +95,42 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn PowChecked<Intege
     fn count(case: &Self::Case) -> Count {
         match (case.0, case.1) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
-            (Mode::Constant, _) | (_, Mode::Constant) => {
-                let mul_count = count!(Integer<E, I>, MulWrapped<Integer<E, I>, Output=Integer<E, I>>, case);
-                (2 * M::BITS * mul_count) + Count::is(2 * I::BITS, 0, I::BITS, I::BITS)
-            }
-            (_, _) => {
-                let mul_count = count!(Integer<E, I>, MulWrapped<Integer<E, I>, Output=Integer<E, I>>, case);
-                (2 * M::BITS * mul_count) + Count::is(2 * I::BITS, 0, I::BITS, I::BITS)
+            (mode_a, _) => {
+                let square_count = count!(
+                    Integer<E, I>,
+                    MulChecked<Integer<E, I>, Output=Integer<E, I>>,
+                    &(CircuitType::Private, CircuitType::Private)
+                );
+                let product_count = match mode_a.is_constant() {
+                    // Bound the product by a constant `self`, which is neither zero nor one.
+                    true => {
+                        let constant = Integer {
+                            bits_le: vec![Boolean::constant(true); I::BITS as usize],
+                            phantom: Default::default(),
+                        };
+                        count!(
+                            Integer<E, I>,
+                            MulChecked<Integer<E, I>, Output=Integer<E, I>>,
+                            &(CircuitType::Private, CircuitType::from(constant))
+                        )
+                    }
+                    false => square_count,
+                };
+                let ternary_count = count!(
+                    Integer<E, I>,
+                    Ternary<Boolean = Boolean<E>, Output = Integer<E, I>>,
+                    &(Mode::Private, Mode::Private, Mode::Private)
+                );
+                // Each iteration squares the result, multiplies it by `self`, and gates the overflow check on the bit.
+                // The count depends on the values of the operands, as some of the operations fold.
+                let iteration_count = square_count + product_count + Count::is(0, 0, 1, 1) + ternary_count;
+                let count = Count::is(I::BITS, 0, 0, 0) + (M::BITS * iteration_count);
+                match mode_a.is_constant() {
+                    // Selecting between the constant `self` and the constant one leaves constant bits in the result,
+                    // and squaring it may decompose a constant product into up to `I::BITS` constants.
+                    true => count + Count::less_than(I::BITS, 0, 0, 0),
+                    false => count + Count::less_than(0, 0, 0, 0),
+                }
             }
         }
     }
@@ -154,7 +186,7 @@ mod tests {
             Some(expected) => Circuit::scope(name, || {
                 let candidate = a.pow_checked(&b);
                 assert_eq!(expected, candidate.eject_value());
-                // assert_count!(PowChecked(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b));
+                assert_count!(PowChecked(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b));
                 // assert_output_mode!(PowChecked(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, CircuitType::from(&b)), candidate);
             }),
             None => {
@@ -162,7 +194,7 @@ mod tests {
                     (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, Integer::pow_checked),
                     _ => Circuit::scope(name, || {
                         let _candidate = a.pow_checked(&b);
-                        // assert_count_fails!(PowChecked(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b));
+                        assert_count_fails!(PowChecked(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b));
                     }),
                 }
             }
@@ -45,13 +45,24 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn PowWrapped<Intege
     fn count(case: &Self::Case) -> Count {
         match (case.0, case.1) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
-            (Mode::Constant, _) | (_, Mode::Constant) => {
-                let mul_count = count!(Integer<E, I>, MulWrapped<Integer<E, I>, Output=Integer<E, I>>, case);
-                (2 * M::BITS * mul_count) + Count::is(2 * I::BITS, 0, I::BITS, I::BITS)
-            }
-            (_, _) => {
-                let mul_count = count!(Integer<E, I>, MulWrapped<Integer<E, I>, Output=Integer<E, I>>, case);
-                (2 * M::BITS * mul_count) + Count::is(2 * I::BITS, 0, I::BITS, I::BITS)
+            (mode_a, mode_b) => {
+                let mul_count = count!(
+                    Integer<E, I>,
+                    MulWrapped<Integer<E, I>, Output=Integer<E, I>>,
+                    &(CircuitType::Private, CircuitType::Private)
+                );
+                let ternary_count = count!(
+                    Integer<E, I>,
+                    Ternary<Boolean = Boolean<E>, Output = Integer<E, I>>,
+                    &(Mode::Private, Mode::Private, Mode::Private)
+                );
+                // The first iteration multiplies by the constant one, which is free.
+                let count = Count::is(I::BITS, 0, 0, 0) + (M::BITS * ternary_count) + ((M::BITS - 1) * (2 * mul_count));
+                match mode_a.is_constant() || mode_b.is_constant() {
+                    // The count depends on the values of the constant, as some of the multiplications fold.
+                    true => count + Count::less_than(0, 0, 0, 0),
+                    false => count,
+                }
             }
         }
     }
@@ -106,7 +117,7 @@ mod tests {
         Circuit::scope(name, || {
             let candidate = a.pow_wrapped(&b);
             assert_eq!(expected, candidate.eject_value());
-            // assert_count!(PowWrapped(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b));
+            assert_count!(PowWrapped(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b));
             // assert_output_mode!(PowWrapped(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, CircuitType::from(&b)), candidate);
         });
         Circuit::reset();
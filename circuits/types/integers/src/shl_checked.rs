@@ -116,7 +116,7 @@ impl<E: Environment, I: IntegerType> OutputMode<dyn Shl<Integer<E, I>, Output =
 impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn ShlChecked<Integer<E, M>, Output = Integer<E, I>>>
     for Integer<E, I>
 {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, Mode);
 
     fn count(case: &Self::Case) -> Count {
         // A quick hack that matches `(u8 -> 0, u16 -> 1, u32 -> 2, u64 -> 3, u128 -> 4)`.
@@ -125,7 +125,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn ShlChecked<Intege
             None => E::halt(format!("Integer of {num_bits} bits is not supported")),
         };
 
-        match (case.0, case.1) {
+        match (case.0.mode(), case.1) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
             (_, Mode::Constant) => Count::is(0, 0, 0, 0),
             (Mode::Constant, _) | (_, _) => {
@@ -139,12 +139,14 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn ShlChecked<Intege
 impl<E: Environment, I: IntegerType, M: Magnitude> OutputMode<dyn ShlChecked<Integer<E, M>, Output = Integer<E, I>>>
     for Integer<E, I>
 {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, Mode);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        match (case.0, case.1) {
+        match (case.0.mode(), case.1) {
             (Mode::Constant, Mode::Constant) => Mode::Constant,
             (mode_a, Mode::Constant) => mode_a,
+            // Shifting the constant zero yields the constant zero.
+            (Mode::Constant, _) if case.0.is_constant_and(|value| value.is_zero()) => Mode::Constant,
             (_, _) => Mode::Private,
         }
     }
@@ -174,14 +176,14 @@ mod tests {
             Some(expected) => Circuit::scope(name, || {
                 let candidate = a.shl_checked(&b);
                 assert_eq!(expected, candidate.eject_value());
-                assert_count!(ShlChecked(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b));
-                assert_output_mode!(ShlChecked(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b), candidate);
+                assert_count!(ShlChecked(Integer<I>, Integer<M>) => Integer<I>, &(CircuitType::from(&a), mode_b));
+                assert_output_mode!(ShlChecked(Integer<I>, Integer<M>) => Integer<I>, &(CircuitType::from(&a), mode_b), candidate);
             }),
             None => match (mode_a, mode_b) {
                 (_, Mode::Constant) => check_operation_halts(&a, &b, Integer::shl_checked),
                 _ => Circuit::scope(name, || {
                     let _candidate = a.shl_checked(&b);
-                    assert_count_fails!(ShlChecked(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b));
+                    assert_count_fails!(ShlChecked(Integer<I>, Integer<M>) => Integer<I>, &(CircuitType::from(&a), mode_b));
                 }),
             },
         };
@@ -203,6 +205,11 @@ mod tests {
             // Check that shift left by two is computed correctly.
             let name = format!("Quadruple: {} << {} {}", mode_a, mode_b, i);
             check_shl(&name, first, M::one() + M::one(), mode_a, mode_b);
+
+            // Check that shifting zero and one is computed correctly.
+            let name = format!("Identity: {} << {} {}", mode_a, mode_b, i);
+            check_shl(&name, I::zero(), second, mode_a, mode_b);
+            check_shl(&name, I::one(), second, mode_a, mode_b);
         }
     }
 
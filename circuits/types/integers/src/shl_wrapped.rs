@@ -25,6 +25,9 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShlWrapped<Integer<E, M>> for
         if self.is_constant() && rhs.is_constant() {
             // This cast is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
             Integer::new(Mode::Constant, self.eject_value().wrapping_shl(rhs.eject_value().to_u32().unwrap()))
+        } else if self.is_constant() && self.eject_value().is_zero() {
+            // If `self` is the constant zero, the result is `self`.
+            self.clone()
         } else {
             // Index of the first upper bit of rhs that we mask.
             let first_upper_bit_index = I::BITS.trailing_zeros() as usize;
@@ -70,7 +73,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShlWrapped<Integer<E, M>> for
 impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn ShlWrapped<Integer<E, M>, Output = Integer<E, I>>>
     for Integer<E, I>
 {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, Mode);
 
     fn count(case: &Self::Case) -> Count {
         // A quick hack that matches `(u8 -> 0, u16 -> 1, u32 -> 2, u64 -> 3, u128 -> 4)`.
@@ -79,9 +82,15 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn ShlWrapped<Intege
             None => E::halt(format!("Integer of {num_bits} bits is not supported")),
         };
 
-        match (case.0, case.1) {
+        match (case.0.mode(), case.1) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
             (_, Mode::Constant) => Count::is(0, 0, 0, 0),
+            // Shifting the constant zero is free.
+            (Mode::Constant, _) if case.0.is_constant_and(|value| value.is_zero()) => Count::is(0, 0, 0, 0),
+            // Shifting the constant one skips the final multiplication.
+            (Mode::Constant, _) if case.0.is_constant_and(|value| value.is_one()) => {
+                Count::is(0, 0, I::BITS + (2 * index(I::BITS)) + 4, I::BITS + (2 * index(I::BITS)) + 5)
+            }
             (Mode::Constant, _) => Count::is(
                 0,
                 0,
@@ -101,12 +110,14 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn ShlWrapped<Intege
 impl<E: Environment, I: IntegerType, M: Magnitude> OutputMode<dyn ShlWrapped<Integer<E, M>, Output = Integer<E, I>>>
     for Integer<E, I>
 {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, Mode);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        match (case.0, case.1) {
+        match (case.0.mode(), case.1) {
             (Mode::Constant, Mode::Constant) => Mode::Constant,
             (mode_a, Mode::Constant) => mode_a,
+            // Shifting the constant zero yields the constant zero.
+            (Mode::Constant, _) if case.0.is_constant_and(|value| value.is_zero()) => Mode::Constant,
             (_, _) => Mode::Private,
         }
     }
@@ -117,6 +128,7 @@ mod tests {
     use super::*;
     use snarkvm_circuits_environment::Circuit;
     use snarkvm_utilities::{test_rng, UniformRand};
+    use test_utilities::*;
 
     use core::{ops::RangeInclusive, panic::RefUnwindSafe};
 
@@ -135,8 +147,12 @@ mod tests {
         Circuit::scope(name, || {
             let candidate = a.shl_wrapped(&b);
             assert_eq!(expected, candidate.eject_value());
-            assert_count!(ShlWrapped(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b));
-            assert_output_mode!(ShlWrapped(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b), candidate);
+            assert_count!(ShlWrapped(Integer<I>, Integer<M>) => Integer<I>, &(CircuitType::from(&a), mode_b));
+            assert_output_mode!(ShlWrapped(Integer<I>, Integer<M>) => Integer<I>, &(CircuitType::from(&a), mode_b), candidate);
+            // Shifting the constant zero is free, and yields the constant zero.
+            if !mode_b.is_constant() && CircuitType::from(&a).is_constant_and(|value| value.is_zero()) {
+                check_operation_folds(&a, &candidate);
+            }
         });
         Circuit::reset();
     }
@@ -158,6 +174,11 @@ mod tests {
             // Check that shift left by two is computed correctly.
             let name = format!("Quadruple: {} << {} {}", mode_a, mode_b, i);
             check_shl(&name, first, M::one() + M::one());
+
+            // Check that shifting zero and one is computed correctly.
+            let name = format!("Identity: {} << {} {}", mode_a, mode_b, i);
+            check_shl(&name, I::zero(), second);
+            check_shl(&name, I::one(), second);
         }
     }
 
@@ -75,6 +75,9 @@ impl<E: Environment, I: IntegerType> SubChecked<Self> for Integer<E, I> {
                 Some(value) => Integer::constant(value),
                 None => E::halt("Integer underflow on subtraction of two constants"),
             }
+        } else if other.is_constant() && other.eject_value().is_zero() {
+            // If `other` is the constant zero, the difference is `self`, which cannot underflow.
+            self.clone()
         } else {
             // Instead of subtracting the bits of `self` and `other` directly, the integers are
             // converted into a field elements, and subtracted, before converting back to integers.
@@ -110,7 +113,7 @@ impl<E: Environment, I: IntegerType> SubChecked<Self> for Integer<E, I> {
 }
 
 impl<E: Environment, I: IntegerType> Metrics<dyn Sub<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn count(case: &Self::Case) -> Count {
         <Self as Metrics<dyn SubChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(case)
@@ -118,7 +121,7 @@ impl<E: Environment, I: IntegerType> Metrics<dyn Sub<Integer<E, I>, Output = Int
 }
 
 impl<E: Environment, I: IntegerType> OutputMode<dyn Sub<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn output_mode(case: &Self::Case) -> Mode {
         <Self as OutputMode<dyn SubChecked<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(case)
@@ -126,18 +129,25 @@ impl<E: Environment, I: IntegerType> OutputMode<dyn Sub<Integer<E, I>, Output =
 }
 
 impl<E: Environment, I: IntegerType> Metrics<dyn SubChecked<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn count(case: &Self::Case) -> Count {
+        // Determine if `other` is the constant zero.
+        let is_identity = case.1.is_constant_and(|value| value.is_zero());
+
         match I::is_signed() {
-            true => match (case.0, case.1) {
+            true => match (case.0.mode(), case.1.mode()) {
                 (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                // Subtracting the constant zero is free.
+                _ if is_identity => Count::is(0, 0, 0, 0),
                 (Mode::Constant, _) => Count::is(0, 0, I::BITS + 3, I::BITS + 5),
                 (_, Mode::Constant) => Count::is(0, 0, I::BITS + 2, I::BITS + 4),
                 (_, _) => Count::is(0, 0, I::BITS + 4, I::BITS + 6),
             },
-            false => match (case.0, case.1) {
+            false => match (case.0.mode(), case.1.mode()) {
                 (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                // Subtracting the constant zero is free.
+                _ if is_identity => Count::is(0, 0, 0, 0),
                 (_, _) => Count::is(0, 0, I::BITS + 1, I::BITS + 3),
             },
         }
@@ -147,11 +157,13 @@ impl<E: Environment, I: IntegerType> Metrics<dyn SubChecked<Integer<E, I>, Outpu
 impl<E: Environment, I: IntegerType> OutputMode<dyn SubChecked<Integer<E, I>, Output = Integer<E, I>>>
     for Integer<E, I>
 {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        match (case.0, case.1) {
+        match (case.0.mode(), case.1.mode()) {
             (Mode::Constant, Mode::Constant) => Mode::Constant,
+            // Subtracting the constant zero preserves the mode of `self`.
+            (mode_a, Mode::Constant) if case.1.is_constant_and(|value| value.is_zero()) => mode_a,
             (_, _) => Mode::Private,
         }
     }
@@ -175,14 +187,22 @@ mod tests {
             Some(expected) => Circuit::scope(name, || {
                 let candidate = a.sub_checked(&b);
                 assert_eq!(expected, candidate.eject_value());
-                assert_count!(Sub(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
-                assert_output_mode!(Sub(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+                assert_count!(Sub(Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&a), CircuitType::from(&b)));
+                assert_output_mode!(Sub(Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&a), CircuitType::from(&b)), candidate);
+                // Subtracting the constant zero is free, and yields `a`.
+                match (CircuitType::from(&a), CircuitType::from(&b)) {
+                    (CircuitType::Constant(_), CircuitType::Constant(_)) => (),
+                    (_, case_b) if case_b.is_constant_and(|value| value.is_zero()) => {
+                        check_operation_folds(&a, &candidate)
+                    }
+                    _ => (),
+                }
             }),
             None => match mode_a.is_constant() && mode_b.is_constant() {
                 true => check_operation_halts(&a, &b, Integer::sub_checked),
                 false => Circuit::scope(name, || {
                     let _candidate = a.sub_checked(&b);
-                    assert_count_fails!(Sub(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                    assert_count_fails!(Sub(Integer<I>, Integer<I>) => Integer<I>, &(CircuitType::from(&a), CircuitType::from(&b)));
                 }),
             },
         }
@@ -195,6 +215,9 @@ mod tests {
             let first: I = UniformRand::rand(&mut test_rng());
             let second: I = UniformRand::rand(&mut test_rng());
             check_sub(&name, first, second, mode_a, mode_b);
+
+            let name = format!("Sub Identity: {} - {} {}", mode_a, mode_b, i);
+            check_sub(&name, first, I::zero(), mode_a, mode_b);
         }
 
         // Overflow
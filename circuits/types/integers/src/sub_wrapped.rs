@@ -25,6 +25,9 @@ impl<E: Environment, I: IntegerType> SubWrapped<Self> for Integer<E, I> {
         if self.is_constant() && other.is_constant() {
             // Compute the difference and return the new constant.
             Integer::new(Mode::Constant, self.eject_value().wrapping_sub(&other.eject_value()))
+        } else if other.is_constant() && other.eject_value().is_zero() {
+            // If `other` is the constant zero, the difference is `self`.
+            self.clone()
         } else {
             // Instead of subtracting the bits of `self` and `other` directly, the integers are
             // converted into field elements to perform the operation, before converting back to integers.
@@ -43,11 +46,13 @@ impl<E: Environment, I: IntegerType> SubWrapped<Self> for Integer<E, I> {
 }
 
 impl<E: Environment, I: IntegerType> Metrics<dyn SubWrapped<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn count(case: &Self::Case) -> Count {
-        match (case.0, case.1) {
+        match (case.0.mode(), case.1.mode()) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+            // Subtracting the constant zero is free.
+            (_, Mode::Constant) if case.1.is_constant_and(|value| value.is_zero()) => Count::is(0, 0, 0, 0),
             (_, _) => Count::is(0, 0, I::BITS + 1, I::BITS + 2),
         }
     }
@@ -56,11 +61,13 @@ impl<E: Environment, I: IntegerType> Metrics<dyn SubWrapped<Integer<E, I>, Outpu
 impl<E: Environment, I: IntegerType> OutputMode<dyn SubWrapped<Integer<E, I>, Output = Integer<E, I>>>
     for Integer<E, I>
 {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        match (case.0, case.1) {
+        match (case.0.mode(), case.1.mode()) {
             (Mode::Constant, Mode::Constant) => Mode::Constant,
+            // Subtracting the constant zero preserves the mode of `self`.
+            (mode_a, Mode::Constant) if case.1.is_constant_and(|value| value.is_zero()) => mode_a,
             (_, _) => Mode::Private,
         }
     }
@@ -71,6 +78,7 @@ mod tests {
     use super::*;
     use snarkvm_circuits_environment::Circuit;
     use snarkvm_utilities::{test_rng, UniformRand};
+    use test_utilities::*;
 
     use std::ops::RangeInclusive;
 
@@ -83,8 +91,15 @@ mod tests {
         Circuit::scope(name, || {
             let candidate = a.sub_wrapped(&b);
             assert_eq!(expected, candidate.eject_value());
-            assert_count!(SubWrapped(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
-            assert_output_mode!(SubWrapped(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+            let case = (CircuitType::from(&a), CircuitType::from(&b));
+            assert_count!(SubWrapped(Integer<I>, Integer<I>) => Integer<I>, &case);
+            assert_output_mode!(SubWrapped(Integer<I>, Integer<I>) => Integer<I>, &case, candidate);
+            // Subtracting the constant zero is free, and yields `a`.
+            match (CircuitType::from(&a), CircuitType::from(&b)) {
+                (CircuitType::Constant(_), CircuitType::Constant(_)) => (),
+                (_, case_b) if case_b.is_constant_and(|value| value.is_zero()) => check_operation_folds(&a, &candidate),
+                _ => (),
+            }
         });
         Circuit::reset();
     }
@@ -95,6 +110,9 @@ mod tests {
             let first: I = UniformRand::rand(&mut test_rng());
             let second: I = UniformRand::rand(&mut test_rng());
             check_sub(&name, first, second, mode_a, mode_b);
+
+            let name = format!("Sub Identity: {} - {} {}", mode_a, mode_b, i);
+            check_sub(&name, first, I::zero(), mode_a, mode_b);
         }
 
         // Overflow
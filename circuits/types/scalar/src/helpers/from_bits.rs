@@ -152,7 +152,7 @@ mod tests {
 
     #[test]
     fn test_from_bits_le_constant() {
-        check_from_bits_le(Mode::Constant, 507, 0, 0, 0);
+        check_from_bits_le(Mode::Constant, 2, 0, 0, 0);
     }
 
     #[test]
@@ -167,7 +167,7 @@ mod tests {
 
     #[test]
     fn test_from_bits_be_constant() {
-        check_from_bits_be(Mode::Constant, 507, 0, 0, 0);
+        check_from_bits_be(Mode::Constant, 2, 0, 0, 0);
     }
 
     #[test]
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_curves::bls12_377::Fr;
-use snarkvm_fields::{Field, PrimeField, SquareRootField};
+use snarkvm_fields::{Field, One, PrimeField, SquareRootField};
 use snarkvm_utilities::{
     biginteger::{BigInteger, BigInteger256 as FrRepr},
     rand::UniformRand,
@@ -313,3 +313,17 @@ pub(crate) fn bench_fr_from_repr(c: &mut Criterion) {
         })
     });
 }
+
+pub(crate) fn bench_fr_product_of_powers(c: &mut Criterion) {
+    const SAMPLES: usize = 64;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let bases: Vec<Fr> = (0..SAMPLES).map(|_| Fr::rand(&mut rng)).collect();
+    let exps: Vec<FrRepr> = (0..SAMPLES).map(|_| Fr::rand(&mut rng).to_repr()).collect();
+
+    c.bench_function("bls12_377: fr_product_of_powers", |c| c.iter(|| Fr::product_of_powers(&bases, &exps)));
+    c.bench_function("bls12_377: fr_product_of_powers_naive", |c| {
+        c.iter(|| bases.iter().zip(&exps).fold(Fr::one(), |acc, (base, exp)| acc * base.pow(exp)))
+    });
+}
@@ -89,6 +89,7 @@ criterion_group!(
     bls12_377::fr::bench_fr_sqrt,
     bls12_377::fr::bench_fr_into_repr,
     bls12_377::fr::bench_fr_from_repr,
+    bls12_377::fr::bench_fr_product_of_powers,
 );
 
 criterion_group!(
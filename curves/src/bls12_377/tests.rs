@@ -28,6 +28,7 @@ use crate::{
         Fq6Parameters,
         FqParameters,
         Fr,
+        FrParameters,
         G1Affine,
         G1Projective,
         G2Affine,
@@ -35,7 +36,14 @@ use crate::{
     },
     templates::{short_weierstrass_jacobian::tests::sw_tests, twisted_edwards_extended::tests::edwards_test},
     traits::{
-        tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
+        tests_field::{
+            field_serialization_test,
+            field_test,
+            frobenius_test,
+            poseidon_parameters_test,
+            primefield_test,
+            sqrt_field_test,
+        },
         tests_group::*,
         tests_projective::curve_tests,
         AffineCurve,
@@ -81,6 +89,7 @@ fn test_bls12_377_fr() {
         sqrt_field_test(b);
         field_serialization_test::<Fr>();
     }
+    poseidon_parameters_test::<Fr>();
 }
 
 #[test]
@@ -93,6 +102,7 @@ fn test_bls12_377_fq() {
         sqrt_field_test(a);
         field_serialization_test::<Fq>();
     }
+    poseidon_parameters_test::<Fq>();
 }
 
 #[test]
@@ -408,6 +418,27 @@ fn test_fq_root_of_unity() {
     assert!(Fq::multiplicative_generator().sqrt().is_none());
 }
 
+#[test]
+fn test_fr_large_subgroup_root_of_unity() {
+    let root = Fr::large_subgroup_root_of_unity().unwrap();
+    assert_eq!(root.pow([3]), Fr::two_adic_root_of_unity());
+    assert_eq!(root.pow([3 << FrParameters::TWO_ADICITY]), Fr::one());
+    assert_ne!(root.pow([1 << FrParameters::TWO_ADICITY]), Fr::one());
+
+    // Ensure the roots of unity of power-of-two order are unchanged, and those of order 3 * 2^k exist.
+    for k in [0, 1, 10, FrParameters::TWO_ADICITY] {
+        let mut two_adic_root = Fr::two_adic_root_of_unity();
+        for _ in k..FrParameters::TWO_ADICITY {
+            two_adic_root.square_in_place();
+        }
+        assert_eq!(Some(two_adic_root), Fr::get_root_of_unity(1 << k));
+        let root = Fr::get_root_of_unity(3 << k).unwrap();
+        assert_eq!(root.pow([3 << k]), Fr::one());
+        assert_ne!(root.pow([1 << k]), Fr::one());
+    }
+    assert!(Fr::get_root_of_unity(9).is_none());
+}
+
 #[test]
 fn test_fq_ordering() {
     // BigInteger384's ordering is well-tested, but we still need to make sure the
@@ -550,6 +581,32 @@ fn test_fq12_mul_by_034() {
     }
 }
 
+#[test]
+fn test_fq12_from_fq6() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        // The embedding followed by the projection is the identity.
+        let a = Fq6::rand(&mut rng);
+        let b = Fq12::from(a);
+        assert_eq!(Fq12::new(a, Fq6::zero()), b);
+        assert_eq!(Some(a), b.try_into_fp6());
+
+        // The embedding is a field homomorphism.
+        let c = Fq6::rand(&mut rng);
+        assert_eq!(Fq12::from(a * c), b * Fq12::from(c));
+        assert_eq!(Fq12::from(a + c), b + Fq12::from(c));
+
+        // An element outside of the subfield is rejected.
+        let d = Fq12::new(a, Fq6::rand(&mut rng));
+        assert!(!d.c1.is_zero());
+        assert_eq!(None, d.try_into_fp6());
+    }
+
+    assert_eq!(Some(Fq6::zero()), Fq12::zero().try_into_fp6());
+    assert_eq!(Some(Fq6::one()), Fq12::one().try_into_fp6());
+}
+
 #[test]
 fn test_g1_projective_curve() {
     curve_tests::<G1Projective>();
@@ -590,6 +647,57 @@ fn test_g2_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+/// Checks that clearing the cofactor of on-curve points outside the subgroup yields subgroup elements.
+fn clear_cofactor_test<G: AffineCurve>() {
+    let mut rng = test_rng();
+
+    let mut num_outside_subgroup = 0;
+    for _ in 0..100 {
+        // Sample a point on the curve, without clearing its cofactor.
+        let point = loop {
+            if let Some(point) = G::from_x_coordinate(G::BaseField::rand(&mut rng), rng.gen()) {
+                break point;
+            }
+        };
+        assert!(point.is_on_curve());
+        if !point.is_in_correct_subgroup_assuming_on_curve() {
+            num_outside_subgroup += 1;
+        }
+
+        let candidate = point.clear_cofactor();
+        assert!(candidate.is_on_curve());
+        assert!(candidate.is_in_correct_subgroup_assuming_on_curve());
+    }
+    // Ensure the points outside the subgroup were tested.
+    assert!(num_outside_subgroup > 0);
+}
+
+#[test]
+fn test_g1_clear_cofactor() {
+    clear_cofactor_test::<G1Affine>();
+}
+
+#[test]
+fn test_g2_clear_cofactor() {
+    clear_cofactor_test::<G2Affine>();
+}
+
+#[test]
+fn test_g2_rand_in_subgroup() {
+    let mut rng = test_rng();
+
+    for _ in 0..100 {
+        let point = G2Projective::rand_in_subgroup(&mut rng).to_affine();
+        assert!(point.is_on_curve());
+        assert!(point.is_in_correct_subgroup_assuming_on_curve());
+
+        // The generic sampler also clears the cofactor.
+        let point = G2Projective::rand(&mut rng).to_affine();
+        assert!(point.is_on_curve());
+        assert!(point.is_in_correct_subgroup_assuming_on_curve());
+    }
+}
+
 #[test]
 fn test_bilinearity() {
     let a: G1Projective = rand::random();
@@ -18,7 +18,7 @@ use crate::{
     edwards_bls12::*,
     templates::twisted_edwards_extended::tests::{edwards_test, montgomery_conversion_test},
     traits::{
-        tests_field::{field_serialization_test, field_test, primefield_test},
+        tests_field::{field_serialization_test, field_test, poseidon_parameters_test, primefield_test},
         tests_group::*,
         tests_projective::curve_tests,
         AffineCurve,
@@ -28,7 +28,16 @@ use crate::{
     },
 };
 use snarkvm_fields::{Field, LegendreSymbol, One, SquareRootField, Zero};
-use snarkvm_utilities::{rand::UniformRand, to_bytes_le, ToBytes};
+use snarkvm_utilities::{
+    from_base58,
+    from_hex,
+    rand::UniformRand,
+    to_base58,
+    to_bytes_le,
+    to_hex,
+    FromBytes,
+    ToBytes,
+};
 
 use rand::thread_rng;
 
@@ -39,6 +48,7 @@ fn test_edwards_bls12_fr() {
     field_test(a, b);
     primefield_test::<Fr>();
     field_serialization_test::<Fr>();
+    poseidon_parameters_test::<Fr>();
 }
 
 #[test]
@@ -88,6 +98,59 @@ fn test_conversion() {
     assert_eq!(b.to_projective().to_affine(), b);
 }
 
+#[test]
+fn test_hex_and_base58() {
+    for _ in 0..10 {
+        let a: Fq = rand::random();
+        assert_eq!(a, from_hex::<Fq>(&to_hex(&a).unwrap()).unwrap());
+        assert_eq!(a, from_base58::<Fq>(&to_base58(&a).unwrap()).unwrap());
+
+        let b: EdwardsAffine = rand::random();
+        assert_eq!(b, from_hex::<EdwardsAffine>(&to_hex(&b).unwrap()).unwrap());
+        assert_eq!(b, from_base58::<EdwardsAffine>(&to_base58(&b).unwrap()).unwrap());
+    }
+
+    // Ensure a field element can not be decoded from the encoding of a point.
+    let b: EdwardsAffine = rand::random();
+    assert!(from_hex::<Fq>(&to_hex(&b).unwrap()).is_err());
+    assert!(from_hex::<Fq>("not a hex string").is_err());
+    assert!(from_base58::<Fq>("not a base58 string").is_err());
+}
+
+#[test]
+fn test_derive_to_bytes_and_from_bytes() {
+    #[derive(Debug, PartialEq, Eq, ToBytes, FromBytes)]
+    struct Inner(Fr, (u8, bool));
+
+    #[derive(Debug, PartialEq, Eq, ToBytes, FromBytes)]
+    struct Record {
+        field: Fq,
+        point: EdwardsAffine,
+        bytes: [u8; 32],
+        inner: Inner,
+    }
+
+    let rng = &mut thread_rng();
+    for _ in 0..10 {
+        let record = Record {
+            field: Fq::rand(rng),
+            point: EdwardsAffine::rand(rng),
+            bytes: rand::random(),
+            inner: Inner(Fr::rand(rng), (rand::random(), rand::random())),
+        };
+
+        // Ensure the fields are written in declaration order.
+        let bytes = record.to_bytes_le().unwrap();
+        let (inner_field, (inner_byte, inner_bool)) = (record.inner.0, record.inner.1);
+        let expected = to_bytes_le![record.field, record.point, record.bytes, inner_field, inner_byte, inner_bool];
+        assert_eq!(expected.unwrap(), bytes);
+
+        // Ensure the record round-trips.
+        assert_eq!(record, Record::read_le(&bytes[..]).unwrap());
+        assert!(Record::read_le(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
+
 #[test]
 fn test_montgomery_conversion() {
     montgomery_conversion_test::<EdwardsParameters>();
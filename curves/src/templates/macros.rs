@@ -133,12 +133,12 @@ macro_rules! impl_sw_curve_serializer {
                     Ok(Self::zero())
                 } else {
                     let p = Affine::<P>::from_x_coordinate(x, flags.is_positive().unwrap())
-                        .ok_or(snarkvm_utilities::serialize::SerializationError::InvalidData)?;
+                        .ok_or(snarkvm_utilities::serialize::SerializationError::NotOnCurve)?;
                     if !snarkvm_utilities::PROCESSING_SNARK_PARAMS
                         .with(|p| p.load(std::sync::atomic::Ordering::Relaxed))
                     {
                         if !p.is_in_correct_subgroup_assuming_on_curve() {
-                            return Err(snarkvm_utilities::serialize::SerializationError::InvalidData);
+                            return Err(snarkvm_utilities::serialize::SerializationError::NotInSubgroup);
                         }
                     } else {
                         snarkvm_utilities::SNARK_PARAMS_AFFINE_COUNT
@@ -157,8 +157,11 @@ macro_rules! impl_sw_curve_serializer {
                     CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
 
                 let p = Affine::<P>::new(x, y, flags.is_infinity());
+                if !p.is_on_curve() {
+                    return Err(snarkvm_utilities::serialize::SerializationError::NotOnCurve);
+                }
                 if !p.is_in_correct_subgroup_assuming_on_curve() {
-                    return Err(snarkvm_utilities::serialize::SerializationError::InvalidData);
+                    return Err(snarkvm_utilities::serialize::SerializationError::NotInSubgroup);
                 }
                 Ok(p)
             }
@@ -276,9 +279,9 @@ macro_rules! impl_edwards_curve_serializer {
                     Ok(Self::zero())
                 } else {
                     let p = Affine::<P>::from_x_coordinate(x, flags.is_positive())
-                        .ok_or(snarkvm_utilities::serialize::SerializationError::InvalidData)?;
+                        .ok_or(snarkvm_utilities::serialize::SerializationError::NotOnCurve)?;
                     if !p.is_in_correct_subgroup_assuming_on_curve() {
-                        return Err(snarkvm_utilities::serialize::SerializationError::InvalidData);
+                        return Err(snarkvm_utilities::serialize::SerializationError::NotInSubgroup);
                     }
                     Ok(p)
                 }
@@ -292,8 +295,11 @@ macro_rules! impl_edwards_curve_serializer {
                 let y: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
 
                 let p = Affine::<P>::new(x, y);
+                if !p.is_on_curve() {
+                    return Err(snarkvm_utilities::serialize::SerializationError::NotOnCurve);
+                }
                 if !p.is_in_correct_subgroup_assuming_on_curve() {
-                    return Err(snarkvm_utilities::serialize::SerializationError::InvalidData);
+                    return Err(snarkvm_utilities::serialize::SerializationError::NotInSubgroup);
                 }
                 Ok(p)
             }
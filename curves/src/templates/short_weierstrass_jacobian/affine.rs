@@ -37,7 +37,7 @@ use rand::{
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
-    io::{Error, ErrorKind, Read, Result as IoResult, Write},
+    io::{Read, Result as IoResult, Write},
     ops::{Mul, Neg},
 };
 
@@ -167,6 +167,13 @@ impl<P: Parameters> AffineCurve for Affine<P> {
         (*self * P::COFACTOR_INV).into()
     }
 
+    #[inline]
+    fn neg_in_place(&mut self) {
+        if !self.is_zero() {
+            self.y = -self.y;
+        }
+    }
+
     #[inline]
     fn to_projective(&self) -> Projective<P> {
         (*self).into()
@@ -294,7 +301,7 @@ impl<P: Parameters> FromBytes for Affine<P> {
         let infinity = bool::read_le(&mut reader)?;
 
         if infinity != x.is_zero() && y.is_one() {
-            return Err(Error::new(ErrorKind::InvalidData, "Infinity flag is not valid"));
+            return Err(SerializationError::InvalidData.into());
         }
         Ok(Self::new(x, y, infinity))
     }
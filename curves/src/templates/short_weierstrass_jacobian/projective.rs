@@ -49,6 +49,21 @@ impl<P: Parameters> Projective<P> {
     pub fn new(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
         Self { x, y, z }
     }
+
+    /// Returns a random point in the prime-order subgroup.
+    ///
+    /// A random point on the curve is sampled from a random x-coordinate,
+    /// and multiplied by the cofactor to map it into the prime-order subgroup.
+    pub fn rand_in_subgroup<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            let x = P::BaseField::rand(rng);
+            let greatest = rng.gen();
+
+            if let Some(p) = Affine::from_x_coordinate(x, greatest) {
+                return p.scale_by_cofactor();
+            }
+        }
+    }
 }
 
 impl<P: Parameters> Display for Projective<P> {
@@ -96,16 +111,10 @@ impl<P: Parameters> PartialEq<Affine<P>> for Projective<P> {
 }
 
 impl<P: Parameters> Distribution<Projective<P>> for Standard {
+    /// Returns a random point in the prime-order subgroup, as in `Projective::rand_in_subgroup`.
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Projective<P> {
-        loop {
-            let x = P::BaseField::rand(rng);
-            let greatest = rng.gen();
-
-            if let Some(p) = Affine::from_x_coordinate(x, greatest) {
-                return p.scale_by_cofactor();
-            }
-        }
+        Projective::rand_in_subgroup(rng)
     }
 }
 
@@ -392,6 +401,13 @@ impl<P: Parameters> ProjectiveCurve for Projective<P> {
         }
     }
 
+    #[inline]
+    fn neg_in_place(&mut self) {
+        if !self.is_zero() {
+            self.y = -self.y;
+        }
+    }
+
     #[inline]
     fn to_affine(&self) -> Affine<P> {
         (*self).into()
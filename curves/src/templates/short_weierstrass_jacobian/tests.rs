@@ -16,18 +16,20 @@
 
 use super::{Affine, Projective};
 use crate::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters};
-use snarkvm_fields::Zero;
+use snarkvm_fields::{Field, One, Zero};
 use snarkvm_utilities::{
     io::Cursor,
     rand::{test_rng, UniformRand},
-    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError},
 };
 
 pub const ITERATIONS: usize = 10;
 
 pub fn sw_tests<P: ShortWeierstrassParameters>() {
     sw_curve_serialization_test::<P>();
+    sw_serialization_error_test::<P>();
     sw_from_random_bytes::<P>();
+    sw_to_affine_test::<P>();
 }
 
 pub fn sw_curve_serialization_test<P: ShortWeierstrassParameters>() {
@@ -78,7 +80,7 @@ pub fn sw_curve_serialization_test<P: ShortWeierstrassParameters>() {
         {
             let serialized = vec![0; buf_size - 1];
             let mut cursor = Cursor::new(&serialized[..]);
-            Affine::<P>::deserialize(&mut cursor).unwrap_err();
+            assert!(matches!(Affine::<P>::deserialize(&mut cursor), Err(SerializationError::UnexpectedEof)));
         }
 
         {
@@ -113,6 +115,46 @@ pub fn sw_curve_serialization_test<P: ShortWeierstrassParameters>() {
     }
 }
 
+pub fn sw_serialization_error_test<P: ShortWeierstrassParameters>() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let x = P::BaseField::rand(&mut rng);
+        let point = Affine::<P>::from_x_coordinate(x, true);
+
+        let mut serialized = vec![0; Affine::<P>::zero().serialized_size()];
+        let mut serialized_uncompressed = vec![0; Affine::<P>::zero().uncompressed_size()];
+        match point {
+            // A point on the curve, which is outside of the subgroup.
+            Some(a) if !a.is_in_correct_subgroup_assuming_on_curve() => {
+                a.serialize(&mut &mut serialized[..]).unwrap();
+                a.serialize_uncompressed(&mut &mut serialized_uncompressed[..]).unwrap();
+                assert!(matches!(
+                    Affine::<P>::deserialize(&mut &serialized[..]),
+                    Err(SerializationError::NotInSubgroup)
+                ));
+                assert!(matches!(
+                    Affine::<P>::deserialize_uncompressed(&mut &serialized_uncompressed[..]),
+                    Err(SerializationError::NotInSubgroup)
+                ));
+            }
+            // A point in the subgroup.
+            Some(_) => continue,
+            // An x-coordinate without a point on the curve.
+            None => {
+                let a = Affine::<P>::new(x, x, false);
+                a.serialize(&mut &mut serialized[..]).unwrap();
+                a.serialize_uncompressed(&mut &mut serialized_uncompressed[..]).unwrap();
+                assert!(matches!(Affine::<P>::deserialize(&mut &serialized[..]), Err(SerializationError::NotOnCurve)));
+                assert!(matches!(
+                    Affine::<P>::deserialize_uncompressed(&mut &serialized_uncompressed[..]),
+                    Err(SerializationError::NotOnCurve)
+                ));
+            }
+        }
+    }
+}
+
 pub fn sw_from_random_bytes<P: ShortWeierstrassParameters>() {
     let buf_size = Affine::<P>::zero().serialized_size();
 
@@ -133,3 +175,27 @@ pub fn sw_from_random_bytes<P: ShortWeierstrassParameters>() {
         }
     }
 }
+
+pub fn sw_to_affine_test<P: ShortWeierstrassParameters>() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        // A normalized point, with `z == 1`.
+        let a = Projective::<P>::rand(&mut rng).to_affine().to_projective();
+        assert!(a.z.is_one());
+
+        // The same point, with a random `z`, which requires an inversion.
+        let z = P::BaseField::rand(&mut rng);
+        let b = Projective::<P>::new(a.x * z.square(), a.y * z.square() * z, z);
+        assert!(!b.z.is_one());
+        assert_eq!(a, b);
+
+        // The conversion of `a` must not invert, and must match the conversion of `b`.
+        assert_eq!(Affine::<P>::new(a.x, a.y, false), a.to_affine());
+        assert_eq!(a.to_affine(), b.to_affine());
+        assert_eq!(a, a.to_affine().to_projective());
+    }
+
+    // The zero point is converted without an inversion.
+    assert!(Projective::<P>::zero().to_affine().is_zero());
+}
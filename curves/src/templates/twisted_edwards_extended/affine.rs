@@ -172,6 +172,11 @@ impl<P: Parameters> AffineCurve for Affine<P> {
         (*self * P::COFACTOR_INV).into()
     }
 
+    #[inline]
+    fn neg_in_place(&mut self) {
+        self.x = -self.x;
+    }
+
     fn to_projective(&self) -> Projective<P> {
         (*self).into()
     }
@@ -245,6 +245,12 @@ impl<P: Parameters> ProjectiveCurve for Projective<P> {
         *self = tmp.double();
     }
 
+    #[inline]
+    fn neg_in_place(&mut self) {
+        self.x = -self.x;
+        self.t = -self.t;
+    }
+
     fn to_affine(&self) -> Affine<P> {
         (*self).into()
     }
@@ -19,7 +19,7 @@ use super::{Affine, Projective};
 use snarkvm_utilities::{
     io::Cursor,
     rand::{test_rng, UniformRand},
-    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError},
     to_bytes_le,
     ToBytes,
 };
@@ -47,8 +47,10 @@ where
     P::BaseField: PrimeField,
 {
     edwards_curve_serialization_test::<P>();
+    edwards_serialization_error_test::<P>();
     edwards_from_random_bytes::<P>();
     edwards_from_x_and_y_coordinates::<P>();
+    edwards_to_affine_test::<P>();
 }
 
 pub fn edwards_curve_serialization_test<P: TwistedEdwardsParameters>() {
@@ -89,7 +91,7 @@ pub fn edwards_curve_serialization_test<P: TwistedEdwardsParameters>() {
         {
             let serialized = vec![0; buf_size - 1];
             let mut cursor = Cursor::new(&serialized[..]);
-            Affine::<P>::deserialize(&mut cursor).unwrap_err();
+            assert!(matches!(Affine::<P>::deserialize(&mut cursor), Err(SerializationError::UnexpectedEof)));
         }
 
         {
@@ -114,6 +116,46 @@ pub fn edwards_curve_serialization_test<P: TwistedEdwardsParameters>() {
     }
 }
 
+pub fn edwards_serialization_error_test<P: TwistedEdwardsParameters>() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let x = P::BaseField::rand(&mut rng);
+        let point = Affine::<P>::from_x_coordinate(x, true);
+
+        let mut serialized = vec![0; Affine::<P>::zero().serialized_size()];
+        let mut serialized_uncompressed = vec![0; Affine::<P>::zero().uncompressed_size()];
+        match point {
+            // A point on the curve, which is outside of the subgroup.
+            Some(a) if !a.is_in_correct_subgroup_assuming_on_curve() => {
+                a.serialize(&mut &mut serialized[..]).unwrap();
+                a.serialize_uncompressed(&mut &mut serialized_uncompressed[..]).unwrap();
+                assert!(matches!(
+                    Affine::<P>::deserialize(&mut &serialized[..]),
+                    Err(SerializationError::NotInSubgroup)
+                ));
+                assert!(matches!(
+                    Affine::<P>::deserialize_uncompressed(&mut &serialized_uncompressed[..]),
+                    Err(SerializationError::NotInSubgroup)
+                ));
+            }
+            // A point in the subgroup.
+            Some(_) => continue,
+            // An x-coordinate without a point on the curve.
+            None => {
+                let a = Affine::<P>::new(x, x);
+                a.serialize(&mut &mut serialized[..]).unwrap();
+                a.serialize_uncompressed(&mut &mut serialized_uncompressed[..]).unwrap();
+                assert!(matches!(Affine::<P>::deserialize(&mut &serialized[..]), Err(SerializationError::NotOnCurve)));
+                assert!(matches!(
+                    Affine::<P>::deserialize_uncompressed(&mut &serialized_uncompressed[..]),
+                    Err(SerializationError::NotOnCurve)
+                ));
+            }
+        }
+    }
+}
+
 pub fn edwards_from_random_bytes<P: TwistedEdwardsParameters>()
 where
     P::BaseField: PrimeField,
@@ -176,3 +218,27 @@ where
         }
     }
 }
+
+pub fn edwards_to_affine_test<P: TwistedEdwardsParameters>() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        // A normalized point, with `z == 1`.
+        let a = Projective::<P>::rand(&mut rng).to_affine().to_projective();
+        assert!(a.z.is_one());
+
+        // The same point, with a random `z`, which requires an inversion.
+        let z = P::BaseField::rand(&mut rng);
+        let b = Projective::<P>::new(a.x * z, a.y * z, a.t * z, z);
+        assert!(!b.z.is_one());
+        assert_eq!(a, b);
+
+        // The conversion of `a` must not invert, and must match the conversion of `b`.
+        assert_eq!(Affine::<P>::new(a.x, a.y), a.to_affine());
+        assert_eq!(a.to_affine(), b.to_affine());
+        assert_eq!(a, a.to_affine().to_projective());
+    }
+
+    // The zero point is converted without an inversion.
+    assert!(Projective::<P>::zero().to_affine().is_zero());
+}
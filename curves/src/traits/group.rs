@@ -88,6 +88,14 @@ pub trait ProjectiveCurve:
     /// Adds an affine element to this element.
     fn add_assign_mixed(&mut self, other: &Self::Affine);
 
+    /// Returns `self + other`, for an affine element `other`.
+    #[must_use]
+    fn add_mixed(&self, other: &Self::Affine) -> Self {
+        let mut copy = *self;
+        copy.add_assign_mixed(other);
+        copy
+    }
+
     /// Adds an affine element to this element.
     fn sub_assign_mixed(&mut self, other: &Self::Affine) {
         self.add_assign_mixed(&-*other);
@@ -100,6 +108,26 @@ pub trait ProjectiveCurve:
     /// Sets `self := self + self`.
     fn double_in_place(&mut self);
 
+    /// Sets `self := -self`.
+    fn neg_in_place(&mut self);
+
+    /// Returns `self * scalar`, where the scalar is given as big-endian bits.
+    ///
+    /// Unlike `mul`, this performs a doubling and an addition for every bit, including leading zeros,
+    /// and selects the result without branching on the bit. This is suitable for secret scalars,
+    /// at the cost of an addition for every bit. Note that the group law may still branch on
+    /// special cases (e.g. the identity), so this does not guarantee constant time at the instruction level.
+    #[must_use]
+    fn mul_bits_constant_time(&self, bits: impl Iterator<Item = bool>) -> Self {
+        let mut output = Self::zero();
+        for bit in bits {
+            output.double_in_place();
+            let sum = output + self;
+            output = [output, sum][bit as usize];
+        }
+        output
+    }
+
     /// Converts this element into its affine representation.
     #[must_use]
     #[allow(clippy::wrong_self_convention)]
@@ -186,11 +214,23 @@ pub trait AffineCurve:
         self.mul_by_cofactor_to_projective().into()
     }
 
+    /// Maps this element into the prime-order subgroup.
+    ///
+    /// By default, this multiplies by the cofactor. Curves may override this
+    /// with a faster map, such as one using an endomorphism.
+    #[must_use]
+    fn clear_cofactor(&self) -> Self {
+        self.mul_by_cofactor()
+    }
+
     /// Multiply this element by the inverse of the cofactor modulo the size of
     /// `Self::ScalarField`.
     #[must_use]
     fn mul_by_cofactor_inv(&self) -> Self;
 
+    /// Sets `self := -self`.
+    fn neg_in_place(&mut self);
+
     /// Checks that the point is in the prime order subgroup given the point on the curve.
     #[must_use]
     fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool;
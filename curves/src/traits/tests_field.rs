@@ -14,11 +14,22 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::{traits::FftParameters, FftField, Field, LegendreSymbol, PrimeField, SquareRootField};
+use snarkvm_fields::{
+    traits::FftParameters,
+    FftField,
+    Field,
+    LegendreSymbol,
+    PoseidonDefaultField,
+    PoseidonParameters,
+    PrimeField,
+    SquareRootField,
+};
 use snarkvm_utilities::{
     io::Cursor,
-    rand::test_rng,
-    serialize::{CanonicalDeserialize, CanonicalSerialize, Flags, SWFlags},
+    FromBytes,
+    ToBytes,
+    rand::{test_rng, UniformRandRange},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Flags, SWFlags, SerializationError},
 };
 
 use rand::Rng;
@@ -171,6 +182,23 @@ fn random_expansion_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_product_of_powers_tests<F: Field, R: Rng>(rng: &mut R) {
+    for num_bases in 0..8 {
+        let bases = (0..num_bases).map(|_| F::rand(rng)).collect::<Vec<_>>();
+        // Use exponents of different lengths, including zero.
+        let exps = (0..num_bases).map(|i| (0..i % 3).map(|_| rng.gen::<u64>()).collect::<Vec<_>>()).collect::<Vec<_>>();
+
+        let expected = bases.iter().zip(&exps).fold(F::one(), |acc, (base, exp)| acc * base.pow(exp));
+        assert_eq!(expected, F::product_of_powers(&bases, &exps));
+    }
+
+    // Compare against `pow` for a single base.
+    let base = F::rand(rng);
+    for exp in [[0u64, 0], [1, 0], [0, 1], [u64::MAX, u64::MAX]] {
+        assert_eq!(base.pow(exp), F::product_of_powers(&[base], &[exp]));
+    }
+}
+
 fn random_string_tests<F: PrimeField>() {
     let mut rng = test_rng();
 
@@ -312,6 +340,7 @@ pub fn field_test<F: Field>(a: F, b: F) {
     random_doubling_tests::<F, _>(&mut rng);
     random_squaring_tests::<F, _>(&mut rng);
     random_expansion_tests::<F, _>(&mut rng);
+    random_product_of_powers_tests::<F, _>(&mut rng);
 
     assert!(F::zero().is_zero());
     {
@@ -363,7 +392,28 @@ pub fn fft_field_test<F: PrimeField + FftField>() {
     assert_eq!(generator.pow(trace.to_repr().as_ref()), two_adic_root_of_unity);
 }
 
-pub fn primefield_test<F: PrimeField>() {
+fn random_rand_range_tests<F: PrimeField + UniformRandRange>() {
+    let mut rng = test_rng();
+
+    // Sample below a small bound, and ensure every value in range is hit.
+    let bound = F::from(16u64);
+    let mut buckets = [0usize; 16];
+    for _ in 0..1600 {
+        let sample = F::rand_range(&mut rng, &bound);
+        assert!(sample.to_repr() < bound.to_repr());
+        buckets[sample.to_repr().as_ref()[0] as usize] += 1;
+    }
+    assert!(buckets.iter().all(|count| *count > 0));
+
+    // Sample below a random bound.
+    for _ in 0..ITERATIONS {
+        let bound = F::rand(&mut rng);
+        let sample = F::rand_range(&mut rng, &bound);
+        assert!(sample.to_repr() < bound.to_repr());
+    }
+}
+
+pub fn primefield_test<F: PrimeField + UniformRandRange>() {
     let one = F::one();
     assert_eq!(F::from_repr(one.to_repr()).unwrap(), one);
     assert_eq!(F::from_str("1").ok().unwrap(), one);
@@ -374,7 +424,14 @@ pub fn primefield_test<F: PrimeField>() {
     assert_eq!(F::from_str("2").ok().unwrap(), two);
     assert_eq!(F::from_str(&two.to_string()).ok().unwrap(), two);
 
+    // The modulus is not a valid field element.
+    let modulus = F::modulus().to_bytes_le().unwrap();
+    let error = F::read_le(&modulus[..]).unwrap_err();
+    assert!(matches!(SerializationError::from(error), SerializationError::NotInField));
+    assert!(matches!(<F as CanonicalDeserialize>::deserialize(&mut &modulus[..]), Err(SerializationError::NotInField)));
+
     random_string_tests::<F>();
+    random_rand_range_tests::<F>();
     fft_field_test::<F>();
 }
 
@@ -471,7 +528,6 @@ pub fn field_serialization_test<F: Field>() {
             }
         }
 
-        use snarkvm_utilities::serialize::SerializationError;
         {
             let mut serialized = vec![0; buf_size];
             assert!(matches!(
@@ -490,7 +546,73 @@ pub fn field_serialization_test<F: Field>() {
             CanonicalSerialize::serialize(&a, &mut cursor).unwrap_err();
 
             let mut cursor = Cursor::new(&serialized[..]);
-            <F as CanonicalDeserialize>::deserialize(&mut cursor).unwrap_err();
+            assert!(matches!(
+                <F as CanonicalDeserialize>::deserialize(&mut cursor),
+                Err(SerializationError::UnexpectedEof)
+            ));
+        }
+    }
+
+    array_serialization_test::<F, 1>();
+    array_serialization_test::<F, 4>();
+    array_serialization_test::<F, 8>();
+}
+
+fn array_serialization_test<F: Field, const N: usize>() {
+    let mut rng = &mut rand::thread_rng();
+
+    for _ in 0..ITERATIONS {
+        let a: [F; N] = [(); N].map(|_| F::rand(&mut rng));
+        assert_eq!(a.serialized_size(), N * F::SERIALIZED_SIZE);
+
+        let mut serialized = vec![0u8; a.serialized_size()];
+        a.serialize(&mut &mut serialized[..]).unwrap();
+        assert_eq!(a, <[F; N]>::deserialize(&mut &serialized[..]).unwrap());
+
+        let mut serialized_uncompressed = vec![0u8; a.uncompressed_size()];
+        a.serialize_uncompressed(&mut &mut serialized_uncompressed[..]).unwrap();
+        assert_eq!(a, <[F; N]>::deserialize_uncompressed(&mut &serialized_uncompressed[..]).unwrap());
+
+        // Deserializing from a truncated buffer must fail.
+        assert!(<[F; N]>::deserialize(&mut &serialized[..serialized.len() - 1]).is_err());
+        assert!(<[F; N]>::deserialize_uncompressed(&mut &serialized_uncompressed[..serialized.len() - 1]).is_err());
+    }
+}
+
+/// Checks that the default Poseidon parameters are reproduced by the parameter generator.
+pub fn poseidon_parameters_test<F: PrimeField + PoseidonDefaultField>() {
+    fn check<F: PrimeField + PoseidonDefaultField, const RATE: usize>() {
+        for optimized_for_weights in [false, true] {
+            let expected = F::default_poseidon_parameters::<RATE>(optimized_for_weights).unwrap();
+            let candidate = PoseidonParameters::<F, RATE, 1>::setup(expected.alpha, 128).unwrap();
+            assert_eq!(expected, *candidate);
+
+            // Ensure the parameters are cached.
+            let cached = PoseidonParameters::<F, RATE, 1>::setup(expected.alpha, 128).unwrap();
+            assert!(std::sync::Arc::ptr_eq(&candidate, &cached));
+
+            // Ensure the parameters are serialized and deserialized.
+            let bytes = candidate.to_bytes_le().unwrap();
+            assert_eq!(expected, PoseidonParameters::<F, RATE, 1>::from_bytes_le(&bytes).unwrap());
         }
     }
+
+    check::<F, 2>();
+    check::<F, 3>();
+    check::<F, 4>();
+    check::<F, 5>();
+    check::<F, 6>();
+    check::<F, 7>();
+    check::<F, 8>();
+
+    // Ensure parameters are generated for a wider rate.
+    let alpha = F::default_poseidon_parameters::<2>(false).unwrap().alpha;
+    let parameters = PoseidonParameters::<F, 16, 1>::setup(alpha, 128).unwrap();
+    assert_eq!(parameters.full_rounds + parameters.partial_rounds, parameters.ark.len());
+    assert!(parameters.ark.iter().all(|round| round.len() == 17));
+    assert_eq!(17, parameters.mds.len());
+    assert!(parameters.mds.iter().all(|row| row.len() == 17));
+
+    // Ensure an S-box that is not a permutation is rejected, as `p - 1` is even.
+    assert!(PoseidonParameters::<F, 2, 1>::setup(2, 128).is_err());
 }
@@ -15,8 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::traits::{AffineCurve, ProjectiveCurve};
-use snarkvm_fields::Zero;
-use snarkvm_utilities::rand::{test_rng, UniformRand};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
+    rand::{test_rng, UniformRand},
+};
 
 use std::ops::Mul;
 
@@ -184,6 +187,73 @@ fn random_negation_test<G: ProjectiveCurve>() {
         t1 = -t1;
         assert_eq!(t1, t2);
     }
+
+    for _ in 0..ITERATIONS {
+        let r = G::rand(&mut rng);
+        let r_affine = r.to_affine();
+
+        // Negating in place matches negation by value.
+        let (mut s, mut s_affine) = (r, r_affine);
+        s.neg_in_place();
+        s_affine.neg_in_place();
+        assert_eq!(-r, s);
+        assert_eq!(-r_affine, s_affine);
+
+        // P + (-P) is the point at infinity.
+        assert!((r + s).is_zero());
+        assert!(r.add_mixed(&s_affine).is_zero());
+
+        // Negating in place twice is the identity.
+        s.neg_in_place();
+        s_affine.neg_in_place();
+        assert_eq!(r, s);
+        assert_eq!(r_affine, s_affine);
+    }
+
+    // The negation of the point at infinity is itself.
+    let (mut zero, mut zero_affine) = (G::zero(), G::Affine::zero());
+    zero.neg_in_place();
+    zero_affine.neg_in_place();
+    assert!(zero.is_zero());
+    assert!(zero_affine.is_zero());
+}
+
+fn random_mixed_addition_test<G: ProjectiveCurve>() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = G::rand(&mut rng);
+        let b = G::rand(&mut rng).to_affine();
+
+        // P + Q
+        assert_eq!(a + b.to_projective(), a.add_mixed(&b));
+        // P + P
+        assert_eq!(a.double(), a.add_mixed(&a.to_affine()));
+        // P + (-P)
+        assert!(a.add_mixed(&(-a).to_affine()).is_zero());
+        // 0 + Q
+        assert_eq!(b.to_projective(), G::zero().add_mixed(&b));
+        // P + 0
+        assert_eq!(a, a.add_mixed(&G::Affine::zero()));
+        // 0 + 0
+        assert!(G::zero().add_mixed(&G::Affine::zero()).is_zero());
+    }
+}
+
+fn random_constant_time_multiplication_test<G: ProjectiveCurve>() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = G::rand(&mut rng);
+        let s = G::ScalarField::rand(&mut rng);
+
+        // a * s
+        assert_eq!(a * s, a.mul_bits_constant_time(BitIteratorBE::new(s.to_repr())));
+        // a * 0
+        assert!(a.mul_bits_constant_time(BitIteratorBE::new(G::ScalarField::zero().to_repr())).is_zero());
+        // 0 * s
+        assert!(G::zero().mul_bits_constant_time(BitIteratorBE::new(s.to_repr())).is_zero());
+    }
 }
 
 fn random_transformation_test<G: ProjectiveCurve>() {
@@ -279,5 +349,7 @@ pub fn curve_tests<G: ProjectiveCurve>() {
     random_multiplication_test::<G>();
     random_doubling_test::<G>();
     random_negation_test::<G>();
+    random_mixed_addition_test::<G>();
+    random_constant_time_multiplication_test::<G>();
     random_transformation_test::<G>();
 }
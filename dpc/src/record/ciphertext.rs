@@ -118,7 +118,7 @@ impl<N: Network> Ciphertext<N> {
         match *self.record_view_key_commitment == candidate_record_view_key_commitment {
             // Decrypt the record ciphertext.
             true => {
-                let plaintext = N::account_encryption_scheme().decrypt(&record_view_key, &self.record_elements);
+                let plaintext = N::account_encryption_scheme().decrypt_unauthenticated(&record_view_key, &self.record_elements);
                 Ok((plaintext, record_view_key))
             }
             false => Err(anyhow!("The given record view key does not correspond to this ciphertext")),
@@ -77,6 +77,14 @@ impl<P: Fp12Parameters> Fp12<P> {
         self.c1 = self.c1.neg();
     }
 
+    /// Returns the element of the subfield `Fp6` that is equal to `self`, if `self.c1` is zero.
+    pub fn try_into_fp6(&self) -> Option<Fp6<P::Fp6Params>> {
+        match self.c1.is_zero() {
+            true => Some(self.c0),
+            false => None,
+        }
+    }
+
     pub fn mul_by_034(&mut self, c0: &Fp2<Fp2Params<P>>, c3: &Fp2<Fp2Params<P>>, c4: &Fp2<Fp2Params<P>>) {
         let a0 = self.c0.c0 * c0;
         let a1 = self.c0.c1 * c0;
@@ -445,6 +453,13 @@ impl<P: Fp12Parameters> PartialOrd for Fp12<P> {
     }
 }
 
+/// Embeds an element of the subfield `Fp6` into `Fp12`, as `c0`.
+impl<P: Fp12Parameters> From<Fp6<P::Fp6Params>> for Fp12<P> {
+    fn from(other: Fp6<P::Fp6Params>) -> Self {
+        Self::new(other, Fp6::zero())
+    }
+}
+
 impl<P: Fp12Parameters> From<u128> for Fp12<P> {
     fn from(other: u128) -> Self {
         Self::new(other.into(), Fp6::zero())
@@ -31,7 +31,7 @@ use crate::{
 };
 use snarkvm_utilities::{
     biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger256 as BigInteger},
-    serialize::CanonicalDeserialize,
+    serialize::{CanonicalDeserialize, SerializationError},
     FromBytes,
     ToBits,
     ToBytes,
@@ -442,6 +442,7 @@ impl_primefield_from_int!(Fp256, u16, Fp256Parameters);
 impl_primefield_from_int!(Fp256, u8, Fp256Parameters);
 
 impl_primefield_standard_sample!(Fp256, Fp256Parameters);
+impl_primefield_uniform_rand_range!(Fp256, Fp256Parameters);
 
 impl_add_sub_from_field_ref!(Fp256, Fp256Parameters);
 impl_mul_div_from_field_ref!(Fp256, Fp256Parameters);
@@ -474,7 +475,7 @@ impl<P: Fp256Parameters> FromBytes for Fp256<P> {
     fn read_le<R: Read>(reader: R) -> IoResult<Self> {
         BigInteger::read_le(reader).and_then(|b| match Self::from_repr(b) {
             Some(f) => Ok(f),
-            None => Err(FieldError::InvalidFieldElement.into()),
+            None => Err(SerializationError::NotInField.into()),
         })
     }
 }
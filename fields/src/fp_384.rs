@@ -31,7 +31,7 @@ use crate::{
 };
 use snarkvm_utilities::{
     biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger384 as BigInteger},
-    serialize::CanonicalDeserialize,
+    serialize::{CanonicalDeserialize, SerializationError},
     FromBytes,
     ToBits,
     ToBytes,
@@ -531,6 +531,7 @@ impl_primefield_from_int!(Fp384, u16, Fp384Parameters);
 impl_primefield_from_int!(Fp384, u8, Fp384Parameters);
 
 impl_primefield_standard_sample!(Fp384, Fp384Parameters);
+impl_primefield_uniform_rand_range!(Fp384, Fp384Parameters);
 
 impl_add_sub_from_field_ref!(Fp384, Fp384Parameters);
 impl_mul_div_from_field_ref!(Fp384, Fp384Parameters);
@@ -563,7 +564,7 @@ impl<P: Fp384Parameters> FromBytes for Fp384<P> {
     fn read_le<R: Read>(reader: R) -> IoResult<Self> {
         BigInteger::read_le(reader).and_then(|b| match Self::from_repr(b) {
             Some(f) => Ok(f),
-            None => Err(FieldError::InvalidFieldElement.into()),
+            None => Err(SerializationError::NotInField.into()),
         })
     }
 }
@@ -57,6 +57,19 @@ macro_rules! impl_primefield_standard_sample {
     };
 }
 
+macro_rules! impl_primefield_uniform_rand_range {
+    ($field: ident, $params: ident) => {
+        impl<P: $params> snarkvm_utilities::UniformRandRange for $field<P> {
+            /// Samples a field element uniformly from `[0, bound)`, in the canonical integer ordering.
+            #[inline]
+            fn rand_range<R: rand::Rng + ?Sized>(rng: &mut R, bound: &Self) -> Self {
+                let repr = P::BigInteger::rand_range(rng, &bound.to_repr());
+                Self::from_repr(repr).unwrap()
+            }
+        }
+    };
+}
+
 macro_rules! impl_primefield_from_int {
     ($field: ident, u128, $params: ident) => {
         impl<P: $params> From<u128> for $field<P> {
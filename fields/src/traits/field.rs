@@ -159,6 +159,34 @@ pub trait Field:
         res
     }
 
+    /// Returns the product of each base raised to its exponent, where each exponent is
+    /// represented with `u64` limbs, least significant limb first.
+    ///
+    /// The squarings are shared by all of the bases. Panics if the number of bases and exponents differ.
+    #[must_use]
+    fn product_of_powers<S: AsRef<[u64]>>(bases: &[Self], exps: &[S]) -> Self {
+        assert_eq!(bases.len(), exps.len(), "Mismatching number of bases and exponents");
+
+        let mut res = Self::one();
+
+        let mut found_one = false;
+
+        let num_bits = exps.iter().map(|exp| exp.as_ref().len() * 64).max().unwrap_or(0);
+        for i in (0..num_bits).rev() {
+            if found_one {
+                res.square_in_place();
+            }
+
+            for (base, exp) in bases.iter().zip(exps) {
+                if exp.as_ref().get(i / 64).map_or(false, |limb| (limb >> (i % 64)) & 1 == 1) {
+                    res *= base;
+                    found_one = true;
+                }
+            }
+        }
+        res
+    }
+
     /// Returns a field element if the set of bytes forms a valid field element,
     /// otherwise returns None. This function is primarily intended for sampling
     /// random field elements from a hash-function or RNG output.
@@ -35,6 +35,8 @@ pub use poseidon_grain_lfsr::*;
 mod poseidon_default;
 pub use poseidon_default::*;
 
+mod poseidon_setup;
+
 mod prime_field;
 pub use prime_field::*;
 
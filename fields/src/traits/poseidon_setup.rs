@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{PoseidonGrainLFSR, PoseidonParameters, PrimeField};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    BigInteger,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::{bail, ensure, Result};
+use once_cell::sync::Lazy;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// The maximum number of Cauchy matrices to sample, before the setup fails.
+const MAX_MDS_ATTEMPTS: usize = 1000;
+
+/// The key of the cache of generated parameters, as (field, rate, capacity, alpha, security level).
+type CacheKey = (TypeId, usize, usize, u64, u64);
+
+/// The cache of generated parameters.
+static POSEIDON_PARAMETERS: Lazy<Mutex<HashMap<CacheKey, Arc<dyn Any + Send + Sync>>>> = Lazy::new(Default::default);
+
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> PoseidonParameters<F, RATE, CAPACITY> {
+    ///
+    /// Returns the Poseidon parameters for the S-box `x^alpha`, at the given security level (in bits).
+    ///
+    /// The numbers of full and partial rounds are the cheapest (in S-boxes) that resist the statistical,
+    /// interpolation, and Gröbner basis attacks, with a security margin of 2 full rounds and 7.5% partial rounds.
+    /// The round constants and the MDS matrix are derived from the Grain LFSR, where Cauchy matrices are
+    /// skipped until one is well-defined, which ensures it is MDS. Note that the invariant subspace trail
+    /// checks of the reference implementation are not performed, as in the default parameters.
+    ///
+    /// The parameters are generated once for each configuration, and cached for subsequent calls.
+    ///
+    pub fn setup(alpha: u64, security_level: u64) -> Result<Arc<Self>> {
+        let key = (TypeId::of::<F>(), RATE, CAPACITY, alpha, security_level);
+
+        let mut cache = match POSEIDON_PARAMETERS.lock() {
+            Ok(cache) => cache,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if let Some(parameters) = cache.get(&key) {
+            match parameters.clone().downcast::<Self>() {
+                Ok(parameters) => return Ok(parameters),
+                Err(_) => bail!("Found Poseidon parameters of an unexpected type in the cache"),
+            }
+        }
+
+        let parameters = Arc::new(Self::generate(alpha, security_level)?);
+        cache.insert(key, parameters.clone());
+        Ok(parameters)
+    }
+
+    /// Returns newly-generated Poseidon parameters for the S-box `x^alpha`, at the given security level (in bits).
+    fn generate(alpha: u64, security_level: u64) -> Result<Self> {
+        ensure!(RATE > 0 && CAPACITY > 0, "The rate and capacity of Poseidon must be non-zero");
+        let width = RATE + CAPACITY;
+
+        // Ensure the S-box is a permutation, which holds if `gcd(alpha, p - 1) == 1`.
+        let modulus = F::modulus().to_biguint();
+        let remainder = (&modulus - 1u64) % alpha.max(1);
+        ensure!(
+            alpha > 1 && gcd(alpha, remainder.to_u64_digits().first().copied().unwrap_or(0)) == 1,
+            "The S-box exponent {alpha} is not a permutation of the field"
+        );
+
+        // Compute `log2(p)`, from the 64 most significant bits of the modulus.
+        let shift = modulus.bits().saturating_sub(64);
+        let log2_modulus = shift as f64 + ((&modulus >> shift).to_u64_digits()[0] as f64).log2();
+
+        let (full_rounds, partial_rounds) = match find_round_numbers(log2_modulus, width, alpha, security_level) {
+            Some(round_numbers) => round_numbers,
+            None => bail!("Failed to find Poseidon round numbers for {security_level}-bit security"),
+        };
+
+        let mut lfsr = PoseidonGrainLFSR::new(
+            false,
+            F::size_in_bits() as u64,
+            width as u64,
+            full_rounds as u64,
+            partial_rounds as u64,
+        );
+
+        // Sample the round constants.
+        let ark = (0..full_rounds + partial_rounds).map(|_| lfsr.get_field_elements_rejection_sampling(width)).collect();
+
+        // Sample Cauchy matrices until a well-defined one is found.
+        for _ in 0..MAX_MDS_ATTEMPTS {
+            let xs = lfsr.get_field_elements_mod_p::<F>(width);
+            let ys = lfsr.get_field_elements_mod_p::<F>(width);
+
+            if let Some(mds) = cauchy_matrix(&xs, &ys) {
+                return Ok(Self { full_rounds, partial_rounds, alpha, ark, mds });
+            }
+        }
+        bail!("Failed to find a Poseidon MDS matrix in {MAX_MDS_ATTEMPTS} attempts")
+    }
+}
+
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> ToBytes for PoseidonParameters<F, RATE, CAPACITY> {
+    /// Writes the parameters, where the dimensions of the round constants and MDS matrix are implied by the width.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.full_rounds as u32).write_le(&mut writer)?;
+        (self.partial_rounds as u32).write_le(&mut writer)?;
+        self.alpha.write_le(&mut writer)?;
+        for element in self.ark.iter().chain(&self.mds).flatten() {
+            element.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> FromBytes for PoseidonParameters<F, RATE, CAPACITY> {
+    /// Reads the parameters, where the dimensions of the round constants and MDS matrix are implied by the width.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let full_rounds = u32::read_le(&mut reader)? as usize;
+        let partial_rounds = u32::read_le(&mut reader)? as usize;
+        let alpha = u64::read_le(&mut reader)?;
+
+        let mut read_matrix = |num_rows: usize| {
+            (0..num_rows)
+                .map(|_| (0..RATE + CAPACITY).map(|_| F::read_le(&mut reader)).collect::<IoResult<Vec<_>>>())
+                .collect::<IoResult<Vec<_>>>()
+        };
+        let ark = read_matrix(full_rounds + partial_rounds)?;
+        let mds = read_matrix(RATE + CAPACITY)?;
+
+        Ok(Self { full_rounds, partial_rounds, alpha, ark, mds })
+    }
+}
+
+///
+/// Returns the cheapest (in S-boxes) numbers of full and partial rounds that resist the statistical,
+/// interpolation, and Gröbner basis attacks, with the security margin applied.
+///
+/// This follows `calc_round_numbers.py` of the reference implementation, for the S-box `x^alpha`.
+///
+fn find_round_numbers(log2_modulus: f64, width: usize, alpha: u64, security_level: u64) -> Option<(usize, usize)> {
+    let (t, alpha, m) = (width as f64, alpha as f64, security_level as f64);
+    let n = log2_modulus.ceil();
+
+    // Returns `true` if the given numbers of rounds resist the known attacks.
+    let is_secure = |full_rounds: f64, partial_rounds: f64| {
+        let statistical = if m <= (log2_modulus - (alpha - 1.0) / 2.0).floor() * (t + 1.0) { 6.0 } else { 10.0 };
+        let interpolation = 1.0 + (2f64.log(alpha) * m.min(n)).ceil() + t.log(alpha).ceil() - partial_rounds;
+        let groebner_1 = 2f64.log(alpha) * m.min(log2_modulus) - partial_rounds;
+        let groebner_2 = t - 1.0 + 2f64.log(alpha) * (m / (t + 1.0)).min(log2_modulus / 2.0) - partial_rounds;
+        let groebner_3 = (t - 2.0 + m / (2.0 * alpha.log2()) - partial_rounds) / (t - 1.0);
+
+        let bounds = [statistical, interpolation, groebner_1, groebner_2, groebner_3];
+        full_rounds >= bounds.iter().map(|bound| bound.ceil()).fold(f64::MIN, f64::max)
+    };
+
+    let mut best: Option<(usize, usize, usize)> = None;
+    for partial_rounds in 1..500usize {
+        for full_rounds in (4..100usize).step_by(2) {
+            if is_secure(full_rounds as f64, partial_rounds as f64) {
+                // Apply the security margin.
+                let full_rounds = full_rounds + 2;
+                let partial_rounds = (partial_rounds as f64 * 1.075).ceil() as usize;
+
+                // Minimize the number of S-boxes, breaking ties with fewer full rounds.
+                let cost = width * full_rounds + partial_rounds;
+                let is_cheaper = match best {
+                    Some((best_cost, best_full_rounds, _)) => {
+                        cost < best_cost || (cost == best_cost && full_rounds < best_full_rounds)
+                    }
+                    None => true,
+                };
+                if is_cheaper {
+                    best = Some((cost, full_rounds, partial_rounds));
+                }
+            }
+        }
+    }
+    best.map(|(_, full_rounds, partial_rounds)| (full_rounds, partial_rounds))
+}
+
+///
+/// Returns the Cauchy matrix `M[i][j] = 1 / (x_i + y_j)`, if the elements are distinct and no `x_i + y_j` is zero.
+/// As every square submatrix of such a matrix is invertible, the matrix is MDS.
+///
+fn cauchy_matrix<F: PrimeField>(xs: &[F], ys: &[F]) -> Option<Vec<Vec<F>>> {
+    let is_distinct = |elements: &[F]| elements.iter().enumerate().all(|(i, a)| !elements[..i].contains(a));
+    if !is_distinct(xs) || !is_distinct(ys) {
+        return None;
+    }
+    xs.iter().map(|x| ys.iter().map(|y| (*x + y).inverse()).collect()).collect()
+}
+
+/// Returns the greatest common divisor of the given integers.
+fn gcd(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
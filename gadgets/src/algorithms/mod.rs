@@ -21,3 +21,6 @@ pub mod encryption;
 pub mod merkle_tree;
 pub mod prf;
 pub mod signature;
+
+#[cfg(feature = "nonnative")]
+pub mod snark;
@@ -39,7 +39,7 @@ use snarkvm_curves::{
     ProjectiveCurve,
     TwistedEdwardsParameters,
 };
-use snarkvm_fields::{FieldParameters, PrimeField};
+use snarkvm_fields::{FieldParameters, PrimeField, ToConstraintField};
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
@@ -378,6 +378,56 @@ impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField> SignatureGadget
         public_key: &Self::PublicKeyGadget,
         message: &[Boolean],
         signature: &Self::SignatureGadget,
+    ) -> Result<Boolean, SynthesisError> {
+        // Construct the message input (message length, message).
+        let mut input = vec![FpGadget::<F>::Constant(F::from(message.len() as u128))];
+        input.extend_from_slice(&message.to_constraint_field(cs.ns(|| "convert message into field elements"))?);
+
+        self.verify_with_input(cs, public_key, &input, signature)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField> AleoSignatureSchemeGadget<TE, F> {
+    /// Verifies a signature from `AleoSignatureScheme::sign_with_domain` on the given message under the given domain.
+    pub fn verify_with_domain<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        public_key: &AleoSignaturePublicKeyGadget<TE, F>,
+        domain: &[u8],
+        message: &[FpGadget<F>],
+        signature: &AleoSignatureGadget<TE, F>,
+    ) -> Result<Boolean, SynthesisError> {
+        // Construct the domain input (-1, domain length, domain, message length, message).
+        let mut input =
+            vec![FpGadget::<F>::Constant(-F::one()), FpGadget::<F>::Constant(F::from(domain.len() as u128))];
+        input.extend(domain.to_field_elements()?.into_iter().map(FpGadget::<F>::Constant));
+        input.push(FpGadget::<F>::Constant(F::from(message.len() as u128)));
+        input.extend_from_slice(message);
+
+        self.verify_with_input(cs, public_key, &input, signature)
+    }
+
+    /// Verifies a signature from `AleoSignatureScheme::sign_prehashed` on the given digest.
+    pub fn verify_prehashed<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        public_key: &AleoSignaturePublicKeyGadget<TE, F>,
+        digest: &FpGadget<F>,
+        signature: &AleoSignatureGadget<TE, F>,
+    ) -> Result<Boolean, SynthesisError> {
+        // Construct the prehashed input (-2, digest).
+        let input = [FpGadget::<F>::Constant(-F::one().double()), digest.clone()];
+
+        self.verify_with_input(cs, public_key, &input, signature)
+    }
+
+    /// Verifies the signature, where the verifier challenge is derived from (G^sk_sig G^r_sig G^sk_prf, G^r, input).
+    fn verify_with_input<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        public_key: &AleoSignaturePublicKeyGadget<TE, F>,
+        input: &[FpGadget<F>],
+        signature: &AleoSignatureGadget<TE, F>,
     ) -> Result<Boolean, SynthesisError> {
         // Prepare the zero element in affine form for use.
         let zero_affine: TEAffineGadget<TE, F> =
@@ -439,13 +489,12 @@ impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField> SignatureGadget
 
         // Compute the candidate verifier challenge.
         let candidate_verifier_challenge = {
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
+            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, input).
             let mut preimage = Vec::new();
             preimage
                 .extend_from_slice(&public_key.0.x.to_constraint_field(cs.ns(|| "public_key to constraint field"))?);
             preimage.extend_from_slice(&g_r.x.to_constraint_field(cs.ns(|| "G^r to constraint field"))?);
-            preimage.push(FpGadget::<F>::Constant(F::from(message.len() as u128)));
-            preimage.extend_from_slice(&message.to_constraint_field(cs.ns(|| "convert message into field elements"))?);
+            preimage.extend_from_slice(input);
 
             // Compute the hash of the preimage on the base field.
             let hash = PoseidonCryptoHashGadget::<F, 4, false>::check_evaluation_gadget(
@@ -19,11 +19,12 @@ mod aleo {
         algorithms::signature::AleoSignatureSchemeGadget,
         traits::{algorithms::SignatureGadget, alloc::AllocGadget, eq::EqGadget},
         Boolean,
+        FpGadget,
     };
     use snarkvm_algorithms::{signature::AleoSignatureScheme, traits::SignatureScheme};
     use snarkvm_curves::{bls12_377::Fr, edwards_bls12::EdwardsParameters};
     use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
-    use snarkvm_utilities::ToBits;
+    use snarkvm_utilities::{ToBits, UniformRand};
 
     use rand::SeedableRng;
     use rand_chacha::ChaChaRng;
@@ -145,4 +146,100 @@ mod aleo {
         }
         assert!(cs.is_satisfied());
     }
+
+    #[test]
+    fn test_signature_verification_with_domain() {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+        let message = (0..5).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        let signature_scheme = TestSignatureScheme::setup("aleo_signature_verification_with_domain_test");
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let signature = signature_scheme.sign_with_domain(&private_key, b"first", &message, rng).unwrap();
+        assert!(signature_scheme.verify_with_domain(&public_key, b"first", &message, &signature).unwrap());
+
+        for (domain, expected) in [(&b"first"[..], true), (&b"second"[..], false)] {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let signature_scheme_gadget =
+                TestSignatureSchemeGadget::alloc_constant(&mut cs.ns(|| "signature_scheme_gadget"), || {
+                    Ok(signature_scheme.clone())
+                })
+                .unwrap();
+            let public_key_gadget =
+                <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::PublicKeyGadget::alloc(
+                    cs.ns(|| "alloc_public_key"),
+                    || Ok(public_key),
+                )
+                .unwrap();
+            let message_gadget = message
+                .iter()
+                .enumerate()
+                .map(|(i, element)| FpGadget::alloc(cs.ns(|| format!("alloc_message {i}")), || Ok(element)).unwrap())
+                .collect::<Vec<_>>();
+            let signature_gadget =
+                <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::SignatureGadget::alloc(
+                    cs.ns(|| "alloc_signature"),
+                    || Ok(signature),
+                )
+                .unwrap();
+
+            let verification = signature_scheme_gadget
+                .verify_with_domain(cs.ns(|| "verify"), &public_key_gadget, domain, &message_gadget, &signature_gadget)
+                .unwrap();
+
+            verification.enforce_equal(cs.ns(|| "check_verification"), &Boolean::constant(expected)).unwrap();
+
+            if !cs.is_satisfied() {
+                println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+            }
+            assert!(cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn test_signature_verification_prehashed() {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+        let digest = Fr::rand(rng);
+
+        let signature_scheme = TestSignatureScheme::setup("aleo_signature_verification_prehashed_test");
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let signature = signature_scheme.sign_prehashed(&private_key, digest, rng).unwrap();
+        assert!(signature_scheme.verify_prehashed(&public_key, digest, &signature).unwrap());
+
+        for (candidate, expected) in [(digest, true), (Fr::rand(rng), false)] {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let signature_scheme_gadget =
+                TestSignatureSchemeGadget::alloc_constant(&mut cs.ns(|| "signature_scheme_gadget"), || {
+                    Ok(signature_scheme.clone())
+                })
+                .unwrap();
+            let public_key_gadget =
+                <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::PublicKeyGadget::alloc(
+                    cs.ns(|| "alloc_public_key"),
+                    || Ok(public_key),
+                )
+                .unwrap();
+            let digest_gadget = FpGadget::alloc(cs.ns(|| "alloc_digest"), || Ok(candidate)).unwrap();
+            let signature_gadget =
+                <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::SignatureGadget::alloc(
+                    cs.ns(|| "alloc_signature"),
+                    || Ok(signature),
+                )
+                .unwrap();
+
+            let verification = signature_scheme_gadget
+                .verify_prehashed(cs.ns(|| "verify"), &public_key_gadget, &digest_gadget, &signature_gadget)
+                .unwrap();
+
+            verification.enforce_equal(cs.ns(|| "check_verification"), &Boolean::constant(expected)).unwrap();
+
+            if !cs.is_satisfied() {
+                println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+            }
+            assert!(cs.is_satisfied());
+        }
+    }
 }
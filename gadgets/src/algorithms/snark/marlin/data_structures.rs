@@ -0,0 +1,331 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::snark::marlin::fiat_shamir::nonnative_from_bits_be,
+    bits::Boolean,
+    nonnative::{NonNativeFieldInputVar, NonNativeFieldVar},
+    traits::{alloc::AllocGadget, curves::PairingGadget},
+};
+use snarkvm_algorithms::snark::marlin::{
+    ahp::indexer::CircuitInfo,
+    AHPForR1CS,
+    CircuitVerifyingKey,
+    MarlinMode,
+    Proof,
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{Field, PrimeField};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+use snarkvm_utilities::ToBits;
+
+use anyhow::anyhow;
+use std::{borrow::Borrow, marker::PhantomData};
+
+/// The way in which the values of a gadget are allocated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AllocationMode {
+    Constant,
+    Witness,
+    Input,
+}
+
+/// Allocates `value` with `mode`. Witness group elements are checked to be in the prime order subgroup.
+fn alloc_with_mode<V, T: AllocGadget<V, F>, F: Field, CS: ConstraintSystem<F>>(
+    cs: CS,
+    value: &V,
+    mode: AllocationMode,
+) -> Result<T, SynthesisError> {
+    match mode {
+        AllocationMode::Constant => T::alloc_constant(cs, || Ok(value)),
+        AllocationMode::Witness => T::alloc_checked(cs, || Ok(value)),
+        AllocationMode::Input => T::alloc_input(cs, || Ok(value)),
+    }
+}
+
+/// Allocates the target field element `value` with `mode`.
+/// Witnesses are allocated through their bits, so that their limbs are in the normal form.
+fn alloc_nonnative_with_mode<TargetField: PrimeField, BaseField: PrimeField, CS: ConstraintSystem<BaseField>>(
+    mut cs: CS,
+    value: &TargetField,
+    mode: AllocationMode,
+) -> Result<NonNativeFieldVar<TargetField, BaseField>, SynthesisError> {
+    match mode {
+        AllocationMode::Constant => NonNativeFieldVar::alloc_constant(cs, || Ok(value)),
+        AllocationMode::Witness => {
+            let value_bits = value.to_repr().to_bits_be();
+            let num_leading_bits = value_bits.len() - TargetField::size_in_bits();
+
+            let mut bits = Vec::with_capacity(TargetField::size_in_bits());
+            for (i, bit) in value_bits[num_leading_bits..].iter().enumerate() {
+                bits.push(Boolean::alloc(cs.ns(|| format!("bit_{}", i)), || Ok(*bit))?);
+            }
+            Boolean::enforce_in_field::<_, _, TargetField>(cs.ns(|| "enforce_in_field"), &bits)?;
+
+            Ok(nonnative_from_bits_be::<_, _, CS>(&bits))
+        }
+        AllocationMode::Input => {
+            Ok(NonNativeFieldInputVar::alloc_input(cs.ns(|| "alloc_input"), || Ok(vec![*value]))?.val.remove(0))
+        }
+    }
+}
+
+/// The gadget for the circuit verifying key of Marlin.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "E: PairingEngine, PG: PairingGadget<E>, MM: MarlinMode"))]
+pub struct CircuitVerifyingKeyVar<E: PairingEngine, PG: PairingGadget<E>, MM: MarlinMode> {
+    /// Stores information about the size of the circuit.
+    pub circuit_info: CircuitInfo<E::Fr>,
+    /// Commitments to the indexed polynomials.
+    pub circuit_commitments: Vec<PG::G1Gadget>,
+    /// The generator of G1.
+    pub g: PG::G1Gadget,
+    /// The generator of G1 that is used for making a commitment hiding.
+    pub gamma_g: PG::G1Gadget,
+    /// The generator of G2, prepared for use in pairings.
+    pub prepared_h: PG::G2PreparedGadget,
+    /// \beta times the generator of G2, prepared for use in pairings.
+    pub prepared_beta_h: PG::G2PreparedGadget,
+    /// Pairs each degree bound enforced by Marlin with its prepared shift power.
+    pub degree_bounds_and_prepared_shift_powers: Vec<(usize, PG::G2PreparedGadget)>,
+    #[doc(hidden)]
+    pub mode: PhantomData<MM>,
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>, MM: MarlinMode> CircuitVerifyingKeyVar<E, PG, MM> {
+    /// Returns the degree bounds of `g_1`, `g_a`, `g_b` and `g_c`, which are the only bounded polynomials.
+    pub fn degree_bounds(circuit_info: &CircuitInfo<E::Fr>) -> Vec<usize> {
+        let second_round_info = AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(circuit_info);
+        let third_round_info = AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(circuit_info);
+
+        let mut degree_bounds =
+            [&second_round_info["g_1"], &third_round_info["g_a"], &third_round_info["g_b"], &third_round_info["g_c"]]
+                .iter()
+                .filter_map(|info| info.degree_bound())
+                .collect::<Vec<_>>();
+        degree_bounds.sort_unstable();
+        degree_bounds.dedup();
+        degree_bounds
+    }
+
+    /// Returns the prepared shift power for `degree_bound`.
+    pub fn get_prepared_shift_power(&self, degree_bound: usize) -> Option<&PG::G2PreparedGadget> {
+        self.degree_bounds_and_prepared_shift_powers.iter().find(|(d, _)| *d == degree_bound).map(|(_, s)| s)
+    }
+
+    fn alloc_with_mode<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let verifier_key = &circuit_verifying_key.verifier_key;
+
+        let mut circuit_commitments = Vec::with_capacity(circuit_verifying_key.circuit_commitments.len());
+        for (i, commitment) in circuit_verifying_key.circuit_commitments.iter().enumerate() {
+            circuit_commitments.push(alloc_with_mode(
+                cs.ns(|| format!("circuit_commitment_{}", i)),
+                &commitment.0,
+                mode,
+            )?);
+        }
+
+        let g = alloc_with_mode(cs.ns(|| "g"), &verifier_key.vk.g, mode)?;
+        let gamma_g = alloc_with_mode(cs.ns(|| "gamma_g"), &verifier_key.vk.gamma_g, mode)?;
+        let prepared_h = alloc_with_mode(cs.ns(|| "prepared_h"), &verifier_key.vk.prepared_h, mode)?;
+        let prepared_beta_h = alloc_with_mode(cs.ns(|| "prepared_beta_h"), &verifier_key.vk.prepared_beta_h, mode)?;
+
+        let mut degree_bounds_and_prepared_shift_powers = Vec::new();
+        for degree_bound in Self::degree_bounds(&circuit_verifying_key.circuit_info) {
+            let shift_power = verifier_key
+                .get_prepared_shift_power(degree_bound)
+                .ok_or_else(|| anyhow!("The verifying key does not support the degree bound {}", degree_bound))?;
+            let shift_power_var =
+                alloc_with_mode(cs.ns(|| format!("shift_power_{}", degree_bound)), &shift_power, mode)?;
+            degree_bounds_and_prepared_shift_powers.push((degree_bound, shift_power_var));
+        }
+
+        Ok(Self {
+            circuit_info: circuit_verifying_key.circuit_info,
+            circuit_commitments,
+            g,
+            gamma_g,
+            prepared_h,
+            prepared_beta_h,
+            degree_bounds_and_prepared_shift_powers,
+            mode: PhantomData,
+        })
+    }
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>, MM: MarlinMode> AllocGadget<CircuitVerifyingKey<E, MM>, E::Fq>
+    for CircuitVerifyingKeyVar<E, PG, MM>
+{
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<CircuitVerifyingKey<E, MM>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Self::alloc_with_mode(cs, value_gen()?.borrow(), AllocationMode::Constant)
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<CircuitVerifyingKey<E, MM>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Self::alloc_with_mode(cs, value_gen()?.borrow(), AllocationMode::Witness)
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<CircuitVerifyingKey<E, MM>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Self::alloc_with_mode(cs, value_gen()?.borrow(), AllocationMode::Input)
+    }
+}
+
+/// The gadget for a KZG10 evaluation proof.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "E: PairingEngine, PG: PairingGadget<E>"))]
+pub struct KZGProofVar<E: PairingEngine, PG: PairingGadget<E>> {
+    /// The commitment to the witness polynomial.
+    pub w: PG::G1Gadget,
+    /// The evaluation of the random polynomial, for hiding proofs.
+    pub random_v: Option<NonNativeFieldVar<E::Fr, E::Fq>>,
+}
+
+/// The gadget for a Marlin proof of a single instance.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "E: PairingEngine, PG: PairingGadget<E>"))]
+pub struct ProofVar<E: PairingEngine, PG: PairingGadget<E>> {
+    /// The commitments to `w`, `z_a` and `z_b`.
+    pub witness_commitments: [PG::G1Gadget; 3],
+    /// The commitment to the masking polynomial, for hiding proofs.
+    pub mask_poly: Option<PG::G1Gadget>,
+    /// The commitments to `g_1` and `h_1`.
+    pub second_round_commitments: [PG::G1Gadget; 2],
+    /// The commitments to `g_a`, `g_b` and `g_c`.
+    pub third_round_commitments: [PG::G1Gadget; 3],
+    /// The commitment to `h_2`.
+    pub h_2: PG::G1Gadget,
+    /// The evaluations of `z_b` and `g_1` at beta, and of `g_a`, `g_b` and `g_c` at gamma.
+    pub evaluations: [NonNativeFieldVar<E::Fr, E::Fq>; 5],
+    /// The prover message: sum_a, sum_b, sum_c.
+    pub msg: [NonNativeFieldVar<E::Fr, E::Fq>; 3],
+    /// The evaluation proofs at beta and at gamma.
+    pub pc_proofs: Vec<KZGProofVar<E, PG>>,
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> ProofVar<E, PG> {
+    fn alloc_with_mode<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        proof: &Proof<E>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let commitments = &proof.commitments;
+        if commitments.witness_commitments.len() != 1 {
+            return Err(anyhow!("The Marlin verifier gadget only supports proofs of a single instance").into());
+        }
+        let witness_commitments = &commitments.witness_commitments[0];
+
+        let mut alloc_g1 = |name: &str, point: &E::G1Affine| alloc_with_mode(cs.ns(|| name), point, mode);
+        let witness_commitments = [
+            alloc_g1("w", &witness_commitments.w.0)?,
+            alloc_g1("z_a", &witness_commitments.z_a.0)?,
+            alloc_g1("z_b", &witness_commitments.z_b.0)?,
+        ];
+        let mask_poly = commitments.mask_poly.map(|mask_poly| alloc_g1("mask_poly", &mask_poly.0)).transpose()?;
+        let second_round_commitments = [alloc_g1("g_1", &commitments.g_1.0)?, alloc_g1("h_1", &commitments.h_1.0)?];
+        let third_round_commitments = [
+            alloc_g1("g_a", &commitments.g_a.0)?,
+            alloc_g1("g_b", &commitments.g_b.0)?,
+            alloc_g1("g_c", &commitments.g_c.0)?,
+        ];
+        let h_2 = alloc_g1("h_2", &commitments.h_2.0)?;
+
+        let mut alloc_nonnative =
+            |name: &str, value: &E::Fr| alloc_nonnative_with_mode(cs.ns(|| name.to_string()), value, mode);
+        let evaluations = &proof.evaluations;
+        let evaluations = [
+            alloc_nonnative("z_b_eval", &evaluations.z_b_evals[0])?,
+            alloc_nonnative("g_1_eval", &evaluations.g_1_eval)?,
+            alloc_nonnative("g_a_eval", &evaluations.g_a_eval)?,
+            alloc_nonnative("g_b_eval", &evaluations.g_b_eval)?,
+            alloc_nonnative("g_c_eval", &evaluations.g_c_eval)?,
+        ];
+        let msg = [
+            alloc_nonnative("sum_a", &proof.msg.sum_a)?,
+            alloc_nonnative("sum_b", &proof.msg.sum_b)?,
+            alloc_nonnative("sum_c", &proof.msg.sum_c)?,
+        ];
+
+        let mut pc_proofs = Vec::new();
+        for (i, pc_proof) in proof.pc_proof.proof.proofs().iter().enumerate() {
+            let w = alloc_with_mode(cs.ns(|| format!("pc_proof_{}_w", i)), &pc_proof.w, mode)?;
+            let random_v = pc_proof
+                .random_v
+                .map(|random_v| {
+                    alloc_nonnative_with_mode(cs.ns(|| format!("pc_proof_{}_random_v", i)), &random_v, mode)
+                })
+                .transpose()?;
+            pc_proofs.push(KZGProofVar { w, random_v });
+        }
+
+        Ok(Self {
+            witness_commitments,
+            mask_poly,
+            second_round_commitments,
+            third_round_commitments,
+            h_2,
+            evaluations,
+            msg,
+            pc_proofs,
+        })
+    }
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> AllocGadget<Proof<E>, E::Fq> for ProofVar<E, PG> {
+    fn alloc_constant<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<Proof<E>>, CS: ConstraintSystem<E::Fq>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Self::alloc_with_mode(cs, value_gen()?.borrow(), AllocationMode::Constant)
+    }
+
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<Proof<E>>, CS: ConstraintSystem<E::Fq>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Self::alloc_with_mode(cs, value_gen()?.borrow(), AllocationMode::Witness)
+    }
+
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<Proof<E>>, CS: ConstraintSystem<E::Fq>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Self::alloc_with_mode(cs, value_gen()?.borrow(), AllocationMode::Input)
+    }
+}
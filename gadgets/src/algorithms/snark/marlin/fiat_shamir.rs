@@ -0,0 +1,243 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::crypto_hash::PoseidonSpongeGadget,
+    bits::{Boolean, ToBitsBEGadget},
+    fields::{AllocatedFp, FpGadget},
+    nonnative::{reduce::Reducer, AllocatedNonNativeFieldVar, NonNativeFieldVar},
+    traits::{algorithms::AlgebraicSpongeVar, fields::FieldGadget},
+};
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonSponge,
+    overhead,
+    snark::marlin::params::{get_params, OptimizationType},
+    DefaultCapacityAlgebraicSponge,
+};
+use snarkvm_fields::{FieldParameters, PrimeField};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem, ConstraintVariable, LinearCombination};
+use snarkvm_utilities::{FromBits, ToBits};
+
+use core::marker::PhantomData;
+
+/// The gadget for `FiatShamirAlgebraicSpongeRng` over the Poseidon sponge,
+/// which derives the same challenges as the native RNG used by Marlin.
+pub struct FiatShamirAlgebraicSpongeRngVar<TargetField: PrimeField, BaseField: PrimeField> {
+    /// The Poseidon sponge gadget.
+    sponge: PoseidonSpongeGadget<BaseField, 6, 1>,
+    #[doc(hidden)]
+    _phantom: PhantomData<TargetField>,
+}
+
+impl<TargetField: PrimeField, BaseField: PrimeField> FiatShamirAlgebraicSpongeRngVar<TargetField, BaseField> {
+    /// Initializes the sponge with the default Poseidon parameters of the native RNG.
+    pub fn new<CS: ConstraintSystem<BaseField>>(cs: CS) -> Self {
+        let parameters = PoseidonSponge::<BaseField, 6, 1>::sample_parameters();
+        Self { sponge: PoseidonSpongeGadget::with_parameters(cs, &parameters), _phantom: PhantomData }
+    }
+
+    /// Absorbs constant bytes, packed into base field elements as the native RNG does.
+    pub fn absorb_bytes<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        elems: &[u8],
+    ) -> Result<(), SynthesisError> {
+        let capacity = BaseField::size_in_bits() - 1;
+        let bits = elems.iter().flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        let elements = bits
+            .chunks(capacity)
+            .map(|bits| FpGadget::Constant(BaseField::from_repr(BaseField::BigInteger::from_bits_be(bits)).unwrap()))
+            .collect::<Vec<_>>();
+
+        self.sponge.absorb(cs, elements.iter())
+    }
+
+    /// Absorbs base field elements.
+    pub fn absorb_native_field_elements<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        elems: &[FpGadget<BaseField>],
+    ) -> Result<(), SynthesisError> {
+        self.sponge.absorb(cs, elems.iter())
+    }
+
+    /// Absorbs target field elements through their limbs, compressing every two limbs when possible.
+    pub fn absorb_nonnative_field_elements<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        mut cs: CS,
+        elems: &[NonNativeFieldVar<TargetField, BaseField>],
+    ) -> Result<(), SynthesisError> {
+        let mut limbs = Vec::new();
+        for (i, elem) in elems.iter().enumerate() {
+            match elem {
+                NonNativeFieldVar::Constant(c) => {
+                    let c_limbs = AllocatedNonNativeFieldVar::<TargetField, BaseField>::get_limbs_representations(
+                        c,
+                        OptimizationType::Weight,
+                    )?;
+                    limbs.extend(c_limbs.into_iter().map(FpGadget::Constant));
+                }
+                NonNativeFieldVar::Var(v) => {
+                    let mut v = v.clone();
+                    Reducer::pre_eq_reduce(&mut cs.ns(|| format!("reduce_{}", i)), &mut v)?;
+                    limbs.extend(v.limbs);
+                }
+            }
+        }
+
+        let compressed = Self::compress_limbs(cs.ns(|| "compress_limbs"), &limbs)?;
+        self.sponge.absorb(cs.ns(|| "absorb"), compressed.iter())
+    }
+
+    /// Squeezes `num` target field elements, each from `TargetField::size_in_bits() - 1` bits.
+    pub fn squeeze_nonnative_field_elements<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        num: usize,
+    ) -> Result<Vec<NonNativeFieldVar<TargetField, BaseField>>, SynthesisError> {
+        self.squeeze_nonnative_field_elements_with_size(cs, num, TargetField::size_in_bits() - 1)
+    }
+
+    /// Squeezes `num` short target field elements, each from 168 bits.
+    pub fn squeeze_short_nonnative_field_elements<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        num: usize,
+    ) -> Result<Vec<NonNativeFieldVar<TargetField, BaseField>>, SynthesisError> {
+        self.squeeze_nonnative_field_elements_with_size(cs, num, 168)
+    }
+
+    /// Squeezes a short target field element.
+    pub fn squeeze_short_nonnative_field_element<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+    ) -> Result<NonNativeFieldVar<TargetField, BaseField>, SynthesisError> {
+        Ok(self.squeeze_short_nonnative_field_elements(cs, 1)?.remove(0))
+    }
+
+    fn squeeze_nonnative_field_elements_with_size<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        mut cs: CS,
+        num: usize,
+        num_bits_per_nonnative: usize,
+    ) -> Result<Vec<NonNativeFieldVar<TargetField, BaseField>>, SynthesisError> {
+        let bits = self.squeeze_bits(cs.ns(|| "squeeze_bits"), num * num_bits_per_nonnative)?;
+        Ok(bits.chunks_exact(num_bits_per_nonnative).map(nonnative_from_bits_be::<_, _, CS>).collect())
+    }
+
+    /// Squeezes `num_bits` bits, discarding the highest bit of each squeezed base field element.
+    fn squeeze_bits<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        mut cs: CS,
+        num_bits: usize,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        let bits_per_element = BaseField::size_in_bits() - 1;
+        let num_elements = (num_bits + bits_per_element - 1) / bits_per_element;
+
+        let elements = self.sponge.squeeze(cs.ns(|| "squeeze"), num_elements)?;
+
+        let mut bits = Vec::with_capacity(num_elements * bits_per_element);
+        for (i, element) in elements.iter().enumerate() {
+            match element {
+                FpGadget::Constant(c) => {
+                    let skip = (BaseField::Parameters::REPR_SHAVE_BITS + 1) as usize;
+                    bits.extend(c.to_repr().to_bits_be().into_iter().skip(skip).map(Boolean::Constant));
+                }
+                FpGadget::Variable(_) => {
+                    let element_bits = element.to_bits_be_strict(cs.ns(|| format!("to_bits_{}", i)))?;
+                    bits.extend_from_slice(&element_bits[1..]);
+                }
+            }
+        }
+        bits.truncate(num_bits);
+
+        Ok(bits)
+    }
+
+    /// Packs every two limbs into one base field element when their sum fits in the capacity.
+    /// Each limb is treated as if it had one addition over the normal form, matching the native RNG.
+    fn compress_limbs<CS: ConstraintSystem<BaseField>>(
+        mut cs: CS,
+        limbs: &[FpGadget<BaseField>],
+    ) -> Result<Vec<FpGadget<BaseField>>, SynthesisError> {
+        let capacity = BaseField::size_in_bits() - 1;
+        let params = get_params(TargetField::size_in_bits(), BaseField::size_in_bits(), OptimizationType::Weight);
+        let max_bits_per_limb = params.bits_per_limb + overhead!(BaseField::one() + BaseField::one());
+
+        if 2 * max_bits_per_limb > capacity {
+            return Ok(limbs.to_vec());
+        }
+
+        let mut adjustment_factor = BaseField::one();
+        for _ in 0..max_bits_per_limb {
+            adjustment_factor.double_in_place();
+        }
+
+        let mut compressed = Vec::with_capacity((limbs.len() + 1) / 2);
+        for (i, pair) in limbs.chunks(2).enumerate() {
+            match pair {
+                [first, second] => {
+                    let shifted = first.mul_by_constant(cs.ns(|| format!("shift_{}", i)), &adjustment_factor)?;
+                    compressed.push(shifted.add(cs.ns(|| format!("add_{}", i)), second)?);
+                }
+                _ => compressed.push(pair[0].clone()),
+            }
+        }
+
+        Ok(compressed)
+    }
+}
+
+/// Packs big-endian bits into the limbs of a target field element in the normal form.
+/// The bits must represent an integer smaller than the target field modulus.
+pub(super) fn nonnative_from_bits_be<
+    TargetField: PrimeField,
+    BaseField: PrimeField,
+    CS: ConstraintSystem<BaseField>,
+>(
+    bits: &[Boolean],
+) -> NonNativeFieldVar<TargetField, BaseField> {
+    let params = get_params(TargetField::size_in_bits(), BaseField::size_in_bits(), OptimizationType::Weight);
+
+    // The limbs are big limb first, and the lowest limbs are full.
+    let mut limbs = bits
+        .rchunks(params.bits_per_limb)
+        .map(|limb_bits| {
+            let mut lc = LinearCombination::zero();
+            let mut value = Some(BaseField::zero());
+            let mut coeff = BaseField::one();
+            for bit in limb_bits.iter().rev() {
+                lc = lc + &bit.lc(CS::one(), coeff);
+                value = match (value, bit.get_value()) {
+                    (Some(value), Some(true)) => Some(value + coeff),
+                    (Some(value), Some(false)) => Some(value),
+                    _ => None,
+                };
+                coeff.double_in_place();
+            }
+            FpGadget::Variable(AllocatedFp { value, variable: ConstraintVariable::LC(lc) })
+        })
+        .collect::<Vec<_>>();
+    limbs.resize(params.num_limbs, FpGadget::Constant(BaseField::zero()));
+    limbs.reverse();
+
+    NonNativeFieldVar::Var(AllocatedNonNativeFieldVar {
+        limbs,
+        num_of_additions_over_normal_form: BaseField::zero(),
+        is_in_the_normal_form: true,
+        target_phantom: PhantomData,
+    })
+}
@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::snark::marlin::{CircuitVerifyingKeyVar, MarlinVerifierGadget, ProofVar},
+    curves::bls12_377::PairingGadget,
+    nonnative::NonNativeFieldInputVar,
+    AllocGadget,
+    Boolean,
+    EqGadget,
+};
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonSponge,
+    snark::marlin::{
+        AHPForR1CS,
+        CircuitVerifyingKey,
+        FiatShamirAlgebraicSpongeRng,
+        MarlinHidingMode,
+        MarlinSNARK,
+        Proof,
+    },
+    SNARK,
+};
+use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError, TestConstraintSystem};
+use snarkvm_utilities::{test_crypto_rng, UniformRand};
+
+type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>;
+type MarlinInst = MarlinSNARK<Bls12_377, FS, MarlinHidingMode, [Fr]>;
+type VerifierGadget = MarlinVerifierGadget<Bls12_377, PairingGadget, MarlinHidingMode>;
+
+#[derive(Copy, Clone)]
+struct Circuit<F: Field> {
+    a: Option<F>,
+    b: Option<F>,
+    num_constraints: usize,
+    num_variables: usize,
+}
+
+impl<F: Field> ConstraintSynthesizer<F> for Circuit<F> {
+    fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let a = cs.alloc(|| "a", || self.a.ok_or(SynthesisError::AssignmentMissing))?;
+        let b = cs.alloc(|| "b", || self.b.ok_or(SynthesisError::AssignmentMissing))?;
+        let c = cs.alloc_input(
+            || "c",
+            || Ok(self.a.ok_or(SynthesisError::AssignmentMissing)? * self.b.ok_or(SynthesisError::AssignmentMissing)?),
+        )?;
+        let d = cs.alloc_input(
+            || "d",
+            || {
+                let b = self.b.ok_or(SynthesisError::AssignmentMissing)?;
+                Ok(self.a.ok_or(SynthesisError::AssignmentMissing)? * b * b)
+            },
+        )?;
+
+        for i in 0..(self.num_variables - 3) {
+            let _ = cs.alloc(|| format!("var {}", i), || self.a.ok_or(SynthesisError::AssignmentMissing))?;
+        }
+
+        for i in 0..(self.num_constraints - 1) {
+            cs.enforce(|| format!("constraint {}", i), |lc| lc + a, |lc| lc + b, |lc| lc + c);
+        }
+        cs.enforce(|| "constraint_final", |lc| lc + c, |lc| lc + b, |lc| lc + d);
+
+        Ok(())
+    }
+}
+
+/// Returns a verifying key, a proof, and the public inputs the proof is valid for.
+fn prove_inner_circuit() -> (CircuitVerifyingKey<Bls12_377, MarlinHidingMode>, Proof<Bls12_377>, Vec<Fr>) {
+    let rng = &mut test_crypto_rng();
+
+    let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+    let universal_srs = MarlinInst::universal_setup(&max_degree, rng).unwrap();
+
+    let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+    let circuit = Circuit { a: Some(a), b: Some(b), num_constraints: 25, num_variables: 25 };
+
+    let (circuit_proving_key, circuit_verifying_key) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+    let proof = MarlinInst::prove(&circuit_proving_key, &circuit, rng).unwrap();
+
+    let public_inputs = vec![a * b, a * b * b];
+    assert!(MarlinInst::verify(&circuit_verifying_key, public_inputs.as_slice(), &proof).unwrap());
+
+    (circuit_verifying_key, proof, public_inputs)
+}
+
+/// The number of constraints of the verifier gadget for the test circuit.
+const NUM_VERIFIER_CONSTRAINTS: usize = 190736;
+
+/// Verifies the proof in a constraint system, enforcing that the result is true.
+fn verify_in_circuit(
+    circuit_verifying_key: &CircuitVerifyingKey<Bls12_377, MarlinHidingMode>,
+    proof: &Proof<Bls12_377>,
+    public_inputs: Vec<Fr>,
+) -> TestConstraintSystem<Fq> {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+
+    let circuit_verifying_key_var =
+        CircuitVerifyingKeyVar::alloc_constant(cs.ns(|| "circuit_verifying_key"), || Ok(circuit_verifying_key))
+            .unwrap();
+    let public_input_var = NonNativeFieldInputVar::alloc_input(cs.ns(|| "public_input"), || Ok(public_inputs)).unwrap();
+    let proof_var = ProofVar::alloc(cs.ns(|| "proof"), || Ok(proof)).unwrap();
+
+    let is_valid =
+        VerifierGadget::verify(cs.ns(|| "verify"), &circuit_verifying_key_var, &public_input_var, &proof_var).unwrap();
+    is_valid.enforce_equal(cs.ns(|| "is_valid"), &Boolean::constant(true)).unwrap();
+
+    cs
+}
+
+#[test]
+fn test_marlin_verifier_gadget() {
+    let (circuit_verifying_key, proof, public_inputs) = prove_inner_circuit();
+
+    let cs = verify_in_circuit(&circuit_verifying_key, &proof, public_inputs);
+    assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+    assert_eq!(cs.num_constraints(), NUM_VERIFIER_CONSTRAINTS);
+}
+
+#[test]
+fn test_marlin_verifier_gadget_with_wrong_public_input() {
+    let (circuit_verifying_key, proof, mut public_inputs) = prove_inner_circuit();
+    public_inputs[0] += Fr::from(1u64);
+
+    let cs = verify_in_circuit(&circuit_verifying_key, &proof, public_inputs);
+    assert!(!cs.is_satisfied());
+    // The constraints of the verifier do not depend on the values of the public input.
+    assert_eq!(cs.num_constraints(), NUM_VERIFIER_CONSTRAINTS);
+}
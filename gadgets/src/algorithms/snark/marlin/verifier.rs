@@ -0,0 +1,475 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::snark::marlin::{CircuitVerifyingKeyVar, FiatShamirAlgebraicSpongeRngVar, ProofVar},
+    bits::{Boolean, ToBitsLEGadget},
+    fields::FpGadget,
+    nonnative::{NonNativeFieldInputVar, NonNativeFieldVar},
+    traits::{
+        alloc::AllocGadget,
+        curves::{GroupGadget, PairingGadget},
+        eq::EqGadget,
+        fields::{FieldGadget, ToConstraintFieldGadget},
+    },
+};
+use snarkvm_algorithms::{
+    crypto_hash::hash_to_curve,
+    fft::EvaluationDomain,
+    snark::marlin::{AHPForR1CS, MarlinMode, MarlinSNARK},
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{One, Zero};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+use anyhow::anyhow;
+use std::{collections::BTreeMap, marker::PhantomData, slice};
+
+type NonNative<E> = NonNativeFieldVar<<E as PairingEngine>::Fr, <E as PairingEngine>::Fq>;
+
+/// The gadget for the Marlin verifier, for proofs of a single instance that use
+/// the Poseidon-based `FiatShamirAlgebraicSpongeRng`.
+///
+/// The verifier assumes that none of the commitments, nor any linear combination of them
+/// that it computes, is the point at infinity.
+pub struct MarlinVerifierGadget<E: PairingEngine, PG: PairingGadget<E>, MM: MarlinMode>(PhantomData<(E, PG, MM)>);
+
+impl<E: PairingEngine, PG: PairingGadget<E>, MM: MarlinMode> MarlinVerifierGadget<E, PG, MM> {
+    /// Returns a boolean that is true if and only if `proof` is valid for `public_input` under `circuit_verifying_key`.
+    pub fn verify<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        circuit_verifying_key: &CircuitVerifyingKeyVar<E, PG, MM>,
+        public_input: &NonNativeFieldInputVar<E::Fr, E::Fq>,
+        proof: &ProofVar<E, PG>,
+    ) -> Result<Boolean, SynthesisError> {
+        // The zero-knowledge mode of a proof is fixed by its shape, so a mismatch is not satisfiable by any witness.
+        let is_hiding = proof.pc_proofs.iter().any(|p| p.random_v.is_some());
+        if proof.mask_poly.is_some() != MM::ZK || is_hiding != MM::ZK {
+            return Ok(Boolean::constant(false));
+        }
+        if proof.pc_proofs.len() != 2 {
+            return Err(anyhow!("Expected 2 evaluation proofs, found {}", proof.pc_proofs.len()).into());
+        }
+
+        let circuit_info = &circuit_verifying_key.circuit_info;
+        if circuit_info.num_constraints != circuit_info.num_variables {
+            return Err(anyhow!("The R1CS matrices are not square").into());
+        }
+        let domain = |size: usize| EvaluationDomain::<E::Fr>::new(size).ok_or(SynthesisError::PolynomialDegreeTooLarge);
+        let constraint_domain = domain(circuit_info.num_constraints)?;
+        let non_zero_domains = [
+            domain(circuit_info.num_non_zero_a)?,
+            domain(circuit_info.num_non_zero_b)?,
+            domain(circuit_info.num_non_zero_c)?,
+        ];
+        let largest_non_zero_domain = *non_zero_domains.iter().max_by_key(|d| d.size()).unwrap();
+        let input_domain = domain(circuit_info.num_public_inputs)?;
+
+        // Format the public input as the verifier does, with a leading one and trailing zeros.
+        let mut padded_public_input = vec![NonNative::<E>::Constant(E::Fr::one())];
+        padded_public_input.extend(public_input.val.iter().cloned());
+        if padded_public_input.len() > input_domain.size() {
+            return Err(anyhow!("Found {} public inputs, but the circuit has fewer", public_input.val.len()).into());
+        }
+        padded_public_input.resize(input_domain.size(), NonNative::<E>::Constant(E::Fr::zero()));
+
+        // --------------------------------------------------------------------
+        // Initialize the sponge
+        let mut fs_rng = FiatShamirAlgebraicSpongeRngVar::<E::Fr, E::Fq>::new(cs.ns(|| "fs_rng"));
+        let protocol_name = to_bytes_le![&MarlinSNARK::<E>::PROTOCOL_NAME].map_err(SynthesisError::IoError)?;
+        fs_rng.absorb_bytes(cs.ns(|| "absorb_protocol_name"), &protocol_name)?;
+        fs_rng.absorb_bytes(cs.ns(|| "absorb_batch_size"), &1usize.to_le_bytes())?;
+        let circuit_commitments = Self::commitments_to_constraint_field(
+            cs.ns(|| "circuit_commitments_to_constraint_field"),
+            &circuit_verifying_key.circuit_commitments,
+        )?;
+        fs_rng.absorb_native_field_elements(cs.ns(|| "absorb_circuit_commitments"), &circuit_commitments)?;
+        fs_rng.absorb_nonnative_field_elements(cs.ns(|| "absorb_public_input"), &padded_public_input)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // First round
+        let mut first_commitments = proof.witness_commitments.to_vec();
+        first_commitments.extend(proof.mask_poly.clone());
+        let first_commitments = Self::commitments_to_constraint_field(
+            cs.ns(|| "first_commitments_to_constraint_field"),
+            &first_commitments,
+        )?;
+        fs_rng.absorb_native_field_elements(cs.ns(|| "absorb_first_commitments"), &first_commitments)?;
+        let elems = fs_rng.squeeze_nonnative_field_elements(cs.ns(|| "squeeze_first_round"), 3)?;
+        let (alpha, eta_b, eta_c) = (&elems[0], &elems[1], &elems[2]);
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Second round
+        let second_commitments = Self::commitments_to_constraint_field(
+            cs.ns(|| "second_commitments_to_constraint_field"),
+            &proof.second_round_commitments,
+        )?;
+        fs_rng.absorb_native_field_elements(cs.ns(|| "absorb_second_commitments"), &second_commitments)?;
+        let beta = fs_rng.squeeze_nonnative_field_elements(cs.ns(|| "squeeze_second_round"), 1)?.remove(0);
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Third round
+        let third_commitments = Self::commitments_to_constraint_field(
+            cs.ns(|| "third_commitments_to_constraint_field"),
+            &proof.third_round_commitments,
+        )?;
+        fs_rng.absorb_native_field_elements(cs.ns(|| "absorb_third_commitments"), &third_commitments)?;
+        fs_rng.absorb_nonnative_field_elements(cs.ns(|| "absorb_prover_message"), &proof.msg)?;
+        let elems = fs_rng.squeeze_nonnative_field_elements(cs.ns(|| "squeeze_third_round"), 2)?;
+        let (r_b, r_c) = (&elems[0], &elems[1]);
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Fourth round
+        let fourth_commitments = Self::commitments_to_constraint_field(
+            cs.ns(|| "fourth_commitments_to_constraint_field"),
+            slice::from_ref(&proof.h_2),
+        )?;
+        fs_rng.absorb_native_field_elements(cs.ns(|| "absorb_fourth_commitments"), &fourth_commitments)?;
+        let gamma = fs_rng.squeeze_nonnative_field_elements(cs.ns(|| "squeeze_fourth_round"), 1)?.remove(0);
+        // --------------------------------------------------------------------
+
+        fs_rng.absorb_nonnative_field_elements(cs.ns(|| "absorb_evaluations"), &proof.evaluations)?;
+        let [z_b_at_beta, g_1_at_beta, g_a_at_gamma, g_b_at_gamma, g_c_at_gamma] = &proof.evaluations;
+        let [sum_a, sum_b, sum_c] = &proof.msg;
+
+        // --------------------------------------------------------------------
+        // Lincheck sumcheck
+        let mut cs_lincheck = cs.ns(|| "lincheck_sumcheck");
+        let cs = &mut cs_lincheck;
+
+        let v_h_at_alpha = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_h_at_alpha"), &constraint_domain, alpha)?;
+        let v_h_at_beta = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_h_at_beta"), &constraint_domain, &beta)?;
+        let v_x_at_beta = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_x_at_beta"), &input_domain, &beta)?;
+
+        // r(alpha, beta) = (v_H(alpha) - v_H(beta)) / (alpha - beta)
+        let r_alpha_at_beta = {
+            let numerator = v_h_at_alpha.sub(cs.ns(|| "r_numerator"), &v_h_at_beta)?;
+            let denominator = alpha.sub(cs.ns(|| "r_denominator"), &beta)?;
+            let denominator_inv = denominator.inverse(cs.ns(|| "r_denominator_inverse"))?;
+            numerator.mul(cs.ns(|| "r_alpha_at_beta"), &denominator_inv)?
+        };
+
+        // t(beta) = |K_A| sum_a + eta_b |K_B| sum_b + eta_c |K_C| sum_c
+        let t_at_beta = {
+            let [k_a, k_b, k_c] = non_zero_domains.map(|d| d.size_as_field_element);
+            let a = sum_a.mul_by_constant(cs.ns(|| "t_a"), &k_a)?;
+            let b = eta_b.mul(cs.ns(|| "eta_b_sum_b"), sum_b)?.mul_by_constant(cs.ns(|| "t_b"), &k_b)?;
+            let c = eta_c.mul(cs.ns(|| "eta_c_sum_c"), sum_c)?.mul_by_constant(cs.ns(|| "t_c"), &k_c)?;
+            a.add(cs.ns(|| "t_a_plus_t_b"), &b)?.add(cs.ns(|| "t_at_beta"), &c)?
+        };
+
+        // x(beta) = sum_i x_i L_i(beta), where L_i(beta) = v_X(beta) / |X| * w^i / (beta - w^i)
+        let combined_x_at_beta = {
+            let v_x_at_beta_over_size =
+                v_x_at_beta.mul_by_constant(cs.ns(|| "v_x_over_size"), &input_domain.size_inv)?;
+            let mut combined_x_at_beta = NonNative::<E>::zero(cs.ns(|| "zero"))?;
+            for (i, (x_i, omega_i)) in padded_public_input.iter().zip(input_domain.elements()).enumerate() {
+                if let NonNativeFieldVar::Constant(c) = x_i {
+                    if c.is_zero() {
+                        continue;
+                    }
+                }
+                let mut cs = cs.ns(|| format!("lagrange_coefficient_{}", i));
+                let denominator = beta.sub(cs.ns(|| "beta_minus_omega_i"), &NonNative::<E>::Constant(omega_i))?;
+                let lagrange_coefficient = denominator
+                    .inverse(cs.ns(|| "denominator_inverse"))?
+                    .mul(cs.ns(|| "scale"), &v_x_at_beta_over_size)?
+                    .mul_by_constant(cs.ns(|| "omega_i"), &omega_i)?;
+                let term = x_i.mul(cs.ns(|| "x_i_times_lagrange_coefficient"), &lagrange_coefficient)?;
+                combined_x_at_beta = combined_x_at_beta.add(cs.ns(|| "accumulate"), &term)?;
+            }
+            combined_x_at_beta
+        };
+
+        let t_v_x_at_beta = t_at_beta.mul(cs.ns(|| "t_v_x_at_beta"), &v_x_at_beta)?;
+        let r_eta_c_z_b = r_alpha_at_beta.mul(cs.ns(|| "r_eta_c"), eta_c)?.mul(cs.ns(|| "r_eta_c_z_b"), z_b_at_beta)?;
+
+        let [w, z_a, z_b] = &proof.witness_commitments;
+        let [g_1, h_1] = &proof.second_round_commitments;
+        let mut lincheck_terms = vec![
+            (z_a, r_alpha_at_beta.add(cs.ns(|| "z_a_coeff"), &r_eta_c_z_b)?),
+            (w, t_v_x_at_beta.negate(cs.ns(|| "w_coeff"))?),
+            (h_1, v_h_at_beta.negate(cs.ns(|| "h_1_coeff"))?),
+        ];
+        if let Some(mask_poly) = &proof.mask_poly {
+            lincheck_terms.push((mask_poly, NonNative::<E>::one(cs.ns(|| "one"))?));
+        }
+
+        // The evaluation of the lincheck sumcheck equation, after moving its constant terms to the right.
+        let lincheck_value = {
+            let t_x = t_at_beta.mul(cs.ns(|| "t_x"), &combined_x_at_beta)?;
+            let beta_g_1 = beta.mul(cs.ns(|| "beta_g_1"), g_1_at_beta)?;
+            let r_eta_b_z_b =
+                r_alpha_at_beta.mul(cs.ns(|| "r_eta_b"), eta_b)?.mul(cs.ns(|| "r_eta_b_z_b"), z_b_at_beta)?;
+            t_x.add(cs.ns(|| "t_x_plus_beta_g_1"), &beta_g_1)?.sub(cs.ns(|| "lincheck_value"), &r_eta_b_z_b)?
+        };
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Matrix sumcheck
+        let mut cs_matrix = cs.ns(|| "matrix_sumcheck");
+        let cs = &mut cs_matrix;
+
+        let circuit_commitments = AHPForR1CS::<E::Fr, MM>::index_polynomial_info()
+            .into_keys()
+            .zip(&circuit_verifying_key.circuit_commitments)
+            .collect::<BTreeMap<_, _>>();
+
+        let v_k_at_gamma =
+            Self::evaluate_vanishing_polynomial(cs.ns(|| "v_k_at_gamma"), &largest_non_zero_domain, &gamma)?;
+        let v_h_at_alpha_beta = v_h_at_alpha.mul(cs.ns(|| "v_h_at_alpha_beta"), &v_h_at_beta)?;
+        let alpha_beta = alpha.mul(cs.ns(|| "alpha_beta"), &beta)?;
+
+        let mut matrix_terms = vec![];
+        let mut matrix_value = NonNative::<E>::zero(cs.ns(|| "zero"))?;
+        let matrices = [
+            ("a", None, g_a_at_gamma, sum_a),
+            ("b", Some(r_b), g_b_at_gamma, sum_b),
+            ("c", Some(r_c), g_c_at_gamma, sum_c),
+        ];
+        for ((label, factor, g_at_gamma, sum), non_zero_domain) in matrices.into_iter().zip(&non_zero_domains) {
+            let mut cs = cs.ns(|| format!("matrix_{}", label));
+
+            // s_M(gamma) = v_K(gamma) |K_M| / (v_K_M(gamma) |K|), which is one when K_M = K.
+            let selector = if non_zero_domain.size() == largest_non_zero_domain.size() {
+                NonNative::<E>::one(cs.ns(|| "one"))?
+            } else {
+                let v_k_m_at_gamma =
+                    Self::evaluate_vanishing_polynomial(cs.ns(|| "v_k_m_at_gamma"), non_zero_domain, &gamma)?;
+                let ratio = non_zero_domain.size_as_field_element / largest_non_zero_domain.size_as_field_element;
+                v_k_m_at_gamma
+                    .inverse(cs.ns(|| "v_k_m_at_gamma_inverse"))?
+                    .mul(cs.ns(|| "v_k_at_gamma_over_v_k_m_at_gamma"), &v_k_at_gamma)?
+                    .mul_by_constant(cs.ns(|| "selector"), &ratio)?
+            };
+            let scale = match factor {
+                Some(factor) => selector.mul(cs.ns(|| "scale"), factor)?,
+                None => selector,
+            };
+            let denominator_scale = gamma
+                .mul(cs.ns(|| "gamma_g"), g_at_gamma)?
+                .add(cs.ns(|| "gamma_g_plus_sum"), sum)?
+                .mul(cs.ns(|| "denominator_scale"), &scale)?;
+
+            let commitment = |name: &str| {
+                circuit_commitments
+                    .get(&format!("{}_{}", name, label))
+                    .copied()
+                    .ok_or_else(|| SynthesisError::from(anyhow!("Missing the commitment to {}_{}", name, label)))
+            };
+            matrix_terms.push((commitment("val")?, scale.mul(cs.ns(|| "val_coeff"), &v_h_at_alpha_beta)?));
+            matrix_terms.push((commitment("row")?, denominator_scale.mul(cs.ns(|| "row_coeff"), alpha)?));
+            matrix_terms.push((commitment("col")?, denominator_scale.mul(cs.ns(|| "col_coeff"), &beta)?));
+            matrix_terms.push((commitment("row_col")?, denominator_scale.negate(cs.ns(|| "row_col_coeff"))?));
+
+            let value = denominator_scale.mul(cs.ns(|| "alpha_beta_denominator_scale"), &alpha_beta)?;
+            matrix_value = matrix_value.add(cs.ns(|| "accumulate"), &value)?;
+        }
+        matrix_terms.push((&proof.h_2, v_k_at_gamma.negate(cs.ns(|| "h_2_coeff"))?));
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Batch check of the evaluation proofs
+        let cs = &mut cs.ns(|| "batch_check");
+
+        let [g_a, g_b, g_c] = &proof.third_round_commitments;
+        let points = [&beta, &gamma, &gamma, &gamma, &beta, &gamma, &beta];
+        let values =
+            [g_1_at_beta, g_a_at_gamma, g_b_at_gamma, g_c_at_gamma, &lincheck_value, &matrix_value, z_b_at_beta];
+
+        let mut batch_fs_rng = FiatShamirAlgebraicSpongeRngVar::<E::Fr, E::Fq>::new(cs.ns(|| "batch_fs_rng"));
+        let points = points.into_iter().cloned().collect::<Vec<_>>();
+        batch_fs_rng.absorb_nonnative_field_elements(cs.ns(|| "absorb_points"), &points)?;
+        let values = values.into_iter().cloned().collect::<Vec<_>>();
+        batch_fs_rng.absorb_nonnative_field_elements(cs.ns(|| "absorb_values"), &values)?;
+        for (i, pc_proof) in proof.pc_proofs.iter().enumerate() {
+            let w = Self::commitments_to_constraint_field(
+                cs.ns(|| format!("pc_proof_{}_w", i)),
+                slice::from_ref(&pc_proof.w),
+            )?;
+            batch_fs_rng.absorb_native_field_elements(cs.ns(|| format!("absorb_pc_proof_{}_w", i)), &w)?;
+            if let Some(random_v) = &pc_proof.random_v {
+                batch_fs_rng.absorb_nonnative_field_elements(
+                    cs.ns(|| format!("absorb_pc_proof_{}_random_v", i)),
+                    slice::from_ref(random_v),
+                )?;
+            }
+        }
+
+        // The opening challenges for the evaluations at beta, in the order of their labels.
+        let xi_g_1 = fs_rng.squeeze_short_nonnative_field_element(cs.ns(|| "xi_g_1"))?;
+        let xi_lincheck = fs_rng.squeeze_short_nonnative_field_element(cs.ns(|| "xi_lincheck"))?;
+        let xi_z_b = fs_rng.squeeze_short_nonnative_field_element(cs.ns(|| "xi_z_b"))?;
+        let randomizer = batch_fs_rng.squeeze_short_nonnative_field_element(cs.ns(|| "randomizer"))?;
+        // The opening challenges for the evaluations at gamma, in the order of their labels.
+        // The checks at gamma are scaled by the randomizer, so that they cannot cancel the checks at beta.
+        let xi_g_a = fs_rng.squeeze_short_nonnative_field_element(cs.ns(|| "xi_g_a"))?;
+        let xi_g_b = fs_rng.squeeze_short_nonnative_field_element(cs.ns(|| "xi_g_b"))?;
+        let xi_g_c = fs_rng.squeeze_short_nonnative_field_element(cs.ns(|| "xi_g_c"))?;
+        let xi_matrix = fs_rng.squeeze_short_nonnative_field_element(cs.ns(|| "xi_matrix"))?;
+        let xi_g_a = randomizer.mul(cs.ns(|| "randomized_xi_g_a"), &xi_g_a)?;
+        let xi_g_b = randomizer.mul(cs.ns(|| "randomized_xi_g_b"), &xi_g_b)?;
+        let xi_g_c = randomizer.mul(cs.ns(|| "randomized_xi_g_c"), &xi_g_c)?;
+        let xi_matrix = randomizer.mul(cs.ns(|| "randomized_xi_matrix"), &xi_matrix)?;
+
+        // The combined values of the evaluations at beta and at gamma.
+        let combined_value_at_beta = {
+            let g_1 = xi_g_1.mul(cs.ns(|| "combined_g_1"), g_1_at_beta)?;
+            let lincheck = xi_lincheck.mul(cs.ns(|| "combined_lincheck"), &lincheck_value)?;
+            let z_b = xi_z_b.mul(cs.ns(|| "combined_z_b"), z_b_at_beta)?;
+            g_1.add(cs.ns(|| "g_1_plus_lincheck"), &lincheck)?.add(cs.ns(|| "combined_value_at_beta"), &z_b)?
+        };
+        let combined_value_at_gamma = {
+            let g_a = xi_g_a.mul(cs.ns(|| "combined_g_a"), g_a_at_gamma)?;
+            let g_b = xi_g_b.mul(cs.ns(|| "combined_g_b"), g_b_at_gamma)?;
+            let g_c = xi_g_c.mul(cs.ns(|| "combined_g_c"), g_c_at_gamma)?;
+            let matrix = xi_matrix.mul(cs.ns(|| "combined_matrix"), &matrix_value)?;
+            g_a.add(cs.ns(|| "g_a_plus_g_b"), &g_b)?
+                .add(cs.ns(|| "plus_g_c"), &g_c)?
+                .add(cs.ns(|| "combined_value_at_gamma"), &matrix)?
+        };
+
+        // The commitments without degree bounds, paired with the generator of G2.
+        let mut unbounded_terms = vec![(z_b, xi_z_b)];
+        for (i, (commitment, coeff)) in lincheck_terms.into_iter().enumerate() {
+            unbounded_terms.push((commitment, coeff.mul(cs.ns(|| format!("lincheck_term_{}", i)), &xi_lincheck)?));
+        }
+        for (i, (commitment, coeff)) in matrix_terms.into_iter().enumerate() {
+            unbounded_terms.push((commitment, coeff.mul(cs.ns(|| format!("matrix_term_{}", i)), &xi_matrix)?));
+        }
+
+        // The adjusted witness, -(g * v - w * z + gamma_g * random_v), for each point.
+        let [proof_at_beta, proof_at_gamma] = [&proof.pc_proofs[0], &proof.pc_proofs[1]];
+        let combined_value = combined_value_at_beta.add(cs.ns(|| "combined_value"), &combined_value_at_gamma)?;
+        unbounded_terms.push((&circuit_verifying_key.g, combined_value.negate(cs.ns(|| "g_coeff"))?));
+        unbounded_terms.push((&proof_at_beta.w, beta.clone()));
+        unbounded_terms.push((&proof_at_gamma.w, gamma.mul(cs.ns(|| "w_at_gamma_coeff"), &randomizer)?));
+        if is_hiding {
+            let mut random_v = NonNative::<E>::zero(cs.ns(|| "zero_random_v"))?;
+            if let Some(random_v_at_beta) = &proof_at_beta.random_v {
+                random_v = random_v.add(cs.ns(|| "random_v_at_beta"), random_v_at_beta)?;
+            }
+            if let Some(random_v_at_gamma) = &proof_at_gamma.random_v {
+                let random_v_at_gamma = random_v_at_gamma.mul(cs.ns(|| "randomized_random_v_at_gamma"), &randomizer)?;
+                random_v = random_v.add(cs.ns(|| "random_v_at_gamma"), &random_v_at_gamma)?;
+            }
+            unbounded_terms.push((&circuit_verifying_key.gamma_g, random_v.negate(cs.ns(|| "gamma_g_coeff"))?));
+        }
+
+        // The commitments with degree bounds, grouped by their bound and paired with its shift power.
+        let degree_bounds = CircuitVerifyingKeyVar::<E, PG, MM>::degree_bounds(circuit_info);
+        let second_round_info = AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(circuit_info);
+        let third_round_info = AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(circuit_info);
+        let bounded = [
+            (second_round_info["g_1"].degree_bound(), g_1, xi_g_1),
+            (third_round_info["g_a"].degree_bound(), g_a, xi_g_a),
+            (third_round_info["g_b"].degree_bound(), g_b, xi_g_b),
+            (third_round_info["g_c"].degree_bound(), g_c, xi_g_c),
+        ];
+        let mut bounded_terms = degree_bounds.iter().map(|d| (*d, vec![])).collect::<BTreeMap<_, _>>();
+        for (degree_bound, commitment, coeff) in bounded {
+            match degree_bound.and_then(|d| bounded_terms.get_mut(&d)) {
+                Some(terms) => terms.push((commitment, coeff)),
+                None => unbounded_terms.push((commitment, coeff)),
+            }
+        }
+
+        // The witnesses, paired with beta times the generator of G2.
+        let witness_terms = vec![
+            (&proof_at_beta.w, NonNative::<E>::Constant(-E::Fr::one())),
+            (&proof_at_gamma.w, randomizer.negate(cs.ns(|| "w_at_gamma_randomizer"))?),
+        ];
+
+        let mut g1s = vec![];
+        let mut g2s = vec![];
+        let unbounded = Self::multi_scalar_multiplication(cs.ns(|| "unbounded_msm"), &unbounded_terms)?;
+        g1s.push(PG::prepare_g1(cs.ns(|| "prepare_unbounded"), unbounded)?);
+        g2s.push(circuit_verifying_key.prepared_h.clone());
+        for (degree_bound, terms) in bounded_terms {
+            if terms.is_empty() {
+                continue;
+            }
+            let shift_power = circuit_verifying_key
+                .get_prepared_shift_power(degree_bound)
+                .ok_or_else(|| anyhow!("The verifying key does not support the degree bound {}", degree_bound))?;
+            let bounded = Self::multi_scalar_multiplication(cs.ns(|| format!("msm_{}", degree_bound)), &terms)?;
+            g1s.push(PG::prepare_g1(cs.ns(|| format!("prepare_{}", degree_bound)), bounded)?);
+            g2s.push(shift_power.clone());
+        }
+        let witness = Self::multi_scalar_multiplication(cs.ns(|| "witness_msm"), &witness_terms)?;
+        g1s.push(PG::prepare_g1(cs.ns(|| "prepare_witness"), witness)?);
+        g2s.push(circuit_verifying_key.prepared_beta_h.clone());
+
+        let product = PG::product_of_pairings(cs.ns(|| "product_of_pairings"), &g1s, &g2s)?;
+        let one = PG::GTGadget::one(cs.ns(|| "gt_one"))?;
+        product.is_eq(cs.ns(|| "is_one"), &one)
+        // --------------------------------------------------------------------
+    }
+
+    /// Returns the constraint field elements of the given commitments, as the native sponge absorbs them.
+    /// As commitments are assumed to not be the point at infinity, their infinity flags are enforced to be zero.
+    fn commitments_to_constraint_field<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        commitments: &[PG::G1Gadget],
+    ) -> Result<Vec<FpGadget<E::Fq>>, SynthesisError> {
+        let mut elements = Vec::with_capacity(commitments.len() * 3);
+        for (i, commitment) in commitments.iter().enumerate() {
+            let commitment_elements = commitment.to_constraint_field(cs.ns(|| format!("to_constraint_field_{}", i)))?;
+            if let [.., infinity] = commitment_elements.as_slice() {
+                infinity.enforce_equal(
+                    cs.ns(|| format!("enforce_not_infinity_{}", i)),
+                    &FpGadget::Constant(E::Fq::zero()),
+                )?;
+            }
+            elements.extend(commitment_elements);
+        }
+        Ok(elements)
+    }
+
+    /// Returns the evaluation of the vanishing polynomial of `domain`, `x^|domain| - 1`.
+    fn evaluate_vanishing_polynomial<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        domain: &EvaluationDomain<E::Fr>,
+        x: &NonNative<E>,
+    ) -> Result<NonNative<E>, SynthesisError> {
+        let mut power = x.clone();
+        for i in 0..domain.log_size_of_group {
+            power = power.square(cs.ns(|| format!("square_{}", i)))?;
+        }
+        power.sub(cs.ns(|| "sub_one"), &NonNative::<E>::Constant(E::Fr::one()))
+    }
+
+    /// Returns the sum of the commitments, each multiplied by its coefficient.
+    fn multi_scalar_multiplication<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        terms: &[(&PG::G1Gadget, NonNative<E>)],
+    ) -> Result<PG::G1Gadget, SynthesisError> {
+        // The additions are incomplete, so the sum starts from a point with no known relation to the commitments.
+        let offset = hash_to_curve::<E::G1Affine>("Marlin verifier gadget MSM offset").0;
+        let mut result = PG::G1Gadget::alloc_constant(cs.ns(|| "offset"), || Ok(offset))?;
+        for (i, (commitment, coeff)) in terms.iter().enumerate() {
+            let mut cs = cs.ns(|| format!("term_{}", i));
+            let bits = coeff.to_bits_le(cs.ns(|| "coeff_to_bits"))?;
+            result = commitment.mul_bits(cs.ns(|| "mul_bits"), &result, bits.into_iter())?;
+        }
+        result.sub_constant(cs.ns(|| "sub_offset"), &offset)
+    }
+}
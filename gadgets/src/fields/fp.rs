@@ -84,8 +84,9 @@ impl<F: PrimeField> From<AllocatedFp<F>> for FpGadget<F> {
 impl<F: PrimeField> AllocatedFp<F> {
     /// Constructs `Self` from a `Boolean`:
     ///     if `cond` is false, this outputs `zero`, else it outputs `one`.
-    pub fn from_boolean<CS: ConstraintSystem<F>>(mut cs: CS, cond: Boolean) -> Result<Self, SynthesisError> {
-        Self::alloc(cs.ns(|| ""), || cond.get_value().map(|value| F::from(value as u128)).get())
+    pub fn from_boolean<CS: ConstraintSystem<F>>(_cs: CS, cond: Boolean) -> Result<Self, SynthesisError> {
+        let value = cond.get_value().map(|value| F::from(value as u128));
+        Ok(Self { value, variable: LC(cond.lc(CS::one(), F::one())) })
     }
 
     #[inline]
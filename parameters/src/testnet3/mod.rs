@@ -17,6 +17,11 @@
 pub mod powers;
 pub use powers::*;
 
+#[cfg(not(target_family = "wasm"))]
+pub mod srs_file;
+#[cfg(not(target_family = "wasm"))]
+pub use srs_file::*;
+
 const REMOTE_URL: &str = "https://s3-us-west-1.amazonaws.com/aleo.parameters";
 
 // Degree 15
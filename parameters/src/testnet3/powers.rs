@@ -36,6 +36,9 @@ use std::{
     path::PathBuf,
 };
 
+#[cfg(not(target_family = "wasm"))]
+use std::path::Path;
+
 lazy_static::lazy_static! {
     static ref UNIVERSAL_SRS_15: Vec<u8> = Degree15::load_bytes().expect("Failed to load universal SRS of degree 15");
     static ref UNIVERSAL_SRS_GAMMA: Vec<u8> = Gamma::load_bytes().expect("Failed to load universal SRS gamma powers");
@@ -44,7 +47,7 @@ lazy_static::lazy_static! {
 // Amount of powers contained in `UNIVERSAL_SRS_GAMMA`.
 const NUM_UNIVERSAL_SRS_GAMMA: usize = 84;
 // Size of a serialized power of G.
-const POWER_OF_G_SERIALIZED_SIZE: usize = 97;
+pub(super) const POWER_OF_G_SERIALIZED_SIZE: usize = 97;
 
 const DEGREE_16: usize = 1 << 16;
 const DEGREE_17: usize = 1 << 17;
@@ -60,17 +63,26 @@ const DEGREE_26: usize = 1 << 26;
 const DEGREE_27: usize = 1 << 27;
 const DEGREE_28: usize = 1 << 28;
 
+/// The storage backing the powers of G.
+#[derive(Debug)]
+enum PowersStorage<E: PairingEngine> {
+    /// A handle to a file of serialized powers, which are read on each access,
+    /// and which can be extended by downloading more powers.
+    File(File),
+    /// A memory-mapped SRS file, whose powers are deserialized on demand.
+    #[cfg(not(target_family = "wasm"))]
+    Mmap(MmapPowers<E>),
+}
+
 /// An abstraction over a vector of powers of G, meant to reduce
 /// memory burden when handling universal setup parameters.
 #[derive(Debug)]
 pub struct PowersOfG<E: PairingEngine> {
     /// Filepath of the powers we're using.
     file_path: String,
-    /// A handle to the file on disk containing the powers of G.
-    /// The handle is guarded to avoid read/write conflicts with potential
-    /// clones.
+    /// The storage of the powers of G, either a file on disk or a memory-mapped SRS file.
     /// Contains group elements of the form `[G, \beta * G, \beta^2 * G, ..., \beta^{d} G]`.
-    file: File,
+    storage: PowersStorage<E>,
     /// Group elements of the form `{ \beta^i \gamma G }`, where `i` ranges from 0 to `degree`.
     /// These are used for hiding.
     powers_of_beta_times_gamma_g: BTreeMap<usize, E::G1Affine>,
@@ -120,7 +132,7 @@ impl<E: PairingEngine> PowersOfG<E> {
 
         let mut powers = Self {
             file_path: String::from(file_path.to_str().expect("could not get filepath for powers of g")),
-            file,
+            storage: PowersStorage::File(file),
             powers_of_beta_times_gamma_g: BTreeMap::new(),
             degree,
         };
@@ -129,6 +141,37 @@ impl<E: PairingEngine> PowersOfG<E> {
         Ok(powers)
     }
 
+    ///
+    /// Returns a new instance of `PowersOfG`, which memory-maps the SRS file at `file_path`
+    /// and deserializes its powers on demand, `chunk_size` powers at a time.
+    ///
+    /// If `verify_digest` is set, the file is checked against the digest in its header, and the powers
+    /// are deserialized without further checks. Otherwise, each chunk of powers is checked to be in the
+    /// prime-order subgroup when it is first read. The powers of an SRS file cannot be downloaded.
+    ///
+    #[cfg(not(target_family = "wasm"))]
+    pub fn open_mmap(file_path: PathBuf, verify_digest: bool, chunk_size: usize) -> Result<Self> {
+        let (powers, powers_of_beta_times_gamma_g) = MmapPowers::open(&file_path, verify_digest, chunk_size)?;
+        Ok(Self {
+            file_path: String::from(file_path.to_str().expect("could not get filepath for powers of g")),
+            degree: powers.num_powers(),
+            storage: PowersStorage::Mmap(powers),
+            powers_of_beta_times_gamma_g,
+        })
+    }
+
+    /// Writes the powers to an SRS file at `file_path`, which can be opened with `open_mmap`,
+    /// and returns the digest of the file.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn write_srs_file(&mut self, file_path: &Path) -> Result<[u8; 32]> {
+        let degree = self.degree;
+        let powers_of_beta_times_gamma_g = self.powers_of_beta_times_gamma_g.clone();
+        let powers = (0..degree)
+            .step_by(DEFAULT_SRS_CHUNK_SIZE)
+            .flat_map(|lower| self.powers_of_beta_g(lower, (lower + DEFAULT_SRS_CHUNK_SIZE).min(degree)));
+        write_srs_file::<E>(file_path, degree, powers, &powers_of_beta_times_gamma_g)
+    }
+
     /// Return the number of current powers of G.
     pub fn degree(&self) -> usize {
         self.degree
@@ -139,10 +182,15 @@ impl<E: PairingEngine> PowersOfG<E> {
     // that we return a reference. We can not return a reference to
     // something that does not exist when this function is called.
     pub fn power_of_beta_g(&mut self, target_power: usize) -> E::G1Affine {
+        #[cfg(not(target_family = "wasm"))]
+        if let PowersStorage::Mmap(powers) = &mut self.storage {
+            return powers.powers_of_beta_g(target_power, target_power + 1).expect("powers of g corrupted")[0];
+        }
+
         let index_start = self.get_starting_byte_index(target_power).expect("Failed to load starting byte index");
 
         // Move our offset to the start of the desired element.
-        let mut reader = BufReader::new(&self.file);
+        let mut reader = BufReader::new(self.file());
         reader.seek(SeekFrom::Start(index_start as u64)).expect("could not seek to element starting index");
 
         // Now read it out, deserialize it, and return it.
@@ -152,12 +200,17 @@ impl<E: PairingEngine> PowersOfG<E> {
     /// Slices the underlying file to return a vector of affine elements
     /// between `lower` and `upper`.
     pub fn powers_of_beta_g(&mut self, lower: usize, upper: usize) -> Vec<E::G1Affine> {
+        #[cfg(not(target_family = "wasm"))]
+        if let PowersStorage::Mmap(powers) = &mut self.storage {
+            return powers.powers_of_beta_g(lower, upper).expect("powers of g corrupted");
+        }
+
         // Ensure index exists for upper power.
         let _ = self.get_starting_byte_index(upper).expect("Failed to load upper power index");
         let index_start = self.get_starting_byte_index(lower).expect("Failed to load lower power index");
 
         // Move our offset to the start of the desired element.
-        let mut reader = BufReader::new(&self.file);
+        let mut reader = BufReader::new(self.file());
         reader.seek(SeekFrom::Start(index_start as u64)).expect("could not seek to element starting index");
 
         // Now iterate until we fill a vector with all desired elements.
@@ -176,7 +229,7 @@ impl<E: PairingEngine> PowersOfG<E> {
         };
 
         // Ensure the powers exist, and download the missing powers if necessary.
-        if starting_byte_index > self.file.metadata()?.len() as usize {
+        if starting_byte_index > self.file().metadata()?.len() as usize {
             self.download_up_to(target_power.next_power_of_two())?;
         }
 
@@ -186,6 +239,14 @@ impl<E: PairingEngine> PowersOfG<E> {
     /// This method downloads the universal SRS powers up to the `next_power_of_two(target_degree)`,
     /// and updates `Self` in place with the new powers.
     pub fn download_up_to(&mut self, target_degree: usize) -> Result<()> {
+        #[cfg(not(target_family = "wasm"))]
+        if let PowersStorage::Mmap(powers) = &self.storage {
+            if target_degree > powers.num_powers() {
+                bail!("Cannot download powers of G up to {target_degree} into an SRS file with {}", self.degree);
+            }
+            return Ok(());
+        }
+
         // Determine the degrees to download.
         let mut degrees_to_download = vec![];
         let mut current = self.degree;
@@ -216,8 +277,8 @@ impl<E: PairingEngine> PowersOfG<E> {
                 };
 
                 // Write the powers to the file.
-                self.file.seek(SeekFrom::End(0))?;
-                self.file.write_all(&bytes)?;
+                self.file().seek(SeekFrom::End(0))?;
+                self.file().write_all(&bytes)?;
 
                 // Update the `degree`.
                 self.degree = *degree;
@@ -258,6 +319,15 @@ impl<E: PairingEngine> PowersOfG<E> {
     pub fn get_powers_times_gamma_g(&self) -> &BTreeMap<usize, E::G1Affine> {
         &self.powers_of_beta_times_gamma_g
     }
+
+    /// Returns the file of powers, which must back `self`.
+    fn file(&self) -> &File {
+        match &self.storage {
+            PowersStorage::File(file) => file,
+            #[cfg(not(target_family = "wasm"))]
+            PowersStorage::Mmap(_) => unreachable!("The powers of G are memory-mapped"),
+        }
+    }
 }
 
 impl<E: PairingEngine> ToBytes for PowersOfG<E> {
@@ -0,0 +1,360 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A file format for the powers of G of a universal SRS, which can be memory-mapped and deserialized on demand.
+//!
+//! The file starts with a header of `magic || version (u32) || curve ID (32 bytes) || digest (32 bytes)`,
+//! followed by a payload of `num_powers (u64) || num_gamma_powers (u64) || (index (u64) || power)* || power*`,
+//! where the first list holds the powers of beta times gamma times G, and the second the powers of beta times G.
+//! Every power is written with `ToBytes`, and the digest is the BLAKE2s-256 hash of the payload.
+
+use super::POWER_OF_G_SERIALIZED_SIZE;
+use snarkvm_curves::traits::{AffineCurve, PairingEngine};
+use snarkvm_utilities::{FromBytes, MmapFile, ToBytes};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use blake2::{Blake2s256, Digest};
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufWriter, Read, Seek, SeekFrom, Write},
+    marker::PhantomData,
+    path::Path,
+};
+
+/// The magic bytes at the start of an SRS file.
+pub(super) const SRS_FILE_MAGIC: [u8; 8] = *b"SVMSRSG1";
+/// The version of the SRS file format.
+pub const SRS_FILE_VERSION: u32 = 1;
+/// The size of the header of an SRS file.
+const SRS_FILE_HEADER_SIZE: usize = 8 + 4 + 32 + 32;
+/// The default number of powers that are deserialized, and checked, together.
+pub const DEFAULT_SRS_CHUNK_SIZE: usize = 1 << 14;
+
+/// Returns the identifier of the curve `E`, which is the BLAKE2s-256 hash of the generators of G1 and G2.
+pub fn curve_id<E: PairingEngine>() -> [u8; 32] {
+    let mut bytes = Vec::new();
+    E::G1Affine::prime_subgroup_generator().write_le(&mut bytes).expect("writing to a vector cannot fail");
+    E::G2Affine::prime_subgroup_generator().write_le(&mut bytes).expect("writing to a vector cannot fail");
+    Blake2s256::digest(&bytes).into()
+}
+
+///
+/// Writes the `num_powers` powers of beta times G in `powers_of_beta_g`, and the powers of beta times gamma times G,
+/// to an SRS file at `path`.
+///
+/// Returns the digest of the file, which is also stored in its header.
+///
+pub fn write_srs_file<E: PairingEngine>(
+    path: &Path,
+    num_powers: usize,
+    powers_of_beta_g: impl IntoIterator<Item = E::G1Affine>,
+    powers_of_beta_times_gamma_g: &BTreeMap<usize, E::G1Affine>,
+) -> Result<[u8; 32]> {
+    let mut file = File::create(path)?;
+
+    // The digest is written once the payload has been hashed.
+    let mut writer = BufWriter::new(&mut file);
+    writer.write_all(&SRS_FILE_MAGIC)?;
+    SRS_FILE_VERSION.write_le(&mut writer)?;
+    curve_id::<E>().write_le(&mut writer)?;
+    [0u8; 32].write_le(&mut writer)?;
+
+    let mut writer = HashingWriter { writer, hasher: Blake2s256::new() };
+    (num_powers as u64).write_le(&mut writer)?;
+    (powers_of_beta_times_gamma_g.len() as u64).write_le(&mut writer)?;
+    for (index, power) in powers_of_beta_times_gamma_g {
+        (*index as u64).write_le(&mut writer)?;
+        power.write_le(&mut writer)?;
+    }
+    let mut num_written = 0;
+    for power in powers_of_beta_g {
+        power.write_le(&mut writer)?;
+        num_written += 1;
+    }
+    ensure!(num_written == num_powers, "Expected {num_powers} powers of G, found {num_written}");
+
+    let HashingWriter { mut writer, hasher } = writer;
+    let digest: [u8; 32] = hasher.finalize().into();
+    writer.seek(SeekFrom::Start((SRS_FILE_HEADER_SIZE - 32) as u64))?;
+    digest.write_le(&mut writer)?;
+    writer.flush()?;
+    Ok(digest)
+}
+
+/// A writer that hashes everything written through it.
+struct HashingWriter<W: Write> {
+    writer: W,
+    hasher: Blake2s256,
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.writer.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+///
+/// The powers of beta times G in a memory-mapped SRS file, deserialized on demand.
+///
+/// If the digest of the file was verified when it was opened, the powers are deserialized without checks.
+/// Otherwise, the powers are checked to be in the prime-order subgroup, one chunk at a time, when they are
+/// first read.
+///
+#[derive(Debug)]
+pub(super) struct MmapPowers<E: PairingEngine> {
+    mmap: MmapFile,
+    /// The byte offset of the first power of beta times G.
+    powers_offset: usize,
+    num_powers: usize,
+    chunk_size: usize,
+    /// Whether each chunk has been checked, or `None` if the digest of the file was verified.
+    checked_chunks: Option<Vec<bool>>,
+    _engine: PhantomData<E>,
+}
+
+impl<E: PairingEngine> MmapPowers<E> {
+    ///
+    /// Memory-maps the SRS file at `path`, and returns its powers of beta times G along with the powers of
+    /// beta times gamma times G.
+    ///
+    /// If `verify_digest` is set, the payload is hashed and compared with the digest in the header, and the
+    /// powers are deserialized without checks. Otherwise, the powers are checked as they are read.
+    ///
+    pub(super) fn open(
+        path: &Path,
+        verify_digest: bool,
+        chunk_size: usize,
+    ) -> Result<(Self, BTreeMap<usize, E::G1Affine>)> {
+        ensure!(chunk_size > 0, "The chunk size of an SRS file must be positive");
+
+        let mmap = MmapFile::open(path)?;
+        ensure!(mmap.len() >= SRS_FILE_HEADER_SIZE, "The SRS file is too short to contain a header");
+
+        let mut header = &mmap[..SRS_FILE_HEADER_SIZE];
+        let mut magic = [0u8; 8];
+        header.read_exact(&mut magic)?;
+        ensure!(magic == SRS_FILE_MAGIC, "The file is not an SRS file");
+        let version = u32::read_le(&mut header)?;
+        ensure!(version == SRS_FILE_VERSION, "Unsupported SRS file version {version}");
+        let file_curve_id: [u8; 32] = FromBytes::read_le(&mut header)?;
+        ensure!(file_curve_id == curve_id::<E>(), "The SRS file is for a different curve");
+        let digest: [u8; 32] = FromBytes::read_le(&mut header)?;
+
+        let payload = &mmap[SRS_FILE_HEADER_SIZE..];
+        if verify_digest {
+            let payload_digest: [u8; 32] = Blake2s256::digest(payload).into();
+            ensure!(payload_digest == digest, "The digest of the SRS file does not match its header");
+        }
+
+        let mut reader = payload;
+        let num_powers = u64::read_le(&mut reader)? as usize;
+        let num_gamma_powers = u64::read_le(&mut reader)? as usize;
+        let mut powers_of_beta_times_gamma_g = BTreeMap::new();
+        for _ in 0..num_gamma_powers {
+            let index = u64::read_le(&mut reader)? as usize;
+            let power = E::G1Affine::read_le(&mut reader)?;
+            ensure!(verify_digest || is_valid(&power), "The SRS file contains an invalid power of gamma times G");
+            powers_of_beta_times_gamma_g.insert(index, power);
+        }
+
+        let powers_offset = mmap.len() - reader.len();
+        let expected_len = num_powers
+            .checked_mul(POWER_OF_G_SERIALIZED_SIZE)
+            .and_then(|len| len.checked_add(powers_offset))
+            .ok_or_else(|| anyhow!("The SRS file has too many powers"))?;
+        ensure!(mmap.len() == expected_len, "The SRS file should have {expected_len} bytes, found {}", mmap.len());
+
+        let checked_chunks = (!verify_digest).then(|| vec![false; (num_powers + chunk_size - 1) / chunk_size]);
+        let powers = Self { mmap, powers_offset, num_powers, chunk_size, checked_chunks, _engine: PhantomData };
+        Ok((powers, powers_of_beta_times_gamma_g))
+    }
+
+    /// Returns the number of powers of beta times G.
+    pub(super) fn num_powers(&self) -> usize {
+        self.num_powers
+    }
+
+    /// Returns the powers of beta times G from `lower` up to, but not including, `upper`.
+    pub(super) fn powers_of_beta_g(&mut self, lower: usize, upper: usize) -> Result<Vec<E::G1Affine>> {
+        ensure!(lower <= upper, "Invalid range of powers {lower}..{upper}");
+        if upper > self.num_powers {
+            bail!("Attempted to load power {upper} of G from an SRS file with {} powers", self.num_powers);
+        }
+        if lower == upper {
+            return Ok(vec![]);
+        }
+
+        if let Some(mut checked_chunks) = self.checked_chunks.take() {
+            let result = (lower / self.chunk_size..=(upper - 1) / self.chunk_size).try_for_each(|chunk| {
+                if !checked_chunks[chunk] {
+                    let chunk_start = chunk * self.chunk_size;
+                    let chunk_end = (chunk_start + self.chunk_size).min(self.num_powers);
+                    let powers = self.read_powers(chunk_start, chunk_end)?;
+                    ensure!(
+                        powers.iter().all(is_valid),
+                        "The SRS file contains an invalid power of G in chunk {chunk}"
+                    );
+                    checked_chunks[chunk] = true;
+                }
+                Ok(())
+            });
+            self.checked_chunks = Some(checked_chunks);
+            result?;
+        }
+        self.read_powers(lower, upper)
+    }
+
+    /// Deserializes the powers from `lower` up to, but not including, `upper`, without checks.
+    fn read_powers(&self, lower: usize, upper: usize) -> Result<Vec<E::G1Affine>> {
+        let start = self.powers_offset + lower * POWER_OF_G_SERIALIZED_SIZE;
+        let end = self.powers_offset + upper * POWER_OF_G_SERIALIZED_SIZE;
+        self.mmap[start..end]
+            .chunks_exact(POWER_OF_G_SERIALIZED_SIZE)
+            .map(|bytes| Ok(E::G1Affine::read_le(bytes)?))
+            .collect()
+    }
+}
+
+/// Returns `true` if `power` is on the curve and in the prime-order subgroup.
+fn is_valid<G: AffineCurve>(power: &G) -> bool {
+    power.is_on_curve() && power.is_in_correct_subgroup_assuming_on_curve()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet3::PowersOfG;
+    use snarkvm_curves::{
+        bls12_377::{Bls12_377, G1Affine},
+        traits::ProjectiveCurve,
+    };
+
+    use rand::Rng;
+    use std::{fs, path::PathBuf};
+
+    const NUM_POWERS: usize = 37;
+    const CHUNK_SIZE: usize = 8;
+
+    /// Returns an artificial SRS of small multiples of the generator, with powers of gamma times G at 1 and 2.
+    fn sample_srs() -> (Vec<G1Affine>, BTreeMap<usize, G1Affine>) {
+        let generator = G1Affine::prime_subgroup_generator();
+        let mut power = generator.to_projective();
+        let powers = (0..NUM_POWERS)
+            .map(|_| {
+                let affine = power.to_affine();
+                power.add_assign_mixed(&generator);
+                affine
+            })
+            .collect::<Vec<_>>();
+        let powers_of_beta_times_gamma_g = [(1, powers[2].mul_by_cofactor()), (2, powers[3].mul_by_cofactor())].into();
+        (powers, powers_of_beta_times_gamma_g)
+    }
+
+    fn temp_srs_path() -> PathBuf {
+        std::env::temp_dir().join(format!("srs_file_{}.srs", rand::thread_rng().gen::<u32>()))
+    }
+
+    fn write_sample_srs() -> (PathBuf, Vec<G1Affine>, BTreeMap<usize, G1Affine>) {
+        let (powers, powers_of_beta_times_gamma_g) = sample_srs();
+        let path = temp_srs_path();
+        write_srs_file::<Bls12_377>(&path, NUM_POWERS, powers.iter().copied(), &powers_of_beta_times_gamma_g).unwrap();
+        (path, powers, powers_of_beta_times_gamma_g)
+    }
+
+    /// Overwrites the byte at `offset` of the file at `path` with its complement.
+    fn corrupt_byte(path: &Path, offset: usize) {
+        let mut bytes = fs::read(path).unwrap();
+        bytes[offset] = !bytes[offset];
+        fs::write(path, bytes).unwrap();
+    }
+
+    #[test]
+    fn test_srs_file_round_trip() {
+        let (path, powers, powers_of_beta_times_gamma_g) = write_sample_srs();
+
+        for verify_digest in [true, false] {
+            let (mut mmap_powers, gamma_powers) =
+                MmapPowers::<Bls12_377>::open(&path, verify_digest, CHUNK_SIZE).unwrap();
+            assert_eq!(mmap_powers.num_powers(), NUM_POWERS);
+            assert_eq!(gamma_powers, powers_of_beta_times_gamma_g);
+
+            // Read every range, which covers ranges within, ending at, and crossing chunk boundaries.
+            for lower in 0..=NUM_POWERS {
+                for upper in lower..=NUM_POWERS {
+                    assert_eq!(mmap_powers.powers_of_beta_g(lower, upper).unwrap(), powers[lower..upper]);
+                }
+            }
+            assert!(mmap_powers.powers_of_beta_g(0, NUM_POWERS + 1).is_err());
+        }
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_srs_file_rejects_digest_mismatch() {
+        let (path, ..) = write_sample_srs();
+        // Corrupt the least significant byte of the x-coordinate of the last power, in chunk 4.
+        let offset = fs::metadata(&path).unwrap().len() as usize - POWER_OF_G_SERIALIZED_SIZE;
+        corrupt_byte(&path, offset);
+
+        // The digest no longer matches.
+        let error = MmapPowers::<Bls12_377>::open(&path, true, CHUNK_SIZE).unwrap_err();
+        assert!(error.to_string().contains("digest"));
+
+        // Without the digest, only the chunk containing the corrupted power is rejected.
+        let (mut mmap_powers, _) = MmapPowers::<Bls12_377>::open(&path, false, CHUNK_SIZE).unwrap();
+        assert!(mmap_powers.powers_of_beta_g(0, 4 * CHUNK_SIZE).is_ok());
+        assert!(mmap_powers.powers_of_beta_g(4 * CHUNK_SIZE, 4 * CHUNK_SIZE + 1).is_err());
+        assert!(mmap_powers.powers_of_beta_g(0, NUM_POWERS).is_err());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_srs_file_rejects_invalid_header() {
+        // Corrupt the magic, the version, and the curve ID in turn.
+        for offset in [0, 8, 12] {
+            let (path, ..) = write_sample_srs();
+            corrupt_byte(&path, offset);
+            assert!(MmapPowers::<Bls12_377>::open(&path, true, CHUNK_SIZE).is_err());
+            assert!(MmapPowers::<Bls12_377>::open(&path, false, CHUNK_SIZE).is_err());
+            fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_powers_of_g_from_srs_file() {
+        let (powers, powers_of_beta_times_gamma_g) = sample_srs();
+        let mut file_powers = PowersOfG::<Bls12_377>::from((powers.clone(), powers_of_beta_times_gamma_g.clone()));
+
+        // Write the powers to an SRS file, and open it with a memory map.
+        let path = temp_srs_path();
+        file_powers.write_srs_file(&path).unwrap();
+        let mut mmap_powers = PowersOfG::<Bls12_377>::open_mmap(path.clone(), true, CHUNK_SIZE).unwrap();
+
+        assert_eq!(mmap_powers.get_powers_times_gamma_g(), &powers_of_beta_times_gamma_g);
+        assert_eq!(mmap_powers.powers_of_beta_g(0, NUM_POWERS), powers);
+        assert_eq!(mmap_powers.power_of_beta_g(CHUNK_SIZE), powers[CHUNK_SIZE]);
+        assert!(mmap_powers.download_up_to(NUM_POWERS).is_ok());
+        assert!(mmap_powers.download_up_to(NUM_POWERS + 1).is_err());
+        fs::remove_file(path).unwrap();
+    }
+}
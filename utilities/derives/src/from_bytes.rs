@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, Type};
+
+fn impl_read_field(ty: &Type) -> TokenStream {
+    // Check if type is a tuple.
+    match ty {
+        Type::Tuple(tuple) => {
+            let fields = tuple.elems.iter().map(impl_read_field);
+            quote! { (#(#fields)*), }
+        }
+        _ => quote! { FromBytes::read_le(&mut reader)?, },
+    }
+}
+
+pub(crate) fn impl_from_bytes(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let read_body = match ast.data {
+        Data::Struct(ref data_struct) => {
+            // Read the fields in declaration order, as the struct expression evaluates them in order.
+            let mut tuple = false;
+            let mut field_cases = Vec::<TokenStream>::with_capacity(data_struct.fields.len());
+            for field in data_struct.fields.iter() {
+                let read_field = impl_read_field(&field.ty);
+                match &field.ident {
+                    None => {
+                        tuple = true;
+                        field_cases.push(read_field);
+                    }
+                    Some(ident) => field_cases.push(quote! { #ident: #read_field }),
+                }
+            }
+
+            match tuple {
+                true => quote!({ Ok(#name ( #(#field_cases)* )) }),
+                false => quote!({ Ok(#name { #(#field_cases)* }) }),
+            }
+        }
+        _ => panic!("FromBytes can only be derived for structs, {} is not a struct", name),
+    };
+
+    let gen = quote! {
+        impl #impl_generics FromBytes for #name #ty_generics #where_clause {
+            #[allow(unused_mut, unused_variables)]
+            fn read_le<R: snarkvm_utilities::Read>(mut reader: R) -> snarkvm_utilities::io::Result<Self> {
+                #read_body
+            }
+        }
+    };
+    gen
+}
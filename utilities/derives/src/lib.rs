@@ -18,6 +18,10 @@ mod canonical_deserialize;
 
 mod canonical_serialize;
 
+mod from_bytes;
+
+mod to_bytes;
+
 use syn::*;
 
 #[proc_macro_derive(CanonicalSerialize)]
@@ -31,3 +35,15 @@ pub fn derive_canonical_deserialize(input: proc_macro::TokenStream) -> proc_macr
     let ast = parse_macro_input!(input as DeriveInput);
     proc_macro::TokenStream::from(canonical_deserialize::impl_canonical_deserialize(&ast))
 }
+
+#[proc_macro_derive(ToBytes)]
+pub fn derive_to_bytes(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    proc_macro::TokenStream::from(to_bytes::impl_to_bytes(&ast))
+}
+
+#[proc_macro_derive(FromBytes)]
+pub fn derive_from_bytes(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    proc_macro::TokenStream::from(from_bytes::impl_from_bytes(&ast))
+}
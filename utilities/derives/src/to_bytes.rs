@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Data, Index, Type};
+
+enum IdentOrIndex {
+    Ident(proc_macro2::Ident),
+    Index(Index),
+}
+
+impl ToTokens for IdentOrIndex {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Ident(ident) => ident.to_tokens(tokens),
+            Self::Index(index) => index.to_tokens(tokens),
+        }
+    }
+}
+
+fn impl_write_field(write_body: &mut Vec<TokenStream>, idents: &mut Vec<IdentOrIndex>, ty: &Type) {
+    // Check if type is a tuple.
+    match ty {
+        Type::Tuple(tuple) => {
+            for (i, elem_ty) in tuple.elems.iter().enumerate() {
+                idents.push(IdentOrIndex::Index(Index::from(i)));
+                impl_write_field(write_body, idents, elem_ty);
+                idents.pop();
+            }
+        }
+        _ => write_body.push(quote! { ToBytes::write_le(&self.#(#idents).*, &mut writer)?; }),
+    }
+}
+
+pub(crate) fn impl_to_bytes(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let mut write_body = Vec::<TokenStream>::new();
+
+    match ast.data {
+        Data::Struct(ref data_struct) => {
+            let mut idents = Vec::<IdentOrIndex>::new();
+
+            // Write the fields in declaration order.
+            for (i, field) in data_struct.fields.iter().enumerate() {
+                match field.ident {
+                    None => idents.push(IdentOrIndex::Index(Index::from(i))),
+                    Some(ref ident) => idents.push(IdentOrIndex::Ident(ident.clone())),
+                }
+
+                impl_write_field(&mut write_body, &mut idents, &field.ty);
+
+                idents.clear();
+            }
+        }
+        _ => panic!("ToBytes can only be derived for structs, {} is not a struct", name),
+    };
+
+    let gen = quote! {
+        impl #impl_generics ToBytes for #name #ty_generics #where_clause {
+            #[allow(unused_mut, unused_variables)]
+            fn write_le<W: snarkvm_utilities::Write>(&self, mut writer: W) -> snarkvm_utilities::io::Result<()> {
+                #(#write_body)*
+                Ok(())
+            }
+        }
+    };
+    gen
+}
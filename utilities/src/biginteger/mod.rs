@@ -14,7 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{rand::UniformRand, FromBits, FromBytes, ToBits, ToBytes};
+use crate::{
+    rand::{UniformRand, UniformRandRange},
+    FromBits,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
 
 use num_bigint::BigUint;
 use std::fmt::{Debug, Display};
@@ -98,6 +104,29 @@ pub trait BigInteger:
     fn find_wnaf(&self) -> Vec<i64>;
 }
 
+impl<B: BigInteger> UniformRandRange for B {
+    #[inline]
+    fn rand_range<R: rand::Rng + ?Sized>(rng: &mut R, bound: &Self) -> Self {
+        assert!(!bound.is_zero(), "Cannot sample below a bound of zero");
+
+        // Mask the samples to the bit length of the bound, and reject those out of range.
+        let num_bits = bound.num_bits() as usize;
+        loop {
+            let mut candidate = B::rand(rng);
+            for (i, limb) in candidate.as_mut().iter_mut().enumerate() {
+                match num_bits.saturating_sub(64 * i) {
+                    0 => *limb = 0,
+                    num_limb_bits if num_limb_bits < 64 => *limb &= (1u64 << num_limb_bits) - 1,
+                    _ => (),
+                }
+            }
+            if candidate < *bound {
+                return candidate;
+            }
+        }
+    }
+}
+
 pub mod arithmetic {
     /// Calculate a + b + carry, returning the sum and modifying the
     /// carry value.
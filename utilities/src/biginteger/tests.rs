@@ -117,6 +117,7 @@ fn test_biginteger<B: BigInteger>(zero: B) {
     biginteger_bytes_test::<B>();
     biginteger_bits_test::<B>();
     biginteger_to_string_test::<B>();
+    biginteger_rand_range_test::<B>();
 }
 
 #[test]
@@ -128,3 +129,21 @@ fn test_biginteger256() {
 fn test_biginteger384() {
     test_biginteger(BigInteger384::new([0u64; 6]));
 }
+
+fn biginteger_rand_range_test<B: BigInteger>() {
+    use crate::rand::UniformRandRange;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // Sample bounds of varying bit lengths, including ones that straddle a limb boundary.
+    for num_bits in [1, 63, 64, 65, 100, 64 * B::NUM_LIMBS as u32 - 1] {
+        let mut bound = B::from(1u64);
+        bound.muln(num_bits);
+        bound.sub_noborrow(&B::from(1u64));
+
+        for _ in 0..1000 {
+            let sample = B::rand_range(&mut rng, &bound);
+            assert!(sample < bound, "{} >= {}", sample, bound);
+        }
+    }
+}
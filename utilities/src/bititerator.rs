@@ -21,7 +21,6 @@ pub struct BitIteratorBE<Slice> {
     n: usize,
 }
 
-#[allow(clippy::len_without_is_empty)]
 impl<Slice: AsRef<[u64]>> BitIteratorBE<Slice> {
     pub fn new(s: Slice) -> Self {
         let n = s.as_ref().len() * 64;
@@ -30,12 +29,15 @@ impl<Slice: AsRef<[u64]>> BitIteratorBE<Slice> {
 
     /// Construct an iterator that automatically skips any leading zeros.
     /// That is, it skips all zeros before the most-significant one.
-    pub fn new_without_leading_zeros(s: Slice) -> impl Iterator<Item = bool> {
-        Self::new(s).skip_while(|b| !b)
-    }
-
-    pub fn len(&self) -> usize {
-        self.n
+    pub fn new_without_leading_zeros(s: Slice) -> Self {
+        let mut n = 0;
+        for (i, limb) in s.as_ref().iter().enumerate().rev() {
+            n = i * 64 + (64 - limb.leading_zeros()) as usize;
+            if *limb != 0 {
+                break;
+            }
+        }
+        BitIteratorBE { s, n }
     }
 }
 
@@ -53,8 +55,14 @@ impl<Slice: AsRef<[u64]>> Iterator for BitIteratorBE<Slice> {
             Some(self.s.as_ref()[part] & (1 << bit) > 0)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.n, Some(self.n))
+    }
 }
 
+impl<Slice: AsRef<[u64]>> ExactSizeIterator for BitIteratorBE<Slice> {}
+
 /// Iterates over a slice of `u64` in *little-endian* order.
 #[derive(Debug)]
 pub struct BitIteratorLE<Slice: AsRef<[u64]>> {
@@ -72,7 +80,7 @@ impl<Slice: AsRef<[u64]>> BitIteratorLE<Slice> {
 
     /// Construct an iterator that automatically skips any trailing zeros.
     /// That is, it skips all zeros after the most-significant one.
-    pub fn new_without_trailing_zeros(s: Slice) -> impl Iterator<Item = bool> {
+    pub fn new_without_trailing_zeros(s: Slice) -> Self {
         let mut first_trailing_zero = 0;
         for (i, limb) in s.as_ref().iter().enumerate().rev() {
             first_trailing_zero = i * 64 + (64 - limb.leading_zeros()) as usize;
@@ -100,8 +108,15 @@ impl<Slice: AsRef<[u64]>> Iterator for BitIteratorLE<Slice> {
             Some(self.s.as_ref()[part] & (1 << bit) > 0)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.max_len - self.n;
+        (remaining, Some(remaining))
+    }
 }
 
+impl<Slice: AsRef<[u64]>> ExactSizeIterator for BitIteratorLE<Slice> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,4 +166,53 @@ mod tests {
         assert_eq!(Some(true), five.next());
         assert_eq!(None, five.next());
     }
+
+    #[test]
+    fn test_bititerator_len() {
+        for value in [[0u64, 0], [5, 0], [0, 1], [u64::MAX, u64::MAX], [0x8000_0000_0000_0001, 0x10]] {
+            // The length must equal the number of bits yielded, and decrease as they are consumed.
+            let mut be = BitIteratorBE::new(value);
+            assert_eq!(128, be.len());
+            be.next();
+            assert_eq!(127, be.len());
+            assert_eq!(127, be.count());
+
+            let mut le = BitIteratorLE::new(value);
+            assert_eq!(128, le.len());
+            le.next();
+            assert_eq!(127, le.len());
+            assert_eq!(127, le.count());
+
+            // The bits without leading or trailing zeros are the significant bits of the value.
+            let num_bits = 128 - ((value[1] as u128) << 64 | value[0] as u128).leading_zeros() as usize;
+            let be = BitIteratorBE::new_without_leading_zeros(value);
+            assert_eq!(num_bits, be.len());
+            let bits_be = be.collect::<Vec<_>>();
+            assert_eq!(num_bits, bits_be.len());
+
+            let le = BitIteratorLE::new_without_trailing_zeros(value);
+            assert_eq!(num_bits, le.len());
+            let mut bits_le = le.collect::<Vec<_>>();
+            assert_eq!(num_bits, bits_le.len());
+
+            // The big-endian bits are the reversed little-endian bits.
+            bits_le.reverse();
+            assert_eq!(bits_be, bits_le);
+        }
+    }
+
+    #[test]
+    fn test_bititerator_len_of_known_value() {
+        let bits = BitIteratorBE::new_without_leading_zeros(&[0b1101]);
+        assert_eq!(4, bits.len());
+        assert_eq!(vec![true, true, false, true], bits.collect::<Vec<_>>());
+
+        let bits = BitIteratorLE::new_without_trailing_zeros(&[0b1101]);
+        assert_eq!(4, bits.len());
+        assert_eq!(vec![true, false, true, true], bits.collect::<Vec<_>>());
+
+        let bits = BitIteratorLE::new_without_trailing_zeros(&[0, 0]);
+        assert_eq!(0, bits.len());
+        assert_eq!(0, bits.count());
+    }
 }
@@ -15,10 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    error,
     fmt,
     io::{Read, Result as IoResult, Write},
     marker::PhantomData,
+    serialize::SerializationError,
     Vec,
 };
 use serde::{
@@ -215,7 +215,7 @@ impl FromBytes for bool {
         match u8::read_le(reader) {
             Ok(0) => Ok(false),
             Ok(1) => Ok(true),
-            Ok(_) => Err(error("FromBytes::read failed")),
+            Ok(_) => Err(SerializationError::InvalidData.into()),
             Err(err) => Err(err),
         }
     }
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+pub use subtle::Choice;
+
+use subtle::ConstantTimeEq;
+
+/// Returns a `Choice` that is set if the given byte slices are equal, in constant time.
+///
+/// For slices of equal length, the running time depends only on the length, and not on the contents.
+/// Slices of unequal lengths are never equal.
+pub fn ct_eq(a: &[u8], b: &[u8]) -> Choice {
+    a.ct_eq(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_rng;
+
+    use rand::Rng;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_ct_eq() {
+        let rng = &mut test_rng();
+
+        for length in [0, 1, 31, 32, 64] {
+            for _ in 0..ITERATIONS {
+                let a = (0..length).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+                assert!(bool::from(ct_eq(&a, &a.clone())));
+
+                // Flip a random bit.
+                if length > 0 {
+                    let mut b = a.clone();
+                    b[rng.gen_range(0..length)] ^= 1 << rng.gen_range(0..8);
+                    assert!(!bool::from(ct_eq(&a, &b)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ct_eq_unequal_lengths() {
+        assert!(!bool::from(ct_eq(&[], &[0u8])));
+        assert!(!bool::from(ct_eq(&[1u8, 2, 3], &[1u8, 2])));
+        assert!(!bool::from(ct_eq(&[0u8; 32], &[0u8; 33])));
+    }
+}
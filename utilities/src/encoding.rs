@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{string::String, FromBytes, ToBytes};
+
+use anyhow::{anyhow, ensure, Result};
+use base58::{FromBase58, ToBase58};
+
+/// Returns the little-endian bytes of the given object, encoded as a lowercase hex string.
+pub fn to_hex<T: ToBytes>(object: &T) -> Result<String> {
+    Ok(hex::encode(object.to_bytes_le()?))
+}
+
+/// Returns the object decoded from the given hex string of its little-endian bytes.
+pub fn from_hex<T: FromBytes>(string: &str) -> Result<T> {
+    let bytes = hex::decode(string).map_err(|error| anyhow!("Invalid hex string: {error}"))?;
+    from_bytes_exact(&bytes)
+}
+
+/// The maximum number of bytes that the base58 decoder accepts.
+const MAX_BASE58_BYTES: usize = 128;
+
+/// Returns the little-endian bytes of the given object, encoded as a base58 string.
+/// Returns an error if the object exceeds 128 bytes, as the base58 decoder rejects longer strings.
+pub fn to_base58<T: ToBytes>(object: &T) -> Result<String> {
+    let bytes = object.to_bytes_le()?;
+    ensure!(
+        bytes.len() <= MAX_BASE58_BYTES,
+        "Cannot base58 encode {} bytes, the maximum is {MAX_BASE58_BYTES}",
+        bytes.len()
+    );
+    Ok(bytes.to_base58())
+}
+
+/// Returns the object decoded from the given base58 string of its little-endian bytes.
+pub fn from_base58<T: FromBytes>(string: &str) -> Result<T> {
+    let bytes = string.from_base58().map_err(|error| anyhow!("Invalid base58 string: {:?}", error))?;
+    from_bytes_exact(&bytes)
+}
+
+/// Returns the object read from the given bytes, ensuring all of the bytes are consumed.
+fn from_bytes_exact<T: FromBytes>(bytes: &[u8]) -> Result<T> {
+    let mut reader = bytes;
+    let object = T::read_le(&mut reader)?;
+    ensure!(reader.is_empty(), "Found {} trailing bytes after decoding {} bytes", reader.len(), bytes.len() - reader.len());
+    Ok(object)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex() {
+        let expected = [0u8, 1, 2, 254, 255];
+        assert_eq!("000102feff", to_hex(&expected).unwrap());
+        assert_eq!(expected, from_hex::<[u8; 5]>("000102feff").unwrap());
+        assert_eq!(expected, from_hex::<[u8; 5]>("000102FEFF").unwrap());
+
+        let expected = 0x0123456789abcdefu64;
+        assert_eq!(expected, from_hex::<u64>(&to_hex(&expected).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_base58() {
+        let expected = [0u8, 1, 2, 254, 255];
+        assert_eq!(expected, from_base58::<[u8; 5]>(&to_base58(&expected).unwrap()).unwrap());
+
+        let expected = 0x0123456789abcdefu64;
+        assert_eq!(expected, from_base58::<u64>(&to_base58(&expected).unwrap()).unwrap());
+
+        let expected = [255u8; MAX_BASE58_BYTES];
+        assert_eq!(expected, from_base58::<[u8; MAX_BASE58_BYTES]>(&to_base58(&expected).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_base58_exceeds_capacity() {
+        assert!(to_base58(&[255u8; MAX_BASE58_BYTES + 1]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_string() {
+        // Invalid characters.
+        assert!(from_hex::<u64>("0x0123456789abcdef").is_err());
+        assert!(from_base58::<u64>("0OIl").is_err());
+        // An odd number of hex digits.
+        assert!(from_hex::<u64>("0123456789abcde").is_err());
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        let bytes = [1u8; 8];
+
+        // Too few bytes.
+        assert!(from_hex::<u64>(&to_hex(&[1u8; 7]).unwrap()).is_err());
+        assert!(from_base58::<u64>(&to_base58(&[1u8; 7]).unwrap()).is_err());
+        // Too many bytes.
+        assert!(from_hex::<u64>(&to_hex(&[1u8; 9]).unwrap()).is_err());
+        assert!(from_base58::<u64>(&to_base58(&[1u8; 9]).unwrap()).is_err());
+        // Exactly enough bytes.
+        assert!(from_hex::<u64>(&to_hex(&bytes).unwrap()).is_ok());
+        assert!(from_base58::<u64>(&to_base58(&bytes).unwrap()).is_ok());
+    }
+}
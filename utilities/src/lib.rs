@@ -59,12 +59,23 @@ pub use bits::*;
 pub mod bytes;
 pub use bytes::*;
 
+pub mod constant_time;
+pub use constant_time::*;
+
+pub mod encoding;
+pub use encoding::*;
+
 pub mod error;
 pub use error::*;
 
 pub mod iterator;
 pub use iterator::*;
 
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+pub mod mmap;
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+pub use mmap::*;
+
 #[macro_use]
 pub mod parallel;
 pub use parallel::*;
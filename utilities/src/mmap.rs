@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use memmap2::Mmap;
+use std::{fs::File, io, ops::Deref, path::Path};
+
+/// A read-only memory map of a file, for crates that forbid unsafe code.
+#[derive(Debug)]
+pub struct MmapFile(Mmap);
+
+impl MmapFile {
+    /// Memory-maps the file at `path` for reading.
+    ///
+    /// The file must not be modified while it is mapped, as the bytes of the map would change with it.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // Safety: the map is read-only, and the caller does not modify the file while it is mapped.
+        let mmap = unsafe { Mmap::map(&file)? };
+        Ok(Self(mmap))
+    }
+}
+
+impl Deref for MmapFile {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
@@ -36,6 +36,36 @@ where
     }
 }
 
+pub trait UniformRandRange: Sized {
+    /// Samples a value uniformly from the range `[0, bound)`.
+    /// Panics if the bound is zero.
+    fn rand_range<R: Rng + ?Sized>(rng: &mut R, bound: &Self) -> Self;
+}
+
+macro_rules! impl_uniform_rand_range {
+    ($($int: ty),*) => {
+        $(
+            impl UniformRandRange for $int {
+                #[inline]
+                fn rand_range<R: Rng + ?Sized>(rng: &mut R, bound: &Self) -> Self {
+                    assert!(*bound > 0, "Cannot sample below a bound of zero");
+
+                    // Mask the samples to the bit length of the bound, and reject those out of range.
+                    let mask = <$int>::MAX >> bound.leading_zeros();
+                    loop {
+                        let candidate = rng.gen::<$int>() & mask;
+                        if candidate < *bound {
+                            return candidate;
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_uniform_rand_range!(u8, u16, u32, u64, u128, usize);
+
 /// A fast Rng which should be used only in tests or benchmarks, but not for any real world purposes.
 pub fn test_rng() -> XorShiftRng {
     // Obtain the initial seed using entropy provided by the OS.
@@ -45,6 +75,12 @@ pub fn test_rng() -> XorShiftRng {
     XorShiftRng::seed_from_u64(seed)
 }
 
+/// A fast Rng which is deterministic for the given seed, to reproduce a specific test case.
+/// Should be used only in tests or benchmarks, but not for any real world purposes.
+pub fn test_rng_seeded(seed: u64) -> XorShiftRng {
+    XorShiftRng::seed_from_u64(seed)
+}
+
 /// An Rng which can be used in tests or benchmarks requiring a CryptoRng.
 pub fn test_crypto_rng() -> StdRng {
     StdRng::from_entropy()
@@ -56,3 +92,74 @@ pub fn test_crypto_rng_fixed() -> StdRng {
     let seed = 1245897092u64;
     StdRng::seed_from_u64(seed)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NUM_SAMPLES: usize = 100_000;
+
+    fn check_rand_range<T: UniformRandRange + Copy + PartialOrd + Into<u128>>(bound: T) {
+        // Use a fixed seed, so that the bucket counts below are deterministic.
+        let mut rng = test_rng_seeded(bound.into() as u64);
+        let num_buckets: u128 = bound.into();
+
+        let mut buckets = vec![0usize; num_buckets as usize];
+        for _ in 0..NUM_SAMPLES {
+            let sample = T::rand_range(&mut rng, &bound);
+            assert!(sample < bound);
+            buckets[sample.into() as usize] += 1;
+        }
+
+        // Ensure every bucket is within 20% of its expected count.
+        let expected = NUM_SAMPLES / num_buckets as usize;
+        for (value, count) in buckets.iter().enumerate() {
+            let difference = core::cmp::max(*count, expected) - core::cmp::min(*count, expected);
+            assert!(difference < expected / 5, "Bucket {value} has {count} samples, expected {expected}");
+        }
+    }
+
+    #[test]
+    fn test_rand_range() {
+        check_rand_range(1u8);
+        check_rand_range(7u8);
+        check_rand_range(10u16);
+        check_rand_range(33u32);
+        check_rand_range(100u64);
+        check_rand_range(129u128);
+    }
+
+    #[test]
+    fn test_rand_range_large_bound() {
+        let mut rng = test_rng();
+        let bound = u64::MAX / 3 * 2;
+        for _ in 0..NUM_SAMPLES {
+            assert!(u64::rand_range(&mut rng, &bound) < bound);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rand_range_zero_bound() {
+        u32::rand_range(&mut test_rng(), &0);
+    }
+
+    #[test]
+    fn test_rng_seeded_is_deterministic() {
+        // Ensure the same seed yields the same sequence.
+        let mut rng_a = test_rng_seeded(1234);
+        let mut rng_b = test_rng_seeded(1234);
+        for _ in 0..100 {
+            assert_eq!(u64::rand(&mut rng_a), u64::rand(&mut rng_b));
+        }
+
+        // Ensure different seeds diverge.
+        let mut rng_c = test_rng_seeded(1235);
+        let sequence_a = (0..100).map(|_| u64::rand(&mut rng_a)).collect::<Vec<_>>();
+        let sequence_c = (0..100).map(|_| u64::rand(&mut rng_c)).collect::<Vec<_>>();
+        assert_ne!(sequence_a, sequence_c);
+
+        // Ensure the sequence is stable across runs and platforms.
+        assert_eq!(16404092901424811733, u64::rand(&mut test_rng_seeded(0)));
+    }
+}
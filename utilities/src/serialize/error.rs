@@ -24,18 +24,49 @@ pub enum SerializationError {
     InvalidData,
     /// During serialization, we countered an I/O error.
     #[error("IoError: {0}")]
-    IoError(#[from] crate::io::Error),
+    IoError(crate::io::Error),
     /// During serialization, we didn't have enough space to write extra info.
     #[error("the last byte does not have enough space to encode the extra info bits")]
     NotEnoughSpace,
+    /// During deserialization, the input was not in the correct subgroup.
+    #[error("the input is not in the correct subgroup")]
+    NotInSubgroup,
+    /// During deserialization, the input was not a canonical field element.
+    #[error("the input is not a valid field element")]
+    NotInField,
+    /// During deserialization, the input was not a point on the curve.
+    #[error("the input is not a point on the curve")]
+    NotOnCurve,
+    /// During deserialization, the input buffer ended before the data was read.
+    #[error("the input buffer ended unexpectedly")]
+    UnexpectedEof,
     /// During serialization, non-empty flags were given where none were
     /// expected.
     #[error("the call expects empty flags")]
     UnexpectedFlags,
 }
 
+impl From<crate::io::Error> for SerializationError {
+    /// Recovers the serialization error carried by the I/O error, if there is one.
+    fn from(error: crate::io::Error) -> Self {
+        if error.get_ref().map_or(false, |inner| inner.is::<SerializationError>()) {
+            // The inner error was checked to be a serialization error.
+            return *error.into_inner().and_then(|inner| inner.downcast().ok()).unwrap();
+        }
+        match error.kind() {
+            crate::io::ErrorKind::UnexpectedEof => SerializationError::UnexpectedEof,
+            _ => SerializationError::IoError(error),
+        }
+    }
+}
+
 impl From<SerializationError> for crate::io::Error {
+    /// Wraps the serialization error, so that it is recovered when converted back.
     fn from(error: SerializationError) -> Self {
-        crate::io::Error::new(crate::io::ErrorKind::Other, format!("{}", error))
+        match error {
+            SerializationError::IoError(error) => error,
+            SerializationError::UnexpectedEof => crate::io::Error::new(crate::io::ErrorKind::UnexpectedEof, error),
+            error => crate::io::Error::new(crate::io::ErrorKind::InvalidData, error),
+        }
     }
 }
@@ -115,7 +115,7 @@ impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {
 
     #[inline]
     fn serialized_size(&self) -> usize {
-        8 + if let Some(item) = self { item.serialized_size() } else { 0 }
+        self.is_some().serialized_size() + if let Some(item) = self { item.serialized_size() } else { 0 }
     }
 
     #[inline]
@@ -127,6 +127,11 @@ impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {
 
         Ok(())
     }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.is_some().uncompressed_size() + if let Some(item) = self { item.uncompressed_size() } else { 0 }
+    }
 }
 
 impl<T: CanonicalDeserialize> CanonicalDeserialize for Option<T> {
@@ -386,6 +391,55 @@ impl<'a, T: CanonicalSerialize> CanonicalSerialize for &'a [T] {
     }
 }
 
+// The length of a fixed-size array is known from its type, so it is not serialized.
+impl<T: CanonicalSerialize, const N: usize> CanonicalSerialize for [T; N] {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        for item in self.iter() {
+            item.serialize(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.iter().map(|item| item.serialized_size()).sum::<usize>()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        for item in self.iter() {
+            item.serialize_uncompressed(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.iter().map(|item| item.uncompressed_size()).sum::<usize>()
+    }
+}
+
+impl<T: CanonicalDeserialize, const N: usize> CanonicalDeserialize for [T; N] {
+    #[inline]
+    fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let mut values = Vec::with_capacity(N);
+        for _ in 0..N {
+            values.push(T::deserialize(reader)?);
+        }
+        values.try_into().map_err(|_| SerializationError::InvalidData)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let mut values = Vec::with_capacity(N);
+        for _ in 0..N {
+            values.push(T::deserialize_uncompressed(reader)?);
+        }
+        values.try_into().map_err(|_| SerializationError::InvalidData)
+    }
+}
+
 // Implement Serialization for tuples
 macro_rules! impl_tuple {
     ($( $ty: ident : $no: tt, )+) => {
@@ -489,6 +543,14 @@ mod test {
         data.serialize(&mut &mut serialized[..]).unwrap();
         let de = T::deserialize(&mut &serialized[..]).unwrap();
         assert_eq!(data, de);
+
+        // The reported sizes match the number of bytes written.
+        let mut serialized = vec![];
+        data.serialize(&mut serialized).unwrap();
+        assert_eq!(data.serialized_size(), serialized.len());
+        let mut serialized = vec![];
+        data.serialize_uncompressed(&mut serialized).unwrap();
+        assert_eq!(data.uncompressed_size(), serialized.len());
     }
 
     #[test]
@@ -497,6 +559,28 @@ mod test {
         test_serialize(false);
     }
 
+    #[test]
+    fn test_unexpected_eof() {
+        assert!(matches!(bool::deserialize(&mut &[][..]), Err(SerializationError::UnexpectedEof)));
+        assert!(matches!(u64::deserialize(&mut &[0u8; 7][..]), Err(SerializationError::UnexpectedEof)));
+        assert!(matches!(
+            Vec::<u8>::deserialize(&mut &[2, 0, 0, 0, 0, 0, 0, 0, 1][..]),
+            Err(SerializationError::UnexpectedEof)
+        ));
+        // The error is recovered from the `FromBytes` error.
+        let error = bool::read_le(&[][..]).unwrap_err();
+        assert!(matches!(SerializationError::from(error), SerializationError::UnexpectedEof));
+    }
+
+    #[test]
+    fn test_invalid_data() {
+        assert!(matches!(bool::deserialize(&mut &[2u8][..]), Err(SerializationError::InvalidData)));
+        assert!(matches!(Vec::<u64>::deserialize(&mut &[0xff; 8][..]), Err(SerializationError::InvalidData)));
+        // The error is recovered from the `FromBytes` error.
+        let error = bool::read_le(&[2u8][..]).unwrap_err();
+        assert!(matches!(SerializationError::from(error), SerializationError::InvalidData));
+    }
+
     #[test]
     fn test_uint() {
         test_serialize(192830918usize);
@@ -517,6 +601,29 @@ mod test {
         test_serialize(Vec::<u64>::new());
     }
 
+    #[test]
+    fn test_array() {
+        test_serialize([1u64, 2, 3, 4]);
+        test_serialize([(123u64, 234u32), (345u64, 456u32)]);
+        test_serialize(Vec::<[u8; 3]>::from([[1, 2, 3], [4, 5, 6]]));
+        test_serialize::<[u64; 0]>([]);
+
+        // The length of the array is not serialized.
+        assert_eq!([1u64, 2, 3, 4].serialized_size(), 32);
+    }
+
+    #[test]
+    fn test_array_truncated() {
+        let data = [1u64, 2, 3, 4];
+        let mut serialized = vec![0; data.serialized_size()];
+        data.serialize(&mut &mut serialized[..]).unwrap();
+
+        for len in 0..serialized.len() {
+            assert!(<[u64; 4]>::deserialize(&mut &serialized[..len]).is_err());
+            assert!(<[u64; 4]>::deserialize_uncompressed(&mut &serialized[..len]).is_err());
+        }
+    }
+
     #[test]
     fn test_tuple() {
         test_serialize((123u64, 234u32, 999u16));